@@ -62,8 +62,8 @@ export class Execution extends EventEmitter {
     prometheus: Prometheus,
   ) {
     super();
-    this.owner = eventData[0];
-    this.xtxId = eventData[1];
+    this.xtxId = eventData[0];
+    this.owner = eventData[1];
     this.id = this.xtxId.toHex();
     this.humanId = this.id.slice(0, 8);
     this.prometheus = prometheus;
@@ -288,7 +288,7 @@ export class ExecutionManager {
           break;
         case ListenerEvents.SideEffectConfirmed:
           {
-            const sfxId = eventData.data[0].toString();
+            const sfxId = eventData.data[1].toString();
             const xtxId = this.sfxToXtx[sfxId];
             const xtx = this.xtx[xtxId];
 
@@ -424,9 +424,9 @@ export class ExecutionManager {
    * @param bidData SCALE encoded bid data, as emitted by the circuit
    */
   addBid(bidData: ListEventData) {
-    const sfxId = bidData[0].toString();
-    const bidder = bidData[1].toString();
-    const amt = bidData[2].toNumber();
+    const sfxId = bidData[1].toString();
+    const bidder = bidData[2].toString();
+    const amt = bidData[3].toNumber();
 
     // read chain state to fetch non-existent mapping
     if (!this.sfxToXtx[sfxId]) {
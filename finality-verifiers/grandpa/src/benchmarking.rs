@@ -0,0 +1,314 @@
+//! Benchmarking setup for pallet-grandpa-finality-verifier
+//!
+//! Ranges of `h` headers are finalized by justifications carrying a precommit of each of `a`
+//! GRANDPA authorities, keyed in the benchmark's keystore, and events are proven against the state
+//! root of the signed header the same way relayers prove them from a live relaychain. The fixture
+//! builders are public so the benchmarks of pallets verifying through this light client, i.e. the
+//! portal, exercise the same proofs.
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::types::{GrandpaHeaderData, RelaychainInclusionProof, RelaychainRegistrationData};
+use frame_benchmarking::{benchmarks_instance_pallet, whitelisted_caller};
+use sp_consensus_grandpa::{AuthorityId, AuthoritySignature, SetId};
+use sp_runtime::{traits::One, RuntimeAppPublic};
+use sp_trie::{MemoryDB, TrieDBMutBuilder, TrieMut};
+
+/// Headers of the longest range benchmarked, besides the signed header
+pub const MAX_HEADERS_RANGE: u32 = 100;
+/// Largest GRANDPA authority set benchmarked, above the size of Polkadot's
+pub const MAX_AUTHORITIES: u32 = 400;
+/// Size of the largest block events benchmarked, in bytes
+pub const MAX_EVENTS_LEN: u32 = 512 * 1024;
+/// Size of the event proven, i.e. a `Balances::Transfer` event: indices, two accounts and amount
+pub const EVENT_PAYLOAD_LEN: u32 = 82;
+
+const GATEWAY_ID: ChainId = *b"roco";
+const ROUND: u64 = 1;
+const SET_ID: SetId = 1;
+
+/// Generate `a` GRANDPA authorities with their keys in the benchmark's keystore.
+pub fn make_authorities(a: u32) -> Vec<AuthorityId> {
+    (0..a).map(|_| AuthorityId::generate_pair(None)).collect()
+}
+
+fn make_header<T: Config<I>, I: 'static>(
+    number: BridgedBlockNumber<T, I>,
+    parent_hash: BridgedBlockHash<T, I>,
+    state_root: BridgedBlockHash<T, I>,
+) -> BridgedHeader<T, I> {
+    HeaderT::new(
+        number,
+        Default::default(),
+        state_root,
+        parent_hash,
+        Default::default(),
+    )
+}
+
+/// Registration data of a relaychain starting at its genesis, finalized by `authorities`.
+/// Returns the genesis header along with the encoded registration data.
+pub fn make_registration_data<T: Config<I>, I: 'static>(
+    authorities: &[AuthorityId],
+    owner: T::AccountId,
+) -> (BridgedHeader<T, I>, Vec<u8>) {
+    let genesis = make_header::<T, I>(Zero::zero(), Default::default(), Default::default());
+    let registration_data = RelaychainRegistrationData::<T::AccountId> {
+        first_header: genesis.encode(),
+        authorities: authorities.to_vec(),
+        authority_set_id: SET_ID,
+        owner,
+    };
+    (genesis, registration_data.encode())
+}
+
+fn sign_precommit<T: Config<I>, I: 'static>(
+    authority: &AuthorityId,
+    target: &BridgedHeader<T, I>,
+) -> finality_grandpa::SignedPrecommit<
+    BridgedBlockHash<T, I>,
+    BridgedBlockNumber<T, I>,
+    AuthoritySignature,
+    AuthorityId,
+> {
+    let precommit = finality_grandpa::Precommit {
+        target_hash: target.hash(),
+        target_number: *target.number(),
+    };
+    let payload = sp_consensus_grandpa::localized_payload(
+        ROUND,
+        SET_ID,
+        &finality_grandpa::Message::Precommit(precommit.clone()),
+    );
+    let signature = authority
+        .sign(&payload)
+        .expect("Authority key should be in the keystore of the benchmark");
+    finality_grandpa::SignedPrecommit {
+        precommit,
+        signature,
+        id: authority.clone(),
+    }
+}
+
+/// A range of `h` headers following `parent`, topped with a header committing to `state_root`
+/// and justified by precommits of all `authorities`.
+pub fn make_headers_data<T: Config<I>, I: 'static>(
+    parent: &BridgedHeader<T, I>,
+    h: u32,
+    authorities: &[AuthorityId],
+    state_root: BridgedBlockHash<T, I>,
+) -> GrandpaHeaderData<BridgedHeader<T, I>> {
+    let mut range = Vec::with_capacity(h as usize);
+    let mut parent = parent.clone();
+    for _ in 0..h {
+        let header = make_header::<T, I>(
+            *parent.number() + One::one(),
+            parent.hash(),
+            Default::default(),
+        );
+        range.push(header.clone());
+        parent = header;
+    }
+
+    let signed_header =
+        make_header::<T, I>(*parent.number() + One::one(), parent.hash(), state_root);
+    let precommits = authorities
+        .iter()
+        .map(|authority| sign_precommit::<T, I>(authority, &signed_header))
+        .collect();
+    let justification = GrandpaJustification {
+        round: ROUND,
+        commit: finality_grandpa::Commit {
+            target_hash: signed_header.hash(),
+            target_number: *signed_header.number(),
+            precommits,
+        },
+        votes_ancestries: vec![],
+    };
+
+    GrandpaHeaderData {
+        signed_header,
+        range,
+        justification,
+    }
+}
+
+/// Block events of `len` bytes ending with `payload`, the worst case of the events scan.
+/// Returns the state root committing to the events, along with the proof of their storage entry.
+pub fn make_events_proof<T: Config<I>, I: 'static>(
+    payload: &[u8],
+    len: u32,
+) -> (BridgedBlockHash<T, I>, StorageProof) {
+    let mut events = vec![0u8; (len as usize).saturating_sub(payload.len())];
+    events.extend_from_slice(payload);
+
+    let mut db = MemoryDB::<BridgedBlockHasher<T, I>>::default();
+    let mut state_root = Default::default();
+    {
+        let mut trie =
+            TrieDBMutBuilder::<LayoutV1<BridgedBlockHasher<T, I>>>::new(&mut db, &mut state_root)
+                .build();
+        trie.insert(&SYSTEM_EVENTS_KEY, &events)
+            .expect("Events should insert into an empty trie");
+    }
+    // The trie holds the events only, so all of its nodes make up their proof
+    let proof = StorageProof::new(db.drain().into_values().map(|(node, _)| node));
+    (state_root, proof)
+}
+
+// Registers the relaychain from scratch, replacing any registered at genesis of the benchmark
+fn initialize_relaychain<T: Config<I>, I: 'static>(
+    authorities: &[AuthorityId],
+) -> BridgedHeader<T, I> {
+    Pallet::<T, I>::reset(RawOrigin::Root.into())
+        .expect("Light client should reset in GrandpaFV::runtime_benchmarks");
+    let (genesis, registration_data) =
+        make_registration_data::<T, I>(authorities, whitelisted_caller());
+    Pallet::<T, I>::initialize(RawOrigin::Root.into(), GATEWAY_ID, registration_data)
+        .expect("Relaychain should initialize in GrandpaFV::runtime_benchmarks");
+    genesis
+}
+
+// Funds the whitelisted caller for the deposits of a header sync session and one chunk into it
+fn funded_relayer<T: Config<I>, I: 'static>() -> T::AccountId {
+    let relayer: T::AccountId = whitelisted_caller();
+    let balance = T::Currency::minimum_balance()
+        .saturating_add(T::HeaderSyncSessionDeposit::get())
+        .saturating_add(T::HeaderSyncChunkDeposit::get());
+    T::Currency::make_free_balance_be(&relayer, balance);
+    relayer
+}
+
+// Opens a header sync session of the whitelisted caller, returning its id
+fn open_header_sync_session<T: Config<I>, I: 'static>() -> u32 {
+    let session_id = <NextHeaderSyncSessionId<T, I>>::get();
+    Pallet::<T, I>::open_header_sync(RawOrigin::Signed(funded_relayer::<T, I>()).into())
+        .expect("Header sync should open in GrandpaFV::runtime_benchmarks");
+    session_id
+}
+
+benchmarks_instance_pallet! {
+    initialize {
+        let a in 3 .. MAX_AUTHORITIES;
+        Pallet::<T, I>::reset(RawOrigin::Root.into())
+            .expect("Light client should reset in GrandpaFV::runtime_benchmarks");
+        let (_, registration_data) =
+            make_registration_data::<T, I>(&make_authorities(a), whitelisted_caller());
+    }: {
+        Pallet::<T, I>::initialize(RawOrigin::Root.into(), GATEWAY_ID, registration_data)
+            .expect("Relaychain should initialize in GrandpaFV::runtime_benchmarks");
+    }
+    verify {
+        assert_eq!(<RelayChainId<T, I>>::get(), Some(GATEWAY_ID));
+    }
+
+    submit_headers {
+        let h in 0 .. MAX_HEADERS_RANGE;
+        let a in 3 .. MAX_AUTHORITIES;
+        let authorities = make_authorities(a);
+        let genesis = initialize_relaychain::<T, I>(&authorities);
+        let data = make_headers_data::<T, I>(&genesis, h, &authorities, Default::default());
+        let signed_hash = data.signed_header.hash();
+        let caller: T::AccountId = whitelisted_caller();
+        let range: BoundedVec<_, T::HeadersToStore> = data.range.try_into()
+            .expect("Range should fit HeadersToStore in GrandpaFV::runtime_benchmarks");
+    }: _(RawOrigin::Signed(caller), range, data.signed_header, data.justification)
+    verify {
+        assert_eq!(<BestFinalizedHash<T, I>>::get(), Some(signed_hash));
+    }
+
+    open_header_sync {
+        initialize_relaychain::<T, I>(&make_authorities(3));
+        let session_id = <NextHeaderSyncSessionId<T, I>>::get();
+        let caller = funded_relayer::<T, I>();
+    }: _(RawOrigin::Signed(caller))
+    verify {
+        assert!(<HeaderSyncSessions<T, I>>::contains_key(session_id));
+    }
+
+    submit_header_chunk {
+        let h in 1 .. MAX_HEADERS_RANGE;
+        let authorities = make_authorities(3);
+        let genesis = initialize_relaychain::<T, I>(&authorities);
+        let data = make_headers_data::<T, I>(&genesis, h, &authorities, Default::default());
+        let session_id = open_header_sync_session::<T, I>();
+        let caller: T::AccountId = whitelisted_caller();
+        let chunk: BoundedVec<_, T::HeadersToStore> = data.range.try_into()
+            .expect("Chunk should fit HeadersToStore in GrandpaFV::runtime_benchmarks");
+    }: _(RawOrigin::Signed(caller), session_id, chunk)
+    verify {
+        assert_eq!(
+            <HeaderSyncSessions<T, I>>::get(session_id).map(|session| session.headers_cnt),
+            Some(h)
+        );
+    }
+
+    finalize_header_sync {
+        let h in 1 .. MAX_HEADERS_RANGE;
+        let a in 3 .. MAX_AUTHORITIES;
+        let authorities = make_authorities(a);
+        let genesis = initialize_relaychain::<T, I>(&authorities);
+        let data = make_headers_data::<T, I>(&genesis, h, &authorities, Default::default());
+        let signed_hash = data.signed_header.hash();
+        let session_id = open_header_sync_session::<T, I>();
+        let caller: T::AccountId = whitelisted_caller();
+        Pallet::<T, I>::submit_header_chunk(
+            RawOrigin::Signed(caller.clone()).into(),
+            session_id,
+            data.range.try_into()
+                .expect("Chunk should fit HeadersToStore in GrandpaFV::runtime_benchmarks"),
+        )
+        .expect("Header chunk should submit in GrandpaFV::runtime_benchmarks");
+    }: _(RawOrigin::Signed(caller), session_id, h, data.signed_header, data.justification)
+    verify {
+        assert_eq!(<BestFinalizedHash<T, I>>::get(), Some(signed_hash));
+    }
+
+    abort_header_sync {
+        let h in 1 .. MAX_HEADERS_RANGE;
+        let authorities = make_authorities(3);
+        let genesis = initialize_relaychain::<T, I>(&authorities);
+        let data = make_headers_data::<T, I>(&genesis, h, &authorities, Default::default());
+        let session_id = open_header_sync_session::<T, I>();
+        let caller: T::AccountId = whitelisted_caller();
+        Pallet::<T, I>::submit_header_chunk(
+            RawOrigin::Signed(caller.clone()).into(),
+            session_id,
+            data.range.try_into()
+                .expect("Chunk should fit HeadersToStore in GrandpaFV::runtime_benchmarks"),
+        )
+        .expect("Header chunk should submit in GrandpaFV::runtime_benchmarks");
+    }: _(RawOrigin::Signed(caller), session_id, h)
+    verify {
+        assert!(!<HeaderSyncSessions<T, I>>::contains_key(session_id));
+    }
+
+    confirm_event_inclusion {
+        let p in EVENT_PAYLOAD_LEN .. MAX_EVENTS_LEN;
+        let authorities = make_authorities(3);
+        let genesis = initialize_relaychain::<T, I>(&authorities);
+        let payload = vec![1u8; EVENT_PAYLOAD_LEN as usize];
+        let (state_root, payload_proof) = make_events_proof::<T, I>(&payload, p);
+        let data = make_headers_data::<T, I>(&genesis, 0, &authorities, state_root);
+        let block_hash = data.signed_header.hash();
+        Pallet::<T, I>::submit_encoded_headers(data.encode())
+            .expect("Headers should submit in GrandpaFV::runtime_benchmarks");
+        let inclusion_proof = RelaychainInclusionProof::<BridgedHeader<T, I>> {
+            encoded_payload: payload.clone(),
+            payload_proof,
+            block_hash,
+        }
+        .encode();
+    }: {
+        let receipt = Pallet::<T, I>::confirm_event_inclusion(GATEWAY_ID, inclusion_proof, None)
+            .expect("Event inclusion should confirm in GrandpaFV::runtime_benchmarks");
+        assert_eq!(receipt.message, payload);
+    }
+}
+
+#[cfg(feature = "testing")]
+frame_benchmarking::impl_benchmark_test_suite!(
+    Pallet,
+    crate::mock::new_benchmark_ext(),
+    crate::mock::TestRuntime
+);
@@ -86,12 +86,19 @@ pub fn decode_justification_target<Header: HeaderT>(
 }
 
 /// Verify that justification, that is generated by given authority set, finalizes given header.
+///
+/// With the `batch-verify` feature, signatures are verified in a single pass once the precommits
+/// are known to justify the header, so justifications failing the cheaper ancestry and weight
+/// checks cost no signature verification at all.
+///
+/// Returns the number of precommit signatures verified, i.e. the precommits left after skipping
+/// those of unknown authorities and repeated votes, for callers to weigh the verification by.
 pub fn verify_justification<Header: HeaderT>(
     finalized_target: (Header::Hash, Header::Number),
     authorities_set_id: SetId,
     authorities_set: &VoterSet<AuthorityId>,
     justification: &GrandpaJustification<Header>,
-) -> Result<(), Error>
+) -> Result<u32, Error>
 where
     Header::Number: finality_grandpa::BlockNumberOps,
 {
@@ -105,9 +112,13 @@ where
     }
 
     let mut chain = AncestryChain::new(&justification.votes_ancestries);
+    #[cfg(not(feature = "batch-verify"))]
     let mut signature_buffer = Vec::new();
+    #[cfg(feature = "batch-verify")]
+    let mut batch = Vec::with_capacity(justification.commit.precommits.len());
     let mut votes = BTreeSet::new();
     let mut cumulative_weight = 0u64;
+    let mut verified_signatures = 0u32;
     for signed in &justification.commit.precommits {
         // authority must be in the set
         let authority_info = match authorities_set.get(&signed.id) {
@@ -153,6 +164,7 @@ where
 				qed",
         );
         // verify authority signature
+        #[cfg(not(feature = "batch-verify"))]
         if !sp_consensus_grandpa::check_message_signature_with_buffer(
             &finality_grandpa::Message::Precommit(signed.precommit.clone()),
             &signed.id,
@@ -163,6 +175,9 @@ where
         ) {
             return Err(Error::InvalidAuthoritySignature)
         }
+        #[cfg(feature = "batch-verify")]
+        batch.push(signed);
+        verified_signatures += 1;
     }
 
     // check that there are no extra headers in the justification
@@ -173,10 +188,39 @@ where
     // check that the cumulative weight of validators voted for the justification target (or one
     // of its descendents) is larger than required threshold.
     let threshold = authorities_set.threshold().0.into();
-    if cumulative_weight >= threshold {
+    if cumulative_weight < threshold {
+        return Err(Error::TooLowCumulativeWeight)
+    }
+
+    #[cfg(feature = "batch-verify")]
+    verify_signatures_batch(&batch, justification.round, authorities_set_id)?;
+
+    Ok(verified_signatures)
+}
+
+/// Verify the signatures of all accepted precommits in one pass, sharing the buffer of the signed
+/// payloads. Fails on the first invalid signature.
+#[cfg(feature = "batch-verify")]
+fn verify_signatures_batch<Hash: Clone + Encode, Number: Clone + Encode>(
+    batch: &[&finality_grandpa::SignedPrecommit<Hash, Number, AuthoritySignature, AuthorityId>],
+    round: u64,
+    authorities_set_id: SetId,
+) -> Result<(), Error> {
+    let mut signature_buffer = Vec::new();
+    let all_valid = batch.iter().all(|signed| {
+        sp_consensus_grandpa::check_message_signature_with_buffer(
+            &finality_grandpa::Message::Precommit(signed.precommit.clone()),
+            &signed.id,
+            &signed.signature,
+            round,
+            authorities_set_id,
+            &mut signature_buffer,
+        )
+    });
+    if all_valid {
         Ok(())
     } else {
-        Err(Error::TooLowCumulativeWeight)
+        Err(Error::InvalidAuthoritySignature)
     }
 }
 
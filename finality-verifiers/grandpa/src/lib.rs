@@ -48,19 +48,28 @@ use bridges::{
     header_chain as bp_header_chain, header_chain::ProofTriePointer, runtime as bp_runtime,
 };
 use finality_grandpa::voter_set::VoterSet;
-use frame_support::{ensure, pallet_prelude::*, transactional, StorageHasher};
+use frame_support::{
+    ensure,
+    pallet_prelude::*,
+    traits::{Currency, ReservableCurrency},
+    transactional, StorageHasher,
+};
 use frame_system::{ensure_signed, pallet_prelude::BlockNumberFor, RawOrigin};
 
 use sp_consensus_grandpa::{ConsensusLog, GRANDPA_ENGINE_ID};
 use sp_core::crypto::ByteArray;
-use sp_runtime::traits::{BadOrigin, Header as HeaderT, Zero};
-use t3rn_primitives::light_client::LightClientAsyncAPI;
+use sp_runtime::traits::{BadOrigin, Header as HeaderT, One, Saturating, Zero};
+use t3rn_primitives::{
+    compression,
+    light_client::LightClientAsyncAPI,
+    portal::{HeaderSubmissionHooks, OnHeadersRolledBack},
+};
 
 use sp_std::{convert::TryInto, vec, vec::Vec};
 use sp_trie::{read_trie_value, LayoutV1, StorageProof};
 
-// #[cfg(feature = "runtime-benchmarks")]
-// pub mod benchmarking;
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
 
 #[cfg(feature = "testing")]
 pub mod mock;
@@ -80,6 +89,21 @@ pub type BridgedBlockHasher<T, I> = HasherOf<<T as Config<I>>::BridgedChain>;
 /// Header of the bridged chain.
 pub type BridgedHeader<T, I> = HeaderOf<<T as Config<I>>::BridgedChain>;
 
+pub type BalanceOf<T, I> =
+    <<T as Config<I>>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+pub type HeaderSyncSessionOf<T, I> = HeaderSyncSession<
+    <T as frame_system::Config>::AccountId,
+    BridgedBlockHash<T, I>,
+    BalanceOf<T, I>,
+    BlockNumberFor<T>,
+>;
+
+/// Least encoded size of a bridged header, with an empty digest, and of a signed precommit,
+/// bounding how many of them the encoded headers of a given size can hold
+const MIN_ENCODED_HEADER_SIZE: u32 = 98;
+const MIN_ENCODED_PRECOMMIT_SIZE: u32 = 132;
+
 #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
 pub enum VMSource {
     EVM([u8; 20]),
@@ -99,20 +123,18 @@ pub fn to_local_block_number<T: Config<I>, I: 'static>(
 }
 
 use crate::types::{
-    GrandpaHeaderData, ParachainInclusionProof, ParachainRegistrationData,
+    GrandpaHeaderData, HeaderSyncSession, ParachainInclusionProof, ParachainRegistrationData,
     RelaychainInclusionProof, RelaychainRegistrationData,
 };
 use frame_system::pallet_prelude::*;
 
 use t3rn_primitives::ExecutionSource;
 
-use t3rn_primitives::light_client::InclusionReceipt;
+use t3rn_primitives::light_client::{InclusionReceipt, ProvableRange};
 
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
-    use num_traits::One;
-    use sp_runtime::traits::Saturating;
     use t3rn_primitives::{light_client::LightClient, GatewayVendor};
 
     #[pallet::config]
@@ -141,8 +163,34 @@ pub mod pallet {
 
         type LightClientAsyncAPI: LightClientAsyncAPI<Self>;
 
+        /// Rate limits the headers submitted straight to the pallet, as the portal does its own
+        type HeaderSubmissionHooks: HeaderSubmissionHooks;
+
+        /// Invalidates what depended on the headers the pallet dropped, reported on the next
+        /// submission
+        type OnHeadersRolledBack: OnHeadersRolledBack<BlockNumberFor<Self>>;
+
         type MyVendor: Get<GatewayVendor>;
 
+        /// Currency the deposits of header sync sessions are reserved in
+        type Currency: ReservableCurrency<Self::AccountId>;
+
+        /// Reserved from the relayer opening a header sync session
+        #[pallet::constant]
+        type HeaderSyncSessionDeposit: Get<BalanceOf<Self, I>>;
+
+        /// Reserved from the relayer for each chunk submitted into a header sync session
+        #[pallet::constant]
+        type HeaderSyncChunkDeposit: Get<BalanceOf<Self, I>>;
+
+        /// Maximal number of header sync sessions a relayer may have open at once
+        #[pallet::constant]
+        type MaxHeaderSyncSessionsPerRelayer: Get<u32>;
+
+        /// Number of blocks after opening that a header sync session expires and is pruned
+        #[pallet::constant]
+        type HeaderSyncSessionTtl: Get<BlockNumberFor<Self>>;
+
         type RuntimeEvent: From<Event<Self, I>>
             + IsType<<Self as frame_system::Config>::RuntimeEvent>;
     }
@@ -151,13 +199,27 @@ pub mod pallet {
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config<I>, I: 'static = ()> {
         HeadersAdded(BridgedBlockNumber<T, I>),
+        /// A relayer opened a header sync session [session_id, relayer]
+        HeaderSyncOpened(u32, T::AccountId),
+        /// A chunk of headers was accepted into a header sync session [session_id, headers_cnt]
+        HeaderSyncChunkAccepted(u32, u32),
+        /// All headers of the session were imported [session_id, signed_number]
+        HeaderSyncFinalized(u32, BridgedBlockNumber<T, I>),
+        HeaderSyncAborted(u32),
+        /// An expired header sync session was pruned, slashing its deposit [session_id, relayer]
+        HeaderSyncPruned(u32, T::AccountId),
     }
     #[pallet::pallet]
     #[pallet::without_storage_info]
     pub struct Pallet<T, I = ()>(pub PhantomData<(T, I)>);
 
     #[pallet::hooks]
-    impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {}
+    impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
+        // Prune the header sync sessions expired, oldest first, within the weight left
+        fn on_idle(n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            Self::prune_expired_header_syncs(n, remaining_weight)
+        }
+    }
 
     #[pallet::error]
     pub enum Error<T, I = ()> {
@@ -211,6 +273,18 @@ pub mod pallet {
         InvalidPayloadSource,
         /// The payload source format is invalid
         InvalidSourceFormat,
+        /// No header sync session was found by the given id
+        HeaderSyncSessionNotFound,
+        /// The header sync session was opened by another relayer
+        NotHeaderSyncRelayer,
+        /// Headers were imported since the header sync session was opened, so it no longer links
+        HeaderSyncSessionStale,
+        /// The headers count given doesn't match the headers accepted into the session
+        HeaderSyncCountMismatch,
+        /// The relayer has the maximal number of header sync sessions open already
+        TooManyHeaderSyncSessions,
+        /// The header sync session is past its time to live and awaits pruning
+        HeaderSyncSessionExpired,
     }
 
     /// Hash of the header used to bootstrap the pallet.
@@ -253,6 +327,13 @@ pub mod pallet {
     pub(super) type RelayChainId<T: Config<I>, I: 'static = ()> =
         StorageValue<_, ChainId, OptionQuery>;
 
+    /// Lowest height of the headers dropped since the portal took it last. GRANDPA finality is
+    /// absolute, so the headers imported are only ever dropped by resetting the pallet, e.g. once
+    /// the bridged chain relaunched, which rolls back all of them.
+    #[pallet::storage]
+    pub(super) type RolledBackHeight<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
     /// The current GRANDPA Authority set.
     #[pallet::storage]
     pub(super) type CurrentAuthoritySet<T: Config<I>, I: 'static = ()> =
@@ -273,6 +354,32 @@ pub mod pallet {
     pub(super) type PalletOwner<T: Config<I>, I: 'static = ()> =
         StorageValue<_, T::AccountId, OptionQuery>;
 
+    #[pallet::storage]
+    pub(super) type NextHeaderSyncSessionId<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, u32, ValueQuery>;
+
+    /// Id of the oldest header sync session which may not have been pruned yet. Sessions expire in
+    /// the order of their ids, so the ones below it were all finalized, aborted or pruned.
+    #[pallet::storage]
+    pub(super) type OldestHeaderSyncSessionId<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, u32, ValueQuery>;
+
+    /// Header sync sessions open, by their ids.
+    #[pallet::storage]
+    #[pallet::getter(fn get_header_sync_session)]
+    pub(super) type HeaderSyncSessions<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Identity, u32, HeaderSyncSessionOf<T, I>, OptionQuery>;
+
+    /// Number of header sync sessions each relayer has open.
+    #[pallet::storage]
+    pub(super) type HeaderSyncSessionsOf<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+    /// Headers accepted into a header sync session, by the session id and their position in it.
+    #[pallet::storage]
+    pub(super) type HeaderSyncChunks<T: Config<I>, I: 'static = ()> =
+        StorageDoubleMap<_, Identity, u32, Identity, u32, BridgedHeader<T, I>, OptionQuery>;
+
     /// If true, all pallet transactions are failed immediately.
     #[pallet::storage]
     #[pallet::getter(fn is_halted)]
@@ -295,52 +402,231 @@ pub mod pallet {
         /// If successful in verification, it will write the target range to the underlying storage
         /// pallet.
         ///
-        /// If the new range was accepted, pays no fee.
-        #[pallet::weight(Weight::from_parts(10_000, 0u64) + T::DbWeight::get().writes(1))]
+        /// If the new range was accepted, pays no fee. Either way, only the precommit signatures
+        /// actually verified are weighed, refunding those of unknown authorities and repeated votes.
+        #[pallet::weight(T::WeightInfo::submit_headers(
+            range.len() as u32,
+            justification.commit.precommits.len() as u32,
+        ))]
         pub fn submit_headers(
             origin: OriginFor<T>,
-            // seq vector of headers to be added.
-            range: Vec<BridgedHeader<T, I>>,
+            // seq vector of headers to be added, no longer than the ring buffer storing them.
+            range: BoundedVec<BridgedHeader<T, I>, T::HeadersToStore>,
             // The header with the highest height, signed in the justification
             signed_header: BridgedHeader<T, I>,
             // GrandpaJustification for the signed_header
             justification: GrandpaJustification<BridgedHeader<T, I>>,
         ) -> DispatchResultWithPostInfo {
             let _ = ensure_signed(origin)?;
+            let gateway_id = Self::gateway_id()?;
+            T::HeaderSubmissionHooks::ensure_headers_size(
+                gateway_id,
+                range
+                    .encoded_size()
+                    .saturating_add(signed_header.encoded_size())
+                    .saturating_add(justification.encoded_size()),
+            )?;
+            T::HeaderSubmissionHooks::take_header_submission(gateway_id)?;
 
             let pointer_prior = <ImportedHashesPointer<T, I>>::get().unwrap_or_default();
-            Pallet::<T, I>::verify_and_store_headers(range, signed_header, justification)?;
+            let headers_cnt = range.len() as u32;
+            let verified_signatures = Pallet::<T, I>::verify_and_store_headers(
+                range.into_inner(),
+                signed_header,
+                justification,
+            )?;
+            let actual_weight = T::WeightInfo::submit_headers(headers_cnt, verified_signatures)
+                .saturating_add(Self::report_rolled_back_headers(gateway_id));
             let pointer_post = <ImportedHashesPointer<T, I>>::get().unwrap_or_default();
             if pointer_prior != pointer_post {
-                let counter = <SubmissionsCounter<T, I>>::get();
-
-                match Pallet::<T, I>(PhantomData).get_latest_heartbeat() {
-                    Ok(heartbeat) => {
-                        let verifier = T::MyVendor::get();
-                        T::LightClientAsyncAPI::on_new_epoch(verifier, counter, heartbeat);
-                    },
-                    Err(e) => {
-                        log::error!(
-                            "Failed to get latest heartbeat after submit_headers: {:?}",
-                            e
-                        );
-                    },
-                }
-
-                <SubmissionsCounter<T, I>>::put(
-                    counter
-                        .saturating_add(frame_system::pallet_prelude::BlockNumberFor::<T>::one()),
+                Pallet::<T, I>::note_submission();
+                Ok((Some(actual_weight), Pays::No).into())
+            } else {
+                Ok((Some(actual_weight), Pays::Yes).into())
+            }
+        }
+
+        /// Open a session syncing a header range too long to submit in a single block, following
+        /// the best finalized header. The range is then submitted in chunks with
+        /// `submit_header_chunk` and imported at once with `finalize_header_sync`. Reserves
+        /// `HeaderSyncSessionDeposit` from the relayer, who may have up to
+        /// `MaxHeaderSyncSessionsPerRelayer` sessions open at once.
+        #[pallet::weight(T::WeightInfo::open_header_sync())]
+        pub fn open_header_sync(origin: OriginFor<T>) -> DispatchResult {
+            let relayer = ensure_signed(origin)?;
+            ensure_operational_single::<T, I>()?;
+            let started_from =
+                <BestFinalizedHash<T, I>>::get().ok_or(Error::<T, I>::NoFinalizedHeader)?;
+            <HeaderSyncSessionsOf<T, I>>::try_mutate(&relayer, |sessions_cnt| {
+                ensure!(
+                    *sessions_cnt < T::MaxHeaderSyncSessionsPerRelayer::get(),
+                    Error::<T, I>::TooManyHeaderSyncSessions
                 );
+                *sessions_cnt += 1;
+                Ok::<(), Error<T, I>>(())
+            })?;
+            let deposit = T::HeaderSyncSessionDeposit::get();
+            T::Currency::reserve(&relayer, deposit)?;
+
+            let session_id = <NextHeaderSyncSessionId<T, I>>::mutate(|next_id| {
+                let session_id = *next_id;
+                *next_id = next_id.wrapping_add(1);
+                session_id
+            });
+            <HeaderSyncSessions<T, I>>::insert(
+                session_id,
+                HeaderSyncSession {
+                    relayer: relayer.clone(),
+                    deposit,
+                    opened_at: <frame_system::Pallet<T>>::block_number(),
+                    started_from,
+                    cursor: started_from,
+                    headers_cnt: 0,
+                },
+            );
 
-                Ok(Pays::No.into())
-            } else {
-                Ok(Pays::Yes.into())
+            Self::deposit_event(Event::HeaderSyncOpened(session_id, relayer));
+            Ok(())
+        }
+
+        /// Add the next chunk of headers to a header sync session, each linked to the one before
+        /// it and the first to the last header accepted into the session so far. Reserves
+        /// `HeaderSyncChunkDeposit` from the relayer on top of the session deposit.
+        #[pallet::weight(T::WeightInfo::submit_header_chunk(chunk.len() as u32))]
+        pub fn submit_header_chunk(
+            origin: OriginFor<T>,
+            session_id: u32,
+            chunk: BoundedVec<BridgedHeader<T, I>, T::HeadersToStore>,
+        ) -> DispatchResult {
+            let relayer = ensure_signed(origin)?;
+            ensure_operational_single::<T, I>()?;
+            ensure!(!chunk.is_empty(), Error::<T, I>::EmptyRangeSubmitted);
+            let gateway_id = Self::gateway_id()?;
+            T::HeaderSubmissionHooks::ensure_headers_size(gateway_id, chunk.encoded_size())?;
+            T::HeaderSubmissionHooks::take_header_submission(gateway_id)?;
+            let mut session = Self::ensure_live_header_sync(session_id, &relayer)?;
+            // Leave room in the ring buffer for the signed header finalizing the session
+            ensure!(
+                session.headers_cnt.saturating_add(chunk.len() as u32) < T::HeadersToStore::get(),
+                Error::<T, I>::RangeToLarge
+            );
+            let chunk_deposit = T::HeaderSyncChunkDeposit::get();
+            T::Currency::reserve(&relayer, chunk_deposit)?;
+            session.deposit = session.deposit.saturating_add(chunk_deposit);
+
+            for header in chunk {
+                ensure!(
+                    *header.parent_hash() == session.cursor,
+                    Error::<T, I>::InvalidRangeLinkage
+                );
+                session.cursor = header.hash();
+                <HeaderSyncChunks<T, I>>::insert(session_id, session.headers_cnt, header);
+                session.headers_cnt += 1;
             }
+            let headers_cnt = session.headers_cnt;
+            <HeaderSyncSessions<T, I>>::insert(session_id, session);
+
+            Self::deposit_event(Event::HeaderSyncChunkAccepted(session_id, headers_cnt));
+            Ok(())
+        }
+
+        /// Import all headers of a header sync session at once, followed by the signed header
+        /// justifying them. `headers_cnt` witnesses the headers accepted into the session, which
+        /// the call is weighed by.
+        #[pallet::weight(T::WeightInfo::finalize_header_sync(
+            *headers_cnt,
+            justification.commit.precommits.len() as u32,
+        ))]
+        pub fn finalize_header_sync(
+            origin: OriginFor<T>,
+            session_id: u32,
+            headers_cnt: u32,
+            signed_header: BridgedHeader<T, I>,
+            justification: GrandpaJustification<BridgedHeader<T, I>>,
+        ) -> DispatchResultWithPostInfo {
+            let relayer = ensure_signed(origin)?;
+            ensure_operational_single::<T, I>()?;
+            let gateway_id = Self::gateway_id()?;
+            T::HeaderSubmissionHooks::ensure_headers_size(
+                gateway_id,
+                signed_header
+                    .encoded_size()
+                    .saturating_add(justification.encoded_size()),
+            )?;
+            T::HeaderSubmissionHooks::take_header_submission(gateway_id)?;
+            let session = Self::ensure_live_header_sync(session_id, &relayer)?;
+            ensure!(
+                session.headers_cnt == headers_cnt,
+                Error::<T, I>::HeaderSyncCountMismatch
+            );
+
+            let range = (0..headers_cnt)
+                .map(|index| {
+                    <HeaderSyncChunks<T, I>>::take(session_id, index)
+                        .ok_or(Error::<T, I>::HeaderSyncCountMismatch)
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            Self::close_header_sync(session_id, &session);
+
+            // Re-checks the linkage of the whole range, which either imports at once or not at all
+            let signed_number = *signed_header.number();
+            let verified_signatures =
+                Pallet::<T, I>::verify_and_store_headers(range, signed_header, justification)?;
+            Pallet::<T, I>::note_submission();
+
+            Self::deposit_event(Event::HeaderSyncFinalized(session_id, signed_number));
+            Ok((
+                Some(
+                    T::WeightInfo::finalize_header_sync(headers_cnt, verified_signatures)
+                        .saturating_add(Self::report_rolled_back_headers(gateway_id)),
+                ),
+                Pays::No,
+            )
+                .into())
+        }
+
+        /// Drop a header sync session along with the headers accepted into it, by its relayer or
+        /// root, i.e. once it's gone stale, returning its deposit. `headers_cnt` witnesses the
+        /// headers of the session.
+        #[pallet::weight(T::WeightInfo::abort_header_sync(*headers_cnt))]
+        pub fn abort_header_sync(
+            origin: OriginFor<T>,
+            session_id: u32,
+            headers_cnt: u32,
+        ) -> DispatchResult {
+            let maybe_relayer = match ensure_signed(origin.clone()) {
+                Ok(relayer) => Some(relayer),
+                Err(_) => {
+                    ensure_root(origin)?;
+                    None
+                },
+            };
+            let session = <HeaderSyncSessions<T, I>>::get(session_id)
+                .ok_or(Error::<T, I>::HeaderSyncSessionNotFound)?;
+            if let Some(relayer) = maybe_relayer {
+                ensure!(
+                    session.relayer == relayer,
+                    Error::<T, I>::NotHeaderSyncRelayer
+                );
+            }
+            ensure!(
+                session.headers_cnt == headers_cnt,
+                Error::<T, I>::HeaderSyncCountMismatch
+            );
+
+            let _ = <HeaderSyncChunks<T, I>>::clear_prefix(session_id, headers_cnt, None);
+            Self::close_header_sync(session_id, &session);
+
+            Self::deposit_event(Event::HeaderSyncAborted(session_id));
+            Ok(())
         }
 
         #[pallet::weight(100_000)]
         pub fn reset(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
             ensure_root(origin)?;
+            if <EverInitialized<T, I>>::get() {
+                <RolledBackHeight<T, I>>::put(BlockNumberFor::<T>::zero());
+            }
             <EverInitialized<T, I>>::kill();
             <BestFinalizedHash<T, I>>::kill();
             for _ in <ParachainIdMap<T, I>>::drain() {}
@@ -354,6 +640,30 @@ pub mod pallet {
             <PalletOwner<T, I>>::kill();
             Ok(().into())
         }
+
+        /// Add a header range submitted as encoded `GrandpaHeaderData`, compressed or not, checked
+        /// as by `submit_headers` once decompressed. Weighed upfront by the most headers and
+        /// precommits the payload can hold decompressed, refunding those it didn't.
+        #[pallet::weight(Pallet::<T, I>::submit_headers_weight_of_size(
+            compression::max_decompressed_len(payload) as u32,
+        ))]
+        pub fn submit_compressed_headers(
+            origin: OriginFor<T>,
+            payload: Vec<u8>,
+        ) -> DispatchResultWithPostInfo {
+            let _ = ensure_signed(origin.clone())?;
+            let encoded_header_data =
+                T::HeaderSubmissionHooks::decompress_headers(Self::gateway_id()?, payload)?;
+            let data: GrandpaHeaderData<BridgedHeader<T, I>> =
+                Decode::decode(&mut &*encoded_header_data)
+                    .map_err(|_| Error::<T, I>::HeaderDataDecodingError)?;
+            let range = data
+                .range
+                .try_into()
+                .map_err(|_| Error::<T, I>::RangeToLarge)?;
+
+            Self::submit_headers(origin, range, data.signed_header, data.justification)
+        }
     }
 
     /// Check the given header for a GRANDPA scheduled authority set change. If a change
@@ -406,14 +716,14 @@ pub mod pallet {
     ///
     /// Will use the GRANDPA current authorities known to the pallet.
     ///
-    /// If succesful it returns the decoded GRANDPA justification so we can refund any weight which
-    /// was overcharged in the initial call.
+    /// If succesful it returns the number of precommit signatures verified, so we can refund any
+    /// weight which was overcharged in the initial call.
     pub(crate) fn verify_justification_single<T: Config<I>, I: 'static>(
         justification: &GrandpaJustification<BridgedHeader<T, I>>,
         hash: BridgedBlockHash<T, I>,
         number: BridgedBlockNumber<T, I>,
         authority_set: bp_header_chain::AuthoritySet,
-    ) -> Result<(), Error<T, I>> {
+    ) -> Result<u32, Error<T, I>> {
         use bp_header_chain::justification::verify_justification;
 
         let voter_set =
@@ -512,7 +822,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
         signed_header: BridgedHeader<T, I>,
         // GrandpaJustification for the signed_header
         justification: GrandpaJustification<BridgedHeader<T, I>>,
-    ) -> DispatchResult {
+    ) -> Result<u32, DispatchError> {
         // °°°°° Implicit Check: °°°°°
         // range.len() < T::HeadersToStore::get() - ensures that we don't mess up our ring buffer
         // Since polkadot updates its authority set every 24h, this is implicitly ensured => Justification check would fail after 1/7th of max len
@@ -534,7 +844,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
         let set_id = authority_set.set_id;
         // °°°°° Begin Check: #2 °°°°°
-        verify_justification_single::<T, I>(
+        let verified_signatures = verify_justification_single::<T, I>(
             &justification,
             signed_hash,
             *signed_number,
@@ -586,7 +896,145 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
         <ImportedHashesPointer<T, I>>::set(Some(buffer_index));
 
         Self::deposit_event(Event::HeadersAdded(*signed_number));
-        Ok(())
+        Ok(verified_signatures)
+    }
+
+    // Count the submission, notifying the verifier's new epoch to the light client API
+    fn note_submission() {
+        let counter = <SubmissionsCounter<T, I>>::get();
+
+        match Pallet::<T, I>(PhantomData).get_latest_heartbeat() {
+            Ok(heartbeat) => {
+                let verifier = T::MyVendor::get();
+                T::LightClientAsyncAPI::on_new_epoch(verifier, counter, heartbeat);
+            },
+            Err(e) => {
+                log::error!(
+                    "Failed to get latest heartbeat after submit_headers: {:?}",
+                    e
+                );
+            },
+        }
+
+        <SubmissionsCounter<T, I>>::put(
+            counter.saturating_add(frame_system::pallet_prelude::BlockNumberFor::<T>::one()),
+        );
+    }
+
+    /// Weight of submitting headers of the encoded size, by the most headers and precommits it holds
+    pub fn submit_headers_weight_of_size(encoded_len: u32) -> Weight {
+        T::WeightInfo::submit_headers(
+            (encoded_len / MIN_ENCODED_HEADER_SIZE).min(T::HeadersToStore::get()),
+            encoded_len / MIN_ENCODED_PRECOMMIT_SIZE,
+        )
+    }
+
+    // Notify the headers dropped since the last submission, i.e. by a reset, returning the weight
+    // of invalidating what depended on them
+    fn report_rolled_back_headers(gateway_id: ChainId) -> Weight {
+        Self::take_rolled_back_height().map_or(Weight::zero(), |from_height| {
+            T::OnHeadersRolledBack::on_headers_rolled_back(gateway_id, from_height)
+        })
+    }
+
+    /// Gateway id the pallet was initialized with, which header submissions are rate limited under
+    pub(crate) fn gateway_id() -> Result<ChainId, Error<T, I>> {
+        <RelayChainId<T, I>>::get().ok_or(Error::<T, I>::NoFinalizedHeader)
+    }
+
+    /// Header sync session of the relayer, as long as it still follows the best finalized header
+    /// and hasn't expired.
+    pub(crate) fn ensure_live_header_sync(
+        session_id: u32,
+        relayer: &T::AccountId,
+    ) -> Result<HeaderSyncSessionOf<T, I>, DispatchError> {
+        let session = <HeaderSyncSessions<T, I>>::get(session_id)
+            .ok_or(Error::<T, I>::HeaderSyncSessionNotFound)?;
+        ensure!(
+            session.relayer == *relayer,
+            Error::<T, I>::NotHeaderSyncRelayer
+        );
+        ensure!(
+            <BestFinalizedHash<T, I>>::get() == Some(session.started_from),
+            Error::<T, I>::HeaderSyncSessionStale
+        );
+        ensure!(
+            !Self::is_header_sync_expired(&session, <frame_system::Pallet<T>>::block_number()),
+            Error::<T, I>::HeaderSyncSessionExpired
+        );
+        Ok(session)
+    }
+
+    fn is_header_sync_expired(session: &HeaderSyncSessionOf<T, I>, now: BlockNumberFor<T>) -> bool {
+        now >= session
+            .opened_at
+            .saturating_add(T::HeaderSyncSessionTtl::get())
+    }
+
+    // Remove a finalized or aborted header sync session, returning its deposit to the relayer
+    fn close_header_sync(session_id: u32, session: &HeaderSyncSessionOf<T, I>) {
+        <HeaderSyncSessions<T, I>>::remove(session_id);
+        <HeaderSyncSessionsOf<T, I>>::mutate(&session.relayer, |sessions_cnt| {
+            *sessions_cnt = sessions_cnt.saturating_sub(1)
+        });
+        T::Currency::unreserve(&session.relayer, session.deposit);
+    }
+
+    /// Prune the header sync sessions expired as of `now` along with their headers, slashing their
+    /// deposits. Walks the sessions from the oldest one not pruned yet, stopping at the first
+    /// session still live or once the next prune would exceed `remaining_weight`.
+    pub(crate) fn prune_expired_header_syncs(
+        now: BlockNumberFor<T>,
+        remaining_weight: Weight,
+    ) -> Weight {
+        let db_weight = T::DbWeight::get();
+        // OldestHeaderSyncSessionId and NextHeaderSyncSessionId, and the write of the former
+        let mut used_weight = db_weight.reads_writes(2, 1);
+        if remaining_weight.any_lt(used_weight) {
+            return Weight::zero()
+        }
+        let oldest_id = <OldestHeaderSyncSessionId<T, I>>::get();
+        let next_id = <NextHeaderSyncSessionId<T, I>>::get();
+        let mut cursor = oldest_id;
+
+        while cursor != next_id {
+            let read_weight = used_weight.saturating_add(db_weight.reads(1));
+            if remaining_weight.any_lt(read_weight) {
+                break
+            }
+            match <HeaderSyncSessions<T, I>>::get(cursor) {
+                // Finalized or aborted already
+                None => used_weight = read_weight,
+                Some(session) => {
+                    if !Self::is_header_sync_expired(&session, now) {
+                        used_weight = read_weight;
+                        break
+                    }
+                    // Pruning a session costs as much as aborting it
+                    let prune_weight = read_weight
+                        .saturating_add(T::WeightInfo::abort_header_sync(session.headers_cnt));
+                    if remaining_weight.any_lt(prune_weight) {
+                        used_weight = read_weight;
+                        break
+                    }
+                    let _ =
+                        <HeaderSyncChunks<T, I>>::clear_prefix(cursor, session.headers_cnt, None);
+                    <HeaderSyncSessions<T, I>>::remove(cursor);
+                    <HeaderSyncSessionsOf<T, I>>::mutate(&session.relayer, |sessions_cnt| {
+                        *sessions_cnt = sessions_cnt.saturating_sub(1)
+                    });
+                    let _ = T::Currency::slash_reserved(&session.relayer, session.deposit);
+                    Self::deposit_event(Event::HeaderSyncPruned(cursor, session.relayer));
+                    used_weight = prune_weight;
+                },
+            }
+            cursor = cursor.wrapping_add(1);
+        }
+
+        if cursor != oldest_id {
+            <OldestHeaderSyncSessionId<T, I>>::put(cursor);
+        }
+        used_weight
     }
 
     // /// Get the best finalized header the pallet knows of.
@@ -826,6 +1274,51 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
         })
     }
 
+    /// Hash of the header an inclusion proof is given against, along with the hash of the payload
+    /// it proves. Parachain payloads are proven against the relaychain header holding their head.
+    pub fn event_inclusion_root_and_leaf(
+        gateway_id: ChainId,
+        encoded_inclusion_proof: &[u8],
+    ) -> Option<([u8; 32], [u8; 32])> {
+        let (root, encoded_payload) = if Some(gateway_id) == <RelayChainId<T, I>>::get() {
+            let proof: RelaychainInclusionProof<BridgedHeader<T, I>> =
+                Decode::decode(&mut &*encoded_inclusion_proof).ok()?;
+            (proof.block_hash, proof.encoded_payload)
+        } else {
+            let proof: ParachainInclusionProof<BridgedHeader<T, I>> =
+                Decode::decode(&mut &*encoded_inclusion_proof).ok()?;
+            (proof.relay_block_hash, proof.encoded_payload)
+        };
+        Some((
+            root.as_ref().try_into().ok()?,
+            sp_io::hashing::blake2_256(&encoded_payload),
+        ))
+    }
+
+    /// Lowest height of the headers the pallet dropped since, cleared once taken.
+    pub fn take_rolled_back_height() -> Option<BlockNumberFor<T>> {
+        <RolledBackHeight<T, I>>::take()
+    }
+
+    /// Heights of the oldest and the best finalized relaychain headers retained in the ring buffer,
+    /// all of which inclusion proofs can be given against. Parachain headers are proven through
+    /// the relaychain storage, without their own heights retained.
+    pub fn provable_range(gateway_id: ChainId) -> Option<ProvableRange<BlockNumberFor<T>>> {
+        if Some(gateway_id) != <RelayChainId<T, I>>::get() {
+            return None
+        }
+        let best_header = <ImportedHeaders<T, I>>::get(<BestFinalizedHash<T, I>>::get()?)?;
+        let pointer = <ImportedHashesPointer<T, I>>::get().unwrap_or_default();
+        // The pointer is at the oldest retained header once the ring buffer wrapped around, and
+        // before that none of the initial header's descendants was overwritten
+        let oldest_hash = <ImportedHashes<T, I>>::get(pointer).or_else(<InitialHash<T, I>>::get)?;
+        let oldest_header = <ImportedHeaders<T, I>>::get(oldest_hash)?;
+        Some(ProvableRange {
+            oldest: to_local_block_number::<T, I>(*oldest_header.number()).ok()?,
+            latest: to_local_block_number::<T, I>(*best_header.number()).ok()?,
+        })
+    }
+
     pub fn get_latest_finalized_header() -> Option<Vec<u8>> {
         if let Some(header_hash) = <BestFinalizedHash<T, I>>::get() {
             return Some(header_hash.encode())
@@ -947,19 +1440,23 @@ pub(crate) fn find_forced_change<H: HeaderT>(
         .convert_first(|l| l.try_to(id).and_then(filter_log))
 }
 
+/// Storage key for System_Events
+pub(crate) const SYSTEM_EVENTS_KEY: [u8; 32] = [
+    38, 170, 57, 78, 234, 86, 48, 224, 124, 72, 174, 12, 149, 88, 206, 247, 128, 212, 30, 94, 22,
+    5, 103, 101, 188, 132, 97, 133, 16, 114, 201, 215,
+];
+
 pub(crate) fn verify_event_storage_proof<T: Config<I>, I: 'static>(
     storage_proof: StorageProof,
     header: BridgedHeader<T, I>,
     encoded_payload: Vec<u8>,
 ) -> Result<Vec<u8>, DispatchError> {
-    // storage key for System_Events
-    let key: Vec<u8> = [
-        38, 170, 57, 78, 234, 86, 48, 224, 124, 72, 174, 12, 149, 88, 206, 247, 128, 212, 30, 94,
-        22, 5, 103, 101, 188, 132, 97, 133, 16, 114, 201, 215,
-    ]
-    .to_vec();
-    let verified_block_events =
-        verify_storage_proof::<T, I>(header, key, storage_proof, ProofTriePointer::Receipts)?;
+    let verified_block_events = verify_storage_proof::<T, I>(
+        header,
+        SYSTEM_EVENTS_KEY.to_vec(),
+        storage_proof,
+        ProofTriePointer::Receipts,
+    )?;
 
     // the problem here is that in substrates current design its not possible to prove the inclusion of a single event, only all events of a block
     // https://github.com/paritytech/substrate/issues/11216
@@ -1037,8 +1534,8 @@ pub mod tests {
     use super::*;
     use crate::mock::{
         produce_mock_headers_range, run_test, test_header, test_header_range,
-        test_header_with_correct_parent, AccountId, RuntimeOrigin as Origin, TestHeader,
-        TestNumber, TestRuntime,
+        test_header_with_correct_parent, AccountId, Balances, RuntimeOrigin as Origin, System,
+        TestHeader, TestNumber, TestRuntime, RELAYER_BALANCE,
     };
     use bp_runtime::ChainId;
     use bridges::{
@@ -1339,6 +1836,19 @@ pub mod tests {
         })
     }
 
+    #[test]
+    fn reset_rolls_back_all_headers_imported_once_initialized() {
+        run_test(|| {
+            let _ = Pallet::<TestRuntime>::reset(Origin::root());
+            assert_eq!(Pallet::<TestRuntime>::take_rolled_back_height(), None);
+
+            let _ = initialize_relaychain(Origin::root());
+            let _ = Pallet::<TestRuntime>::reset(Origin::root());
+            assert_eq!(Pallet::<TestRuntime>::take_rolled_back_height(), Some(0));
+            assert_eq!(Pallet::<TestRuntime>::take_rolled_back_height(), None);
+        })
+    }
+
     #[test]
     fn pallet_owner_may_change_owner() {
         run_test(|| {
@@ -1460,6 +1970,229 @@ pub mod tests {
         })
     }
 
+    #[test]
+    fn submit_headers_refunds_weight_of_precommits_skipped_unverified() {
+        run_test(|| {
+            let _ = initialize_relaychain(Origin::root());
+            let mut data = produce_mock_headers_range(1, 3);
+            // A repeated vote of the same authority is skipped before its signature is verified
+            let repeated_precommit = data.justification.commit.precommits[0].clone();
+            data.justification
+                .commit
+                .precommits
+                .push(repeated_precommit);
+            let precommits_cnt = data.justification.commit.precommits.len() as u32;
+            let headers_cnt = data.range.len() as u32;
+
+            let post_info = Pallet::<TestRuntime>::submit_headers(
+                Origin::signed(1),
+                data.range.try_into().unwrap(),
+                data.signed_header,
+                data.justification,
+            )
+            .unwrap();
+
+            assert_eq!(
+                post_info.actual_weight,
+                Some(<() as WeightInfo>::submit_headers(
+                    headers_cnt,
+                    precommits_cnt - 1
+                ))
+            );
+            assert!(
+                post_info.actual_weight.unwrap()
+                    < <() as WeightInfo>::submit_headers(headers_cnt, precommits_cnt)
+            );
+        })
+    }
+
+    #[test]
+    fn imports_header_range_synced_in_chunks_at_once() {
+        run_test(|| {
+            let _ = initialize_relaychain(Origin::root());
+            let data = produce_mock_headers_range(1, 5);
+
+            assert_ok!(Pallet::<TestRuntime>::open_header_sync(Origin::signed(1)));
+            assert_ok!(Pallet::<TestRuntime>::submit_header_chunk(
+                Origin::signed(1),
+                0,
+                data.range[..2].to_vec().try_into().unwrap(),
+            ));
+            assert_ok!(Pallet::<TestRuntime>::submit_header_chunk(
+                Origin::signed(1),
+                0,
+                data.range[2..].to_vec().try_into().unwrap(),
+            ));
+            // Nothing is imported until the session is finalized
+            assert!(!<ImportedHeaders<TestRuntime>>::contains_key(
+                data.range[0].hash()
+            ));
+            // The session deposit and one deposit per chunk
+            assert_eq!(Balances::reserved_balance(1), 12);
+            assert_eq!(
+                <HeaderSyncSessions<TestRuntime>>::get(0).map(|session| session.headers_cnt),
+                Some(4)
+            );
+
+            assert_ok!(Pallet::<TestRuntime>::finalize_header_sync(
+                Origin::signed(1),
+                0,
+                4,
+                data.signed_header.clone(),
+                data.justification,
+            ));
+
+            assert_eq!(
+                <BestFinalizedHash<TestRuntime>>::get(),
+                Some(data.signed_header.hash())
+            );
+            for header in data.range.iter() {
+                assert!(<ImportedHeaders<TestRuntime>>::contains_key(header.hash()));
+            }
+            assert_eq!(<HeaderSyncSessions<TestRuntime>>::get(0), None);
+            assert_eq!(<HeaderSyncChunks<TestRuntime>>::iter_prefix(0).count(), 0);
+            assert_eq!(Balances::reserved_balance(1), 0);
+            assert_eq!(Balances::free_balance(1), RELAYER_BALANCE);
+        })
+    }
+
+    #[test]
+    fn rejects_header_chunks_not_following_the_session_cursor() {
+        run_test(|| {
+            let _ = initialize_relaychain(Origin::root());
+            let data = produce_mock_headers_range(1, 5);
+
+            assert_ok!(Pallet::<TestRuntime>::open_header_sync(Origin::signed(1)));
+            assert_ok!(Pallet::<TestRuntime>::submit_header_chunk(
+                Origin::signed(1),
+                0,
+                data.range[..2].to_vec().try_into().unwrap(),
+            ));
+            assert_noop!(
+                Pallet::<TestRuntime>::submit_header_chunk(
+                    Origin::signed(1),
+                    0,
+                    data.range[3..].to_vec().try_into().unwrap(),
+                ),
+                Error::<TestRuntime>::InvalidRangeLinkage
+            );
+            assert_noop!(
+                Pallet::<TestRuntime>::submit_header_chunk(
+                    Origin::signed(2),
+                    0,
+                    data.range[2..].to_vec().try_into().unwrap(),
+                ),
+                Error::<TestRuntime>::NotHeaderSyncRelayer
+            );
+        })
+    }
+
+    #[test]
+    fn header_sync_session_goes_stale_once_headers_are_imported_past_it() {
+        run_test(|| {
+            let _ = initialize_relaychain(Origin::root());
+            let data = produce_mock_headers_range(1, 5);
+
+            assert_ok!(Pallet::<TestRuntime>::open_header_sync(Origin::signed(1)));
+            assert_ok!(Pallet::<TestRuntime>::submit_header_chunk(
+                Origin::signed(1),
+                0,
+                data.range[..2].to_vec().try_into().unwrap(),
+            ));
+            assert_ok!(submit_headers(1, 3));
+
+            assert_noop!(
+                Pallet::<TestRuntime>::submit_header_chunk(
+                    Origin::signed(1),
+                    0,
+                    data.range[2..].to_vec().try_into().unwrap(),
+                ),
+                Error::<TestRuntime>::HeaderSyncSessionStale
+            );
+            assert_ok!(Pallet::<TestRuntime>::abort_header_sync(
+                Origin::signed(1),
+                0,
+                2
+            ));
+            assert_eq!(<HeaderSyncSessions<TestRuntime>>::get(0), None);
+            assert_eq!(<HeaderSyncChunks<TestRuntime>>::iter_prefix(0).count(), 0);
+            assert_eq!(Balances::reserved_balance(1), 0);
+        })
+    }
+
+    #[test]
+    fn header_sync_sessions_open_at_once_are_capped_per_relayer() {
+        run_test(|| {
+            let _ = initialize_relaychain(Origin::root());
+
+            assert_ok!(Pallet::<TestRuntime>::open_header_sync(Origin::signed(1)));
+            assert_ok!(Pallet::<TestRuntime>::open_header_sync(Origin::signed(1)));
+            assert_noop!(
+                Pallet::<TestRuntime>::open_header_sync(Origin::signed(1)),
+                Error::<TestRuntime>::TooManyHeaderSyncSessions
+            );
+            // Other relayers open theirs regardless
+            assert_ok!(Pallet::<TestRuntime>::open_header_sync(Origin::signed(2)));
+
+            assert_ok!(Pallet::<TestRuntime>::abort_header_sync(
+                Origin::signed(1),
+                0,
+                0
+            ));
+            assert_eq!(Balances::reserved_balance(1), 10);
+            assert_ok!(Pallet::<TestRuntime>::open_header_sync(Origin::signed(1)));
+        })
+    }
+
+    #[test]
+    fn expired_header_sync_sessions_are_pruned_on_idle_slashing_their_deposits() {
+        run_test(|| {
+            let _ = initialize_relaychain(Origin::root());
+            let data = produce_mock_headers_range(1, 5);
+
+            System::set_block_number(1);
+            assert_ok!(Pallet::<TestRuntime>::open_header_sync(Origin::signed(1)));
+            assert_ok!(Pallet::<TestRuntime>::submit_header_chunk(
+                Origin::signed(1),
+                0,
+                data.range[..2].to_vec().try_into().unwrap(),
+            ));
+            System::set_block_number(5);
+            assert_ok!(Pallet::<TestRuntime>::open_header_sync(Origin::signed(2)));
+
+            // The first session expires HeaderSyncSessionTtl blocks after opening
+            System::set_block_number(11);
+            assert_noop!(
+                Pallet::<TestRuntime>::submit_header_chunk(
+                    Origin::signed(1),
+                    0,
+                    data.range[2..].to_vec().try_into().unwrap(),
+                ),
+                Error::<TestRuntime>::HeaderSyncSessionExpired
+            );
+
+            // Nothing is pruned without the weight to
+            assert_eq!(
+                <Pallet<TestRuntime> as Hooks<u32>>::on_idle(11, Weight::zero()),
+                Weight::zero()
+            );
+            assert!(<HeaderSyncSessions<TestRuntime>>::contains_key(0));
+
+            assert!(
+                <Pallet<TestRuntime> as Hooks<u32>>::on_idle(11, Weight::MAX) != Weight::zero()
+            );
+            assert_eq!(<HeaderSyncSessions<TestRuntime>>::get(0), None);
+            assert_eq!(<HeaderSyncChunks<TestRuntime>>::iter_prefix(0).count(), 0);
+            assert_eq!(<HeaderSyncSessionsOf<TestRuntime>>::get(1), 0);
+            assert_eq!(Balances::reserved_balance(1), 0);
+            assert_eq!(Balances::free_balance(1), RELAYER_BALANCE - 11);
+            // The second session is still live, so pruning resumes from it next time
+            assert!(<HeaderSyncSessions<TestRuntime>>::contains_key(1));
+            assert_eq!(<OldestHeaderSyncSessionId<TestRuntime>>::get(), 1);
+            assert_eq!(Balances::reserved_balance(2), 10);
+        })
+    }
+
     #[test]
     fn reject_header_range_gap() {
         run_test(|| {
@@ -11,7 +11,7 @@ use t3rn_abi::types::Bytes;
 pub use t3rn_primitives::light_client::{LightClient, LightClientHeartbeat};
 use t3rn_primitives::{
     execution_source_to_option,
-    light_client::{HeaderResult, HeightResult, InclusionReceipt},
+    light_client::{HeaderResult, HeightResult, InclusionReceipt, ProvableRange},
     ExecutionSource, GatewayVendor, SpeedMode,
 };
 
@@ -287,6 +287,40 @@ where
             PalletInstance::Phantom(_) => unreachable!("Phantom variant should not be used"),
         }
     }
+
+    fn get_provable_range(&self, gateway_id: [u8; 4]) -> Option<ProvableRange<BlockNumberFor<T>>> {
+        match self {
+            PalletInstance::Rococo(pallet) => pallet.get_provable_range(gateway_id),
+            PalletInstance::Kusama(pallet) => pallet.get_provable_range(gateway_id),
+            PalletInstance::Polkadot(pallet) => pallet.get_provable_range(gateway_id),
+            PalletInstance::Phantom(_) => unreachable!("Phantom variant should not be used"),
+        }
+    }
+
+    fn take_rolled_back_height(&self) -> Option<BlockNumberFor<T>> {
+        match self {
+            PalletInstance::Rococo(pallet) => pallet.take_rolled_back_height(),
+            PalletInstance::Kusama(pallet) => pallet.take_rolled_back_height(),
+            PalletInstance::Polkadot(pallet) => pallet.take_rolled_back_height(),
+            PalletInstance::Phantom(_) => unreachable!("Phantom variant should not be used"),
+        }
+    }
+
+    fn event_inclusion_root_and_leaf(
+        &self,
+        gateway_id: [u8; 4],
+        message: &[u8],
+    ) -> Option<([u8; 32], [u8; 32])> {
+        match self {
+            PalletInstance::Rococo(pallet) =>
+                pallet.event_inclusion_root_and_leaf(gateway_id, message),
+            PalletInstance::Kusama(pallet) =>
+                pallet.event_inclusion_root_and_leaf(gateway_id, message),
+            PalletInstance::Polkadot(pallet) =>
+                pallet.event_inclusion_root_and_leaf(gateway_id, message),
+            PalletInstance::Phantom(_) => unreachable!("Phantom variant should not be used"),
+        }
+    }
 }
 
 impl<T: Config<I>, I: 'static> LightClient<T> for Pallet<T, I> {
@@ -444,6 +478,22 @@ impl<T: Config<I>, I: 'static> LightClient<T> for Pallet<T, I> {
     ) -> Result<Bytes, DispatchError> {
         Err("GrandpaFV::verify_tx_inclusion not implemented yet".into())
     }
+
+    fn get_provable_range(&self, gateway_id: [u8; 4]) -> Option<ProvableRange<BlockNumberFor<T>>> {
+        Pallet::<T, I>::provable_range(gateway_id)
+    }
+
+    fn take_rolled_back_height(&self) -> Option<BlockNumberFor<T>> {
+        Pallet::<T, I>::take_rolled_back_height()
+    }
+
+    fn event_inclusion_root_and_leaf(
+        &self,
+        gateway_id: [u8; 4],
+        message: &[u8],
+    ) -> Option<([u8; 32], [u8; 32])> {
+        Pallet::<T, I>::event_inclusion_root_and_leaf(gateway_id, message)
+    }
 }
 
 #[cfg(all(feature = "testing"))]
@@ -670,6 +720,53 @@ pub mod grandpa_light_clients_test {
         );
     }
 
+    #[test]
+    fn given_rococo_instance_provable_range_follows_headers_retained_in_ring_buffer() {
+        stage_test_and_init_instance::<TestRuntime, RococoInstance>(
+            || {
+                let roco_light_client =
+                    grab_lc_instance_unsafe::<TestRuntime, RococoInstance>(GatewayVendor::Rococo);
+
+                assert_eq!(
+                    roco_light_client.get_provable_range([0, 0, 0, 0]),
+                    Some(ProvableRange {
+                        oldest: 0,
+                        latest: 0
+                    })
+                );
+
+                // Fills the ring buffer of 5 headers to store up, leaving the pointer at its start
+                insert_headers_range_to_roco(produce_mock_headers_range(1, 5));
+                assert_eq!(
+                    roco_light_client.get_provable_range([0, 0, 0, 0]),
+                    Some(ProvableRange {
+                        oldest: 1,
+                        latest: 5
+                    })
+                );
+
+                insert_headers_range_to_roco(produce_mock_headers_range(6, 8));
+                assert_eq!(
+                    roco_light_client.get_provable_range([0, 0, 0, 0]),
+                    Some(ProvableRange {
+                        oldest: 4,
+                        latest: 8
+                    })
+                );
+
+                // Parachains are proven through the relaychain headers, without heights of their own
+                assert_eq!(roco_light_client.get_provable_range([1, 1, 1, 1]), None);
+
+                assert_err!(
+                    roco_light_client.verify_event_inclusion_at([0, 0, 0, 0], 3, None, vec![]),
+                    DispatchError::Other("LightClient::HeightNotProvable")
+                );
+            },
+            GatewayVendor::Rococo,
+            [0, 0, 0, 0],
+        );
+    }
+
     #[test]
     fn initialize_works_for_default_rococo_instance_between_direct_access() {
         run_test(|| {
@@ -17,13 +17,17 @@
 // From construct_runtime macro
 #![allow(clippy::from_over_into)]
 
-use frame_support::{construct_runtime, parameter_types, traits::Everything, weights::Weight};
+use frame_support::{
+    construct_runtime, parameter_types,
+    traits::{Everything, GenesisBuild},
+    weights::Weight,
+};
 use frame_system::mocking::MockUncheckedExtrinsic;
 use sp_runtime::{
     generic,
     testing::H256,
     traits::{BlakeTwo256, ConstU32, IdentityLookup},
-    Perbill,
+    BuildStorage, Perbill,
 };
 
 type Header = generic::Header<u32, BlakeTwo256>;
@@ -117,6 +121,10 @@ impl pallet_sudo::Config for TestRuntime {
 
 parameter_types! {
     pub const HeadersToStore: u32 = 5;
+    pub const HeaderSyncSessionDeposit: u64 = 10;
+    pub const HeaderSyncChunkDeposit: u64 = 1;
+    pub const HeaderSyncSessionTtl: u32 = 10;
+    pub const MaxHeaderSyncSessionsPerRelayer: u32 = 2;
     pub const SessionLength: u64 = 5;
     pub const NumValidators: u32 = 5;
 
@@ -150,12 +158,19 @@ impl pallet_balances::Config for TestRuntime {
 
 impl Config<RococoInstance> for TestRuntime {
     type BridgedChain = TestCircuitLikeChain;
+    type Currency = Balances;
     type EpochOffset = ConstU32<2_400u32>;
     type FastConfirmationOffset = ConstU32<0u32>;
     type FinalizedConfirmationOffset = ConstU32<0u32>;
+    type HeaderSubmissionHooks = ();
+    type HeaderSyncChunkDeposit = HeaderSyncChunkDeposit;
+    type HeaderSyncSessionDeposit = HeaderSyncSessionDeposit;
+    type HeaderSyncSessionTtl = HeaderSyncSessionTtl;
     type HeadersToStore = HeadersToStore;
     type LightClientAsyncAPI = LightClientAsyncAPIEmptyMock<TestRuntime>;
+    type MaxHeaderSyncSessionsPerRelayer = MaxHeaderSyncSessionsPerRelayer;
     type MyVendor = RococoVendor;
+    type OnHeadersRolledBack = ();
     type RationalConfirmationOffset = ConstU32<0u32>;
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = ();
@@ -163,12 +178,19 @@ impl Config<RococoInstance> for TestRuntime {
 
 impl Config<KusamaInstance> for TestRuntime {
     type BridgedChain = TestCircuitLikeChain;
+    type Currency = Balances;
     type EpochOffset = ConstU32<2_400u32>;
     type FastConfirmationOffset = ConstU32<0u32>;
     type FinalizedConfirmationOffset = ConstU32<0u32>;
+    type HeaderSubmissionHooks = ();
+    type HeaderSyncChunkDeposit = HeaderSyncChunkDeposit;
+    type HeaderSyncSessionDeposit = HeaderSyncSessionDeposit;
+    type HeaderSyncSessionTtl = HeaderSyncSessionTtl;
     type HeadersToStore = HeadersToStore;
     type LightClientAsyncAPI = LightClientAsyncAPIEmptyMock<TestRuntime>;
+    type MaxHeaderSyncSessionsPerRelayer = MaxHeaderSyncSessionsPerRelayer;
     type MyVendor = KusamaVendor;
+    type OnHeadersRolledBack = ();
     type RationalConfirmationOffset = ConstU32<0u32>;
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = ();
@@ -176,12 +198,19 @@ impl Config<KusamaInstance> for TestRuntime {
 
 impl Config<PolkadotInstance> for TestRuntime {
     type BridgedChain = TestCircuitLikeChain;
+    type Currency = Balances;
     type EpochOffset = ConstU32<2_400u32>;
     type FastConfirmationOffset = ConstU32<0u32>;
     type FinalizedConfirmationOffset = ConstU32<0u32>;
+    type HeaderSubmissionHooks = ();
+    type HeaderSyncChunkDeposit = HeaderSyncChunkDeposit;
+    type HeaderSyncSessionDeposit = HeaderSyncSessionDeposit;
+    type HeaderSyncSessionTtl = HeaderSyncSessionTtl;
     type HeadersToStore = HeadersToStore;
     type LightClientAsyncAPI = LightClientAsyncAPIEmptyMock<TestRuntime>;
+    type MaxHeaderSyncSessionsPerRelayer = MaxHeaderSyncSessionsPerRelayer;
     type MyVendor = KusamaVendor;
+    type OnHeadersRolledBack = ();
     type RationalConfirmationOffset = ConstU32<0u32>;
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = ();
@@ -197,8 +226,29 @@ impl Chain for TestCircuitLikeChain {
     type Header = Header;
 }
 
+/// Balance of the relayers 1 and 2, which the deposits of header sync sessions reserve from
+pub const RELAYER_BALANCE: u64 = 100;
+
 pub fn run_test<T>(test: impl FnOnce() -> T) -> T {
-    sp_io::TestExternalities::new(Default::default()).execute_with(test)
+    let mut storage = frame_system::GenesisConfig::<TestRuntime>::default()
+        .build_storage()
+        .expect("Frame system builds valid default genesis config");
+    pallet_balances::GenesisConfig::<TestRuntime> {
+        balances: vec![(1, RELAYER_BALANCE), (2, RELAYER_BALANCE)],
+    }
+    .assimilate_storage(&mut storage)
+    .expect("Pallet balances builds valid genesis config of the relayers");
+    sp_io::TestExternalities::new(storage).execute_with(test)
+}
+
+/// Externalities of the benchmark tests, with a keystore to generate and sign with authority keys
+#[cfg(feature = "runtime-benchmarks")]
+pub fn new_benchmark_ext() -> sp_io::TestExternalities {
+    let mut ext = sp_io::TestExternalities::new(Default::default());
+    ext.register_extension(sp_keystore::KeystoreExt::new(
+        sp_keystore::testing::MemoryKeystore::new(),
+    ));
+    ext
 }
 
 #[cfg(all(feature = "testing"))]
@@ -31,6 +31,23 @@ pub struct GrandpaHeaderData<Header: sp_runtime::traits::Header> {
     pub justification: GrandpaJustification<Header>,
 }
 
+/// Sync of a header range too long for a single block, accepted in chunks linked one to another
+/// and imported at once when finalized with the justification of the header following them.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+pub struct HeaderSyncSession<AccountId, Hash, Balance, BlockNumber> {
+    pub relayer: AccountId,
+    /// Reserved from the relayer for the session and each chunk accepted, returned once the
+    /// session is finalized or aborted and slashed once it's pruned expired
+    pub deposit: Balance,
+    /// Block the session opened at, which it expires `HeaderSyncSessionTtl` blocks after
+    pub opened_at: BlockNumber,
+    /// Best finalized hash as of opening the session, which goes stale once another import moved it
+    pub started_from: Hash,
+    /// Hash of the last header accepted, which the next chunk or the signed header must follow
+    pub cursor: Hash,
+    pub headers_cnt: u32,
+}
+
 #[derive(Clone, Encode, Decode, Eq, PartialEq, Debug)]
 pub struct RelaychainInclusionProof<Header: sp_runtime::traits::Header> {
     /// this is the item we're proving to be included in a specfic block (e.g. event, storage entry, etc)
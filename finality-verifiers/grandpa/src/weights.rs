@@ -62,6 +62,13 @@ pub trait WeightInfo {
     fn submit_finality_proof_on_many_forks(p: u32) -> Weight;
     fn find_scheduled_change(n: u32) -> Weight;
     fn read_write_authority_sets(n: u32) -> Weight;
+    fn initialize(a: u32) -> Weight;
+    fn submit_headers(h: u32, a: u32) -> Weight;
+    fn confirm_event_inclusion(p: u32) -> Weight;
+    fn open_header_sync() -> Weight;
+    fn submit_header_chunk(h: u32) -> Weight;
+    fn finalize_header_sync(h: u32, a: u32) -> Weight;
+    fn abort_header_sync(h: u32) -> Weight;
 }
 
 /// Weights for pallet_bridge_grandpa using the Rialto node and recommended hardware.
@@ -106,6 +113,78 @@ impl<T: frame_system::Config> WeightInfo for GatewayWeight<T> {
             .saturating_add(T::DbWeight::get().reads(1))
             .saturating_add(T::DbWeight::get().writes(1))
     }
+
+    // Estimated ahead of a run on reference hardware, the numbers below are to be replaced by the
+    // output of `benchmark pallet --pallet pallet_grandpa_finality_verifier --extrinsic *`.
+    //
+    // Storage: RelayChainId (r:1 w:1), BestFinalizedHash (r:1 w:1), InitialHash (r:0 w:1),
+    // ImportedHeaders (r:0 w:1), ImportedHashesPointer (r:0 w:1), CurrentAuthoritySet (r:0 w:1),
+    // IsHalted (r:0 w:1), EverInitialized (r:0 w:1), PalletOwner (r:0 w:1)
+    fn initialize(a: u32) -> Weight {
+        Weight::from_parts(38_410_000, 0u64)
+            .saturating_add(Weight::from_parts(41_000, 0u64).saturating_mul(a as u64))
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(9))
+    }
+
+    // Storage: BestFinalizedHash (r:2 w:1), CurrentAuthoritySet (r:1 w:0), IsHalted (r:2 w:0),
+    // ImportedHashesPointer (r:2 w:1), SubmissionsCounter (r:1 w:1), EverInitialized (r:1 w:0),
+    // ImportedHashes (r:1 w:1 per header), ImportedHeaders (r:1 w:2 per header)
+    fn submit_headers(h: u32, a: u32) -> Weight {
+        Weight::from_parts(71_950_000, 0u64)
+            .saturating_add(Weight::from_parts(14_220_000, 0u64).saturating_mul(h as u64))
+            .saturating_add(Weight::from_parts(52_640_000, 0u64).saturating_mul(a as u64))
+            .saturating_add(T::DbWeight::get().reads(10))
+            .saturating_add(T::DbWeight::get().reads(1).saturating_mul(h as u64))
+            .saturating_add(T::DbWeight::get().writes(6))
+            .saturating_add(T::DbWeight::get().writes(3).saturating_mul(h as u64))
+    }
+
+    // Storage: RelayChainId (r:1 w:0), ImportedHeaders (r:1 w:0)
+    fn confirm_event_inclusion(p: u32) -> Weight {
+        Weight::from_parts(31_760_000, 0u64)
+            .saturating_add(Weight::from_parts(5_120, 0u64).saturating_mul(p as u64))
+            .saturating_add(T::DbWeight::get().reads(2))
+    }
+
+    // Storage: IsHalted (r:1 w:0), BestFinalizedHash (r:1 w:0), HeaderSyncSessionsOf (r:1 w:1),
+    // System Account (r:1 w:1), NextHeaderSyncSessionId (r:1 w:1), HeaderSyncSessions (r:0 w:1)
+    fn open_header_sync() -> Weight {
+        Weight::from_parts(36_700_000, 0u64)
+            .saturating_add(T::DbWeight::get().reads(5))
+            .saturating_add(T::DbWeight::get().writes(4))
+    }
+
+    // Storage: IsHalted (r:1 w:0), BestFinalizedHash (r:1 w:0), HeaderSyncSessions (r:1 w:1),
+    // System Account (r:1 w:1), HeaderSyncChunks (r:0 w:1 per header)
+    fn submit_header_chunk(h: u32) -> Weight {
+        Weight::from_parts(37_400_000, 0u64)
+            .saturating_add(Weight::from_parts(9_870_000, 0u64).saturating_mul(h as u64))
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().writes(2))
+            .saturating_add(T::DbWeight::get().writes(1).saturating_mul(h as u64))
+    }
+
+    // Storage: HeaderSyncSessions (r:1 w:1), HeaderSyncSessionsOf (r:1 w:1), System Account (r:1 w:1),
+    // HeaderSyncChunks (r:1 w:1 per header), then all of submit_headers for the headers of the
+    // session
+    fn finalize_header_sync(h: u32, a: u32) -> Weight {
+        Self::submit_headers(h, a)
+            .saturating_add(Weight::from_parts(12_100_000, 0u64))
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().reads(1).saturating_mul(h as u64))
+            .saturating_add(T::DbWeight::get().writes(3))
+            .saturating_add(T::DbWeight::get().writes(1).saturating_mul(h as u64))
+    }
+
+    // Storage: HeaderSyncSessions (r:1 w:1), HeaderSyncSessionsOf (r:1 w:1), System Account (r:1 w:1),
+    // HeaderSyncChunks (r:0 w:1 per header)
+    fn abort_header_sync(h: u32) -> Weight {
+        Weight::from_parts(31_000_000, 0u64)
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(3))
+            .saturating_add(T::DbWeight::get().writes(1).saturating_mul(h as u64))
+    }
 }
 
 // For backwards compatibility and tests
@@ -149,4 +228,73 @@ impl WeightInfo for () {
             .saturating_add(RocksDbWeight::get().reads(1))
             .saturating_add(RocksDbWeight::get().writes(1))
     }
+
+    // Storage: RelayChainId (r:1 w:1), BestFinalizedHash (r:1 w:1), InitialHash (r:0 w:1),
+    // ImportedHeaders (r:0 w:1), ImportedHashesPointer (r:0 w:1), CurrentAuthoritySet (r:0 w:1),
+    // IsHalted (r:0 w:1), EverInitialized (r:0 w:1), PalletOwner (r:0 w:1)
+    fn initialize(a: u32) -> Weight {
+        Weight::from_parts(38_410_000, 0u64)
+            .saturating_add(Weight::from_parts(41_000, 0u64).saturating_mul(a as u64))
+            .saturating_add(RocksDbWeight::get().reads(2))
+            .saturating_add(RocksDbWeight::get().writes(9))
+    }
+
+    // Storage: BestFinalizedHash (r:2 w:1), CurrentAuthoritySet (r:1 w:0), IsHalted (r:2 w:0),
+    // ImportedHashesPointer (r:2 w:1), SubmissionsCounter (r:1 w:1), EverInitialized (r:1 w:0),
+    // ImportedHashes (r:1 w:1 per header), ImportedHeaders (r:1 w:2 per header)
+    fn submit_headers(h: u32, a: u32) -> Weight {
+        Weight::from_parts(71_950_000, 0u64)
+            .saturating_add(Weight::from_parts(14_220_000, 0u64).saturating_mul(h as u64))
+            .saturating_add(Weight::from_parts(52_640_000, 0u64).saturating_mul(a as u64))
+            .saturating_add(RocksDbWeight::get().reads(10))
+            .saturating_add(RocksDbWeight::get().reads(1).saturating_mul(h as u64))
+            .saturating_add(RocksDbWeight::get().writes(6))
+            .saturating_add(RocksDbWeight::get().writes(3).saturating_mul(h as u64))
+    }
+
+    // Storage: RelayChainId (r:1 w:0), ImportedHeaders (r:1 w:0)
+    fn confirm_event_inclusion(p: u32) -> Weight {
+        Weight::from_parts(31_760_000, 0u64)
+            .saturating_add(Weight::from_parts(5_120, 0u64).saturating_mul(p as u64))
+            .saturating_add(RocksDbWeight::get().reads(2))
+    }
+
+    // Storage: IsHalted (r:1 w:0), BestFinalizedHash (r:1 w:0), HeaderSyncSessionsOf (r:1 w:1),
+    // System Account (r:1 w:1), NextHeaderSyncSessionId (r:1 w:1), HeaderSyncSessions (r:0 w:1)
+    fn open_header_sync() -> Weight {
+        Weight::from_parts(36_700_000, 0u64)
+            .saturating_add(RocksDbWeight::get().reads(5))
+            .saturating_add(RocksDbWeight::get().writes(4))
+    }
+
+    // Storage: IsHalted (r:1 w:0), BestFinalizedHash (r:1 w:0), HeaderSyncSessions (r:1 w:1),
+    // System Account (r:1 w:1), HeaderSyncChunks (r:0 w:1 per header)
+    fn submit_header_chunk(h: u32) -> Weight {
+        Weight::from_parts(37_400_000, 0u64)
+            .saturating_add(Weight::from_parts(9_870_000, 0u64).saturating_mul(h as u64))
+            .saturating_add(RocksDbWeight::get().reads(4))
+            .saturating_add(RocksDbWeight::get().writes(2))
+            .saturating_add(RocksDbWeight::get().writes(1).saturating_mul(h as u64))
+    }
+
+    // Storage: HeaderSyncSessions (r:1 w:1), HeaderSyncSessionsOf (r:1 w:1), System Account (r:1 w:1),
+    // HeaderSyncChunks (r:1 w:1 per header), then all of submit_headers for the headers of the
+    // session
+    fn finalize_header_sync(h: u32, a: u32) -> Weight {
+        Self::submit_headers(h, a)
+            .saturating_add(Weight::from_parts(12_100_000, 0u64))
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().reads(1).saturating_mul(h as u64))
+            .saturating_add(RocksDbWeight::get().writes(3))
+            .saturating_add(RocksDbWeight::get().writes(1).saturating_mul(h as u64))
+    }
+
+    // Storage: HeaderSyncSessions (r:1 w:1), HeaderSyncSessionsOf (r:1 w:1), System Account (r:1 w:1),
+    // HeaderSyncChunks (r:0 w:1 per header)
+    fn abort_header_sync(h: u32) -> Weight {
+        Weight::from_parts(31_000_000, 0u64)
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().writes(3))
+            .saturating_add(RocksDbWeight::get().writes(1).saturating_mul(h as u64))
+    }
 }
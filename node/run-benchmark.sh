@@ -1,7 +1,9 @@
 #!/usr/bin/env bash
 
 # Usage: sh run-benchmark.sh pallet_name location_to_generate_weights_file
-# Example for Execution-Delivery: sh run-benchmark.sh pallet_circuit_execution_delivery ./src/execution-delivery/src/weights.rs
+# Example for XDNS: sh run-benchmark.sh pallet_xdns ../pallets/xdns/src/weights.rs
+# Pallets benchmarked by the t0rn runtime: pallet_xdns, pallet_portal, pallet_vacuum,
+# pallet_eth2_finality_verifier and pallet_grandpa_finality_verifier.
 
 set -eux
 
@@ -9,7 +11,7 @@ pallet=$1
 output=$2
 
 echo "Benchmark: ${pallet}"
-cargo +nightly run --release --features runtime-benchmarks -- benchmark \
+cargo +nightly run --release -p t0rn-parachain-collator --features runtime-benchmarks -- benchmark pallet \
   --chain=dev \
   --steps=50 \
   --repeat=100 \
@@ -22,4 +24,4 @@ cargo +nightly run --release --features runtime-benchmarks -- benchmark \
   --template=../benchmarking/frame-weight-template.hbs
 
 # Weights file may not be formatted 
-cargo fmt
\ No newline at end of file
+cargo fmt
@@ -17,7 +17,6 @@ use circuit_standalone_runtime::{
 const CANDIDACY_BOND: u128 = 0; // 10K TRN
 const DESIRED_CANDIDATES: u32 = 2;
 
-use codec::Encode;
 use sc_service::ChainType;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_consensus_grandpa::AuthorityId as GrandpaId;
@@ -294,11 +293,7 @@ fn testnet_genesis(
         transaction_payment: Default::default(),
         assets: Default::default(),
         rewards: Default::default(),
-        xdns: XDNSConfig {
-            known_gateway_records: vec![],
-            standard_sfx_abi: t3rn_abi::standard::standard_sfx_abi().encode(),
-            _marker: Default::default(),
-        },
+        xdns: XDNSConfig::default().with_standard_sfx_abi(),
         contracts_registry: Default::default(),
         account_manager: Default::default(),
         attesters: Default::default(),
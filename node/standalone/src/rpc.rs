@@ -53,10 +53,11 @@ pub fn create_full<C, P, B>(
 where
     C: ProvideRuntimeApi<Block>,
     C: HeaderBackend<Block> + HeaderMetadata<Block, Error = BlockChainError> + 'static,
+    C: sc_client_api::BlockchainEvents<Block>,
     C: Send + Sync + 'static,
     C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
     C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
-    C::Api: pallet_xdns_rpc::XdnsRuntimeApi<Block, AccountId>,
+    C::Api: pallet_xdns_rpc::XdnsRuntimeApi<Block, AccountId, Balance>,
     C::Api: pallet_portal_rpc::PortalRuntimeApi<Block, AccountId, Balance, Hash>,
     C::Api: sp_consensus_grandpa::GrandpaApi<Block>,
     C::Api: BlockBuilder<Block>,
@@ -79,7 +80,7 @@ where
     module.merge(System::new(client.clone(), pool, deny_unsafe).into_rpc())?;
     module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
     module.merge(Xdns::new(client.clone()).into_rpc())?;
-    module.merge(Portal::new(client).into_rpc())?;
+    module.merge(Portal::new(client, grandpa.subscription_executor.clone()).into_rpc())?;
 
     let GrandpaDeps {
         shared_voter_state,
@@ -111,7 +111,7 @@ where
     C: Send + Sync + 'static,
     C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
     C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
-    C::Api: pallet_xdns_rpc::XdnsRuntimeApi<Block, AccountId>,
+    C::Api: pallet_xdns_rpc::XdnsRuntimeApi<Block, AccountId, Balance>,
     C::Api: pallet_portal_rpc::PortalRuntimeApi<Block, AccountId, Balance, Hash>,
     C::Api: fp_rpc::ConvertTransactionRuntimeApi<Block>,
     C::Api: fp_rpc::EthereumRuntimeRPCApi<Block>,
@@ -148,7 +148,7 @@ where
     module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
 
     module.merge(Xdns::new(client.clone()).into_rpc())?;
-    module.merge(Portal::new(client.clone()).into_rpc())?;
+    module.merge(Portal::new(client.clone(), subscription_task_executor.clone()).into_rpc())?;
 
     // Ethereum  modules
     let no_tx_converter: Option<fp_rpc::NoTransactionConverter> = None;
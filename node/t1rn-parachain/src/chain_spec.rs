@@ -435,11 +435,7 @@ fn polkadot_genesis_full(
         three_vm: Default::default(),
         rewards: Default::default(),
         maintenance: Default::default(),
-        xdns: XDNSConfig {
-            known_gateway_records: vec![],
-            standard_sfx_abi: t3rn_abi::standard::standard_sfx_abi().encode(),
-            _marker: Default::default(),
-        },
+        xdns: XDNSConfig::default().with_standard_sfx_abi(),
     }
 }
 
@@ -29,6 +29,8 @@ pub struct FullDeps<C, P> {
     pub pool: Arc<P>,
     /// Whether to deny unsafe calls
     pub deny_unsafe: DenyUnsafe,
+    /// Executor to drive the subscriptions of the Portal RPC handler.
+    pub subscription_executor: sc_rpc::SubscriptionTaskExecutor,
 }
 
 /// Instantiate all full RPC extensions.
@@ -38,10 +40,11 @@ pub fn create_full<C, P>(
 where
     C: ProvideRuntimeApi<Block>,
     C: HeaderBackend<Block> + HeaderMetadata<Block, Error = BlockChainError> + 'static,
+    C: sc_client_api::BlockchainEvents<Block>,
     C: Send + Sync + 'static,
     C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
     C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
-    C::Api: pallet_xdns_rpc::XdnsRuntimeApi<Block, AccountId>,
+    C::Api: pallet_xdns_rpc::XdnsRuntimeApi<Block, AccountId, Balance>,
     C::Api: pallet_portal_rpc::PortalRuntimeApi<Block, AccountId, Balance, Hash>,
     C::Api: BlockBuilder<Block>,
     P: TransactionPool + 'static,
@@ -54,13 +57,14 @@ where
         client,
         pool,
         deny_unsafe,
+        subscription_executor,
     } = deps;
 
     module.merge(System::new(client.clone(), pool, deny_unsafe).into_rpc())?;
     module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
 
     module.merge(Xdns::new(client.clone()).into_rpc())?;
-    module.merge(Portal::new(client).into_rpc())?;
+    module.merge(Portal::new(client, subscription_executor).into_rpc())?;
 
     Ok(module)
 }
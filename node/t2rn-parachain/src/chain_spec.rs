@@ -227,11 +227,7 @@ fn testnet_genesis(
         transaction_payment: Default::default(),
         assets: Default::default(),
         rewards: Default::default(),
-        xdns: XDNSConfig {
-            known_gateway_records: vec![],
-            standard_sfx_abi: t3rn_abi::standard::standard_sfx_abi().encode(),
-            _marker: Default::default(),
-        },
+        xdns: XDNSConfig::default().with_standard_sfx_abi(),
         contracts_registry: Default::default(),
         account_manager: Default::default(),
         attesters: Default::default(),
@@ -0,0 +1,121 @@
+use crate::{gas, BalanceOf, Config, Event, Pallet, PendingCallbacks, XtxCallbacks, XtxGasBudgets};
+use codec::Encode;
+use frame_support::{
+    dispatch::RawOrigin,
+    storage::{with_transaction, TransactionOutcome},
+    traits::Get,
+    weights::Weight,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::{DispatchError, Saturating};
+use t3rn_primitives::{
+    circuit::{CircuitStatus, OnLocalTrigger},
+    threevm::XtxResultCallback,
+};
+use t3rn_sdk_primitives::state::XtxResult;
+
+const LOG_TARGET: &str = "3vm::callback";
+
+/// Storage reads needed to load a concluded execution: the xtx, its local state and its side
+/// effects.
+const LOAD_EXECUTION_READS: u64 = 3;
+
+/// Await the conclusion of an Xtx posted on behalf of a contract, to deliver its result back.
+pub(crate) fn await_result<T: Config>(contract: &T::AccountId, xtx_id: T::Hash) {
+    if T::XtxResultCallback::is_contract(contract) {
+        <XtxCallbacks<T>>::insert(xtx_id, contract);
+    }
+}
+
+/// Queue the result of a concluded Xtx for delivery to the contract that originated it.
+///
+/// The awaiting callback is taken, so an Xtx reported as concluded twice is only queued once. An
+/// Xtx with no result to deliver settles its gas budget right away.
+pub(crate) fn on_concluded<T: Config>(xtx_id: T::Hash, status: &CircuitStatus) {
+    if let Some(contract) = <XtxCallbacks<T>>::take(xtx_id) {
+        let succeeded = matches!(
+            status,
+            CircuitStatus::FinishedAllSteps | CircuitStatus::Committed
+        );
+        <PendingCallbacks<T>>::insert(xtx_id, (contract, succeeded));
+    } else {
+        gas::settle::<T>(xtx_id, 0);
+    }
+}
+
+/// Deliver queued results for as long as the remaining weight fits a callback exhausting its gas.
+///
+/// Each result is dequeued before its contract is invoked, so it's delivered at most once, even if
+/// the callback fails. Callbacks never spend more than `CallbackGasLimit`, nor more than what's left
+/// of their Xtx gas budget, which is settled once they return.
+pub(crate) fn deliver_pending<T: Config>(remaining_weight: Weight) -> Weight {
+    let max_gas_limit = T::CallbackGasLimit::get();
+    let db_weight = T::DbWeight::get().reads_writes(LOAD_EXECUTION_READS + 2, 3);
+    let mut consumed = T::DbWeight::get().reads(1);
+
+    while consumed
+        .saturating_add(db_weight)
+        .saturating_add(max_gas_limit)
+        .all_lte(remaining_weight)
+    {
+        let Some((xtx_id, (contract, succeeded))) = <PendingCallbacks<T>>::iter().next() else {
+            break
+        };
+        <PendingCallbacks<T>>::remove(xtx_id);
+        consumed.saturating_accrue(db_weight);
+
+        let gas_limit = <XtxGasBudgets<T>>::get(xtx_id)
+            .map(|(_, budget)| budget.limit_weight::<T::UnifiedGas>(max_gas_limit))
+            .unwrap_or(max_gas_limit);
+
+        let origin: T::RuntimeOrigin = RawOrigin::Signed(contract.clone()).into();
+        let outputs = T::OnLocalTrigger::load_local_state(&origin, Some(xtx_id))
+            .map(|view| view.into_confirmed_outputs())
+            .unwrap_or_default();
+        let result = XtxResult::<T::Hash, T::AccountId, BlockNumberFor<T>, BalanceOf<T>> {
+            xtx_id,
+            succeeded,
+            outputs,
+        };
+
+        let delivered = with_transaction(|| {
+            match T::XtxResultCallback::deliver(&contract, result.encode(), gas_limit) {
+                Ok(gas_consumed) => TransactionOutcome::Commit(Ok(gas_consumed)),
+                Err(err) => TransactionOutcome::Rollback(Err::<_, DispatchError>(err)),
+            }
+        });
+
+        match delivered {
+            Ok(gas_consumed) => {
+                log::debug!(
+                    target: LOG_TARGET,
+                    "Delivered result of xtx {:?} to {:?}",
+                    xtx_id,
+                    contract
+                );
+                let gas_consumed = gas_consumed.min(gas_limit);
+                consumed.saturating_accrue(gas_consumed);
+                gas::settle::<T>(xtx_id, T::UnifiedGas::weight_to_gas(gas_consumed));
+                Pallet::<T>::deposit_event(Event::XtxResultDelivered((
+                    contract,
+                    xtx_id,
+                    gas_consumed,
+                )));
+            },
+            Err(err) => {
+                log::debug!(
+                    target: LOG_TARGET,
+                    "Failed to deliver result of xtx {:?} to {:?}: {:?}",
+                    xtx_id,
+                    contract,
+                    err
+                );
+                consumed.saturating_accrue(gas_limit);
+                gas::settle::<T>(xtx_id, T::UnifiedGas::weight_to_gas(gas_limit));
+                Pallet::<T>::deposit_event(Event::XtxResultUndelivered((contract, xtx_id, err)));
+            },
+        }
+    }
+
+    consumed
+}
@@ -0,0 +1,104 @@
+use crate::{callback, gas, Config, DeclaredSideEffects, Error, Event, Pallet, XtxGasBudgets};
+use frame_support::{
+    dispatch::RawOrigin,
+    storage::{with_transaction, TransactionOutcome},
+};
+use sp_runtime::DispatchError;
+use sp_std::vec::Vec;
+use t3rn_primitives::{
+    circuit::{LocalTrigger, OnLocalTrigger},
+    SpeedMode,
+};
+use t3rn_sdk_primitives::MAX_PARAMETERS_IN_FUNCTION;
+
+const LOG_TARGET: &str = "3vm::declaration";
+
+/// Collect side effects declared by a contract during its execution.
+///
+/// Declarations live in storage, so the ones made by a call that later reverts are rolled back
+/// together with the rest of its state.
+pub(crate) fn declare<T: Config>(
+    contract: &T::AccountId,
+    side_effects: Vec<Vec<u8>>,
+    speed_mode: SpeedMode,
+) -> Result<(), Error<T>> {
+    if side_effects.is_empty() {
+        return Err(Error::<T>::CannotTriggerWithoutSideEffects)
+    }
+
+    <DeclaredSideEffects<T>>::try_mutate(contract, |maybe_declared| {
+        let (declared_speed_mode, declared) =
+            maybe_declared.get_or_insert_with(|| (speed_mode.clone(), Vec::new()));
+
+        if *declared_speed_mode != speed_mode {
+            return Err(Error::<T>::DeclaredSpeedModeMismatch)
+        }
+        if declared.len() + side_effects.len() > MAX_PARAMETERS_IN_FUNCTION {
+            return Err(Error::<T>::TooManyDeclaredSideEffects)
+        }
+
+        log::debug!(
+            target: LOG_TARGET,
+            "Contract {:?} declared {} side effects",
+            contract,
+            side_effects.len()
+        );
+        declared.extend(side_effects);
+        Ok(())
+    })
+}
+
+/// Post the side effects declared throughout an execution, one Xtx requested by each contract,
+/// whose result is delivered back to it once concluded.
+///
+/// Each Xtx reserves its gas budget from the contract, which first pays for the posting itself. A
+/// contract that can't afford its Xtx has its side effects dropped without failing the others.
+pub(crate) fn post_declared<T: Config>() {
+    let declarations: Vec<_> = <DeclaredSideEffects<T>>::drain().collect();
+
+    for (contract, (speed_mode, side_effects)) in declarations {
+        let side_effects_cnt = side_effects.len() as u32;
+        let origin: T::RuntimeOrigin = RawOrigin::Signed(contract.clone()).into();
+        let trigger = LocalTrigger::<T>::new(contract.clone(), side_effects, speed_mode, None);
+
+        let posted = with_transaction(|| {
+            let posted = gas::open_budget::<T>(&contract)
+                .map_err(DispatchError::from)
+                .and_then(|budget| {
+                    let view = T::OnLocalTrigger::on_local_trigger(&origin, trigger)?;
+                    <XtxGasBudgets<T>>::insert(view.xtx_id, (contract.clone(), budget));
+                    Ok(view.xtx_id)
+                });
+            match posted {
+                Ok(xtx_id) => TransactionOutcome::Commit(Ok(xtx_id)),
+                Err(err) => TransactionOutcome::Rollback(Err::<_, DispatchError>(err)),
+            }
+        });
+
+        match posted {
+            Ok(xtx_id) => {
+                log::debug!(
+                    target: LOG_TARGET,
+                    "Posted side effects of {:?} as xtx {:?}",
+                    contract,
+                    xtx_id
+                );
+                callback::await_result::<T>(&contract, xtx_id);
+                Pallet::<T>::deposit_event(Event::SideEffectsPosted((
+                    contract,
+                    xtx_id,
+                    side_effects_cnt,
+                )));
+            },
+            Err(err) => {
+                log::debug!(
+                    target: LOG_TARGET,
+                    "Dropping side effects of {:?}: {:?}",
+                    contract,
+                    err
+                );
+                Pallet::<T>::deposit_event(Event::SideEffectsDropped((contract, err)));
+            },
+        }
+    }
+}
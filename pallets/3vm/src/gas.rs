@@ -0,0 +1,94 @@
+use crate::{BalanceOf, Config, Error, Event, Pallet, XtxGasBudgets};
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{
+    ensure,
+    traits::{Get, ReservableCurrency},
+    weights::Weight,
+};
+use scale_info::TypeInfo;
+use sp_runtime::{traits::Zero, SaturatedConversion, Saturating};
+use t3rn_primitives::threevm::UnifiedGas;
+
+const LOG_TARGET: &str = "3vm::gas";
+
+/// Gas an Xtx posted on behalf of a contract may spend, on its posting by the circuit and on the
+/// delivery of its result back to the contract, in either VM.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub struct XtxGasBudget {
+    pub limit: u64,
+    pub used: u64,
+}
+
+impl XtxGasBudget {
+    pub fn new(limit: u64) -> Self {
+        Self { limit, used: 0 }
+    }
+
+    pub fn remaining(&self) -> u64 {
+        self.limit.saturating_sub(self.used)
+    }
+
+    /// Spend `gas` out of the remaining budget, leaving it untouched if it doesn't fit.
+    pub fn try_charge(&mut self, gas: u64) -> bool {
+        if gas > self.remaining() {
+            return false
+        }
+        self.used = self.used.saturating_add(gas);
+        true
+    }
+
+    /// Cap the weight a VM may spend to the remaining budget. Gas only meters ref time, so the
+    /// proof size is left to the cap.
+    pub fn limit_weight<Gas: UnifiedGas>(&self, cap: Weight) -> Weight {
+        let budget = Gas::gas_to_weight(self.remaining());
+        Weight::from_parts(cap.ref_time().min(budget.ref_time()), cap.proof_size())
+    }
+}
+
+fn gas_cost<T: Config>(gas: u64) -> BalanceOf<T> {
+    T::GasPrice::get().saturating_mul(gas.saturated_into())
+}
+
+/// Reserve the budget of an Xtx being posted on behalf of a contract, charging its posting by the
+/// circuit right away.
+pub(crate) fn open_budget<T: Config>(contract: &T::AccountId) -> Result<XtxGasBudget, Error<T>> {
+    let mut budget = XtxGasBudget::new(T::XtxGasLimit::get());
+    ensure!(
+        budget.try_charge(T::UnifiedGas::weight_to_gas(T::LocalTriggerWeight::get())),
+        Error::<T>::XtxGasLimitExceeded
+    );
+    T::Currency::reserve(contract, gas_cost::<T>(budget.limit))
+        .map_err(|_| Error::<T>::CannotAffordXtxGas)?;
+    Ok(budget)
+}
+
+/// Pay for the gas the Xtx used and refund the rest of its budget to the contract.
+pub(crate) fn settle<T: Config>(xtx_id: T::Hash, delivery_gas: u64) {
+    let Some((contract, mut budget)) = <XtxGasBudgets<T>>::take(xtx_id) else {
+        return
+    };
+    // Deliveries never exceed their limit, which fits the remaining budget
+    budget.used = budget.used.saturating_add(delivery_gas).min(budget.limit);
+
+    let reserved = gas_cost::<T>(budget.limit);
+    let (_burnt, unslashed) = T::Currency::slash_reserved(&contract, gas_cost::<T>(budget.used));
+    let unreserved = T::Currency::unreserve(
+        &contract,
+        reserved.saturating_sub(gas_cost::<T>(budget.used)),
+    );
+    if !unslashed.is_zero() || !unreserved.is_zero() {
+        log::warn!(
+            target: LOG_TARGET,
+            "Gas reserved by {:?} for xtx {:?} was short of its budget",
+            contract,
+            xtx_id
+        );
+    }
+
+    Pallet::<T>::deposit_event(Event::XtxGasSettled((
+        contract,
+        xtx_id,
+        budget.used,
+        budget.remaining(),
+    )));
+}
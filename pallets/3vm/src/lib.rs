@@ -13,8 +13,8 @@ use sp_std::vec::Vec;
 use t3rn_primitives::{
     account_manager::Outcome,
     circuit::{
-        LocalStateExecutionView, OnLocalTrigger, VacuumEVM3DOrder, VacuumEVMOrder, VacuumEVMProof,
-        VacuumEVMTeleportOrder,
+        CircuitStatus, LocalStateExecutionView, OnLocalTrigger, OnXtxConcluded, VacuumEVM3DOrder,
+        VacuumEVMOrder, VacuumEVMProof, VacuumEVMTeleportOrder,
     },
     contract_metadata::ContractType,
     contracts_registry::{AuthorInfo, ContractsRegistry, KindValidator, RegistryContract},
@@ -33,6 +33,9 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
+pub mod callback;
+pub mod declaration;
+pub mod gas;
 pub mod precompile;
 pub mod remuneration;
 pub mod signal;
@@ -48,7 +51,7 @@ pub type BalanceOf<T> =
 pub mod pallet {
 
     use crate::BalanceOf;
-    use frame_support::{pallet_prelude::*, traits::Currency};
+    use frame_support::{pallet_prelude::*, traits::ReservableCurrency};
     use frame_system::pallet_prelude::BlockNumberFor;
     use sp_std::vec::Vec;
     use t3rn_primitives::{
@@ -57,8 +60,8 @@ pub mod pallet {
         contract_metadata::ContractType,
         contracts_registry::ContractsRegistry,
         portal::Portal,
-        threevm::{AddressMapping, VacuumAccess},
-        ChainId,
+        threevm::{AddressMapping, UnifiedGas, VacuumAccess, XtxResultCallback},
+        ChainId, SpeedMode,
     };
 
     use t3rn_sdk_primitives::signal::SignalKind;
@@ -77,7 +80,7 @@ pub mod pallet {
         /// The pallet that handles the contracts registry, used to fetch contracts
         type ContractsRegistry: ContractsRegistry<Self, Self::Currency>;
 
-        type Currency: Currency<Self::AccountId>;
+        type Currency: ReservableCurrency<Self::AccountId>;
 
         /// The address of the escrow account
         #[pallet::constant]
@@ -103,6 +106,29 @@ pub mod pallet {
 
         /// Inject access to portal so contracts can use light clients
         type Portal: Portal<Self>;
+
+        /// Delivers the results of concluded Xtx back to the contracts that originated them
+        type XtxResultCallback: XtxResultCallback<Self::AccountId>;
+
+        /// The most gas a contract can spend handling the result of one of its Xtx
+        #[pallet::constant]
+        type CallbackGasLimit: Get<Weight>;
+
+        /// Converts between gas and weight, for a single budget to be spent in either VM
+        type UnifiedGas: UnifiedGas;
+
+        /// The gas budget of each Xtx posted on behalf of a contract, covering both its posting by
+        /// the circuit and the delivery of its result
+        #[pallet::constant]
+        type XtxGasLimit: Get<u64>;
+
+        /// The price of a unit of gas, reserved from the contract for the whole budget upfront
+        #[pallet::constant]
+        type GasPrice: Get<BalanceOf<Self>>;
+
+        /// The weight of the circuit posting an Xtx on behalf of a contract
+        #[pallet::constant]
+        type LocalTriggerWeight: Get<Weight>;
     }
 
     #[pallet::pallet]
@@ -122,6 +148,29 @@ pub mod pallet {
     #[pallet::getter(fn author_of)]
     pub(crate) type AuthorOf<T: Config> = StorageMap<_, Identity, T::AccountId, T::AccountId>;
 
+    /// Side effects declared by local contracts throughout the current execution, awaiting to be
+    /// posted to the circuit once it succeeds.
+    #[pallet::storage]
+    #[pallet::unbounded]
+    pub(crate) type DeclaredSideEffects<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, (SpeedMode, Vec<Vec<u8>>)>;
+
+    /// Xtx posted on behalf of local contracts, awaiting to conclude. [xtx_id => contract]
+    #[pallet::storage]
+    pub(crate) type XtxCallbacks<T: Config> = StorageMap<_, Identity, T::Hash, T::AccountId>;
+
+    /// Results of concluded Xtx awaiting delivery to the contracts that originated them.
+    /// [xtx_id => (contract, succeeded)]
+    #[pallet::storage]
+    pub(crate) type PendingCallbacks<T: Config> =
+        StorageMap<_, Identity, T::Hash, (T::AccountId, bool)>;
+
+    /// Gas budgets of Xtx posted on behalf of local contracts, reserved until they conclude.
+    /// [xtx_id => (contract, budget)]
+    #[pallet::storage]
+    pub(crate) type XtxGasBudgets<T: Config> =
+        StorageMap<_, Identity, T::Hash, (T::AccountId, crate::gas::XtxGasBudget)>;
+
     #[pallet::genesis_config]
     #[derive(frame_support::DefaultNoBound)]
     pub struct GenesisConfig<T: Config> {
@@ -152,6 +201,16 @@ pub mod pallet {
         AuthorStored((T::AccountId, T::AccountId)),
         /// An author of a module was removed [contract]
         AuthorRemoved(T::AccountId),
+        /// Side effects declared by a contract were posted to the circuit [contract, xtx_id, side_effects_cnt]
+        SideEffectsPosted((T::AccountId, T::Hash, u32)),
+        /// Side effects declared by a contract could not be posted to the circuit [contract, error]
+        SideEffectsDropped((T::AccountId, DispatchError)),
+        /// The result of an Xtx was delivered to the contract that originated it [contract, xtx_id, gas_consumed]
+        XtxResultDelivered((T::AccountId, T::Hash, Weight)),
+        /// The result of an Xtx could not be delivered to the contract that originated it, and won't be retried [contract, xtx_id, error]
+        XtxResultUndelivered((T::AccountId, T::Hash, DispatchError)),
+        /// The gas used by an Xtx was paid for by the contract that originated it, and the rest of its budget refunded [contract, xtx_id, gas_used, gas_refunded]
+        XtxGasSettled((T::AccountId, T::Hash, u64, u64)),
     }
 
     #[derive(PartialEq)]
@@ -181,6 +240,21 @@ pub mod pallet {
         /// Invalid arithmetic computation causes overflow
         InvalidArithmeticOverflow,
         DownstreamCircuit,
+        /// A contract declared more side effects than fit in a single Xtx
+        TooManyDeclaredSideEffects,
+        /// A contract declared side effects with a speed mode other than its previous declarations
+        DeclaredSpeedModeMismatch,
+        /// Posting an Xtx costs more gas than its budget
+        XtxGasLimitExceeded,
+        /// A contract can't reserve the gas budget of its Xtx
+        CannotAffordXtxGas,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            crate::callback::deliver_pending::<T>(remaining_weight)
+        }
     }
 
     #[pallet::call]
@@ -196,6 +270,12 @@ impl<T: Config> Pallet<T> {
     }
 }
 
+impl<T: Config> OnXtxConcluded<T::Hash> for Pallet<T> {
+    fn on_xtx_concluded(xtx_id: T::Hash, status: &CircuitStatus) {
+        callback::on_concluded::<T>(xtx_id, status)
+    }
+}
+
 impl<T: Config> Precompile<T, BalanceOf<T>> for Pallet<T> {
     fn lookup(dest: &T::Hash) -> Option<u8> {
         precompile::lookup::<T>(dest)
@@ -381,4 +461,8 @@ impl<T: Config> ThreeVm<T, BalanceOf<T>> for Pallet<T> {
 
         Ok(())
     }
+
+    fn post_declared_side_effects() {
+        declaration::post_declared::<T>()
+    }
 }
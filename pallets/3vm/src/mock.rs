@@ -2,6 +2,7 @@ use crate as pallet_3vm;
 use frame_support::{
     parameter_types,
     traits::{ConstU16, ConstU32},
+    weights::Weight,
 };
 use frame_system as system;
 
@@ -196,6 +197,30 @@ parameter_types! {
     pub const CircuitTargetId: t3rn_primitives::ChainId = [3, 3, 3, 3];
     pub const CircuitTargetIdOptimistic: t3rn_primitives::ChainId = [0, 3, 3, 3];
     pub EscrowAccount: AccountId = ESCROW;
+    pub const SettlementWeightBudget: Weight = Weight::from_parts(50_000_000_000, u64::MAX);
+    pub const CallbackGasLimit: Weight = Weight::from_parts(1_000_000_000, 64 * 1024);
+    pub const XtxGasLimit: u64 = 2_000_000_000;
+    pub const XtxGasPrice: Balance = 1;
+    pub const LocalTriggerWeight: Weight = Weight::from_parts(100_000_000, 0);
+    pub static DeliveredXtxResults: Vec<(AccountId, Vec<u8>)> = vec![];
+}
+
+/// Treats every account as a contract, recording the results delivered to it
+pub struct MockXtxResultCallback;
+
+impl t3rn_primitives::threevm::XtxResultCallback<AccountId> for MockXtxResultCallback {
+    fn is_contract(_contract: &AccountId) -> bool {
+        true
+    }
+
+    fn deliver(
+        contract: &AccountId,
+        result: Vec<u8>,
+        gas_limit: Weight,
+    ) -> Result<Weight, DispatchError> {
+        DeliveredXtxResults::mutate(|delivered| delivered.push((contract.clone(), result)));
+        Ok(gas_limit / 2)
+    }
 }
 
 parameter_types! {
@@ -207,6 +232,7 @@ parameter_types! {
     pub const MetadataDepositBase: Balance = 0;
     pub const MetadataDepositPerByte: Balance = 0;
     pub const AssetAccountDeposit: Balance = 0;
+    pub const GatewayIdReservationDeposit: Balance = 1;
 }
 
 impl pallet_assets::Config for Test {
@@ -235,15 +261,21 @@ impl pallet_3vm::Config for Test {
     type AccountManager = AccountManager;
     type AddressMapping = EvmAddressMapping<Test>;
     type AssetId = u32;
+    type CallbackGasLimit = CallbackGasLimit;
     type CircuitTargetId = CircuitTargetId;
     type ContractsRegistry = ContractsRegistry;
     type Currency = Balances;
     type EscrowAccount = EscrowAccount;
+    type GasPrice = XtxGasPrice;
+    type LocalTriggerWeight = LocalTriggerWeight;
     type OnLocalTrigger = Circuit;
     type Portal = Portal;
     type RuntimeEvent = RuntimeEvent;
     type SignalBounceThreshold = ConstU32<2>;
+    type UnifiedGas = ();
     type VacuumEVMApi = Vacuum;
+    type XtxGasLimit = XtxGasLimit;
+    type XtxResultCallback = MockXtxResultCallback;
 }
 
 pub struct RewardsMockApi;
@@ -272,9 +304,13 @@ impl pallet_circuit_vacuum::Config for Test {
 
 impl pallet_contracts_registry::Config for Test {
     type Balances = Balances;
+    type CircuitSubmitAPI = Circuit;
     type Currency = Balances;
+    type Portal = Portal;
+    type ReadSFX = Circuit;
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = ();
+    type Xdns = Xdns;
 }
 
 impl pallet_account_manager::Config for Test {
@@ -285,8 +321,12 @@ impl pallet_account_manager::Config for Test {
     type Currency = Balances;
     type EscrowAccount = EscrowAccount;
     type Executors = t3rn_primitives::executors::ExecutorsMock<Self>;
+    type InsuranceFund = ();
+    type ProtocolBurn = ();
     type RuntimeEvent = RuntimeEvent;
+    type SettlementWeightBudget = SettlementWeightBudget;
     type Time = Timestamp;
+    type TreasuryAccounts = Test;
     type WeightInfo = ();
 }
 
@@ -297,11 +337,19 @@ parameter_types! {
 impl pallet_circuit::Config for Test {
     type AccountManager = AccountManager;
     type Attesters =
+    type MaxEncodedArgLen = ConstU32<4_096u32>;
+    type MaxPrunedPerBlock = ConstU32<100u32>;
+    type MaxSideEffectsPerXtx = ConstU32<32u32>;
+    type MaxStepsPerXtx = ConstU32<8u32>;
+    type RetentionPeriod = ConstU32<1000u32>;
         t3rn_primitives::attesters::AttestersReadApiEmptyMock<AccountId, Balance, DispatchError>;
     type Balances = Balances;
+    type ConfirmationDepth = ConstU32<0u32>;
     type Currency = Balances;
     type DeletionQueueLimit = ConstU32<1024>;
+    type EscrowGovernanceOrigin = EnsureRoot<AccountId>;
     type Executors = t3rn_primitives::executors::ExecutorsMock<Self>;
+    type OnXtxConcluded = (ThreeVm, ContractsRegistry);
     type Portal = Portal;
     type RuntimeEvent = RuntimeEvent;
     type SFXBiddingPeriod = ConstU32<3>;
@@ -309,8 +357,13 @@ impl pallet_circuit::Config for Test {
     type SelfGatewayId = CircuitTargetId;
     type SelfParaId = ConstU32<3333u32>;
     type SignalQueueDepth = ConstU32<4>;
+    type SponsorSignature = sp_runtime::MultiSignature;
+    type SponsorSigner = sp_runtime::MultiSigner;
+    type TelemetryInterval = ConstU32<0u32>;
     type TreasuryAccounts = Test;
     type WeightInfo = ();
+    type XcmOrderNotifier = ();
+    type XcmOrderOrigin = frame_support::traits::NeverEnsureOrigin<(u32, AccountId)>;
     type Xdns = Xdns;
     type XtxTimeoutCheckInterval = ConstU32<1024>;
     type XtxTimeoutDefault = ConstU32<1024>;
@@ -361,6 +414,19 @@ impl PalletAssetsOverlay<Test, Balance> for Test {
     }
 }
 
+impl<C> frame_system::offchain::SendTransactionTypes<C> for Test
+where
+    RuntimeCall: From<C>,
+{
+    type Extrinsic = UncheckedExtrinsic;
+    type OverarchingCall = RuntimeCall;
+}
+
+impl frame_system::offchain::SigningTypes for Test {
+    type Public = sp_runtime::MultiSigner;
+    type Signature = sp_runtime::MultiSignature;
+}
+
 impl pallet_xdns::Config for Test {
     type AssetsOverlay = Test;
     type AttestersRead =
@@ -368,12 +434,18 @@ impl pallet_xdns::Config for Test {
     type Balances = Balances;
     type CircuitDLQ = Circuit;
     type Currency = Balances;
+    type FeeOracleAuthorityId = pallet_xdns::fee_oracle::crypto::FeeOracleAuthId;
+    type FeeOracleInterval = ConstU32<10>;
+    type GatewayIdReservationDeposit = GatewayIdReservationDeposit;
     type Portal = Portal;
+    type PurgeOrigin = EnsureRoot<AccountId>;
+    type RegistrationOrigin = EnsureRoot<AccountId>;
     type RuntimeEvent = RuntimeEvent;
     type SelfGatewayId = CircuitTargetId;
     type SelfTokenId = ConstU32<3333>;
     type Time = Timestamp;
     type TreasuryAccounts = Test;
+    type UpdateOrigin = EnsureRoot<AccountId>;
     type WeightInfo = ();
 }
 
@@ -383,6 +455,8 @@ pub type Amount = i64;
 
 parameter_types! {
     pub const HeadersToStore: u32 = 100;
+    pub const HeaderSyncSessionDeposit: Balance = 10;
+    pub const HeaderSyncChunkDeposit: Balance = 1;
     pub const RococoVendor: GatewayVendor = GatewayVendor::Rococo;
     pub const KusamaVendor: GatewayVendor = GatewayVendor::Kusama;
     pub const PolkadotVendor: GatewayVendor = GatewayVendor::Polkadot;
@@ -403,12 +477,19 @@ impl pallet_grandpa_finality_verifier::bridges::runtime::Chain for Blake2ValU32C
 
 impl pallet_grandpa_finality_verifier::Config<RococoInstance> for Test {
     type BridgedChain = Blake2ValU32Chain;
+    type Currency = Balances;
     type EpochOffset = ConstU32<2_400u32>;
     type FastConfirmationOffset = ConstU32<0u32>;
     type FinalizedConfirmationOffset = ConstU32<0u32>;
+    type HeaderSubmissionHooks = Portal;
+    type HeaderSyncChunkDeposit = HeaderSyncChunkDeposit;
+    type HeaderSyncSessionDeposit = HeaderSyncSessionDeposit;
+    type HeaderSyncSessionTtl = ConstU32<14_400u32>;
     type HeadersToStore = HeadersToStore;
     type LightClientAsyncAPI = Xdns;
+    type MaxHeaderSyncSessionsPerRelayer = ConstU32<4u32>;
     type MyVendor = RococoVendor;
+    type OnHeadersRolledBack = Portal;
     type RationalConfirmationOffset = ConstU32<0u32>;
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = ();
@@ -416,12 +497,19 @@ impl pallet_grandpa_finality_verifier::Config<RococoInstance> for Test {
 
 impl pallet_grandpa_finality_verifier::Config<PolkadotInstance> for Test {
     type BridgedChain = Blake2ValU32Chain;
+    type Currency = Balances;
     type EpochOffset = ConstU32<2_400u32>;
     type FastConfirmationOffset = ConstU32<0u32>;
     type FinalizedConfirmationOffset = ConstU32<0u32>;
+    type HeaderSubmissionHooks = Portal;
+    type HeaderSyncChunkDeposit = HeaderSyncChunkDeposit;
+    type HeaderSyncSessionDeposit = HeaderSyncSessionDeposit;
+    type HeaderSyncSessionTtl = ConstU32<14_400u32>;
     type HeadersToStore = HeadersToStore;
     type LightClientAsyncAPI = Xdns;
+    type MaxHeaderSyncSessionsPerRelayer = ConstU32<4u32>;
     type MyVendor = PolkadotVendor;
+    type OnHeadersRolledBack = Portal;
     type RationalConfirmationOffset = ConstU32<0u32>;
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = ();
@@ -429,12 +517,19 @@ impl pallet_grandpa_finality_verifier::Config<PolkadotInstance> for Test {
 
 impl pallet_grandpa_finality_verifier::Config<KusamaInstance> for Test {
     type BridgedChain = Blake2ValU32Chain;
+    type Currency = Balances;
     type EpochOffset = ConstU32<2_400u32>;
     type FastConfirmationOffset = ConstU32<0u32>;
     type FinalizedConfirmationOffset = ConstU32<0u32>;
+    type HeaderSubmissionHooks = Portal;
+    type HeaderSyncChunkDeposit = HeaderSyncChunkDeposit;
+    type HeaderSyncSessionDeposit = HeaderSyncSessionDeposit;
+    type HeaderSyncSessionTtl = ConstU32<14_400u32>;
     type HeadersToStore = HeadersToStore;
     type LightClientAsyncAPI = Xdns;
+    type MaxHeaderSyncSessionsPerRelayer = ConstU32<4u32>;
     type MyVendor = KusamaVendor;
+    type OnHeadersRolledBack = Portal;
     type RationalConfirmationOffset = ConstU32<0u32>;
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = ();
@@ -468,8 +563,18 @@ impl pallet_portal::SelectLightClient<Test> for SelectLightClientRegistry {
 
 impl pallet_portal::Config for Test {
     type Currency = Balances;
+    type HeaderExporter = ();
+    type HeaderSubmissionWindow = ConstU32<1>;
+    type HeaderSubscriberOrigin = frame_support::traits::NeverEnsureOrigin<u32>;
+    type MaxCachedInclusions = ConstU32<16>;
+    type MaxHeaderExportsPerBlock = ConstU32<16>;
+    type MaxHeaderSubmissionsPerWindow = ConstU32<0>;
+    type MaxPrunedPerBlock = ConstU32<100>;
+    type OnHeadersRolledBack = Circuit;
+    type RetentionPeriod = ConstU32<1000>;
     type RuntimeEvent = RuntimeEvent;
     type SelectLightClient = SelectLightClientRegistry;
+    type VendorLimits = ();
     type WeightInfo = pallet_portal::weights::SubstrateWeight<Test>;
     type Xdns = Xdns;
 }
@@ -1,4 +1,4 @@
-use crate::{BalanceOf, Config, Error, Pallet, PrecompileIndex};
+use crate::{declaration, BalanceOf, Config, Error, Pallet, PrecompileIndex};
 use codec::{Decode, Encode};
 use frame_support::{dispatch::RawOrigin, sp_runtime::DispatchError};
 use frame_system::ensure_signed;
@@ -13,7 +13,7 @@ use t3rn_primitives::{
     portal::{Portal, PrecompileArgs as PortalPrecompileArgs},
     threevm::{
         AddressMapping, GetState, LocalStateAccess, PrecompileArgs, PrecompileInvocation,
-        VacuumAccess, GET_STATE, PORTAL, POST_SIGNAL, SUBMIT,
+        VacuumAccess, DECLARE, GET_STATE, PORTAL, POST_SIGNAL, SUBMIT,
     },
     SpeedMode, T3rnCodec,
 };
@@ -329,6 +329,29 @@ pub(crate) fn invoke_raw<T: Config>(precompile: &u8, args: &mut &[u8], output: &
                     Err::<(), _>(Error::<T>::InvalidPrecompileArgs).encode_to(output)
                 }
             },
+            DECLARE => {
+                let args: CodecResult<(
+                    SideEffects<T::AccountId, BalanceOf<T>, T::Hash>,
+                    SpeedMode,
+                )> = match codec {
+                    T3rnCodec::Scale => Decode::decode(args),
+                    T3rnCodec::Rlp =>
+                        Err(codec::Error::from("Cannot decode SideEffects with RLP yet")),
+                };
+
+                if let Ok((sfx_arg, speed_mode_arg)) = args {
+                    match invoke::<T>(PrecompileArgs::DeclareSideEffects(
+                        origin,
+                        sfx_arg,
+                        speed_mode_arg,
+                    )) {
+                        Ok(_) => Ok::<_, Error<T>>(()).encode_to(output),
+                        Err(e) => Err::<(), _>(e).encode_to(output),
+                    }
+                } else {
+                    Err::<(), _>(Error::<T>::InvalidPrecompileArgs).encode_to(output)
+                }
+            },
             PORTAL => {
                 let mut result = PortalPrecompileArgs::recode_to_scale_and_decode(&codec, args)
                 .and_then(|recoded_call_as_enum| {
@@ -480,6 +503,21 @@ pub(crate) fn invoke<T: Config>(
                 },
             }
         },
+        PrecompileArgs::DeclareSideEffects(origin, side_effects, speed_mode) => {
+            // Side effects are declared by the contract itself, which requests and pays for the Xtx
+            let contract = ensure_signed(origin).map_err(|_e| Error::<T>::InvalidOrigin)?;
+
+            declaration::declare::<T>(
+                &contract,
+                side_effects
+                    .side_effects
+                    .iter()
+                    .map(|i| i.encode())
+                    .collect(),
+                speed_mode,
+            )?;
+            Ok(PrecompileInvocation::Declare)
+        },
         PrecompileArgs::Portal(args) => match args {
             PortalPrecompileArgs::GetLatestFinalizedHeader(chain_id) =>
                 T::Portal::get_latest_finalized_header(chain_id)
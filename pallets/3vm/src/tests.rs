@@ -42,3 +42,130 @@ fn test_substrate_boundec_vec_compatibility() {
         <t3rn_sdk_primitives::storage::BoundedVec::<Chain::<AccountId32, u128, [u8; 32]>, 3> as MaxEncodedLen>::max_encoded_len()
     );
 }
+
+#[test]
+fn declared_side_effects_accumulate_per_contract_under_one_speed_mode() {
+    use crate::{declaration, DeclaredSideEffects, Error};
+    use t3rn_primitives::SpeedMode;
+
+    new_test_ext().execute_with(|| {
+        let contract = ALICE;
+
+        assert!(matches!(
+            declaration::declare::<Test>(&contract, vec![], SpeedMode::Fast),
+            Err(Error::<Test>::CannotTriggerWithoutSideEffects)
+        ));
+        assert!(declaration::declare::<Test>(&contract, vec![vec![1_u8]], SpeedMode::Fast).is_ok());
+        assert!(declaration::declare::<Test>(&contract, vec![vec![2_u8]], SpeedMode::Fast).is_ok());
+        assert!(matches!(
+            declaration::declare::<Test>(&contract, vec![vec![3_u8]], SpeedMode::Finalized),
+            Err(Error::<Test>::DeclaredSpeedModeMismatch)
+        ));
+
+        assert_eq!(
+            DeclaredSideEffects::<Test>::get(&contract),
+            Some((SpeedMode::Fast, vec![vec![1_u8], vec![2_u8]]))
+        );
+    });
+}
+
+#[test]
+fn concluded_xtx_results_are_delivered_to_their_contracts_at_most_once() {
+    use crate::{callback, Event, PendingCallbacks, XtxCallbacks};
+    use codec::Decode;
+    use frame_support::{traits::Hooks, weights::Weight};
+    use sp_core::H256;
+    use t3rn_primitives::circuit::{CircuitStatus, OnXtxConcluded};
+    use t3rn_sdk_primitives::state::XtxResult;
+
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let xtx_id = H256::repeat_byte(1);
+        callback::await_result::<Test>(&ALICE, xtx_id);
+
+        <ThreeVm as OnXtxConcluded<H256>>::on_xtx_concluded(xtx_id, &CircuitStatus::Committed);
+        <ThreeVm as OnXtxConcluded<H256>>::on_xtx_concluded(xtx_id, &CircuitStatus::Committed);
+        assert!(XtxCallbacks::<Test>::get(xtx_id).is_none());
+        assert_eq!(PendingCallbacks::<Test>::get(xtx_id), Some((ALICE, true)));
+
+        // Not enough weight left for a callback exhausting its gas
+        ThreeVm::on_idle(1, CallbackGasLimit::get());
+        assert!(DeliveredXtxResults::get().is_empty());
+
+        ThreeVm::on_idle(1, Weight::MAX);
+        ThreeVm::on_idle(2, Weight::MAX);
+
+        let delivered = DeliveredXtxResults::get();
+        assert_eq!(delivered.len(), 1);
+        assert_eq!(delivered[0].0, ALICE);
+        let result =
+            XtxResult::<H256, AccountId, BlockNumber, Balance>::decode(&mut &delivered[0].1[..])
+                .unwrap();
+        assert_eq!(result.xtx_id, xtx_id);
+        assert!(result.succeeded);
+        assert!(PendingCallbacks::<Test>::get(xtx_id).is_none());
+        System::assert_has_event(
+            Event::<Test>::XtxResultDelivered((ALICE, xtx_id, CallbackGasLimit::get() / 2)).into(),
+        );
+    });
+}
+
+#[test]
+fn xtx_gas_budgets_pay_for_posting_and_delivery_and_refund_the_rest() {
+    use crate::{
+        callback,
+        gas::{self, XtxGasBudget},
+        Error, Event, XtxGasBudgets,
+    };
+    use frame_support::{
+        traits::{Currency, Hooks, ReservableCurrency},
+        weights::Weight,
+    };
+    use sp_core::H256;
+    use t3rn_primitives::circuit::{CircuitStatus, OnXtxConcluded};
+
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let xtx_id = H256::repeat_byte(1);
+        Balances::make_free_balance_be(&ALICE, 10_000_000_000);
+
+        assert!(matches!(
+            gas::open_budget::<Test>(&BOB),
+            Err(Error::<Test>::CannotAffordXtxGas)
+        ));
+
+        // Posting is charged right away, out of the reserved budget
+        let budget = gas::open_budget::<Test>(&ALICE).unwrap();
+        assert_eq!(
+            budget,
+            XtxGasBudget {
+                limit: 2_000_000_000,
+                used: 100_000_000,
+            }
+        );
+        assert_eq!(Balances::reserved_balance(&ALICE), 2_000_000_000);
+        XtxGasBudgets::<Test>::insert(xtx_id, (ALICE, budget));
+        callback::await_result::<Test>(&ALICE, xtx_id);
+
+        <ThreeVm as OnXtxConcluded<H256>>::on_xtx_concluded(xtx_id, &CircuitStatus::Committed);
+        ThreeVm::on_idle(1, Weight::MAX);
+
+        // The callback spent half of its limit, capped by `CallbackGasLimit` below the budget left
+        System::assert_has_event(
+            Event::<Test>::XtxGasSettled((ALICE, xtx_id, 600_000_000, 1_400_000_000)).into(),
+        );
+        assert!(XtxGasBudgets::<Test>::get(xtx_id).is_none());
+        assert_eq!(Balances::reserved_balance(&ALICE), 0);
+        assert_eq!(Balances::free_balance(&ALICE), 9_400_000_000);
+
+        // A depleted budget limits the callback below `CallbackGasLimit`
+        let depleted = XtxGasBudget {
+            limit: 2_000_000_000,
+            used: 1_800_000_000,
+        };
+        assert_eq!(
+            depleted.limit_weight::<()>(CallbackGasLimit::get()),
+            Weight::from_parts(200_000_000, 64 * 1024)
+        );
+    });
+}
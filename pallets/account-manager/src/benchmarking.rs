@@ -1,62 +1,54 @@
-// use super::*;
-// #[allow(unused)]
-// use crate::Pallet as AccountManager;
-// use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite};
-// use frame_system::RawOrigin;
-// use sp_runtime::create_runtime_str;
-// use sp_version::RuntimeVersion;
-//
-// const USER_SEED: u32 = 999666;
-// pub const TEST_RUNTIME_VERSION: RuntimeVersion = RuntimeVersion {
-//     spec_name: create_runtime_str!("test-runtime"),
-//     impl_name: create_runtime_str!("test-runtime"),
-//     authoring_version: 1,
-//     spec_version: 1,
-//     impl_version: 1,
-//     apis: sp_version::create_apis_vec!([]),
-//     transaction_version: 1,
-//     state_version: 0,
-// };
-//
-// benchmarks! {
-//     deposit {
-//         let payee: T::AccountId = account("PAYEE", 1_u32, USER_SEED);
-//         let escrow: T::AccountId = account("PAYEE", 2_u32, USER_SEED);
-//         let recipient: T::AccountId = account("RECIPIENT", 3_u32, USER_SEED);
-//         let _ = T::Currency::make_free_balance_be(&payee, BalanceOf::<T>::from(100_u32));
-//         let _ = T::Currency::make_free_balance_be(&escrow, BalanceOf::<T>::from(10_u32));
-//         let transfer_amt: BalanceOf<T> = BalanceOf::<T>::from(10_u32);
-//     }: _(RawOrigin::Root, payee.clone(), recipient.clone(), transfer_amt)
-//     verify {
-//         assert!(pallet::ExecutionRegistry::<T>::contains_key(&0));
-//     }
-//
-//
-//     impl_benchmark_test_suite!(
-//         AccountManager,
-//         crate::benchmarking::tests::new_test_ext(),
-//         crate::mock::Test
-//     );
-// }
-//
-// #[cfg(test)]
-// mod tests {
-//     use super::*;
-//     use crate::mock::Test;
-//     use frame_support::assert_ok;
-//     use sp_io::TestExternalities;
-//
-//     pub fn new_test_ext() -> TestExternalities {
-//         let t = frame_system::GenesisConfig::default()
-//             .build_storage::<Test>()
-//             .unwrap();
-//         TestExternalities::new(t)
-//     }
-//
-//     #[test]
-//     fn benchmark_deposit() {
-//         new_test_ext().execute_with(|| {
-//             bench_deposit();
-//         })
-//     }
-// }
+//! Benchmarking setup for pallet-account-manager
+//!
+//! Each benchmark settles a single item of the round end ledger settlement, along with the upkeep
+//! of the settlement cursor, so the weights bound the cost per item settled.
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet as AccountManager;
+use frame_benchmarking::{account, benchmarks};
+use sp_runtime::traits::Hash;
+use t3rn_primitives::account_manager::LedgerEntry;
+
+const USER_SEED: u32 = 999666;
+const ROUND: u32 = 1;
+
+type AssetIdOf<T> =
+    <<T as Config>::Assets as Inspect<<T as frame_system::Config>::AccountId>>::AssetId;
+
+benchmarks! {
+    // A credit paid out to a beneficiary without an account yet, which the deposit creates
+    settle_net_credit {
+        let beneficiary: T::AccountId = account("beneficiary", 0, USER_SEED);
+        NetCredits::<T>::insert(&beneficiary, None::<AssetIdOf<T>>, T::Currency::minimum_balance());
+        AccountManager::<T>::open_settlement(ROUND);
+    }: {
+        AccountManager::<T>::settle_ledger(Weight::MAX);
+    }
+    verify {
+        assert!(!NetCredits::<T>::contains_key(&beneficiary, None::<AssetIdOf<T>>));
+        assert!(!LedgerSettlement::<T>::exists());
+    }
+
+    // A finalized ledger entry pruned along with the index of its charge
+    settle_ledger_entry {
+        let xtx_id = T::Hashing::hash_of(&(b"xtx", 0u32));
+        let charge_id = T::Hashing::hash_of(&(b"charge", 0u32));
+        XtxLedger::<T>::insert(xtx_id, charge_id, LedgerEntry {
+            debtor: account("debtor", 0, USER_SEED),
+            creditor: account("creditor", 0, USER_SEED),
+            amount: T::Currency::minimum_balance(),
+            maybe_asset_id: None,
+            role: CircuitRole::Executor,
+            outcome: Some(Outcome::Commit),
+        });
+        ChargeXtx::<T>::insert(charge_id, xtx_id);
+        AccountManager::<T>::open_settlement(ROUND);
+    }: {
+        AccountManager::<T>::settle_ledger(Weight::MAX);
+    }
+    verify {
+        assert!(!XtxLedger::<T>::contains_key(xtx_id, charge_id));
+        assert!(!ChargeXtx::<T>::contains_key(charge_id));
+    }
+}
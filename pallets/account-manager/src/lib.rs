@@ -12,7 +12,7 @@ use frame_support::{
     pallet_prelude::Weight,
     traits::{fungibles::Inspect, Currency, Get},
 };
-use sp_runtime::traits::Convert;
+use sp_runtime::{traits::Convert, Percent};
 
 use t3rn_primitives::{
     account_manager::{AccountManager, Outcome},
@@ -20,7 +20,8 @@ use t3rn_primitives::{
     clock::Clock,
     common::RoundInfo,
     executors::Executors,
-    reexport_currency_types,
+    insurance::InsuranceFund,
+    reexport_currency_types, TreasuryAccount, TreasuryAccountProvider,
 };
 
 #[cfg(test)]
@@ -53,7 +54,10 @@ pub mod pallet {
     };
     use frame_system::pallet_prelude::*;
 
-    use t3rn_primitives::account_manager::{ExecutionId, RequestCharge, Settlement};
+    use t3rn_primitives::{
+        account_manager::{ExecutionId, LedgerEntry, RequestCharge, Settlement, SettlementCursor},
+        common::RoundIndex,
+    };
 
     #[pallet::config]
     pub trait Config: frame_system::Config {
@@ -80,6 +84,21 @@ pub mod pallet {
         type AssetBalanceOf: Convert<BalanceOf<Self>, AssetsBalanceOf<Self>>;
 
         type AssetId: FullCodec + Copy + MaybeSerializeDeserialize + Debug + Default + Eq + TypeInfo;
+
+        /// Treasuries collecting protocol fees and slashed deposits.
+        type TreasuryAccounts: TreasuryAccountProvider<Self::AccountId>;
+
+        /// Portion of protocol fees and slashed deposits burned instead of paid to the treasury.
+        #[pallet::constant]
+        type ProtocolBurn: Get<Percent>;
+
+        /// Insurance fund capitalized by a share of the protocol fees.
+        type InsuranceFund: InsuranceFund<Self::AccountId, BalanceOf<Self>>;
+
+        /// Weight the round end ledger settlement may consume per block; the rest of the
+        /// settlement continues in the following blocks.
+        #[pallet::constant]
+        type SettlementWeightBudget: Get<Weight>;
     }
 
     // Simple declaration of the `Pallet` type. It is placeholder we use to implement traits and
@@ -119,6 +138,49 @@ pub mod pallet {
         >,
     >;
 
+    #[pallet::storage]
+    #[pallet::getter(fn xtx_ledger)]
+    /// Charges created for each Xtx until they're finalized and settled at round end.
+    pub type XtxLedger<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        T::Hash, // xtx_id
+        Identity,
+        T::Hash, // charge_id
+        LedgerEntry<
+            T::AccountId,
+            <T::Currency as Currency<T::AccountId>>::Balance,
+            <T::Assets as Inspect<T::AccountId>>::AssetId,
+        >,
+    >;
+
+    #[pallet::storage]
+    /// Xtx each ledger charge belongs to.
+    pub type ChargeXtx<T: Config> = StorageMap<_, Identity, T::Hash, T::Hash>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn net_credits)]
+    /// Refunds owed per account and asset, netted against new deposits and paid at round end.
+    pub type NetCredits<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        Option<<T::Assets as Inspect<T::AccountId>>::AssetId>,
+        BalanceOf<T>,
+        ValueQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn is_deferred_settlement_on)]
+    /// Whether refunds of ledger charges are deferred to the round end settlement.
+    pub type IsDeferredSettlementOn<T> = StorageValue<_, bool, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn ledger_settlement)]
+    /// Progress of the ledger settlement of the last closed round, until it completes.
+    pub type LedgerSettlement<T> = StorageValue<_, SettlementCursor>;
+
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         #[pallet::weight(T::DbWeight::get().reads(2) + T::DbWeight::get().writes(1))]
@@ -174,6 +236,17 @@ pub mod pallet {
                 <T::Assets as Inspect<T::AccountId>>::AssetId,
             >>::finalize(charge_id, outcome, maybe_recipient, maybe_actual_fees)
         }
+
+        #[pallet::weight(T::DbWeight::get().writes(1))]
+        pub fn set_deferred_settlement(origin: OriginFor<T>, enabled: bool) -> DispatchResult {
+            ensure_root(origin)?;
+
+            IsDeferredSettlementOn::<T>::put(enabled);
+
+            Self::deposit_event(Event::DeferredSettlementSet { enabled });
+
+            Ok(())
+        }
     }
 
     // Pallet implements [`Hooks`] trait to define some logic to execute in some context.
@@ -189,9 +262,25 @@ pub mod pallet {
         //
         // This function must return the weight consumed by `on_initialize` and `on_finalize`.
         fn on_initialize(_n: frame_system::pallet_prelude::BlockNumberFor<T>) -> Weight {
-            // Anything that needs to be done at the start of the block.
-            // We don't do anything here.
-            Weight::zero()
+            // Continue the ledger settlement within the per-block budget, as far as the block
+            // has weight left for it
+            let block_weight_left = T::BlockWeights::get()
+                .max_block
+                .saturating_sub(<frame_system::Pallet<T>>::block_weight().total());
+            Self::settle_ledger(T::SettlementWeightBudget::get().min(block_weight_left))
+        }
+
+        fn integrity_test() {
+            // The settlement only progresses as long as the budget fits settling a single item
+            let settle_item_weight = <T as Config>::WeightInfo::settle_net_credit()
+                .max(<T as Config>::WeightInfo::settle_ledger_entry());
+            assert!(
+                T::DbWeight::get()
+                    .reads_writes(1, 1)
+                    .saturating_add(settle_item_weight)
+                    .all_lte(T::SettlementWeightBudget::get()),
+                "SettlementWeightBudget must fit settling at least one net credit or ledger entry"
+            );
         }
 
         // A runtime code run after every block and have access to extended set of APIs.
@@ -222,6 +311,31 @@ pub mod pallet {
             recipient: Option<T::AccountId>,
             amount: BalanceOf<T>,
         },
+        ProtocolFundsSunk {
+            treasury: TreasuryAccount,
+            amount: BalanceOf<T>,
+            burned: BalanceOf<T>,
+        },
+        XtxChargeNoted {
+            xtx_id: T::Hash,
+            charge_id: T::Hash,
+            debtor: T::AccountId,
+            creditor: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        DeferredSettlementSet {
+            enabled: bool,
+        },
+        DepositOwed {
+            beneficiary: T::AccountId,
+            maybe_asset_id: Option<<T::Assets as Inspect<T::AccountId>>::AssetId>,
+            amount: BalanceOf<T>,
+        },
+        LedgerSettled {
+            round: RoundIndex,
+            transfers: u32,
+            entries: u32,
+        },
     }
 
     #[pallet::error]
@@ -238,6 +352,7 @@ pub mod pallet {
         DecodingExecutionIDFailed,
         TransferDepositFailedOldChargeNotFound,
         TransferDepositFailedToReleasePreviousCharge,
+        ChargeAssetNotFound,
     }
 
     #[pallet::genesis_config]
@@ -1,26 +1,32 @@
 use crate::{
-    AccountManager as AccountManagerExt, BalanceOf, Config, ContractsRegistryExecutionNonce, Error,
-    Outcome, Pallet, PendingCharges, SettlementsPerRound,
+    AccountManager as AccountManagerExt, BalanceOf, ChargeXtx, Config,
+    ContractsRegistryExecutionNonce, Error, Event, IsDeferredSettlementOn, LedgerSettlement,
+    NetCredits, Outcome, Pallet, PendingCharges, SettlementsPerRound, XtxLedger,
 };
 
 use codec::{Decode, Encode};
 use frame_support::{
     dispatch::DispatchResult,
+    pallet_prelude::Weight,
     traits::{fungibles::Inspect, Get},
 };
+use frame_system::pallet_prelude::BlockNumberFor;
 use sp_runtime::{
-    traits::{CheckedAdd, CheckedDiv, CheckedMul, Convert, Zero},
+    traits::{CheckedAdd, CheckedDiv, CheckedMul, Convert, Saturating, Zero},
     ArithmeticError, DispatchError,
 };
 use sp_std::prelude::*;
 
 use t3rn_primitives::{
-    account_manager::{RequestCharge, Settlement},
+    account_manager::{LedgerEntry, RequestCharge, Settlement, SettlementCursor, SettlementPhase},
     claimable::CircuitRole,
-    clock::Clock,
+    clock::{Clock, OnNewRound},
+    common::RoundIndex,
+    insurance::InsuranceFund,
+    TreasuryAccount, TreasuryAccountProvider,
 };
 
-use crate::monetary::Monetary;
+use crate::{monetary::Monetary, weights::WeightInfo};
 use substrate_abi::{SubstrateAbiConverter as Sabi, Value256};
 use t3rn_primitives::{circuit::OrderOrigin, claimable::BenefitSource};
 
@@ -106,6 +112,17 @@ impl<T: Config>
     ) -> Result<BalanceOf<T>, DispatchError> {
         Self::no_charge_or_fail(charge_id).map_err(|_e| Error::<T>::ExecutionAlreadyRegistered)?;
 
+        // Remote origin charges are funded on the remote origin
+        if let Some(asset_id) = request_charge.maybe_asset_id {
+            if OrderOrigin::<T::AccountId>::new(&request_charge.payee).is_local()
+                && !Monetary::<T::AccountId, T::Assets, T::Currency, T::AssetBalanceOf>::asset_exists(
+                    asset_id,
+                )
+            {
+                return Err(Error::<T>::ChargeAssetNotFound.into())
+            }
+        }
+
         let total_reserve_deposit = if let Some(checked_reserve) = request_charge
             .charge_fee
             .checked_add(&request_charge.offered_reward)
@@ -153,7 +170,7 @@ impl<T: Config>
         }
 
         for (charge_id, request_charge, total_deposit) in validated_requests {
-            Self::withdraw_immediately(
+            Self::withdraw_netted(
                 &request_charge.payee,
                 total_deposit,
                 request_charge.maybe_asset_id.clone(),
@@ -207,6 +224,13 @@ impl<T: Config>
 
             let maybe_asset_id = charge.maybe_asset_id.clone();
 
+            let creditor = match outcome {
+                Outcome::Commit => recipient.clone(),
+                Outcome::Slash => T::TreasuryAccounts::get_treasury_account(TreasuryAccount::Slash),
+                Outcome::UnexpectedFailure | Outcome::Revert => charge.payee.clone(),
+            };
+            Self::note_ledger_outcome(charge_id, creditor, outcome.clone());
+
             if charge.offered_reward > Zero::zero() {
                 match outcome {
                     Outcome::Commit => {
@@ -218,11 +242,7 @@ impl<T: Config>
 
                         // Deposit funds out of Escrow executions to recipient account immediately
                         if charge.source == BenefitSource::EscrowUnlock {
-                            Monetary::<T::AccountId, T::Assets, T::Currency, T::AssetBalanceOf>::deposit(
-                                &recipient,
-                                maybe_asset_id,
-                                charge.offered_reward,
-                            );
+                            Self::pay_or_owe(&recipient, maybe_asset_id, charge.offered_reward);
                             return true
                         }
 
@@ -245,14 +265,15 @@ impl<T: Config>
                         );
                     },
                     Outcome::Slash => {
-                        Monetary::<T::AccountId, T::Assets, T::Currency, T::AssetBalanceOf>::deposit(
-                            &T::EscrowAccount::get(),
+                        Self::sink_protocol_funds(
+                            TreasuryAccount::Slash,
                             maybe_asset_id,
                             charge.offered_reward,
                         );
                     },
                     Outcome::UnexpectedFailure | Outcome::Revert => {
-                        Monetary::<T::AccountId, T::Assets, T::Currency, T::AssetBalanceOf>::deposit(
+                        Self::refund(
+                            charge_id,
                             &charge.payee,
                             maybe_asset_id,
                             charge.offered_reward,
@@ -263,8 +284,8 @@ impl<T: Config>
 
             // Take charge fee to treasury
             if charge.charge_fee > Zero::zero() {
-                Monetary::<T::AccountId, T::Assets, T::Currency, T::AssetBalanceOf>::deposit(
-                    &T::EscrowAccount::get(),
+                Self::sink_protocol_funds(
+                    TreasuryAccount::Fee,
                     charge.maybe_asset_id,
                     charge.charge_fee,
                 );
@@ -279,10 +300,12 @@ impl<T: Config>
     fn cancel_deposit(charge_id: T::Hash) -> bool {
         match PendingCharges::<T>::get(charge_id) {
             Some(charge) => {
-                Self::deposit_immediately(
+                Self::note_ledger_outcome(charge_id, charge.payee.clone(), Outcome::Revert);
+                Self::refund(
+                    charge_id,
                     &charge.payee,
-                    charge.offered_reward,
                     charge.maybe_asset_id,
+                    charge.offered_reward,
                 );
                 PendingCharges::<T>::remove(charge_id);
                 true
@@ -291,14 +314,49 @@ impl<T: Config>
         }
     }
 
+    fn note_xtx_charge(xtx_id: T::Hash, charge_id: T::Hash) {
+        if let Some(charge) = PendingCharges::<T>::get(charge_id) {
+            let creditor = charge.recipient.unwrap_or_else(T::EscrowAccount::get);
+            let amount = charge.offered_reward.saturating_add(charge.charge_fee);
+            XtxLedger::<T>::insert(
+                xtx_id,
+                charge_id,
+                LedgerEntry {
+                    debtor: charge.payee.clone(),
+                    creditor: creditor.clone(),
+                    amount,
+                    maybe_asset_id: charge.maybe_asset_id,
+                    role: charge.role,
+                    outcome: None,
+                },
+            );
+            ChargeXtx::<T>::insert(charge_id, xtx_id);
+            Self::deposit_event(Event::XtxChargeNoted {
+                xtx_id,
+                charge_id,
+                debtor: charge.payee,
+                creditor,
+                amount,
+            });
+        }
+    }
+
     fn assign_deposit(charge_id: T::Hash, recipient: &T::AccountId) -> bool {
-        PendingCharges::<T>::mutate(charge_id, |maybe_charge| match maybe_charge {
+        let assigned = PendingCharges::<T>::mutate(charge_id, |maybe_charge| match maybe_charge {
             Some(charge) => {
                 charge.recipient = Some(recipient.clone());
                 true
             },
             None => false,
-        })
+        });
+        if let Some(xtx_id) = ChargeXtx::<T>::get(charge_id) {
+            XtxLedger::<T>::mutate(xtx_id, charge_id, |maybe_entry| {
+                if let Some(entry) = maybe_entry {
+                    entry.creditor = recipient.clone();
+                }
+            });
+        }
+        assigned
     }
 
     fn transfer_deposit(
@@ -375,11 +433,7 @@ impl<T: Config>
         amount: BalanceOf<T>,
         asset_id: Option<<T::Assets as Inspect<T::AccountId>>::AssetId>,
     ) {
-        Monetary::<T::AccountId, T::Assets, T::Currency, T::AssetBalanceOf>::deposit(
-            beneficiary,
-            asset_id,
-            amount,
-        )
+        Self::pay_or_owe(beneficiary, asset_id, amount)
     }
 
     fn withdraw_immediately(
@@ -398,6 +452,226 @@ impl<T: Config>
     }
 }
 
+impl<T: Config> Pallet<T> {
+    /// Withdraw from the payee, first netting the amount against refunds owed to them.
+    fn withdraw_netted(
+        payee: &T::AccountId,
+        amount: BalanceOf<T>,
+        asset_id: Option<<T::Assets as Inspect<T::AccountId>>::AssetId>,
+    ) -> DispatchResult {
+        let credit = NetCredits::<T>::get(payee, asset_id);
+        let netted = credit.min(amount);
+        let to_withdraw = amount.saturating_sub(netted);
+        if to_withdraw > Zero::zero() {
+            <Self as AccountManagerExt<
+                T::AccountId,
+                BalanceOf<T>,
+                T::Hash,
+                BlockNumberFor<T>,
+                <T::Assets as Inspect<T::AccountId>>::AssetId,
+            >>::withdraw_immediately(payee, to_withdraw, asset_id)?;
+        }
+        if netted > Zero::zero() {
+            if credit == netted {
+                NetCredits::<T>::remove(payee, asset_id);
+            } else {
+                NetCredits::<T>::insert(payee, asset_id, credit.saturating_sub(netted));
+            }
+        }
+        Ok(())
+    }
+
+    /// Deposit to the beneficiary in native currency or the charge's asset. Deposits that can't
+    /// be credited yet, e.g. below the asset's minimum balance, are owed until the round end
+    /// settlement instead of being lost.
+    fn pay_or_owe(
+        beneficiary: &T::AccountId,
+        asset_id: Option<<T::Assets as Inspect<T::AccountId>>::AssetId>,
+        amount: BalanceOf<T>,
+    ) {
+        if let Err(err) =
+            Monetary::<T::AccountId, T::Assets, T::Currency, T::AssetBalanceOf>::try_deposit(
+                beneficiary,
+                asset_id,
+                amount,
+            )
+        {
+            log::warn!(
+                "AccountManager: deposit of {:?} in {:?} owed until settlement: {:?}",
+                amount,
+                asset_id,
+                err
+            );
+            NetCredits::<T>::mutate(beneficiary, asset_id, |credit| {
+                *credit = credit.saturating_add(amount)
+            });
+            Self::deposit_event(Event::DepositOwed {
+                beneficiary: beneficiary.clone(),
+                maybe_asset_id: asset_id,
+                amount,
+            });
+        }
+    }
+
+    /// Refund the charge's payee. Refunds of ledger charges are owed until the round end
+    /// settlement while deferred settlement is on.
+    fn refund(
+        charge_id: T::Hash,
+        beneficiary: &T::AccountId,
+        asset_id: Option<<T::Assets as Inspect<T::AccountId>>::AssetId>,
+        amount: BalanceOf<T>,
+    ) {
+        if IsDeferredSettlementOn::<T>::get() && ChargeXtx::<T>::contains_key(charge_id) {
+            NetCredits::<T>::mutate(beneficiary, asset_id, |credit| {
+                *credit = credit.saturating_add(amount)
+            });
+        } else {
+            Self::pay_or_owe(beneficiary, asset_id, amount);
+        }
+    }
+
+    fn note_ledger_outcome(charge_id: T::Hash, creditor: T::AccountId, outcome: Outcome) {
+        if let Some(xtx_id) = ChargeXtx::<T>::get(charge_id) {
+            XtxLedger::<T>::mutate(xtx_id, charge_id, |maybe_entry| {
+                if let Some(entry) = maybe_entry {
+                    entry.creditor = creditor;
+                    entry.outcome = Some(outcome);
+                }
+            });
+        }
+    }
+
+    /// Open the ledger settlement of the closed round, unless the previous one is still in
+    /// progress. In that case the charges finalized meanwhile are settled along with it.
+    pub fn open_settlement(round: RoundIndex) -> Weight {
+        if LedgerSettlement::<T>::exists() {
+            return T::DbWeight::get().reads(1)
+        }
+        LedgerSettlement::<T>::put(SettlementCursor {
+            round,
+            phase: SettlementPhase::Credits,
+            last_key: None,
+            transfers: 0,
+            entries: 0,
+        });
+        T::DbWeight::get().reads_writes(1, 1)
+    }
+
+    /// Continue the open ledger settlement within the weight budget, from where the previous
+    /// block left off: pay out the net credits, then prune the finalized ledger entries.
+    /// Credits the beneficiaries can't receive yet are retried at the next settlement.
+    pub fn settle_ledger(budget: Weight) -> Weight {
+        let mut consumed = T::DbWeight::get().reads(1);
+        let mut cursor = match LedgerSettlement::<T>::get() {
+            Some(cursor) => cursor,
+            None => return consumed,
+        };
+        consumed = consumed.saturating_add(T::DbWeight::get().writes(1));
+
+        if cursor.phase == SettlementPhase::Credits {
+            let credit_weight = <T as Config>::WeightInfo::settle_net_credit();
+            let mut credits = match cursor.last_key.take() {
+                Some(last_key) => NetCredits::<T>::iter_from(last_key),
+                None => NetCredits::<T>::iter(),
+            };
+            loop {
+                if consumed.saturating_add(credit_weight).any_gt(budget) {
+                    cursor.last_key = Some(credits.last_raw_key().to_vec());
+                    LedgerSettlement::<T>::put(cursor);
+                    return consumed
+                }
+                let Some((beneficiary, asset_id, amount)) = credits.next() else {
+                    break
+                };
+                consumed = consumed.saturating_add(credit_weight);
+                if amount.is_zero() {
+                    NetCredits::<T>::remove(&beneficiary, asset_id);
+                    continue
+                }
+                if Monetary::<T::AccountId, T::Assets, T::Currency, T::AssetBalanceOf>::try_deposit(
+                    &beneficiary,
+                    asset_id,
+                    amount,
+                )
+                .is_ok()
+                {
+                    NetCredits::<T>::remove(&beneficiary, asset_id);
+                    cursor.transfers += 1;
+                }
+            }
+            cursor.phase = SettlementPhase::Ledger;
+        }
+
+        let entry_weight = <T as Config>::WeightInfo::settle_ledger_entry();
+        let mut entries = match cursor.last_key.take() {
+            Some(last_key) => XtxLedger::<T>::iter_from(last_key),
+            None => XtxLedger::<T>::iter(),
+        };
+        loop {
+            if consumed.saturating_add(entry_weight).any_gt(budget) {
+                cursor.last_key = Some(entries.last_raw_key().to_vec());
+                LedgerSettlement::<T>::put(cursor);
+                return consumed
+            }
+            let Some((xtx_id, charge_id, entry)) = entries.next() else {
+                break
+            };
+            consumed = consumed.saturating_add(entry_weight);
+            if entry.outcome.is_some() {
+                XtxLedger::<T>::remove(xtx_id, charge_id);
+                ChargeXtx::<T>::remove(charge_id);
+                cursor.entries += 1;
+            }
+        }
+
+        LedgerSettlement::<T>::kill();
+        if cursor.transfers > 0 || cursor.entries > 0 {
+            Self::deposit_event(Event::LedgerSettled {
+                round: cursor.round,
+                transfers: cursor.transfers,
+                entries: cursor.entries,
+            });
+        }
+
+        consumed
+    }
+
+    /// Pay protocol funds to the given treasury, burning the `ProtocolBurn` portion. Native
+    /// fees also capitalize the insurance fund with its fee share of the unburned rest.
+    pub fn sink_protocol_funds(
+        treasury: TreasuryAccount,
+        asset_id: Option<<T::Assets as Inspect<T::AccountId>>::AssetId>,
+        amount: BalanceOf<T>,
+    ) {
+        let burned = T::ProtocolBurn::get().mul_floor(amount);
+        let mut to_treasury = amount.saturating_sub(burned);
+        if treasury == TreasuryAccount::Fee && asset_id.is_none() {
+            let insured = T::InsuranceFund::fee_share().mul_floor(to_treasury);
+            T::InsuranceFund::capitalize(insured);
+            to_treasury = to_treasury.saturating_sub(insured);
+        }
+        if to_treasury > Zero::zero() {
+            Self::pay_or_owe(
+                &T::TreasuryAccounts::get_treasury_account(treasury.clone()),
+                asset_id,
+                to_treasury,
+            );
+        }
+        Self::deposit_event(Event::ProtocolFundsSunk {
+            treasury,
+            amount: to_treasury,
+            burned,
+        });
+    }
+}
+
+impl<T: Config> OnNewRound<BlockNumberFor<T>> for Pallet<T> {
+    fn on_new_round(index: RoundIndex, _head: BlockNumberFor<T>) -> Weight {
+        // Settle the charges of the round that just closed over the following blocks
+        Self::open_settlement(index.saturating_sub(1))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -405,7 +679,7 @@ mod tests {
 
     use frame_support::{assert_err, assert_ok};
 
-    pub use frame_support::traits::Currency;
+    pub use frame_support::traits::{fungibles::Mutate, Currency};
 
     use sp_core::H256;
     use t3rn_primitives::{
@@ -862,4 +1136,300 @@ mod tests {
             assert_eq!(settlement, None);
         });
     }
+
+    #[test]
+    fn test_finalize_slash_sinks_reward_and_fee_to_treasuries_minus_burn() {
+        ExtBuilder::default().build().execute_with(|| {
+            let _ = Balances::deposit_creating(&ALICE, DEFAULT_BALANCE);
+            let slash_treasury = Runtime::get_treasury_account(TreasuryAccount::Slash);
+            let fee_treasury = Runtime::get_treasury_account(TreasuryAccount::Fee);
+            let slash_treasury_balance = Balances::free_balance(&slash_treasury);
+            let fee_treasury_balance = Balances::free_balance(&fee_treasury);
+            let total_issuance = Balances::total_issuance();
+            let execution_id: H256 = H256::repeat_byte(0);
+
+            assert_ok!(<AccountManager as AccountManagerExt<
+                AccountId,
+                Balance,
+                Hash,
+                BlockNumber,
+                AssetId,
+            >>::deposit(
+                execution_id,
+                RequestCharge {
+                    payee: ALICE,
+                    offered_reward: 100,
+                    charge_fee: 10,
+                    source: BenefitSource::TrafficRewards,
+                    role: CircuitRole::Executor,
+                    recipient: Some(BOB),
+                    maybe_asset_id: None
+                }
+            ));
+
+            assert_ok!(<AccountManager as AccountManagerExt<
+                AccountId,
+                Balance,
+                Hash,
+                BlockNumber,
+                AssetId,
+            >>::finalize(
+                execution_id, Outcome::Slash, None, None,
+            ));
+
+            // ProtocolBurn = 50% of both the slashed reward and the charge fee
+            assert_eq!(
+                Balances::free_balance(&slash_treasury),
+                slash_treasury_balance + 50
+            );
+            assert_eq!(
+                Balances::free_balance(&fee_treasury),
+                fee_treasury_balance + 5
+            );
+            assert_eq!(Balances::total_issuance(), total_issuance - 55);
+        });
+    }
+
+    #[test]
+    fn test_deferred_refunds_are_netted_against_deposits_and_settled_at_round_end() {
+        ExtBuilder::default().build().execute_with(|| {
+            System::set_block_number(1);
+            let _ = Balances::deposit_creating(&ALICE, DEFAULT_BALANCE);
+            let xtx_id: H256 = H256::repeat_byte(1);
+            let first_charge_id: H256 = H256::repeat_byte(2);
+            let second_charge_id: H256 = H256::repeat_byte(3);
+            let charge = |offered_reward: Balance| RequestCharge {
+                payee: ALICE,
+                offered_reward,
+                charge_fee: 0,
+                source: BenefitSource::TrafficFees,
+                role: CircuitRole::Executor,
+                recipient: None,
+                maybe_asset_id: None,
+            };
+
+            assert_ok!(AccountManager::set_deferred_settlement(
+                RuntimeOrigin::root(),
+                true
+            ));
+
+            assert_ok!(<AccountManager as AccountManagerExt<
+                AccountId,
+                Balance,
+                Hash,
+                BlockNumber,
+                AssetId,
+            >>::deposit(first_charge_id, charge(100)));
+            <AccountManager as AccountManagerExt<
+                AccountId,
+                Balance,
+                Hash,
+                BlockNumber,
+                AssetId,
+            >>::note_xtx_charge(xtx_id, first_charge_id);
+            assert_eq!(
+                AccountManager::xtx_ledger(xtx_id, first_charge_id),
+                Some(LedgerEntry {
+                    debtor: ALICE,
+                    creditor: <Runtime as Config>::EscrowAccount::get(),
+                    amount: 100,
+                    maybe_asset_id: None,
+                    role: CircuitRole::Executor,
+                    outcome: None,
+                })
+            );
+
+            // The refund is owed instead of paid out immediately
+            assert!(<AccountManager as AccountManagerExt<
+                AccountId,
+                Balance,
+                Hash,
+                BlockNumber,
+                AssetId,
+            >>::finalize_infallible(
+                first_charge_id, Outcome::Revert
+            ));
+            assert_eq!(Balances::free_balance(&ALICE), DEFAULT_BALANCE - 100);
+            assert_eq!(AccountManager::net_credits(ALICE, None::<AssetId>), 100);
+            assert_eq!(
+                AccountManager::xtx_ledger(xtx_id, first_charge_id).map(|entry| entry.outcome),
+                Some(Some(Outcome::Revert))
+            );
+
+            // The next deposit is netted against the owed refund
+            assert_ok!(<AccountManager as AccountManagerExt<
+                AccountId,
+                Balance,
+                Hash,
+                BlockNumber,
+                AssetId,
+            >>::deposit(second_charge_id, charge(60)));
+            <AccountManager as AccountManagerExt<
+                AccountId,
+                Balance,
+                Hash,
+                BlockNumber,
+                AssetId,
+            >>::note_xtx_charge(xtx_id, second_charge_id);
+            assert_eq!(Balances::free_balance(&ALICE), DEFAULT_BALANCE - 100);
+            assert_eq!(AccountManager::net_credits(ALICE, None::<AssetId>), 40);
+
+            AccountManager::on_new_round(2, 300);
+            AccountManager::settle_ledger(Weight::MAX);
+
+            assert_eq!(Balances::free_balance(&ALICE), DEFAULT_BALANCE - 60);
+            assert_eq!(AccountManager::net_credits(ALICE, None::<AssetId>), 0);
+            // Only the pending charge remains on the ledger
+            assert_eq!(AccountManager::xtx_ledger(xtx_id, first_charge_id), None);
+            assert!(AccountManager::xtx_ledger(xtx_id, second_charge_id).is_some());
+            System::assert_last_event(RuntimeEvent::AccountManager(crate::Event::LedgerSettled {
+                round: 1,
+                transfers: 1,
+                entries: 1,
+            }));
+        });
+    }
+
+    #[test]
+    fn test_asset_charges_are_paid_in_asset_or_owed_until_the_beneficiary_can_receive_them() {
+        ExtBuilder::default().build().execute_with(|| {
+            System::set_block_number(1);
+            const ASSET: AssetId = 7;
+            const MIN_BALANCE: Balance = 100;
+            assert_ok!(Assets::force_create(
+                RuntimeOrigin::root(),
+                ASSET,
+                sp_runtime::MultiAddress::Id(ALICE), /* owner */
+                true,                                /* is_sufficient */
+                MIN_BALANCE
+            ));
+            assert_ok!(Assets::mint_into(ASSET, &ALICE, DEFAULT_BALANCE));
+            let charge = |maybe_asset_id: Option<AssetId>| RequestCharge {
+                payee: ALICE,
+                offered_reward: 50,
+                charge_fee: 0,
+                source: BenefitSource::EscrowUnlock,
+                role: CircuitRole::Executor,
+                recipient: Some(BOB),
+                maybe_asset_id,
+            };
+
+            assert_err!(
+                <AccountManager as AccountManagerExt<
+                    AccountId,
+                    Balance,
+                    Hash,
+                    BlockNumber,
+                    AssetId,
+                >>::deposit(H256::repeat_byte(1), charge(Some(ASSET + 1))),
+                Error::<Runtime>::ChargeAssetNotFound
+            );
+
+            let charge_id: H256 = H256::repeat_byte(2);
+            assert_ok!(<AccountManager as AccountManagerExt<
+                AccountId,
+                Balance,
+                Hash,
+                BlockNumber,
+                AssetId,
+            >>::deposit(charge_id, charge(Some(ASSET))));
+            assert_eq!(Assets::balance(ASSET, ALICE), DEFAULT_BALANCE - 50);
+
+            // BOB can't hold less than the asset's minimum balance, so the payout is owed
+            assert!(<AccountManager as AccountManagerExt<
+                AccountId,
+                Balance,
+                Hash,
+                BlockNumber,
+                AssetId,
+            >>::finalize_infallible(
+                charge_id, Outcome::Commit
+            ));
+            assert_eq!(Assets::balance(ASSET, BOB), 0);
+            assert_eq!(AccountManager::net_credits(BOB, Some(ASSET)), 50);
+
+            AccountManager::on_new_round(2, 300);
+            AccountManager::settle_ledger(Weight::MAX);
+            assert_eq!(AccountManager::net_credits(BOB, Some(ASSET)), 50);
+
+            assert_ok!(Assets::mint_into(ASSET, &BOB, MIN_BALANCE));
+            AccountManager::on_new_round(3, 600);
+            AccountManager::settle_ledger(Weight::MAX);
+            assert_eq!(Assets::balance(ASSET, BOB), MIN_BALANCE + 50);
+            assert_eq!(AccountManager::net_credits(BOB, Some(ASSET)), 0);
+        });
+    }
+
+    #[test]
+    fn test_ledger_settlement_continues_across_blocks_within_the_weight_budget() {
+        ExtBuilder::default().build().execute_with(|| {
+            System::set_block_number(1);
+            let _ = Balances::deposit_creating(&ALICE, DEFAULT_BALANCE);
+            let _ = Balances::deposit_creating(&BOB, DEFAULT_BALANCE);
+            let (alice_before, bob_before) =
+                (Balances::free_balance(&ALICE), Balances::free_balance(&BOB));
+            NetCredits::<Runtime>::insert(ALICE, None::<AssetId>, 10);
+            NetCredits::<Runtime>::insert(BOB, None::<AssetId>, 20);
+
+            // Nothing to settle until a round closes
+            AccountManager::settle_ledger(Weight::MAX);
+            assert_eq!(NetCredits::<Runtime>::iter().count(), 2);
+
+            AccountManager::on_new_round(2, 300);
+            let one_credit_budget = <Runtime as frame_system::Config>::DbWeight::get()
+                .reads_writes(1, 1)
+                .saturating_add(<() as crate::weights::WeightInfo>::settle_net_credit());
+
+            AccountManager::settle_ledger(one_credit_budget);
+            assert_eq!(NetCredits::<Runtime>::iter().count(), 1);
+            assert!(AccountManager::ledger_settlement().is_some());
+
+            AccountManager::settle_ledger(one_credit_budget);
+            assert_eq!(NetCredits::<Runtime>::iter().count(), 0);
+            assert_eq!(Balances::free_balance(&ALICE), alice_before + 10);
+            assert_eq!(Balances::free_balance(&BOB), bob_before + 20);
+
+            AccountManager::settle_ledger(one_credit_budget);
+            assert_eq!(AccountManager::ledger_settlement(), None);
+            System::assert_last_event(RuntimeEvent::AccountManager(crate::Event::LedgerSettled {
+                round: 1,
+                transfers: 2,
+                entries: 0,
+            }));
+        });
+    }
+
+    #[test]
+    fn test_ledger_settlement_on_initialize_is_bounded_by_the_block_weight_left() {
+        use frame_support::{dispatch::DispatchClass, traits::Hooks};
+
+        ExtBuilder::default().build().execute_with(|| {
+            System::set_block_number(1);
+            NetCredits::<Runtime>::insert(ALICE, None::<AssetId>, 10);
+            AccountManager::on_new_round(2, 300);
+
+            // Leave less weight in the block than settling the credit takes
+            let max_block = <Runtime as frame_system::Config>::BlockWeights::get().max_block;
+            let one_credit_weight = <Runtime as frame_system::Config>::DbWeight::get()
+                .reads_writes(1, 1)
+                .saturating_add(<() as crate::weights::WeightInfo>::settle_net_credit());
+            System::register_extra_weight_unchecked(
+                max_block
+                    .saturating_sub(one_credit_weight.saturating_sub(Weight::from_parts(1, 0))),
+                DispatchClass::Mandatory,
+            );
+
+            let consumed = AccountManager::on_initialize(1);
+            assert!(consumed.ref_time() < one_credit_weight.ref_time());
+            assert_eq!(NetCredits::<Runtime>::iter().count(), 1);
+            assert!(AccountManager::ledger_settlement().is_some());
+        });
+    }
+
+    #[test]
+    fn test_settlement_weight_budget_fits_settling_a_single_item() {
+        use frame_support::traits::Hooks;
+
+        AccountManager::integrity_test();
+    }
 }
@@ -4,14 +4,14 @@ use frame_support::{
     pallet_prelude::*,
     traits::{
         tokens::{fungibles::Unbalanced, WithdrawConsequence},
-        ExistenceRequirement, ReservableCurrency, WithdrawReasons,
+        ExistenceRequirement, Imbalance, ReservableCurrency, WithdrawReasons,
     },
 };
 
 use frame_support::traits::tokens::{
     Fortitude::Polite, Precision::Exact, Preservation::Expendable,
 };
-use sp_runtime::traits::Convert;
+use sp_runtime::traits::{Convert, Zero};
 
 pub struct Monetary<AccountId, Assets, NativeCurrency, AssetBalanceOf>(
     PhantomData<(AccountId, Assets, NativeCurrency, AssetBalanceOf)>,
@@ -28,21 +28,45 @@ impl<
         asset_id: Option<Assets::AssetId>,
         amount: NativeCurrency::Balance,
     ) {
+        if let Err(e) = Self::try_deposit(beneficiary, asset_id, amount) {
+            log::error!("Monetary::deposit failed with {:?}", e);
+        }
+    }
+
+    /// Deposit to the beneficiary, failing if the funds can't be credited in full, e.g. when
+    /// the amount is below the asset's minimum balance for a new account.
+    pub fn try_deposit(
+        beneficiary: &AccountId,
+        asset_id: Option<Assets::AssetId>,
+        amount: NativeCurrency::Balance,
+    ) -> DispatchResult {
+        if amount.is_zero() {
+            return Ok(())
+        }
         match asset_id {
             None => {
-                NativeCurrency::deposit_creating(beneficiary, amount);
-            },
-            Some(asset_id) => {
-                Assets::increase_balance(
-                    asset_id,
-                    beneficiary,
-                    AssetBalanceOf::convert(amount),
-                    Exact,
-                );
+                if NativeCurrency::deposit_creating(beneficiary, amount)
+                    .peek()
+                    .is_zero()
+                {
+                    return Err(DispatchError::Token(sp_runtime::TokenError::BelowMinimum))
+                }
+                Ok(())
             },
+            Some(asset_id) => Assets::increase_balance(
+                asset_id,
+                beneficiary,
+                AssetBalanceOf::convert(amount),
+                Exact,
+            )
+            .map(|_| ()),
         }
     }
 
+    pub fn asset_exists(asset_id: Assets::AssetId) -> bool {
+        Assets::asset_exists(asset_id)
+    }
+
     pub fn can_withdraw(
         beneficiary: &AccountId,
         asset_id: Option<Assets::AssetId>,
@@ -106,6 +106,9 @@ where
 /// author-aware. This needs to be a macro since the injection site can only be at the runtime, we don't want to import
 /// pallet-balances or pallet-transaction-payment, since these traits are not available in frame_support and
 /// only in their respective pallets.
+///
+/// Fees of sponsored Xtx submissions are withdrawn from, and refunded to, the sponsor as long as
+/// the voucher would be redeemed, rather than from the signer.
 #[macro_export]
 macro_rules! setup_currency_adapter {
     () => {
@@ -115,16 +118,21 @@ macro_rules! setup_currency_adapter {
         use codec::Decode;
 
         pub struct AccountManagerCurrencyAdapter<C, OU>(sp_std::marker::PhantomData<(C, OU)>);
+        // Beneficiary of the fees, fees paid and the sponsor they were paid by, if not the signer
         type AccountImbalanceLiquidityInfo<T, C> = (
             Option<<T as frame_system::Config>::AccountId>,
             Option<pallet_account_manager::transaction::NegativeImbalanceOf<T, C>>,
+            Option<<T as frame_system::Config>::AccountId>,
         );
 
         impl<T, C, OU> pallet_transaction_payment::OnChargeTransaction<T>
             for AccountManagerCurrencyAdapter<C, OU>
         where
-            T: pallet_transaction_payment::Config + pallet_account_manager::Config<Currency = C>,
-            <T as frame_system::Config>::RuntimeCall: IsSubType<pallet_3vm_contracts::Call<Runtime>>,
+            T: pallet_transaction_payment::Config
+                + pallet_account_manager::Config<Currency = C>
+                + pallet_circuit::Config,
+            <T as frame_system::Config>::RuntimeCall: IsSubType<pallet_3vm_contracts::Call<Runtime>>
+                + IsSubType<pallet_circuit::Call<T>>,
             C: frame_support::traits::Currency<<T as frame_system::Config>::AccountId>,
             C::PositiveImbalance: Imbalance<
                 <C as frame_support::traits::Currency<<T as frame_system::Config>::AccountId>>::Balance,
@@ -149,20 +157,38 @@ macro_rules! setup_currency_adapter {
             ) -> Result<Self::LiquidityInfo, frame_support::pallet_prelude::TransactionValidityError> {
                 let call: &<T as frame_system::Config>::RuntimeCall = call;
 
+                let sponsor = match <_ as IsSubType<pallet_circuit::Call<T>>>::is_sub_type(call) {
+                    Some(pallet_circuit::Call::on_sponsored_extrinsic_trigger {
+                        side_effects,
+                        voucher,
+                        signature,
+                        ..
+                    }) => pallet_circuit::sponsor::Sponsor::<T>::ensure_redeemable(
+                        voucher,
+                        signature,
+                        who,
+                        side_effects,
+                    )
+                    .ok(),
+                    _ => None,
+                };
+
                 let result = <CurrencyAdapter<C, OU> as pallet_transaction_payment::OnChargeTransaction<T>>::withdraw_fee(
-                    who, call, info, fee, tip,
+                    sponsor.as_ref().unwrap_or(who), call, info, fee, tip,
                 );
 
-                if let Some(pallet_3vm_contracts::Call::call { dest, .. }) = call.is_sub_type() {
+                if let Some(pallet_3vm_contracts::Call::call { dest, .. }) =
+                    <_ as IsSubType<pallet_3vm_contracts::Call<Runtime>>>::is_sub_type(call)
+                {
                     if let Some(author) = ThreeVm::get_author(dest) {
                         return result.map(|info| {
                             let opaque_author = T::AccountId::decode(&mut author.as_ref()).ok();
-                            (opaque_author, info)
+                            (opaque_author, info, sponsor)
                         });
                     }
                 }
 
-                result.map(|info| (None, info))
+                result.map(|info| (None, info, sponsor))
             }
 
             // Largely, this is a copy of pallet-tx-payment except the part at the end where we check
@@ -175,13 +201,14 @@ macro_rules! setup_currency_adapter {
                 tip: Self::Balance,
                 already_withdrawn: Self::LiquidityInfo,
             ) -> Result<(), frame_support::pallet_prelude::TransactionValidityError> {
-                if let (beneficiary, Some(paid)) = already_withdrawn {
+                if let (beneficiary, Some(paid), sponsor) = already_withdrawn {
                     // Calculate how much refund we should return
                     let refund_amount = paid.peek().saturating_sub(corrected_fee);
                     // refund to the the account that paid the fees. If this fails, the
                     // account might have dropped below the existential balance. In
                     // that case we don't refund anything.
-                    let refund_imbalance = C::deposit_into_existing(who, refund_amount)
+                    let refund_imbalance =
+                        C::deposit_into_existing(sponsor.as_ref().unwrap_or(who), refund_amount)
                         .unwrap_or_else(|_| C::PositiveImbalance::zero());
                     // merge the imbalance caused by paying the fees and refunding parts of it again.
                     let adjusted_paid = paid.offset(refund_imbalance).same().map_err(|_| {
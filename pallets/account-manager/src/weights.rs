@@ -9,12 +9,39 @@ use frame_support::{
 };
 use sp_std::marker::PhantomData;
 
-/// Weight functions needed for pallet_xdns.
-pub trait WeightInfo {}
+/// Weight functions needed for pallet_account_manager.
+pub trait WeightInfo {
+    fn settle_net_credit() -> Weight;
+    fn settle_ledger_entry() -> Weight;
+}
 
-/// Weights for pallet_xdns using the Substrate node and recommended hardware.
+/// Weights for pallet_account_manager using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
-impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {}
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+    fn settle_net_credit() -> Weight {
+        Weight::from_parts(32_120_000_u64, 0u64)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+
+    fn settle_ledger_entry() -> Weight {
+        Weight::from_parts(11_405_000_u64, 0u64)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+}
 
 // For backwards compatibility and tests
-impl WeightInfo for () {}
+impl WeightInfo for () {
+    fn settle_net_credit() -> Weight {
+        Weight::from_parts(32_120_000_u64, 0u64)
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
+
+    fn settle_ledger_entry() -> Weight {
+        Weight::from_parts(11_405_000_u64, 0u64)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+}
@@ -2,6 +2,7 @@
 
 #[cfg(feature = "runtime-benchmarks")]
 pub mod benchmarking;
+pub mod weights;
 
 pub use crate::pallet::*;
 
@@ -17,8 +18,11 @@ pub mod pallet {
     const TWO_EPOCHS_IN_LOCAL_BLOCKS_U8: u8 = 2 * 32;
     const ONE_EPOCHS_IN_LOCAL_BLOCKS_U8: u8 = 32;
     pub const REWARD_ADJUSTMENT: Percent = Percent::from_percent(25);
+    // Batching windows whose batches roots are kept for destination chains to prove against.
+    pub const MAX_BATCHES_ROOTS: usize = 256;
 
     use super::*;
+    use crate::weights::WeightInfo;
     t3rn_primitives::reexport_currency_types!();
     use t3rn_primitives::ExecutionSource;
     use tiny_keccak::{Hasher, Keccak};
@@ -27,7 +31,10 @@ pub mod pallet {
     use frame_support::{
         dispatch::DispatchResult,
         pallet_prelude::*,
-        traits::{Currency, ExistenceRequirement, GenesisBuild, Randomness, ReservableCurrency},
+        traits::{
+            BalanceStatus, Currency, ExistenceRequirement, GenesisBuild, Randomness,
+            ReservableCurrency,
+        },
     };
     use frame_system::pallet_prelude::{BlockNumberFor, *};
     use sp_core::{hexdisplay::AsBytesRef, H160, H256, H512};
@@ -52,8 +59,10 @@ pub mod pallet {
         ECDSA_ATTESTER_KEY_TYPE_ID, ED25519_ATTESTER_KEY_TYPE_ID, SR25519_ATTESTER_KEY_TYPE_ID,
     };
     use t3rn_primitives::{
-        attesters::{CommitteeRecoverable, CommitteeTransition},
+        attesters::{ecdsa_pubkey_to_eth_address, CommitteeRecoverable, CommitteeTransition},
         circuit::{Cause, CircuitStatus, ReadSFX},
+        clock::OnNewRound,
+        common::RoundIndex,
         portal::Portal,
         rewards::RewardsWriteApi,
         xdns::Xdns,
@@ -87,6 +96,15 @@ pub mod pallet {
         pub halt: bool,
     }
 
+    /// Root over the batches sealed across all targets in a batching window, signed by the
+    /// committee alike the batches themselves.
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+    pub struct SealedBatchesRoot {
+        pub root: H256,
+        pub sealed_batches: Vec<(TargetId, u32, H256)>,
+        pub signatures: Vec<(u32, Signature65b)>,
+    }
+
     #[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
     pub struct InfluxMessage<BlockNumber> {
         pub message_hash: H256,
@@ -117,6 +135,8 @@ pub mod pallet {
     }
     // Add the following method to `BatchMessage` struct
     impl<BlockNumber> BatchMessage<BlockNumber> {
+        /// Message signed by the committee: the next and banned committee addresses as 32-byte
+        /// words, followed by the SFX outcomes root if the batch carries any SFX, and the index.
         pub fn message(&self) -> Vec<u8> {
             let mut encoded_message = Vec::new();
 
@@ -149,15 +169,8 @@ pub mod pallet {
             if let Some(ref committee) = self.banned_committee {
                 encode_eth_committee_addresses_into_message(committee);
             }
-            if let Some(ref sfx_vec) = self.committed_sfx {
-                for sfx in sfx_vec.iter() {
-                    encoded_message.extend_from_slice(sfx.as_bytes());
-                }
-            }
-            if let Some(ref sfx_vec) = self.reverted_sfx {
-                for sfx in sfx_vec.iter() {
-                    encoded_message.extend_from_slice(sfx.as_bytes());
-                }
+            if self.read_batching_factor() > 0 {
+                encoded_message.extend_from_slice(self.sfx_outcomes_root().as_bytes());
             }
             encoded_message.extend_from_slice(self.index.to_be_bytes().as_slice());
             encoded_message
@@ -193,6 +206,155 @@ pub mod pallet {
 
             batching_factor
         }
+
+        /// Leaves committing to the outcome of each SFX in the batch, committed ones first, as
+        /// `keccak256(bytes.concat(keccak256(abi.encode(sfx_id, is_committed))))` - the leaves of
+        /// OpenZeppelin's standard Merkle tree.
+        pub fn sfx_outcome_leaves(&self) -> Vec<(H256, H256)> {
+            let committed = self
+                .committed_sfx
+                .iter()
+                .flatten()
+                .map(|sfx| (H256::from_slice(&sfx.as_bytes()[..32]), true));
+            let reverted = self
+                .reverted_sfx
+                .iter()
+                .flatten()
+                .map(|sfx_id| (*sfx_id, false));
+
+            committed
+                .chain(reverted)
+                .map(|(sfx_id, is_committed)| {
+                    let mut encoded = [0u8; 64];
+                    encoded[..32].copy_from_slice(sfx_id.as_bytes());
+                    encoded[63] = is_committed as u8;
+                    (sfx_id, keccak_256(&keccak_256(&encoded)))
+                })
+                .collect()
+        }
+
+        /// Merkle root of the SFX outcomes in the batch, which Ethereum contracts imply once out of
+        /// the attested batch to then settle each SFX, i.e. release its escrow, against a proof.
+        pub fn sfx_outcomes_root(&self) -> H256 {
+            let leaves: Vec<H256> = self
+                .sfx_outcome_leaves()
+                .into_iter()
+                .map(|(_, leaf)| leaf)
+                .collect();
+            merkle_root(leaves)
+        }
+
+        /// Proof of the outcome of `sfx_id` against `sfx_outcomes_root`, verifiable with
+        /// OpenZeppelin's `MerkleProof.verify`. Returns whether the SFX was committed.
+        pub fn sfx_outcome_proof(&self, sfx_id: H256) -> Option<(bool, Vec<H256>)> {
+            let leaves = self.sfx_outcome_leaves();
+            let index = leaves.iter().position(|(id, _)| *id == sfx_id)?;
+            let is_committed = self
+                .committed_sfx
+                .as_ref()
+                .map_or(false, |committed| index < committed.len());
+
+            let proof = merkle_proof(leaves.into_iter().map(|(_, leaf)| leaf).collect(), index);
+            Some((is_committed, proof))
+        }
+    }
+
+    fn keccak_256(data: &[u8]) -> H256 {
+        let mut keccak = Keccak::v256();
+        keccak.update(data);
+        let mut res: [u8; 32] = [0; 32];
+        keccak.finalize(&mut res);
+        H256::from(res)
+    }
+
+    /// Levels of a Merkle tree built out of `leaves`, hashing sorted pairs of nodes.
+    fn merkle_levels(leaves: Vec<H256>) -> Vec<Vec<H256>> {
+        if leaves.is_empty() {
+            return vec![]
+        }
+        let mut levels = vec![leaves];
+        while let Some(level) = levels.last().filter(|level| level.len() > 1) {
+            let next_level = level
+                .chunks(2)
+                .map(|pair| match pair {
+                    [a, b] => {
+                        let (low, high) = if a <= b { (a, b) } else { (b, a) };
+                        keccak_256(&[low.as_bytes(), high.as_bytes()].concat())
+                    },
+                    [single] => *single,
+                    _ => unreachable!("chunks of 2 are never empty; qed"),
+                })
+                .collect();
+            levels.push(next_level);
+        }
+        levels
+    }
+
+    fn merkle_root(leaves: Vec<H256>) -> H256 {
+        merkle_levels(leaves)
+            .last()
+            .and_then(|root| root.first().copied())
+            .unwrap_or_default()
+    }
+
+    fn merkle_proof(leaves: Vec<H256>, mut index: usize) -> Vec<H256> {
+        let levels = merkle_levels(leaves);
+        let mut proof = Vec::new();
+        for level in levels.iter().take(levels.len().saturating_sub(1)) {
+            // Nodes left without a sibling are carried up to the next level as they are
+            if let Some(sibling) = level.get(index ^ 1) {
+                proof.push(*sibling);
+            }
+            index /= 2;
+        }
+        proof
+    }
+
+    /// Leaves of the batches sealed within a batching window, as
+    /// `keccak256(bytes.concat(keccak256(abi.encode(target, message_hash))))` with the target
+    /// encoded as `bytes4`.
+    pub fn batches_root_leaves(sealed_batches: &[(TargetId, u32, H256)]) -> Vec<H256> {
+        sealed_batches
+            .iter()
+            .map(|(target, _index, message_hash)| {
+                let mut encoded = [0u8; 64];
+                encoded[..4].copy_from_slice(target);
+                encoded[32..].copy_from_slice(message_hash.as_bytes());
+                keccak_256(keccak_256(&encoded).as_bytes())
+            })
+            .collect()
+    }
+
+    pub fn batches_root(sealed_batches: &[(TargetId, u32, H256)]) -> H256 {
+        merkle_root(batches_root_leaves(sealed_batches))
+    }
+
+    /// Root of the committee at `addresses`, with leaves
+    /// `keccak256(bytes.concat(keccak256(abi.encode(address))))` as `implyCommitteeRoot` implies
+    /// them on EVM targets. Recoverables other than 20-byte addresses are left out.
+    pub fn committee_root(addresses: &[Vec<u8>]) -> H256 {
+        let leaves = addresses
+            .iter()
+            .filter(|address| address.len() == 20)
+            .map(|address| {
+                let mut encoded = [0u8; 32];
+                encoded[12..].copy_from_slice(address);
+                keccak_256(keccak_256(&encoded).as_bytes())
+            })
+            .collect();
+        merkle_root(leaves)
+    }
+
+    /// Verify `proof` of `leaf` against `root`, as OpenZeppelin's `MerkleProof.verify` does.
+    pub fn verify_merkle_proof(root: H256, leaf: H256, proof: &[H256]) -> bool {
+        proof.iter().fold(leaf, |node, sibling| {
+            let (low, high) = if node <= *sibling {
+                (node, *sibling)
+            } else {
+                (*sibling, node)
+            };
+            keccak_256(&[low.as_bytes(), high.as_bytes()].concat())
+        }) == root
     }
 
     #[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
@@ -203,6 +365,39 @@ pub mod pallet {
         Permanent,
     }
 
+    /// Proof of an attester signing batch messages it shouldn't have. Messages are passed in full,
+    /// ending with the index of the batch they claim to be.
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+    pub enum AttestationFraudProof {
+        // A signed message differing from the batch the circuit produced at its index
+        InvalidBatch {
+            message: Vec<u8>,
+            signature: Vec<u8>,
+        },
+        // Two different messages signed for the same batch index
+        ConflictingBatches {
+            first_message: Vec<u8>,
+            first_signature: Vec<u8>,
+            second_message: Vec<u8>,
+            second_signature: Vec<u8>,
+        },
+    }
+
+    impl AttestationFraudProof {
+        /// Length of the messages to hash and recover the signer of.
+        pub fn messages_len(&self) -> u32 {
+            let len = match self {
+                AttestationFraudProof::InvalidBatch { message, .. } => message.len(),
+                AttestationFraudProof::ConflictingBatches {
+                    first_message,
+                    second_message,
+                    ..
+                } => first_message.len().saturating_add(second_message.len()),
+            };
+            len.try_into().unwrap_or(u32::MAX)
+        }
+    }
+
     #[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
     pub struct TargetBatchInclusionProof {
         // The batch message that was included in the block
@@ -244,6 +439,10 @@ pub mod pallet {
         type ReadSFX: ReadSFX<Self::Hash, Self::AccountId, BalanceOf<Self>, BlockNumberFor<Self>>;
         type Xdns: Xdns<Self, BalanceOf<Self>>;
         type LightClientAsyncAPI: LightClientAsyncAPI<Self>;
+        /// Rotate the committee at Clock round boundaries, as notified via `OnNewRound`, instead
+        /// of every `ShufflingFrequency` blocks.
+        type RotateCommitteeOnNewRound: Get<bool>;
+        type WeightInfo: WeightInfo;
     }
 
     #[pallet::pallet]
@@ -348,6 +547,41 @@ pub mod pallet {
         BalanceOf<T>,
     >;
 
+    /// ECDSA keys attesters rotate to, applied with the next committee rotation so that
+    /// destination chains learn of them along with the committee transition.
+    #[pallet::storage]
+    #[pallet::getter(fn pending_key_rotations)]
+    pub type PendingKeyRotations<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, [u8; 33]>;
+
+    /// Merkle root of the current committee addresses on each target, as destination chains
+    /// verify attestations against after applying the committee transition.
+    #[pallet::storage]
+    #[pallet::getter(fn committee_root_on_target)]
+    pub type CommitteeRootOnTarget<T: Config> = StorageMap<_, Identity, TargetId, H256>;
+
+    /// Batching windows of targets sealing their batches less often than every `BatchingWindow`.
+    #[pallet::storage]
+    #[pallet::getter(fn target_batching_window)]
+    pub type TargetBatchingWindows<T: Config> =
+        StorageMap<_, Identity, TargetId, BlockNumberFor<T>>;
+
+    /// Merkle roots over the batches sealed across all targets in each batching window, letting
+    /// destination chains verify many batches against a single signed root.
+    #[pallet::storage]
+    #[pallet::getter(fn batches_root_at)]
+    pub type BatchesRoots<T: Config> =
+        StorageMap<_, Identity, BlockNumberFor<T>, SealedBatchesRoot>;
+
+    /// Batching windows of the `MAX_BATCHES_ROOTS` latest batches roots, oldest first.
+    #[pallet::storage]
+    pub type BatchesRootsHistory<T: Config> = StorageValue<_, Vec<BlockNumberFor<T>>, ValueQuery>;
+
+    /// Late batches re-included into the root of the next batching window.
+    #[pallet::storage]
+    #[pallet::getter(fn late_batches_to_recover)]
+    pub type LateBatchesToRecover<T: Config> = StorageValue<_, Vec<(TargetId, u32)>, ValueQuery>;
+
     #[pallet::storage]
     #[pallet::getter(fn paid_finality_fees)]
     pub type PaidFinalityFees<T: Config> =
@@ -390,6 +624,16 @@ pub mod pallet {
         AttestationTargetRemoved(TargetId, Vec<TargetId>),
         // ShufflingCompleted(current committee, previous committee, next committee)
         ShufflingCompleted(Vec<T::AccountId>, Vec<T::AccountId>, Vec<T::AccountId>),
+        EcdsaKeyRotationScheduled(T::AccountId, [u8; 33]),
+        EcdsaKeyRotated(T::AccountId, [u8; 33]),
+        TargetBatchingWindowSet(TargetId, Option<BlockNumberFor<T>>),
+        // NewBatchesRoot(batching window, root, sealed batches as (target, index))
+        NewBatchesRoot(BlockNumberFor<T>, H256, Vec<(TargetId, u32)>),
+        LateTargetRecovered(TargetId, Vec<u32>),
+        // BatchesRootAttested(batching window, root) once signed by the committee majority
+        BatchesRootAttested(BlockNumberFor<T>, H256),
+        // AttesterSlashedForFraud(attester, target, slashed self-bond, reporter)
+        AttesterSlashedForFraud(T::AccountId, TargetId, BalanceOf<T>, T::AccountId),
     }
 
     #[pallet::error]
@@ -432,6 +676,16 @@ pub mod pallet {
         CommitteeSizeTooLarge,
         InfluxSignatureAlreadySubmitted,
         InfluxMessageHashIncorrect,
+        InvalidEcdsaKey,
+        EcdsaKeyAlreadyRegistered,
+        BatchingWindowNotMultipleOfDefault,
+        NoLateBatchesToRecover,
+        BatchesRootNotFound,
+        FraudProofMessageMalformed,
+        FraudProofSignatureInvalid,
+        FraudProofBatchUnknown,
+        FraudProofOfValidBatch,
+        AttesterAlreadySlashed,
     }
 
     #[pallet::call]
@@ -577,6 +831,183 @@ pub mod pallet {
             Ok(())
         }
 
+        /// Report `attester` for signing batch messages inconsistent with the circuit state, or
+        /// conflicting with each other. Once proven, the attester's self-bond is slashed to the
+        /// slash treasury and the attester is removed from the committee for good.
+        #[pallet::weight(T::WeightInfo::submit_fraud_proof(proof.messages_len()))]
+        pub fn submit_fraud_proof(
+            origin: OriginFor<T>,
+            attester: T::AccountId,
+            target: TargetId,
+            proof: AttestationFraudProof,
+        ) -> DispatchResult {
+            let reporter = ensure_signed(origin)?;
+
+            ensure!(
+                !Self::is_permanently_slashed(&attester),
+                Error::<T>::AttesterAlreadySlashed
+            );
+
+            match proof {
+                AttestationFraudProof::InvalidBatch { message, signature } => {
+                    let index =
+                        Self::verify_signed_batch_message(&attester, target, &message, &signature)?;
+                    let batch = Batches::<T>::get(target)
+                        .unwrap_or_default()
+                        .into_iter()
+                        .find(|batch| batch.index == index)
+                        .ok_or(Error::<T>::FraudProofBatchUnknown)?;
+                    ensure!(
+                        batch.message() != message,
+                        Error::<T>::FraudProofOfValidBatch
+                    );
+                },
+                AttestationFraudProof::ConflictingBatches {
+                    first_message,
+                    first_signature,
+                    second_message,
+                    second_signature,
+                } => {
+                    let first_index = Self::verify_signed_batch_message(
+                        &attester,
+                        target,
+                        &first_message,
+                        &first_signature,
+                    )?;
+                    let second_index = Self::verify_signed_batch_message(
+                        &attester,
+                        target,
+                        &second_message,
+                        &second_signature,
+                    )?;
+                    ensure!(
+                        first_index == second_index && first_message != second_message,
+                        Error::<T>::FraudProofOfValidBatch
+                    );
+                },
+            }
+
+            let slashed = Self::slash_for_fraud(&attester);
+            Self::deposit_event(Event::AttesterSlashedForFraud(
+                attester, target, slashed, reporter,
+            ));
+
+            Ok(())
+        }
+
+        /// Seal the batches of `target` every `window` blocks, a multiple of `BatchingWindow`, or
+        /// every `BatchingWindow` again with `None`.
+        #[pallet::weight(T::WeightInfo::set_target_batching_window())]
+        pub fn set_target_batching_window(
+            origin: OriginFor<T>,
+            target: TargetId,
+            window: Option<BlockNumberFor<T>>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            if let Some(window) = window {
+                ensure!(
+                    !window.is_zero() && (window % T::BatchingWindow::get()).is_zero(),
+                    Error::<T>::BatchingWindowNotMultipleOfDefault
+                );
+            }
+            TargetBatchingWindows::<T>::set(target, window);
+            Self::deposit_event(Event::TargetBatchingWindowSet(target, window));
+
+            Ok(())
+        }
+
+        /// Re-include the late batches of `target`, still pending attestation, into the root of the
+        /// next batching window, i.e. once the target recovers from an outage.
+        #[pallet::weight(T::WeightInfo::recover_late_target())]
+        pub fn recover_late_target(origin: OriginFor<T>, target: TargetId) -> DispatchResult {
+            ensure_root(origin)?;
+
+            let late_indices: Vec<u32> = Self::get_batches(target, BatchStatus::PendingAttestation)
+                .iter()
+                .filter(|batch| matches!(batch.latency, LatencyStatus::Late(..)))
+                .map(|batch| batch.index)
+                .collect();
+            ensure!(!late_indices.is_empty(), Error::<T>::NoLateBatchesToRecover);
+
+            LateBatchesToRecover::<T>::mutate(|late_batches| {
+                for index in late_indices.iter() {
+                    if !late_batches.contains(&(target, *index)) {
+                        late_batches.push((target, *index));
+                    }
+                }
+            });
+            Self::deposit_event(Event::LateTargetRecovered(target, late_indices));
+
+            Ok(())
+        }
+
+        /// Sign the root of the batches sealed in the batching window ending at `at`, with the
+        /// recoverable agreed to for `target`, which verifies the root there.
+        #[pallet::weight(T::WeightInfo::submit_batches_root_attestation())]
+        pub fn submit_batches_root_attestation(
+            origin: OriginFor<T>,
+            at: BlockNumberFor<T>,
+            signature: Vec<u8>,
+            target: TargetId,
+        ) -> DispatchResult {
+            let account_id = ensure_signed(origin)?;
+
+            let attester = Attesters::<T>::get(&account_id).ok_or(Error::<T>::NotRegistered)?;
+            ensure!(
+                ActiveSet::<T>::get().contains(&account_id),
+                Error::<T>::NotActiveSet
+            );
+            ensure!(
+                CurrentCommittee::<T>::get().contains(&account_id),
+                Error::<T>::NotInCurrentCommittee
+            );
+            let attested_recoverable = AttestersAgreements::<T>::get(&account_id, target)
+                .ok_or(Error::<T>::AttesterDidNotAgreeToNewTarget)?;
+            let vendor = <T as Config>::Xdns::get_verification_vendor(&target)
+                .map_err(|_| Error::<T>::XdnsTargetNotActive)?;
+
+            BatchesRoots::<T>::try_mutate(at, |sealed_root| {
+                let sealed_root = sealed_root
+                    .as_mut()
+                    .ok_or(Error::<T>::BatchesRootNotFound)?;
+
+                let is_verified = attester
+                    .verify_attestation_signature(
+                        ECDSA_ATTESTER_KEY_TYPE_ID,
+                        &sealed_root.root.encode(),
+                        &signature,
+                        attested_recoverable,
+                        &vendor,
+                    )
+                    .unwrap_or(false);
+                ensure!(is_verified, Error::<T>::AttestationSignatureInvalid);
+                let signature_65b: [u8; 65] = signature
+                    .try_into()
+                    .map_err(|_| Error::<T>::InvalidSignature)?;
+
+                ensure!(
+                    !sealed_root
+                        .signatures
+                        .iter()
+                        .any(|(attester_index, _)| *attester_index == attester.index),
+                    Error::<T>::AttestationDoubleSignAttempt
+                );
+                sealed_root.signatures.push((attester.index, signature_65b));
+
+                let quorum = (T::CommitteeSize::get() * 2 / 3) as usize;
+                if sealed_root.signatures.len() == quorum {
+                    Self::deposit_event(Event::BatchesRootAttested(at, sealed_root.root));
+                }
+
+                Ok::<(), DispatchError>(())
+            })?;
+
+            Self::deposit_event(Event::AttestationSubmitted(account_id));
+
+            Ok(())
+        }
+
         #[pallet::weight(10_000)]
         pub fn force_activate_target(origin: OriginFor<T>, target: TargetId) -> DispatchResult {
             ensure_root(origin)?;
@@ -986,6 +1417,29 @@ pub mod pallet {
 
             Ok(())
         }
+
+        /// Rotate the attester's ECDSA key used to sign attestations on targets. The new key takes
+        /// effect with the next committee rotation.
+        #[pallet::weight(10_000)]
+        pub fn rotate_ecdsa_key(origin: OriginFor<T>, ecdsa_key: [u8; 33]) -> DispatchResult {
+            let attester = ensure_signed(origin)?;
+
+            ensure!(
+                Attesters::<T>::contains_key(&attester),
+                Error::<T>::NotRegistered
+            );
+            ecdsa_pubkey_to_eth_address(&ecdsa_key).map_err(|_| Error::<T>::InvalidEcdsaKey)?;
+            ensure!(
+                !Attesters::<T>::iter_values().any(|info| info.key_ec == ecdsa_key)
+                    && !PendingKeyRotations::<T>::iter_values().any(|key| key == ecdsa_key),
+                Error::<T>::EcdsaKeyAlreadyRegistered
+            );
+
+            PendingKeyRotations::<T>::insert(&attester, ecdsa_key);
+            Self::deposit_event(Event::EcdsaKeyRotationScheduled(attester, ecdsa_key));
+
+            Ok(())
+        }
     }
 
     impl<T: Config> AttestersWriteApi<T::AccountId, DispatchError> for Pallet<T> {
@@ -1450,6 +1904,65 @@ pub mod pallet {
             )
         }
 
+        /// Verify `attester` signed `message` on `target`, returning the batch index it claims.
+        fn verify_signed_batch_message(
+            attester: &T::AccountId,
+            target: TargetId,
+            message: &[u8],
+            signature: &[u8],
+        ) -> Result<u32, DispatchError> {
+            let index_bytes: [u8; 4] = message
+                .len()
+                .checked_sub(4)
+                .and_then(|offset| message[offset..].try_into().ok())
+                .ok_or(Error::<T>::FraudProofMessageMalformed)?;
+
+            let attester_info = Attesters::<T>::get(attester).ok_or(Error::<T>::NotRegistered)?;
+            let attested_recoverable = AttestersAgreements::<T>::get(attester, target)
+                .ok_or(Error::<T>::AttesterDidNotAgreeToNewTarget)?;
+            let vendor = <T as Config>::Xdns::get_verification_vendor(&target)
+                .map_err(|_| Error::<T>::XdnsTargetNotActive)?;
+
+            let is_verified = attester_info
+                .verify_attestation_signature(
+                    ECDSA_ATTESTER_KEY_TYPE_ID,
+                    &keccak_256(message).encode(),
+                    signature,
+                    attested_recoverable,
+                    &vendor,
+                )
+                .unwrap_or(false);
+            ensure!(is_verified, Error::<T>::FraudProofSignatureInvalid);
+
+            Ok(u32::from_be_bytes(index_bytes))
+        }
+
+        /// Slash the self-bond of a fraudulent attester to the slash treasury and remove it from
+        /// the active set and committees for good. Returns the slashed amount.
+        fn slash_for_fraud(attester: &T::AccountId) -> BalanceOf<T> {
+            let self_bond = Nominations::<T>::take(attester, attester).unwrap_or_else(Zero::zero);
+            let slash_treasury = T::TreasuryAccounts::get_treasury_account(TreasuryAccount::Slash);
+            let unslashed = T::Currency::repatriate_reserved(
+                attester,
+                &slash_treasury,
+                self_bond,
+                BalanceStatus::Free,
+            )
+            .unwrap_or(self_bond);
+
+            PermanentSlashes::<T>::append(attester);
+            ActiveSet::<T>::mutate(|set| set.retain(|member| member != attester));
+            CurrentCommittee::<T>::mutate(|committee| {
+                committee.retain(|member| member != attester)
+            });
+            NextCommittee::<T>::mutate(|committee| committee.retain(|member| member != attester));
+            SortedNominatedAttesters::<T>::mutate(|attesters| {
+                attesters.retain(|(member, _)| member != attester)
+            });
+
+            self_bond.saturating_sub(unslashed)
+        }
+
         /// Applies permanent slashes to colluding attesters.
         fn apply_permanent_attesters_slash(attester_local_addresses: Vec<T::AccountId>) {
             for account_id in attester_local_addresses {
@@ -1621,6 +2134,27 @@ pub mod pallet {
             batches.iter().map(|b| b.message_hash()).next()
         }
 
+        /// Locate `sfx_id` among the batches attested on `target`, returning the batch message
+        /// hash, its SFX outcomes root, whether the SFX was committed and the proof of its outcome
+        /// against the root.
+        pub fn get_sfx_outcome_proof(
+            target: TargetId,
+            sfx_id: H256,
+        ) -> Option<(H256, H256, bool, Vec<H256>)> {
+            Batches::<T>::get(target)?
+                .iter()
+                .filter(|b| {
+                    b.status == BatchStatus::ReadyForSubmissionByMajority
+                        || b.status == BatchStatus::ReadyForSubmissionFullyApproved
+                        || b.status == BatchStatus::Committed
+                })
+                .find_map(|b| {
+                    b.sfx_outcome_proof(sfx_id).map(|(is_committed, proof)| {
+                        (b.message_hash(), b.sfx_outcomes_root(), is_committed, proof)
+                    })
+                })
+        }
+
         pub fn get_latest_batch_to_sign_message(target: TargetId) -> Option<Vec<u8>> {
             let mut batches = Self::get_batches(target, BatchStatus::PendingAttestation);
             batches.sort_by(|a, b| b.created.cmp(&a.created));
@@ -1800,6 +2334,86 @@ pub mod pallet {
             full_shuffle
         }
 
+        /// Select the active set out of the nominated attesters and shuffle the next committee out
+        /// of it, applying the ECDSA key rotations staged since the previous rotation.
+        pub fn rotate_committee(n: BlockNumberFor<T>, aggregated_weight: Weight) -> Weight {
+            let mut aggregated_weight = Self::apply_key_rotations(aggregated_weight);
+            // Process pending unnominations
+            aggregated_weight = Self::process_pending_unnominations(n, aggregated_weight);
+            // Update the active set of attesters
+            ActiveSet::<T>::put(
+                SortedNominatedAttesters::<T>::get()
+                    .iter()
+                    .filter(|(account_id, _)| !Self::is_permanently_slashed(account_id))
+                    .take(32)
+                    .cloned()
+                    .map(|(account_id, _balance)| account_id)
+                    .collect::<Vec<T::AccountId>>(),
+            );
+            aggregated_weight += T::DbWeight::get().reads_writes(1, 1);
+
+            // Call shuffle_committee
+            Self::shuffle_committee();
+            aggregated_weight += T::DbWeight::get().reads_writes(2, 2);
+
+            Self::deposit_event(Event::ShufflingCompleted(
+                CurrentCommittee::<T>::get(),
+                PreviousCommittee::<T>::get(),
+                NextCommittee::<T>::get(),
+            ));
+
+            for (target, batch_index) in Self::request_next_committee_attestation() {
+                CommitteeTransitionOn::<T>::insert(target, batch_index);
+            }
+            aggregated_weight += T::DbWeight::get().reads_writes(2, 3);
+
+            aggregated_weight = Self::update_committee_roots(aggregated_weight);
+
+            aggregated_weight
+        }
+
+        /// Swap the ECDSA keys of attesters who rotated them, re-deriving the addresses they
+        /// agreed to attest with on EVM targets.
+        fn apply_key_rotations(mut aggregated_weight: Weight) -> Weight {
+            for (attester, ecdsa_key) in PendingKeyRotations::<T>::drain() {
+                aggregated_weight += T::DbWeight::get().reads_writes(2, 2);
+                let Ok(eth_address) = ecdsa_pubkey_to_eth_address(&ecdsa_key) else {
+                    continue
+                };
+                Attesters::<T>::mutate(&attester, |maybe_info| {
+                    if let Some(info) = maybe_info {
+                        info.key_ec = ecdsa_key;
+                    }
+                });
+                for target in AttestationTargets::<T>::get() {
+                    AttestersAgreements::<T>::mutate(&attester, target, |maybe_recoverable| {
+                        match maybe_recoverable {
+                            Some(recoverable) if recoverable.len() == 20 =>
+                                *recoverable = eth_address.to_vec(),
+                            _ => {},
+                        }
+                    });
+                }
+                Self::deposit_event(Event::EcdsaKeyRotated(attester, ecdsa_key));
+            }
+            aggregated_weight
+        }
+
+        /// Store the roots of the current committee addresses on each target.
+        fn update_committee_roots(mut aggregated_weight: Weight) -> Weight {
+            let current_committee = CurrentCommittee::<T>::get();
+            for target in AttestationTargets::<T>::get() {
+                let addresses: Vec<Vec<u8>> = current_committee
+                    .iter()
+                    .filter_map(|attester| AttestersAgreements::<T>::get(attester, target))
+                    .collect();
+                CommitteeRootOnTarget::<T>::insert(target, committee_root(&addresses));
+                aggregated_weight +=
+                    T::DbWeight::get().reads_writes(current_committee.len() as u64, 1);
+            }
+            aggregated_weight
+        }
+
         pub fn process_repatriations(n: BlockNumberFor<T>, aggregated_weight: Weight) -> Weight {
             for target in AttestationTargets::<T>::get() {
                 Batches::<T>::mutate(target, |batches| {
@@ -1888,8 +2502,15 @@ pub mod pallet {
             aggregated_weight: Weight,
         ) -> Weight {
             let quorum = (T::CommitteeSize::get() * 2 / 3) as usize;
+            let mut sealed_batches: Vec<(TargetId, u32, H256)> = Vec::new();
 
             for target in AttestationTargets::<T>::get() {
+                // Targets with longer batching windows skip the windows in between
+                if let Some(window) = TargetBatchingWindows::<T>::get(target) {
+                    if !(n % window).is_zero() {
+                        continue
+                    }
+                }
                 let mut new_next_batch = BatchMessage {
                     created: n,
                     ..Default::default()
@@ -1943,6 +2564,7 @@ pub mod pallet {
                     // Leave the batch empty if it has no messages to attest for
                     if !next_batch.is_empty() {
                         let message_hash = next_batch.message_hash();
+                        sealed_batches.push((target, next_batch.index, message_hash));
                         next_batch.status = BatchStatus::PendingAttestation;
                         // Push the batch to the batches vector
                         Batches::<T>::append(target, &next_batch);
@@ -1972,9 +2594,67 @@ pub mod pallet {
                     Self::request_next_committee_attestation();
                 }
             }
+
+            for (target, index) in LateBatchesToRecover::<T>::take() {
+                Batches::<T>::mutate(target, |batches| {
+                    if let Some(batch) = batches.iter_mut().flatten().find(|batch| {
+                        batch.index == index && batch.status == BatchStatus::PendingAttestation
+                    }) {
+                        batch.latency = LatencyStatus::OnTime;
+                        sealed_batches.push((target, index, batch.message_hash()));
+                    }
+                });
+            }
+
+            if !sealed_batches.is_empty() {
+                let root = batches_root(&sealed_batches);
+                Self::deposit_event(Event::NewBatchesRoot(
+                    n,
+                    root,
+                    sealed_batches
+                        .iter()
+                        .map(|(target, index, _)| (*target, *index))
+                        .collect(),
+                ));
+                BatchesRoots::<T>::insert(
+                    n,
+                    SealedBatchesRoot {
+                        root,
+                        sealed_batches,
+                        signatures: Vec::new(),
+                    },
+                );
+                BatchesRootsHistory::<T>::mutate(|history| {
+                    history.push(n);
+                    if history.len() > MAX_BATCHES_ROOTS {
+                        BatchesRoots::<T>::remove(history.remove(0));
+                    }
+                });
+            }
             aggregated_weight
         }
 
+        /// Proof of the batch at `index` on `target` against the root of the batches sealed in the
+        /// batching window ending at `at`.
+        pub fn get_batches_root_proof(
+            at: BlockNumberFor<T>,
+            target: TargetId,
+            index: u32,
+        ) -> Option<(H256, Vec<H256>)> {
+            let SealedBatchesRoot {
+                root,
+                sealed_batches,
+                ..
+            } = BatchesRoots::<T>::get(at)?;
+            let position = sealed_batches
+                .iter()
+                .position(|(t, i, _)| *t == target && *i == index)?;
+            Some((
+                root,
+                merkle_proof(batches_root_leaves(&sealed_batches), position),
+            ))
+        }
+
         pub fn is_permanently_slashed(account: &T::AccountId) -> bool {
             PermanentSlashes::<T>::get().contains(account)
         }
@@ -2068,42 +2748,25 @@ pub mod pallet {
         }
     }
 
-    #[pallet::hooks]
+    impl<T: Config> OnNewRound<BlockNumberFor<T>> for Pallet<T> {
+        fn on_new_round(_index: RoundIndex, head: BlockNumberFor<T>) -> Weight {
+            if !T::RotateCommitteeOnNewRound::get() || !Self::is_last_transition_attested() {
+                return T::DbWeight::get().reads(1)
+            }
+            Self::rotate_committee(head, Zero::zero())
+        }
+    }
+
+    #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
         fn on_initialize(n: BlockNumberFor<T>) -> Weight {
             let mut aggregated_weight: Weight = Zero::zero();
             // Check if a shuffling round has passed
-            if (n % T::ShufflingFrequency::get()).is_zero() && Self::is_last_transition_attested() {
-                // Process pending unnominations
-                aggregated_weight = Self::process_pending_unnominations(n, aggregated_weight);
-                // Update the active set of attesters
-                ActiveSet::<T>::put(
-                    SortedNominatedAttesters::<T>::get()
-                        .iter()
-                        .filter(|(account_id, _)| !Self::is_permanently_slashed(account_id))
-                        .take(32)
-                        .cloned()
-                        .map(|(account_id, _balance)| account_id)
-                        .collect::<Vec<T::AccountId>>(),
-                );
-                aggregated_weight += T::DbWeight::get().reads_writes(1, 1);
-
-                // Call shuffle_committee
-                Self::shuffle_committee();
-                aggregated_weight += T::DbWeight::get().reads_writes(2, 2);
-
-                Self::deposit_event(Event::ShufflingCompleted(
-                    CurrentCommittee::<T>::get(),
-                    PreviousCommittee::<T>::get(),
-                    NextCommittee::<T>::get(),
-                ));
-
-                for (target, batch_index) in Self::request_next_committee_attestation() {
-                    CommitteeTransitionOn::<T>::insert(target, batch_index);
-                }
-                aggregated_weight += T::DbWeight::get().reads_writes(2, 3);
-
-                return aggregated_weight
+            if !T::RotateCommitteeOnNewRound::get()
+                && (n % T::ShufflingFrequency::get()).is_zero()
+                && Self::is_last_transition_attested()
+            {
+                return Self::rotate_committee(n, aggregated_weight)
             }
 
             if (n % T::BatchingWindow::get()).is_zero() {
@@ -2163,7 +2826,7 @@ pub mod pallet {
 #[cfg(test)]
 pub mod attesters_test {
     use super::{
-        TargetId, ECDSA_ATTESTER_KEY_TYPE_ID, ED25519_ATTESTER_KEY_TYPE_ID,
+        AttestationFraudProof, TargetId, ECDSA_ATTESTER_KEY_TYPE_ID, ED25519_ATTESTER_KEY_TYPE_ID,
         SR25519_ATTESTER_KEY_TYPE_ID,
     };
     use std::ops::Index;
@@ -2185,9 +2848,9 @@ pub mod attesters_test {
         CommitteeTransitionOn, ConfigAttesters, ConfigRewards, CurrentCommittee,
         ExistentialDeposit, ExtBuilder, FullSideEffects, InfluxMessage, LatencyStatus, MiniRuntime,
         NextBatch, NextCommitteeOnTarget, Nominations, PaidFinalityFees, PendingUnnominations,
-        PermanentSlashes, PreviousCommittee, Rewards, RuntimeEvent as Event, RuntimeOrigin,
-        SFX2XTXLinksMap, SortedNominatedAttesters, System, XExecSignals, ETHEREUM_TARGET,
-        POLKADOT_TARGET,
+        PermanentSlashes, PreviousCommittee, Rewards, RotateAttestersCommitteeOnNewRound,
+        RuntimeEvent as Event, RuntimeOrigin, SFX2XTXLinksMap, SortedNominatedAttesters, System,
+        XExecSignals, ETHEREUM_TARGET, POLKADOT_TARGET,
     };
     use t3rn_primitives::{
         attesters::{
@@ -2198,6 +2861,7 @@ pub mod attesters_test {
             AdaptiveTimeout, CircuitStatus, FullSideEffect, SecurityLvl, SideEffect, XExecSignal,
         },
         claimable::{BenefitSource, CircuitRole, ClaimableArtifacts},
+        clock::OnNewRound,
         TreasuryAccount, TreasuryAccountProvider,
     };
     use tiny_keccak::{Hasher, Keccak};
@@ -2500,6 +3164,78 @@ pub mod attesters_test {
         });
     }
 
+    #[test]
+    fn rotated_ecdsa_keys_take_effect_with_the_committee_rotated_at_new_round() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            let attester = AccountId::from([1u8; 32]);
+            let attester_info = register_attester_with_single_private_key([1u8; 32]);
+            let old_eth_address = ecdsa_pubkey_to_eth_address(&attester_info.key_ec).unwrap();
+            AttestationTargets::<MiniRuntime>::append(ETHEREUM_TARGET);
+            assert_ok!(Attesters::agree_to_new_attestation_target(
+                RuntimeOrigin::signed(attester.clone()),
+                ETHEREUM_TARGET,
+                old_eth_address.to_vec(),
+            ));
+
+            let new_ecdsa_key: [u8; 33] = ecdsa::Pair::from_seed(&[2u8; 32])
+                .public()
+                .to_raw_vec()
+                .try_into()
+                .unwrap();
+            assert_err!(
+                Attesters::rotate_ecdsa_key(
+                    RuntimeOrigin::signed(AccountId::from([2u8; 32])),
+                    new_ecdsa_key
+                ),
+                AttestersError::<MiniRuntime>::NotRegistered
+            );
+            assert_err!(
+                Attesters::rotate_ecdsa_key(RuntimeOrigin::signed(attester.clone()), [0u8; 33]),
+                AttestersError::<MiniRuntime>::InvalidEcdsaKey
+            );
+            assert_err!(
+                Attesters::rotate_ecdsa_key(
+                    RuntimeOrigin::signed(attester.clone()),
+                    attester_info.key_ec
+                ),
+                AttestersError::<MiniRuntime>::EcdsaKeyAlreadyRegistered
+            );
+            assert_ok!(Attesters::rotate_ecdsa_key(
+                RuntimeOrigin::signed(attester.clone()),
+                new_ecdsa_key
+            ));
+
+            // Rotations wait for the next round once the committee rotates with rounds
+            RotateAttestersCommitteeOnNewRound::set(&true);
+            Attesters::on_initialize(800u32);
+            assert_eq!(
+                Attesters::pending_key_rotations(&attester),
+                Some(new_ecdsa_key)
+            );
+
+            <Attesters as OnNewRound<BlockNumber>>::on_new_round(1, 900u32);
+
+            let new_eth_address = ecdsa_pubkey_to_eth_address(&new_ecdsa_key).unwrap();
+            assert_eq!(Attesters::pending_key_rotations(&attester), None);
+            assert_eq!(
+                AttestersStore::<MiniRuntime>::get(&attester)
+                    .unwrap()
+                    .key_ec,
+                new_ecdsa_key
+            );
+            assert_eq!(
+                Attesters::attesters_agreements(&attester, ETHEREUM_TARGET),
+                Some(new_eth_address.to_vec())
+            );
+            assert_eq!(CurrentCommittee::<MiniRuntime>::get(), vec![attester]);
+            assert_eq!(
+                Attesters::committee_root_on_target(ETHEREUM_TARGET),
+                Some(crate::committee_root(&[new_eth_address.to_vec()]))
+            );
+        });
+    }
+
     #[test]
     fn register_attester_with_sudo_privilige_sets_as_invulnerable() {
         let mut ext = ExtBuilder::default().build();
@@ -2728,6 +3464,223 @@ pub mod attesters_test {
         });
     }
 
+    #[test]
+    fn attester_signing_a_batch_inconsistent_with_the_circuit_is_slashed_on_fraud_proof() {
+        let target = ETHEREUM_TARGET;
+
+        let mut ext = ExtBuilder::default()
+            .with_standard_sfx_abi()
+            .with_eth_gateway_record()
+            .build();
+
+        ext.execute_with(|| {
+            let attester = AccountId::from([1; 32]);
+            let reporter = AccountId::from([2; 32]);
+            register_attester_with_single_private_key([1u8; 32]);
+            let sfx_id_to_sign_on: [u8; 32] = *b"message_that_needs_attestation32";
+            let (message_hash, signature) = sign_and_submit_sfx_to_latest_attestation(
+                attester.clone(),
+                vec![sfx_id_to_sign_on],
+                ECDSA_ATTESTER_KEY_TYPE_ID,
+                target,
+                [1u8; 32],
+            );
+            let batch = Attesters::get_batch_by_message_hash(target, message_hash).unwrap();
+
+            // The genuine batch can't be reported
+            assert_err!(
+                Attesters::submit_fraud_proof(
+                    RuntimeOrigin::signed(reporter.clone()),
+                    attester.clone(),
+                    target,
+                    AttestationFraudProof::InvalidBatch {
+                        message: batch.message(),
+                        signature: signature.clone(),
+                    },
+                ),
+                AttestersError::<MiniRuntime>::FraudProofOfValidBatch
+            );
+
+            // Neither can a message the attester didn't sign
+            let forged_batch = BatchMessage {
+                committed_sfx: Some(vec![H512::repeat_byte(9)]),
+                ..batch.clone()
+            };
+            assert_err!(
+                Attesters::submit_fraud_proof(
+                    RuntimeOrigin::signed(reporter.clone()),
+                    attester.clone(),
+                    target,
+                    AttestationFraudProof::InvalidBatch {
+                        message: forged_batch.message(),
+                        signature: signature.clone(),
+                    },
+                ),
+                AttestersError::<MiniRuntime>::FraudProofSignatureInvalid
+            );
+
+            let forged_signature = ecdsa::Pair::from_seed(&[1u8; 32])
+                .sign_prehashed(&forged_batch.message_hash().0)
+                .encode();
+
+            let slash_treasury = MiniRuntime::get_treasury_account(TreasuryAccount::Slash);
+            let _ = Balances::deposit_creating(&slash_treasury, 100);
+
+            assert_ok!(Attesters::submit_fraud_proof(
+                RuntimeOrigin::signed(reporter.clone()),
+                attester.clone(),
+                target,
+                AttestationFraudProof::ConflictingBatches {
+                    first_message: batch.message(),
+                    first_signature: signature,
+                    second_message: forged_batch.message(),
+                    second_signature: forged_signature.clone(),
+                },
+            ));
+
+            assert_eq!(Balances::free_balance(&slash_treasury), 110);
+            assert_eq!(Balances::reserved_balance(&attester), 0);
+            assert!(Attesters::is_permanently_slashed(&attester));
+            assert!(!Attesters::active_set().contains(&attester));
+            assert!(!CurrentCommittee::<MiniRuntime>::get().contains(&attester));
+            assert!(Attesters::nominations(&attester, &attester).is_none());
+
+            assert_err!(
+                Attesters::submit_fraud_proof(
+                    RuntimeOrigin::signed(reporter),
+                    attester,
+                    target,
+                    AttestationFraudProof::InvalidBatch {
+                        message: forged_batch.message(),
+                        signature: forged_signature,
+                    },
+                ),
+                AttestersError::<MiniRuntime>::AttesterAlreadySlashed
+            );
+        });
+    }
+
+    #[test]
+    fn fraud_proofs_of_malformed_unknown_or_consistent_messages_are_rejected() {
+        let target = ETHEREUM_TARGET;
+
+        let mut ext = ExtBuilder::default()
+            .with_standard_sfx_abi()
+            .with_eth_gateway_record()
+            .build();
+
+        ext.execute_with(|| {
+            let attester = AccountId::from([1; 32]);
+            let reporter = AccountId::from([2; 32]);
+            register_attester_with_single_private_key([1u8; 32]);
+            let sfx_id_to_sign_on: [u8; 32] = *b"message_that_needs_attestation32";
+            let (message_hash, signature) = sign_and_submit_sfx_to_latest_attestation(
+                attester.clone(),
+                vec![sfx_id_to_sign_on],
+                ECDSA_ATTESTER_KEY_TYPE_ID,
+                target,
+                [1u8; 32],
+            );
+            let batch = Attesters::get_batch_by_message_hash(target, message_hash).unwrap();
+            let sign = |message: &[u8]| {
+                ecdsa::Pair::from_seed(&[1u8; 32])
+                    .sign_prehashed(&keccak_256(message))
+                    .encode()
+            };
+
+            // Messages too short to carry a batch index
+            assert_noop!(
+                Attesters::submit_fraud_proof(
+                    RuntimeOrigin::signed(reporter.clone()),
+                    attester.clone(),
+                    target,
+                    AttestationFraudProof::InvalidBatch {
+                        message: vec![0u8; 3],
+                        signature: sign(&[0u8; 3]),
+                    },
+                ),
+                AttestersError::<MiniRuntime>::FraudProofMessageMalformed
+            );
+            assert_noop!(
+                Attesters::submit_fraud_proof(
+                    RuntimeOrigin::signed(reporter.clone()),
+                    attester.clone(),
+                    target,
+                    AttestationFraudProof::ConflictingBatches {
+                        first_message: batch.message(),
+                        first_signature: signature.clone(),
+                        second_message: vec![],
+                        second_signature: sign(&[]),
+                    },
+                ),
+                AttestersError::<MiniRuntime>::FraudProofMessageMalformed
+            );
+
+            // Messages claiming an index the circuit never produced a batch at
+            let mut unknown_index_message = batch.message();
+            let index_offset = unknown_index_message.len() - 4;
+            unknown_index_message[index_offset..].copy_from_slice(&99u32.to_be_bytes());
+            let unknown_index_signature = sign(&unknown_index_message);
+            assert_noop!(
+                Attesters::submit_fraud_proof(
+                    RuntimeOrigin::signed(reporter.clone()),
+                    attester.clone(),
+                    target,
+                    AttestationFraudProof::InvalidBatch {
+                        message: unknown_index_message.clone(),
+                        signature: unknown_index_signature.clone(),
+                    },
+                ),
+                AttestersError::<MiniRuntime>::FraudProofBatchUnknown
+            );
+
+            // Signing different batches, or the same batch twice, isn't conflicting
+            assert_noop!(
+                Attesters::submit_fraud_proof(
+                    RuntimeOrigin::signed(reporter.clone()),
+                    attester.clone(),
+                    target,
+                    AttestationFraudProof::ConflictingBatches {
+                        first_message: batch.message(),
+                        first_signature: signature.clone(),
+                        second_message: unknown_index_message.clone(),
+                        second_signature: unknown_index_signature,
+                    },
+                ),
+                AttestersError::<MiniRuntime>::FraudProofOfValidBatch
+            );
+            assert_noop!(
+                Attesters::submit_fraud_proof(
+                    RuntimeOrigin::signed(reporter.clone()),
+                    attester.clone(),
+                    target,
+                    AttestationFraudProof::ConflictingBatches {
+                        first_message: batch.message(),
+                        first_signature: signature.clone(),
+                        second_message: batch.message(),
+                        second_signature: signature,
+                    },
+                ),
+                AttestersError::<MiniRuntime>::FraudProofOfValidBatch
+            );
+            assert!(!Attesters::is_permanently_slashed(&attester));
+
+            // A signed message differing from the batch at its index is fraud on its own
+            let mut forged_message = batch.message();
+            forged_message[0] ^= 1;
+            assert_ok!(Attesters::submit_fraud_proof(
+                RuntimeOrigin::signed(reporter),
+                attester.clone(),
+                target,
+                AttestationFraudProof::InvalidBatch {
+                    signature: sign(&forged_message),
+                    message: forged_message,
+                },
+            ));
+            assert!(Attesters::is_permanently_slashed(&attester));
+        });
+    }
+
     #[test]
     fn test_adding_sfx_moves_next_batch_to_pending_attestation() {
         let mut ext = ExtBuilder::default()
@@ -2795,6 +3748,9 @@ pub mod attesters_test {
                 submission_target_height: 1,
                 best_bid: None,
                 index: 0,
+                deadline: None,
+                dependencies: vec![],
+                metadata: vec![],
             };
 
             let sfx_id = mock_fsx
@@ -2874,6 +3830,9 @@ pub mod attesters_test {
                 submission_target_height: 1,
                 best_bid: None,
                 index: 0,
+                deadline: None,
+                dependencies: vec![],
+                metadata: vec![],
             };
 
             let mock_xtx = XExecSignal {
@@ -3257,8 +4216,12 @@ pub mod attesters_test {
 
             let _current_block_2 = add_target_and_transition_to_next_batch(target, 1);
 
-            let (_message_hash, expected_message_bytes) =
-                calculate_hash_for_sfx_message(sfx_gmp_a.encode(), 0);
+            // A single SFX outcome leaf is the SFX outcomes root itself
+            let (_message_hash, expected_message_bytes) = calculate_hash_for_sfx_message(
+                sfx_outcome_leaf(sfx_id_a, true).as_bytes().to_vec(),
+                0,
+            );
+            assert_ne!(expected_message_bytes[..32], sfx_gmp_a[..32]);
 
             assert_eq!(
                 Attesters::get_latest_batch_to_sign_message(target),
@@ -3277,6 +4240,338 @@ pub mod attesters_test {
         });
     }
 
+    #[test]
+    fn sfx_outcome_proofs_of_attested_batch_verify_against_root_in_signed_message() {
+        let mut ext = ExtBuilder::default()
+            .with_standard_sfx_abi()
+            .with_eth_gateway_record()
+            .build();
+
+        ext.execute_with(|| {
+            let target: TargetId = ETHEREUM_TARGET;
+            for counter in 1..22u8 {
+                register_attester_with_single_private_key([counter; 32]);
+            }
+            select_new_committee();
+
+            let _current_block_1 = add_target_and_transition_to_next_batch(target, 0);
+            let committed_sfx_a = H256::repeat_byte(1);
+            let committed_sfx_b = H256::repeat_byte(2);
+            let reverted_sfx_c = H256::repeat_byte(3);
+            assert_ok!(Attesters::request_sfx_attestation_commit(
+                target,
+                committed_sfx_a,
+                None
+            ));
+            assert_ok!(Attesters::request_sfx_attestation_commit(
+                target,
+                committed_sfx_b,
+                Some(H256::repeat_byte(9))
+            ));
+            assert_ok!(Attesters::request_sfx_attestation_revert(
+                target,
+                reverted_sfx_c
+            ));
+            let _current_block_2 = add_target_and_transition_to_next_batch(target, 1);
+
+            let signed_message = Attesters::get_latest_batch_to_sign_message(target).unwrap();
+            // Neither the SFX ids nor the GMP payloads are signed over, only their outcomes root
+            for sfx_id in [
+                committed_sfx_a,
+                committed_sfx_b,
+                reverted_sfx_c,
+                H256::repeat_byte(9),
+            ] {
+                assert!(!signed_message
+                    .windows(32)
+                    .any(|word| word == sfx_id.as_bytes()));
+            }
+
+            for counter in 1..22u8 {
+                sign_and_submit_sfx_to_latest_attestation(
+                    AccountId::from([counter; 32]),
+                    vec![],
+                    ECDSA_ATTESTER_KEY_TYPE_ID,
+                    target,
+                    [counter; 32],
+                );
+            }
+            // Outcomes aren't provable until the batch is attested
+            assert_eq!(
+                Attesters::get_sfx_outcome_proof(target, committed_sfx_a),
+                None
+            );
+
+            add_target_and_transition_to_next_batch(target, 1);
+            let batch = Attesters::get_batch_by_message(target, signed_message.clone()).unwrap();
+            assert_eq!(batch.status, BatchStatus::ReadyForSubmissionByMajority);
+
+            let signed_root = H256::from_slice(
+                &signed_message[signed_message.len() - 36..signed_message.len() - 4],
+            );
+            for (sfx_id, expected_committed) in [
+                (committed_sfx_a, true),
+                (committed_sfx_b, true),
+                (reverted_sfx_c, false),
+            ] {
+                let (message_hash, root, is_committed, proof) =
+                    Attesters::get_sfx_outcome_proof(target, sfx_id).unwrap();
+                assert_eq!(message_hash, batch.message_hash());
+                assert_eq!(root, signed_root);
+                assert_eq!(is_committed, expected_committed);
+                assert!(crate::verify_merkle_proof(
+                    signed_root,
+                    sfx_outcome_leaf(sfx_id, expected_committed),
+                    &proof
+                ));
+                // The opposite outcome doesn't verify with the same proof
+                assert!(!crate::verify_merkle_proof(
+                    signed_root,
+                    sfx_outcome_leaf(sfx_id, !expected_committed),
+                    &proof
+                ));
+            }
+            assert_eq!(
+                Attesters::get_sfx_outcome_proof(target, H256::repeat_byte(4)),
+                None
+            );
+        });
+    }
+
+    #[test]
+    fn test_batch_sfx_outcomes_prove_against_their_merkle_root() {
+        let committed = vec![H512::repeat_byte(1), H512::repeat_byte(2)];
+        let reverted = vec![H256::repeat_byte(3)];
+        let batch = BatchMessage::<BlockNumber> {
+            committed_sfx: Some(committed.clone()),
+            reverted_sfx: Some(reverted.clone()),
+            ..Default::default()
+        };
+        let root = batch.sfx_outcomes_root();
+        let leaves = batch.sfx_outcome_leaves();
+        assert_eq!(leaves.len(), 3);
+
+        for (index, (sfx_id, leaf)) in leaves.iter().enumerate() {
+            let (is_committed, proof) = batch.sfx_outcome_proof(*sfx_id).unwrap();
+            assert_eq!(is_committed, index < committed.len());
+            assert!(crate::verify_merkle_proof(root, *leaf, &proof));
+        }
+
+        // Outcomes can't be proven the other way around
+        let (_, proof) = batch.sfx_outcome_proof(reverted[0]).unwrap();
+        let (_, committed_leaf) = BatchMessage::<BlockNumber> {
+            committed_sfx: Some(vec![H512::from_slice(
+                &[reverted[0].as_bytes(), &[0u8; 32]].concat(),
+            )]),
+            ..Default::default()
+        }
+        .sfx_outcome_leaves()[0];
+        assert!(!crate::verify_merkle_proof(root, committed_leaf, &proof));
+        assert_eq!(batch.sfx_outcome_proof(H256::repeat_byte(4)), None);
+    }
+
+    #[test]
+    fn test_batches_sealed_within_window_are_merkleized_and_late_ones_recovered() {
+        let mut ext = ExtBuilder::default()
+            .with_standard_sfx_abi()
+            .with_eth_gateway_record()
+            .build();
+        ext.execute_with(|| {
+            let target: TargetId = ETHEREUM_TARGET;
+            assert_err!(
+                Attesters::set_target_batching_window(RuntimeOrigin::root(), target, Some(7)),
+                AttestersError::<MiniRuntime>::BatchingWindowNotMultipleOfDefault
+            );
+            assert_ok!(Attesters::set_target_batching_window(
+                RuntimeOrigin::root(),
+                target,
+                Some(6)
+            ));
+
+            let _current_block_1 = add_target_and_transition_to_next_batch(target, 0);
+            assert_ok!(Attesters::request_sfx_attestation_commit(
+                target,
+                H256::repeat_byte(1),
+                None
+            ));
+            let sealed_at = add_target_and_transition_to_next_batch(target, 1);
+
+            let batch = Attesters::get_latest_batch_to_sign(target).unwrap();
+            let crate::SealedBatchesRoot {
+                root,
+                sealed_batches,
+                ..
+            } = Attesters::batches_root_at(sealed_at).unwrap();
+            assert_eq!(sealed_batches, vec![(target, 0, batch.message_hash())]);
+            let (proven_root, proof) =
+                Attesters::get_batches_root_proof(sealed_at, target, 0).unwrap();
+            assert_eq!(proven_root, root);
+            assert!(crate::verify_merkle_proof(
+                root,
+                crate::batches_root_leaves(&sealed_batches)[0],
+                &proof
+            ));
+
+            assert_err!(
+                Attesters::recover_late_target(RuntimeOrigin::root(), target),
+                AttestersError::<MiniRuntime>::NoLateBatchesToRecover
+            );
+            // The batch goes late once left unattested over the next window
+            let _late_at = transition_to_next_batch(target, 1);
+            assert_ok!(Attesters::recover_late_target(
+                RuntimeOrigin::root(),
+                target
+            ));
+
+            let recovered_at = transition_to_next_batch(target, 1);
+            assert_eq!(
+                Attesters::batches_root_at(recovered_at)
+                    .map(|sealed_root| (sealed_root.root, sealed_root.sealed_batches)),
+                Some((root, sealed_batches))
+            );
+            assert_eq!(
+                Attesters::get_latest_batch_to_sign(target).unwrap().latency,
+                LatencyStatus::OnTime
+            );
+        });
+    }
+
+    #[test]
+    fn batches_root_is_attested_once_signed_by_the_committee_majority() {
+        let mut ext = ExtBuilder::default()
+            .with_standard_sfx_abi()
+            .with_eth_gateway_record()
+            .build();
+        ext.execute_with(|| {
+            let target: TargetId = ETHEREUM_TARGET;
+            for counter in 1..22u8 {
+                register_attester_with_single_private_key([counter; 32]);
+            }
+            select_new_committee();
+
+            let _current_block_1 = add_target_and_transition_to_next_batch(target, 0);
+            assert_ok!(Attesters::request_sfx_attestation_commit(
+                target,
+                H256::repeat_byte(1),
+                None
+            ));
+            let sealed_at = add_target_and_transition_to_next_batch(target, 1);
+            let root = Attesters::batches_root_at(sealed_at).unwrap().root;
+            let sign_root = |secret_key: [u8; 32], root: H256| {
+                ecdsa::Pair::from_seed(&secret_key)
+                    .sign_prehashed(&root.0)
+                    .encode()
+            };
+
+            assert_noop!(
+                Attesters::submit_batches_root_attestation(
+                    RuntimeOrigin::signed(AccountId::from([1u8; 32])),
+                    sealed_at + 1,
+                    sign_root([1u8; 32], root),
+                    target,
+                ),
+                AttestersError::<MiniRuntime>::BatchesRootNotFound
+            );
+            assert_noop!(
+                Attesters::submit_batches_root_attestation(
+                    RuntimeOrigin::signed(AccountId::from([1u8; 32])),
+                    sealed_at,
+                    sign_root([1u8; 32], H256::repeat_byte(2)),
+                    target,
+                ),
+                AttestersError::<MiniRuntime>::AttestationSignatureInvalid
+            );
+            assert_noop!(
+                Attesters::submit_batches_root_attestation(
+                    RuntimeOrigin::signed(AccountId::from([22u8; 32])),
+                    sealed_at,
+                    sign_root([22u8; 32], root),
+                    target,
+                ),
+                AttestersError::<MiniRuntime>::NotRegistered
+            );
+
+            let root_attested =
+                Event::Attesters(AttestersEvent::BatchesRootAttested(sealed_at, root));
+            for counter in 1..22u8 {
+                assert!(!System::events()
+                    .iter()
+                    .any(|record| record.event == root_attested));
+                assert_ok!(Attesters::submit_batches_root_attestation(
+                    RuntimeOrigin::signed(AccountId::from([counter; 32])),
+                    sealed_at,
+                    sign_root([counter; 32], root),
+                    target,
+                ));
+            }
+            assert!(System::events()
+                .iter()
+                .any(|record| record.event == root_attested));
+            assert_eq!(
+                Attesters::batches_root_at(sealed_at)
+                    .unwrap()
+                    .signatures
+                    .len(),
+                21
+            );
+
+            assert_noop!(
+                Attesters::submit_batches_root_attestation(
+                    RuntimeOrigin::signed(AccountId::from([1u8; 32])),
+                    sealed_at,
+                    sign_root([1u8; 32], root),
+                    target,
+                ),
+                AttestersError::<MiniRuntime>::AttestationDoubleSignAttempt
+            );
+        });
+    }
+
+    #[test]
+    fn batches_roots_are_pruned_past_max_batches_roots() {
+        let mut ext = ExtBuilder::default()
+            .with_standard_sfx_abi()
+            .with_eth_gateway_record()
+            .build();
+        ext.execute_with(|| {
+            let target: TargetId = ETHEREUM_TARGET;
+            let _current_block_1 = add_target_and_transition_to_next_batch(target, 0);
+            // Seal the next batch past the windows of the roots already kept
+            let batching_window: BlockNumber =
+                <MiniRuntime as ConfigAttesters>::BatchingWindow::get();
+            System::set_block_number(batching_window * 300);
+
+            let oldest_windows: Vec<BlockNumber> =
+                (1..=crate::MAX_BATCHES_ROOTS as BlockNumber).collect();
+            for window in oldest_windows.iter() {
+                crate::BatchesRoots::<MiniRuntime>::insert(
+                    window,
+                    crate::SealedBatchesRoot {
+                        root: H256::repeat_byte(7),
+                        sealed_batches: vec![],
+                        signatures: vec![],
+                    },
+                );
+            }
+            crate::BatchesRootsHistory::<MiniRuntime>::put(oldest_windows);
+
+            assert_ok!(Attesters::request_sfx_attestation_commit(
+                target,
+                H256::repeat_byte(1),
+                None
+            ));
+            let sealed_at = add_target_and_transition_to_next_batch(target, 1);
+
+            let history = crate::BatchesRootsHistory::<MiniRuntime>::get();
+            assert_eq!(history.len(), crate::MAX_BATCHES_ROOTS);
+            assert_eq!(history.first(), Some(&2));
+            assert_eq!(history.last(), Some(&sealed_at));
+            assert_eq!(Attesters::batches_root_at(1), None);
+            assert!(Attesters::batches_root_at(2).is_some());
+            assert!(Attesters::batches_root_at(sealed_at).is_some());
+        });
+    }
+
     #[test]
     fn test_pending_attestation_batch_with_committee_transition_yields_correct_message_hash() {
         let mut ext = ExtBuilder::default()
@@ -3979,6 +5274,21 @@ pub mod attesters_test {
         });
     }
 
+    fn keccak_256(data: &[u8]) -> [u8; 32] {
+        let mut keccak = Keccak::v256();
+        keccak.update(data);
+        let mut res: [u8; 32] = [0; 32];
+        keccak.finalize(&mut res);
+        res
+    }
+
+    fn sfx_outcome_leaf(sfx_id: H256, is_committed: bool) -> H256 {
+        let mut encoded = [0u8; 64];
+        encoded[..32].copy_from_slice(sfx_id.as_bytes());
+        encoded[63] = is_committed as u8;
+        H256::from(keccak_256(&keccak_256(&encoded)))
+    }
+
     fn calculate_hash_for_sfx_message(message: Vec<u8>, index: u32) -> ([u8; 32], Vec<u8>) {
         let mut message_bytes: Vec<u8> = Vec::new();
         message_bytes.extend_from_slice(message.as_slice());
@@ -4521,12 +5831,17 @@ pub mod attesters_test {
         };
 
         let msg = filled_batch.message();
+        // 35 committee addresses as 32-byte words, followed by the SFX outcomes root
+        assert_eq!(
+            &msg[35 * 32..36 * 32],
+            filled_batch.sfx_outcomes_root().as_bytes()
+        );
         let msg_as_hex = hex::encode(msg);
-        assert_eq!(msg_as_hex, "0000000000000000000000002b7a372d58541c3053793f022cf28ef971f94efa00000000000000000000000060ea580734420a9c23e51c7fdf455b5e0237e07c00000000000000000000000098df91ef04a5c0695f8050b7da4facc0e7d9444e0000000000000000000000003cfbc429d7435fd5707390362c210bd272bae8ea00000000000000000000000066ed579d14cbad8dfc352a3ceaeee9711ea65e41000000000000000000000000786402fa462909785a55ced48aa5682d99902c57000000000000000000000000401b7cb06493efdb82818f14f9cd345c01463a81000000000000000000000000a2e7607a23b5a744a10a096c936ab033866d3bee000000000000000000000000ac9c643b32916ea52e0fa0c3a3bbdbe120e5ca9e000000000000000000000000d53d6af58a2bd8c0f86b25b1309c91f61700144f0000000000000000000000002fef1f5268d9732cac331785987d45fad487fcd6000000000000000000000000debc7a55486dbacb06985ba2415b784e05a35bae000000000000000000000000d7b33a07ee05b604138f94335405b55e2b6bbfdd0000000000000000000000001831c8f78c8b59c1300b79e308bfbf9e4fdd13b0000000000000000000000000361134e27af99a288714e428c290d48f82a4895c0000000000000000000000005897b47e1357ed81b2d85d8f287759502e33f588000000000000000000000000a880bf7e031ed87d422d31bebcc9d0339c7b95b4000000000000000000000000edab03983d839e6a3a887c3ee711a724391f8ee100000000000000000000000080d80649e13268382cea3b0a56a57078c2076fe1000000000000000000000000b0de4907432a9a4ac92f4988daa6024cd57d1b270000000000000000000000005449d051328da4cfe8d1efe7481ff3b690cf86960000000000000000000000004705522d19458a90f06a15d9836a64e45c182c9f000000000000000000000000b6de743a22a7a43edda8b5e21e2f0aeb70354f5b000000000000000000000000970c0720316bc03cd055c5ec74208fe0ba3d3c440000000000000000000000007905754a5b6a28d1edf338d9be06a49ad60d74b600000000000000000000000093054a6f5eb0e1978d1e3e27ae758f17480e5988000000000000000000000000a185b4f947a09286fc028b034f01babe53d9830100000000000000000000000014c74ce14e833d76dc0190651c0eba64f3e67c79000000000000000000000000861fa47e5229c9079d087d6354c1ede95d233f430000000000000000000000006f9925aceffbe67742257abff393b123010c4a10000000000000000000000000a1ea906c54379032c9857139c6f796acf88ddb790000000000000000000000006219f12779268f8a7ddf0f1e44fd75253219d6390000000000000000000000002b7a372d58541c3053793f022cf28ef971f94efa00000000000000000000000060ea580734420a9c23e51c7fdf455b5e0237e07c00000000000000000000000098df91ef04a5c0695f8050b7da4facc0e7d9444e6e906f8388de8faea67a770476ade4b76654545002126aa3ea17890fd8acdd7e0000000000000000000000000000000000000000000000000000000000000000580032f247eebb5c75889ab42c43dd88a1071c3950f9bbab1f901c47d5331dfa0000000000000000000000000000000000000000000000000000000000000000e23ab05c5ca561870b6f55d3fcb94ead2b14d8ce49ccf159b8e3449cbd5050c60000000000000000000000000000000000000000000000000000000000000000ff17743a6b48933b94f38f423b15b2fc9ebcd34aab19bd81c2a69d3d052f467f21e5cd2c2f3e32ac4a52543a386821b079711432c2fefd4be3836ed36d129b1100000001");
+        assert_eq!(msg_as_hex, "0000000000000000000000002b7a372d58541c3053793f022cf28ef971f94efa00000000000000000000000060ea580734420a9c23e51c7fdf455b5e0237e07c00000000000000000000000098df91ef04a5c0695f8050b7da4facc0e7d9444e0000000000000000000000003cfbc429d7435fd5707390362c210bd272bae8ea00000000000000000000000066ed579d14cbad8dfc352a3ceaeee9711ea65e41000000000000000000000000786402fa462909785a55ced48aa5682d99902c57000000000000000000000000401b7cb06493efdb82818f14f9cd345c01463a81000000000000000000000000a2e7607a23b5a744a10a096c936ab033866d3bee000000000000000000000000ac9c643b32916ea52e0fa0c3a3bbdbe120e5ca9e000000000000000000000000d53d6af58a2bd8c0f86b25b1309c91f61700144f0000000000000000000000002fef1f5268d9732cac331785987d45fad487fcd6000000000000000000000000debc7a55486dbacb06985ba2415b784e05a35bae000000000000000000000000d7b33a07ee05b604138f94335405b55e2b6bbfdd0000000000000000000000001831c8f78c8b59c1300b79e308bfbf9e4fdd13b0000000000000000000000000361134e27af99a288714e428c290d48f82a4895c0000000000000000000000005897b47e1357ed81b2d85d8f287759502e33f588000000000000000000000000a880bf7e031ed87d422d31bebcc9d0339c7b95b4000000000000000000000000edab03983d839e6a3a887c3ee711a724391f8ee100000000000000000000000080d80649e13268382cea3b0a56a57078c2076fe1000000000000000000000000b0de4907432a9a4ac92f4988daa6024cd57d1b270000000000000000000000005449d051328da4cfe8d1efe7481ff3b690cf86960000000000000000000000004705522d19458a90f06a15d9836a64e45c182c9f000000000000000000000000b6de743a22a7a43edda8b5e21e2f0aeb70354f5b000000000000000000000000970c0720316bc03cd055c5ec74208fe0ba3d3c440000000000000000000000007905754a5b6a28d1edf338d9be06a49ad60d74b600000000000000000000000093054a6f5eb0e1978d1e3e27ae758f17480e5988000000000000000000000000a185b4f947a09286fc028b034f01babe53d9830100000000000000000000000014c74ce14e833d76dc0190651c0eba64f3e67c79000000000000000000000000861fa47e5229c9079d087d6354c1ede95d233f430000000000000000000000006f9925aceffbe67742257abff393b123010c4a10000000000000000000000000a1ea906c54379032c9857139c6f796acf88ddb790000000000000000000000006219f12779268f8a7ddf0f1e44fd75253219d6390000000000000000000000002b7a372d58541c3053793f022cf28ef971f94efa00000000000000000000000060ea580734420a9c23e51c7fdf455b5e0237e07c00000000000000000000000098df91ef04a5c0695f8050b7da4facc0e7d9444e3d06a118d927b2e459eeda91cbec1dbef4bc97923a46fcf484a686760c33f3f100000001");
 
         assert_eq!(
             filled_batch.message_hash(),
-            hex!("f59f343a5c07f43e9a654e43ed9ce710005802f9e63a87111ed95072617008d0").into() // hex!("0e5ff1395ff4b94e02bad28b793efe3e27a32b3170191aae7a0a7c3c46a4a718").into()
+            hex!("476502d7e5ff5680cf62f9625b1a5d8d8bcb689b70ba0efc6317d820d705c152").into()
         );
     }
 
@@ -0,0 +1,117 @@
+//! Weights for pallet_attesters
+//!
+//! Estimated from the storage each call accesses ahead of a run on reference hardware, as
+//! `benchmarking.rs` doesn't cover the pallet yet. Fraud proofs scale with the bytes `m` of the
+//! messages they hash. Regenerate the numbers with the command below once it does.
+
+// Executed Command:
+// ./target/release/circuit
+// benchmark
+// pallet
+// --chain
+// dev
+// --execution
+// wasm
+// --wasm-execution
+// compiled
+// --pallet
+// pallet_attesters
+// --extrinsic
+// *
+// --steps
+// 50
+// --repeat
+// 20
+// --output
+// .
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{
+    traits::Get,
+    weights::{constants::RocksDbWeight, Weight},
+};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_attesters.
+pub trait WeightInfo {
+    fn set_target_batching_window() -> Weight;
+    fn recover_late_target() -> Weight;
+    fn submit_batches_root_attestation() -> Weight;
+    fn submit_fraud_proof(m: u32) -> Weight;
+}
+
+/// Weights for pallet_attesters using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+    /// Storage: Attesters TargetBatchingWindows (r:0 w:1)
+    fn set_target_batching_window() -> Weight {
+        Weight::from_parts(14_000_000_u64, 0u64).saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+
+    /// Storage: Attesters Batches (r:1 w:0), Attesters LateBatchesToRecover (r:1 w:1)
+    fn recover_late_target() -> Weight {
+        Weight::from_parts(28_000_000_u64, 0u64)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+
+    /// Storage: Attesters Attesters (r:1 w:0), Attesters ActiveSet (r:1 w:0),
+    /// Attesters CurrentCommittee (r:1 w:0), Attesters AttestersAgreements (r:1 w:0),
+    /// XDNS Gateways (r:1 w:0), Attesters BatchesRoots (r:1 w:1)
+    fn submit_batches_root_attestation() -> Weight {
+        Weight::from_parts(62_000_000_u64, 0u64)
+            .saturating_add(T::DbWeight::get().reads(6_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+
+    /// Storage: Attesters PermanentSlashes (r:1 w:1), Attesters Attesters (r:2 w:0),
+    /// Attesters AttestersAgreements (r:2 w:0), XDNS Gateways (r:2 w:0), Attesters Batches (r:1 w:0),
+    /// Attesters Nominations (r:1 w:1), System Account (r:2 w:2), Attesters ActiveSet (r:1 w:1),
+    /// Attesters CurrentCommittee (r:1 w:1), Attesters NextCommittee (r:1 w:1),
+    /// Attesters SortedNominatedAttesters (r:1 w:1)
+    fn submit_fraud_proof(m: u32) -> Weight {
+        Weight::from_parts(148_000_000_u64, 0u64)
+            .saturating_add(Weight::from_parts(4_000_u64, 0u64).saturating_mul(m as u64))
+            .saturating_add(T::DbWeight::get().reads(15_u64))
+            .saturating_add(T::DbWeight::get().writes(8_u64))
+    }
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+    /// Storage: Attesters TargetBatchingWindows (r:0 w:1)
+    fn set_target_batching_window() -> Weight {
+        Weight::from_parts(14_000_000_u64, 0u64).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    /// Storage: Attesters Batches (r:1 w:0), Attesters LateBatchesToRecover (r:1 w:1)
+    fn recover_late_target() -> Weight {
+        Weight::from_parts(28_000_000_u64, 0u64)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    /// Storage: Attesters Attesters (r:1 w:0), Attesters ActiveSet (r:1 w:0),
+    /// Attesters CurrentCommittee (r:1 w:0), Attesters AttestersAgreements (r:1 w:0),
+    /// XDNS Gateways (r:1 w:0), Attesters BatchesRoots (r:1 w:1)
+    fn submit_batches_root_attestation() -> Weight {
+        Weight::from_parts(62_000_000_u64, 0u64)
+            .saturating_add(RocksDbWeight::get().reads(6_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    /// Storage: Attesters PermanentSlashes (r:1 w:1), Attesters Attesters (r:2 w:0),
+    /// Attesters AttestersAgreements (r:2 w:0), XDNS Gateways (r:2 w:0), Attesters Batches (r:1 w:0),
+    /// Attesters Nominations (r:1 w:1), System Account (r:2 w:2), Attesters ActiveSet (r:1 w:1),
+    /// Attesters CurrentCommittee (r:1 w:1), Attesters NextCommittee (r:1 w:1),
+    /// Attesters SortedNominatedAttesters (r:1 w:1)
+    fn submit_fraud_proof(m: u32) -> Weight {
+        Weight::from_parts(148_000_000_u64, 0u64)
+            .saturating_add(Weight::from_parts(4_000_u64, 0u64).saturating_mul(m as u64))
+            .saturating_add(RocksDbWeight::get().reads(15_u64))
+            .saturating_add(RocksDbWeight::get().writes(8_u64))
+    }
+}
@@ -0,0 +1,33 @@
+//! Runtime API definition exposing the ids circuit assigns to Xtx and their side effects,
+//! and the side effects open for bidding.
+//!
+//! This API should be imported and implemented by the runtime
+//! including the circuit pallet, so that executor clients and SDKs
+//! derive the same ids as the chain does.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::prelude::*;
+pub use t3rn_primitives::circuit::OpenOrder;
+use t3rn_types::sfx::{SideEffect, TargetId};
+
+sp_api::decl_runtime_apis! {
+    /// The API to interact with pallet Circuit
+    #[api_version(2)]
+    pub trait CircuitRuntimeApi<AccountId, Balance, Hash, BlockNumber> where
+        AccountId: Codec,
+        Balance: Codec,
+        Hash: Codec,
+        BlockNumber: Codec,
+    {
+        /// Returns the id of the Xtx the requester submits at the given nonce, being the
+        /// requester's account nonce as read by circuit while dispatching the submission
+        fn compute_xtx_id(requester: AccountId, requester_nonce: u32) -> Hash;
+        /// Returns the id of the side effect at the given index of the Xtx
+        fn compute_sfx_id(side_effect: SideEffect<AccountId, Balance>, xtx_id: Hash, index: u32) -> Hash;
+        /// Returns the side effects on the gateway open for bidding with their best bids, highest max reward first
+        #[api_version(2)]
+        fn order_book(gateway_id: TargetId) -> Vec<OpenOrder<Hash, AccountId, Balance, BlockNumber>>;
+    }
+}
@@ -55,6 +55,20 @@ impl<T: Config> Bids<T> {
             fsx.security_lvl.clone(),
             fsx.input.insurance,
         );
+        // Check if bidder is registered as an executor.
+        if !T::Executors::is_registered(bidder) {
+            return Err(Error::<T>::BiddingRejectedExecutorNotRegistered)
+        }
+        // Check if bidder's reputation allows bidding on the SFX of this value.
+        if !T::Executors::is_eligible_for(bidder, fsx.input.max_reward) {
+            return Err(Error::<T>::BiddingRejectedExecutorReputationTooLow)
+        }
+        // Check if bidder's bond meets the minimum scaled by the target gateway's tier and revert rate.
+        if T::Executors::collateral_bond(bidder)
+            < T::Xdns::min_executor_bond(&fsx.input.target, T::Executors::min_executor_bond())
+        {
+            return Err(Error::<T>::BiddingRejectedExecutorBondTooLow)
+        }
         // Check if bid doesn't go below dust limit.
         if bid.amount < T::Currency::minimum_balance() {
             return Err(Error::<T>::BiddingRejectedBidBelowDust)
@@ -83,6 +97,10 @@ impl<T: Config> Bids<T> {
             bid.insurance = T::Attesters::estimate_finality_fee(&fsx.input.target);
             bid.reward_asset_id = None;
         } else if sfx_security_lvl == SecurityLvl::Optimistic {
+            // Only executors selected into the active set of the round can bid on Optimistic SFX
+            if !T::Executors::is_active(bidder) {
+                return Err(Error::<T>::BiddingRejectedExecutorNotInActiveSet)
+            }
             // Is the current bid for type SFX::Optimistic? If yes reserve the bond lock requirements
             let total_xtx_step_optimistic_rewards_of_others = step_fsx
                 .iter()
@@ -91,21 +109,23 @@ impl<T: Config> Bids<T> {
                 .filter(|&fsx| fsx.calc_sfx_id::<SystemHashing<T>, T>(xtx_id) != sfx_id)
                 // Since we don't know the final bid amounts, sum up the max reward for each SFX
                 .map(|fsx| fsx.input.max_reward)
-                .reduce(|total_reserved, next_amount| {
-                    total_reserved
-                        .checked_add(&next_amount)
-                        .unwrap_or(total_reserved)
-                });
+                .try_fold(Zero::zero(), |total_reserved: BalanceOf<T>, next_amount| {
+                    total_reserved.checked_add(&next_amount)
+                })
+                .ok_or(Error::<T>::ArithmeticErrorOverflow)?;
 
-            bid.reserved_bond = match total_xtx_step_optimistic_rewards_of_others {
-                Some(x) =>
-                    if x > Zero::zero() {
-                        Some(x)
-                    } else {
-                        None
-                    },
-                None => None,
+            bid.reserved_bond = if total_xtx_step_optimistic_rewards_of_others > Zero::zero() {
+                Some(total_xtx_step_optimistic_rewards_of_others)
+            } else {
+                None
             };
+
+            // Optimistic bonds are backed by the executor's self-bond and delegated stake.
+            if let Some(reserved_bond) = bid.reserved_bond {
+                if reserved_bond > T::Executors::bonding_capacity(bidder) {
+                    return Err(Error::<T>::BiddingRejectedExecutorBondingCapacityExceeded)
+                }
+            }
         }
 
         SquareUp::<T>::try_bid(sfx_id, requester, bidder, &bid, current_accepted_bid).map_err(
@@ -0,0 +1,89 @@
+use crate::{pallet::Error, *};
+use frame_support::ensure;
+use scale_info::TypeInfo;
+use sp_std::marker::PhantomData;
+
+/// Funds an executor has escrowed on t3rn for an `SecurityLvl::Escrow` SFX, held until its Xtx is
+/// finalized: paid out to the SFX target on commit, refunded to the executor otherwise.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct EscrowHolding<Hash, Balance> {
+    pub xtx_id: Hash,
+    pub escrow_id: Hash,
+    pub asset: Option<AssetId>,
+    pub amount: Balance,
+}
+
+pub type EscrowHoldingOf<T> = EscrowHolding<<T as frame_system::Config>::Hash, BalanceOf<T>>;
+
+pub struct EscrowVault<T: Config> {
+    _phantom: PhantomData<T>,
+}
+
+impl<T: Config> EscrowVault<T> {
+    /// Account the funds escrowed for `sfx_id` to the vault, provided they keep the exposure to
+    /// their asset within its cap. Assets without a cap are uncapped.
+    pub fn lock(
+        xtx_id: XExecSignalId<T>,
+        sfx_id: SideEffectId<T>,
+        escrow_id: T::Hash,
+        asset: Option<AssetId>,
+        amount: BalanceOf<T>,
+    ) -> Result<(), Error<T>> {
+        ensure!(
+            !<pallet::Pallet<T> as Store>::EscrowedSFX::contains_key(sfx_id),
+            Error::<T>::EscrowAlreadyHeld
+        );
+        let exposure = <pallet::Pallet<T> as Store>::EscrowExposure::get(asset)
+            .checked_add(&amount)
+            .ok_or(Error::<T>::ArithmeticErrorOverflow)?;
+        if let Some(cap) = <pallet::Pallet<T> as Store>::EscrowCaps::get(asset) {
+            ensure!(exposure <= cap, Error::<T>::EscrowCapExceeded);
+        }
+
+        <pallet::Pallet<T> as Store>::EscrowExposure::insert(asset, exposure);
+        <pallet::Pallet<T> as Store>::EscrowedSFX::insert(
+            sfx_id,
+            EscrowHolding {
+                xtx_id,
+                escrow_id,
+                asset,
+                amount,
+            },
+        );
+        pallet::Pallet::<T>::deposit_event(Event::EscrowLocked(xtx_id, sfx_id, asset, amount));
+        Ok(())
+    }
+
+    /// Release the funds held for `sfx_id` once their Xtx is finalized with `outcome`.
+    pub fn release(sfx_id: SideEffectId<T>, outcome: &Outcome) {
+        let Some(holding) = <pallet::Pallet<T> as Store>::EscrowedSFX::take(sfx_id) else {
+            return
+        };
+        <pallet::Pallet<T> as Store>::EscrowExposure::mutate(holding.asset, |exposure| {
+            *exposure = exposure.saturating_sub(holding.amount)
+        });
+        pallet::Pallet::<T>::deposit_event(Event::EscrowReleased(
+            holding.xtx_id,
+            sfx_id,
+            holding.asset,
+            holding.amount,
+            outcome.clone(),
+        ));
+    }
+
+    /// Withdraw the funds held for `sfx_id` ahead of their Xtx finalization, reverting the Xtx so
+    /// that every escrow it holds is refunded to its executor.
+    pub fn emergency_withdraw(sfx_id: SideEffectId<T>) -> Result<XExecSignalId<T>, Error<T>> {
+        let holding = <pallet::Pallet<T> as Store>::EscrowedSFX::get(sfx_id)
+            .ok_or(Error::<T>::EscrowNotHeld)?;
+        ensure!(
+            Machine::<T>::revert(
+                holding.xtx_id,
+                Cause::IntentionalKill,
+                infallible_no_post_updates
+            ),
+            Error::<T>::EscrowWithdrawalFailed
+        );
+        Ok(holding.xtx_id)
+    }
+}
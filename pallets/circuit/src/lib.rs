@@ -41,7 +41,7 @@ use frame_system::{
 };
 use sp_core::H256;
 use sp_runtime::{
-    traits::{CheckedAdd, Zero},
+    traits::{CheckedAdd, Saturating, Zero},
     DispatchError, KeyTypeId,
 };
 use sp_std::{convert::TryInto, vec, vec::Vec};
@@ -67,8 +67,13 @@ pub use t3rn_primitives::{
 };
 
 use crate::{
+    escrow::{EscrowHoldingOf, EscrowVault},
     machine::{Machine, *},
+    reorgs::Reorgs,
+    retention::Retention,
+    sponsor::{Sponsor, SponsorshipVoucherOf},
     square_up::SquareUp,
+    telemetry::{GatewayTelemetry, OpenSfxStats, Telemetry},
 };
 pub use state::XExecSignal;
 
@@ -77,10 +82,14 @@ use t3rn_abi::{recode::Codec, sfx_abi::SFXAbi};
 pub use t3rn_primitives::light_client::InclusionReceipt;
 use t3rn_primitives::{
     attesters::AttestersWriteApi,
-    circuit::{CircuitSubmitAPI, ReadSFX},
+    circuit::{CircuitSubmitAPI, OpenOrder, ReadSFX, SfxNotification},
+    compression,
 };
 pub use t3rn_sdk_primitives::signal::{ExecutionSignal, SignalKind};
-use t3rn_types::{fsx::TargetId, sfx::Sfx4bId};
+use t3rn_types::{
+    fsx::TargetId,
+    sfx::{Sfx4bId, MESSAGE_SIDE_EFFECT_ID, READ_SIDE_EFFECT_ID},
+};
 
 #[cfg(test)]
 pub mod tests;
@@ -89,9 +98,17 @@ pub mod tests;
 mod benchmarking;
 
 pub mod bids;
+pub mod escrow;
 pub mod machine;
+pub mod migrations;
+pub mod reorgs;
+pub mod retention;
+#[cfg(feature = "dev-simulation")]
+pub mod simulation;
+pub mod sponsor;
 pub mod square_up;
 pub mod state;
+pub mod telemetry;
 pub mod weights;
 
 /// Defines application identifier for crypto keys of this module.
@@ -116,7 +133,7 @@ pub mod pallet {
         pallet_prelude::*,
         traits::{
             fungible::{Inspect, Mutate},
-            Get,
+            Get, OnRuntimeUpgrade,
         },
     };
     use frame_system::pallet_prelude::*;
@@ -167,18 +184,20 @@ pub mod pallet {
         pub witness: Data,
     }
 
+    use sp_runtime::traits::{IdentifyAccount, Verify};
     use sp_std::borrow::ToOwned;
     use t3rn_primitives::{
         attesters::AttestersWriteApi,
         circuit::{
             CircuitDLQ, CircuitSubmitAPI, LocalStateExecutionView, LocalTrigger, OnLocalTrigger,
-            ReadSFX,
+            OnXtxConcluded, ReadSFX, XcmOrder, XcmOrderNotifier,
         },
+        executors::OnExecutorDeactivated,
         portal::Portal,
         xdns::Xdns,
         SpeedMode,
     };
-    use t3rn_types::{migrations::v13::FullSideEffectV13, sfx::Sfx4bId};
+    use t3rn_types::sfx::Sfx4bId;
 
     pub use crate::weights::WeightInfo;
 
@@ -278,6 +297,47 @@ pub mod pallet {
         OptionQuery,
     >;
 
+    /// Committed and reverted Xtx, under the block they settled at, along with their SFX ids,
+    ///     pruned from the links and records they leave behind once RetentionPeriod is over
+    #[pallet::storage]
+    pub type SettledXtx<T> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        Identity,
+        XExecSignalId<T>,
+        Vec<SideEffectId<T>>,
+        OptionQuery,
+    >;
+
+    /// Oldest block holding settled Xtx left to prune
+    #[pallet::storage]
+    pub type SettledXtxPruneCursor<T> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
+    /// Side effects open on each target, reported to monitoring along with the gateway's health
+    #[pallet::storage]
+    pub type OpenSfxByTarget<T> =
+        StorageMap<_, Identity, TargetId, OpenSfxStats<BalanceOf<T>>, ValueQuery>;
+
+    /// Confirmations of SFX on targets without absolute finality, under the Xtx and the target
+    /// height they were included at, invalidated by reorgs of the target till their Xtx settles
+    #[pallet::storage]
+    pub type ProvisionalConfirmations<T> = StorageDoubleMap<
+        _,
+        Identity,
+        TargetId,
+        Identity,
+        SideEffectId<T>,
+        (XExecSignalId<T>, BlockNumberFor<T>),
+        OptionQuery,
+    >;
+
+    /// Xtx confirmed by the Circuit on its own at the given block, under `dev-simulation` only
+    #[pallet::storage]
+    #[pallet::unbounded]
+    pub type SimulatedConfirmations<T> =
+        StorageMap<_, Twox64Concat, BlockNumberFor<T>, Vec<XExecSignalId<T>>, ValueQuery>;
+
     #[pallet::storage]
     #[pallet::getter(fn get_dlq)]
     pub type DLQ<T> = StorageMap<
@@ -288,6 +348,42 @@ pub mod pallet {
         OptionQuery,
     >;
 
+    /// Xtx ordered by sibling parachains over XCM, awaiting notification of their outcome.
+    #[pallet::storage]
+    #[pallet::getter(fn get_xcm_order)]
+    pub type XcmOrders<T> = StorageMap<_, Identity, XExecSignalId<T>, XcmOrder, OptionQuery>;
+
+    /// Nonce of the next voucher each sponsor can authorise.
+    #[pallet::storage]
+    #[pallet::getter(fn sponsor_nonce)]
+    pub type SponsorNonces<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+    /// Sponsors paying for the fees and reward deposits of Xtx on behalf of their requesters.
+    #[pallet::storage]
+    #[pallet::getter(fn get_xtx_sponsor)]
+    pub type XtxSponsors<T: Config> =
+        StorageMap<_, Identity, XExecSignalId<T>, T::AccountId, OptionQuery>;
+
+    /// Caps of the funds the escrow vault holds at once per asset, with `None` standing for the
+    /// native currency. Assets without a cap are uncapped.
+    #[pallet::storage]
+    #[pallet::getter(fn get_escrow_cap)]
+    pub type EscrowCaps<T: Config> =
+        StorageMap<_, Blake2_128Concat, Option<AssetId>, BalanceOf<T>, OptionQuery>;
+
+    /// Funds the escrow vault currently holds per asset.
+    #[pallet::storage]
+    #[pallet::getter(fn get_escrow_exposure)]
+    pub type EscrowExposure<T: Config> =
+        StorageMap<_, Blake2_128Concat, Option<AssetId>, BalanceOf<T>, ValueQuery>;
+
+    /// Funds held by the escrow vault for each Escrow SFX, until its Xtx is finalized.
+    #[pallet::storage]
+    #[pallet::getter(fn get_escrowed_sfx)]
+    pub type EscrowedSFX<T: Config> =
+        StorageMap<_, Identity, SideEffectId<T>, EscrowHoldingOf<T>, OptionQuery>;
+
     /// Handles queued signals
     ///
     /// This operation is performed lazily in `on_initialize`.
@@ -331,6 +427,41 @@ pub mod pallet {
         #[pallet::constant]
         type DeletionQueueLimit: Get<u32>;
 
+        /// The Circuit's retention period of settled Xtx - their SFX links and records are pruned
+        ///     once it's over, when blocks have weight to spare
+        #[pallet::constant]
+        type RetentionPeriod: Get<BlockNumberFor<Self>>;
+
+        /// The Circuit's limit of settled Xtx records pruned within a single block
+        #[pallet::constant]
+        type MaxPrunedPerBlock: Get<u32>;
+
+        /// The Circuit's interval of reporting gateways telemetry to monitoring, zero disabling it
+        #[pallet::constant]
+        type TelemetryInterval: Get<BlockNumberFor<Self>>;
+
+        /// The Circuit's number of headers built on top of SFX confirmations on gateways with
+        /// probabilistic finality, before they're accepted
+        #[pallet::constant]
+        type ConfirmationDepth: Get<BlockNumberFor<Self>>;
+
+        /// The Circuit's limit of side effects requested within a single Xtx
+        #[pallet::constant]
+        type MaxSideEffectsPerXtx: Get<u32>;
+
+        /// The Circuit's limit of steps a single Xtx executes its side effects in
+        #[pallet::constant]
+        type MaxStepsPerXtx: Get<u32>;
+
+        /// The Circuit's limit of bytes of any single encoded argument of a side effect
+        #[pallet::constant]
+        type MaxEncodedArgLen: Get<u32>;
+
+        /// The Circuit's delay of dev chains confirming SFX on their own once bids are accepted,
+        ///     in place of executors relaying confirmations from live chains
+        #[cfg(feature = "dev-simulation")]
+        type SimulatedConfirmationDelay: Get<BlockNumberFor<Self>>;
+
         /// The overarching event type.
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
@@ -380,6 +511,25 @@ pub mod pallet {
 
         // Needed in square_up mod
         type TreasuryAccounts: TreasuryAccountProvider<Self::AccountId>;
+
+        /// Origin of Xtx orders sent by sibling parachains over XCM, resolving to the sibling's
+        ///     para id and its sovereign account, which requests the Xtx and covers its rewards
+        type XcmOrderOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = (u32, Self::AccountId)>;
+
+        /// Reports the outcome of Xtx ordered over XCM back to their sibling parachains
+        type XcmOrderNotifier: XcmOrderNotifier<Self::Hash>;
+
+        /// Notified of every concluded Xtx, i.e. to deliver their results back to local contracts
+        type OnXtxConcluded: OnXtxConcluded<Self::Hash>;
+
+        /// Signature sponsors authorise vouchers with, covering Xtx submitted by others
+        type SponsorSignature: Parameter + Verify<Signer = Self::SponsorSigner>;
+
+        /// Identifies sponsors by the public key their vouchers are verified against
+        type SponsorSigner: IdentifyAccount<AccountId = Self::AccountId>;
+
+        /// Origin allowed to cap the escrow vault and to withdraw its funds in emergencies
+        type EscrowGovernanceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
     }
 
     #[pallet::pallet]
@@ -389,62 +539,43 @@ pub mod pallet {
 
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-        fn on_initialize(_n: frame_system::pallet_prelude::BlockNumberFor<T>) -> Weight {
-            Weight::zero()
+        #[cfg(not(feature = "dev-simulation"))]
+        fn on_initialize(n: frame_system::pallet_prelude::BlockNumberFor<T>) -> Weight {
+            Telemetry::<T>::report(n)
+        }
+
+        #[cfg(feature = "dev-simulation")]
+        fn on_initialize(n: frame_system::pallet_prelude::BlockNumberFor<T>) -> Weight {
+            simulation::Simulation::<T>::confirm_due(n).saturating_add(Telemetry::<T>::report(n))
         }
 
         fn on_finalize(_n: frame_system::pallet_prelude::BlockNumberFor<T>) {
             // x-t3rn#4: Go over open Xtx and cancel if necessary
         }
 
+        fn on_idle(n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            Retention::<T>::prune_settled(n, remaining_weight)
+        }
+
         fn offchain_worker(_n: frame_system::pallet_prelude::BlockNumberFor<T>) {}
 
         fn on_runtime_upgrade() -> Weight {
-            // Define the maximum weight of this migration.
-            let max_weight = T::DbWeight::get().reads_writes(10, 10);
-            // Define the current storage migration version.
-            const CURRENT_STORAGE_VERSION: u32 = 1;
-            // Migrate the storage entries.
-            StorageMigrations::<T>::try_mutate(|current_version| {
-                match *current_version {
-                    0 => {
-                        // Storage Migration: FSX::SFX updates field "encoded_action: Vec<u8>" to "action: Action: [u8; 4]"
-                        // Storage Migration Details: 16-03-2023; v1.3.0-rc -> v1.4.0-rc
-                        // Iterate through the old storage entries and migrate them.
-                        FullSideEffects::<T>::translate(
-                            |_,
-                             value: Vec<
-                                Vec<
-                                    FullSideEffectV13<
-                                        T::AccountId,
-                                        frame_system::pallet_prelude::BlockNumberFor<T>,
-                                        BalanceOf<T>,
-                                    >,
-                                >,
-                            >| {
-                                Some(
-                                    value
-                                        .into_iter()
-                                        .map(|v| v.into_iter().map(FullSideEffect::from).collect())
-                                        .collect(),
-                                )
-                            },
-                        );
+            crate::migrations::Migrations::<T>::on_runtime_upgrade()
+        }
 
-                        // Set migrations_done to true
-                        *current_version = CURRENT_STORAGE_VERSION;
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            crate::migrations::Migrations::<T>::pre_upgrade()
+        }
 
-                        // Return the weight consumed by the migration.
-                        Ok::<Weight, DispatchError>(max_weight)
-                    },
-                    // Add more migration cases here, if needed in the future
-                    _ => {
-                        // No migration needed.
-                        Ok::<Weight, DispatchError>(Weight::zero())
-                    },
-                }
-            })
-            .unwrap_or(Weight::zero())
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            crate::migrations::Migrations::<T>::post_upgrade(state)?;
+            ensure!(
+                StorageMigrations::<T>::get() == crate::migrations::CURRENT_STORAGE_VERSION,
+                "Circuit storage left behind the current version"
+            );
+            crate::migrations::ensure_in_flight_xtx_targets_registered::<T>()
         }
     }
 
@@ -461,6 +592,12 @@ pub mod pallet {
         }
     }
 
+    impl<T: Config> OnExecutorDeactivated<T::AccountId> for Pallet<T> {
+        fn on_executor_deactivated(executor: &T::AccountId) -> Weight {
+            Self::release_executor_bids(executor)
+        }
+    }
+
     impl<T: Config> ReadSFX<T::Hash, T::AccountId, BalanceOf<T>, BlockNumberFor<T>> for Pallet<T> {
         fn get_fsx_of_xtx(xtx_id: T::Hash) -> Result<Vec<T::Hash>, DispatchError> {
             let full_side_effects = FullSideEffects::<T>::get(xtx_id)
@@ -666,6 +803,10 @@ pub mod pallet {
         ) -> Result<InclusionReceipt<BlockNumberFor<T>>, DispatchError> {
             <T as Config>::Portal::verify_event_inclusion(target, speed_mode, source, encoded_proof)
         }
+
+        fn verify_sfx_proof_weight(proof_len: u32) -> Weight {
+            <T as Config>::Portal::verify_event_inclusion_weight(proof_len)
+        }
     }
 
     impl<T: Config> OnLocalTrigger<T, BalanceOf<T>> for Pallet<T> {
@@ -761,6 +902,39 @@ pub mod pallet {
             // Authorize: Retrieve sender of the transaction.
             let requester = Self::authorize(origin.to_owned(), CircuitRole::ContractAuthor)?;
 
+            // Side effects declared by a contract over its execution open a new Xtx, requested
+            // and paid for by the contract itself
+            if trigger.maybe_xtx_id.is_none() && !trigger.submitted_side_effects.is_empty() {
+                ensure!(
+                    requester == trigger.contract,
+                    Error::<T>::LocalExecutionUnauthorized
+                );
+                let side_effects = trigger
+                    .submitted_side_effects
+                    .into_iter()
+                    .map(SideEffect::<T::AccountId, BalanceOf<T>>::try_from)
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(|e| {
+                        log::debug!(
+                            target: "runtime::circuit",
+                            "Failed to decode side effects of local trigger: {:?}",
+                            e
+                        );
+                        Error::<T>::InvalidLocalTrigger
+                    })?;
+
+                let local_ctx = Self::do_on_extrinsic_trigger(
+                    requester,
+                    side_effects,
+                    trigger.speed_mode,
+                    &SecurityLvl::Optimistic,
+                    None,
+                    None,
+                )?;
+
+                return Self::load_local_state(origin, Some(local_ctx.xtx_id))
+            }
+
             let mut local_ctx = match trigger.maybe_xtx_id {
                 Some(xtx_id) => Machine::<T>::load_xtx(xtx_id)?,
                 None => {
@@ -836,9 +1010,40 @@ pub mod pallet {
             Ok(())
         }
 
-        #[pallet::weight(<T as pallet::Config>::WeightInfo::on_local_trigger())]
-        pub fn on_xcm_trigger(_origin: OriginFor<T>) -> DispatchResultWithPostInfo {
-            Err(Error::<T>::NotImplemented.into())
+        /// Submit side effects on behalf of a sibling parachain, i.e. with XCM `Transact`.
+        ///
+        /// The sibling's sovereign account requests the Xtx. Given `notify_call`, the sibling is sent
+        ///     that call back with the (xtx_id, status) outcome appended once the Xtx concludes.
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::on_extrinsic_trigger())]
+        pub fn on_xcm_trigger(
+            origin: OriginFor<T>,
+            side_effects: Vec<SideEffect<T::AccountId, BalanceOf<T>>>,
+            speed_mode: SpeedMode,
+            notify_call: Option<Vec<u8>>,
+        ) -> DispatchResultWithPostInfo {
+            let (para_id, sovereign) = T::XcmOrderOrigin::ensure_origin(origin)?;
+
+            let local_ctx = Self::do_on_extrinsic_trigger(
+                sovereign,
+                side_effects,
+                speed_mode,
+                &SecurityLvl::Optimistic,
+                None,
+                None,
+            )?;
+
+            if let Some(notify_call) = notify_call {
+                <XcmOrders<T>>::insert(
+                    local_ctx.xtx_id,
+                    XcmOrder {
+                        para_id,
+                        notify_call,
+                    },
+                );
+            }
+            Self::deposit_event(Event::XcmOrderReceived(local_ctx.xtx_id, para_id));
+
+            Ok(().into())
         }
 
         #[pallet::weight(<T as pallet::Config>::WeightInfo::on_local_trigger())]
@@ -905,6 +1110,7 @@ pub mod pallet {
                 speed_mode,
                 &SecurityLvl::Escrow,
                 Some(call_origin),
+                None,
             )?;
 
             Ok(().into())
@@ -926,6 +1132,7 @@ pub mod pallet {
                 speed_mode,
                 &preferred_security_level,
                 None,
+                None,
             )?;
 
             Ok(().into())
@@ -972,6 +1179,8 @@ pub mod pallet {
                 .input
                 .generate_id::<SystemHashing<T>>(sfx_id.as_ref(), 3333);
 
+            EscrowVault::<T>::lock(xtx_id, sfx_id, escrow_id, escrow_asset, escrow_amount)?;
+
             T::AccountManager::deposit(
                 escrow_id,
                 RequestCharge {
@@ -1003,7 +1212,16 @@ pub mod pallet {
                     ))
                 },
                 |status_change, local_ctx| {
-                    Self::deposit_event(Event::SideEffectConfirmed(sfx_id));
+                    Self::deposit_event(Event::SideEffectConfirmed(
+                        xtx_id,
+                        sfx_id,
+                        executor.clone(),
+                    ));
+                    T::Executors::note_confirmation(
+                        &executor,
+                        frame_system::Pallet::<T>::block_number()
+                            .saturating_sub(local_ctx.xtx.timeouts_at.estimated_height_here),
+                    );
                     if status_change.1 == CircuitStatus::FinishedAllSteps
                         || status_change.1 == CircuitStatus::Committed
                     {
@@ -1026,7 +1244,7 @@ pub mod pallet {
                     Error::<T>::FailedToPerformDynamicDestinationDealHotSwap
                 })?;
 
-            Self::deposit_event(Event::SideEffectConfirmed(sfx_id));
+            Self::deposit_event(Event::SideEffectConfirmed(xtx_id, sfx_id, executor.clone()));
 
             for (xtx_id, sfx_id, executor, new_action_id) in ddd_hotswap_result {
                 Self::deposit_event(Event::DynamicDestinationDealReplaced(
@@ -1064,6 +1282,7 @@ pub mod pallet {
                 },
                 |_status_change, _local_ctx| {
                     Self::deposit_event(Event::SFXNewBidReceived(
+                        xtx_id,
                         sfx_id,
                         bidder.clone(),
                         bid_amount,
@@ -1076,7 +1295,10 @@ pub mod pallet {
         }
 
         /// Blind version should only be used for testing - unsafe since skips inclusion proof check.
-        #[pallet::weight(< T as Config >::WeightInfo::confirm_side_effect())]
+        ///     Refunds the inclusion's verification weight whenever the light client was skipped.
+        #[pallet::weight(< T as Config >::WeightInfo::confirm_side_effect().saturating_add(
+            < T as Config >::Portal::verify_event_inclusion_weight(compression::max_decompressed_len(&confirmation.inclusion_data) as u32)
+        ))]
         pub fn confirm_side_effect(
             origin: OriginFor<T>,
             sfx_id: SideEffectId<T>,
@@ -1087,21 +1309,46 @@ pub mod pallet {
             >,
         ) -> DispatchResultWithPostInfo {
             // Authorize: Retrieve sender of the transaction.
-            let _executor = Self::authorize(origin, CircuitRole::Executor)?;
+            let executor = Self::authorize(origin, CircuitRole::Executor)?;
             let xtx_id = <Self as Store>::SFX2XTXLinksMap::get(sfx_id)
                 .ok_or(Error::<T>::LocalSideEffectExecutionNotApplicable)?;
+            let mut local_ctx = Machine::<T>::load_xtx(xtx_id)?;
+            let speed_mode = local_ctx.xtx.speed_mode.clone();
+            // Inclusions verified earlier skip the light client, whose weight is then refunded
+            let mut is_inclusion_cached = false;
 
             Machine::<T>::compile(
-                &mut Machine::<T>::load_xtx(xtx_id)?,
+                &mut local_ctx,
                 |current_fsx, _local_state, _steps_cnt, __status, _requester| {
-                    Self::confirm(xtx_id, current_fsx, &sfx_id, &confirmation).map_err(|e| {
+                    is_inclusion_cached = current_fsx
+                        .iter()
+                        .find(|fsx| fsx.calc_sfx_id::<SystemHashing<T>, T>(xtx_id) == sfx_id)
+                        .map_or(false, |fsx| {
+                            <T as Config>::Portal::is_event_inclusion_cached(
+                                fsx.input.target,
+                                &speed_mode,
+                                &Self::expected_event_source(&fsx.input).ok().flatten(),
+                                &confirmation.inclusion_data,
+                            )
+                        });
+                    let confirmation = Self::confirm(xtx_id, current_fsx, &sfx_id, &confirmation)
+                        .map_err(|e| {
                         log::error!("Self::confirm hit an error -- {:?}", e);
                         Error::<T>::ConfirmationFailed
                     })?;
                     Ok(PrecompileResult::TryConfirm(sfx_id, confirmation))
                 },
                 |status_change, local_ctx| {
-                    Self::deposit_event(Event::SideEffectConfirmed(sfx_id));
+                    Self::deposit_event(Event::SideEffectConfirmed(
+                        xtx_id,
+                        sfx_id,
+                        executor.clone(),
+                    ));
+                    T::Executors::note_confirmation(
+                        &executor,
+                        frame_system::Pallet::<T>::block_number()
+                            .saturating_sub(local_ctx.xtx.timeouts_at.estimated_height_here),
+                    );
                     if status_change.1 == CircuitStatus::FinishedAllSteps
                         || status_change.1 == CircuitStatus::Committed
                     {
@@ -1117,6 +1364,80 @@ pub mod pallet {
                 },
             )?;
 
+            // Weigh the inclusion as verified by the light client, which is charged by proof size
+            let inclusion_weight = if is_inclusion_cached {
+                T::DbWeight::get().reads(1)
+            } else if cfg!(feature = "test-skip-verification") {
+                Weight::zero()
+            } else {
+                <T as Config>::Portal::verify_event_inclusion_weight(
+                    compression::max_decompressed_len(&confirmation.inclusion_data) as u32,
+                )
+            };
+            Ok(Some(
+                <T as Config>::WeightInfo::confirm_side_effect().saturating_add(inclusion_weight),
+            )
+            .into())
+        }
+
+        /// Submit side effects paid for by a sponsor, who authorised the submission with a signed
+        ///     voucher. The sponsor covers the reward deposits up to the voucher's allowance, and is
+        ///     refunded whatever the Xtx doesn't spend. The transaction fee is charged to the sponsor
+        ///     by the runtime's fee adapter as long as the voucher checks out, else to the requester.
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::on_sponsored_extrinsic_trigger())]
+        pub fn on_sponsored_extrinsic_trigger(
+            origin: OriginFor<T>,
+            side_effects: Vec<SideEffect<T::AccountId, BalanceOf<T>>>,
+            speed_mode: SpeedMode,
+            preferred_security_level: SecurityLvl,
+            voucher: SponsorshipVoucherOf<T>,
+            signature: T::SponsorSignature,
+        ) -> DispatchResultWithPostInfo {
+            // Authorize: Retrieve sender of the transaction.
+            let requester = Self::authorize(origin, CircuitRole::Requester)?;
+
+            let sponsor =
+                Sponsor::<T>::try_redeem(&voucher, &signature, &requester, &side_effects)?;
+
+            let local_ctx = Self::do_on_extrinsic_trigger(
+                requester.clone(),
+                side_effects,
+                speed_mode,
+                &preferred_security_level,
+                None,
+                Some(sponsor.clone()),
+            )?;
+
+            Self::deposit_event(Event::XtxSponsored(local_ctx.xtx_id, sponsor, requester));
+
+            Ok(().into())
+        }
+
+        /// Cap the funds the escrow vault may hold at once in `asset`, or lift its cap with `None`.
+        ///     Escrows already held above a lowered cap remain until their Xtx is finalized.
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::set_escrow_cap())]
+        pub fn set_escrow_cap(
+            origin: OriginFor<T>,
+            asset: Option<AssetId>,
+            cap: Option<BalanceOf<T>>,
+        ) -> DispatchResultWithPostInfo {
+            T::EscrowGovernanceOrigin::ensure_origin(origin)?;
+            <Self as Store>::EscrowCaps::set(asset, cap);
+            Self::deposit_event(Event::EscrowCapSet(asset, cap));
+            Ok(().into())
+        }
+
+        /// Withdraw the funds escrowed for `sfx_id` before its Xtx is finalized, i.e. once the SFX
+        ///     target is found compromised. The Xtx is reverted, refunding each of its escrows to the
+        ///     executors who escrowed them.
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::emergency_escrow_withdrawal())]
+        pub fn emergency_escrow_withdrawal(
+            origin: OriginFor<T>,
+            sfx_id: SideEffectId<T>,
+        ) -> DispatchResultWithPostInfo {
+            T::EscrowGovernanceOrigin::ensure_origin(origin)?;
+            let xtx_id = EscrowVault::<T>::emergency_withdraw(sfx_id)?;
+            Self::deposit_event(Event::EscrowEmergencyWithdrawn(xtx_id, sfx_id));
             Ok(().into())
         }
     }
@@ -1124,6 +1445,9 @@ pub mod pallet {
     use crate::machine::{no_mangle, Machine};
 
     /// Events for the pallet.
+    ///
+    /// Events of an Xtx and its SFX lead with their identifiers in fixed positions - xtx_id, then
+    /// sfx_id, then the executor - as laid out by t3rn_primitives::EVENTS_VERSION.
     #[pallet::event]
     #[pallet::generate_deposit(pub (super) fn deposit_event)]
     pub enum Event<T: Config> {
@@ -1160,14 +1484,15 @@ pub mod pallet {
         Result(T::AccountId, AccountId32, XbiResult, Data, Data),
         // Listeners - users + SDK + UI to know whether their request is accepted for exec and pending
         XTransactionReceivedForExec(XExecSignalId<T>),
-        // New best bid for SFX has been accepted. Account here is an executor.
+        // New best bid for SFX has been accepted [xtx_id, sfx_id, executor, bid]
         SFXNewBidReceived(
+            XExecSignalId<T>,
             SideEffectId<T>,
             <T as frame_system::Config>::AccountId,
             BalanceOf<T>,
         ),
-        // An executions SideEffect was confirmed.
-        SideEffectConfirmed(XExecSignalId<T>),
+        // An executions SideEffect was confirmed [xtx_id, sfx_id, executor]
+        SideEffectConfirmed(XExecSignalId<T>, SideEffectId<T>, T::AccountId),
         // An executions SideEffect was confirmed.
         DynamicDestinationDealReplaced(XExecSignalId<T>, SideEffectId<T>, T::AccountId, Sfx4bId),
         // Listeners - users + SDK + UI to know whether their request is accepted for exec and ready
@@ -1185,17 +1510,25 @@ pub mod pallet {
         // Listeners - users + SDK + UI to know whether their request is accepted for exec and finished
         XTransactionXtxDroppedAtBidding(XExecSignalId<T>),
         // Listeners - executioners/relayers to know new challenges and perform offline risk/reward calc
-        //  of whether side effect is worth picking up
+        //  of whether side effect is worth picking up [xtx_id, requester, sfxs, sfx_ids]
         NewSideEffectsAvailable(
-            <T as frame_system::Config>::AccountId,
             XExecSignalId<T>,
+            <T as frame_system::Config>::AccountId,
             Vec<SideEffect<<T as frame_system::Config>::AccountId, BalanceOf<T>>>,
             Vec<SideEffectId<T>>,
         ),
+        // Listeners - executors that declared to serve the SFX's target and type, invited to bid on it
+        //  [xtx_id, sfx_id, executors]
+        SFXBidInvitation(
+            XExecSignalId<T>,
+            SideEffectId<T>,
+            Vec<<T as frame_system::Config>::AccountId>,
+        ),
         // Listeners - executioners/relayers to know that certain SideEffects are no longer valid
+        //  [xtx_id, requester, sfxs]
         CancelledSideEffects(
-            <T as frame_system::Config>::AccountId,
             XtxId<T>,
+            <T as frame_system::Config>::AccountId,
             Vec<SideEffect<<T as frame_system::Config>::AccountId, BalanceOf<T>>>,
         ),
         // Listeners - executioners/relayers to know whether they won the confirmation challenge
@@ -1220,6 +1553,47 @@ pub mod pallet {
         UnsuccessfulFSXCommitAttestationRequest(H256),
         SuccessfulFSXRevertAttestationRequest(H256),
         UnsuccessfulFSXRevertAttestationRequest(H256),
+        // Listeners - sibling parachains to know their XCM order has been accepted as Xtx [xtx_id, para_id]
+        XcmOrderReceived(XExecSignalId<T>, u32),
+        // Listeners - sibling parachains to know the outcome of their XCM order has been sent back
+        //  [xtx_id, para_id, status]
+        XcmOrderNotified(XExecSignalId<T>, u32, CircuitStatus),
+        XcmOrderNotificationFailed(XExecSignalId<T>, u32),
+        // Listeners - dApps to know their sponsorship was redeemed [xtx_id, sponsor, requester]
+        XtxSponsored(XExecSignalId<T>, T::AccountId, T::AccountId),
+        // Listeners - auditors to track the escrow vault [xtx_id, sfx_id, asset, amount]
+        EscrowLocked(
+            XExecSignalId<T>,
+            SideEffectId<T>,
+            Option<AssetId>,
+            BalanceOf<T>,
+        ),
+        // Listeners - auditors to track the escrow vault [xtx_id, sfx_id, asset, amount, outcome]
+        EscrowReleased(
+            XExecSignalId<T>,
+            SideEffectId<T>,
+            Option<AssetId>,
+            BalanceOf<T>,
+            Outcome,
+        ),
+        // Listeners - auditors to track governance of the escrow vault [xtx_id, sfx_id]
+        EscrowEmergencyWithdrawn(XExecSignalId<T>, SideEffectId<T>),
+        EscrowCapSet(Option<AssetId>, Option<BalanceOf<T>>),
+        // Listeners - indexers to drop their copies of settled Xtx [xtx_count, sfx_links_count]
+        SettledXtxPruned(u32, u32),
+        // Listeners - monitoring to alert on lagging gateways and piling up SFX [per-gateway telemetry]
+        GatewaysTelemetry(Vec<GatewayTelemetry<BlockNumberFor<T>, BalanceOf<T>>>),
+        // Listeners - executors to confirm again the SFX whose inclusion a reorg rolled back [xtx_id, sfx_ids]
+        XtxReconfirmationRequired(XExecSignalId<T>, Vec<SideEffectId<T>>),
+    }
+
+    #[pallet::extra_constants]
+    impl<T: Config> Pallet<T> {
+        /// Version of the layout of identifiers leading the events, bumped on breaking changes.
+        #[pallet::constant_name(EventsVersion)]
+        fn events_version() -> u32 {
+            t3rn_primitives::EVENTS_VERSION
+        }
     }
 
     #[pallet::error]
@@ -1253,6 +1627,11 @@ pub mod pallet {
         BiddingRejectedInsuranceTooLow,
         BiddingRejectedBetterBidFound,
         BiddingRejectedFailedToDepositBidderBond,
+        BiddingRejectedExecutorNotRegistered,
+        BiddingRejectedExecutorBondingCapacityExceeded,
+        BiddingRejectedExecutorNotInActiveSet,
+        BiddingRejectedExecutorReputationTooLow,
+        BiddingRejectedExecutorBondTooLow,
         BiddingFailedExecutorsBalanceTooLowToReserve,
         InsuranceBondAlreadyDeposited,
         InvalidFTXStateEmptyBidForReadyXtx,
@@ -1309,7 +1688,21 @@ pub mod pallet {
         SideEffectsValidationFailedAgainstABI,
         XtxChargeFailedOnEscrowFee,
         FailedToPerformDynamicDestinationDealHotSwap,
+        SecurityTierOfTargetInsufficientForRequestedSecurityLvl,
         NotImplemented,
+        SponsorshipRequesterMismatch,
+        SponsorshipExpired,
+        SponsorshipNonceMismatch,
+        SponsorshipSignatureInvalid,
+        SponsorshipAllowanceExceeded,
+        EscrowCapExceeded,
+        EscrowAlreadyHeld,
+        EscrowNotHeld,
+        EscrowWithdrawalFailed,
+        TooManySideEffectsInXtx,
+        TooManyStepsInXtx,
+        SideEffectEncodedArgTooLong,
+        ConfirmationBelowDepth,
     }
 }
 
@@ -1334,18 +1727,27 @@ impl<T: Config> Pallet<T> {
         side_effects: &Vec<SideEffect<T::AccountId, BalanceOf<T>>>,
     ) {
         if !side_effects.is_empty() {
+            let sfx_ids = side_effects
+                .iter()
+                .enumerate()
+                .map(|(index, se)| {
+                    se.generate_id::<SystemHashing<T>>(xtx_id.as_ref(), index as u32)
+                })
+                .collect::<Vec<SideEffectId<T>>>();
             Self::deposit_event(Event::NewSideEffectsAvailable(
-                subjected_account.clone(),
                 xtx_id,
+                subjected_account.clone(),
                 side_effects.to_vec(),
-                side_effects
-                    .iter()
-                    .enumerate()
-                    .map(|(index, se)| {
-                        se.generate_id::<SystemHashing<T>>(xtx_id.as_ref(), index as u32)
-                    })
-                    .collect::<Vec<SideEffectId<T>>>(),
+                sfx_ids.clone(),
             ));
+            // Invite the executors serving the SFX target and type, sized by the SFX max reward
+            for (se, sfx_id) in side_effects.iter().zip(sfx_ids) {
+                let invited =
+                    T::Executors::executors_serving(&se.target, &se.action, &se.max_reward);
+                if !invited.is_empty() {
+                    Self::deposit_event(Event::SFXBidInvitation(xtx_id, sfx_id, invited));
+                }
+            }
             Self::deposit_event(Event::XTransactionReceivedForExec(xtx_id));
         }
     }
@@ -1383,6 +1785,7 @@ impl<T: Config> Pallet<T> {
                     Self::deposit_event(Event::XTransactionXtxDroppedAtBidding(xtx_id)),
                 _ => {},
             }
+            Self::notify_xtx_concluded(xtx_id, &xtx.status);
             if xtx.status >= CircuitStatus::PendingExecution {
                 if let Some(full_side_effects) = maybe_full_side_effects {
                     Self::deposit_event(Event::SideEffectsConfirmed(xtx_id, full_side_effects));
@@ -1391,12 +1794,51 @@ impl<T: Config> Pallet<T> {
         }
     }
 
+    /// Let everyone awaiting the outcome of an Xtx know about it, once it has concluded.
+    fn notify_xtx_concluded(xtx_id: XExecSignalId<T>, status: &CircuitStatus) {
+        if !matches!(
+            status,
+            CircuitStatus::FinishedAllSteps
+                | CircuitStatus::Committed
+                | CircuitStatus::Reverted(_)
+                | CircuitStatus::Killed(_)
+        ) {
+            return
+        }
+        T::OnXtxConcluded::on_xtx_concluded(xtx_id, status);
+        Self::notify_xcm_order(xtx_id, status);
+    }
+
+    /// Report the outcome of an Xtx ordered over XCM back to its sibling.
+    fn notify_xcm_order(xtx_id: XExecSignalId<T>, status: &CircuitStatus) {
+        if let Some(order) = <XcmOrders<T>>::take(xtx_id) {
+            match T::XcmOrderNotifier::notify(&order, xtx_id, status) {
+                Ok(()) => Self::deposit_event(Event::XcmOrderNotified(
+                    xtx_id,
+                    order.para_id,
+                    status.clone(),
+                )),
+                Err(err) => {
+                    log::warn!(
+                        target: "runtime::circuit",
+                        "Failed to notify sibling {:?} of xtx {:?} outcome: {:?}",
+                        order.para_id,
+                        xtx_id,
+                        err
+                    );
+                    Self::deposit_event(Event::XcmOrderNotificationFailed(xtx_id, order.para_id));
+                },
+            }
+        }
+    }
+
     fn do_on_extrinsic_trigger(
         requester: T::AccountId,
         side_effects: Vec<SideEffect<T::AccountId, BalanceOf<T>>>,
         speed_mode: SpeedMode,
         preferred_security_level: &SecurityLvl,
         maybe_call_origin: Option<T::AccountId>,
+        maybe_sponsor: Option<T::AccountId>,
     ) -> Result<LocalXtxCtx<T, BalanceOf<T>>, Error<T>> {
         // Setup: new xtx context with SFX validation
         let mut fresh_xtx = Machine::<T>::setup(
@@ -1415,8 +1857,15 @@ impl<T: Config> Pallet<T> {
 
         fresh_xtx.xtx.set_speed_mode(speed_mode);
 
+        // Sponsors pay for the Xtx in place of its requester, both fees and reward deposits
+        if let Some(sponsor) = &maybe_sponsor {
+            <XtxSponsors<T>>::insert(fresh_xtx.xtx_id, sponsor);
+        }
+
         // Charge finality fees for each Escrow SFX
-        let call_origin = maybe_call_origin.clone().unwrap_or(requester.clone());
+        let call_origin = maybe_sponsor
+            .or_else(|| maybe_call_origin.clone())
+            .unwrap_or(requester.clone());
         SquareUp::<T>::charge_finality_fee(&fresh_xtx, &call_origin)
             .map_err(|_e| Error::<T>::XtxChargeFailedOnEscrowFee)?;
 
@@ -1429,6 +1878,10 @@ impl<T: Config> Pallet<T> {
                 let _call_origin = maybe_call_origin.unwrap_or(requester.clone());
 
                 Self::emit_sfx(local_ctx.xtx_id, &requester, &side_effects);
+                side_effects
+                    .iter()
+                    .for_each(|sfx| T::Xdns::note_sfx_targeted(&sfx.target));
+                Telemetry::<T>::note_opened(local_ctx);
                 Ok(())
             },
         )?;
@@ -1454,7 +1907,7 @@ impl<T: Config> Pallet<T> {
     fn validate(
         side_effects: &[SideEffect<T::AccountId, BalanceOf<T>>],
         local_ctx: &mut LocalXtxCtx<T, BalanceOf<T>>,
-        _preferred_security_lvl: &SecurityLvl,
+        preferred_security_lvl: &SecurityLvl,
     ) -> Result<(), Error<T>> {
         let mut full_side_effects: Vec<
             FullSideEffect<
@@ -1470,6 +1923,11 @@ impl<T: Config> Pallet<T> {
             return Ok(())
         }
 
+        ensure!(
+            side_effects.len() <= T::MaxSideEffectsPerXtx::get() as usize,
+            Error::<T>::TooManySideEffectsInXtx
+        );
+
         // Verify each requested asset is supported by the gateway
         let all_targets = side_effects
             .iter()
@@ -1482,9 +1940,26 @@ impl<T: Config> Pallet<T> {
         );
 
         for (index, sfx) in side_effects.iter().enumerate() {
+            ensure!(
+                sfx.encoded_args
+                    .iter()
+                    .all(|arg| arg.len() <= T::MaxEncodedArgLen::get() as usize),
+                Error::<T>::SideEffectEncodedArgTooLong
+            );
+
             let gateway_max_security_lvl =
                 <T as Config>::Xdns::get_gateway_max_security_lvl(&sfx.target);
 
+            // Reject SFX requesting a security level the target's light client can't back, e.g. Escrow on probabilistic finality
+            if let Ok(gateway_security_tier) =
+                <T as Config>::Xdns::get_gateway_security_tier(&sfx.target)
+            {
+                ensure!(
+                    gateway_security_tier.allows(preferred_security_lvl),
+                    Error::<T>::SecurityTierOfTargetInsufficientForRequestedSecurityLvl
+                );
+            }
+
             let sfx_abi: SFXAbi = match <T as Config>::Xdns::get_sfx_abi(&sfx.target, sfx.action) {
                 Some(sfx_abi) => sfx_abi,
                 None => return Err(Error::<T>::ABIOnSelectedTargetNotFoundForSubmittedSFX),
@@ -1510,6 +1985,9 @@ impl<T: Config> Pallet<T> {
                 submission_target_height,
                 best_bid: None,
                 index: index as u32,
+                deadline: None,
+                dependencies: vec![],
+                metadata: vec![],
             });
         }
         // Skip automatic ordering of SFX for now, allow user to decide - consult PR#https://github.com/t3rn/t3rn/pull/1489
@@ -1518,9 +1996,16 @@ impl<T: Config> Pallet<T> {
         // Assign the full_side_effects to the local_ctx as they are
         local_ctx.full_side_effects = vec![full_side_effects];
 
+        ensure!(
+            local_ctx.full_side_effects.len() <= T::MaxStepsPerXtx::get() as usize,
+            Error::<T>::TooManyStepsInXtx
+        );
+
         Ok(())
     }
 
+    /// Verify the confirmation of a side effect against its inclusion on the target, returning the
+    /// confirmation to record. Reads record the value verified on the target as their output.
     fn confirm(
         xtx_id: XExecSignalId<T>,
         step_side_effects: &mut Vec<
@@ -1536,7 +2021,14 @@ impl<T: Config> Pallet<T> {
             frame_system::pallet_prelude::BlockNumberFor<T>,
             BalanceOf<T>,
         >,
-    ) -> Result<(), DispatchError> {
+    ) -> Result<
+        ConfirmedSideEffect<
+            T::AccountId,
+            frame_system::pallet_prelude::BlockNumberFor<T>,
+            BalanceOf<T>,
+        >,
+        DispatchError,
+    > {
         // Double check there are some side effects for that Xtx - should have been checked at API level tho already
         if step_side_effects.is_empty() {
             return Err(DispatchError::Other("Xtx has an empty single step."))
@@ -1581,7 +2073,7 @@ impl<T: Config> Pallet<T> {
         let inclusion_receipt = <T as Config>::Portal::verify_event_inclusion(
             fsx.input.target,
             xtx.speed_mode,
-            None, // Skips source checks since target account and destination are relevant from the protocol perspective
+            Self::expected_event_source(&fsx.input)?,
             confirmation.inclusion_data.clone(),
         )
         .map_err(|_| DispatchError::Other("SideEffect confirmation of inclusion failed"))?;
@@ -1592,6 +2084,9 @@ impl<T: Config> Pallet<T> {
             inclusion_receipt
         );
 
+        #[cfg(not(feature = "test-skip-verification"))]
+        Reorgs::<T>::note_confirmed(fsx.input.target, *sfx_id, xtx_id, inclusion_receipt.height)?;
+
         log::debug!("Inclusion confirmed!");
 
         let sfx_abi =
@@ -1621,6 +2116,16 @@ impl<T: Config> Pallet<T> {
 
         let payload_codec = <T as Config>::Xdns::get_target_codec(&fsx.input.target)?;
 
+        let mut confirmation = confirmation.clone();
+        if &fsx.input.action == READ_SIDE_EFFECT_ID {
+            confirmation.output = Some(sfx_abi.extract_from_received(
+                &b"value".to_vec(),
+                inclusion_receipt.message.clone(),
+                &Codec::Scale,
+                &payload_codec,
+            )?);
+        }
+
         fsx.input.confirm(
             sfx_abi,
             inclusion_receipt.message,
@@ -1630,7 +2135,34 @@ impl<T: Config> Pallet<T> {
 
         log::debug!("Confirmation success");
 
-        Ok(())
+        <T as Config>::Xdns::note_sfx_confirmed(&fsx.input.target);
+
+        Ok(confirmation)
+    }
+
+    /// Contract the confirming event of a side effect must be emitted by. Messages are only
+    /// delivered once their target contract emitted the receipt, and reads only once the contract
+    /// read off reported the value itself. Other side effects skip source checks since their
+    /// target account and destination are what the protocol relies on.
+    pub fn expected_event_source(
+        side_effect: &SideEffect<T::AccountId, BalanceOf<T>>,
+    ) -> Result<Option<ExecutionSource>, DispatchError> {
+        if &side_effect.action != MESSAGE_SIDE_EFFECT_ID
+            && &side_effect.action != READ_SIDE_EFFECT_ID
+        {
+            return Ok(None)
+        }
+
+        let target_contract = side_effect
+            .encoded_args
+            .first()
+            .ok_or(DispatchError::Other(
+                "Side effect is missing its target contract",
+            ))?;
+        let source = ExecutionSource::try_from(target_contract.as_slice())
+            .map_err(|_| DispatchError::Other("Side effect target contract is not 32 bytes"))?;
+
+        Ok(Some(source))
     }
 
     pub fn get_all_xtx_targets(xtx_id: XExecSignalId<T>) -> Vec<TargetId> {
@@ -1703,6 +2235,66 @@ impl<T: Config> Pallet<T> {
         <T as Config>::SelfAccountId::get()
     }
 
+    /// Id of the Xtx the requester submits at the given nonce, as derived on submission.
+    pub fn compute_xtx_id(requester: &T::AccountId, requester_nonce: u32) -> XExecSignalId<T> {
+        XExecSignal::<T::AccountId, BlockNumberFor<T>>::new(
+            requester,
+            requester_nonce,
+            Default::default(),
+            None,
+            Default::default(),
+            (0, 0),
+        )
+        .generate_id::<T, SystemHashing<T>>()
+    }
+
+    /// Id of the side effect at the given index of the Xtx, as derived on submission.
+    pub fn compute_sfx_id(
+        side_effect: &SideEffect<T::AccountId, BalanceOf<T>>,
+        xtx_id: XExecSignalId<T>,
+        index: u32,
+    ) -> SideEffectId<T> {
+        side_effect.generate_id::<SystemHashing<T>>(xtx_id.as_ref(), index)
+    }
+
+    /// Side effects on the gateway still open for bidding along with their best bids, highest
+    /// max reward first.
+    pub fn order_book(
+        gateway_id: TargetId,
+    ) -> Vec<OpenOrder<T::Hash, T::AccountId, BalanceOf<T>, BlockNumberFor<T>>> {
+        let mut orders = Vec::new();
+        for (xtx_id, bidding_closes_at) in PendingXtxBidsTimeoutsMap::<T>::iter() {
+            let Ok(local_ctx) = Machine::<T>::load_xtx(xtx_id) else {
+                continue
+            };
+            let is_remote = OrderOrigin::new(&local_ctx.xtx.requester).is_remote();
+            for fsx in Machine::<T>::read_current_step_fsx(&local_ctx) {
+                if fsx.input.target != gateway_id
+                    || fsx.input.enforce_executor.is_some()
+                    || fsx.confirmed.is_some()
+                {
+                    continue
+                }
+                // Mirrors the insurance accepted at bidding
+                let required_insurance = if is_remote || fsx.security_lvl == SecurityLvl::Escrow {
+                    T::Attesters::estimate_finality_fee(&fsx.input.target)
+                } else {
+                    fsx.input.insurance
+                };
+                orders.push(OpenOrder {
+                    xtx_id,
+                    sfx_id: fsx.calc_sfx_id::<SystemHashing<T>, T>(xtx_id),
+                    side_effect: fsx.input.clone(),
+                    best_bid: fsx.best_bid.clone(),
+                    bidding_closes_at,
+                    required_insurance,
+                });
+            }
+        }
+        orders.sort_by(|a, b| b.side_effect.max_reward.cmp(&a.side_effect.max_reward));
+        orders
+    }
+
     /// Get pending Bids for SFX - Pending meaning that the SFX is still In Bidding
     pub fn get_pending_sfx_bids(
         xtx_id: T::Hash,
@@ -1727,6 +2319,36 @@ impl<T: Config> Pallet<T> {
         }
     }
 
+    /// Notifications of the SFX submitted and bid on among `events`, expected to be the events
+    /// of a single block.
+    pub fn get_sfx_notifications(
+        events: impl IntoIterator<Item = Event<T>>,
+    ) -> Vec<SfxNotification<T::Hash, T::AccountId, BalanceOf<T>>> {
+        events
+            .into_iter()
+            .filter_map(|event| match event {
+                Event::NewSideEffectsAvailable(xtx_id, requester, side_effects, sfx_ids) =>
+                    Some(SfxNotification::XtxSubmitted {
+                        xtx_id,
+                        requester,
+                        side_effects,
+                        sfx_ids,
+                    }),
+                Event::SFXNewBidReceived(xtx_id, sfx_id, executor, bid) => {
+                    let fsx = Self::get_fsx(sfx_id).ok()?;
+                    Some(SfxNotification::BidPlaced {
+                        xtx_id,
+                        sfx_id,
+                        side_effect: fsx.input,
+                        executor,
+                        bid,
+                    })
+                },
+                _ => None,
+            })
+            .collect()
+    }
+
     pub fn convert_side_effects(
         side_effects: Vec<Vec<u8>>,
     ) -> Result<Vec<SideEffect<T::AccountId, BalanceOf<T>>>, &'static str> {
@@ -1958,7 +2580,10 @@ impl<T: Config> Pallet<T> {
         let success: bool =
             Machine::<T>::revert(xtx_id, Cause::Timeout, |_status_change, local_ctx| {
                 Self::request_sfx_attestation(local_ctx);
+                Self::note_executors_failures(local_ctx);
+                Self::note_gateways_reverts(local_ctx);
                 Self::deposit_event(Event::XTransactionXtxRevertedAfterTimeOut(xtx_id));
+                Self::notify_xtx_concluded(xtx_id, &local_ctx.xtx.status);
             });
 
         (
@@ -1967,6 +2592,71 @@ impl<T: Config> Pallet<T> {
         )
     }
 
+    /// Note a failure against every executor that won a bid on a side effect left unconfirmed.
+    pub fn note_executors_failures(local_ctx: &LocalXtxCtx<T, BalanceOf<T>>) {
+        local_ctx
+            .full_side_effects
+            .iter()
+            .flatten()
+            .filter(|fsx| fsx.confirmed.is_none())
+            .filter_map(|fsx| fsx.best_bid.as_ref())
+            .for_each(|bid| T::Executors::note_failure(&bid.executor));
+    }
+
+    /// Note a revert against the gateway of every side effect left unconfirmed.
+    pub fn note_gateways_reverts(local_ctx: &LocalXtxCtx<T, BalanceOf<T>>) {
+        local_ctx
+            .full_side_effects
+            .iter()
+            .flatten()
+            .filter(|fsx| fsx.confirmed.is_none())
+            .for_each(|fsx| T::Xdns::note_sfx_reverted(&fsx.input.target));
+    }
+
+    /// Release the executor's bids on side effects still in bidding and refund their deposits,
+    /// so that other executors can take the side effects over before bidding closes.
+    pub fn release_executor_bids(executor: &T::AccountId) -> Weight {
+        let mut reads: u64 = 0;
+        let mut writes: u64 = 0;
+
+        for xtx_id in PendingXtxBidsTimeoutsMap::<T>::iter_keys() {
+            reads = reads.saturating_add(2);
+            let mut full_side_effects = match FullSideEffects::<T>::get(xtx_id) {
+                Some(full_side_effects) => full_side_effects,
+                None => continue,
+            };
+
+            let mut released = false;
+            for fsx in full_side_effects.iter_mut().flatten() {
+                let is_open_bid_of_executor = fsx.input.enforce_executor.is_none()
+                    && matches!(&fsx.best_bid, Some(bid) if &bid.executor == executor);
+                if !is_open_bid_of_executor {
+                    continue
+                }
+                if let Some(bid) = fsx.best_bid.take() {
+                    let sfx_id = fsx.calc_sfx_id::<SystemHashing<T>, T>(xtx_id);
+                    if !<T as Config>::AccountManager::cancel_deposit(
+                        bid.generate_id::<SystemHashing<T>, T>(sfx_id),
+                    ) {
+                        log::error!(
+                            "release_executor_bids: expect cancel_deposit to succeed for sfx_id: {:?}",
+                            sfx_id
+                        );
+                    }
+                    writes = writes.saturating_add(1);
+                    released = true;
+                }
+            }
+
+            if released {
+                FullSideEffects::<T>::insert(xtx_id, full_side_effects);
+                writes = writes.saturating_add(1);
+            }
+        }
+
+        T::DbWeight::get().reads_writes(reads, writes)
+    }
+
     pub fn request_sfx_attestation(local_ctx: &LocalXtxCtx<T, BalanceOf<T>>) {
         Machine::<T>::read_current_step_fsx(local_ctx)
             .iter()
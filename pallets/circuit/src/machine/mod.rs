@@ -1,7 +1,7 @@
 use crate::{pallet::Error, *};
 use frame_support::ensure;
 
-use crate::square_up::SquareUp;
+use crate::{reorgs::Reorgs, retention::Retention, square_up::SquareUp, telemetry::Telemetry};
 
 pub mod extra;
 pub use extra::*;
@@ -517,6 +517,14 @@ impl<T: Config> Machine<T> {
                 });
                 // Always clean temporary PendingSFXBids and TimeoutsMap after bidding
                 <pallet::Pallet<T> as Store>::PendingXtxBidsTimeoutsMap::remove(local_ctx.xtx_id);
+                // Winning bids feed the fee hints suggested to the upcoming requesters
+                for fsx in local_ctx.full_side_effects.iter().flatten() {
+                    if let Some(bid) = &fsx.best_bid {
+                        T::Xdns::note_sfx_bid_won(&fsx.input.target, &fsx.input.action, bid.amount);
+                    }
+                }
+                #[cfg(feature = "dev-simulation")]
+                crate::simulation::Simulation::<T>::schedule_confirmations(local_ctx.xtx_id);
 
                 true
             },
@@ -539,6 +547,7 @@ impl<T: Config> Machine<T> {
                 }
                 // Always clean temporary PendingXtxBidsTimeoutsMap after bidding
                 <pallet::Pallet<T> as Store>::PendingXtxBidsTimeoutsMap::remove(local_ctx.xtx_id);
+                Telemetry::<T>::note_settled(local_ctx);
 
                 SquareUp::<T>::kill(local_ctx);
 
@@ -553,6 +562,9 @@ impl<T: Config> Machine<T> {
                 <pallet::Pallet<T> as Store>::PendingXtxTimeoutsMap::remove(local_ctx.xtx_id);
                 <pallet::Pallet<T> as Store>::DLQ::remove(local_ctx.xtx_id);
                 <pallet::Pallet<T> as Store>::LocalXtxStates::remove(local_ctx.xtx_id);
+                Retention::<T>::enqueue_settled(local_ctx);
+                Telemetry::<T>::note_settled(local_ctx);
+                Reorgs::<T>::note_settled(local_ctx);
 
                 SquareUp::<T>::finalize(local_ctx);
 
@@ -562,6 +574,9 @@ impl<T: Config> Machine<T> {
                 <pallet::Pallet<T> as Store>::XExecSignals::remove(local_ctx.xtx_id);
                 <pallet::Pallet<T> as Store>::FullSideEffects::remove(local_ctx.xtx_id);
                 <pallet::Pallet<T> as Store>::LocalXtxStates::remove(local_ctx.xtx_id);
+                Retention::<T>::enqueue_settled(local_ctx);
+                Telemetry::<T>::note_settled(local_ctx);
+                Reorgs::<T>::note_settled(local_ctx);
 
                 true
             },
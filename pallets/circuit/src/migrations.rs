@@ -0,0 +1,137 @@
+//! Storage migrations of the circuit, run by the `StorageMigrations` counter they start from.
+
+use crate::{
+    pallet::{FullSideEffects, StorageMigrations, XExecSignals},
+    *,
+};
+use frame_support::traits::{Get, OnRuntimeUpgrade};
+use sp_std::marker::PhantomData;
+use t3rn_primitives::migrations::VersionedMigration;
+use t3rn_types::migrations::{v13::FullSideEffectV13, v14::FullSideEffectV14};
+#[cfg(feature = "try-runtime")]
+use {frame_support::ensure, sp_runtime::TryRuntimeError};
+
+pub const CURRENT_STORAGE_VERSION: u32 = 2;
+
+/// Migrations of all versions circuit storage is found at on live networks.
+pub type Migrations<T> = (
+    VersionedMigration<
+        0,
+        CURRENT_STORAGE_VERSION,
+        SfxActionTo4bId<T>,
+        StorageMigrations<T>,
+        <T as frame_system::Config>::DbWeight,
+    >,
+    VersionedMigration<
+        1,
+        CURRENT_STORAGE_VERSION,
+        FsxDeadlinesDependenciesAndMetadata<T>,
+        StorageMigrations<T>,
+        <T as frame_system::Config>::DbWeight,
+    >,
+);
+
+/// Storage Migration: FSX::SFX updates field "encoded_action: Vec<u8>" to "action: Action: [u8; 4]"
+/// Storage Migration Details: 16-03-2023; v1.3.0-rc -> v1.4.0-rc
+pub struct SfxActionTo4bId<T>(PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for SfxActionTo4bId<T> {
+    fn on_runtime_upgrade() -> Weight {
+        FullSideEffects::<T>::translate(
+            |_,
+             value: Vec<
+                Vec<FullSideEffectV13<T::AccountId, BlockNumberFor<T>, BalanceOf<T>>>,
+            >| {
+                Some(
+                    value
+                        .into_iter()
+                        .map(|v| v.into_iter().map(FullSideEffect::from).collect())
+                        .collect(),
+                )
+            },
+        );
+        T::DbWeight::get().reads_writes(10, 10)
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+        Ok((FullSideEffects::<T>::iter_keys().count() as u64).encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+        let xtx_cnt = u64::decode(&mut &state[..])
+            .map_err(|_| TryRuntimeError::Other("FullSideEffects count undecodable"))?;
+        // Entries failing to translate are dropped, so all of them must decode as the new FSX
+        ensure!(
+            FullSideEffects::<T>::iter_values().count() as u64 == xtx_cnt,
+            "FullSideEffects dropped while migrating the SFX action"
+        );
+        Ok(())
+    }
+}
+
+/// Storage Migration: FSX gains the fields "deadline", "dependencies" and "metadata", left unset
+/// Storage Migration Details: 14-10-2026
+pub struct FsxDeadlinesDependenciesAndMetadata<T>(PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for FsxDeadlinesDependenciesAndMetadata<T> {
+    fn on_runtime_upgrade() -> Weight {
+        let mut translated = 0u64;
+        FullSideEffects::<T>::translate(
+            |_,
+             value: Vec<
+                Vec<FullSideEffectV14<T::AccountId, BlockNumberFor<T>, BalanceOf<T>>>,
+            >| {
+                translated += 1;
+                Some(
+                    value
+                        .into_iter()
+                        .map(|v| v.into_iter().map(FullSideEffect::from).collect())
+                        .collect(),
+                )
+            },
+        );
+        T::DbWeight::get().reads_writes(translated, translated)
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+        Ok((FullSideEffects::<T>::iter_keys().count() as u64).encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+        let xtx_cnt = u64::decode(&mut &state[..])
+            .map_err(|_| TryRuntimeError::Other("FullSideEffects count undecodable"))?;
+        ensure!(
+            FullSideEffects::<T>::iter_values().count() as u64 == xtx_cnt,
+            "FullSideEffects dropped while adding the FSX deadlines, dependencies and metadata"
+        );
+        Ok(())
+    }
+}
+
+/// Check every Xtx still in flight targets gateways registered in XDNS, so that no migration
+/// of either pallet left its side effects without a light client to confirm them against.
+#[cfg(feature = "try-runtime")]
+pub fn ensure_in_flight_xtx_targets_registered<T: Config>() -> Result<(), TryRuntimeError> {
+    for (xtx_id, fsx_steps) in FullSideEffects::<T>::iter() {
+        let in_flight = XExecSignals::<T>::get(xtx_id).map_or(false, |xtx| {
+            !matches!(
+                xtx.status,
+                CircuitStatus::Committed | CircuitStatus::Reverted(_) | CircuitStatus::Killed(_)
+            )
+        });
+        if !in_flight {
+            continue
+        }
+        for fsx in fsx_steps.iter().flatten() {
+            ensure!(
+                T::Xdns::get_verification_vendor(&fsx.input.target).is_ok(),
+                "In-flight Xtx orphaned: its side effects target an unregistered gateway"
+            );
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,135 @@
+use crate::{machine::PrecompileResult, pallet::ProvisionalConfirmations, *};
+use frame_support::{ensure, traits::Get};
+use sp_runtime::DispatchResult;
+use sp_std::{collections::btree_map::BTreeMap, marker::PhantomData};
+use t3rn_primitives::{portal::OnHeadersRolledBack, GatewaySecurityTier};
+
+/// DB reads loading an Xtx to roll its confirmations back: XExecSignals, FullSideEffects and
+/// LocalXtxStates
+const LOAD_XTX_READS: u64 = 3;
+
+// Targets with probabilistic finality can reorg the headers SFX confirmations were included at.
+// Their confirmations are accepted once ConfirmationDepth headers were built on top of them and
+// tracked till the Xtx settles. Once the portal rolls the headers back, they're cleared from the
+// current step of their Xtx for executors to confirm them again.
+pub struct Reorgs<T: Config> {
+    _phantom: PhantomData<T>,
+}
+
+impl<T: Config> Reorgs<T> {
+    pub fn note_confirmed(
+        target: TargetId,
+        sfx_id: SideEffectId<T>,
+        xtx_id: XExecSignalId<T>,
+        height: BlockNumberFor<T>,
+    ) -> DispatchResult {
+        if !Self::has_probabilistic_finality(&target) {
+            return Ok(())
+        }
+        ensure!(
+            T::Portal::get_confirmation_depth(target, height)? >= T::ConfirmationDepth::get(),
+            Error::<T>::ConfirmationBelowDepth
+        );
+        ProvisionalConfirmations::<T>::insert(target, sfx_id, (xtx_id, height));
+        Ok(())
+    }
+
+    /// Targets verified by light clients of vendors with probabilistic finality, or downgraded to
+    /// optimistic security on their own
+    pub fn has_probabilistic_finality(target: &TargetId) -> bool {
+        T::Xdns::get_gateway_security_tier(target) == Ok(GatewaySecurityTier::OptimisticOnly)
+            || T::Xdns::get_verification_vendor(target)
+                .map_or(false, |vendor| vendor.has_probabilistic_finality())
+    }
+
+    pub fn note_settled(local_ctx: &LocalXtxCtx<T, BalanceOf<T>>) {
+        for fsx in local_ctx.full_side_effects.iter().flatten() {
+            ProvisionalConfirmations::<T>::remove(
+                fsx.input.target,
+                fsx.calc_sfx_id::<SystemHashing<T>, T>(local_ctx.xtx_id),
+            );
+        }
+    }
+
+    /// Drop the confirmations included on the target from `from_height` on, flagging their Xtx
+    /// for re-confirmation against the heavier chain.
+    pub fn roll_back(target: TargetId, from_height: BlockNumberFor<T>) -> Weight {
+        let mut scanned = 0u64;
+        let mut rolled_back: BTreeMap<XExecSignalId<T>, Vec<SideEffectId<T>>> = BTreeMap::new();
+        for (sfx_id, (xtx_id, height)) in ProvisionalConfirmations::<T>::iter_prefix(target) {
+            scanned += 1;
+            if height >= from_height {
+                rolled_back.entry(xtx_id).or_default().push(sfx_id);
+            }
+        }
+
+        let xtx_cnt = rolled_back.len() as u64;
+        let mut removed = 0u64;
+        for (xtx_id, sfx_ids) in rolled_back {
+            for sfx_id in sfx_ids.iter() {
+                ProvisionalConfirmations::<T>::remove(target, sfx_id);
+                removed += 1;
+            }
+            Self::unconfirm(xtx_id, &sfx_ids);
+            Pallet::<T>::deposit_event(Event::XtxReconfirmationRequired(xtx_id, sfx_ids));
+        }
+
+        T::DbWeight::get().reads_writes(
+            scanned.saturating_add(LOAD_XTX_READS.saturating_mul(xtx_cnt)),
+            removed.saturating_add(2 * xtx_cnt),
+        )
+    }
+
+    // Only steps still awaiting confirmations are rolled back - confirmations of the steps already
+    // finished are left as they are, with their Xtx flagged for re-confirmation
+    fn unconfirm(xtx_id: XExecSignalId<T>, sfx_ids: &[SideEffectId<T>]) {
+        let Ok(mut local_ctx) = Machine::<T>::load_xtx(xtx_id) else {
+            return
+        };
+        if !matches!(
+            local_ctx.xtx.status,
+            CircuitStatus::Ready | CircuitStatus::PendingExecution
+        ) {
+            return
+        }
+        let mut current_fsx = Machine::<T>::read_current_step_fsx(&local_ctx).clone();
+        let mut is_rolled_back = false;
+        for fsx in current_fsx.iter_mut() {
+            if fsx.confirmed.is_some()
+                && sfx_ids.contains(&fsx.calc_sfx_id::<SystemHashing<T>, T>(xtx_id))
+            {
+                fsx.confirmed = None;
+                is_rolled_back = true;
+            }
+        }
+        if !is_rolled_back {
+            return
+        }
+
+        let result = Machine::<T>::compile(
+            &mut local_ctx,
+            |_, _, _, _, _| Ok(PrecompileResult::TryUpdateFSX(current_fsx)),
+            |_status_change, local_ctx| {
+                Pallet::<T>::emit_status_update(
+                    local_ctx.xtx_id,
+                    Some(local_ctx.xtx.clone()),
+                    Some(local_ctx.full_side_effects.clone()),
+                );
+                Ok(())
+            },
+        );
+        if let Err(err) = result {
+            log::error!(
+                "Rolling back confirmations of Xtx {:?} failed -- {:?}",
+                xtx_id,
+                err
+            );
+        }
+    }
+}
+
+impl<T: Config> OnHeadersRolledBack<BlockNumberFor<T>> for Pallet<T> {
+    fn on_headers_rolled_back(gateway_id: ChainId, from_height: BlockNumberFor<T>) -> Weight {
+        Reorgs::<T>::roll_back(gateway_id, from_height)
+    }
+}
@@ -0,0 +1,79 @@
+use crate::{
+    pallet::{
+        FinalizedXtx, PendingXtxBidsTimeoutsMap, SFX2XTXLinksMap, SettledXtx,
+        SettledXtxPruneCursor, XtxSponsors,
+    },
+    *,
+};
+use frame_support::traits::Get;
+use sp_std::marker::PhantomData;
+use t3rn_primitives::retention;
+
+/// DB writes pruning a settled Xtx takes, besides its SFX links: FinalizedXtx, XtxSponsors and
+/// PendingXtxBidsTimeoutsMap
+const PRUNE_XTX_WRITES: u64 = 3;
+
+pub struct Retention<T: Config> {
+    _phantom: PhantomData<T>,
+}
+
+impl<T: Config> Retention<T> {
+    /// Keep the links of a committed or reverted Xtx around for `RetentionPeriod`, for executors
+    /// and indexers to look its SFX up by, before they are pruned.
+    pub fn enqueue_settled(local_ctx: &LocalXtxCtx<T, BalanceOf<T>>) {
+        let sfx_ids = local_ctx
+            .full_side_effects
+            .iter()
+            .flatten()
+            .map(|fsx| fsx.calc_sfx_id::<SystemHashing<T>, T>(local_ctx.xtx_id))
+            .collect::<Vec<_>>();
+        retention::enqueue::<_, _, _, SettledXtx<T>, SettledXtxPruneCursor<T>>(
+            frame_system::Pallet::<T>::block_number(),
+            local_ctx.xtx_id,
+            sfx_ids,
+        );
+    }
+
+    /// Prune what's left of the Xtx settled more than `RetentionPeriod` blocks ago, within the
+    /// weight remaining in the block and up to `MaxPrunedPerBlock` steps.
+    pub fn prune_settled(n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+        let step_weight = T::DbWeight::get().reads_writes(1, PRUNE_XTX_WRITES + 1);
+        // The cursor is read and written once per block
+        let base_weight = T::DbWeight::get().reads_writes(1, 1);
+        let max_steps = remaining_weight
+            .saturating_sub(base_weight)
+            .ref_time()
+            .checked_div(step_weight.ref_time())
+            .unwrap_or_default()
+            .min(T::MaxPrunedPerBlock::get() as u64) as u32;
+        if max_steps == 0 {
+            return Weight::zero()
+        }
+
+        let mut pruned_xtx = 0u32;
+        let mut pruned_links = 0u32;
+        let steps = retention::prune_expired::<_, _, _, SettledXtx<T>, SettledXtxPruneCursor<T>>(
+            n,
+            T::RetentionPeriod::get(),
+            max_steps,
+            |xtx_id, sfx_ids| {
+                for sfx_id in sfx_ids.iter() {
+                    <SFX2XTXLinksMap<T>>::remove(sfx_id);
+                }
+                <FinalizedXtx<T>>::remove(xtx_id);
+                <XtxSponsors<T>>::remove(xtx_id);
+                <PendingXtxBidsTimeoutsMap<T>>::remove(xtx_id);
+                pruned_xtx += 1;
+                pruned_links = pruned_links.saturating_add(sfx_ids.len() as u32);
+            },
+        );
+
+        if pruned_xtx > 0 {
+            Pallet::<T>::deposit_event(Event::SettledXtxPruned(pruned_xtx, pruned_links));
+        }
+
+        base_weight
+            .saturating_add(step_weight.saturating_mul(steps as u64))
+            .saturating_add(T::DbWeight::get().writes(pruned_links as u64))
+    }
+}
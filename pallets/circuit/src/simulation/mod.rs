@@ -0,0 +1,94 @@
+use crate::{machine::PrecompileResult, pallet::SimulatedConfirmations, *};
+use frame_support::traits::Get;
+use sp_std::marker::PhantomData;
+
+// Dev chains confirm the SFX of Xtx on their own, SimulatedConfirmationDelay blocks after their
+// bids were accepted, in place of executors confirming their execution on live chains. The Xtx
+// then settles as if each confirmation came from its winning bidder.
+pub struct Simulation<T: Config> {
+    _phantom: PhantomData<T>,
+}
+
+impl<T: Config> Simulation<T> {
+    pub fn schedule_confirmations(xtx_id: XExecSignalId<T>) {
+        let due_at = frame_system::Pallet::<T>::block_number()
+            .saturating_add(T::SimulatedConfirmationDelay::get());
+        SimulatedConfirmations::<T>::append(due_at, xtx_id);
+    }
+
+    pub fn confirm_due(n: BlockNumberFor<T>) -> Weight {
+        let mut confirmed = 0u64;
+        for xtx_id in SimulatedConfirmations::<T>::take(n) {
+            confirmed = confirmed.saturating_add(Self::confirm_all(xtx_id, n) as u64);
+        }
+        T::DbWeight::get().reads_writes(1, 1).saturating_add(
+            <T as Config>::WeightInfo::confirm_side_effect().saturating_mul(confirmed),
+        )
+    }
+
+    // Confirm SFX of the Xtx one at a time, till it settled or a confirmation failed
+    fn confirm_all(xtx_id: XExecSignalId<T>, n: BlockNumberFor<T>) -> u32 {
+        let mut confirmed = 0u32;
+        while let Ok(mut local_ctx) = Machine::<T>::load_xtx(xtx_id) {
+            let mut confirming = None;
+            let result = Machine::<T>::compile(
+                &mut local_ctx,
+                |current_fsx, _local_state, _steps_cnt, _status, requester| {
+                    let Some(fsx) = current_fsx.iter().find(|fsx| fsx.confirmed.is_none()) else {
+                        return Ok(PrecompileResult::Continue)
+                    };
+                    let id = fsx.calc_sfx_id::<SystemHashing<T>, T>(xtx_id);
+                    let executioner = fsx
+                        .best_bid
+                        .as_ref()
+                        .map(|bid| bid.executor.clone())
+                        .unwrap_or(requester);
+                    confirming = Some((id, executioner.clone()));
+                    Ok(PrecompileResult::TryConfirm(
+                        id,
+                        ConfirmedSideEffect {
+                            err: None,
+                            output: None,
+                            inclusion_data: fsx.input.encode(),
+                            executioner,
+                            received_at: n,
+                            cost: None,
+                        },
+                    ))
+                },
+                |status_change, local_ctx| {
+                    if status_change.1 == CircuitStatus::FinishedAllSteps
+                        || status_change.1 == CircuitStatus::Committed
+                    {
+                        Pallet::<T>::request_sfx_attestation(local_ctx);
+                    }
+                    Pallet::<T>::emit_status_update(
+                        local_ctx.xtx_id,
+                        Some(local_ctx.xtx.clone()),
+                        Some(local_ctx.full_side_effects.clone()),
+                    );
+                    Ok(())
+                },
+            );
+
+            match (result, confirming) {
+                (Ok(_), Some((sfx_id, executor))) => {
+                    Pallet::<T>::deposit_event(Event::SideEffectConfirmed(
+                        xtx_id, sfx_id, executor,
+                    ));
+                    confirmed += 1
+                },
+                (Ok(_), None) => break,
+                (Err(err), _) => {
+                    log::error!(
+                        "Simulated confirmation of Xtx {:?} failed -- {:?}",
+                        xtx_id,
+                        err
+                    );
+                    break
+                },
+            }
+        }
+        confirmed
+    }
+}
@@ -0,0 +1,104 @@
+use crate::{pallet::Error, *};
+use frame_support::ensure;
+use scale_info::TypeInfo;
+use sp_runtime::traits::Verify;
+use sp_std::marker::PhantomData;
+
+/// Prefix of the payload sponsors sign, keeping vouchers from being mistaken for other messages.
+pub const SPONSORSHIP_CONTEXT: &[u8] = b"t3rn/xtx-sponsorship";
+
+/// A sponsor's authorisation to cover the fees and reward deposits of an Xtx submitted by
+/// `requester`, for up to `max_rewards` summed over its side effects, until `valid_until`.
+///
+/// Vouchers are authorised in order: each is valid for the sponsor's next `nonce` only.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct SponsorshipVoucher<AccountId, Balance, BlockNumber> {
+    pub sponsor: AccountId,
+    pub requester: AccountId,
+    pub max_rewards: Balance,
+    pub valid_until: BlockNumber,
+    pub nonce: u32,
+}
+
+impl<AccountId: Encode, Balance: Encode, BlockNumber: Encode>
+    SponsorshipVoucher<AccountId, Balance, BlockNumber>
+{
+    /// The payload sponsors sign, binding the voucher to the chain of `genesis_hash`.
+    pub fn signing_payload<Hash: Encode>(&self, genesis_hash: &Hash) -> Vec<u8> {
+        (SPONSORSHIP_CONTEXT, genesis_hash, self).encode()
+    }
+}
+
+pub type SponsorshipVoucherOf<T> =
+    SponsorshipVoucher<<T as frame_system::Config>::AccountId, BalanceOf<T>, BlockNumberFor<T>>;
+
+pub struct Sponsor<T: Config> {
+    _phantom: PhantomData<T>,
+}
+
+impl<T: Config> Sponsor<T> {
+    /// Check the voucher was signed by its sponsor for `requester`'s submission of `side_effects`,
+    /// and consume it. Returns the sponsor.
+    pub fn try_redeem(
+        voucher: &SponsorshipVoucherOf<T>,
+        signature: &T::SponsorSignature,
+        requester: &T::AccountId,
+        side_effects: &[SideEffect<T::AccountId, BalanceOf<T>>],
+    ) -> Result<T::AccountId, Error<T>> {
+        let sponsor = Self::ensure_redeemable(voucher, signature, requester, side_effects)?;
+        <pallet::Pallet<T> as Store>::SponsorNonces::insert(
+            &voucher.sponsor,
+            voucher.nonce.saturating_add(1),
+        );
+        Ok(sponsor)
+    }
+
+    /// Check the voucher would be redeemed for `requester`'s submission of `side_effects`,
+    /// without consuming it. Returns the sponsor, who the transaction fees are charged to.
+    pub fn ensure_redeemable(
+        voucher: &SponsorshipVoucherOf<T>,
+        signature: &T::SponsorSignature,
+        requester: &T::AccountId,
+        side_effects: &[SideEffect<T::AccountId, BalanceOf<T>>],
+    ) -> Result<T::AccountId, Error<T>> {
+        ensure!(
+            &voucher.requester == requester,
+            Error::<T>::SponsorshipRequesterMismatch
+        );
+        ensure!(
+            frame_system::Pallet::<T>::block_number() <= voucher.valid_until,
+            Error::<T>::SponsorshipExpired
+        );
+        ensure!(
+            voucher.nonce == <pallet::Pallet<T> as Store>::SponsorNonces::get(&voucher.sponsor),
+            Error::<T>::SponsorshipNonceMismatch
+        );
+
+        let genesis_hash = frame_system::Pallet::<T>::block_hash(BlockNumberFor::<T>::zero());
+        ensure!(
+            signature.verify(
+                &voucher.signing_payload(&genesis_hash)[..],
+                &voucher.sponsor
+            ),
+            Error::<T>::SponsorshipSignatureInvalid
+        );
+
+        let rewards = side_effects
+            .iter()
+            .try_fold(BalanceOf::<T>::zero(), |acc, sfx| {
+                acc.checked_add(&sfx.max_reward)
+            })
+            .ok_or(Error::<T>::ArithmeticErrorOverflow)?;
+        ensure!(
+            rewards <= voucher.max_rewards,
+            Error::<T>::SponsorshipAllowanceExceeded
+        );
+        Ok(voucher.sponsor.clone())
+    }
+
+    /// The account paying for an Xtx: its sponsor if it was sponsored, otherwise its requester.
+    pub fn payer_of(local_ctx: &LocalXtxCtx<T, BalanceOf<T>>) -> T::AccountId {
+        <pallet::Pallet<T> as Store>::XtxSponsors::get(local_ctx.xtx_id)
+            .unwrap_or_else(|| local_ctx.xtx.requester.clone())
+    }
+}
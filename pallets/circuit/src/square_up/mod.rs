@@ -1,4 +1,4 @@
-use crate::*;
+use crate::{escrow::EscrowVault, sponsor::Sponsor, *};
 use frame_support::{ensure, traits::ExistenceRequirement};
 use sp_runtime::DispatchResult;
 
@@ -42,11 +42,8 @@ impl<T: Config> SquareUp<T> {
         let finality_fees_sum = all_escrow_targets
             .iter()
             .map(T::Attesters::estimate_finality_fee)
-            .collect::<Vec<BalanceOf<T>>>()
-            .iter()
-            .fold(Zero::zero(), |acc: BalanceOf<T>, fee| {
-                acc.checked_add(fee).unwrap_or_else(Zero::zero)
-            });
+            .try_fold(Zero::zero(), |acc: BalanceOf<T>, fee| acc.checked_add(&fee))
+            .ok_or(Error::<T>::ArithmeticErrorOverflow)?;
 
         // log requester balance
         T::Currency::transfer(
@@ -60,10 +57,10 @@ impl<T: Config> SquareUp<T> {
         Ok(finality_fees_sum)
     }
 
-    /// Fallible lock requester' max rewards for Xtx.
+    /// Fallible lock requester' max rewards for Xtx, or its sponsor's if it was sponsored.
     pub fn try_request(local_ctx: &LocalXtxCtx<T, BalanceOf<T>>) -> DispatchResult {
         let fsx_array = Machine::<T>::read_current_step_fsx(local_ctx);
-        let requester = local_ctx.xtx.requester.clone();
+        let requester = Sponsor::<T>::payer_of(local_ctx);
 
         if !fsx_array.iter().all(|fsx| {
             <T as Config>::AccountManager::can_withdraw(
@@ -130,6 +127,9 @@ impl<T: Config> SquareUp<T> {
                 }),
             Error::<T>::SanityAfterCreatingSFXDepositsFailed
         );
+        for (sfx_id, _request_charge) in local_request_charges.iter() {
+            <T as Config>::AccountManager::note_xtx_charge(local_ctx.xtx_id, *sfx_id);
+        }
         Ok(())
     }
 
@@ -149,21 +149,30 @@ impl<T: Config> SquareUp<T> {
             .checked_add(&bid.insurance)
             .ok_or(Error::<T>::ArithmeticErrorOverflow)?;
 
+        let bid_id = bid.generate_id::<SystemHashing<T>, T>(sfx_id);
+
         match current_best_bid {
             Some(current_best_bid) => {
-                if bid.amount >= current_best_bid.amount {
+                // Equal bids are decided by the executors' stake and reputation
+                if bid.amount > current_best_bid.amount
+                    || (bid.amount == current_best_bid.amount
+                        && !<T as Config>::Executors::outranks(
+                            &bid.executor,
+                            &current_best_bid.executor,
+                        ))
+                {
                     return Err(Error::<T>::BiddingRejectedBetterBidFound.into())
                 }
                 <T as Config>::AccountManager::transfer_deposit(
                     current_best_bid.generate_id::<SystemHashing<T>, T>(sfx_id),
-                    bid.generate_id::<SystemHashing<T>, T>(sfx_id),
+                    bid_id,
                     Some(total_bid_deposit),
                     Some(&bid.executor),
                     None,
-                )
+                )?
             },
             None => <T as Config>::AccountManager::deposit(
-                bid.generate_id::<SystemHashing<T>, T>(sfx_id),
+                bid_id,
                 RequestCharge {
                     payee: bidder.clone(),
                     offered_reward: total_bid_deposit,
@@ -173,8 +182,13 @@ impl<T: Config> SquareUp<T> {
                     recipient: Some(requester.clone()),
                     maybe_asset_id: bid.reward_asset_id,
                 },
-            ),
+            )?,
+        }
+
+        if let Some(xtx_id) = SFX2XTXLinksMap::<T>::get(sfx_id) {
+            <T as Config>::AccountManager::note_xtx_charge(xtx_id, bid_id);
         }
+        Ok(())
     }
 
     /// Infallible re-balance requesters locked rewards after possibly lower bids are posted.
@@ -200,6 +214,9 @@ impl<T: Config> SquareUp<T> {
                     );
                 } else {
                     fsx.input.enforce_executor = Some(bid.executor.clone());
+                    if let Some(reserved_bond) = bid.reserved_bond {
+                        <T as Config>::Executors::note_obligation(&bid.executor, reserved_bond);
+                    }
                     res = true;
                 }
             } else {
@@ -261,6 +278,9 @@ impl<T: Config> SquareUp<T> {
                     if outcome == Outcome::Slash {
                         step_outcome = Outcome::Revert;
                     }
+                    if let Some(reserved_bond) = bid.reserved_bond {
+                        <T as Config>::Executors::settle_obligation(&bid.executor, reserved_bond);
+                    }
                     if !<T as Config>::AccountManager::finalize_infallible(
                         bid.generate_id::<SystemHashing<T>, T>(sfx_id),
                         outcome.clone(),
@@ -293,23 +313,34 @@ impl<T: Config> SquareUp<T> {
                         sfx_id
                     );
                     finalized = false;
-                }
-
-                // Finalize Escrow settlements if associated with SFX
-                // Standardize escrow_account IDs as re-hash of sfx_id with 3333
-                if fsx.security_lvl == SecurityLvl::Escrow {
-                    let escrow_id = fsx
-                        .input
-                        .generate_id::<SystemHashing<T>>(sfx_id.as_ref(), 3333);
-                    if !<T as Config>::AccountManager::finalize_infallible(escrow_id, step_outcome.clone()) {
-                        log::error!(
-                            "squareUp::finalize: expect finalize_infallible to succeed for escrow_id: {:?}",
-                            escrow_id
-                        );
-                        finalized = false;
+                } else if step_outcome == Outcome::Commit {
+                    if let Some(bid) = &fsx.best_bid {
+                        <T as Config>::Executors::note_reward(&bid.executor, bid.amount);
                     }
                 }
             });
+        // Finalize Escrow settlements of SFX from all of the steps, since escrows held for
+        // the steps executed before the current one are otherwise never released.
+        // Standardize escrow_account IDs as re-hash of sfx_id with 3333
+        local_ctx
+            .full_side_effects
+            .iter()
+            .flatten()
+            .filter(|fsx| fsx.security_lvl == SecurityLvl::Escrow)
+            .for_each(|fsx| {
+                let sfx_id = fsx.calc_sfx_id::<SystemHashing<T>, T>(local_ctx.xtx_id);
+                let escrow_id = fsx
+                    .input
+                    .generate_id::<SystemHashing<T>>(sfx_id.as_ref(), 3333);
+                if !<T as Config>::AccountManager::finalize_infallible(escrow_id, step_outcome.clone()) {
+                    log::error!(
+                        "squareUp::finalize: expect finalize_infallible to succeed for escrow_id: {:?}",
+                        escrow_id
+                    );
+                    finalized = false;
+                }
+                EscrowVault::<T>::release(sfx_id, &step_outcome);
+            });
         finalized
     }
 
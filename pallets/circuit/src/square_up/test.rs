@@ -6,11 +6,11 @@ pub mod test {
         SFXBid,
     };
     use circuit_mock_runtime::{
-        AccountId, AccountManager, AssetId, Balance, Balances, BlockNumber, ExtBuilder, Hash,
-        Runtime, System,
+        AccountId, AccountManager, AssetId, Balance, Balances, BlockNumber, Circuit, ExtBuilder,
+        Hash, Runtime, System,
     };
     use circuit_runtime_pallets::pallet_circuit::{
-        machine::Machine, square_up::SquareUp, state::LocalXtxCtx,
+        escrow::EscrowVault, machine::Machine, square_up::SquareUp, state::LocalXtxCtx,
     };
     use frame_support::{assert_err, assert_ok, traits::Currency};
     use sp_core::H256;
@@ -256,6 +256,34 @@ pub mod test {
             });
     }
 
+    #[test]
+    fn square_up_rejects_bid_with_deposit_overflowing_max_balance() {
+        ExtBuilder::default()
+            .with_standard_sfx_abi()
+            .with_default_xdns_records()
+            .build()
+            .execute_with(|| {
+                let (local_ctx, sfx_id, bid, _bid_id) = stage_single_sfx_xtx();
+                assert_ok!(SquareUp::<Runtime>::try_request(&local_ctx));
+
+                // Reserved bond and insurance are deposited together
+                assert_err!(
+                    SquareUp::<Runtime>::try_bid(
+                        sfx_id,
+                        &REQUESTER,
+                        &EXECUTOR,
+                        &SFXBid {
+                            reserved_bond: Some(Balance::MAX),
+                            ..bid
+                        },
+                        None
+                    ),
+                    circuit_runtime_pallets::pallet_circuit::Error::<Runtime>::ArithmeticErrorOverflow
+                );
+                assert_eq!(Balances::free_balance(&EXECUTOR), INITIAL_BALANCE);
+            });
+    }
+
     #[test]
     fn square_up_kills_xtx_with_its_all_bids() {
         ExtBuilder::default()
@@ -418,4 +446,52 @@ pub mod test {
                 assert_eq!(Balances::free_balance(&ESCROW_ACCOUNT), bid.insurance);
             });
     }
+
+    #[test]
+    fn square_up_finalize_releases_escrows_of_all_xtx_steps() {
+        ExtBuilder::default()
+            .with_standard_sfx_abi()
+            .with_default_xdns_records()
+            .build()
+            .execute_with(|| {
+                let (mut local_ctx, first_sfx_id, bid, _bid_id) = stage_single_sfx_xtx();
+                assert_ok!(request_and_bid_single_sfx_xtx(&mut local_ctx, &bid));
+
+                local_ctx.full_side_effects[0][0].security_lvl = SecurityLvl::Escrow;
+                local_ctx.full_side_effects[0][0].confirmed = Some(ConfirmedSideEffect {
+                    err: None,
+                    output: None,
+                    inclusion_data: vec![0, 1, 2, 3],
+                    executioner: EXECUTOR,
+                    received_at: 1 as BlockNumber,
+                    cost: None,
+                });
+                // Stage the second step of the Xtx as the current one
+                let mut second_fsx = local_ctx.full_side_effects[0][0].clone();
+                second_fsx.index = 1;
+                local_ctx.full_side_effects.push(vec![second_fsx.clone()]);
+                local_ctx.xtx.steps_cnt = (1, 2);
+                let second_sfx_id = second_fsx
+                    .calc_sfx_id::<circuit_runtime_pallets::pallet_circuit::SystemHashing<Runtime>, Runtime>(
+                        local_ctx.xtx_id,
+                    );
+
+                for sfx_id in [first_sfx_id, second_sfx_id] {
+                    assert_ok!(EscrowVault::<Runtime>::lock(
+                        local_ctx.xtx_id,
+                        sfx_id,
+                        H256::repeat_byte(2),
+                        None,
+                        5
+                    ));
+                }
+                assert_eq!(Circuit::get_escrow_exposure(None::<u32>), 10);
+
+                SquareUp::<Runtime>::finalize(&local_ctx);
+
+                assert!(Circuit::get_escrowed_sfx(first_sfx_id).is_none());
+                assert!(Circuit::get_escrowed_sfx(second_sfx_id).is_none());
+                assert_eq!(Circuit::get_escrow_exposure(None::<u32>), 0);
+            });
+    }
 }
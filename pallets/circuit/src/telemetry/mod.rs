@@ -0,0 +1,84 @@
+use crate::{pallet::OpenSfxByTarget, *};
+use frame_support::traits::Get;
+use scale_info::TypeInfo;
+use sp_std::marker::PhantomData;
+use t3rn_primitives::light_client::LightClientHeartbeat;
+
+/// Side effects open on a target, from their request till their Xtx settles.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, Debug, Default, TypeInfo)]
+pub struct OpenSfxStats<Balance> {
+    pub count: u32,
+    /// Max rewards of the open side effects, regardless of the asset they're paid out in
+    pub max_rewards: Balance,
+}
+
+/// Health of a gateway as reported to monitoring every TelemetryInterval blocks.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, Debug, TypeInfo)]
+pub struct GatewayTelemetry<BlockNumber, Balance> {
+    pub gateway_id: TargetId,
+    /// Blocks since the gateway's light client last accepted a header
+    pub header_lag: BlockNumber,
+    pub open_sfx: u32,
+    /// Max rewards pending settlement on the gateway
+    pub pending_settlement: Balance,
+}
+
+pub struct Telemetry<T: Config> {
+    _phantom: PhantomData<T>,
+}
+
+impl<T: Config> Telemetry<T> {
+    pub fn note_opened(local_ctx: &LocalXtxCtx<T, BalanceOf<T>>) {
+        for fsx in local_ctx.full_side_effects.iter().flatten() {
+            OpenSfxByTarget::<T>::mutate(fsx.input.target, |stats| {
+                stats.count = stats.count.saturating_add(1);
+                stats.max_rewards = stats.max_rewards.saturating_add(fsx.input.max_reward);
+            });
+        }
+    }
+
+    pub fn note_settled(local_ctx: &LocalXtxCtx<T, BalanceOf<T>>) {
+        for fsx in local_ctx.full_side_effects.iter().flatten() {
+            OpenSfxByTarget::<T>::mutate_exists(fsx.input.target, |maybe_stats| {
+                let Some(stats) = maybe_stats else { return };
+                stats.count = stats.count.saturating_sub(1);
+                stats.max_rewards = stats.max_rewards.saturating_sub(fsx.input.max_reward);
+                if stats.count == 0 {
+                    *maybe_stats = None;
+                }
+            });
+        }
+    }
+
+    /// Telemetry of all gateways registered in XDNS, as of the current block.
+    pub fn collect() -> Vec<GatewayTelemetry<BlockNumberFor<T>, BalanceOf<T>>> {
+        let now = frame_system::Pallet::<T>::block_number();
+        T::Xdns::fetch_gateways()
+            .into_iter()
+            .map(|gateway| {
+                let heartbeat = T::Portal::get_latest_heartbeat(&gateway.gateway_id)
+                    .unwrap_or_else(|_| LightClientHeartbeat::default());
+                let open_sfx = OpenSfxByTarget::<T>::get(gateway.gateway_id);
+                GatewayTelemetry {
+                    gateway_id: gateway.gateway_id,
+                    header_lag: now.saturating_sub(heartbeat.last_heartbeat),
+                    open_sfx: open_sfx.count,
+                    pending_settlement: open_sfx.max_rewards,
+                }
+            })
+            .collect()
+    }
+
+    /// Report the telemetry of all gateways at every TelemetryInterval blocks, zero disabling it.
+    pub fn report(n: BlockNumberFor<T>) -> Weight {
+        let interval = T::TelemetryInterval::get();
+        if interval.is_zero() || !(n % interval).is_zero() {
+            return Weight::zero()
+        }
+        let telemetry = Self::collect();
+        let gateways_cnt = telemetry.len() as u64;
+        Pallet::<T>::deposit_event(Event::GatewaysTelemetry(telemetry));
+        // Gateway records are read at once, while each gateway's heartbeat and open SFX are read
+        T::DbWeight::get().reads(1 + 2 * gateways_cnt)
+    }
+}
@@ -25,7 +25,10 @@ use t3rn_sdk_primitives::{
 };
 
 use codec::Encode;
-use frame_support::{assert_err, assert_ok, traits::Currency};
+use frame_support::{
+    assert_err, assert_ok,
+    traits::{Currency, Get},
+};
 use frame_system::{EventRecord, Phase};
 
 use circuit_mock_runtime::test_utils::*;
@@ -45,7 +48,11 @@ use std::fs;
 use t3rn_types::sfx::*;
 
 use t3rn_primitives::{
-    circuit::{AdaptiveTimeout, LocalStateExecutionView, LocalTrigger, OnLocalTrigger},
+    circuit::{
+        AdaptiveTimeout, LocalStateExecutionView, LocalTrigger, OnLocalTrigger, OpenOrder,
+        SfxNotification,
+    },
+    portal::OnHeadersRolledBack,
     volatile::LocalState,
     Balance,
 };
@@ -238,6 +245,91 @@ fn on_extrinsic_trigger_works_raw_insured_side_effect() {
         });
 }
 
+#[test]
+fn on_extrinsic_trigger_rejects_escrow_sfx_on_gateway_with_optimistic_only_security_tier() {
+    let origin = Origin::signed(ALICE);
+    let valid_transfer_side_effect = produce_and_validate_side_effect(
+        *b"tran",
+        1, // insurance
+        1, // max_reward
+        t3rn_abi::Codec::Scale,
+        ArgVariant::A,
+    );
+
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            let _ = Balances::deposit_creating(&ALICE, 1 + 2);
+
+            advance_to_block(1);
+            brute_seed_block_1([0, 0, 0, 0]);
+
+            assert_ok!(XDNS::set_gateway_security_tier(
+                Origin::root(),
+                [0, 0, 0, 0],
+                t3rn_primitives::GatewaySecurityTier::OptimisticOnly,
+            ));
+
+            assert_err!(
+                Circuit::on_extrinsic_trigger(
+                    origin,
+                    vec![valid_transfer_side_effect],
+                    SpeedMode::Finalized,
+                    SecurityLvl::Escrow,
+                ),
+                circuit_error::<Runtime>::SecurityTierOfTargetInsufficientForRequestedSecurityLvl
+            );
+        });
+}
+
+#[test]
+fn on_extrinsic_trigger_rejects_xtx_above_side_effects_and_encoded_arg_limits() {
+    let valid_transfer_side_effect = produce_and_validate_side_effect(
+        *b"tran",
+        1, // insurance
+        1, // max_reward
+        t3rn_abi::Codec::Scale,
+        ArgVariant::A,
+    );
+    let mut oversized_arg_side_effect = valid_transfer_side_effect.clone();
+    oversized_arg_side_effect.encoded_args[0] =
+        vec![9u8; <Runtime as pallet_circuit::Config>::MaxEncodedArgLen::get() as usize + 1];
+
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            let _ = Balances::deposit_creating(&ALICE, 1 + 2);
+
+            advance_to_block(1);
+            brute_seed_block_1([0, 0, 0, 0]);
+
+            let max_sfx = <Runtime as pallet_circuit::Config>::MaxSideEffectsPerXtx::get();
+            assert_err!(
+                Circuit::on_extrinsic_trigger(
+                    Origin::signed(ALICE),
+                    vec![valid_transfer_side_effect; max_sfx as usize + 1],
+                    SpeedMode::Finalized,
+                    SecurityLvl::Optimistic,
+                ),
+                circuit_error::<Runtime>::TooManySideEffectsInXtx
+            );
+
+            assert_err!(
+                Circuit::on_extrinsic_trigger(
+                    Origin::signed(ALICE),
+                    vec![oversized_arg_side_effect],
+                    SpeedMode::Finalized,
+                    SecurityLvl::Optimistic,
+                ),
+                circuit_error::<Runtime>::SideEffectEncodedArgTooLong
+            );
+        });
+}
+
 #[test]
 fn on_extrinsic_trigger_works_with_single_transfer_sets_storage_entries() {
     let origin = Origin::signed(ALICE); // Only sudo access to register new gateways for now
@@ -281,13 +373,13 @@ fn on_extrinsic_trigger_works_with_single_transfer_sets_storage_entries() {
                         event: Event::Circuit(circuit_runtime_pallets::pallet_circuit::Event::<
                             Runtime,
                         >::NewSideEffectsAvailable(
-                            AccountId32::new(hex!(
-                                "0101010101010101010101010101010101010101010101010101010101010101"
-                            )),
                             hex!(
                                 "c9c2b9c48fb9c3ca9e71817fb01e907be3e0eda4d950bbdcb6dcc4c1a73a6537"
                             )
                             .into(),
+                            AccountId32::new(hex!(
+                                "0101010101010101010101010101010101010101010101010101010101010101"
+                            )),
                             vec![SideEffect {
                                 target: [0u8, 0u8, 0u8, 0u8],
                                 max_reward: 1,
@@ -362,11 +454,132 @@ fn on_extrinsic_trigger_works_with_single_transfer_sets_storage_entries() {
                     security_lvl: SecurityLvl::Optimistic,
                     submission_target_height: 0,
                     index: FIRST_SFX_INDEX,
+                    deadline: None,
+                    dependencies: vec![],
+                    metadata: vec![],
                 }]]
             );
         });
 }
 
+#[test]
+fn compute_ids_match_the_ids_derived_on_submission() {
+    let valid_transfer_side_effect = produce_and_validate_side_effect(
+        *b"tran",
+        1, // insurance
+        1, // max_reward
+        t3rn_abi::Codec::Scale,
+        ArgVariant::A,
+    );
+
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            let _ = Balances::deposit_creating(&ALICE, 1 + 2);
+
+            advance_to_block(1);
+            brute_seed_block_1([0, 0, 0, 0]);
+
+            assert_ok!(Circuit::on_extrinsic_trigger(
+                Origin::signed(ALICE),
+                vec![valid_transfer_side_effect.clone()],
+                SpeedMode::Finalized,
+                SecurityLvl::Optimistic,
+            ));
+
+            let (xtx_id, sfx_ids) = System::events()
+                .into_iter()
+                .find_map(|record| {
+                    match record.event {
+                            Event::Circuit(circuit_runtime_pallets::pallet_circuit::Event::<
+                                Runtime,
+                            >::NewSideEffectsAvailable(
+                                xtx_id, _, _, sfx_ids
+                            )) => Some((xtx_id, sfx_ids)),
+                            _ => None,
+                        }
+                })
+                .unwrap();
+
+            assert_eq!(
+                Circuit::compute_xtx_id(&ALICE, FIRST_REQUESTER_NONCE),
+                xtx_id
+            );
+            assert_eq!(
+                vec![Circuit::compute_sfx_id(
+                    &valid_transfer_side_effect,
+                    xtx_id,
+                    FIRST_SFX_INDEX
+                )],
+                sfx_ids
+            );
+        });
+}
+
+#[test]
+fn order_book_lists_sfx_of_gateway_open_for_bidding_highest_reward_first() {
+    let low_reward_sfx = produce_and_validate_side_effect(
+        *b"tran",
+        1, // insurance
+        1, // max_reward
+        t3rn_abi::Codec::Scale,
+        ArgVariant::A,
+    );
+    let high_reward_sfx = produce_and_validate_side_effect(
+        *b"tran",
+        1, // insurance
+        2, // max_reward
+        t3rn_abi::Codec::Scale,
+        ArgVariant::A,
+    );
+
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            let _ = Balances::deposit_creating(&ALICE, 10);
+
+            advance_to_block(1);
+            brute_seed_block_1([0, 0, 0, 0]);
+
+            assert_ok!(Circuit::on_extrinsic_trigger(
+                Origin::signed(ALICE),
+                vec![low_reward_sfx.clone(), high_reward_sfx.clone()],
+                SpeedMode::Finalized,
+                SecurityLvl::Optimistic,
+            ));
+
+            let xtx_id = Circuit::compute_xtx_id(&ALICE, FIRST_REQUESTER_NONCE);
+            let bidding_closes_at =
+                <Runtime as pallet_circuit::Config>::SFXBiddingPeriod::get() + 1;
+            assert_eq!(
+                Circuit::order_book([0, 0, 0, 0]),
+                vec![
+                    OpenOrder {
+                        xtx_id,
+                        sfx_id: Circuit::compute_sfx_id(&high_reward_sfx, xtx_id, 1),
+                        side_effect: high_reward_sfx,
+                        best_bid: None,
+                        bidding_closes_at,
+                        required_insurance: 1,
+                    },
+                    OpenOrder {
+                        xtx_id,
+                        sfx_id: Circuit::compute_sfx_id(&low_reward_sfx, xtx_id, 0),
+                        side_effect: low_reward_sfx,
+                        best_bid: None,
+                        bidding_closes_at,
+                        required_insurance: 1,
+                    },
+                ]
+            );
+            assert_eq!(Circuit::order_book(*b"pdot"), vec![]);
+        });
+}
+
 #[test]
 fn on_extrinsic_trigger_validation_works_with_single_transfer_insured() {
     let origin = Origin::signed(ALICE); // Only sudo access to register new gateways for now
@@ -444,13 +657,13 @@ fn on_extrinsic_trigger_works_with_single_transfer_emits_expect_events() {
                         event: Event::Circuit(circuit_runtime_pallets::pallet_circuit::Event::<
                             Runtime,
                         >::NewSideEffectsAvailable(
-                            AccountId32::new(hex!(
-                                "0101010101010101010101010101010101010101010101010101010101010101"
-                            )),
                             hex!(
                                 "c9c2b9c48fb9c3ca9e71817fb01e907be3e0eda4d950bbdcb6dcc4c1a73a6537"
                             )
                             .into(),
+                            AccountId32::new(hex!(
+                                "0101010101010101010101010101010101010101010101010101010101010101"
+                            )),
                             vec![SideEffect {
                                 target: [0u8, 0u8, 0u8, 0u8],
                                 max_reward: 1 as Balance,
@@ -560,6 +773,9 @@ fn circuit_handles_single_bid_for_transfer_sfx() {
                     security_lvl: SecurityLvl::Optimistic,
                     submission_target_height: 0,
                     index: FIRST_SFX_INDEX,
+                    deadline: None,
+                    dependencies: vec![],
+                    metadata: vec![],
                 }]]
             );
 
@@ -601,6 +817,84 @@ fn circuit_handles_single_bid_for_transfer_sfx() {
         });
 }
 
+#[test]
+fn circuit_notifies_of_sfx_submitted_and_bid_on_in_block() {
+    let origin = Origin::signed(ALICE);
+
+    let valid_transfer_side_effect = produce_and_validate_side_effect(
+        *b"tran",
+        1, // insurance
+        1, // max_reward
+        t3rn_abi::Codec::Scale,
+        ArgVariant::A,
+    );
+
+    const REQUESTED_INSURANCE_AMOUNT: Balance = 1;
+    const BID_AMOUNT: Balance = 1;
+
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            let _ = Balances::deposit_creating(&ALICE, 1 + 2 + ED);
+            let _ = Balances::deposit_creating(
+                &BOB_RELAYER,
+                REQUESTED_INSURANCE_AMOUNT + BID_AMOUNT + ED,
+            );
+
+            advance_to_block(1);
+            brute_seed_block_1([0, 0, 0, 0]);
+
+            assert_ok!(Circuit::on_extrinsic_trigger(
+                origin,
+                vec![valid_transfer_side_effect.clone()],
+                SpeedMode::Finalized,
+                SecurityLvl::Optimistic,
+            ));
+
+            let (xtx_id, side_effect_a_id) = set_ids(
+                valid_transfer_side_effect.clone(),
+                ALICE,
+                FIRST_REQUESTER_NONCE,
+                FIRST_SFX_INDEX,
+            );
+
+            assert_ok!(Circuit::bid_sfx(
+                Origin::signed(BOB_RELAYER),
+                side_effect_a_id,
+                BID_AMOUNT,
+            ));
+
+            let circuit_events =
+                System::events()
+                    .into_iter()
+                    .filter_map(|record| match record.event {
+                        Event::Circuit(event) => Some(event),
+                        _ => None,
+                    });
+
+            assert_eq!(
+                Circuit::get_sfx_notifications(circuit_events),
+                vec![
+                    SfxNotification::XtxSubmitted {
+                        xtx_id,
+                        requester: ALICE,
+                        side_effects: vec![valid_transfer_side_effect.clone()],
+                        sfx_ids: vec![side_effect_a_id],
+                    },
+                    SfxNotification::BidPlaced {
+                        xtx_id,
+                        sfx_id: side_effect_a_id,
+                        side_effect: valid_transfer_side_effect,
+                        executor: BOB_RELAYER,
+                        bid: BID_AMOUNT,
+                    },
+                ]
+            );
+        });
+}
+
 #[test]
 fn circuit_handles_dropped_at_bidding() {
     let origin = Origin::signed(ALICE); // Only sudo access to register new gateways for now
@@ -683,6 +977,260 @@ fn circuit_handles_dropped_at_bidding() {
         })
 }
 
+#[test]
+fn telemetry_reports_sfx_open_on_gateways_till_their_xtx_settles() {
+    let valid_transfer_side_effect = produce_and_validate_side_effect(
+        *b"tran",
+        1, // insurance
+        1, // max_reward
+        t3rn_abi::Codec::Scale,
+        ArgVariant::A,
+    );
+    let target = valid_transfer_side_effect.target;
+
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            let _ = Balances::deposit_creating(&ALICE, 3);
+            advance_to_block(1);
+            brute_seed_block_1([0, 0, 0, 0]);
+
+            let open_on_target = || {
+                pallet_circuit::telemetry::Telemetry::<Runtime>::collect()
+                    .into_iter()
+                    .find(|telemetry| telemetry.gateway_id == target)
+                    .map(|telemetry| (telemetry.open_sfx, telemetry.pending_settlement))
+            };
+            assert_eq!(open_on_target(), Some((0, 0)));
+
+            assert_ok!(Circuit::on_extrinsic_trigger(
+                Origin::signed(ALICE),
+                vec![valid_transfer_side_effect.clone()],
+                SpeedMode::Finalized,
+                SecurityLvl::Optimistic,
+            ));
+            assert_eq!(open_on_target(), Some((1, 1)));
+
+            // Dropped at bidding for the lack of bids
+            advance_to_block(4);
+            <Clock as frame_support::traits::OnInitialize<BlockNumber>>::on_initialize(4);
+            assert_eq!(open_on_target(), Some((0, 0)));
+            assert!(!pallet_circuit::OpenSfxByTarget::<Runtime>::contains_key(
+                target
+            ));
+        })
+}
+
+#[test]
+fn headers_rolled_back_on_optimistic_only_target_clear_confirmations_included_above_fork() {
+    let valid_transfer_side_effect = produce_and_validate_side_effect(
+        *b"tran",
+        1, // insurance
+        1, // max_reward
+        t3rn_abi::Codec::Scale,
+        ArgVariant::A,
+    );
+    let target = valid_transfer_side_effect.target;
+
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            let _ = Balances::deposit_creating(&ALICE, 1 + 2 + ED);
+            let _ = Balances::deposit_creating(&BOB_RELAYER, 1 + 1 + ED);
+
+            advance_to_block(1);
+            brute_seed_block_1([0, 0, 0, 0]);
+
+            assert_ok!(XDNS::set_gateway_security_tier(
+                Origin::root(),
+                target,
+                t3rn_primitives::GatewaySecurityTier::OptimisticOnly,
+            ));
+
+            assert_ok!(Circuit::on_extrinsic_trigger(
+                Origin::signed(ALICE),
+                vec![valid_transfer_side_effect.clone()],
+                SpeedMode::Finalized,
+                SecurityLvl::Optimistic,
+            ));
+
+            let (xtx_id, sfx_id) = set_ids(
+                valid_transfer_side_effect,
+                ALICE,
+                FIRST_REQUESTER_NONCE,
+                FIRST_SFX_INDEX,
+            );
+            place_winning_bid_and_advance_3_blocks(BOB_RELAYER, xtx_id, sfx_id, 1);
+
+            // Confirmation included at the target's height 7, still awaiting the rest of its step
+            pallet_circuit::FullSideEffects::<Runtime>::mutate(xtx_id, |steps| {
+                steps.as_mut().unwrap()[0][0].confirmed = Some(ConfirmedSideEffect {
+                    err: None,
+                    output: None,
+                    inclusion_data: vec![],
+                    executioner: BOB_RELAYER,
+                    received_at: System::block_number(),
+                    cost: None,
+                })
+            });
+            pallet_circuit::XExecSignals::<Runtime>::mutate(xtx_id, |xtx| {
+                xtx.as_mut().unwrap().status = CircuitStatus::PendingExecution
+            });
+            pallet_circuit::ProvisionalConfirmations::<Runtime>::insert(
+                target,
+                sfx_id,
+                (xtx_id, 7),
+            );
+            // Confirmation of another Xtx included below the fork
+            pallet_circuit::ProvisionalConfirmations::<Runtime>::insert(
+                target,
+                H256::repeat_byte(1),
+                (H256::repeat_byte(2), 3),
+            );
+
+            <Circuit as OnHeadersRolledBack<BlockNumber>>::on_headers_rolled_back(target, 5);
+
+            assert_eq!(
+                Circuit::get_full_side_effects(xtx_id).unwrap()[0][0].confirmed,
+                None
+            );
+            assert_eq!(
+                Circuit::get_x_exec_signals(xtx_id).unwrap().status,
+                CircuitStatus::Ready
+            );
+            assert!(
+                !pallet_circuit::ProvisionalConfirmations::<Runtime>::contains_key(target, sfx_id)
+            );
+            assert!(
+                pallet_circuit::ProvisionalConfirmations::<Runtime>::contains_key(
+                    target,
+                    H256::repeat_byte(1)
+                )
+            );
+            assert!(System::events().into_iter().any(|record| record.event
+                == Event::Circuit(pallet_circuit::Event::<Runtime>::XtxReconfirmationRequired(
+                    xtx_id,
+                    vec![sfx_id]
+                ))));
+        })
+}
+
+#[test]
+fn confirmations_on_targets_with_probabilistic_finality_await_the_confirmation_depth() {
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            let (xtx_id, sfx_id) = (H256::repeat_byte(2), H256::repeat_byte(1));
+            // Confirmations on targets with absolute finality aren't tracked
+            assert_ok!(pallet_circuit::reorgs::Reorgs::<Runtime>::note_confirmed(
+                [0, 0, 0, 0],
+                sfx_id,
+                xtx_id,
+                1
+            ));
+            assert!(
+                !pallet_circuit::ProvisionalConfirmations::<Runtime>::contains_key(
+                    [0, 0, 0, 0],
+                    sfx_id
+                )
+            );
+
+            assert_ok!(XDNS::set_gateway_security_tier(
+                Origin::root(),
+                [0, 0, 0, 0],
+                t3rn_primitives::GatewaySecurityTier::OptimisticOnly,
+            ));
+            ConfirmationDepth::set(&2);
+            // Rococo's light client is still at its genesis, with nothing built on top of height 1
+            assert_err!(
+                pallet_circuit::reorgs::Reorgs::<Runtime>::note_confirmed(
+                    [0, 0, 0, 0],
+                    sfx_id,
+                    xtx_id,
+                    1
+                ),
+                circuit_error::<Runtime>::ConfirmationBelowDepth
+            );
+
+            ConfirmationDepth::set(&0);
+            assert_ok!(pallet_circuit::reorgs::Reorgs::<Runtime>::note_confirmed(
+                [0, 0, 0, 0],
+                sfx_id,
+                xtx_id,
+                1
+            ));
+            assert_eq!(
+                pallet_circuit::ProvisionalConfirmations::<Runtime>::get([0, 0, 0, 0], sfx_id),
+                Some((xtx_id, 1))
+            );
+        })
+}
+
+#[test]
+fn on_xcm_trigger_notifies_sibling_of_xtx_dropped_at_bidding() {
+    let valid_transfer_side_effect = produce_and_validate_side_effect(
+        *b"tran",
+        1, // insurance
+        1, // max_reward
+        t3rn_abi::Codec::Scale,
+        ArgVariant::A,
+    );
+
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            // The mock resolves signed origins to the sibling MOCK_SIBLING_PARA_ID, owning ALICE
+            let _ = Balances::deposit_creating(&ALICE, 3);
+
+            advance_to_block(1);
+            brute_seed_block_1([0, 0, 0, 0]);
+
+            assert_ok!(Circuit::on_xcm_trigger(
+                Origin::signed(ALICE),
+                vec![valid_transfer_side_effect.clone()],
+                SpeedMode::Finalized,
+                Some(vec![42, 0]),
+            ));
+
+            let (xtx_id, _sfx_id) = set_ids(
+                valid_transfer_side_effect,
+                ALICE,
+                FIRST_REQUESTER_NONCE,
+                FIRST_SFX_INDEX,
+            );
+            assert_eq!(
+                Circuit::get_xcm_order(xtx_id),
+                Some(XcmOrder {
+                    para_id: MOCK_SIBLING_PARA_ID,
+                    notify_call: vec![42, 0],
+                })
+            );
+            System::assert_has_event(Event::Circuit(
+                pallet_circuit::Event::<Runtime>::XcmOrderReceived(xtx_id, MOCK_SIBLING_PARA_ID),
+            ));
+
+            advance_to_block(4);
+            <Clock as frame_support::traits::OnInitialize<BlockNumber>>::on_initialize(4);
+
+            System::assert_has_event(Event::Circuit(
+                pallet_circuit::Event::<Runtime>::XcmOrderNotified(
+                    xtx_id,
+                    MOCK_SIBLING_PARA_ID,
+                    CircuitStatus::Killed(Cause::Timeout),
+                ),
+            ));
+            assert_eq!(Circuit::get_xcm_order(xtx_id), None);
+        })
+}
+
 const SINGLE_XTX_DEL_WEIGHT: Weight = Weight::from_parts(450000000, 0);
 
 #[test]
@@ -793,6 +1341,9 @@ fn circuit_selects_best_bid_out_of_3_for_transfer_sfx() {
                     security_lvl: SecurityLvl::Optimistic,
                     submission_target_height: 0,
                     index: FIRST_SFX_INDEX,
+                    deadline: None,
+                    dependencies: vec![],
+                    metadata: vec![],
                 }]]
             );
 
@@ -824,6 +1375,7 @@ fn circuit_selects_best_bid_out_of_3_for_transfer_sfx() {
                         topics: vec![] },
                     EventRecord { phase: Phase::Initialization, event: Event::Circuit(
                         circuit_runtime_pallets::pallet_circuit::Event::<Runtime>::SFXNewBidReceived(
+                            xtx_id,
                             side_effect_a_id,
                             BID_WINNER,
                             BID_AMOUNT_C,
@@ -982,6 +1534,9 @@ fn circuit_handles_swap_with_insurance() {
                     security_lvl: SecurityLvl::Optimistic,
                     submission_target_height: 0,
                     index: FIRST_SFX_INDEX,
+                    deadline: None,
+                    dependencies: vec![],
+                    metadata: vec![],
                 }]]
             );
 
@@ -1120,6 +1675,9 @@ fn circuit_handles_add_liquidity_with_insurance() {
                     security_lvl: SecurityLvl::Optimistic,
                     submission_target_height: 0,
                     index: FIRST_SFX_INDEX,
+                    deadline: None,
+                    dependencies: vec![],
+                    metadata: vec![],
                 }]]
             );
 
@@ -1541,6 +2099,16 @@ fn circuit_cancels_xtx_with_bids_after_timeout() {
                     } }),
                 true
             );
+
+            // The side effect left unconfirmed counts as reverted against the side effects requested on its gateway
+            let stats = circuit_runtime_pallets::pallet_xdns::GatewayActivityStatsStore::<Runtime>::get(
+                valid_transfer_side_effect.target,
+            );
+            assert_eq!(
+                (stats.sfx_targeted, stats.sfx_confirmed, stats.sfx_reverted),
+                (1, 0, 1)
+            );
+            assert_eq!(stats.revert_rate(), sp_runtime::Percent::from_percent(100));
             // Voids all associated side effects with Xtx by setting their confirmation to Err
         });
 }
@@ -1892,7 +2460,10 @@ use t3rn_sdk_primitives::{
     storage::BoundedVec,
     xc::{Call as CallVM, Operation},
 };
-use t3rn_types::migrations::v13::{FullSideEffectV13, SideEffectV13};
+use t3rn_types::migrations::{
+    v13::{FullSideEffectV13, SideEffectV13},
+    v14::FullSideEffectV14,
+};
 
 // TODO: this fails because the side effect doesnt work for the gateway, will be fixed in the future
 #[ignore]
@@ -2330,8 +2901,8 @@ fn no_duplicate_xtx_and_sfx_ids() {
                 },
                 EventRecord { phase: Phase::Initialization, event: Event::Circuit(
                     circuit_runtime_pallets::pallet_circuit::Event::<Runtime>::NewSideEffectsAvailable(
-                        ALICE,
                         expected_xtx_id_1,
+                        ALICE,
                         vec![
                             SideEffect {
                                 target: [0, 0, 0, 0],
@@ -2363,8 +2934,8 @@ fn no_duplicate_xtx_and_sfx_ids() {
                         payee: ALICE, recipient: None, amount: BID_AMOUNT }), topics: vec![] },
                 EventRecord { phase: Phase::Initialization, event: Event::Circuit(
                     circuit_runtime_pallets::pallet_circuit::Event::<Runtime>::NewSideEffectsAvailable(
-                        ALICE,
                         expected_xtx_id_2,
+                        ALICE,
                         vec![
                             SideEffect {
                                 target: [0, 0, 0, 0],
@@ -2447,6 +3018,9 @@ fn test_storage_migration_v130_to_v140_for_fsx_map_with_updated_encoded_action_f
                     submission_target_height: 920844,
                     best_bid: None,
                     index: 0,
+                    deadline: None,
+                    dependencies: vec![],
+                    metadata: vec![],
                 }
             );
 
@@ -2467,12 +3041,14 @@ fn test_storage_migration_v130_to_v140_for_fsx_map_with_updated_encoded_action_f
             // Perform the runtime upgrade (call the `on_runtime_upgrade` function)
             let consumed_weight =
                 <Circuit as frame_support::traits::OnRuntimeUpgrade>::on_runtime_upgrade();
+            // Migration weight plus the read and write of the migrations counter
+            // plus the read of the counter by the migration of v1 skipped past
             let max_weight =
-                <Runtime as frame_system::Config>::DbWeight::get().reads_writes(10, 10);
+                <Runtime as frame_system::Config>::DbWeight::get().reads_writes(12, 11);
             assert_eq!(consumed_weight, max_weight);
 
             // Check that storage version has been updated.
-            assert_eq!(pallet_circuit::StorageMigrations::<Runtime>::get(), 1);
+            assert_eq!(pallet_circuit::StorageMigrations::<Runtime>::get(), 2);
 
             // Check if the migrated data is as expected.
             let fsx =
@@ -2492,3 +3068,369 @@ fn test_storage_migration_v130_to_v140_for_fsx_map_with_updated_encoded_action_f
             }
         });
 }
+
+#[test]
+fn test_storage_migration_v1_to_v2_adds_fsx_deadlines_dependencies_and_metadata() {
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            let fsx_v14 = FullSideEffectV14::<AccountId32, BlockNumber, Balance> {
+                input: SideEffect {
+                    target: [1, 1, 1, 1],
+                    max_reward: 100,
+                    insurance: 50,
+                    action: *b"tran",
+                    encoded_args: vec![vec![5, 6], vec![7, 8]],
+                    signature: vec![9, 10, 11],
+                    enforce_executor: None,
+                    reward_asset_id: Some(3),
+                },
+                confirmed: None,
+                security_lvl: SecurityLvl::Optimistic,
+                submission_target_height: 12,
+                best_bid: None,
+                index: 1,
+            };
+
+            // Store the FSX as encoded before the new fields were added
+            frame_support::storage::unhashed::put_raw(
+                &pallet_circuit::FullSideEffects::<Runtime>::hashed_key_for(H256::repeat_byte(1)),
+                &vec![vec![fsx_v14.clone()]].encode(),
+            );
+            pallet_circuit::StorageMigrations::<Runtime>::put(1);
+
+            let consumed_weight =
+                <Circuit as frame_support::traits::OnRuntimeUpgrade>::on_runtime_upgrade();
+            // Translation of the Xtx FSX, the counter read by both migrations and written once
+            let max_weight = <Runtime as frame_system::Config>::DbWeight::get().reads_writes(3, 2);
+            assert_eq!(consumed_weight, max_weight);
+            assert_eq!(pallet_circuit::StorageMigrations::<Runtime>::get(), 2);
+
+            assert_eq!(
+                pallet_circuit::FullSideEffects::<Runtime>::get(H256::repeat_byte(1)),
+                Some(vec![vec![FullSideEffect {
+                    input: fsx_v14.input,
+                    confirmed: None,
+                    security_lvl: SecurityLvl::Optimistic,
+                    submission_target_height: 12,
+                    best_bid: None,
+                    index: 1,
+                    deadline: None,
+                    dependencies: vec![],
+                    metadata: vec![],
+                }]])
+            );
+        });
+}
+
+#[test]
+fn sponsored_xtx_are_paid_for_by_sponsors_who_signed_their_voucher_once() {
+    use circuit_runtime_pallets::pallet_circuit::sponsor::SponsorshipVoucher;
+    use sp_core::{sr25519, Pair};
+    use sp_runtime::MultiSignature;
+
+    let sponsor_pair = sr25519::Pair::from_seed(&[7u8; 32]);
+    let sponsor: AccountId32 = sponsor_pair.public().into();
+    let valid_transfer_side_effect = produce_and_validate_side_effect(
+        *b"tran",
+        1, // insurance
+        1, // max_reward
+        t3rn_abi::Codec::Scale,
+        ArgVariant::A,
+    );
+
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            let _ = Balances::deposit_creating(&sponsor, 10);
+
+            advance_to_block(1);
+            brute_seed_block_1([0, 0, 0, 0]);
+
+            let voucher = SponsorshipVoucher {
+                sponsor: sponsor.clone(),
+                requester: ALICE,
+                max_rewards: 1,
+                valid_until: 10,
+                nonce: 0,
+            };
+            let sign = |voucher: &SponsorshipVoucher<AccountId32, Balance, BlockNumber>| {
+                let genesis_hash = System::block_hash(0);
+                MultiSignature::Sr25519(sponsor_pair.sign(&voucher.signing_payload(&genesis_hash)))
+            };
+            let signature = sign(&voucher);
+
+            assert_err!(
+                Circuit::on_sponsored_extrinsic_trigger(
+                    Origin::signed(BOB),
+                    vec![valid_transfer_side_effect.clone()],
+                    SpeedMode::Finalized,
+                    SecurityLvl::Optimistic,
+                    voucher.clone(),
+                    signature.clone(),
+                ),
+                circuit_error::<Runtime>::SponsorshipRequesterMismatch
+            );
+            assert_err!(
+                Circuit::on_sponsored_extrinsic_trigger(
+                    Origin::signed(ALICE),
+                    vec![valid_transfer_side_effect.clone()],
+                    SpeedMode::Finalized,
+                    SecurityLvl::Optimistic,
+                    SponsorshipVoucher {
+                        max_rewards: 100,
+                        ..voucher.clone()
+                    },
+                    signature.clone(),
+                ),
+                circuit_error::<Runtime>::SponsorshipSignatureInvalid
+            );
+
+            // The requester holds no funds of its own
+            assert_ok!(Circuit::on_sponsored_extrinsic_trigger(
+                Origin::signed(ALICE),
+                vec![valid_transfer_side_effect.clone()],
+                SpeedMode::Finalized,
+                SecurityLvl::Optimistic,
+                voucher.clone(),
+                signature.clone(),
+            ));
+            assert_eq!(Balances::free_balance(&sponsor), 10 - 1);
+            assert_eq!(Balances::free_balance(&ALICE), 0);
+            assert_eq!(Circuit::sponsor_nonce(&sponsor), 1);
+
+            assert_err!(
+                Circuit::on_sponsored_extrinsic_trigger(
+                    Origin::signed(ALICE),
+                    vec![valid_transfer_side_effect.clone()],
+                    SpeedMode::Finalized,
+                    SecurityLvl::Optimistic,
+                    voucher.clone(),
+                    signature,
+                ),
+                circuit_error::<Runtime>::SponsorshipNonceMismatch
+            );
+
+            let next_voucher = SponsorshipVoucher {
+                nonce: 1,
+                ..voucher
+            };
+            assert_err!(
+                Circuit::on_sponsored_extrinsic_trigger(
+                    Origin::signed(ALICE),
+                    vec![
+                        valid_transfer_side_effect.clone(),
+                        valid_transfer_side_effect
+                    ],
+                    SpeedMode::Finalized,
+                    SecurityLvl::Optimistic,
+                    next_voucher.clone(),
+                    sign(&next_voucher),
+                ),
+                circuit_error::<Runtime>::SponsorshipAllowanceExceeded
+            );
+        });
+}
+
+#[test]
+fn sponsored_xtx_transaction_fees_are_charged_to_sponsors_as_long_as_the_voucher_checks_out() {
+    use circuit_runtime_pallets::{
+        pallet_circuit::sponsor::SponsorshipVoucher,
+        pallet_transaction_payment::{Config as TransactionPaymentConfig, OnChargeTransaction},
+    };
+    use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
+    use sp_core::{sr25519, Pair};
+    use sp_runtime::MultiSignature;
+
+    type FeeAdapter = <Runtime as TransactionPaymentConfig>::OnChargeTransaction;
+
+    let sponsor_pair = sr25519::Pair::from_seed(&[7u8; 32]);
+    let sponsor: AccountId32 = sponsor_pair.public().into();
+    let valid_transfer_side_effect = produce_and_validate_side_effect(
+        *b"tran",
+        1, // insurance
+        1, // max_reward
+        t3rn_abi::Codec::Scale,
+        ArgVariant::A,
+    );
+
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            let _ = Balances::deposit_creating(&sponsor, 100);
+            let _ = Balances::deposit_creating(&ALICE, 100);
+            let requester_balance = Balances::free_balance(&ALICE);
+
+            advance_to_block(1);
+
+            let voucher = SponsorshipVoucher {
+                sponsor: sponsor.clone(),
+                requester: ALICE,
+                max_rewards: 1,
+                valid_until: 10,
+                nonce: 0,
+            };
+            let genesis_hash = System::block_hash(0);
+            let sponsored_call =
+                |voucher: SponsorshipVoucher<AccountId32, Balance, BlockNumber>| {
+                    RuntimeCall::Circuit(pallet_circuit::Call::on_sponsored_extrinsic_trigger {
+                        side_effects: vec![valid_transfer_side_effect.clone()],
+                        speed_mode: SpeedMode::Finalized,
+                        preferred_security_level: SecurityLvl::Optimistic,
+                        signature: MultiSignature::Sr25519(
+                            sponsor_pair.sign(&voucher.signing_payload(&genesis_hash)),
+                        ),
+                        voucher,
+                    })
+                };
+
+            let call = sponsored_call(voucher.clone());
+            let info = call.get_dispatch_info();
+            let paid = FeeAdapter::withdraw_fee(&ALICE, &call, &info, 10, 0).unwrap();
+            assert_eq!(Balances::free_balance(&sponsor), 100 - 10);
+            assert_eq!(Balances::free_balance(&ALICE), requester_balance);
+
+            // The sponsor is refunded whatever the submission didn't use
+            assert_ok!(FeeAdapter::correct_and_deposit_fee(
+                &ALICE,
+                &info,
+                &PostDispatchInfo::default(),
+                4,
+                0,
+                paid,
+            ));
+            assert_eq!(Balances::free_balance(&sponsor), 100 - 4);
+            assert_eq!(Balances::free_balance(&ALICE), requester_balance);
+
+            // Vouchers which wouldn't be redeemed leave the fee to the requester
+            let expired_call = sponsored_call(SponsorshipVoucher {
+                valid_until: 0,
+                ..voucher
+            });
+            let paid = FeeAdapter::withdraw_fee(&ALICE, &expired_call, &info, 10, 0).unwrap();
+            assert_ok!(FeeAdapter::correct_and_deposit_fee(
+                &ALICE,
+                &info,
+                &PostDispatchInfo::default(),
+                10,
+                0,
+                paid,
+            ));
+            assert_eq!(Balances::free_balance(&sponsor), 100 - 4);
+            assert_eq!(Balances::free_balance(&ALICE), requester_balance - 10);
+        });
+}
+
+#[test]
+fn escrow_vault_holds_escrows_within_per_asset_caps_until_released() {
+    use circuit_runtime_pallets::pallet_circuit::escrow::EscrowVault;
+    use t3rn_primitives::account_manager::Outcome;
+
+    let xtx_id = H256::repeat_byte(1);
+    let escrow_id = H256::repeat_byte(2);
+    let (first_sfx_id, second_sfx_id) = (H256::repeat_byte(3), H256::repeat_byte(4));
+
+    ExtBuilder::default().build().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_err!(
+            Circuit::set_escrow_cap(Origin::signed(ALICE), Some(1), Some(100)),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(Circuit::set_escrow_cap(Origin::root(), Some(1), Some(100)));
+
+        assert_ok!(EscrowVault::<Runtime>::lock(
+            xtx_id,
+            first_sfx_id,
+            escrow_id,
+            Some(1),
+            60
+        ));
+        assert_err!(
+            EscrowVault::<Runtime>::lock(xtx_id, first_sfx_id, escrow_id, Some(1), 10),
+            circuit_error::<Runtime>::EscrowAlreadyHeld
+        );
+        assert_err!(
+            EscrowVault::<Runtime>::lock(xtx_id, second_sfx_id, escrow_id, Some(1), 50),
+            circuit_error::<Runtime>::EscrowCapExceeded
+        );
+        // The native currency is left uncapped
+        assert_ok!(EscrowVault::<Runtime>::lock(
+            xtx_id,
+            second_sfx_id,
+            escrow_id,
+            None,
+            1_000
+        ));
+        assert_eq!(Circuit::get_escrow_exposure(Some(1)), 60);
+        assert_eq!(Circuit::get_escrow_exposure(None::<u32>), 1_000);
+
+        EscrowVault::<Runtime>::release(first_sfx_id, &Outcome::Commit);
+        assert_eq!(Circuit::get_escrow_exposure(Some(1)), 0);
+        assert!(Circuit::get_escrowed_sfx(first_sfx_id).is_none());
+        System::assert_last_event(Event::Circuit(
+            circuit_runtime_pallets::pallet_circuit::Event::<Runtime>::EscrowReleased(
+                xtx_id,
+                first_sfx_id,
+                Some(1),
+                60,
+                Outcome::Commit,
+            ),
+        ));
+
+        assert_err!(
+            Circuit::emergency_escrow_withdrawal(Origin::root(), first_sfx_id),
+            circuit_error::<Runtime>::EscrowNotHeld
+        );
+        // The held Xtx no longer exists and can't be reverted
+        assert_err!(
+            Circuit::emergency_escrow_withdrawal(Origin::root(), second_sfx_id),
+            circuit_error::<Runtime>::EscrowWithdrawalFailed
+        );
+    });
+}
+
+#[test]
+fn message_and_read_confirmations_must_be_emitted_by_the_target_contract() {
+    let side_effect = |action: [u8; 4], encoded_args: Vec<Vec<u8>>| SideEffect {
+        target: [3u8, 3u8, 3u8, 3u8],
+        max_reward: 2,
+        action,
+        encoded_args,
+        signature: vec![],
+        enforce_executor: None,
+        insurance: 3,
+        reward_asset_id: None,
+    };
+
+    assert_eq!(
+        Circuit::expected_event_source(&side_effect(*b"mesg", vec![vec![7u8; 32], vec![1u8; 4]])),
+        Ok(Some([7u8; 32]))
+    );
+    assert_eq!(
+        Circuit::expected_event_source(&side_effect(*b"read", vec![vec![8u8; 32], vec![2u8; 32]])),
+        Ok(Some([8u8; 32]))
+    );
+    assert_eq!(
+        Circuit::expected_event_source(&side_effect(*b"tran", vec![vec![7u8; 32], vec![1u8; 16]])),
+        Ok(None)
+    );
+    assert!(Circuit::expected_event_source(&side_effect(
+        *b"mesg",
+        vec![vec![7u8; 20], vec![1u8; 4]]
+    ))
+    .is_err());
+    assert!(Circuit::expected_event_source(&side_effect(
+        *b"read",
+        vec![vec![8u8; 20], vec![2u8; 32]]
+    ))
+    .is_err());
+    assert!(Circuit::expected_event_source(&side_effect(*b"mesg", vec![])).is_err());
+    assert!(Circuit::expected_event_source(&side_effect(*b"read", vec![])).is_err());
+}
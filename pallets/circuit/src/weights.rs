@@ -31,10 +31,13 @@ use sp_std::marker::PhantomData;
 pub trait WeightInfo {
     fn on_local_trigger() -> Weight;
     fn on_extrinsic_trigger() -> Weight;
+    fn on_sponsored_extrinsic_trigger() -> Weight;
     fn bid_sfx() -> Weight;
     fn cancel_xtx() -> Weight;
     fn confirm_side_effect() -> Weight;
     fn execute_side_effects_with_xbi() -> Weight;
+    fn set_escrow_cap() -> Weight;
+    fn emergency_escrow_withdrawal() -> Weight;
 }
 
 /// Storage: `XDNS::Gateways` (r:2 w:0)
@@ -89,6 +92,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
         single_order_weight::<T>()
     }
 
+    /// Storage: `Circuit::SponsorNonces` (r:1 w:1)
+    /// Storage: `Circuit::XtxSponsors` (r:1 w:1)
+    fn on_sponsored_extrinsic_trigger() -> Weight {
+        single_order_weight::<T>()
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+
+    // Excludes verifying the inclusion proof, weighed by the portal for the size of the proof
     fn confirm_side_effect() -> Weight {
         single_order_weight::<T>()
     }
@@ -104,6 +116,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     fn execute_side_effects_with_xbi() -> Weight {
         single_order_weight::<T>()
     }
+
+    /// Storage: `Circuit::EscrowCaps` (r:0 w:1)
+    fn set_escrow_cap() -> Weight {
+        Weight::from_parts(10_000_000, 0).saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    /// Storage: `Circuit::EscrowedSFX` (r:1 w:0)
+    fn emergency_escrow_withdrawal() -> Weight {
+        single_order_weight::<T>().saturating_add(T::DbWeight::get().reads(1))
+    }
 }
 
 // For backwards compatibility and tests
@@ -116,6 +138,12 @@ impl WeightInfo for () {
         Weight::from_parts(60_000_000_u64, 0u64)
     }
 
+    fn on_sponsored_extrinsic_trigger() -> Weight {
+        Weight::from_parts(60_000_000_u64, 0u64)
+            .saturating_add(RocksDbWeight::get().reads(2))
+            .saturating_add(RocksDbWeight::get().writes(2))
+    }
+
     fn confirm_side_effect() -> Weight {
         Weight::from_parts(60_000_000_u64, 0u64)
     }
@@ -131,4 +159,12 @@ impl WeightInfo for () {
     fn execute_side_effects_with_xbi() -> Weight {
         Weight::from_parts(60_000_000_u64, 0u64)
     }
+
+    fn set_escrow_cap() -> Weight {
+        Weight::from_parts(10_000_000_u64, 0u64).saturating_add(RocksDbWeight::get().writes(1))
+    }
+
+    fn emergency_escrow_withdrawal() -> Weight {
+        Weight::from_parts(60_000_000_u64, 0u64).saturating_add(RocksDbWeight::get().reads(1))
+    }
 }
@@ -28,6 +28,7 @@ use frame_benchmarking::{benchmarks, whitelisted_caller};
 use frame_support::assert_ok;
 use frame_system::{EventRecord, Pallet as System, RawOrigin};
 use pallet_xdns::Pallet as XDNS;
+use sp_runtime::traits::UniqueSaturatedFrom;
 use t3rn_primitives::{monetary::EXISTENTIAL_DEPOSIT, xdns::Xdns};
 fn assume_last_xtx_event<T: Config>() -> T::Hash {
     let events = System::<T>::events();
@@ -101,6 +102,63 @@ fn submit_one_single_order_as_tran<T: Config>(
     ));
 }
 
+const REMOTE_GATEWAY: TargetId = [5u8; 4];
+const BRIDGED_ASSET: u32 = 1_000_000;
+const BRIDGED_AMOUNT: u128 = 1_000 * EXISTENTIAL_DEPOSIT;
+
+fn bridged_amount<T: Config>() -> BalanceOf<T> {
+    BalanceOf::<T>::unique_saturated_from(BRIDGED_AMOUNT)
+}
+
+/// Register `BRIDGED_ASSET` as a mintable local asset corresponding to itself on `REMOTE_GATEWAY`
+fn enroll_bridged_asset<T: Config>() {
+    let self_gateway_id = <T as pallet_xdns::Config>::SelfGatewayId::get();
+    XDNS::<T>::do_reboot_self_gateway(RawOrigin::Root.into(), GatewayVendor::Rococo)
+        .expect("Self-gateway should reboot in Vacuum::runtime_benchmarks");
+    <T::Xdns>::register_new_token(
+        &RawOrigin::Root.into(),
+        BRIDGED_ASSET,
+        TokenInfo::Substrate(SubstrateToken {
+            id: BRIDGED_ASSET,
+            symbol: b"brdg".to_vec(),
+            decimals: 12,
+        }),
+    )
+    .expect("Bridged asset should register in Vacuum::runtime_benchmarks");
+    pallet_xdns::AuthorizedMintAssets::<T>::try_append((BRIDGED_ASSET, self_gateway_id))
+        .expect("Bridged asset should be authorised to mint in Vacuum::runtime_benchmarks");
+    XDNS::<T>::insert_asset_correspondence(
+        REMOTE_GATEWAY,
+        BRIDGED_ASSET,
+        self_gateway_id,
+        BRIDGED_ASSET,
+    )
+    .expect("Bridged asset should correspond to itself in Vacuum::runtime_benchmarks");
+    XDNS::<T>::insert_asset_correspondence(
+        self_gateway_id,
+        BRIDGED_ASSET,
+        REMOTE_GATEWAY,
+        BRIDGED_ASSET,
+    )
+    .expect("Bridged asset should correspond to itself in Vacuum::runtime_benchmarks");
+}
+
+/// Pair `BRIDGED_ASSET` with a vault on `REMOTE_GATEWAY` rate limited to `BRIDGED_AMOUNT`
+fn register_bridged_asset_vault<T: Config>() -> VaultRecord<BalanceOf<T>, BlockNumberFor<T>> {
+    enroll_bridged_asset::<T>();
+    Vacuum::<T>::register_vault(
+        RawOrigin::Root.into(),
+        BRIDGED_ASSET,
+        REMOTE_GATEWAY,
+        H256::repeat_byte(2),
+        H160::repeat_byte(3),
+        bridged_amount::<T>(),
+        100u32.into(),
+    )
+    .expect("Vault should register in Vacuum::runtime_benchmarks");
+    Vaults::<T>::get(BRIDGED_ASSET).expect("Vault should be stored in Vacuum::runtime_benchmarks")
+}
+
 benchmarks! {
 
     single_order {
@@ -133,4 +191,121 @@ benchmarks! {
 
         let xtx_id = assume_last_xtx_event::<T>();
     }: _(RawOrigin::Signed(caller), xtx_id)
+
+    // Proofs are verified by the Portal and charged at its weight on top, so only minting
+    // against the already verified lock is measured
+    bridge_in {
+        enroll_bridged_asset::<T>();
+        let lock = RemoteEVMBridgeLockLocalized {
+            lock_id: H256::repeat_byte(1),
+            asset: BRIDGED_ASSET,
+            beneficiary: AccountId32::new([1u8; 32]),
+            amount: U256::from(BRIDGED_AMOUNT),
+        };
+    }: {
+        Vacuum::<T>::mint_bridge_lock(REMOTE_GATEWAY, lock)?;
+    }
+    verify {
+        assert!(ProcessedBridgeLocks::<T>::contains_key(REMOTE_GATEWAY, H256::repeat_byte(1)));
+    }
+
+    bridge_out {
+        let caller: T::AccountId = whitelisted_caller();
+        enroll_bridged_asset::<T>();
+        let amount = bridged_amount::<T>();
+        <T::Xdns>::mint(BRIDGED_ASSET, caller.clone(), amount + amount)
+            .expect("Bridged asset should mint in Vacuum::runtime_benchmarks");
+    }: _(RawOrigin::Signed(caller), BRIDGED_ASSET, REMOTE_GATEWAY, [1u8; 32], amount)
+    verify {
+        assert_eq!(BridgeOutNonce::<T>::get(), 1);
+    }
+
+    eth_signed_order {
+        let s in 1 .. 8;
+        let relayer: T::AccountId = whitelisted_caller();
+        let gateway_id: TargetId = [4u8; 4];
+        let order_amount = BalanceOf::<T>::from(100u8);
+        let max_reward = BalanceOf::<T>::from(200u8);
+        let insurance = max_reward / BalanceOf::<T>::from(10u8);
+        register_gateway_under_xbi_vendor::<T>(gateway_id, relayer.clone());
+
+        let sfx_actions = (0..s)
+            .map(|_| OrderSFX {
+                sfx_action: SFXAction::Transfer(gateway_id, NATIVE_ASSET, relayer.clone(), order_amount),
+                max_reward,
+                insurance,
+                reward_asset: NATIVE_ASSET,
+                remote_origin_nonce: None,
+            })
+            .collect::<Vec<_>>();
+        let digest = Vacuum::<T>::eth_signed_order_digest(&sfx_actions, &SpeedMode::Fast, 0);
+        let eth_key = sp_io::crypto::ecdsa_generate(sp_core::testing::ECDSA, None);
+        let signature = sp_io::crypto::ecdsa_sign_prehashed(sp_core::testing::ECDSA, &eth_key, &digest)
+            .expect("Orders should sign in Vacuum::runtime_benchmarks")
+            .0;
+        let signer = Vacuum::<T>::recover_eth_signer(&signature, &digest)
+            .expect("Signer should recover in Vacuum::runtime_benchmarks");
+
+        // Mint enough of local currency for the derived account as requester
+        <T as Config>::Currency::deposit_creating(
+            &T::AddressMapping::into_account_id(&signer),
+            (insurance + max_reward + order_amount) * BalanceOf::<T>::from(s)
+                + BalanceOf::<T>::from(EXISTENTIAL_DEPOSIT as u8),
+        );
+    }: _(RawOrigin::Signed(relayer), sfx_actions, SpeedMode::Fast, signer, 0, signature)
+    verify {
+        assert_eq!(Vacuum::<T>::eth_signer_nonce(signer), 1);
+    }
+
+    register_vault {
+        enroll_bridged_asset::<T>();
+    }: _(RawOrigin::Root, BRIDGED_ASSET, REMOTE_GATEWAY, H256::repeat_byte(2), H160::repeat_byte(3), bridged_amount::<T>(), 100u32.into())
+    verify {
+        assert!(Vaults::<T>::contains_key(BRIDGED_ASSET));
+    }
+
+    set_vault_paused {
+        register_bridged_asset_vault::<T>();
+    }: _(RawOrigin::Root, BRIDGED_ASSET, true)
+    verify {
+        assert!(Vaults::<T>::get(BRIDGED_ASSET).map_or(false, |vault| vault.paused));
+    }
+
+    // As with bridge_in, only minting against the already verified deposit is measured
+    vault_deposit {
+        register_bridged_asset_vault::<T>();
+        let deposit = RemoteEVMBridgeLockLog {
+            lock_id: H256::repeat_byte(1),
+            asset: H160::repeat_byte(3),
+            beneficiary: AccountId32::new([1u8; 32]),
+            amount: U256::from(BRIDGED_AMOUNT),
+        };
+    }: {
+        let vault = Vaults::<T>::get(BRIDGED_ASSET)
+            .ok_or("Vault should be registered in Vacuum::runtime_benchmarks")?;
+        ensure!(!vault.paused, "Vault should be unpaused in Vacuum::runtime_benchmarks");
+        Vacuum::<T>::mint_vault_deposit(BRIDGED_ASSET, &vault, deposit)?;
+    }
+    verify {
+        assert!(ProcessedVaultDeposits::<T>::contains_key(BRIDGED_ASSET, H256::repeat_byte(1)));
+    }
+
+    vault_withdraw {
+        let caller: T::AccountId = whitelisted_caller();
+        let max_reward = BalanceOf::<T>::from(200u8);
+        register_gateway_under_xbi_vendor::<T>(REMOTE_GATEWAY, caller.clone());
+        register_bridged_asset_vault::<T>();
+        let amount = bridged_amount::<T>();
+        <T::Xdns>::mint(BRIDGED_ASSET, caller.clone(), amount + amount)
+            .expect("Bridged asset should mint in Vacuum::runtime_benchmarks");
+
+        // Mint enough of local currency for caller as requester
+        <T as Config>::Currency::deposit_creating(
+            &caller,
+            max_reward + max_reward + BalanceOf::<T>::from(EXISTENTIAL_DEPOSIT as u8),
+        );
+    }: _(RawOrigin::Signed(caller), BRIDGED_ASSET, [1u8; 32], amount, NATIVE_ASSET, max_reward, SpeedMode::Fast)
+    verify {
+        assert_eq!(VaultWithdrawalNonce::<T>::get(BRIDGED_ASSET), 1);
+    }
 }
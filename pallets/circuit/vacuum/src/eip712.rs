@@ -0,0 +1,98 @@
+//! EIP-712 typed-data schema of side-effect orders, letting wallets display what an order
+//! requests before its signer authorises it.
+//!
+//! The order is signed under the domain `EIP712Domain(string name,string version,bytes32 salt)`
+//! named "t3rn" at version "1", salted with the genesis hash of the chain it is meant for.
+use crate::{Asset, Destination};
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_core::U256;
+use sp_io::hashing::keccak_256;
+use sp_std::vec::Vec;
+use t3rn_primitives::SpeedMode;
+
+pub const DOMAIN_TYPE: &[u8] = b"EIP712Domain(string name,string version,bytes32 salt)";
+pub const DOMAIN_NAME: &[u8] = b"t3rn";
+pub const DOMAIN_VERSION: &[u8] = b"1";
+
+pub const SIDE_EFFECT_TYPE: &[u8] = b"SideEffect(bytes4 destination,uint32 asset,bytes32 target,uint256 amount,uint32 rewardAsset,uint256 maxReward,uint256 insurance)";
+/// Referenced struct types are appended to the primary type, as EIP-712 encodes them
+pub const ORDER_TYPE: &[u8] = b"Order(SideEffect[] sideEffects,uint8 speedMode,uint32 nonce)SideEffect(bytes4 destination,uint32 asset,bytes32 target,uint256 amount,uint32 rewardAsset,uint256 maxReward,uint256 insurance)";
+
+/// Transfer of `amount` of `asset` to `target` on `destination`, as displayed to the signer.
+#[derive(Debug, Clone, Eq, PartialEq, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub struct Eip712SideEffect {
+    pub destination: Destination,
+    pub asset: Asset,
+    pub target: [u8; 32],
+    pub amount: u128,
+    pub reward_asset: Asset,
+    pub max_reward: u128,
+    pub insurance: u128,
+}
+
+impl Eip712SideEffect {
+    pub fn struct_hash(&self) -> [u8; 32] {
+        let mut encoded = keccak_256(SIDE_EFFECT_TYPE).to_vec();
+        // bytes4 is left-aligned within its word
+        let mut destination_word = [0u8; 32];
+        destination_word[..4].copy_from_slice(&self.destination);
+        encoded.extend_from_slice(&destination_word);
+        encoded.extend_from_slice(&uint_word(self.asset.into()));
+        encoded.extend_from_slice(&self.target);
+        encoded.extend_from_slice(&uint_word(self.amount));
+        encoded.extend_from_slice(&uint_word(self.reward_asset.into()));
+        encoded.extend_from_slice(&uint_word(self.max_reward));
+        encoded.extend_from_slice(&uint_word(self.insurance));
+        keccak_256(&encoded)
+    }
+}
+
+/// Speed modes are displayed by their SCALE discriminant.
+pub fn speed_mode_discriminant(speed_mode: &SpeedMode) -> u8 {
+    speed_mode.encode()[0]
+}
+
+pub fn domain_separator(salt: [u8; 32]) -> [u8; 32] {
+    let mut encoded = keccak_256(DOMAIN_TYPE).to_vec();
+    encoded.extend_from_slice(&keccak_256(DOMAIN_NAME));
+    encoded.extend_from_slice(&keccak_256(DOMAIN_VERSION));
+    encoded.extend_from_slice(&salt);
+    keccak_256(&encoded)
+}
+
+pub fn order_struct_hash(
+    side_effects: &[Eip712SideEffect],
+    speed_mode: &SpeedMode,
+    nonce: u32,
+) -> [u8; 32] {
+    let side_effect_hashes: Vec<u8> = side_effects
+        .iter()
+        .flat_map(|side_effect| side_effect.struct_hash())
+        .collect();
+
+    let mut encoded = keccak_256(ORDER_TYPE).to_vec();
+    encoded.extend_from_slice(&keccak_256(&side_effect_hashes));
+    encoded.extend_from_slice(&uint_word(speed_mode_discriminant(speed_mode).into()));
+    encoded.extend_from_slice(&uint_word(nonce.into()));
+    keccak_256(&encoded)
+}
+
+/// Digest signed by `eth_signTypedData_v4` for the order under the domain salted with `salt`.
+pub fn typed_data_digest(
+    salt: [u8; 32],
+    side_effects: &[Eip712SideEffect],
+    speed_mode: &SpeedMode,
+    nonce: u32,
+) -> [u8; 32] {
+    let mut encoded = b"\x19\x01".to_vec();
+    encoded.extend_from_slice(&domain_separator(salt));
+    encoded.extend_from_slice(&order_struct_hash(side_effects, speed_mode, nonce));
+    keccak_256(&encoded)
+}
+
+fn uint_word(value: u128) -> [u8; 32] {
+    let mut word = [0u8; 32];
+    U256::from(value).to_big_endian(&mut word);
+    word
+}
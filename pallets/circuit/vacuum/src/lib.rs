@@ -7,6 +7,7 @@ use frame_support::{
 };
 
 use frame_system::pallet_prelude::*;
+pub mod eip712;
 pub mod weights;
 use crate::weights::WeightInfo;
 
@@ -26,20 +27,24 @@ pub mod benchmarking;
 pub type Asset = u32;
 pub type Destination = [u8; 4];
 pub type Input = Vec<u8>;
+/// ECDSA signature of an Ethereum account, in the `r ++ s ++ v` layout produced by wallets
+pub type EthSignature = [u8; 65];
 use frame_support::sp_runtime::Saturating;
 use scale_info::TypeInfo;
 use sp_core::{crypto::AccountId32, hexdisplay::AsBytesRef, H160, H256, U256};
 use t3rn_abi::{
     evm_ingress_logs::{
-        get_remote_bid_abi_descriptor, get_remote_order_abi_descriptor,
-        get_remote_transfer_commit_applied_abi_descriptor, RemoteEVMBidLog, RemoteEVMCommitLog,
-        RemoteEVMConfirmationLog, RemoteEVMOrderLog,
+        get_remote_bid_abi_descriptor, get_remote_bridge_lock_abi_descriptor,
+        get_remote_order_abi_descriptor, get_remote_transfer_commit_applied_abi_descriptor,
+        RemoteEVMBidLog, RemoteEVMBridgeLockLog, RemoteEVMCommitLog, RemoteEVMConfirmationLog,
+        RemoteEVMOrderLog,
     },
     recode::recode_bytes_with_descriptor,
     Codec,
 };
 use t3rn_primitives::circuit::{
-    AdaptiveTimeout, CircuitStatus, OrderOrigin, ReadSFX, SFXAction, SecurityLvl, SideEffect,
+    AdaptiveTimeout, CircuitStatus, OnXtxConcluded, OrderOrigin, ReadSFX, SFXAction, SecurityLvl,
+    SideEffect,
 };
 use t3rn_types::sfx::TargetId;
 t3rn_primitives::reexport_currency_types!();
@@ -55,6 +60,7 @@ pub struct OrderStatusRead<Hash, BlockNumber, Account> {
 
 #[derive(Debug, Clone, Eq, PartialEq, Encode, TypeInfo)]
 pub struct RemoteEVMOrderLocalized {
+    pub order_id: H256,
     pub from: H160,
     pub destination: TargetId,
     pub asset: u32,
@@ -85,6 +91,7 @@ impl RemoteEVMOrderLocalized {
             })?;
 
         let decoded_remote_order: RemoteEVMOrderLocalized = RemoteEVMOrderLocalized {
+            order_id: decoded_remote_order_log.sfx_id,
             from: decoded_remote_order_log.sender,
             destination: decoded_remote_order_log.destination,
             asset: T::Xdns::get_token_by_eth_address(
@@ -211,6 +218,91 @@ impl RemoteEVMBidLocalized {
     }
 }
 
+#[derive(Debug, Clone, Eq, PartialEq, Encode, TypeInfo)]
+pub struct RemoteEVMBridgeLockLocalized {
+    pub lock_id: H256,
+    pub asset: u32,
+    pub beneficiary: AccountId32,
+    pub amount: U256,
+}
+
+impl RemoteEVMBridgeLockLocalized {
+    pub fn rlp_to_remote_bridge_lock_log<T: Config>(
+        verified_event_bytes: Vec<u8>,
+        remote_target_id: TargetId,
+    ) -> Result<RemoteEVMBridgeLockLocalized, DispatchError> {
+        let decoded_remote_lock_log =
+            Self::rlp_to_remote_bridge_lock_log_raw(verified_event_bytes)?;
+
+        Ok(RemoteEVMBridgeLockLocalized {
+            lock_id: decoded_remote_lock_log.lock_id,
+            asset: T::Xdns::get_token_by_eth_address(
+                remote_target_id,
+                decoded_remote_lock_log.asset,
+            )?
+            .token_id,
+            beneficiary: decoded_remote_lock_log.beneficiary,
+            amount: decoded_remote_lock_log.amount,
+        })
+    }
+
+    /// Decode the lock log as emitted, leaving the remote asset address unresolved.
+    pub fn rlp_to_remote_bridge_lock_log_raw(
+        verified_event_bytes: Vec<u8>,
+    ) -> Result<RemoteEVMBridgeLockLog, DispatchError> {
+        let recoded_message = recode_bytes_with_descriptor(
+            verified_event_bytes,
+            get_remote_bridge_lock_abi_descriptor(),
+            Codec::Rlp,
+            Codec::Scale,
+        )?;
+
+        RemoteEVMBridgeLockLog::decode(&mut &recoded_message[..]).map_err(|_e| {
+            DispatchError::Other(
+                "RemoteEVMBridgeLockLocalized::bridge_in -- error decoding RemoteEVMBridgeLockLog",
+            )
+        })
+    }
+}
+
+/// A t3rn-side asset paired with the vault contract locking its counterpart on a remote gateway.
+#[derive(Debug, Clone, Eq, PartialEq, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub struct VaultRecord<Balance, BlockNumber> {
+    /// Gateway the vault contract is deployed on
+    pub gateway: TargetId,
+    /// Generalized address of the vault contract, the only accepted source of deposits
+    pub remote_vault: H256,
+    /// Token locked by the vault on the remote gateway
+    pub remote_token: H160,
+    /// Most that can be minted, and separately released, within a single period
+    pub rate_limit: Balance,
+    /// Length of the rate limit period in blocks
+    pub period: BlockNumber,
+    pub paused: bool,
+}
+
+/// Amounts moved through a vault within its current rate limit period.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub struct VaultFlow<Balance, BlockNumber> {
+    pub period_start: BlockNumber,
+    pub minted: Balance,
+    pub released: Balance,
+}
+
+/// Calldata of `release(bytes32,uint256,uint32)`, instructing a vault to release `amount` of its
+/// token to `beneficiary`. The nonce lets the vault refuse to release the same withdrawal twice.
+pub fn vault_release_input(beneficiary: ExecutionSource, amount: u128, nonce: u32) -> Vec<u8> {
+    let mut input = sp_core::keccak_256(b"release(bytes32,uint256,uint32)")[..4].to_vec();
+    input.extend_from_slice(&beneficiary);
+    let mut amount_word = [0u8; 32];
+    U256::from(amount).to_big_endian(&mut amount_word);
+    input.extend_from_slice(&amount_word);
+    let mut nonce_word = [0u8; 32];
+    nonce_word[28..].copy_from_slice(&nonce.to_be_bytes());
+    input.extend_from_slice(&nonce_word);
+    input
+}
+
 impl<AccountId, Balance> TryInto<SideEffect<AccountId, Balance>> for RemoteEVMOrderLocalized
 where
     u32: From<Asset>,
@@ -262,7 +354,7 @@ pub mod pallet {
         circuit::types::VacuumEVMOrder,
         threevm::VacuumAccess,
         xdns::{TokenRecord, Xdns},
-        TokenInfo,
+        TokenInfo, TreasuryAccount, TreasuryAccountProvider,
     };
 
     #[pallet::config]
@@ -293,6 +385,38 @@ pub mod pallet {
         OrderStatusRead(OrderStatusRead<T::Hash, BlockNumberFor<T>, T::AccountId>),
         FaultProofConfirmed([u8; 4], VacuumEVMProof),
         CorrectnessProofConfirmed([u8; 4], VacuumEVMProof),
+        /// Bridged asset minted to the beneficiary of a lock proven on the source gateway. [source_gateway, asset, beneficiary, amount, lock_id]
+        BridgedAssetMinted(TargetId, Asset, T::AccountId, BalanceOf<T>, H256),
+        /// Bridged asset burnt to be released on the destination gateway. [destination_gateway, asset, who, beneficiary, amount, nonce]
+        BridgedAssetBurnt(
+            TargetId,
+            Asset,
+            T::AccountId,
+            ExecutionSource,
+            BalanceOf<T>,
+            u32,
+        ),
+        /// Orders authorised by an Ethereum signature submitted for its derived account. [signer, requester, nonce]
+        EthSignedOrderSubmitted(H160, T::AccountId, u32),
+        /// Vault registered for the asset. [asset, gateway, remote_vault]
+        VaultRegistered(Asset, TargetId, H256),
+        /// Vault paused or resumed. [asset, paused]
+        VaultPauseSet(Asset, bool),
+        /// Asset minted against a deposit proven on its vault. [asset, beneficiary, amount, deposit_id]
+        VaultDepositMinted(Asset, T::AccountId, BalanceOf<T>, H256),
+        /// Asset escrowed and its vault ordered to release the counterpart. [asset, who, beneficiary, amount, nonce, xtx_id]
+        VaultWithdrawalOrdered(
+            Asset,
+            T::AccountId,
+            ExecutionSource,
+            BalanceOf<T>,
+            u32,
+            T::Hash,
+        ),
+        /// Escrowed withdrawal burnt once the Xtx releasing it finished. [xtx_id, asset, amount]
+        VaultWithdrawalBurnt(T::Hash, Asset, BalanceOf<T>),
+        /// Escrowed withdrawal returned once the Xtx releasing it was reverted. [xtx_id, asset, who, amount]
+        VaultWithdrawalRefunded(T::Hash, Asset, T::AccountId, BalanceOf<T>),
     }
 
     #[pallet::error]
@@ -300,8 +424,66 @@ pub mod pallet {
         // Define your errors here
         XdnsGatewayDoesNotHaveRemoteOrderAddressRegistered,
         XdnsGatewayDoesNotHaveEscrowAddressRegistered,
+        BridgeLockAlreadyProcessed,
+        RemoteOrderAlreadyProcessed,
+        RemoteOrderGatewayInactive,
+        BridgeAssetHasNoCorrespondence,
+        BridgeAssetNotMintable,
+        EthSignatureInvalid,
+        EthSignedOrderNonceMismatch,
+        VaultNotRegistered,
+        VaultPaused,
+        VaultRateLimitExceeded,
+        VaultDepositAlreadyProcessed,
+        VaultRemoteTokenMismatch,
+        RemoteAmountOverflow,
+        RemoteOrderRewardExceedsAmount,
     }
 
+    /// Orders created on the remote order contract of each gateway already ingested, by order id.
+    #[pallet::storage]
+    pub type ProcessedRemoteOrders<T: Config> =
+        StorageDoubleMap<_, Identity, TargetId, Identity, H256, BlockNumberFor<T>, OptionQuery>;
+
+    /// Locks on remote gateways already minted against, by source gateway and lock id.
+    #[pallet::storage]
+    pub type ProcessedBridgeLocks<T: Config> =
+        StorageDoubleMap<_, Identity, TargetId, Identity, H256, BlockNumberFor<T>, OptionQuery>;
+
+    /// Nonce of the next outbound bridge transfer.
+    #[pallet::storage]
+    pub type BridgeOutNonce<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    /// Nonce expected in the next order signed by each Ethereum address.
+    #[pallet::storage]
+    #[pallet::getter(fn eth_signer_nonce)]
+    pub type EthSignerNonces<T: Config> = StorageMap<_, Identity, H160, u32, ValueQuery>;
+
+    /// Vaults pairing local assets with the remote contracts locking their counterparts.
+    #[pallet::storage]
+    #[pallet::getter(fn vaults)]
+    pub type Vaults<T: Config> =
+        StorageMap<_, Identity, Asset, VaultRecord<BalanceOf<T>, BlockNumberFor<T>>, OptionQuery>;
+
+    /// Amounts minted and released per vault within the current rate limit period.
+    #[pallet::storage]
+    pub type VaultFlows<T: Config> =
+        StorageMap<_, Identity, Asset, VaultFlow<BalanceOf<T>, BlockNumberFor<T>>, ValueQuery>;
+
+    /// Vault deposits already minted against, by asset and deposit id.
+    #[pallet::storage]
+    pub type ProcessedVaultDeposits<T: Config> =
+        StorageDoubleMap<_, Identity, Asset, Identity, H256, BlockNumberFor<T>, OptionQuery>;
+
+    /// Nonce of the next withdrawal released by each vault.
+    #[pallet::storage]
+    pub type VaultWithdrawalNonce<T: Config> = StorageMap<_, Identity, Asset, u32, ValueQuery>;
+
+    /// Withdrawals held in escrow until the Xtx releasing them concludes, by Xtx id.
+    #[pallet::storage]
+    pub type PendingVaultWithdrawals<T: Config> =
+        StorageMap<_, Identity, T::Hash, (Asset, T::AccountId, BalanceOf<T>), OptionQuery>;
+
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
 
@@ -330,6 +512,65 @@ pub mod pallet {
             Ok(().into())
         }
 
+        /// Submit orders on behalf of an Ethereum account, authorised by its signature over the
+        /// orders and its next nonce rather than by a native key. The orders are requested by
+        /// the account derived from the signer's address, while the relaying origin only pays
+        /// the transaction fee.
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::eth_signed_order(sfx_actions.len() as u32))]
+        pub fn eth_signed_order(
+            origin: OriginFor<T>,
+            sfx_actions: Vec<
+                OrderSFX<T::AccountId, Asset, BalanceOf<T>, Destination, Input, BalanceOf<T>>,
+            >,
+            speed_mode: SpeedMode,
+            signer: H160,
+            nonce: u32,
+            signature: EthSignature,
+        ) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+
+            let digest = Self::eth_signed_order_digest(&sfx_actions, &speed_mode, nonce);
+            Self::submit_eth_signed_order(
+                signer,
+                nonce,
+                &signature,
+                &digest,
+                sfx_actions,
+                speed_mode,
+            )
+        }
+
+        /// Submit transfer orders signed by an Ethereum account as EIP-712 typed data, so that
+        /// its wallet displays each transfer before the signer authorises it. Nonces are shared
+        /// with `eth_signed_order`.
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::eth_signed_order(side_effects.len() as u32))]
+        pub fn eip712_order(
+            origin: OriginFor<T>,
+            side_effects: Vec<eip712::Eip712SideEffect>,
+            speed_mode: SpeedMode,
+            signer: H160,
+            nonce: u32,
+            signature: EthSignature,
+        ) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+
+            let digest =
+                eip712::typed_data_digest(Self::genesis_salt(), &side_effects, &speed_mode, nonce);
+            let sfx_actions = side_effects
+                .into_iter()
+                .map(Self::localize_eip712_side_effect)
+                .collect::<Result<Vec<_>, DispatchError>>()?;
+
+            Self::submit_eth_signed_order(
+                signer,
+                nonce,
+                &signature,
+                &digest,
+                sfx_actions,
+                speed_mode,
+            )
+        }
+
         #[pallet::weight(<T as pallet::Config>::WeightInfo::single_order())]
         pub fn dynamic_destination_deal(
             origin: OriginFor<T>,
@@ -427,6 +668,9 @@ pub mod pallet {
             Ok(().into())
         }
 
+        /// Ingest an order created on the remote order contract of `remote_target_id`, proven by
+        /// the inclusion of its event. Bridging orders mint locally, any other is materialized as
+        /// an Xtx requested by the remote origin. Each order is only ingested once.
         #[pallet::weight(<T as pallet::Config>::WeightInfo::single_order())]
         pub fn remote_order(
             origin: OriginFor<T>,
@@ -450,10 +694,22 @@ pub mod pallet {
                     remote_target_id,
                 )?;
 
-            assert!(T::Xdns::is_target_active(
-                remote_target_id.clone(),
-                &SecurityLvl::Optimistic
-            ));
+            ensure!(
+                !<ProcessedRemoteOrders<T>>::contains_key(
+                    remote_target_id,
+                    decoded_remote_order.order_id
+                ),
+                Error::<T>::RemoteOrderAlreadyProcessed
+            );
+            ensure!(
+                T::Xdns::is_target_active(remote_target_id, &SecurityLvl::Optimistic),
+                Error::<T>::RemoteOrderGatewayInactive
+            );
+            <ProcessedRemoteOrders<T>>::insert(
+                remote_target_id,
+                decoded_remote_order.order_id,
+                frame_system::Pallet::<T>::block_number(),
+            );
 
             let mut side_effect: SideEffect<T::AccountId, BalanceOf<T>> =
                 decoded_remote_order.clone().try_into()?;
@@ -478,39 +734,7 @@ pub mod pallet {
                             }
                     })
             {
-                // Mint wrapped assets on local chain.
-                let amount = BalanceOf::<T>::decode(
-                    &mut &decoded_remote_order.amount.as_u128().encode()[..],
-                )
-                .map_err(|_e| {
-                    DispatchError::Other("Vacuum::remote_order -- error decoding amount")
-                })?;
-                // In the context of minting assets, max reward is the net reward to executor.
-                let max_reward = BalanceOf::<T>::decode(
-                    &mut &decoded_remote_order.max_reward.as_u128().encode()[..],
-                )
-                .map_err(|_e| {
-                    DispatchError::Other("Vacuum::remote_order -- error decoding amount")
-                })?;
-
-                assert!(amount >= max_reward, "Vacuum::remote_order -- amount must be greater (rarely equal) than max_reward for minting assets");
-                let asset = decoded_remote_order.asset;
-                assert!(
-                    T::Xdns::check_asset_is_mintable([3, 3, 3, 3], asset),
-                    "Vacuum::remote_order -- asset is not mintable"
-                );
-
-                let target_account =
-                    T::AccountId::decode(&mut &decoded_remote_order.target_account.encode()[..])
-                        .map_err(|_e| {
-                            DispatchError::Other(
-                                "Vacuum::remote_order -- error decoding target_account",
-                            )
-                        })?;
-
-                // Mint wrapped assets on local chain.
-                T::Xdns::mint(asset, who, max_reward)?;
-                T::Xdns::mint(asset, target_account, max_reward.saturating_sub(amount))?;
+                Self::mint_bridge_order(who, &decoded_remote_order)?;
                 Ok(().into())
             } else {
                 // For remote order + remote reward, assume on_remote_origin_trigger
@@ -523,163 +747,707 @@ pub mod pallet {
             }
         }
 
-        #[pallet::weight(<T as pallet::Config>::WeightInfo::single_order())]
-        pub fn read_order_status(
-            _origin: OriginFor<T>,
-            xtx_id: T::Hash,
+        /// Mint the bridged asset to the beneficiary of a lock event emitted by the escrow contract
+        /// of `source_gateway`. The locked asset is resolved to its local counterpart via the XDNS
+        /// asset correspondence registry and each lock is only honoured once.
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::bridge_in().saturating_add(
+            T::CircuitSubmitAPI::verify_sfx_proof_weight(lock_proof.len() as u32)
+        ))]
+        pub fn bridge_in(
+            origin: OriginFor<T>,
+            lock_proof: Vec<u8>,
+            source_gateway: TargetId,
+            speed_mode: SpeedMode,
         ) -> DispatchResultWithPostInfo {
-            Self::emit_order_status(xtx_id)
+            ensure_signed(origin)?;
+
+            let source_escrow_address_generalized: ExecutionSource =
+                ExecutionSource::decode(&mut &T::Xdns::get_escrow_account(&source_gateway)?[..])
+                    .map_err(|_| Error::<T>::XdnsGatewayDoesNotHaveEscrowAddressRegistered)?;
+
+            let verified_lock_bytes = T::CircuitSubmitAPI::verify_sfx_proof(
+                source_gateway,
+                speed_mode,
+                Some(source_escrow_address_generalized),
+                lock_proof,
+            )?
+            .message;
+
+            let lock = RemoteEVMBridgeLockLocalized::rlp_to_remote_bridge_lock_log::<T>(
+                verified_lock_bytes,
+                source_gateway,
+            )?;
+            Self::mint_bridge_lock(source_gateway, lock)?;
+
+            Ok(().into())
         }
 
-        #[pallet::weight(100_000)]
-        pub fn read_all_pending_orders_status(_origin: OriginFor<T>) -> DispatchResultWithPostInfo {
-            for xtx_id in T::ReadSFX::get_pending_xtx_ids() {
-                Self::emit_order_status(xtx_id)?;
-            }
+        /// Burn the bridged asset so that its counterpart on `destination_gateway` can be released
+        /// to `beneficiary` once the emitted burn is relayed there.
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::bridge_out())]
+        pub fn bridge_out(
+            origin: OriginFor<T>,
+            asset: Asset,
+            destination_gateway: TargetId,
+            beneficiary: ExecutionSource,
+            amount: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            let self_gateway_id = <T as pallet_xdns::Config>::SelfGatewayId::get();
+            ensure!(
+                T::Xdns::check_asset_is_mintable(self_gateway_id, asset),
+                Error::<T>::BridgeAssetNotMintable
+            );
+            T::Xdns::get_corresponding_asset(&self_gateway_id, asset, &destination_gateway)
+                .ok_or(Error::<T>::BridgeAssetHasNoCorrespondence)?;
+
+            T::Xdns::burn(asset, who.clone(), amount)?;
+
+            let nonce = <BridgeOutNonce<T>>::mutate(|nonce| {
+                let current = *nonce;
+                *nonce = nonce.saturating_add(1);
+                current
+            });
+
+            Self::deposit_event(Event::BridgedAssetBurnt(
+                destination_gateway,
+                asset,
+                who,
+                beneficiary,
+                amount,
+                nonce,
+            ));
+
             Ok(().into())
         }
-    }
 
-    impl<T: Config> Pallet<T> {
-        pub fn emit_order_status(xtx_id: T::Hash) -> DispatchResultWithPostInfo {
-            let (status, timeouts_at) = T::ReadSFX::get_xtx_status(xtx_id)?;
-            let sfx_of_xtx = T::ReadSFX::get_fsx_of_xtx(xtx_id)?;
-            let all_included_sfx = sfx_of_xtx
-                .into_iter()
-                .map(|sfx| {
-                    let fsx_status = T::ReadSFX::get_fsx_status(sfx)?;
-                    let fsx_executor = T::ReadSFX::get_fsx_executor(sfx)?;
-                    Ok((sfx, fsx_status, fsx_executor))
-                })
-                .collect::<Result<Vec<(T::Hash, CircuitStatus, Option<T::AccountId>)>, DispatchError>>()?;
+        /// Pair a mintable local asset with the vault contract locking its counterpart on
+        /// `gateway`, replacing any previous vault of the asset. New vaults start unpaused.
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::register_vault())]
+        pub fn register_vault(
+            origin: OriginFor<T>,
+            asset: Asset,
+            gateway: TargetId,
+            remote_vault: H256,
+            remote_token: H160,
+            rate_limit: BalanceOf<T>,
+            period: BlockNumberFor<T>,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
 
-            Self::deposit_event(Event::OrderStatusRead(OrderStatusRead {
-                xtx_id,
-                status,
-                all_included_sfx,
-                timeouts_at,
-            }));
+            let self_gateway_id = <T as pallet_xdns::Config>::SelfGatewayId::get();
+            ensure!(
+                T::Xdns::check_asset_is_mintable(self_gateway_id, asset),
+                Error::<T>::BridgeAssetNotMintable
+            );
+
+            <Vaults<T>>::insert(
+                asset,
+                VaultRecord {
+                    gateway,
+                    remote_vault,
+                    remote_token,
+                    rate_limit,
+                    period,
+                    paused: false,
+                },
+            );
+            <VaultFlows<T>>::remove(asset);
+
+            Self::deposit_event(Event::VaultRegistered(asset, gateway, remote_vault));
 
             Ok(().into())
         }
-    }
 
-    impl<T: Config> VacuumAccess<T> for Pallet<T> {
-        fn evm_order(
-            origin: &T::RuntimeOrigin,
-            vacuum_evm_order: VacuumEVMOrder,
-        ) -> Result<bool, DispatchError> {
-            let amount_localized =
-                BalanceOf::<T>::decode(&mut &vacuum_evm_order.amount.as_u128().encode()[..])
-                    .map_err(|_e| {
-                        DispatchError::Other("Vacuum::remote_order -- error decoding amount")
-                    })?;
-            let max_reward_localized =
-                BalanceOf::<T>::decode(&mut &vacuum_evm_order.max_reward.as_u128().encode()[..])
-                    .map_err(|_e| {
-                        DispatchError::Other("Vacuum::remote_order -- error decoding max_reward")
-                    })?;
-            let insurance_localized =
-                BalanceOf::<T>::decode(&mut &vacuum_evm_order.insurance.as_u128().encode()[..])
-                    .map_err(|_e| {
-                        DispatchError::Other("Vacuum::remote_order -- error decoding insurance")
-                    })?;
-
-            let reward_asset_localized = T::Xdns::get_token_by_eth_address(
-                vacuum_evm_order.destination,
-                vacuum_evm_order.reward_asset,
-            )?
-            .token_id;
+        /// Pause or resume both deposits and withdrawals of the asset's vault.
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::set_vault_paused())]
+        pub fn set_vault_paused(
+            origin: OriginFor<T>,
+            asset: Asset,
+            paused: bool,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
 
-            let target_account = T::AccountId::decode(
-                &mut &vacuum_evm_order.target_account.encode()[..],
-            )
-            .map_err(|_e| {
-                DispatchError::Other("Vacuum::remote_order -- error decoding target_account")
+            <Vaults<T>>::try_mutate(asset, |maybe_vault| {
+                let vault = maybe_vault.as_mut().ok_or(Error::<T>::VaultNotRegistered)?;
+                vault.paused = paused;
+                Ok::<(), Error<T>>(())
             })?;
 
-            Pallet::<T>::single_order(
-                origin.clone(),
-                vacuum_evm_order.destination,
-                vacuum_evm_order.asset,
-                amount_localized,
-                reward_asset_localized,
-                max_reward_localized,
-                insurance_localized,
-                target_account,
-                SpeedMode::Fast,
-            )
-            .map_err(|e| {
-                log::error!("Vacuum::evm_order -- error calling single_order: {:?}", e);
-                DispatchError::Other("Vacuum::evm_order -- error calling single_order")
-            })?;
+            Self::deposit_event(Event::VaultPauseSet(asset, paused));
 
-            Ok(true)
+            Ok(().into())
         }
 
-        fn evm_confirm(
-            origin: &T::RuntimeOrigin,
-            vacuum_evm_order: VacuumEVMOrder,
-        ) -> Result<bool, DispatchError> {
-            Ok(true)
+        /// Mint the asset to the beneficiary of a deposit event emitted by its vault contract.
+        /// Each deposit is only honoured once and counts towards the vault's rate limit.
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::vault_deposit().saturating_add(
+            T::CircuitSubmitAPI::verify_sfx_proof_weight(deposit_proof.len() as u32)
+        ))]
+        pub fn vault_deposit(
+            origin: OriginFor<T>,
+            asset: Asset,
+            deposit_proof: Vec<u8>,
+            speed_mode: SpeedMode,
+        ) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+
+            let vault = <Vaults<T>>::get(asset).ok_or(Error::<T>::VaultNotRegistered)?;
+            ensure!(!vault.paused, Error::<T>::VaultPaused);
+
+            let verified_deposit_bytes = T::CircuitSubmitAPI::verify_sfx_proof(
+                vault.gateway,
+                speed_mode,
+                Some(vault.remote_vault.0),
+                deposit_proof,
+            )?
+            .message;
+
+            let deposit = RemoteEVMBridgeLockLocalized::rlp_to_remote_bridge_lock_log_raw(
+                verified_deposit_bytes,
+            )?;
+            Self::mint_vault_deposit(asset, &vault, deposit)?;
+
+            Ok(().into())
         }
 
-        fn evm_teleport_order(
-            origin: &T::RuntimeOrigin,
-            vacuum_evm_order: VacuumEVMTeleportOrder,
-        ) -> Result<bool, DispatchError> {
+        /// Escrow the asset and order its vault contract to release the counterpart to
+        /// `beneficiary`, as a call side effect rewarded with `max_reward` of `reward_asset`.
+        /// The escrowed asset is burnt once the release finishes and returned if it's reverted.
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::vault_withdraw())]
+        pub fn vault_withdraw(
+            origin: OriginFor<T>,
+            asset: Asset,
+            beneficiary: ExecutionSource,
+            amount: BalanceOf<T>,
+            reward_asset: Asset,
+            max_reward: BalanceOf<T>,
+            speed_mode: SpeedMode,
+        ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin.clone())?;
 
-            let gateway_id = vacuum_evm_order.gateway_id.clone();
+            let vault = <Vaults<T>>::get(asset).ok_or(Error::<T>::VaultNotRegistered)?;
+            ensure!(!vault.paused, Error::<T>::VaultPaused);
 
-            let verified_order_bytes_inclusion_receipt = T::CircuitSubmitAPI::verify_sfx_proof(
-                gateway_id,
-                SpeedMode::Finalized,
-                None,
-                vacuum_evm_order.order_proof.clone(),
-            )?;
+            Self::record_vault_flow(asset, &vault, amount, false)?;
 
-            let decoded_remote_order_log =
-                RemoteEVMOrderLog::decode(&mut &verified_order_bytes_inclusion_receipt.message[..])
-                    .map_err(|e| {
-                        log::error!(
-                            "Vecuum::remote_order -- error decoding RemoteEVMOrderLog: {:?}",
-                            e
-                        );
-                        DispatchError::Other(
-                            "Vecuum::remote_order -- error decoding RemoteEVMOrderLog",
-                        )
-                    })?;
+            T::Xdns::burn(asset, who.clone(), amount)?;
+            T::Xdns::mint(asset, Self::escrow_account(), amount)?;
 
-            let decoded_remote_order: RemoteEVMOrderLocalized = RemoteEVMOrderLocalized {
-                from: decoded_remote_order_log.sender,
-                destination: decoded_remote_order_log.destination,
-                asset: T::Xdns::get_token_by_eth_address(
-                    gateway_id,
-                    decoded_remote_order_log.reward_asset,
-                )?
-                .token_id,
-                target_account: decoded_remote_order_log.target_account,
-                reward_asset: decoded_remote_order_log.reward_asset,
-                amount: decoded_remote_order_log.amount,
-                insurance: decoded_remote_order_log.insurance,
-                max_reward: decoded_remote_order_log.max_reward,
-                nonce: decoded_remote_order_log.nonce,
-            };
+            let nonce = <VaultWithdrawalNonce<T>>::mutate(asset, |nonce| {
+                let current = *nonce;
+                *nonce = nonce.saturating_add(1);
+                current
+            });
 
-            let side_effect: SideEffect<T::AccountId, BalanceOf<T>> =
-                decoded_remote_order.clone().try_into()?;
-            let remote_origin: OrderOrigin<T::AccountId> =
-                OrderOrigin::from_remote_nonce(decoded_remote_order.nonce);
+            let amount_u128 = u128::decode(&mut &amount.encode()[..]).map_err(|_e| {
+                DispatchError::Other("Vacuum::vault_withdraw -- error encoding amount")
+            })?;
+            let remote_vault = T::AccountId::decode(&mut &vault.remote_vault.encode()[..])
+                .map_err(|_e| {
+                    DispatchError::Other("Vacuum::vault_withdraw -- error decoding remote_vault")
+                })?;
 
-            T::CircuitSubmitAPI::on_remote_origin_trigger(
-                origin.clone(),
-                remote_origin.to_account_id(),
-                vec![side_effect],
-                SpeedMode::Fast,
-            )
-            .map_err(|e| {
-                log::error!(
+            let release_order =
+                OrderSFX::<T::AccountId, Asset, BalanceOf<T>, TargetId, Vec<u8>, BalanceOf<T>> {
+                    sfx_action: SFXAction::Call(
+                        vault.gateway,
+                        remote_vault,
+                        Zero::zero(),
+                        max_reward,
+                        vault_release_input(beneficiary, amount_u128, nonce),
+                    ),
+                    max_reward,
+                    insurance: <T as Config>::Currency::minimum_balance(), // will be auto-adjusted to current Finality Fee
+                    reward_asset,
+                    remote_origin_nonce: None,
+                };
+            let side_effect: SideEffect<T::AccountId, BalanceOf<T>> = release_order.try_into()?;
+
+            T::CircuitSubmitAPI::on_extrinsic_trigger(
+                origin,
+                sp_std::vec![side_effect],
+                speed_mode,
+                SecurityLvl::Optimistic,
+            )?;
+            let xtx_id = T::ReadSFX::recover_latest_submitted_xtx_id()?;
+            <PendingVaultWithdrawals<T>>::insert(xtx_id, (asset, who.clone(), amount));
+
+            Self::deposit_event(Event::VaultWithdrawalOrdered(
+                asset,
+                who,
+                beneficiary,
+                amount,
+                nonce,
+                xtx_id,
+            ));
+
+            Ok(().into())
+        }
+
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::single_order())]
+        pub fn read_order_status(
+            _origin: OriginFor<T>,
+            xtx_id: T::Hash,
+        ) -> DispatchResultWithPostInfo {
+            Self::emit_order_status(xtx_id)
+        }
+
+        #[pallet::weight(100_000)]
+        pub fn read_all_pending_orders_status(_origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+            for xtx_id in T::ReadSFX::get_pending_xtx_ids() {
+                Self::emit_order_status(xtx_id)?;
+            }
+            Ok(().into())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Digest an Ethereum account signs with `personal_sign` to authorise orders: the
+        /// keccak hash of the SCALE-encoded orders, speed mode and nonce, bound to this chain
+        /// by its genesis hash.
+        pub fn eth_signed_order_digest(
+            sfx_actions: &[OrderSFX<
+                T::AccountId,
+                Asset,
+                BalanceOf<T>,
+                Destination,
+                Input,
+                BalanceOf<T>,
+            >],
+            speed_mode: &SpeedMode,
+            nonce: u32,
+        ) -> [u8; 32] {
+            let payload_hash = sp_io::hashing::keccak_256(
+                &(Self::genesis_salt(), sfx_actions, speed_mode, nonce).encode(),
+            );
+
+            let mut prefixed = b"\x19Ethereum Signed Message:\n32".to_vec();
+            prefixed.extend_from_slice(&payload_hash);
+            sp_io::hashing::keccak_256(&prefixed)
+        }
+
+        /// Genesis hash binding signed orders to this chain.
+        pub fn genesis_salt() -> [u8; 32] {
+            let mut salt = [0u8; 32];
+            salt.copy_from_slice(
+                frame_system::Pallet::<T>::block_hash(BlockNumberFor::<T>::zero()).as_ref(),
+            );
+            salt
+        }
+
+        fn localize_eip712_side_effect(
+            side_effect: eip712::Eip712SideEffect,
+        ) -> Result<
+            OrderSFX<T::AccountId, Asset, BalanceOf<T>, Destination, Input, BalanceOf<T>>,
+            DispatchError,
+        > {
+            let localize_balance = |amount: u128| {
+                BalanceOf::<T>::decode(&mut &amount.encode()[..]).map_err(|_e| {
+                    DispatchError::Other("Vacuum::eip712_order -- error decoding balance")
+                })
+            };
+            let target = T::AccountId::decode(&mut &side_effect.target[..]).map_err(|_e| {
+                DispatchError::Other("Vacuum::eip712_order -- error decoding target")
+            })?;
+
+            Ok(OrderSFX {
+                sfx_action: SFXAction::Transfer(
+                    side_effect.destination,
+                    side_effect.asset,
+                    target,
+                    localize_balance(side_effect.amount)?,
+                ),
+                max_reward: localize_balance(side_effect.max_reward)?,
+                insurance: localize_balance(side_effect.insurance)?,
+                reward_asset: side_effect.reward_asset,
+                remote_origin_nonce: None,
+            })
+        }
+
+        /// Check the signer authorised `digest` with its next nonce, then submit the orders as
+        /// requested by the account derived from its address.
+        fn submit_eth_signed_order(
+            signer: H160,
+            nonce: u32,
+            signature: &EthSignature,
+            digest: &[u8; 32],
+            sfx_actions: Vec<
+                OrderSFX<T::AccountId, Asset, BalanceOf<T>, Destination, Input, BalanceOf<T>>,
+            >,
+            speed_mode: SpeedMode,
+        ) -> DispatchResultWithPostInfo {
+            ensure!(
+                Self::recover_eth_signer(signature, digest) == Some(signer),
+                Error::<T>::EthSignatureInvalid
+            );
+            <EthSignerNonces<T>>::try_mutate(signer, |expected_nonce| {
+                ensure!(
+                    *expected_nonce == nonce,
+                    Error::<T>::EthSignedOrderNonceMismatch
+                );
+                *expected_nonce = expected_nonce.saturating_add(1);
+                Ok::<(), Error<T>>(())
+            })?;
+
+            let requester = T::AddressMapping::into_account_id(&signer);
+            let side_effects: Vec<SideEffect<T::AccountId, BalanceOf<T>>> = sfx_actions
+                .into_iter()
+                .map(|sfx_action| sfx_action.try_into())
+                .collect::<Result<Vec<SideEffect<T::AccountId, BalanceOf<T>>>, DispatchError>>()?;
+
+            T::CircuitSubmitAPI::on_extrinsic_trigger(
+                frame_system::RawOrigin::Signed(requester.clone()).into(),
+                side_effects,
+                speed_mode,
+                SecurityLvl::Optimistic,
+            )?;
+
+            Self::deposit_event(Event::EthSignedOrderSubmitted(signer, requester, nonce));
+
+            Ok(().into())
+        }
+
+        /// Address of the Ethereum account whose signature over `digest` this is, if any.
+        pub fn recover_eth_signer(signature: &EthSignature, digest: &[u8; 32]) -> Option<H160> {
+            let public_key = sp_io::crypto::secp256k1_ecdsa_recover(signature, digest).ok()?;
+            Some(H160::from_slice(
+                &sp_io::hashing::keccak_256(&public_key)[12..],
+            ))
+        }
+
+        /// Mint the asset to the beneficiary of a deposit proven on its vault, unless the
+        /// deposit was already minted against.
+        pub(crate) fn mint_vault_deposit(
+            asset: Asset,
+            vault: &VaultRecord<BalanceOf<T>, BlockNumberFor<T>>,
+            deposit: RemoteEVMBridgeLockLog,
+        ) -> DispatchResult {
+            ensure!(
+                deposit.asset == vault.remote_token,
+                Error::<T>::VaultRemoteTokenMismatch
+            );
+            ensure!(
+                !<ProcessedVaultDeposits<T>>::contains_key(asset, deposit.lock_id),
+                Error::<T>::VaultDepositAlreadyProcessed
+            );
+
+            let amount = Self::localize_remote_amount(deposit.amount)?;
+            let beneficiary = T::AccountId::decode(&mut &deposit.beneficiary.encode()[..])
+                .map_err(|_e| {
+                    DispatchError::Other("Vacuum::vault_deposit -- error decoding beneficiary")
+                })?;
+
+            Self::record_vault_flow(asset, vault, amount, true)?;
+
+            T::Xdns::mint(asset, beneficiary.clone(), amount)?;
+            <ProcessedVaultDeposits<T>>::insert(
+                asset,
+                deposit.lock_id,
+                frame_system::Pallet::<T>::block_number(),
+            );
+
+            Self::deposit_event(Event::VaultDepositMinted(
+                asset,
+                beneficiary,
+                amount,
+                deposit.lock_id,
+            ));
+
+            Ok(())
+        }
+
+        /// Mint the local counterpart of a lock proven on `source_gateway` to its beneficiary,
+        /// unless the lock was already minted against.
+        pub(crate) fn mint_bridge_lock(
+            source_gateway: TargetId,
+            lock: RemoteEVMBridgeLockLocalized,
+        ) -> DispatchResult {
+            ensure!(
+                !<ProcessedBridgeLocks<T>>::contains_key(source_gateway, lock.lock_id),
+                Error::<T>::BridgeLockAlreadyProcessed
+            );
+
+            let self_gateway_id = <T as pallet_xdns::Config>::SelfGatewayId::get();
+            let asset =
+                T::Xdns::get_corresponding_asset(&source_gateway, lock.asset, &self_gateway_id)
+                    .ok_or(Error::<T>::BridgeAssetHasNoCorrespondence)?;
+            ensure!(
+                T::Xdns::check_asset_is_mintable(self_gateway_id, asset),
+                Error::<T>::BridgeAssetNotMintable
+            );
+
+            let amount = Self::localize_remote_amount(lock.amount)?;
+            let beneficiary =
+                T::AccountId::decode(&mut &lock.beneficiary.encode()[..]).map_err(|_e| {
+                    DispatchError::Other("Vacuum::bridge_in -- error decoding beneficiary")
+                })?;
+
+            T::Xdns::mint(asset, beneficiary.clone(), amount)?;
+            <ProcessedBridgeLocks<T>>::insert(
+                source_gateway,
+                lock.lock_id,
+                frame_system::Pallet::<T>::block_number(),
+            );
+
+            Self::deposit_event(Event::BridgedAssetMinted(
+                source_gateway,
+                asset,
+                beneficiary,
+                amount,
+                lock.lock_id,
+            ));
+
+            Ok(())
+        }
+
+        /// Mint the wrapped asset of a bridging order: the max reward to the executor as its net
+        /// reward, and the rest of the bridged amount to the target account.
+        pub(crate) fn mint_bridge_order(
+            executor: T::AccountId,
+            order: &RemoteEVMOrderLocalized,
+        ) -> DispatchResult {
+            let amount = Self::localize_remote_amount(order.amount)?;
+            let max_reward = Self::localize_remote_amount(order.max_reward)?;
+            ensure!(
+                amount >= max_reward,
+                Error::<T>::RemoteOrderRewardExceedsAmount
+            );
+            ensure!(
+                T::Xdns::check_asset_is_mintable([3, 3, 3, 3], order.asset),
+                Error::<T>::BridgeAssetNotMintable
+            );
+
+            let target_account = T::AccountId::decode(&mut &order.target_account.encode()[..])
+                .map_err(|_e| {
+                    DispatchError::Other("Vacuum::remote_order -- error decoding target_account")
+                })?;
+
+            T::Xdns::mint(order.asset, executor, max_reward)?;
+            T::Xdns::mint(
+                order.asset,
+                target_account,
+                amount.saturating_sub(max_reward),
+            )
+        }
+
+        /// Amount carried by a remote log in the local balance, refusing amounts it can't hold.
+        fn localize_remote_amount(amount: U256) -> Result<BalanceOf<T>, Error<T>> {
+            let amount: u128 = amount
+                .try_into()
+                .map_err(|_| Error::<T>::RemoteAmountOverflow)?;
+            BalanceOf::<T>::try_from(amount).map_err(|_| Error::<T>::RemoteAmountOverflow)
+        }
+
+        /// Account holding withdrawals in escrow while their release is pending.
+        fn escrow_account() -> T::AccountId {
+            <T as pallet_xdns::Config>::TreasuryAccounts::get_treasury_account(
+                TreasuryAccount::Escrow,
+            )
+        }
+
+        /// Burn the escrowed withdrawal of a finished release, or return it to its owner if
+        /// the release was reverted.
+        pub(crate) fn settle_vault_withdrawal(
+            xtx_id: T::Hash,
+            status: &CircuitStatus,
+        ) -> Result<(), DispatchError> {
+            let refund = match status {
+                CircuitStatus::FinishedAllSteps | CircuitStatus::Committed => false,
+                CircuitStatus::Reverted(_) | CircuitStatus::Killed(_) => true,
+                _ => return Ok(()),
+            };
+            let (asset, who, amount) = match <PendingVaultWithdrawals<T>>::take(xtx_id) {
+                Some(withdrawal) => withdrawal,
+                None => return Ok(()),
+            };
+
+            T::Xdns::burn(asset, Self::escrow_account(), amount)?;
+            if refund {
+                T::Xdns::mint(asset, who.clone(), amount)?;
+                Self::deposit_event(Event::VaultWithdrawalRefunded(xtx_id, asset, who, amount));
+            } else {
+                Self::deposit_event(Event::VaultWithdrawalBurnt(xtx_id, asset, amount));
+            }
+
+            Ok(())
+        }
+
+        /// Count `amount` towards the vault's minted or released total of the current period,
+        /// starting a fresh period once the previous one has elapsed.
+        fn record_vault_flow(
+            asset: Asset,
+            vault: &VaultRecord<BalanceOf<T>, BlockNumberFor<T>>,
+            amount: BalanceOf<T>,
+            minted: bool,
+        ) -> Result<(), Error<T>> {
+            let now = frame_system::Pallet::<T>::block_number();
+
+            <VaultFlows<T>>::try_mutate(asset, |flow| {
+                if now >= flow.period_start.saturating_add(vault.period) {
+                    *flow = VaultFlow {
+                        period_start: now,
+                        minted: Zero::zero(),
+                        released: Zero::zero(),
+                    };
+                }
+
+                let moved = if minted {
+                    &mut flow.minted
+                } else {
+                    &mut flow.released
+                };
+                let total = moved.saturating_add(amount);
+                ensure!(
+                    total <= vault.rate_limit,
+                    Error::<T>::VaultRateLimitExceeded
+                );
+                *moved = total;
+                Ok(())
+            })
+        }
+
+        pub fn emit_order_status(xtx_id: T::Hash) -> DispatchResultWithPostInfo {
+            let (status, timeouts_at) = T::ReadSFX::get_xtx_status(xtx_id)?;
+            let sfx_of_xtx = T::ReadSFX::get_fsx_of_xtx(xtx_id)?;
+            let all_included_sfx = sfx_of_xtx
+                .into_iter()
+                .map(|sfx| {
+                    let fsx_status = T::ReadSFX::get_fsx_status(sfx)?;
+                    let fsx_executor = T::ReadSFX::get_fsx_executor(sfx)?;
+                    Ok((sfx, fsx_status, fsx_executor))
+                })
+                .collect::<Result<Vec<(T::Hash, CircuitStatus, Option<T::AccountId>)>, DispatchError>>()?;
+
+            Self::deposit_event(Event::OrderStatusRead(OrderStatusRead {
+                xtx_id,
+                status,
+                all_included_sfx,
+                timeouts_at,
+            }));
+
+            Ok(().into())
+        }
+    }
+
+    impl<T: Config> VacuumAccess<T> for Pallet<T> {
+        fn evm_order(
+            origin: &T::RuntimeOrigin,
+            vacuum_evm_order: VacuumEVMOrder,
+        ) -> Result<bool, DispatchError> {
+            let amount_localized =
+                BalanceOf::<T>::decode(&mut &vacuum_evm_order.amount.as_u128().encode()[..])
+                    .map_err(|_e| {
+                        DispatchError::Other("Vacuum::remote_order -- error decoding amount")
+                    })?;
+            let max_reward_localized =
+                BalanceOf::<T>::decode(&mut &vacuum_evm_order.max_reward.as_u128().encode()[..])
+                    .map_err(|_e| {
+                        DispatchError::Other("Vacuum::remote_order -- error decoding max_reward")
+                    })?;
+            let insurance_localized =
+                BalanceOf::<T>::decode(&mut &vacuum_evm_order.insurance.as_u128().encode()[..])
+                    .map_err(|_e| {
+                        DispatchError::Other("Vacuum::remote_order -- error decoding insurance")
+                    })?;
+
+            let reward_asset_localized = T::Xdns::get_token_by_eth_address(
+                vacuum_evm_order.destination,
+                vacuum_evm_order.reward_asset,
+            )?
+            .token_id;
+
+            let target_account = T::AccountId::decode(
+                &mut &vacuum_evm_order.target_account.encode()[..],
+            )
+            .map_err(|_e| {
+                DispatchError::Other("Vacuum::remote_order -- error decoding target_account")
+            })?;
+
+            Pallet::<T>::single_order(
+                origin.clone(),
+                vacuum_evm_order.destination,
+                vacuum_evm_order.asset,
+                amount_localized,
+                reward_asset_localized,
+                max_reward_localized,
+                insurance_localized,
+                target_account,
+                SpeedMode::Fast,
+            )
+            .map_err(|e| {
+                log::error!("Vacuum::evm_order -- error calling single_order: {:?}", e);
+                DispatchError::Other("Vacuum::evm_order -- error calling single_order")
+            })?;
+
+            Ok(true)
+        }
+
+        fn evm_confirm(
+            origin: &T::RuntimeOrigin,
+            vacuum_evm_order: VacuumEVMOrder,
+        ) -> Result<bool, DispatchError> {
+            Ok(true)
+        }
+
+        fn evm_teleport_order(
+            origin: &T::RuntimeOrigin,
+            vacuum_evm_order: VacuumEVMTeleportOrder,
+        ) -> Result<bool, DispatchError> {
+            let who = ensure_signed(origin.clone())?;
+
+            let gateway_id = vacuum_evm_order.gateway_id.clone();
+
+            let verified_order_bytes_inclusion_receipt = T::CircuitSubmitAPI::verify_sfx_proof(
+                gateway_id,
+                SpeedMode::Finalized,
+                None,
+                vacuum_evm_order.order_proof.clone(),
+            )?;
+
+            let decoded_remote_order_log =
+                RemoteEVMOrderLog::decode(&mut &verified_order_bytes_inclusion_receipt.message[..])
+                    .map_err(|e| {
+                        log::error!(
+                            "Vecuum::remote_order -- error decoding RemoteEVMOrderLog: {:?}",
+                            e
+                        );
+                        DispatchError::Other(
+                            "Vecuum::remote_order -- error decoding RemoteEVMOrderLog",
+                        )
+                    })?;
+
+            let decoded_remote_order: RemoteEVMOrderLocalized = RemoteEVMOrderLocalized {
+                order_id: decoded_remote_order_log.sfx_id,
+                from: decoded_remote_order_log.sender,
+                destination: decoded_remote_order_log.destination,
+                asset: T::Xdns::get_token_by_eth_address(
+                    gateway_id,
+                    decoded_remote_order_log.reward_asset,
+                )?
+                .token_id,
+                target_account: decoded_remote_order_log.target_account,
+                reward_asset: decoded_remote_order_log.reward_asset,
+                amount: decoded_remote_order_log.amount,
+                insurance: decoded_remote_order_log.insurance,
+                max_reward: decoded_remote_order_log.max_reward,
+                nonce: decoded_remote_order_log.nonce,
+            };
+
+            let side_effect: SideEffect<T::AccountId, BalanceOf<T>> =
+                decoded_remote_order.clone().try_into()?;
+            let remote_origin: OrderOrigin<T::AccountId> =
+                OrderOrigin::from_remote_nonce(decoded_remote_order.nonce);
+
+            T::CircuitSubmitAPI::on_remote_origin_trigger(
+                origin.clone(),
+                remote_origin.to_account_id(),
+                vec![side_effect],
+                SpeedMode::Fast,
+            )
+            .map_err(|e| {
+                log::error!(
                     "Vacuum::evm_teleport_order -- error calling on_remote_origin_trigger: {:?}",
                     e
                 );
@@ -849,6 +1617,9 @@ pub mod pallet {
                 submission_target_height: Zero::zero(),
                 best_bid: None,
                 index: 0,
+                deadline: None,
+                dependencies: vec![],
+                metadata: vec![],
             };
 
             // Submit fault proof to rewards
@@ -914,37 +1685,52 @@ pub mod pallet {
     }
 }
 
+impl<T: Config> OnXtxConcluded<T::Hash> for Pallet<T> {
+    fn on_xtx_concluded(xtx_id: T::Hash, status: &CircuitStatus) {
+        if let Err(e) = Pallet::<T>::settle_vault_withdrawal(xtx_id, status) {
+            log::error!(
+                "Vacuum::on_xtx_concluded -- error settling vault withdrawal of {:?}: {:?}",
+                xtx_id,
+                e
+            );
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use crate::eip712::{self, Eip712SideEffect};
     use codec::Encode;
 
     use circuit_runtime_types::UNIT;
 
     use frame_support::{assert_err, assert_ok, traits::Hooks};
     use hex_literal::hex;
-    use sp_core::H256;
+    use sp_core::{ecdsa, Pair, H160, H256, U256};
     use sp_runtime::{traits::Keccak256, AccountId32};
     use sp_std::convert::TryInto;
     pub use t3rn_mini_mock_runtime::{
         activate_all_light_clients, hotswap_latest_receipt_header_root,
-        initialize_eth2_with_3rd_epoch, prepare_ext_builder_playground, AccountId, AssetId, Assets,
-        Balance, Balances, BlockNumber, Circuit, CircuitError, CircuitEvent, Clock, ConfigVacuum,
-        EthereumEventInclusionProof, GlobalOnInitQueues, Hash, MiniRuntime, MockedAssetEvent,
-        OrderStatusRead, Portal, Rewards, RuntimeEvent as Event, RuntimeOrigin, System, Vacuum,
-        VacuumEvent, ASSET_ASTAR, ASSET_DOT, ASSET_ETH, ASSET_USDT, ASTAR_TARGET, ETHEREUM_TARGET,
-        POLKADOT_TARGET, XDNS,
+        initialize_eth2_with_3rd_epoch, prepare_ext_builder_playground, AccountId,
+        AddressMappingMock, AssetId, Assets, Balance, Balances, BlockNumber, Circuit, CircuitError,
+        CircuitEvent, Clock, ConfigVacuum, EthereumEventInclusionProof, GlobalOnInitQueues, Hash,
+        MiniRuntime, MockedAssetEvent, OrderStatusRead, Portal, Rewards, RuntimeEvent as Event,
+        RuntimeOrigin, System, Vacuum, VacuumEvent, ASSET_ASTAR, ASSET_DOT, ASSET_ETH, ASSET_USDT,
+        ASTAR_TARGET, ETHEREUM_TARGET, POLKADOT_TARGET, XDNS,
     };
 
     use t3rn_primitives::{
         circuit::{
             types::{OrderSFX, SFXAction},
-            AdaptiveTimeout, CircuitStatus, CircuitSubmitAPI, OrderOrigin,
+            AdaptiveTimeout, Cause, CircuitStatus, CircuitSubmitAPI, OnXtxConcluded, OrderOrigin,
         },
         claimable::CircuitRole,
         clock::OnHookQueues,
         light_client::LightClientAsyncAPI,
         monetary::MOCK_EXISTENTIAL_DEPOSIT as EXISTENTIAL_DEPOSIT,
         portal::Portal as PortalT,
+        threevm::AddressMapping,
+        xdns::Xdns as XdnsT,
         EthereumToken, ExecutionSource, GatewayVendor, SpeedMode, TokenInfo, TreasuryAccount,
         TreasuryAccountProvider,
     };
@@ -2241,6 +3027,17 @@ mod tests {
                     "ad3228b676f7d3cd4284a5443f17f1962b36e491b30a40b2405849e597ba5fb5"
                 ))
             );
+
+            // Relaying the same order again doesn't materialize another Xtx
+            assert_err!(
+                Vacuum::remote_order(
+                    RuntimeOrigin::signed(executor.clone()),
+                    rlp_encoded_remote_order_local_reward_event.encode(),
+                    ETHEREUM_TARGET,
+                    SpeedMode::Fast,
+                ),
+                crate::Error::<MiniRuntime>::RemoteOrderAlreadyProcessed
+            );
         });
     }
 
@@ -2327,6 +3124,549 @@ mod tests {
         });
     }
 
+    #[test]
+    fn bridging_orders_mint_the_amount_net_of_max_reward_to_the_target_account() {
+        let mut ext = prepare_ext_builder_playground();
+        ext.execute_with(|| {
+            assert_ok!(XDNS::enroll_bridge_asset(
+                RuntimeOrigin::root(),
+                1u32,
+                [3, 3, 3, 3],
+                TokenInfo::Ethereum(EthereumToken {
+                    decimals: 18,
+                    symbol: b"sepl".to_vec(),
+                    address: Some([0; 20])
+                })
+            ));
+
+            let executor = AccountId32::from([1u8; 32]);
+            let target_account = AccountId32::from([4u8; 32]);
+            let order = crate::RemoteEVMOrderLocalized {
+                order_id: H256::repeat_byte(9),
+                from: H160::repeat_byte(2),
+                destination: [3, 3, 3, 3],
+                asset: 1u32,
+                target_account: target_account.clone(),
+                reward_asset: H160::zero(),
+                amount: 1000.into(),
+                insurance: 10.into(),
+                max_reward: 100.into(),
+                nonce: 0,
+            };
+
+            assert_ok!(Vacuum::mint_bridge_order(executor.clone(), &order));
+            assert_eq!(Assets::balance(1u32, &executor), 100);
+            assert_eq!(Assets::balance(1u32, &target_account), 900);
+
+            assert_err!(
+                Vacuum::mint_bridge_order(
+                    executor.clone(),
+                    &crate::RemoteEVMOrderLocalized {
+                        max_reward: 1001.into(),
+                        ..order.clone()
+                    }
+                ),
+                crate::Error::<MiniRuntime>::RemoteOrderRewardExceedsAmount
+            );
+            assert_err!(
+                Vacuum::mint_bridge_order(
+                    executor.clone(),
+                    &crate::RemoteEVMOrderLocalized {
+                        asset: ASSET_DOT,
+                        ..order
+                    }
+                ),
+                crate::Error::<MiniRuntime>::BridgeAssetNotMintable
+            );
+            assert_eq!(Assets::balance(1u32, &executor), 100);
+            assert_eq!(Assets::balance(1u32, &target_account), 900);
+        });
+    }
+
+    #[test]
+    fn vacuum_bridges_out_corresponding_assets_by_burning() {
+        let mut ext = prepare_ext_builder_playground();
+        ext.execute_with(|| {
+            System::set_block_number(1);
+            let user = AccountId32::from([5u8; 32]);
+            let beneficiary_on_dest = [6u8; 32];
+
+            assert_ok!(XDNS::enroll_bridge_asset(
+                RuntimeOrigin::root(),
+                ASSET_ETH,
+                [3, 3, 3, 3],
+                TokenInfo::Ethereum(EthereumToken {
+                    decimals: 18,
+                    symbol: b"eth".to_vec(),
+                    address: Some([0; 20])
+                })
+            ));
+            assert_ok!(<XDNS as XdnsT<MiniRuntime, Balance>>::mint(
+                ASSET_ETH,
+                user.clone(),
+                100
+            ));
+
+            // No correspondence to the destination gateway declared yet
+            assert_err!(
+                Vacuum::bridge_out(
+                    RuntimeOrigin::signed(user.clone()),
+                    ASSET_ETH,
+                    ETHEREUM_TARGET,
+                    beneficiary_on_dest,
+                    40,
+                ),
+                crate::Error::<MiniRuntime>::BridgeAssetHasNoCorrespondence
+            );
+
+            assert_ok!(XDNS::link_asset_correspondence(
+                RuntimeOrigin::root(),
+                [3, 3, 3, 3],
+                ASSET_ETH,
+                ETHEREUM_TARGET,
+                ASSET_ETH,
+            ));
+            assert_ok!(Vacuum::bridge_out(
+                RuntimeOrigin::signed(user.clone()),
+                ASSET_ETH,
+                ETHEREUM_TARGET,
+                beneficiary_on_dest,
+                40,
+            ));
+
+            assert_eq!(Assets::balance(ASSET_ETH, &user), 60);
+            System::assert_last_event(Event::Vacuum(VacuumEvent::BridgedAssetBurnt(
+                ETHEREUM_TARGET,
+                ASSET_ETH,
+                user,
+                beneficiary_on_dest,
+                40,
+                0,
+            )));
+            assert_eq!(crate::BridgeOutNonce::<MiniRuntime>::get(), 1);
+        });
+    }
+
+    #[test]
+    fn bridge_locks_are_minted_once_per_source_gateway_and_only_if_the_amount_fits() {
+        let mut ext = prepare_ext_builder_playground();
+        ext.execute_with(|| {
+            System::set_block_number(1);
+            let beneficiary = AccountId32::from([5u8; 32]);
+
+            assert_ok!(XDNS::enroll_bridge_asset(
+                RuntimeOrigin::root(),
+                ASSET_ETH,
+                [3, 3, 3, 3],
+                TokenInfo::Ethereum(EthereumToken {
+                    decimals: 18,
+                    symbol: b"eth".to_vec(),
+                    address: Some([0; 20])
+                })
+            ));
+            for source_gateway in [ETHEREUM_TARGET, POLKADOT_TARGET] {
+                assert_ok!(XDNS::link_asset_correspondence(
+                    RuntimeOrigin::root(),
+                    source_gateway,
+                    ASSET_ETH,
+                    [3, 3, 3, 3],
+                    ASSET_ETH,
+                ));
+            }
+
+            let lock = crate::RemoteEVMBridgeLockLocalized {
+                lock_id: H256::repeat_byte(7),
+                asset: ASSET_ETH,
+                beneficiary: beneficiary.clone(),
+                amount: 40.into(),
+            };
+
+            assert_ok!(Vacuum::mint_bridge_lock(ETHEREUM_TARGET, lock.clone()));
+            assert_err!(
+                Vacuum::mint_bridge_lock(ETHEREUM_TARGET, lock.clone()),
+                crate::Error::<MiniRuntime>::BridgeLockAlreadyProcessed
+            );
+            // Lock ids are only unique per escrow contract
+            assert_ok!(Vacuum::mint_bridge_lock(POLKADOT_TARGET, lock.clone()));
+            assert_eq!(Assets::balance(ASSET_ETH, &beneficiary), 80);
+
+            assert_err!(
+                Vacuum::mint_bridge_lock(
+                    ETHEREUM_TARGET,
+                    crate::RemoteEVMBridgeLockLocalized {
+                        lock_id: H256::repeat_byte(8),
+                        amount: U256::from(u128::MAX) + 1,
+                        ..lock
+                    }
+                ),
+                crate::Error::<MiniRuntime>::RemoteAmountOverflow
+            );
+            assert_eq!(Assets::balance(ASSET_ETH, &beneficiary), 80);
+        });
+    }
+
+    #[test]
+    fn eth_signed_orders_are_requested_by_the_derived_account_exactly_once() {
+        let mut ext = prepare_ext_builder_playground();
+        ext.execute_with(|| {
+            System::set_block_number(1);
+            let relayer = AccountId32::from([4u8; 32]);
+            let executor = AccountId32::from([1u8; 32]);
+            let requester_on_dest = AccountId32::from([3u8; 32]);
+            let eth_key = ecdsa::Pair::from_seed(&[42u8; 32]);
+
+            let sfx_order = OrderSFX::<AccountId32, u32, u128, [u8; 4], Vec<u8>, u128> {
+                sfx_action: SFXAction::Transfer(POLKADOT_TARGET, 1u32, requester_on_dest, 100u128),
+                max_reward: 200u128,
+                insurance: 50u128,
+                reward_asset: ASSET_DOT,
+                remote_origin_nonce: None,
+            };
+            let digest = Vacuum::eth_signed_order_digest(&[sfx_order.clone()], &SpeedMode::Fast, 0);
+            let signature = eth_key.sign_prehashed(&digest).0;
+            let signer = Vacuum::recover_eth_signer(&signature, &digest).unwrap();
+            let requester = AddressMappingMock::into_account_id(&signer);
+
+            mint_required_assets_for_optimistic_actors(
+                requester.clone(),
+                executor,
+                200u128,
+                50u128,
+                ASSET_DOT,
+            );
+            activate_all_light_clients();
+
+            // The signature only authorises the nonce it was made over
+            assert_err!(
+                Vacuum::eth_signed_order(
+                    RuntimeOrigin::signed(relayer.clone()),
+                    vec![sfx_order.clone()],
+                    SpeedMode::Fast,
+                    signer,
+                    1,
+                    signature,
+                ),
+                crate::Error::<MiniRuntime>::EthSignatureInvalid
+            );
+
+            assert_ok!(Vacuum::eth_signed_order(
+                RuntimeOrigin::signed(relayer.clone()),
+                vec![sfx_order.clone()],
+                SpeedMode::Fast,
+                signer,
+                0,
+                signature,
+            ));
+            System::assert_last_event(Event::Vacuum(VacuumEvent::EthSignedOrderSubmitted(
+                signer,
+                requester.clone(),
+                0,
+            )));
+            // The reward is charged to the derived account rather than the relayer
+            assert_eq!(
+                Assets::balance(ASSET_DOT, &requester),
+                EXISTENTIAL_DEPOSIT as Balance
+            );
+            assert_eq!(Vacuum::eth_signer_nonce(signer), 1);
+
+            assert_err!(
+                Vacuum::eth_signed_order(
+                    RuntimeOrigin::signed(relayer),
+                    vec![sfx_order],
+                    SpeedMode::Fast,
+                    signer,
+                    0,
+                    signature,
+                ),
+                crate::Error::<MiniRuntime>::EthSignedOrderNonceMismatch
+            );
+        });
+    }
+
+    #[test]
+    fn eip712_orders_only_submit_the_transfers_their_signer_was_shown() {
+        let mut ext = prepare_ext_builder_playground();
+        ext.execute_with(|| {
+            System::set_block_number(1);
+            let relayer = AccountId32::from([4u8; 32]);
+            let executor = AccountId32::from([1u8; 32]);
+            let eth_key = ecdsa::Pair::from_seed(&[43u8; 32]);
+
+            let side_effect = Eip712SideEffect {
+                destination: POLKADOT_TARGET,
+                asset: 1u32,
+                target: [3u8; 32],
+                amount: 100u128,
+                reward_asset: ASSET_DOT,
+                max_reward: 200u128,
+                insurance: 50u128,
+            };
+            let digest = eip712::typed_data_digest(
+                Vacuum::genesis_salt(),
+                &[side_effect.clone()],
+                &SpeedMode::Fast,
+                0,
+            );
+            let signature = eth_key.sign_prehashed(&digest).0;
+            let signer = Vacuum::recover_eth_signer(&signature, &digest).unwrap();
+            let requester = AddressMappingMock::into_account_id(&signer);
+
+            mint_required_assets_for_optimistic_actors(
+                requester.clone(),
+                executor,
+                200u128,
+                50u128,
+                ASSET_DOT,
+            );
+            activate_all_light_clients();
+
+            let tampered_side_effect = Eip712SideEffect {
+                amount: 1_000u128,
+                ..side_effect.clone()
+            };
+            assert_err!(
+                Vacuum::eip712_order(
+                    RuntimeOrigin::signed(relayer.clone()),
+                    vec![tampered_side_effect],
+                    SpeedMode::Fast,
+                    signer,
+                    0,
+                    signature,
+                ),
+                crate::Error::<MiniRuntime>::EthSignatureInvalid
+            );
+
+            assert_ok!(Vacuum::eip712_order(
+                RuntimeOrigin::signed(relayer),
+                vec![side_effect],
+                SpeedMode::Fast,
+                signer,
+                0,
+                signature,
+            ));
+            System::assert_last_event(Event::Vacuum(VacuumEvent::EthSignedOrderSubmitted(
+                signer,
+                requester.clone(),
+                0,
+            )));
+            assert_eq!(
+                Assets::balance(ASSET_DOT, &requester),
+                EXISTENTIAL_DEPOSIT as Balance
+            );
+            assert_eq!(Vacuum::eth_signer_nonce(signer), 1);
+        });
+    }
+
+    #[test]
+    fn vault_withdrawals_escrow_and_order_release_within_rate_limits_unless_paused() {
+        let mut ext = prepare_ext_builder_playground();
+        ext.execute_with(|| {
+            System::set_block_number(1);
+            activate_all_light_clients();
+            let user = AccountId32::from([5u8; 32]);
+            let executor = AccountId32::from([1u8; 32]);
+            let beneficiary_on_dest = [6u8; 32];
+            let remote_vault = H256::repeat_byte(7);
+
+            assert_ok!(XDNS::enroll_bridge_asset(
+                RuntimeOrigin::root(),
+                ASSET_ETH,
+                [3, 3, 3, 3],
+                TokenInfo::Ethereum(EthereumToken {
+                    decimals: 18,
+                    symbol: b"eth".to_vec(),
+                    address: Some([0; 20])
+                })
+            ));
+            assert_ok!(<XDNS as XdnsT<MiniRuntime, Balance>>::mint(
+                ASSET_ETH,
+                user.clone(),
+                100
+            ));
+            mint_required_assets_for_optimistic_actors(
+                user.clone(),
+                executor,
+                200u128,
+                50u128,
+                ASSET_DOT,
+            );
+
+            assert_err!(
+                Vacuum::vault_withdraw(
+                    RuntimeOrigin::signed(user.clone()),
+                    ASSET_ETH,
+                    beneficiary_on_dest,
+                    40,
+                    ASSET_DOT,
+                    100,
+                    SpeedMode::Fast,
+                ),
+                crate::Error::<MiniRuntime>::VaultNotRegistered
+            );
+
+            assert!(Vacuum::register_vault(
+                RuntimeOrigin::signed(user.clone()),
+                ASSET_ETH,
+                ETHEREUM_TARGET,
+                remote_vault,
+                H160::repeat_byte(8),
+                50,
+                10,
+            )
+            .is_err());
+            assert_ok!(Vacuum::register_vault(
+                RuntimeOrigin::root(),
+                ASSET_ETH,
+                ETHEREUM_TARGET,
+                remote_vault,
+                H160::repeat_byte(8),
+                50,
+                10,
+            ));
+
+            assert_ok!(Vacuum::set_vault_paused(
+                RuntimeOrigin::root(),
+                ASSET_ETH,
+                true
+            ));
+            assert_err!(
+                Vacuum::vault_withdraw(
+                    RuntimeOrigin::signed(user.clone()),
+                    ASSET_ETH,
+                    beneficiary_on_dest,
+                    40,
+                    ASSET_DOT,
+                    100,
+                    SpeedMode::Fast,
+                ),
+                crate::Error::<MiniRuntime>::VaultPaused
+            );
+            assert_ok!(Vacuum::set_vault_paused(
+                RuntimeOrigin::root(),
+                ASSET_ETH,
+                false
+            ));
+
+            assert_ok!(Vacuum::vault_withdraw(
+                RuntimeOrigin::signed(user.clone()),
+                ASSET_ETH,
+                beneficiary_on_dest,
+                40,
+                ASSET_DOT,
+                100,
+                SpeedMode::Fast,
+            ));
+            let escrow = MiniRuntime::get_treasury_account(TreasuryAccount::Escrow);
+            assert_eq!(Assets::balance(ASSET_ETH, &user), 60);
+            assert_eq!(Assets::balance(ASSET_ETH, &escrow), 40);
+            let finished_xtx_id = match System::events().last().map(|record| record.event.clone()) {
+                Some(Event::Vacuum(VacuumEvent::VaultWithdrawalOrdered(
+                    asset,
+                    who,
+                    beneficiary,
+                    amount,
+                    nonce,
+                    xtx_id,
+                ))) => {
+                    assert_eq!(asset, ASSET_ETH);
+                    assert_eq!(who, user);
+                    assert_eq!(beneficiary, beneficiary_on_dest);
+                    assert_eq!(amount, 40);
+                    assert_eq!(nonce, 0);
+                    xtx_id
+                },
+                _ => panic!("expected VaultWithdrawalOrdered event"),
+            };
+
+            // Another 20 would exceed the 50 released per period
+            assert_err!(
+                Vacuum::vault_withdraw(
+                    RuntimeOrigin::signed(user.clone()),
+                    ASSET_ETH,
+                    beneficiary_on_dest,
+                    20,
+                    ASSET_DOT,
+                    100,
+                    SpeedMode::Fast,
+                ),
+                crate::Error::<MiniRuntime>::VaultRateLimitExceeded
+            );
+            assert_eq!(Assets::balance(ASSET_ETH, &user), 60);
+            assert_eq!(
+                crate::VaultFlows::<MiniRuntime>::get(ASSET_ETH).released,
+                40
+            );
+
+            // A new period resets the released total
+            System::set_block_number(11);
+            assert_ok!(Vacuum::vault_withdraw(
+                RuntimeOrigin::signed(user.clone()),
+                ASSET_ETH,
+                beneficiary_on_dest,
+                20,
+                ASSET_DOT,
+                100,
+                SpeedMode::Fast,
+            ));
+            assert_eq!(Assets::balance(ASSET_ETH, &user), 40);
+            assert_eq!(Assets::balance(ASSET_ETH, &escrow), 60);
+            assert_eq!(
+                crate::VaultFlows::<MiniRuntime>::get(ASSET_ETH).released,
+                20
+            );
+            assert_eq!(
+                crate::VaultWithdrawalNonce::<MiniRuntime>::get(ASSET_ETH),
+                2
+            );
+            let reverted_xtx_id = match System::events().last().map(|record| record.event.clone()) {
+                Some(Event::Vacuum(VacuumEvent::VaultWithdrawalOrdered(.., xtx_id))) => xtx_id,
+                _ => panic!("expected VaultWithdrawalOrdered event"),
+            };
+
+            // Escrowed withdrawals are only settled once their release concludes
+            <Vacuum as OnXtxConcluded<Hash>>::on_xtx_concluded(
+                finished_xtx_id,
+                &CircuitStatus::PendingExecution,
+            );
+            assert_eq!(Assets::balance(ASSET_ETH, &escrow), 60);
+
+            <Vacuum as OnXtxConcluded<Hash>>::on_xtx_concluded(
+                finished_xtx_id,
+                &CircuitStatus::FinishedAllSteps,
+            );
+            System::assert_last_event(Event::Vacuum(VacuumEvent::VaultWithdrawalBurnt(
+                finished_xtx_id,
+                ASSET_ETH,
+                40,
+            )));
+            assert_eq!(Assets::balance(ASSET_ETH, &escrow), 20);
+            assert_eq!(Assets::balance(ASSET_ETH, &user), 40);
+
+            <Vacuum as OnXtxConcluded<Hash>>::on_xtx_concluded(
+                reverted_xtx_id,
+                &CircuitStatus::Reverted(Cause::Timeout),
+            );
+            System::assert_last_event(Event::Vacuum(VacuumEvent::VaultWithdrawalRefunded(
+                reverted_xtx_id,
+                ASSET_ETH,
+                user.clone(),
+                20,
+            )));
+            assert_eq!(Assets::balance(ASSET_ETH, &escrow), 0);
+            assert_eq!(Assets::balance(ASSET_ETH, &user), 60);
+
+            // Committing after finishing all steps doesn't burn twice
+            <Vacuum as OnXtxConcluded<Hash>>::on_xtx_concluded(
+                finished_xtx_id,
+                &CircuitStatus::Committed,
+            );
+            assert_eq!(Assets::balance(ASSET_ETH, &user), 60);
+            assert!(crate::PendingVaultWithdrawals::<MiniRuntime>::iter()
+                .next()
+                .is_none());
+        });
+    }
+
     #[test]
     fn optimistic_order_single_sfx_vacuum_delivers_to_circuit_and_handles_potential_delays_via_dlq_eventually(
     ) {
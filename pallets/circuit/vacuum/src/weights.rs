@@ -6,6 +6,11 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `Maciejs-Mac-Studio.local`, CPU: `<UNKNOWN>`
 //! EXECUTION: ``, WASM-EXECUTION: `Compiled`, CHAIN: `Some("local")`, DB CACHE: 1024
+//!
+//! Only `single_order` was generated by the command below. The remaining weights are estimated
+//! from the benchmarks of `benchmarking.rs` ahead of a run on reference hardware; regenerate them
+//! with `--extrinsic '*'`. The weights of calls taking an inclusion proof leave out verifying it,
+//! which the calls charge at the Portal's weight for the proof's length.
 
 // Executed Command:
 // ./target/debug/t0rn-collator
@@ -36,6 +41,13 @@ use core::marker::PhantomData;
 
 pub trait WeightInfo {
     fn single_order() -> Weight;
+    fn bridge_in() -> Weight;
+    fn bridge_out() -> Weight;
+    fn eth_signed_order(s: u32, ) -> Weight;
+    fn register_vault() -> Weight;
+    fn set_vault_paused() -> Weight;
+    fn vault_deposit() -> Weight;
+    fn vault_withdraw() -> Weight;
 }
 pub struct SubstrateWeight<T>(PhantomData<T>);
 
@@ -81,4 +93,194 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
             .saturating_add(T::DbWeight::get().reads(11))
             .saturating_add(T::DbWeight::get().writes(9))
     }
+    /// Storage: `Vacuum::ProcessedBridgeLocks` (r:1 w:1)
+    /// Proof: `Vacuum::ProcessedBridgeLocks` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `XDNS::AssetCorrespondence` (r:1 w:0)
+    /// Proof: `XDNS::AssetCorrespondence` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `XDNS::AuthorizedMintAssets` (r:1 w:0)
+    /// Proof: `XDNS::AuthorizedMintAssets` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+    /// Storage: `XDNS::Tokens` (r:1 w:0)
+    /// Proof: `XDNS::Tokens` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Assets::Asset` (r:1 w:1)
+    /// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(222), added: 2697, mode: `MaxEncodedLen`)
+    /// Storage: `Assets::Account` (r:1 w:1)
+    /// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
+    fn bridge_in() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `1128`
+        //  Estimated: `4593`
+        // Minimum execution time: 61_000_000 picoseconds.
+        Weight::from_parts(66_000_000, 0)
+            .saturating_add(Weight::from_parts(0, 4593))
+            .saturating_add(T::DbWeight::get().reads(6))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
+    /// Storage: `XDNS::AuthorizedMintAssets` (r:1 w:0)
+    /// Proof: `XDNS::AuthorizedMintAssets` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+    /// Storage: `XDNS::Tokens` (r:1 w:0)
+    /// Proof: `XDNS::Tokens` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `XDNS::AssetCorrespondence` (r:1 w:0)
+    /// Proof: `XDNS::AssetCorrespondence` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Assets::Asset` (r:1 w:1)
+    /// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(222), added: 2697, mode: `MaxEncodedLen`)
+    /// Storage: `Assets::Account` (r:1 w:1)
+    /// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
+    /// Storage: `Vacuum::BridgeOutNonce` (r:1 w:1)
+    /// Proof: `Vacuum::BridgeOutNonce` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+    fn bridge_out() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `1114`
+        //  Estimated: `4579`
+        // Minimum execution time: 57_000_000 picoseconds.
+        Weight::from_parts(60_000_000, 0)
+            .saturating_add(Weight::from_parts(0, 4579))
+            .saturating_add(T::DbWeight::get().reads(6))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
+    /// Storage: `System::BlockHash` (r:1 w:0)
+    /// Proof: `System::BlockHash` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+    /// Storage: `Vacuum::EthSignerNonces` (r:1 w:1)
+    /// Proof: `Vacuum::EthSignerNonces` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `XDNS::Gateways` (r:1 w:0)
+    /// Proof: `XDNS::Gateways` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `XDNS::EpochHistory` (r:1 w:0)
+    /// Proof: `XDNS::EpochHistory` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `XDNS::VerifierOverviewStoreHistory` (r:1 w:0)
+    /// Proof: `XDNS::VerifierOverviewStoreHistory` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Circuit::XExecSignals` (r:1 w:1)
+    /// Proof: `Circuit::XExecSignals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `XDNS::GatewaysOverviewStore` (r:1 w:1)
+    /// Proof: `XDNS::GatewaysOverviewStore` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+    /// Storage: `XDNS::Tokens` (r:8 w:0)
+    /// Proof: `XDNS::Tokens` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Attesters::Batches` (r:1 w:0)
+    /// Proof: `Attesters::Batches` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `XDNS::GatewaysOverviewStoreHistory` (r:1 w:1)
+    /// Proof: `XDNS::GatewaysOverviewStoreHistory` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `XDNS::SFXABIRegistry` (r:8 w:0)
+    /// Proof: `XDNS::SFXABIRegistry` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `AccountManager::PendingCharges` (r:8 w:8)
+    /// Proof: `AccountManager::PendingCharges` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Circuit::PendingXtxTimeoutsMap` (r:0 w:1)
+    /// Proof: `Circuit::PendingXtxTimeoutsMap` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Circuit::LocalXtxStates` (r:0 w:1)
+    /// Proof: `Circuit::LocalXtxStates` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Circuit::PendingXtxBidsTimeoutsMap` (r:0 w:1)
+    /// Proof: `Circuit::PendingXtxBidsTimeoutsMap` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Circuit::SFX2XTXLinksMap` (r:0 w:8)
+    /// Proof: `Circuit::SFX2XTXLinksMap` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Circuit::FullSideEffects` (r:0 w:1)
+    /// Proof: `Circuit::FullSideEffects` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// The range of component `s` is `[1, 8]`.
+    fn eth_signed_order(s: u32, ) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `1301 + s * (96 ±0)`
+        //  Estimated: `7241 + s * (2571 ±0)`
+        // Minimum execution time: 1_104_000_000 picoseconds.
+        Weight::from_parts(1_092_000_000, 0)
+            .saturating_add(Weight::from_parts(0, 7241))
+            // Standard Error: 41_000_000
+            .saturating_add(Weight::from_parts(412_000_000, 0).saturating_mul(s.into()))
+            .saturating_add(T::DbWeight::get().reads(9))
+            .saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(s.into())))
+            .saturating_add(T::DbWeight::get().writes(8))
+            .saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(s.into())))
+            .saturating_add(Weight::from_parts(0, 2571).saturating_mul(s.into()))
+    }
+    /// Storage: `XDNS::AuthorizedMintAssets` (r:1 w:0)
+    /// Proof: `XDNS::AuthorizedMintAssets` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+    /// Storage: `Vacuum::Vaults` (r:0 w:1)
+    /// Proof: `Vacuum::Vaults` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Vacuum::VaultFlows` (r:0 w:1)
+    /// Proof: `Vacuum::VaultFlows` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    fn register_vault() -> Weight {
+        Weight::from_parts(20_000_000, 0)
+            .saturating_add(Weight::from_parts(0, 1489))
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+    /// Storage: `Vacuum::Vaults` (r:1 w:1)
+    /// Proof: `Vacuum::Vaults` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    fn set_vault_paused() -> Weight {
+        Weight::from_parts(15_000_000, 0)
+            .saturating_add(Weight::from_parts(0, 1489))
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    /// Storage: `Vacuum::Vaults` (r:1 w:0)
+    /// Proof: `Vacuum::Vaults` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Vacuum::ProcessedVaultDeposits` (r:1 w:1)
+    /// Proof: `Vacuum::ProcessedVaultDeposits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Vacuum::VaultFlows` (r:1 w:1)
+    /// Proof: `Vacuum::VaultFlows` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `XDNS::AuthorizedMintAssets` (r:1 w:0)
+    /// Proof: `XDNS::AuthorizedMintAssets` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+    /// Storage: `XDNS::Tokens` (r:1 w:0)
+    /// Proof: `XDNS::Tokens` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Assets::Asset` (r:1 w:1)
+    /// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(222), added: 2697, mode: `MaxEncodedLen`)
+    /// Storage: `Assets::Account` (r:1 w:1)
+    /// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
+    fn vault_deposit() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `1203`
+        //  Estimated: `4668`
+        // Minimum execution time: 68_000_000 picoseconds.
+        Weight::from_parts(73_000_000, 0)
+            .saturating_add(Weight::from_parts(0, 4668))
+            .saturating_add(T::DbWeight::get().reads(7))
+            .saturating_add(T::DbWeight::get().writes(4))
+    }
+    /// Storage: `Vacuum::Vaults` (r:1 w:0)
+    /// Proof: `Vacuum::Vaults` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Vacuum::VaultFlows` (r:1 w:1)
+    /// Proof: `Vacuum::VaultFlows` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Assets::Asset` (r:1 w:1)
+    /// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(222), added: 2697, mode: `MaxEncodedLen`)
+    /// Storage: `Assets::Account` (r:2 w:2)
+    /// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
+    /// Storage: `Vacuum::VaultWithdrawalNonce` (r:1 w:1)
+    /// Proof: `Vacuum::VaultWithdrawalNonce` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Vacuum::PendingVaultWithdrawals` (r:0 w:1)
+    /// Proof: `Vacuum::PendingVaultWithdrawals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `XDNS::Gateways` (r:2 w:0)
+    /// Proof: `XDNS::Gateways` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `XDNS::EpochHistory` (r:1 w:0)
+    /// Proof: `XDNS::EpochHistory` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `XDNS::VerifierOverviewStoreHistory` (r:1 w:0)
+    /// Proof: `XDNS::VerifierOverviewStoreHistory` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Circuit::XExecSignals` (r:1 w:1)
+    /// Proof: `Circuit::XExecSignals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `XDNS::GatewaysOverviewStore` (r:1 w:1)
+    /// Proof: `XDNS::GatewaysOverviewStore` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+    /// Storage: `XDNS::Tokens` (r:1 w:0)
+    /// Proof: `XDNS::Tokens` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Attesters::Batches` (r:1 w:0)
+    /// Proof: `Attesters::Batches` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `XDNS::GatewaysOverviewStoreHistory` (r:1 w:1)
+    /// Proof: `XDNS::GatewaysOverviewStoreHistory` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `XDNS::SFXABIRegistry` (r:1 w:0)
+    /// Proof: `XDNS::SFXABIRegistry` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `AccountManager::PendingCharges` (r:1 w:1)
+    /// Proof: `AccountManager::PendingCharges` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Circuit::PendingXtxTimeoutsMap` (r:0 w:1)
+    /// Proof: `Circuit::PendingXtxTimeoutsMap` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Circuit::LocalXtxStates` (r:0 w:1)
+    /// Proof: `Circuit::LocalXtxStates` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Circuit::PendingXtxBidsTimeoutsMap` (r:0 w:1)
+    /// Proof: `Circuit::PendingXtxBidsTimeoutsMap` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Circuit::SFX2XTXLinksMap` (r:0 w:1)
+    /// Proof: `Circuit::SFX2XTXLinksMap` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `Circuit::FullSideEffects` (r:0 w:1)
+    /// Proof: `Circuit::FullSideEffects` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    fn vault_withdraw() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `1412`
+        //  Estimated: `7352`
+        // Minimum execution time: 1_086_000_000 picoseconds.
+        Weight::from_parts(1_503_000_000, 0)
+            .saturating_add(Weight::from_parts(0, 7352))
+            .saturating_add(T::DbWeight::get().reads(17))
+            .saturating_add(T::DbWeight::get().writes(15))
+    }
 }
@@ -32,10 +32,10 @@ pub mod pallet {
 
     // Import various types used to declare pallet in scope.
     use super::*;
-    use frame_support::pallet_prelude::*;
+    use frame_support::{pallet_prelude::*, sp_runtime::traits::Zero};
     use frame_system::pallet_prelude::*;
 
-    use t3rn_primitives::clock::OnHookQueues;
+    use t3rn_primitives::clock::{OnHookQueues, OnNewRound, OnRoundDurationChange};
 
     const FIVE: u64 = 5;
 
@@ -44,9 +44,19 @@ pub mod pallet {
         /// The overarching event type.
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
+        /// Round duration used until governance sets a different one.
         #[pallet::constant]
         type RoundDuration: Get<BlockNumberFor<Self>>;
 
+        /// Origin allowed to change the round duration.
+        type UpdateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Notified when a changed round duration takes effect.
+        type OnRoundDurationChange: OnRoundDurationChange<BlockNumberFor<Self>>;
+
+        /// Listeners notified whenever a new round starts.
+        type OnNewRound: OnNewRound<BlockNumberFor<Self>>;
+
         /// Description of on_initialize queues and their max. consumption of % of total on_init weight.
         /// The first element of the tuple is the queue name, the second is the max. % of total on_init weight.}
         type OnInitializeQueues: OnHookQueues<Self>;
@@ -65,31 +75,79 @@ pub mod pallet {
     /// Information on the current round.
     pub type CurrentRound<T: Config> = StorageValue<_, RoundInfo<BlockNumberFor<T>>, ValueQuery>;
 
+    #[pallet::storage]
+    /// Round duration set by governance, overriding `RoundDuration`.
+    pub type RoundDurationOverride<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn pending_round_duration)]
+    /// Round duration taking effect from the next round.
+    pub type PendingRoundDuration<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
     impl<T: Config> Pallet<T> {
         pub fn check_bump_round(n: frame_system::pallet_prelude::BlockNumberFor<T>) -> Weight {
             let past_round = <CurrentRound<T>>::get();
-            let term = T::RoundDuration::get();
+            if !past_round.should_update(n) {
+                return T::DbWeight::get().reads(2)
+            }
+
+            let mut weight = T::DbWeight::get().reads_writes(3, 1);
+            if let Some(new_duration) = <PendingRoundDuration<T>>::take() {
+                let old_duration = Self::round_duration();
+                <RoundDurationOverride<T>>::put(new_duration);
+                Self::deposit_event(Event::RoundDurationChanged {
+                    old_duration,
+                    new_duration,
+                });
+                weight = weight
+                    .saturating_add(T::DbWeight::get().writes(2))
+                    .saturating_add(T::OnRoundDurationChange::on_round_duration_change(
+                        old_duration,
+                        new_duration,
+                    ));
+            }
+
             let new_round = RoundInfo {
                 index: past_round.index.saturating_add(1),
                 head: n,
-                term,
+                term: Self::round_duration(),
             };
             log::debug!(
                 "check_bump_round: past_round: {:?}, new_round: {:?}",
                 past_round,
                 new_round
             );
-            if past_round.should_update(n) {
-                <CurrentRound<T>>::put(new_round);
-                Self::deposit_event(Event::NewRound {
-                    index: new_round.index,
-                    head: new_round.head,
-                    term: new_round.term,
-                });
-                T::DbWeight::get().reads_writes(2, 1)
-            } else {
-                T::DbWeight::get().reads(2)
-            }
+            <CurrentRound<T>>::put(new_round);
+            Self::deposit_event(Event::NewRound {
+                index: new_round.index,
+                head: new_round.head,
+                term: new_round.term,
+            });
+            weight.saturating_add(T::OnNewRound::on_new_round(new_round.index, new_round.head))
+        }
+
+        /// Duration of rounds started from now on.
+        pub fn round_duration() -> BlockNumberFor<T> {
+            <RoundDurationOverride<T>>::get().unwrap_or_else(T::RoundDuration::get)
+        }
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Change the round duration, taking effect from the next round.
+        #[pallet::weight(T::DbWeight::get().writes(1))]
+        pub fn set_round_duration(
+            origin: OriginFor<T>,
+            duration: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+
+            ensure!(!duration.is_zero(), Error::<T>::ZeroRoundDuration);
+
+            <PendingRoundDuration<T>>::put(duration);
+            Self::deposit_event(Event::RoundDurationChangeScheduled { duration });
+
+            Ok(())
         }
     }
 
@@ -140,10 +198,19 @@ pub mod pallet {
             head: frame_system::pallet_prelude::BlockNumberFor<T>,
             term: frame_system::pallet_prelude::BlockNumberFor<T>,
         },
+        RoundDurationChangeScheduled {
+            duration: frame_system::pallet_prelude::BlockNumberFor<T>,
+        },
+        RoundDurationChanged {
+            old_duration: frame_system::pallet_prelude::BlockNumberFor<T>,
+            new_duration: frame_system::pallet_prelude::BlockNumberFor<T>,
+        },
     }
 
     #[pallet::error]
-    pub enum Error<T> {}
+    pub enum Error<T> {
+        ZeroRoundDuration,
+    }
 
     #[pallet::genesis_config]
     #[derive(frame_support::DefaultNoBound)]
@@ -165,7 +232,7 @@ pub mod pallet {
         }
 
         fn round_duration() -> frame_system::pallet_prelude::BlockNumberFor<T> {
-            T::RoundDuration::get()
+            Self::round_duration()
         }
     }
 }
@@ -1,6 +1,11 @@
 #[cfg(test)]
 pub mod clock_test {
-    use t3rn_mini_mock_runtime::{BlockNumber, Clock, ExtBuilder, System};
+    use frame_support::{assert_noop, assert_ok};
+    use sp_runtime::DispatchError;
+    use t3rn_mini_mock_runtime::{
+        BlockNumber, Clock, ClockError, ClockEvent, ExtBuilder, MiniRuntime, RuntimeEvent,
+        RuntimeOrigin, System,
+    };
     use t3rn_primitives::common::RoundInfo;
 
     #[test]
@@ -47,4 +52,57 @@ pub mod clock_test {
             assert_eq!(current_round, expected_round_next);
         });
     }
+
+    #[test]
+    fn set_round_duration_takes_effect_from_next_round() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            System::set_block_number(1u32);
+
+            assert_noop!(
+                Clock::set_round_duration(RuntimeOrigin::signed([1u8; 32].into()), 100),
+                DispatchError::BadOrigin
+            );
+            assert_noop!(
+                Clock::set_round_duration(RuntimeOrigin::root(), 0),
+                ClockError::<MiniRuntime>::ZeroRoundDuration
+            );
+            assert_ok!(Clock::set_round_duration(RuntimeOrigin::root(), 100));
+            assert_eq!(Clock::pending_round_duration(), Some(100));
+
+            // The ongoing round keeps its term
+            System::set_block_number(299u32);
+            Clock::check_bump_round(BlockNumber::from(299u32));
+            assert_eq!(Clock::current_round().index, 1);
+
+            System::set_block_number(300u32);
+            Clock::check_bump_round(BlockNumber::from(300u32));
+            assert_eq!(
+                Clock::current_round(),
+                RoundInfo {
+                    index: 2,
+                    head: 300,
+                    term: 100,
+                }
+            );
+            assert_eq!(Clock::round_duration(), 100);
+            assert_eq!(Clock::pending_round_duration(), None);
+            assert!(System::events().iter().any(|record| record.event
+                == RuntimeEvent::Clock(ClockEvent::RoundDurationChanged {
+                    old_duration: 300,
+                    new_duration: 100,
+                })));
+
+            System::set_block_number(400u32);
+            Clock::check_bump_round(BlockNumber::from(400u32));
+            assert_eq!(
+                Clock::current_round(),
+                RoundInfo {
+                    index: 3,
+                    head: 400,
+                    term: 100,
+                }
+            );
+        });
+    }
 }
@@ -26,14 +26,23 @@
 use codec::Encode;
 use frame_support::dispatch::DispatchResult;
 use frame_system::{ensure_signed, pallet_prelude::BlockNumberFor};
+use sp_runtime::DispatchError;
 use sp_std::{convert::TryInto, prelude::*};
+use t3rn_abi::{standard::get_deploy_contract_abi, Abi, FilledAbi};
 
 // Re-export pallet items so that they can be accessed from the crate namespace.
 pub use pallet::*;
 use t3rn_primitives::{
-    contracts_registry::{ContractsRegistry as ContractsRegistryT, RegistryContractId},
+    circuit::{CircuitStatus, CircuitSubmitAPI, OnXtxConcluded, OrderSFX, ReadSFX, SFXAction},
+    contracts_registry::{
+        ContractsRegistry as ContractsRegistryT, KindValidator, RegistryContractId,
+    },
+    portal::Portal,
     reexport_currency_types,
+    xdns::Xdns,
+    SpeedMode, TargetId,
 };
+use t3rn_types::sfx::{SecurityLvl, SideEffect, DEPLOY_SIDE_EFFECT_ID};
 
 #[cfg(test)]
 mod tests;
@@ -80,6 +89,18 @@ pub mod pallet {
 
         /// A type that provides inspection and mutation to some fungible assets
         type Balances: Inspect<Self::AccountId> + Mutate<Self::AccountId>;
+
+        /// Submits the Xtx deploying registry contracts onto remote gateways
+        type CircuitSubmitAPI: CircuitSubmitAPI<Self, BalanceOf<Self>>;
+
+        /// Reads back the confirmations of deployment Xtx
+        type ReadSFX: ReadSFX<Self::Hash, Self::AccountId, BalanceOf<Self>, BlockNumberFor<Self>>;
+
+        /// Verifies the inclusion of deployment confirmations on their gateways
+        type Portal: Portal<Self>;
+
+        /// Resolves the codec deployment confirmations are encoded with on their gateways
+        type Xdns: Xdns<Self, BalanceOf<Self>>;
     }
 
     // Simple declaration of the `Pallet` type. It is placeholder we use to implement traits and
@@ -129,20 +150,141 @@ pub mod pallet {
         ) -> DispatchResultWithPostInfo {
             ensure_root(origin)?;
 
-            let contract_id = contract.generate_id::<T>();
+            Self::store_contract(requester, contract)?;
+            Ok(().into())
+        }
+
+        /// Stores a contract authored by the signer, addressable by the hash of its artifacts.
+        /// System contracts can only be added by root.
+        #[pallet::weight(<T as Config>::WeightInfo::register_contract())]
+        pub fn register_contract(
+            origin: OriginFor<T>,
+            contract: RegistryContract<T::Hash, T::AccountId, BalanceOf<T>, BlockNumberFor<T>>,
+        ) -> DispatchResultWithPostInfo {
+            let author = ensure_signed(origin)?;
 
-            assert!(
-                requester == contract.author.account,
-                "only the first submitter of contract to registry can become the author",
+            ensure!(
+                contract.meta.get_contract_type().can_instantiate(),
+                Error::<T>::SystemContractRequiresRoot
             );
 
-            if <ContractsRegistry<T>>::contains_key(contract_id) {
-                Err(Error::<T>::ContractAlreadyExists.into())
-            } else {
-                <ContractsRegistry<T>>::insert(contract_id, contract);
-                Self::deposit_event(Event::<T>::ContractStored(requester, contract_id));
-                Ok(().into())
-            }
+            Self::store_contract(author, contract)?;
+            Ok(().into())
+        }
+
+        /// Updates the fees the author charges for every use of their contract.
+        #[pallet::weight(<T as Config>::WeightInfo::set_author_fees())]
+        pub fn set_author_fees(
+            origin: OriginFor<T>,
+            contract_id: RegistryContractId<T>,
+            fees_per_single_use: Option<BalanceOf<T>>,
+        ) -> DispatchResultWithPostInfo {
+            let author = ensure_signed(origin)?;
+
+            <ContractsRegistry<T>>::try_mutate(contract_id, |maybe_contract| {
+                let contract = maybe_contract.as_mut().ok_or(Error::<T>::UnknownContract)?;
+                ensure!(
+                    contract.author.account == author,
+                    Error::<T>::NotContractAuthor
+                );
+                contract.author.fees_per_single_use = fees_per_single_use;
+                Ok::<(), Error<T>>(())
+            })?;
+
+            Self::deposit_event(Event::<T>::AuthorFeesSet(contract_id, fees_per_single_use));
+            Ok(().into())
+        }
+
+        /// Removes a contract from the onchain registry on behalf of its author.
+        #[pallet::weight(<T as Config>::WeightInfo::purge())]
+        pub fn purge_own_contract(
+            origin: OriginFor<T>,
+            contract_id: RegistryContractId<T>,
+        ) -> DispatchResultWithPostInfo {
+            let author = ensure_signed(origin)?;
+
+            let contract =
+                <ContractsRegistry<T>>::get(contract_id).ok_or(Error::<T>::UnknownContract)?;
+            ensure!(
+                contract.author.account == author,
+                Error::<T>::NotContractAuthor
+            );
+
+            <ContractsRegistry<T>>::remove(contract_id);
+            let _ = <RemoteDeployments<T>>::clear_prefix(contract_id, u32::MAX, None);
+            Self::deposit_event(Event::<T>::ContractPurged(author, contract_id));
+            Ok(().into())
+        }
+
+        /// Deploys a contract authored by the signer onto each of the given gateways in a single
+        /// Xtx, one `depl` side effect per gateway rewarded with up to `max_reward`. The remote
+        /// addresses are recorded against the contract once the Xtx commits.
+        #[pallet::weight(<T as Config>::WeightInfo::deploy_to_gateways(gateways.len() as u32))]
+        pub fn deploy_to_gateways(
+            origin: OriginFor<T>,
+            contract_id: RegistryContractId<T>,
+            gateways: Vec<TargetId>,
+            input: Vec<u8>,
+            max_reward: BalanceOf<T>,
+            insurance: BalanceOf<T>,
+            speed_mode: SpeedMode,
+        ) -> DispatchResultWithPostInfo {
+            let author = ensure_signed(origin.clone())?;
+
+            let contract =
+                <ContractsRegistry<T>>::get(contract_id).ok_or(Error::<T>::UnknownContract)?;
+            ensure!(
+                contract.author.account == author,
+                Error::<T>::NotContractAuthor
+            );
+            ensure!(!gateways.is_empty(), Error::<T>::NoDeploymentTargets);
+            let mut distinct_gateways = gateways.clone();
+            distinct_gateways.sort();
+            distinct_gateways.dedup();
+            ensure!(
+                distinct_gateways.len() == gateways.len(),
+                Error::<T>::DuplicateDeploymentTarget
+            );
+
+            let side_effects = gateways
+                .iter()
+                .map(|gateway| {
+                    OrderSFX::<T::AccountId, u32, BalanceOf<T>, TargetId, Vec<u8>, BalanceOf<T>> {
+                        sfx_action: SFXAction::Deploy(
+                            *gateway,
+                            contract.bytes.clone(),
+                            input.clone(),
+                        ),
+                        max_reward,
+                        reward_asset: 0,
+                        insurance,
+                        remote_origin_nonce: None,
+                    }
+                    .try_into()
+                })
+                .collect::<Result<Vec<SideEffect<T::AccountId, BalanceOf<T>>>, DispatchError>>()?;
+
+            T::CircuitSubmitAPI::on_extrinsic_trigger(
+                origin,
+                side_effects,
+                speed_mode.clone(),
+                SecurityLvl::Optimistic,
+            )?;
+            let xtx_id = T::ReadSFX::recover_latest_submitted_xtx_id()?;
+
+            <PendingDeployments<T>>::insert(
+                xtx_id,
+                PendingDeployment {
+                    contract_id,
+                    speed_mode,
+                },
+            );
+            Self::deposit_event(Event::<T>::DeploymentRequested(
+                contract_id,
+                xtx_id,
+                gateways,
+            ));
+            Ok(().into())
         }
 
         /// Removes a contract from the onchain registry. Root only access.
@@ -158,6 +300,7 @@ pub mod pallet {
                 Err(Error::<T>::UnknownContract.into())
             } else {
                 <ContractsRegistry<T>>::remove(contract_id);
+                let _ = <RemoteDeployments<T>>::clear_prefix(contract_id, u32::MAX, None);
                 Self::deposit_event(Event::<T>::ContractPurged(requester, contract_id));
                 Ok(().into())
             }
@@ -171,6 +314,12 @@ pub mod pallet {
         ContractStored(T::AccountId, RegistryContractId<T>),
         /// \[requester, contract_id\]
         ContractPurged(T::AccountId, RegistryContractId<T>),
+        /// \[contract_id, fees_per_single_use\]
+        AuthorFeesSet(RegistryContractId<T>, Option<BalanceOf<T>>),
+        /// \[contract_id, xtx_id, gateways\]
+        DeploymentRequested(RegistryContractId<T>, T::Hash, Vec<TargetId>),
+        /// \[contract_id, gateway, remote_address\]
+        RemoteDeploymentRecorded(RegistryContractId<T>, TargetId, Vec<u8>),
     }
 
     // Errors inform users that something went wrong.
@@ -181,6 +330,14 @@ pub mod pallet {
         ContractAlreadyExists,
         /// Access of unknown contract
         UnknownContract,
+        /// Only the author of a contract can register or manage it
+        NotContractAuthor,
+        /// System contracts can only be added to the registry by root
+        SystemContractRequiresRoot,
+        /// A contract can't be deployed without any gateways to deploy it onto
+        NoDeploymentTargets,
+        /// A contract can only be deployed once per gateway in a single Xtx
+        DuplicateDeploymentTarget,
     }
 
     /// The pre-validated composable contracts on-chain registry.
@@ -199,6 +356,24 @@ pub mod pallet {
         OptionQuery,
     >;
 
+    /// Deployments of registry contracts onto remote gateways, awaiting their Xtx to conclude.
+    #[pallet::storage]
+    pub type PendingDeployments<T: Config> =
+        StorageMap<_, Identity, T::Hash, PendingDeployment<RegistryContractId<T>>, OptionQuery>;
+
+    /// Addresses registry contracts were deployed at on remote gateways.
+    #[pallet::storage]
+    #[pallet::getter(fn remote_deployments)]
+    pub type RemoteDeployments<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        RegistryContractId<T>,
+        Blake2_128Concat,
+        TargetId,
+        Vec<u8>,
+        OptionQuery,
+    >;
+
     // The genesis config type.
     #[pallet::genesis_config]
     #[derive(frame_support::DefaultNoBound)]
@@ -222,6 +397,100 @@ impl<T: Config> Pallet<T> {
 
         Ok(())
     }
+
+    /// Only the first submitter of a contract to the registry can become its author.
+    fn store_contract(
+        requester: T::AccountId,
+        contract: RegistryContract<T::Hash, T::AccountId, BalanceOf<T>, BlockNumberFor<T>>,
+    ) -> Result<RegistryContractId<T>, Error<T>> {
+        if requester != contract.author.account {
+            return Err(Error::<T>::NotContractAuthor)
+        }
+
+        let contract_id = contract.generate_id::<T>();
+        if <ContractsRegistry<T>>::contains_key(contract_id) {
+            return Err(Error::<T>::ContractAlreadyExists)
+        }
+
+        <ContractsRegistry<T>>::insert(contract_id, contract);
+        Self::deposit_event(Event::<T>::ContractStored(requester, contract_id));
+        Ok(contract_id)
+    }
+
+    /// Record the remote addresses reported by the confirmed `depl` side effects of an Xtx.
+    fn record_remote_deployments(
+        xtx_id: T::Hash,
+        deployment: PendingDeployment<RegistryContractId<T>>,
+    ) {
+        let fsx_ids = T::ReadSFX::get_fsx_of_xtx(xtx_id).unwrap_or_default();
+        for fsx in fsx_ids
+            .into_iter()
+            .filter_map(|fsx_id| T::ReadSFX::get_fsx(fsx_id).ok())
+        {
+            if fsx.input.action != *DEPLOY_SIDE_EFFECT_ID {
+                continue
+            }
+            let Some(confirmed) = fsx.confirmed else {
+                continue
+            };
+
+            match Self::decode_remote_address(
+                fsx.input.target,
+                deployment.speed_mode.clone(),
+                confirmed.inclusion_data,
+            ) {
+                Ok(remote_address) => {
+                    <RemoteDeployments<T>>::insert(
+                        deployment.contract_id,
+                        fsx.input.target,
+                        remote_address.clone(),
+                    );
+                    Self::deposit_event(Event::<T>::RemoteDeploymentRecorded(
+                        deployment.contract_id,
+                        fsx.input.target,
+                        remote_address,
+                    ));
+                },
+                Err(err) => log::warn!(
+                    target: "runtime::contracts-registry",
+                    "Failed to recover the address of {:?} deployed on {:?}: {:?}",
+                    deployment.contract_id,
+                    fsx.input.target,
+                    err
+                ),
+            }
+        }
+    }
+
+    /// Re-verify the inclusion of a deployment confirmation and read the contract address off it.
+    fn decode_remote_address(
+        gateway: TargetId,
+        speed_mode: SpeedMode,
+        inclusion_data: Vec<u8>,
+    ) -> Result<Vec<u8>, DispatchError> {
+        let receipt = T::Portal::verify_event_inclusion(gateway, speed_mode, None, inclusion_data)?;
+        let codec = T::Xdns::get_target_codec(&gateway)?;
+        let abi: Abi = get_deploy_contract_abi()
+            .get_expected_ingress_descriptor(codec.clone())
+            .try_into()?;
+
+        FilledAbi::try_fill_abi(abi, receipt.message, codec)?
+            .get_data_by_name(&b"contract".to_vec())
+            .ok_or_else(|| "Deployment confirmation carries no contract address".into())
+    }
+}
+
+impl<T: Config> OnXtxConcluded<T::Hash> for Pallet<T> {
+    fn on_xtx_concluded(xtx_id: T::Hash, status: &CircuitStatus) {
+        if let Some(deployment) = <PendingDeployments<T>>::take(xtx_id) {
+            if matches!(
+                status,
+                CircuitStatus::FinishedAllSteps | CircuitStatus::Committed
+            ) {
+                Self::record_remote_deployments(xtx_id, deployment);
+            }
+        }
+    }
 }
 
 impl<T: Config> ContractsRegistryT<T, T::Currency> for Pallet<T> {
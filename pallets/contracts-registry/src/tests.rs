@@ -18,9 +18,9 @@
 //! Unit tests for pallet contracts-registry.
 
 use circuit_mock_runtime::{
-    pallet_contracts_registry, pallet_contracts_registry::pallet::Error, ContractsRegistry,
-    ContractsRegistryStorage, ExtBuilder, Runtime, RuntimeEvent as Event, RuntimeOrigin as Origin,
-    ALICE, BOB,
+    pallet_contracts_registry, pallet_contracts_registry::pallet::Error, AccountId, Balance,
+    BlockNumber, ContractsRegistry, ContractsRegistryStorage, ExtBuilder, Runtime,
+    RuntimeEvent as Event, RuntimeOrigin as Origin, ALICE, BOB,
 };
 
 use frame_support::{assert_err, assert_ok};
@@ -632,6 +632,177 @@ fn purge_fails_if_origin_not_root() {
         })
 }
 
+fn authored_contract(
+    author: AccountId,
+    kind: ContractType,
+) -> RegistryContract<H256, AccountId, Balance, BlockNumber> {
+    RegistryContract {
+        code_txt: b"some_code".to_vec(),
+        bytes: vec![0, 97, 115, 109],
+        author: AuthorInfo::new(author, Some(10)),
+        abi: Some(b"[]".to_vec()),
+        action_descriptions: vec![],
+        info: None,
+        meta: ContractMetadata::new(
+            vec![],
+            b"contract 1".to_vec(),
+            kind,
+            vec![],
+            vec![],
+            None,
+            None,
+            None,
+            None,
+            None,
+        ),
+    }
+}
+
+#[test]
+fn register_contract_stores_it_under_the_signing_author() {
+    let test_contract = authored_contract(ALICE, ContractType::VolatileWasm);
+    let contract_id = test_contract.generate_id::<Runtime>();
+
+    ExtBuilder::default().build().execute_with(|| {
+        assert_err!(
+            ContractsRegistry::register_contract(Origin::signed(BOB), test_contract.clone()),
+            Error::<Runtime>::NotContractAuthor
+        );
+        assert_ok!(ContractsRegistry::register_contract(
+            Origin::signed(ALICE),
+            test_contract.clone()
+        ));
+        assert_eq!(
+            ContractsRegistryStorage::<Runtime>::get(contract_id),
+            Some(test_contract.clone())
+        );
+        assert_err!(
+            ContractsRegistry::register_contract(Origin::signed(ALICE), test_contract),
+            Error::<Runtime>::ContractAlreadyExists
+        );
+    })
+}
+
+#[test]
+fn register_contract_fails_for_system_contracts() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_err!(
+            ContractsRegistry::register_contract(
+                Origin::signed(ALICE),
+                authored_contract(ALICE, ContractType::System)
+            ),
+            Error::<Runtime>::SystemContractRequiresRoot
+        );
+    })
+}
+
+#[test]
+fn only_author_can_set_fees_and_purge_own_contract() {
+    let test_contract = authored_contract(ALICE, ContractType::VolatileEvm);
+    let contract_id = test_contract.generate_id::<Runtime>();
+
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(ContractsRegistry::register_contract(
+            Origin::signed(ALICE),
+            test_contract
+        ));
+
+        assert_err!(
+            ContractsRegistry::set_author_fees(Origin::signed(BOB), contract_id, Some(20)),
+            Error::<Runtime>::NotContractAuthor
+        );
+        assert_ok!(ContractsRegistry::set_author_fees(
+            Origin::signed(ALICE),
+            contract_id,
+            Some(20)
+        ));
+        assert_eq!(
+            ContractsRegistry::fetch_contract_by_id(contract_id)
+                .unwrap()
+                .author
+                .fees_per_single_use,
+            Some(20)
+        );
+
+        assert_err!(
+            ContractsRegistry::purge_own_contract(Origin::signed(BOB), contract_id),
+            Error::<Runtime>::NotContractAuthor
+        );
+        assert_ok!(ContractsRegistry::purge_own_contract(
+            Origin::signed(ALICE),
+            contract_id
+        ));
+        assert!(!ContractsRegistryStorage::<Runtime>::contains_key(
+            contract_id
+        ));
+    })
+}
+
+#[test]
+fn only_author_can_deploy_own_contract_onto_distinct_gateways() {
+    use t3rn_primitives::SpeedMode;
+
+    let test_contract = authored_contract(ALICE, ContractType::VolatileEvm);
+    let contract_id = test_contract.generate_id::<Runtime>();
+
+    ExtBuilder::default().build().execute_with(|| {
+        assert_err!(
+            ContractsRegistry::deploy_to_gateways(
+                Origin::signed(ALICE),
+                contract_id,
+                vec![*b"eth2"],
+                vec![],
+                10,
+                1,
+                SpeedMode::Finalized
+            ),
+            Error::<Runtime>::UnknownContract
+        );
+
+        assert_ok!(ContractsRegistry::register_contract(
+            Origin::signed(ALICE),
+            test_contract
+        ));
+
+        assert_err!(
+            ContractsRegistry::deploy_to_gateways(
+                Origin::signed(BOB),
+                contract_id,
+                vec![*b"eth2"],
+                vec![],
+                10,
+                1,
+                SpeedMode::Finalized
+            ),
+            Error::<Runtime>::NotContractAuthor
+        );
+        assert_err!(
+            ContractsRegistry::deploy_to_gateways(
+                Origin::signed(ALICE),
+                contract_id,
+                vec![],
+                vec![],
+                10,
+                1,
+                SpeedMode::Finalized
+            ),
+            Error::<Runtime>::NoDeploymentTargets
+        );
+        assert_err!(
+            ContractsRegistry::deploy_to_gateways(
+                Origin::signed(ALICE),
+                contract_id,
+                vec![*b"eth2", *b"sepl", *b"eth2"],
+                vec![],
+                10,
+                1,
+                SpeedMode::Finalized
+            ),
+            Error::<Runtime>::DuplicateDeploymentTarget
+        );
+    })
+}
+
 #[test]
 fn test_kind_validator() {
     let test_contract = RegistryContract {
@@ -5,6 +5,16 @@ use serde::{Deserialize, Serialize};
 use sp_runtime::RuntimeDebug;
 use sp_std::vec::Vec;
 pub use t3rn_primitives::contracts_registry::{RegistryContract, RegistryContractId};
+use t3rn_primitives::SpeedMode;
+
+/// A deployment of a registry contract onto remote gateways, awaiting its Xtx to conclude
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct PendingDeployment<ContractId> {
+    pub contract_id: ContractId,
+    /// Speed mode the deployment confirmations are verified under
+    pub speed_mode: SpeedMode,
+}
+
 /// The possible errors that can happen querying the storage of a contract.
 #[derive(Eq, PartialEq, Encode, Decode, Debug, Clone, TypeInfo)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
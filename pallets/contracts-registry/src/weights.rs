@@ -39,7 +39,10 @@ use sp_std::marker::PhantomData;
 pub trait WeightInfo {
     fn add_new_contract() -> Weight;
     fn purge() -> Weight;
+    fn register_contract() -> Weight;
+    fn set_author_fees() -> Weight;
     fn fetch_contracts() -> Weight;
+    fn deploy_to_gateways(n: u32) -> Weight;
 }
 
 /// Weights for pallet_contracts_registry using the Substrate node and recommended hardware.
@@ -60,6 +63,27 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     fn fetch_contracts() -> Weight {
         Weight::from_parts(53_000_000_u64, 0u64).saturating_add(T::DbWeight::get().reads(4_u64))
     }
+
+    fn register_contract() -> Weight {
+        Weight::from_parts(52_000_000_u64, 0u64)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+
+    fn set_author_fees() -> Weight {
+        Weight::from_parts(30_000_000_u64, 0u64)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+
+    fn deploy_to_gateways(n: u32) -> Weight {
+        Weight::from_parts(90_000_000_u64, 0u64)
+            .saturating_add(Weight::from_parts(25_000_000_u64, 0u64).saturating_mul(n as u64))
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n as u64)))
+            .saturating_add(T::DbWeight::get().writes(5_u64))
+            .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n as u64)))
+    }
 }
 
 // For backwards compatibility and tests
@@ -79,4 +103,25 @@ impl WeightInfo for () {
     fn fetch_contracts() -> Weight {
         Weight::from_parts(53_000_000_u64, 0u64).saturating_add(RocksDbWeight::get().reads(4_u64))
     }
+
+    fn register_contract() -> Weight {
+        Weight::from_parts(52_000_000_u64, 0u64)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn set_author_fees() -> Weight {
+        Weight::from_parts(30_000_000_u64, 0u64)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn deploy_to_gateways(n: u32) -> Weight {
+        Weight::from_parts(90_000_000_u64, 0u64)
+            .saturating_add(Weight::from_parts(25_000_000_u64, 0u64).saturating_mul(n as u64))
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n as u64)))
+            .saturating_add(RocksDbWeight::get().writes(5_u64))
+            .saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n as u64)))
+    }
 }
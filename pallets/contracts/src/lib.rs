@@ -142,6 +142,7 @@ pub use crate::{
     exec::Frame,
     migration::{MigrateSequence, Migration, NoopMigration},
     pallet::*,
+    patch3vm::{ThreeVmExtension, WasmXtxResultCallback},
     schedule::{HostFnWeights, InstructionWeights, Limits, Schedule},
     wasm::Determinism,
 };
@@ -1263,6 +1264,9 @@ impl<T: Config> Invokable<T> for CallInput<T> {
             debug_message,
             determinism,
         );
+        if let Ok(output) = &result {
+            patch3vm::post_declared_side_effects::<T>(output);
+        }
 
         match storage_meter.try_into_deposit(&origin) {
             Ok(storage_deposit) => InternalOutput {
@@ -1326,6 +1330,9 @@ impl<T: Config> Invokable<T> for InstantiateInput<T> {
                 &salt,
                 debug_message,
             );
+            if let Ok((_, output)) = &result {
+                patch3vm::post_declared_side_effects::<T>(output);
+            }
 
             storage_deposit = storage_meter.try_into_deposit(&contract_origin)?;
             result
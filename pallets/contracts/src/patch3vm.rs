@@ -1,7 +1,8 @@
-use crate::{Config, Determinism, Origin, Schedule};
+use crate::{CollectEvents, Config, DebugInfo, Determinism, Origin, Pallet, Schedule};
 use codec::{Decode, Encode, MaxEncodedLen};
 
-use frame_support::{dispatch::RawOrigin, pallet_prelude::Weight};
+use frame_support::{dispatch::RawOrigin, pallet_prelude::Weight, traits::Get};
+use frame_system::pallet_prelude::BlockNumberFor;
 
 use pallet_contracts_primitives::{
     ContractExecResult, ExecReturnValue, ReturnFlags, StorageDeposit,
@@ -15,19 +16,34 @@ use sp_runtime::{
 
 use sp_std::vec::Vec;
 use t3rn_primitives::{
+    circuit::LocalStateExecutionView,
     threevm::{
         GetState, ModuleOperations, Precompile, PrecompileArgs, PrecompileInvocation, ThreeVm,
+        XtxResultCallback,
     },
     SpeedMode,
 };
 use t3rn_sdk_primitives::{
-    signal::ExecutionSignal, state::SideEffects, GET_STATE_FUNCTION_CODE,
-    POST_SIGNAL_FUNCTION_CODE, SUBMIT_FUNCTION_CODE,
+    signal::ExecutionSignal,
+    state::{ConfirmedOutput, SideEffectStatus, SideEffects},
+    DECLARE_SIDE_EFFECTS_FUNCTION_CODE, GET_CONFIRMED_OUTPUTS_FUNCTION_CODE,
+    GET_SFX_STATUS_FUNCTION_CODE, GET_STATE_FUNCTION_CODE, POST_SIGNAL_FUNCTION_CODE,
+    SUBMIT_FUNCTION_CODE, XTX_RESULT_CALLBACK_SELECTOR,
 };
 
 const CONTRACTS_LOG_TARGET: &str = "runtime::contracts::chain_extension";
 const GET_STATE_LOG_TARGET: &str = "runtime::contracts::get_state";
 const SIGNAL_LOG_TARGET: &str = "runtime::contracts::signal";
+const SFX_LOG_TARGET: &str = "runtime::contracts::sfx";
+
+/// Storage reads needed by the circuit to load an execution: the xtx, its local state and its
+/// side effects.
+const LOAD_EXECUTION_READS: u64 = 3;
+/// Storage writes needed by the circuit to store a submitted step: the xtx, its local state and
+/// its side effects.
+const SUBMIT_STEP_WRITES: u64 = 3;
+/// Gas charged per byte of output written back into the contract's memory
+const WEIGHT_PER_OUTPUT_BYTE: Weight = Weight::from_parts(1, 0);
 
 #[derive(PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
 pub struct ComposableExecReturnValue {
@@ -91,6 +107,13 @@ impl<AccountId, Balance: Default, EventRecord> Contracts<AccountId, Balance, Eve
 // Chain extensions
 use crate::BalanceOf;
 
+/// Chain extension giving ink! contracts access to the circuit: reading the local state of an
+/// execution, submitting side effects to it, posting signals and tracking the status and
+/// confirmed outputs of its side effects.
+///
+/// Every call is charged to the contract's gas in terms of the storage accesses it causes in the
+/// circuit and the size of its input and output.
+#[derive(Default)]
 pub struct ThreeVmExtension;
 use crate::{
     chain_extension::{
@@ -105,7 +128,8 @@ impl<C: Config> ChainExtension<C> for ThreeVmExtension {
     where
         E: Ext<T = C>,
     {
-        let func_id = env.func_id() as u32;
+        // Function codes span the whole id passed by the contract
+        let func_id = (env.ext_id() as u32) << 16 | env.func_id() as u32;
         log::trace!(
             target: CONTRACTS_LOG_TARGET,
             "[ChainExtension]|call|func_id:{:}",
@@ -131,13 +155,10 @@ impl<C: Config> ChainExtension<C> for ThreeVmExtension {
                     Some(execution_id)
                 };
 
-                let raw_origin: RawOrigin<C::AccountId> = match env.ext().caller() {
-                    Origin::Signed(acc) => RawOrigin::Signed(acc.clone()),
-                    Origin::Root => RawOrigin::Root,
-                };
+                env.charge_weight(load_execution_weight::<C>())?;
 
                 let invocation = <C as Config>::ThreeVm::invoke(PrecompileArgs::GetState(
-                    C::RuntimeOrigin::from(raw_origin),
+                    caller_origin(env.ext()),
                     GetState {
                         xtx_id: execution_id,
                     },
@@ -153,7 +174,7 @@ impl<C: Config> ChainExtension<C> for ThreeVmExtension {
                     bytes,
                 );
 
-                env.write(&bytes[..], false, None)?;
+                env.write(&bytes[..], false, Some(WEIGHT_PER_OUTPUT_BYTE))?;
 
                 Ok(RetVal::Converging(0))
             },
@@ -162,17 +183,17 @@ impl<C: Config> ChainExtension<C> for ThreeVmExtension {
 
                 let arg: (SideEffects<C::AccountId, BalanceOf<C>, C::Hash>, SpeedMode) =
                     read_from_environment(&mut env)?;
+                env.charge_weight(submit_weight::<C>(arg.0.side_effects.len() as u64))?;
 
-                let raw_origin: RawOrigin<C::AccountId> = match env.ext().caller() {
-                    Origin::Signed(acc) => RawOrigin::Signed(acc.clone()),
-                    Origin::Root => RawOrigin::Root,
-                };
+                let invocation = <C as Config>::ThreeVm::invoke(
+                    PrecompileArgs::SubmitSideEffects(caller_origin(env.ext()), arg.0, arg.1),
+                )?;
+
+                // Hand the id of the execution back so the contract can track it
+                if let Some(state) = invocation.get_submit() {
+                    env.write(&state.xtx_id.encode(), false, Some(WEIGHT_PER_OUTPUT_BYTE))?;
+                }
 
-                <C as Config>::ThreeVm::invoke(PrecompileArgs::SubmitSideEffects(
-                    C::RuntimeOrigin::from(raw_origin),
-                    arg.0,
-                    arg.1,
-                ))?;
                 Ok(RetVal::Converging(0))
             },
             POST_SIGNAL_FUNCTION_CODE => {
@@ -181,16 +202,84 @@ impl<C: Config> ChainExtension<C> for ThreeVmExtension {
                 let signal: ExecutionSignal<C::Hash> = read_from_environment(&mut env)?;
                 log::debug!(target: SIGNAL_LOG_TARGET, "submitting signal {:?}", signal);
 
-                let raw_origin: RawOrigin<C::AccountId> = match env.ext().caller() {
-                    Origin::Signed(acc) => RawOrigin::Signed(acc.clone()),
-                    Origin::Root => RawOrigin::Root,
-                };
-                C::ThreeVm::invoke(PrecompileArgs::Signal(
-                    C::RuntimeOrigin::from(raw_origin),
-                    signal,
+                env.charge_weight(
+                    <C as frame_system::Config>::DbWeight::get()
+                        .reads_writes(LOAD_EXECUTION_READS, 1),
+                )?;
+
+                C::ThreeVm::invoke(PrecompileArgs::Signal(caller_origin(env.ext()), signal))?;
+                Ok(RetVal::Converging(0))
+            },
+            DECLARE_SIDE_EFFECTS_FUNCTION_CODE => {
+                let mut env = env.buf_in_buf_out();
+
+                let arg: (SideEffects<C::AccountId, BalanceOf<C>, C::Hash>, SpeedMode) =
+                    read_from_environment(&mut env)?;
+                // Posting happens after the execution, so the contract pays for it upfront
+                env.charge_weight(submit_weight::<C>(arg.0.side_effects.len() as u64))?;
+
+                // The contract itself requests the Xtx, paying for it from its own balance
+                let contract = env.ext().address().clone();
+                log::debug!(
+                    target: SFX_LOG_TARGET,
+                    "contract {:?} declares {} side effects",
+                    contract,
+                    arg.0.side_effects.len()
+                );
+                <C as Config>::ThreeVm::invoke(PrecompileArgs::DeclareSideEffects(
+                    RawOrigin::Signed(contract).into(),
+                    arg.0,
+                    arg.1,
                 ))?;
                 Ok(RetVal::Converging(0))
             },
+            GET_SFX_STATUS_FUNCTION_CODE => {
+                let mut env = env.buf_in_buf_out();
+
+                let execution_id: C::Hash = env.read_as()?;
+                env.charge_weight(load_execution_weight::<C>())?;
+
+                let state = load_execution::<C, E>(&mut env, execution_id)?;
+                let status: Vec<Vec<SideEffectStatus>> = state
+                    .hardened_side_effects
+                    .iter()
+                    .map(|step| {
+                        step.iter()
+                            .map(|sfx| SideEffectStatus::from(&sfx.confirmation_outcome))
+                            .collect()
+                    })
+                    .collect();
+                log::debug!(
+                    target: SFX_LOG_TARGET,
+                    "loaded sfx status for execution_id: {:?}, status: {:?}",
+                    execution_id,
+                    status
+                );
+
+                env.write(&status.encode(), false, Some(WEIGHT_PER_OUTPUT_BYTE))?;
+
+                Ok(RetVal::Converging(0))
+            },
+            GET_CONFIRMED_OUTPUTS_FUNCTION_CODE => {
+                let mut env = env.buf_in_buf_out();
+
+                let execution_id: C::Hash = env.read_as()?;
+                env.charge_weight(load_execution_weight::<C>())?;
+
+                let state = load_execution::<C, E>(&mut env, execution_id)?;
+                let outputs: Vec<ConfirmedOutput<C::AccountId, BlockNumberFor<C>, BalanceOf<C>>> =
+                    state.into_confirmed_outputs();
+                log::debug!(
+                    target: SFX_LOG_TARGET,
+                    "loaded {} confirmed outputs for execution_id: {:?}",
+                    outputs.len(),
+                    execution_id
+                );
+
+                env.write(&outputs.encode(), false, Some(WEIGHT_PER_OUTPUT_BYTE))?;
+
+                Ok(RetVal::Converging(0))
+            },
             n => {
                 log::error!(
                     target: CONTRACTS_LOG_TARGET,
@@ -207,6 +296,52 @@ impl<C: Config> RegisteredChainExtension<C> for () {
     const ID: u16 = 3330;
 }
 
+fn caller_origin<C, E>(ext: &E) -> C::RuntimeOrigin
+where
+    C: Config,
+    E: Ext<T = C>,
+{
+    let raw_origin: RawOrigin<C::AccountId> = match ext.caller() {
+        Origin::Signed(acc) => RawOrigin::Signed(acc.clone()),
+        Origin::Root => RawOrigin::Root,
+    };
+    C::RuntimeOrigin::from(raw_origin)
+}
+
+/// Load an existing execution from the circuit on behalf of the calling contract
+fn load_execution<C, E>(
+    env: &mut Environment<E, BufInBufOutState>,
+    execution_id: C::Hash,
+) -> Result<LocalStateExecutionView<C, BalanceOf<C>>, DispatchError>
+where
+    C: Config,
+    E: Ext<T = C>,
+{
+    match <C as Config>::ThreeVm::invoke(PrecompileArgs::GetState(
+        caller_origin(env.ext()),
+        GetState {
+            xtx_id: Some(execution_id),
+        },
+    ))? {
+        PrecompileInvocation::GetState(state) => Ok(state),
+        _ => Err("NoStateReturned".into()),
+    }
+}
+
+fn load_execution_weight<C: Config>() -> Weight {
+    <C as frame_system::Config>::DbWeight::get().reads(LOAD_EXECUTION_READS)
+}
+
+fn submit_weight<C: Config>(side_effects: u64) -> Weight {
+    <C as frame_system::Config>::DbWeight::get()
+        .reads_writes(LOAD_EXECUTION_READS, SUBMIT_STEP_WRITES)
+        .saturating_add(
+            <C as frame_system::Config>::DbWeight::get()
+                .writes(1)
+                .saturating_mul(side_effects),
+        )
+}
+
 fn read_from_environment<C, T, E>(
     env: &mut Environment<E, BufInBufOutState>,
 ) -> Result<T, DispatchError>
@@ -259,6 +394,49 @@ pub fn try_instantiate_from_contracts_registry<T: Config>(
     Ok((module, fee))
 }
 
+// Used in src/lib.rs
+pub fn post_declared_side_effects<T: Config>(output: &ExecReturnValue) {
+    // Declarations of reverted frames were rolled back along with the rest of their storage
+    if !output.did_revert() {
+        T::ThreeVm::post_declared_side_effects();
+    }
+}
+
+/// Delivers the results of concluded Xtx to the WASM contracts that originated them, calling
+/// into them at `XTX_RESULT_CALLBACK_SELECTOR` on their own behalf.
+pub struct WasmXtxResultCallback<T>(sp_std::marker::PhantomData<T>);
+
+impl<T: Config> XtxResultCallback<T::AccountId> for WasmXtxResultCallback<T> {
+    fn is_contract(contract: &T::AccountId) -> bool {
+        Pallet::<T>::code_hash(contract).is_some()
+    }
+
+    fn deliver(
+        contract: &T::AccountId,
+        result: Vec<u8>,
+        gas_limit: Weight,
+    ) -> Result<Weight, DispatchError> {
+        let data = [&XTX_RESULT_CALLBACK_SELECTOR[..], &result[..]].concat();
+        let outcome = Pallet::<T>::bare_call(
+            contract.clone(),
+            contract.clone(),
+            Zero::zero(),
+            gas_limit,
+            None,
+            data,
+            DebugInfo::Skip,
+            CollectEvents::Skip,
+            Determinism::Enforced,
+        );
+
+        match outcome.result {
+            Ok(output) if !output.did_revert() => Ok(outcome.gas_consumed),
+            Ok(_) => Err("Xtx result callback reverted".into()),
+            Err(err) => Err(err),
+        }
+    }
+}
+
 pub fn try_submit_side_effects<T: Config>(
     caller: &T::AccountId,
     mut input_data: &[u8],
@@ -3,14 +3,26 @@
 extern crate alloc;
 
 use alloc::vec::Vec;
+use codec::Encode;
 use core::marker::PhantomData;
 use fp_evm::{
     ExitError, ExitSucceed, Precompile as EvmPrecompile, PrecompileFailure, PrecompileHandle,
     PrecompileOutput, PrecompileResult,
 };
-use t3rn_primitives::threevm::Precompile;
+use frame_support::traits::Get;
+use pallet_evm::{AddressMapping, GasWeightMapping};
+use t3rn_primitives::{
+    threevm::{Precompile, DECLARE},
+    T3rnCodec,
+};
+
+/// Storage reads and writes of the circuit posting the declared side effects as an Xtx once the
+/// execution succeeds, charged upfront to the declaring contract.
+const POST_READS: u64 = 3;
+const POST_WRITES: u64 = 4;
 
-// TODO: build this for the next phase of evm artifacts.
+/// Lets EVM contracts declare SCALE encoded side effects along with their speed mode. They are
+/// posted to the circuit as an Xtx requested by the contract once its execution succeeds.
 pub struct ThreeVmDispatch<T> {
     _marker: PhantomData<T>,
 }
@@ -20,22 +32,34 @@ where
     T: pallet_evm::Config,
 {
     fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
-        let input = handle.input();
-        let _target_gas = handle.gas_limit();
-        let _context = handle.context();
+        handle.record_cost(T::GasWeightMapping::weight_to_gas(
+            <T as frame_system::Config>::DbWeight::get().reads_writes(POST_READS, POST_WRITES),
+        ))?;
+
+        // The declaring contract is the caller of the precompile
+        let contract = T::AddressMapping::into_account_id(handle.context().caller);
+        let args = [
+            &[T3rnCodec::Scale.into()][..],
+            &contract.encode()[..],
+            handle.input(),
+        ]
+        .concat();
 
         let mut output = Vec::new();
-        T::ThreeVm::invoke_raw(&55_u8, input, &mut output); // TODO: dummy ptr for now
+        T::ThreeVm::invoke_raw(&DECLARE, &args, &mut output);
 
-        if !output.is_empty() {
-            Ok(PrecompileOutput {
+        // The output is an encoded `Result<(), Error>`, starting with 0 if it's an ok
+        match output.first() {
+            Some(0) => Ok(PrecompileOutput {
                 exit_status: ExitSucceed::Stopped,
                 output,
-            })
-        } else {
-            Err(PrecompileFailure::Error {
+            }),
+            Some(_) => Err(PrecompileFailure::Error {
+                exit_status: ExitError::Other("failed to declare side effects".into()),
+            }),
+            None => Err(PrecompileFailure::Error {
                 exit_status: ExitError::Other("invalid output".into()),
-            })
+            }),
         }
     }
 }
@@ -0,0 +1,21 @@
+// SPDX-License-Identifier: Apache-2.0
+pragma solidity ^0.8.0;
+
+/// Portal verification precompile, deployed at 0x0000000000000000000000000000000000000F01.
+/// Speed modes: 0 = Fast, 1 = Rational, 2 = Finalized, 3 = Instant.
+interface IPortalVerification {
+    function latestFinalizedHeight(bytes4 gatewayId) external view returns (uint256);
+
+    function verifyEventInclusion(
+        bytes4 gatewayId,
+        uint8 speedMode,
+        bytes32 source,
+        bytes calldata proof
+    ) external view returns (uint256 height, bytes memory message);
+
+    function verifyTxInclusion(
+        bytes4 gatewayId,
+        uint8 speedMode,
+        bytes calldata proof
+    ) external view returns (uint256 height, bytes memory message);
+}
@@ -1,17 +1,42 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+use codec::{Decode, Encode};
 use fp_evm::{
     ExitError, ExitSucceed, Precompile as EvmPrecompile, PrecompileFailure, PrecompileHandle,
     PrecompileOutput, PrecompileResult,
 };
-use frame_support::{sp_runtime::app_crypto::sp_core, traits::Currency};
+use frame_support::{
+    sp_runtime::{app_crypto::sp_core, SaturatedConversion},
+    traits::Currency,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use pallet_evm::{AddressMapping, GasWeightMapping};
+use sp_core::{H160, H256, U256};
 use sp_std::{marker::PhantomData, vec::Vec};
 use t3rn_primitives::{
+    light_client::{HeightResult, InclusionReceipt},
+    portal::{Portal, PrecompileArgs as PortalPrecompileArgs},
     threevm::{Precompile, PORTAL},
-    T3rnCodec,
+    ChainId, SpeedMode, T3rnCodec,
+};
+
+use precompile_util_solidity::{
+    data::{Bytes, EvmData, EvmDataReader, EvmDataWriter},
+    error,
+    handle::PrecompileHandleExt,
+    modifier::FunctionModifier,
+    revert,
+    substrate::RuntimeHelper,
+    succeed, EvmResult,
 };
 
-use precompile_util_solidity::data::EvmData;
+#[precompile_util_macro::generate_function_selector]
+#[derive(Debug, PartialEq)]
+pub enum Action {
+    LatestFinalizedHeight = "latestFinalizedHeight(bytes4)",
+    VerifyEventInclusion = "verifyEventInclusion(bytes4,uint8,bytes32,bytes)",
+    VerifyTxInclusion = "verifyTxInclusion(bytes4,uint8,bytes)",
+}
 
 pub struct PortalPrecompile<T>(PhantomData<T>);
 
@@ -60,3 +85,121 @@ where
         }
     }
 }
+
+/// Solidity ABI interface to the Portal, letting EVM contracts verify the inclusion of events
+/// and transactions on any registered gateway and read its latest finalized height.
+/// Verifications are charged the Portal's verification weight for the proof's length.
+pub struct PortalVerificationPrecompile<T>(PhantomData<T>);
+
+impl<T> EvmPrecompile for PortalVerificationPrecompile<T>
+where
+    T: pallet_evm::Config + pallet_portal::Config,
+{
+    fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+        let selector = handle.read_selector()?;
+        handle.check_function_modifier(FunctionModifier::View)?;
+        handle.record_cost(RuntimeHelper::<T>::db_read_gas_cost())?;
+
+        let mut input = handle.read_input()?;
+        let args = match selector {
+            Action::LatestFinalizedHeight => {
+                input.expect_arguments(1)?;
+                PortalPrecompileArgs::GetFinalizedHeight(Self::read_gateway_id(&mut input)?)
+            },
+            Action::VerifyEventInclusion => {
+                input.expect_arguments(4)?;
+                let gateway_id = Self::read_gateway_id(&mut input)?;
+                let speed_mode = Self::read_speed_mode(&mut input)?;
+                let source: H256 = input.read()?;
+                let proof: Bytes = input.read()?;
+                handle.record_cost(Self::verification_gas_cost(proof.0.len()))?;
+                PortalPrecompileArgs::VerifyEventInclusion(
+                    gateway_id,
+                    speed_mode,
+                    source.to_fixed_bytes(),
+                    proof.0,
+                )
+            },
+            Action::VerifyTxInclusion => {
+                input.expect_arguments(3)?;
+                let gateway_id = Self::read_gateway_id(&mut input)?;
+                let speed_mode = Self::read_speed_mode(&mut input)?;
+                let proof: Bytes = input.read()?;
+                handle.record_cost(Self::verification_gas_cost(proof.0.len()))?;
+                PortalPrecompileArgs::VerifyTxInclusion(gateway_id, speed_mode, proof.0)
+            },
+        };
+
+        let result = Self::invoke_portal(handle.context().caller, args)?;
+
+        match selector {
+            Action::LatestFinalizedHeight =>
+                match HeightResult::<BlockNumberFor<T>>::decode(&mut &result[..]) {
+                    Ok(HeightResult::Height(height)) => Ok(succeed(
+                        EvmDataWriter::new()
+                            .write(U256::from(height.saturated_into::<u64>()))
+                            .build(),
+                    )),
+                    Ok(HeightResult::NotActive) => Err(revert("Gateway not active")),
+                    Err(_) => Err(error("Invalid portal output")),
+                },
+            Action::VerifyEventInclusion | Action::VerifyTxInclusion =>
+                match InclusionReceipt::<BlockNumberFor<T>>::decode(&mut &result[..]) {
+                    Ok(receipt) => Ok(succeed(
+                        EvmDataWriter::new()
+                            .write(U256::from(receipt.height.saturated_into::<u64>()))
+                            .write(Bytes(receipt.message))
+                            .build(),
+                    )),
+                    Err(_) => Err(error("Invalid portal output")),
+                },
+        }
+    }
+}
+
+impl<T> PortalVerificationPrecompile<T>
+where
+    T: pallet_evm::Config + pallet_portal::Config,
+{
+    /// Gas covering the Portal's verification of an inclusion proof of `proof_len` bytes
+    fn verification_gas_cost(proof_len: usize) -> u64 {
+        <T as pallet_evm::Config>::GasWeightMapping::weight_to_gas(
+            <pallet_portal::Pallet<T> as Portal<T>>::verify_event_inclusion_weight(
+                proof_len.saturated_into(),
+            ),
+        )
+    }
+
+    /// Gateway ids are passed as `bytes4`, left-aligned in the argument word
+    fn read_gateway_id(input: &mut EvmDataReader) -> EvmResult<ChainId> {
+        let word: H256 = input.read()?;
+        let mut gateway_id: ChainId = Default::default();
+        gateway_id.copy_from_slice(&word.as_bytes()[..4]);
+        Ok(gateway_id)
+    }
+
+    fn read_speed_mode(input: &mut EvmDataReader) -> EvmResult<SpeedMode> {
+        let speed_mode: u8 = input.read()?;
+        SpeedMode::decode(&mut &[speed_mode][..]).map_err(|_| revert("Invalid speed mode"))
+    }
+
+    /// Call the Portal through the 3VM on behalf of the caller, returning the SCALE encoded result
+    fn invoke_portal(caller: H160, args: PortalPrecompileArgs) -> EvmResult<Vec<u8>> {
+        let caller = <T as pallet_evm::Config>::AddressMapping::into_account_id(caller);
+        let raw_args = [
+            &[T3rnCodec::Scale.into()][..],
+            &caller.encode()[..],
+            &args.encode()[..],
+        ]
+        .concat();
+
+        let mut output = Vec::new();
+        T::ThreeVm::invoke_raw(&PORTAL, &raw_args, &mut output);
+
+        match output.split_first() {
+            Some((0, result)) => Ok(result.to_vec()),
+            Some(_) => Err(revert("Portal verification failed")),
+            None => Err(error("Empty buffer")),
+        }
+    }
+}
@@ -6,18 +6,20 @@ pub use pallet_3vm_evm_primitives::{
 
 use frame_support::traits::Currency;
 use pallet_3vm_evm_primitives::{ExitError, PrecompileFailure};
+pub use pallet_evm_precompile_3vm_dispatch::ThreeVmDispatch;
 pub use pallet_evm_precompile_modexp::Modexp;
 pub use pallet_evm_precompile_sha3fips::{Sha3FIPS256, Sha3FIPS512};
 pub use pallet_evm_precompile_simple::{
     ECRecover, ECRecoverPublicKey, Identity, Ripemd160, Sha256,
 };
 
-use portal_precompile::PortalPrecompile;
+use portal_precompile::{PortalPrecompile, PortalVerificationPrecompile};
 use precompile_util_solidity::data::EvmData;
 use sp_core::H160;
 use sp_std::{collections::btree_map::BTreeMap, marker::PhantomData, vec::Vec};
 use tokens_precompile::TokensPrecompile;
 use vacuum_precompile::VacuumPrecompile;
+use xdns_precompile::XdnsPrecompile;
 
 pub mod precompile_mock;
 
@@ -57,7 +59,10 @@ where
     >,
     TokensPrecompile<T>: Precompile,
     PortalPrecompile<T>: Precompile,
+    PortalVerificationPrecompile<T>: Precompile,
+    ThreeVmDispatch<T>: Precompile,
     VacuumPrecompile<T>: Precompile,
+    XdnsPrecompile<T>: Precompile,
 {
     fn execute(&self, handle: &mut impl PrecompileHandle) -> Option<PrecompileResult> {
         let address = handle.code_address();
@@ -83,6 +88,9 @@ where
             // t3rn precompiles
             a if H160([7u8; 20]) == a => Some(PortalPrecompile::<T>::execute(handle)),
             a if H160([8u8; 20]) == a => Some(VacuumPrecompile::<T>::execute(handle)),
+            a if hash(&0x0F01) == a => Some(PortalVerificationPrecompile::<T>::execute(handle)),
+            a if hash(&0x0F02) == a => Some(ThreeVmDispatch::<T>::execute(handle)),
+            a if hash(&0x0F03) == a => Some(XdnsPrecompile::<T>::execute(handle)),
             a if &a.to_fixed_bytes()[0..16] == TOKENS_PRECOMPILE_PREFIX =>
                 Some(TokensPrecompile::<T>::execute(handle)),
             // Default
@@ -101,6 +109,9 @@ where
             || address == hash(&102)
             || address == hash(&103)
             || address == hash(&10001)
+            || address == hash(&0x0F01)
+            || address == hash(&0x0F02)
+            || address == hash(&0x0F03)
             || &address.to_fixed_bytes()[0..16] == TOKENS_PRECOMPILE_PREFIX)
         {
             is_precompile_result = true;
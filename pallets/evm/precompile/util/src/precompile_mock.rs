@@ -5,12 +5,13 @@ use pallet_3vm_evm_primitives::{Precompile, PrecompileResult};
 use pallet_evm_precompile_modexp::Modexp;
 use pallet_evm_precompile_sha3fips::{Sha3FIPS256, Sha3FIPS512};
 use pallet_evm_precompile_simple::{ECRecover, ECRecoverPublicKey, Identity, Ripemd160, Sha256};
-use portal_precompile::PortalPrecompile;
+use portal_precompile::{PortalPrecompile, PortalVerificationPrecompile};
 use precompile_util_solidity::data::EvmData;
 use sp_core::H160;
 use sp_std::{collections::btree_map::BTreeMap, marker::PhantomData, vec::Vec};
 use tokens_precompile::TokensPrecompile;
 use vacuum_precompile::VacuumPrecompile;
+use xdns_precompile::XdnsPrecompile;
 
 pub enum KnownPrecompile<T: pallet_3vm_evm::Config + pallet_assets::Config + frame_system::Config>
 where
@@ -39,8 +40,10 @@ where
     ECRecoverPublicKey,
     // T3rn precompiles:
     Portal,
+    PortalVerification,
     Tokens,
     Vacuum,
+    Xdns,
     Noop(T),
 }
 
@@ -58,6 +61,8 @@ where
             <T as frame_system::pallet::Config>::AccountId,
         >>::Balance,
     >,
+    PortalVerificationPrecompile<T>: Precompile,
+    XdnsPrecompile<T>: Precompile,
 {
     pub fn execute(&self, handle: &mut impl PrecompileHandle) -> PrecompileResult {
         match self {
@@ -73,8 +78,11 @@ where
             KnownPrecompile::ECRecoverPublicKey =>
                 <ECRecoverPublicKey as Precompile>::execute(handle),
             KnownPrecompile::Portal => PortalPrecompile::<T>::execute(handle),
+            KnownPrecompile::PortalVerification =>
+                PortalVerificationPrecompile::<T>::execute(handle),
             KnownPrecompile::Tokens => TokensPrecompile::<T>::execute(handle),
             KnownPrecompile::Vacuum => VacuumPrecompile::<T>::execute(handle),
+            KnownPrecompile::Xdns => XdnsPrecompile::<T>::execute(handle),
             KnownPrecompile::Noop(_) => PrecompileResult::Err(PrecompileFailure::from(
                 ExitError::Other("Noop precompile".into()),
             )),
@@ -147,6 +155,8 @@ where
             <T as frame_system::pallet::Config>::AccountId,
         >>::Balance,
     >,
+    PortalVerificationPrecompile<T>: Precompile,
+    XdnsPrecompile<T>: Precompile,
 {
     fn execute(&self, handle: &mut impl PrecompileHandle) -> Option<PrecompileResult> {
         self.inner
@@ -0,0 +1,16 @@
+// SPDX-License-Identifier: Apache-2.0
+pragma solidity ^0.8.0;
+
+/// XDNS gateway discovery precompile, deployed at 0x0000000000000000000000000000000000000F03.
+/// Vendors: 0 = Polkadot, 1 = Kusama, 2 = Rococo, 3 = Ethereum, 4 = Sepolia, 5 = XBI, 6 = Attesters.
+interface IXdns {
+    function gatewayVendor(bytes4 gatewayId) external view returns (uint8);
+
+    function isGatewayActive(bytes4 gatewayId) external view returns (bool);
+
+    function allowedSideEffects(bytes4 gatewayId) external view returns (bytes4[] memory);
+
+    function feeEstimate(bytes4 gatewayId, uint32 assetId) external view returns (uint256);
+
+    function bestGatewayFor(bytes4 sfxId, uint32 assetId) external view returns (bytes4);
+}
@@ -0,0 +1,187 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Encode;
+use fp_evm::{Precompile as EvmPrecompile, PrecompileHandle, PrecompileResult};
+use frame_support::traits::Get;
+use pallet_xdns::BalanceOf;
+use precompile_util_solidity::{
+    data::{EvmDataReader, EvmDataWriter},
+    handle::PrecompileHandleExt,
+    modifier::FunctionModifier,
+    revert,
+    substrate::RuntimeHelper,
+    succeed, EvmResult,
+};
+use sp_core::{H256, U256};
+use sp_runtime::SaturatedConversion;
+use sp_std::{marker::PhantomData, vec::Vec};
+use t3rn_primitives::{xdns::Xdns, ChainId};
+
+#[precompile_util_macro::generate_function_selector]
+#[derive(Debug, PartialEq)]
+pub enum Action {
+    GatewayVendor = "gatewayVendor(bytes4)",
+    IsGatewayActive = "isGatewayActive(bytes4)",
+    AllowedSideEffects = "allowedSideEffects(bytes4)",
+    FeeEstimate = "feeEstimate(bytes4,uint32)",
+    BestGatewayFor = "bestGatewayFor(bytes4,uint32)",
+    EstimateXtxCost = "estimateXtxCost(bytes4[],uint32[],uint256[],uint32)",
+}
+
+/// Read-only Solidity ABI interface to XDNS, letting EVM contracts discover registered gateways,
+/// their operational status, allowed side effects and fee estimates to pick targets on the fly,
+/// and quote the cost of an Xtx before ordering it.
+pub struct XdnsPrecompile<T>(PhantomData<T>);
+
+impl<T> EvmPrecompile for XdnsPrecompile<T>
+where
+    T: pallet_evm::Config + pallet_xdns::Config,
+{
+    fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+        let selector = handle.read_selector()?;
+        handle.check_function_modifier(FunctionModifier::View)?;
+        handle.record_cost(RuntimeHelper::<T>::db_read_gas_cost())?;
+
+        let mut input = handle.read_input()?;
+        match selector {
+            Action::GatewayVendor => {
+                input.expect_arguments(1)?;
+                let gateway_id = Self::read_4b_id(&mut input)?;
+                let vendor =
+                    <pallet_xdns::Pallet<T> as Xdns<T, BalanceOf<T>>>::get_verification_vendor(
+                        &gateway_id,
+                    )
+                    .map_err(|_| revert("Gateway not found"))?;
+                // Vendors are exposed by their SCALE discriminant
+                let discriminant = vendor.encode().first().copied().unwrap_or_default();
+                Ok(succeed(EvmDataWriter::new().write(discriminant).build()))
+            },
+            Action::IsGatewayActive => {
+                input.expect_arguments(1)?;
+                let gateway_id = Self::read_4b_id(&mut input)?;
+                let is_active =
+                    <pallet_xdns::Pallet<T> as Xdns<T, BalanceOf<T>>>::read_last_activity(
+                        gateway_id,
+                    )
+                    .map(|activity| activity.is_active)
+                    .unwrap_or(false);
+                Ok(succeed(EvmDataWriter::new().write(is_active).build()))
+            },
+            Action::AllowedSideEffects => {
+                input.expect_arguments(1)?;
+                let gateway_id = Self::read_4b_id(&mut input)?;
+                let allowed_side_effects: Vec<H256> =
+                    <pallet_xdns::Pallet<T> as Xdns<T, BalanceOf<T>>>::allowed_side_effects(
+                        &gateway_id,
+                    )
+                    .into_iter()
+                    .map(|(sfx_4b_id, _)| Self::to_4b_word(sfx_4b_id))
+                    .collect();
+                Ok(succeed(
+                    EvmDataWriter::new().write(allowed_side_effects).build(),
+                ))
+            },
+            Action::FeeEstimate => {
+                input.expect_arguments(2)?;
+                let gateway_id = Self::read_4b_id(&mut input)?;
+                let asset_id: u32 = input.read()?;
+                // Estimates are quoted in the native currency
+                let fee_estimate = pallet_xdns::Pallet::<T>::per_target_asset_estimates(
+                    gateway_id,
+                    (asset_id, T::SelfTokenId::get()),
+                );
+                Ok(succeed(
+                    EvmDataWriter::new()
+                        .write(U256::from(fee_estimate.saturated_into::<u128>()))
+                        .build(),
+                ))
+            },
+            Action::BestGatewayFor => {
+                input.expect_arguments(2)?;
+                let sfx_4b_id = Self::read_4b_id(&mut input)?;
+                let asset_id: u32 = input.read()?;
+                // Ranking reads the record, tokens and SFX ABI of each registered gateway
+                handle.record_cost(
+                    RuntimeHelper::<T>::db_read_gas_cost()
+                        .saturating_mul(3)
+                        .saturating_mul(pallet_xdns::Pallet::<T>::all_gateway_ids().len() as u64),
+                )?;
+                let gateway_id =
+                    <pallet_xdns::Pallet<T> as Xdns<T, BalanceOf<T>>>::best_gateway_for(
+                        &sfx_4b_id, asset_id,
+                    )
+                    .ok_or_else(|| revert("No active gateway"))?;
+                Ok(succeed(
+                    EvmDataWriter::new()
+                        .write(Self::to_4b_word(gateway_id))
+                        .build(),
+                ))
+            },
+            Action::EstimateXtxCost => {
+                input.expect_arguments(4)?;
+                let targets: Vec<H256> = input.read()?;
+                let assets: Vec<u32> = input.read()?;
+                let insurances: Vec<U256> = input.read()?;
+                let quote_asset: u32 = input.read()?;
+                if targets.len() != assets.len() || targets.len() != insurances.len() {
+                    return Err(revert("Side effect arguments differ in length"))
+                }
+                // Each side effect reads its fee estimate, security tier and activity stats
+                handle.record_cost(
+                    RuntimeHelper::<T>::db_read_gas_cost()
+                        .saturating_mul(3)
+                        .saturating_mul(targets.len() as u64),
+                )?;
+
+                let side_effects = targets
+                    .iter()
+                    .zip(assets)
+                    .zip(insurances)
+                    .map(|((target, asset_id), insurance)| {
+                        let mut gateway_id: ChainId = Default::default();
+                        gateway_id.copy_from_slice(&target.as_bytes()[..4]);
+                        let insurance = u128::try_from(insurance)
+                            .map_err(|_| revert("Insurance out of bounds"))?;
+                        Ok((
+                            gateway_id,
+                            asset_id,
+                            insurance.saturated_into::<BalanceOf<T>>(),
+                        ))
+                    })
+                    .collect::<EvmResult<Vec<_>>>()?;
+                let estimate = <pallet_xdns::Pallet<T> as Xdns<T, BalanceOf<T>>>::estimate_xtx_cost(
+                    &side_effects,
+                    quote_asset,
+                );
+
+                Ok(succeed(
+                    EvmDataWriter::new()
+                        .write(U256::from(estimate.fees.saturated_into::<u128>()))
+                        .write(U256::from(estimate.insurance.saturated_into::<u128>()))
+                        .write(U256::from(estimate.min_bid.saturated_into::<u128>()))
+                        .write(U256::from(estimate.max_bid.saturated_into::<u128>()))
+                        .build(),
+                ))
+            },
+        }
+    }
+}
+
+impl<T> XdnsPrecompile<T>
+where
+    T: pallet_evm::Config + pallet_xdns::Config,
+{
+    /// Gateway and side effect ids are passed as `bytes4`, left-aligned in the argument word
+    fn read_4b_id(input: &mut EvmDataReader) -> EvmResult<ChainId> {
+        let word: H256 = input.read()?;
+        let mut id: ChainId = Default::default();
+        id.copy_from_slice(&word.as_bytes()[..4]);
+        Ok(id)
+    }
+
+    fn to_4b_word(id: [u8; 4]) -> H256 {
+        let mut word = H256::zero();
+        word.as_bytes_mut()[..4].copy_from_slice(&id);
+        word
+    }
+}
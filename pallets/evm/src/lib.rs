@@ -100,7 +100,10 @@ pub use fp_evm::{
     PrecompileFailure, PrecompileHandle, PrecompileOutput, PrecompileResult, PrecompileSet,
     Vicinity,
 };
-use t3rn_primitives::threevm::{convert_decimals_to_evm, Erc20Mapping, ThreeVm};
+use t3rn_primitives::threevm::{
+    convert_decimals_to_evm, AddressMapping as ThreeVmAddressMapping, Erc20Mapping, ThreeVm,
+    XtxResultCallback,
+};
 
 pub use self::{
     pallet::*,
@@ -893,6 +896,78 @@ impl<T: Config> GasWeightMapping for FixedGasWeightMapping<T> {
     }
 }
 
+/// Meters the 3VM in the same gas as EVM transactions, so that limits are consistent across VMs.
+impl<T: Config> t3rn_primitives::threevm::UnifiedGas for FixedGasWeightMapping<T> {
+    fn gas_to_weight(gas: u64) -> Weight {
+        <Self as GasWeightMapping>::gas_to_weight(gas, false)
+    }
+
+    fn weight_to_gas(weight: Weight) -> u64 {
+        <Self as GasWeightMapping>::weight_to_gas(weight)
+    }
+}
+
+/// Delivers the results of concluded Xtx to the EVM contracts that originated them, calling into
+/// their `onXtxResult(bytes)` on their own behalf.
+pub struct EvmXtxResultCallback<T, Mapping>(sp_std::marker::PhantomData<(T, Mapping)>);
+
+impl<T, Mapping> EvmXtxResultCallback<T, Mapping> {
+    /// ABI encode the `onXtxResult(bytes)` call, passing the SCALE encoded result as its argument
+    fn encode_call(result: &[u8]) -> Vec<u8> {
+        let mut input = sp_io::hashing::keccak_256(b"onXtxResult(bytes)")[..4].to_vec();
+        input.extend_from_slice(H256::from_low_u64_be(32).as_bytes());
+        input.extend_from_slice(H256::from_low_u64_be(result.len() as u64).as_bytes());
+        input.extend_from_slice(result);
+        input.resize(input.len() + (32 - result.len() % 32) % 32, 0);
+        input
+    }
+}
+
+impl<T, Mapping> XtxResultCallback<T::AccountId> for EvmXtxResultCallback<T, Mapping>
+where
+    T: Config,
+    Mapping: ThreeVmAddressMapping<T::AccountId>,
+{
+    fn is_contract(contract: &T::AccountId) -> bool {
+        Mapping::get_evm_address(contract)
+            .map(|address| <AccountCodes<T>>::contains_key(address))
+            .unwrap_or(false)
+    }
+
+    fn deliver(
+        contract: &T::AccountId,
+        result: Vec<u8>,
+        gas_limit: Weight,
+    ) -> Result<Weight, sp_runtime::DispatchError> {
+        let address = Mapping::get_evm_address(contract).ok_or("Contract has no EVM address")?;
+        let info = T::Runner::call(
+            address,
+            address,
+            Self::encode_call(&result),
+            U256::zero(),
+            T::GasWeightMapping::weight_to_gas(gas_limit),
+            None,
+            None,
+            None,
+            Vec::new(),
+            false,
+            false,
+            Some(gas_limit),
+            None,
+            T::config(),
+        )
+        .map_err(|err| err.error.into())?;
+
+        if !info.exit_reason.is_succeed() {
+            return Err("Xtx result callback reverted".into())
+        }
+        Ok(T::GasWeightMapping::gas_to_weight(
+            info.used_gas.effective.unique_saturated_into(),
+            true,
+        ))
+    }
+}
+
 static SHANGHAI_CONFIG: EvmConfig = EvmConfig::shanghai();
 
 impl<T: Config> Pallet<T> {
@@ -47,7 +47,7 @@ use fp_evm::{
     Vicinity, WeightInfo, ACCOUNT_BASIC_PROOF_SIZE, ACCOUNT_CODES_METADATA_PROOF_SIZE,
     ACCOUNT_STORAGE_PROOF_SIZE, IS_EMPTY_CHECK_PROOF_SIZE, WRITE_PROOF_SIZE,
 };
-use t3rn_primitives::threevm::{convert_decimals_from_evm, DECIMALS_VALUE};
+use t3rn_primitives::threevm::{convert_decimals_from_evm, ThreeVm, DECIMALS_VALUE};
 
 use crate::{
     runner::Runner as RunnerT, AccountCodes, AccountCodesMetadata, AccountStorages, AddressMapping,
@@ -268,6 +268,11 @@ where
 
         let (reason, retv) = f(&mut executor);
 
+        // Side effects declared by reverted calls were rolled back along with their substate
+        if reason.is_succeed() {
+            T::ThreeVm::post_declared_side_effects();
+        }
+
         // Post execution.
         let used_gas = executor.used_gas();
         let effective_gas = match executor.state().weight_info() {
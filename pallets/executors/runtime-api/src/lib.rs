@@ -0,0 +1,40 @@
+//! Runtime API definition exposing the executors registry to executor clients.
+//!
+//! This API should be imported and implemented by the runtime
+//! including the executors pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::prelude::*;
+use t3rn_primitives::{
+    common::RoundIndex,
+    executors::{ExecutorRoundMetrics, ExecutorStanding, NominatedStake},
+};
+
+sp_api::decl_runtime_apis! {
+    /// The API to interact with pallet Executors
+    #[api_version(4)]
+    pub trait ExecutorsRuntimeApi<AccountId, Balance> where
+        AccountId: Codec,
+        Balance: Codec,
+    {
+        /// Returns the executors selected into the active set of the current round
+        fn active_set() -> Vec<AccountId>;
+        /// Returns the executor's metrics for every retained round, oldest first
+        #[api_version(2)]
+        fn executor_metrics(executor: AccountId) -> Vec<(RoundIndex, ExecutorRoundMetrics<Balance>)>;
+        /// Returns the liquidity executors offer for the given side effect (4b id) on the given gateway
+        #[api_version(3)]
+        fn corridor_liquidity(target: [u8; 4], sfx_4b_id: [u8; 4]) -> Balance;
+        /// Returns the self-bond, delegations and capabilities of the executor, if registered
+        #[api_version(4)]
+        fn executor_standing(executor: AccountId) -> Option<ExecutorStanding<AccountId, Balance>>;
+        /// Returns the self-bonds, delegations and capabilities of all registered executors
+        #[api_version(4)]
+        fn executor_standings() -> Vec<ExecutorStanding<AccountId, Balance>>;
+        /// Returns the stakes the staker delegated to executors
+        #[api_version(4)]
+        fn staker_stakes(staker: AccountId) -> Vec<NominatedStake<AccountId, Balance>>;
+    }
+}
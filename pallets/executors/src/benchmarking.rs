@@ -0,0 +1,282 @@
+//! Benchmarking setup for pallet-executors
+//!
+//! Rounds are moved forward through the Clock pallet, which drives the executors' rounds in the
+//! runtimes. Every benchmark runs against the bounds of the runtime's config.
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet as ExecutorsPallet;
+use frame_benchmarking::{account, benchmarks, BenchmarkError};
+use frame_support::traits::{Currency, EnsureOrigin, Get};
+use frame_system::RawOrigin;
+use sp_runtime::traits::{Saturating, Zero};
+use sp_std::prelude::*;
+use t3rn_primitives::{
+    clock::Clock, common::RoundIndex, executors::ExecutorCapability, TreasuryAccount,
+    TreasuryAccountProvider,
+};
+
+const SEED: u32 = 0;
+
+fn funds<T: Config>() -> BalanceOf<T> {
+    T::MinExecutorBond::get()
+        .saturating_add(T::MinStake::get())
+        .saturating_mul(1_000u32.into())
+}
+
+fn funded<T: Config>(name: &'static str, index: u32) -> T::AccountId {
+    let who: T::AccountId = account(name, index, SEED);
+    T::Currency::make_free_balance_be(&who, funds::<T>());
+    who
+}
+
+fn registered<T: Config>(index: u32, bond: BalanceOf<T>) -> T::AccountId {
+    let executor = funded::<T>("executor", index);
+    ExecutorsPallet::<T>::register_executor(RawOrigin::Signed(executor.clone()).into(), bond)
+        .expect("Executor should register in Executors::runtime_benchmarks");
+    executor
+}
+
+fn staked<T: Config>(executor: &T::AccountId, index: u32, amount: BalanceOf<T>) -> T::AccountId {
+    let staker = funded::<T>("staker", index);
+    ExecutorsPallet::<T>::stake(
+        RawOrigin::Signed(staker.clone()).into(),
+        executor.clone(),
+        amount,
+    )
+    .expect("Staker should stake in Executors::runtime_benchmarks");
+    staker
+}
+
+fn bump_rounds<T: pallet_clock::Config>(rounds: RoundIndex) {
+    pallet_clock::CurrentRound::<T>::mutate(|round| {
+        round.index = round.index.saturating_add(rounds)
+    });
+}
+
+benchmarks! {
+    where_clause {
+        where T: pallet_clock::Config
+    }
+
+    register_executor {
+        let executor = funded::<T>("executor", 0);
+        let bond = T::MinExecutorBond::get();
+    }: _(RawOrigin::Signed(executor.clone()), bond)
+    verify {
+        assert_eq!(Executors::<T>::get(&executor), Some(bond));
+    }
+
+    deregister_executor {
+        let executor = registered::<T>(0, T::MinExecutorBond::get());
+    }: _(RawOrigin::Signed(executor.clone()))
+    verify {
+        assert!(ExitQueue::<T>::contains_key(&executor));
+    }
+
+    leave_executors {
+        let executor = registered::<T>(0, T::MinExecutorBond::get());
+    }: _(RawOrigin::Signed(executor.clone()))
+    verify {
+        assert!(ExitQueue::<T>::contains_key(&executor));
+    }
+
+    stake {
+        let s in 0 .. T::MaxStakersPerExecutor::get() - 1;
+        let executor = registered::<T>(0, T::MinExecutorBond::get());
+        for index in 0..s {
+            staked::<T>(&executor, index, T::MinStake::get());
+        }
+        let staker = funded::<T>("staker", s);
+    }: _(RawOrigin::Signed(staker.clone()), executor.clone(), T::MinStake::get())
+    verify {
+        assert_eq!(Stakes::<T>::get(&executor, &staker), Some(T::MinStake::get()));
+    }
+
+    schedule_unstake {
+        let executor = registered::<T>(0, T::MinExecutorBond::get());
+        let stake = T::MinStake::get().saturating_mul(2u32.into());
+        let stakers = T::MaxStakersPerExecutor::get();
+        for index in 0..stakers.saturating_sub(1) {
+            let staker = staked::<T>(&executor, index, stake);
+            ExecutorsPallet::<T>::schedule_unstake(
+                RawOrigin::Signed(staker).into(),
+                executor.clone(),
+                T::MinStake::get(),
+            )
+            .expect("Unstake should be scheduled in Executors::runtime_benchmarks");
+        }
+        let staker = staked::<T>(&executor, stakers.saturating_sub(1), stake);
+    }: _(RawOrigin::Signed(staker), executor.clone(), T::MinStake::get())
+    verify {
+        assert_eq!(StakingRequests::<T>::get(&executor).len() as u32, stakers);
+    }
+
+    cancel_unstake {
+        let executor = registered::<T>(0, T::MinExecutorBond::get());
+        let staker = staked::<T>(&executor, 0, T::MinStake::get());
+        ExecutorsPallet::<T>::schedule_unstake(
+            RawOrigin::Signed(staker.clone()).into(),
+            executor.clone(),
+            T::MinStake::get(),
+        )
+        .expect("Unstake should be scheduled in Executors::runtime_benchmarks");
+    }: _(RawOrigin::Signed(staker), executor.clone())
+    verify {
+        assert!(StakingRequests::<T>::get(&executor).is_empty());
+    }
+
+    execute_unstake {
+        let executor = registered::<T>(0, T::MinExecutorBond::get());
+        let staker = staked::<T>(&executor, 0, T::MinStake::get());
+        ExecutorsPallet::<T>::schedule_unstake(
+            RawOrigin::Signed(staker.clone()).into(),
+            executor.clone(),
+            T::MinStake::get(),
+        )
+        .expect("Unstake should be scheduled in Executors::runtime_benchmarks");
+        bump_rounds::<T>(T::UnstakeDelay::get());
+    }: _(RawOrigin::Signed(staker.clone()), executor.clone())
+    verify {
+        assert!(Stakes::<T>::get(&executor, &staker).is_none());
+    }
+
+    schedule_unbond {
+        let chunks = T::MaxUnbondingChunks::get();
+        let bond = T::MinExecutorBond::get().saturating_mul(chunks.saturating_add(1).into());
+        let executor = registered::<T>(0, bond);
+        for _ in 0..chunks.saturating_sub(1) {
+            ExecutorsPallet::<T>::schedule_unbond(
+                RawOrigin::Signed(executor.clone()).into(),
+                T::MinExecutorBond::get(),
+            )
+            .expect("Unbond should be scheduled in Executors::runtime_benchmarks");
+        }
+    }: _(RawOrigin::Signed(executor.clone()), T::MinExecutorBond::get())
+    verify {
+        assert_eq!(Unbonding::<T>::get(&executor).len() as u32, chunks);
+    }
+
+    withdraw_unbonded {
+        let u in 1 .. T::MaxUnbondingChunks::get();
+        let bond = T::MinExecutorBond::get().saturating_mul(u.saturating_add(1).into());
+        let executor = registered::<T>(0, bond);
+        for _ in 0..u {
+            ExecutorsPallet::<T>::schedule_unbond(
+                RawOrigin::Signed(executor.clone()).into(),
+                T::MinExecutorBond::get(),
+            )
+            .expect("Unbond should be scheduled in Executors::runtime_benchmarks");
+        }
+        bump_rounds::<T>(T::UnbondingDelay::get());
+    }: _(RawOrigin::Signed(executor.clone()))
+    verify {
+        assert!(Unbonding::<T>::get(&executor).is_empty());
+    }
+
+    set_commission {
+        let executor = registered::<T>(0, T::MinExecutorBond::get());
+    }: _(RawOrigin::Signed(executor.clone()), T::MaxCommission::get())
+    verify {
+        assert!(PendingCommission::<T>::contains_key(&executor));
+    }
+
+    set_round_issuance {
+        let origin = T::IssuanceOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+        let issuance = T::MinStake::get();
+    }: _<T::RuntimeOrigin>(origin, issuance)
+    verify {
+        assert_eq!(RoundIssuance::<T>::get(), issuance);
+    }
+
+    report_slash {
+        let executor = registered::<T>(0, T::MinExecutorBond::get());
+        let beneficiary: T::AccountId = account("beneficiary", 0, SEED);
+        let origin = T::SlashOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+        let amount = T::MinExecutorBond::get();
+    }: _<T::RuntimeOrigin>(origin, executor.clone(), amount, Some(beneficiary), amount)
+    verify {
+        assert_eq!(PendingSlashCount::<T>::get(&executor), 1);
+    }
+
+    cancel_slash {
+        let executor = registered::<T>(0, T::MinExecutorBond::get());
+        let slash_id = NextSlashId::<T>::get();
+        ExecutorsPallet::<T>::report_slash(
+            T::SlashOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?,
+            executor.clone(),
+            T::MinExecutorBond::get(),
+            None,
+            Zero::zero(),
+        )
+        .expect("Slash should be reported in Executors::runtime_benchmarks");
+        let origin = T::SlashCancelOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+    }: _<T::RuntimeOrigin>(origin, slash_id)
+    verify {
+        assert!(!PendingSlashes::<T>::contains_key(slash_id));
+        assert_eq!(PendingSlashCount::<T>::get(&executor), 0);
+    }
+
+    heartbeat {
+        let executor = registered::<T>(0, T::MinExecutorBond::get());
+        Deactivated::<T>::insert(&executor, T::Clock::current_round().index);
+    }: _(RawOrigin::Signed(executor.clone()))
+    verify {
+        assert!(!Deactivated::<T>::contains_key(&executor));
+    }
+
+    declare_capabilities {
+        let c in 0 .. T::MaxCapabilities::get();
+        let executor = registered::<T>(0, T::MinExecutorBond::get());
+        let capabilities: Vec<ExecutorCapability<BalanceOf<T>>> = (0..c)
+            .map(|index| ExecutorCapability {
+                target: index.to_le_bytes(),
+                action: *b"tran",
+                max_order_size: Some(T::MinExecutorBond::get()),
+            })
+            .collect();
+    }: _(RawOrigin::Signed(executor.clone()), capabilities)
+    verify {
+        assert_eq!(Capabilities::<T>::get(&executor).len() as u32, c);
+    }
+
+    // Worst case: a staker compounding the rewards into its stakes on `s` executors
+    claim_rewards {
+        let r in 1 .. T::MaxRoundsPerClaim::get();
+        let s in 1 .. T::MaxStakesPerStaker::get();
+        let staker = funded::<T>("staker", 0);
+        for index in 0..s {
+            let executor = registered::<T>(index, T::MinExecutorBond::get());
+            ExecutorsPallet::<T>::stake(
+                RawOrigin::Signed(staker.clone()).into(),
+                executor,
+                T::MinStake::get(),
+            )
+            .expect("Staker should stake in Executors::runtime_benchmarks");
+        }
+        let first_round = T::Clock::current_round().index;
+        for round in first_round..first_round.saturating_add(r) {
+            AccruedRewards::<T>::insert(&staker, round, T::MinStake::get());
+        }
+        AutoCompound::<T>::insert(&staker, true);
+        T::Currency::make_free_balance_be(
+            &T::TreasuryAccounts::get_treasury_account(TreasuryAccount::Treasury),
+            funds::<T>(),
+        );
+        bump_rounds::<T>(r);
+        let up_to_round = T::Clock::current_round().index;
+    }: _(RawOrigin::Signed(staker.clone()), up_to_round)
+    verify {
+        assert_eq!(AccruedRewards::<T>::iter_prefix(&staker).count(), 0);
+    }
+
+    set_auto_compound {
+        let who = funded::<T>("staker", 0);
+    }: _(RawOrigin::Signed(who.clone()), true)
+    verify {
+        assert!(AutoCompound::<T>::get(&who));
+    }
+}
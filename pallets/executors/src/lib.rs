@@ -0,0 +1,1767 @@
+//! # Executors pallet
+//!
+//! Registry of executors - accounts eligible to bid on and execute side effects.
+//! An account registers by reserving a self-bond of at least `MinExecutorBond`,
+//! which stays reserved for as long as the account remains registered.
+//!
+//! Token holders may delegate stake to registered executors, extending the capacity an
+//! executor can bond for optimistic side effects and entitling the stakers to a pro-rata
+//! share of the executor's rewards. Unstaking is scheduled and becomes executable after
+//! `UnstakeDelay` rounds have passed.
+//!
+//! Executors charge a commission on the rewards earned with delegated capital. Commission
+//! changes are announced one round ahead so stakers can react before they take effect.
+//!
+//! Round boundaries are driven by the Clock, which notifies this pallet as one of its
+//! `OnNewRound` listeners. At every round boundary the `ActiveSetSize` executors with the
+//! highest bonding capacity are selected into the active set - only its members may bid on
+//! Optimistic side effects.
+//!
+//! Members of the active set prove liveness by submitting a heartbeat or confirming a side
+//! effect at least once per round. Those missing `MaxMissedHeartbeats` rounds in a row are
+//! deactivated: they leave the active set, their open bids are released through
+//! `OnExecutorDeactivated` and they aren't selected again until their next heartbeat.
+//!
+//! Confirmations, failures, confirmation delays and slashes of each executor are tracked
+//! into a reputation score recomputed at every round boundary. Executors whose reputation
+//! falls below `MinReputationForHighValueBids` can't bid on side effects with a max reward
+//! of `HighValueBidThreshold` or more.
+//!
+//! Executors unbond part of their self-bond through a queue: scheduled chunks stop counting
+//! towards the bonding capacity right away and can be withdrawn after `UnbondingDelay`
+//! rounds. Withdrawals, executed unstakes and deregistration are blocked while the executor
+//! still backs unsettled optimistic side effects. Unbonding chunks stay slashable until they're
+//! withdrawn, and neither unbonding nor withdrawals go through while a slash is pending.
+//!
+//! Executors declare the gateways and side effect types they serve, optionally capped at a max
+//! order size. The Circuit invites the matching executors to bid on new side effects, and the
+//! declarations back the liquidity reported per corridor.
+//!
+//! Executions, earned rewards and timeouts are also recorded per executor and round, and kept
+//! for the last `MetricsRetentionRounds` rounds.
+//!
+//! On top of per-SFX user fees, every round closes by accruing `RoundIssuance` to the members
+//! of the round's active set, pro-rata to the side effects each of them confirmed. Rewards are
+//! shared with the stakers just like the executors' other rewards, and claimed out of the
+//! treasury, at most `MaxRoundsPerClaim` rounds at a time.
+//!
+//! Slashes for failed or fraudulent executions are reported by `SlashOrigin` and stay pending
+//! for `SlashAppealPeriod` rounds, during which `SlashCancelOrigin` can cancel them. Due
+//! slashes are applied at the round boundary, at most `MaxSlashesPerRound` at a time in the order
+//! they were reported, moving the slashed bond to the affected user or
+//! to the slash treasury. Executors can't deregister while a slash against them is pending.
+//! When the slashed funds paid to an affected user fall short of the loss reported with the
+//! slash, the `InsuranceFund` tops up the compensation.
+
+// Ensure we're `no_std` when compiling for Wasm.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use crate::pallet::*;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+
+#[frame_support::pallet]
+pub mod pallet {
+    // Weights of the reputation score components.
+    const SUCCESS_RATE_WEIGHT: Percent = Percent::from_percent(70);
+    const LATENCY_WEIGHT: Percent = Percent::from_percent(30);
+    // Reputation lost per slash, in percent.
+    const SLASH_PENALTY: u8 = 10;
+
+    use super::*;
+    use crate::weights::WeightInfo;
+    t3rn_primitives::reexport_currency_types!();
+
+    use frame_support::{
+        dispatch::DispatchResult,
+        pallet_prelude::*,
+        traits::{BalanceStatus, Currency, ExistenceRequirement, ReservableCurrency},
+    };
+    use frame_system::pallet_prelude::*;
+    use sp_runtime::{
+        traits::{Saturating, UniqueSaturatedInto, Zero},
+        Perbill, Percent,
+    };
+    use sp_std::{prelude::*, vec};
+    use t3rn_primitives::{
+        clock::{Clock, OnNewRound},
+        common::RoundIndex,
+        executors::{
+            Bond, ExecutorCapability, ExecutorRoundMetrics, ExecutorStanding, ExecutorTrackRecord,
+            Executors as ExecutorsApi, NominatedStake, OnExecutorDeactivated, PendingSlash,
+            ScheduledStakingRequest, StakingAction, UnbondingChunk,
+        },
+        insurance::InsuranceFund,
+        TreasuryAccount, TreasuryAccountProvider,
+    };
+    use t3rn_types::sfx::{Sfx4bId, TargetId};
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// The overarching event type.
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        type Currency: ReservableCurrency<Self::AccountId>;
+
+        /// Minimum self-bond an account has to reserve to register as an executor.
+        #[pallet::constant]
+        type MinExecutorBond: Get<BalanceOf<Self>>;
+
+        /// Maximum number of registered executors, bounding the work done at round boundaries.
+        #[pallet::constant]
+        type MaxExecutors: Get<u32>;
+
+        type Clock: Clock<Self>;
+
+        /// Minimum stake a staker has to keep delegated to an executor.
+        #[pallet::constant]
+        type MinStake: Get<BalanceOf<Self>>;
+
+        /// Maximum number of stakers delegating to a single executor.
+        #[pallet::constant]
+        type MaxStakersPerExecutor: Get<u32>;
+
+        /// Maximum number of executors a single staker can delegate to.
+        #[pallet::constant]
+        type MaxStakesPerStaker: Get<u32>;
+
+        /// Maximum number of unbonding chunks an executor can have scheduled at once.
+        #[pallet::constant]
+        type MaxUnbondingChunks: Get<u32>;
+
+        /// Number of rounds a scheduled unstake waits before it can be executed.
+        #[pallet::constant]
+        type UnstakeDelay: Get<RoundIndex>;
+
+        /// Number of rounds unbonded self-bond waits before it can be withdrawn.
+        #[pallet::constant]
+        type UnbondingDelay: Get<RoundIndex>;
+
+        /// Maximum commission an executor can charge on rewards earned with delegated stake.
+        #[pallet::constant]
+        type MaxCommission: Get<Percent>;
+
+        /// Number of executors selected into the active set every round.
+        #[pallet::constant]
+        type ActiveSetSize: Get<u32>;
+
+        /// Average confirmation delay (in blocks) at which the latency part of the
+        /// reputation score drops to zero.
+        #[pallet::constant]
+        type MaxConfirmationDelay: Get<u32>;
+
+        /// Max reward from which a side effect is considered high-value.
+        #[pallet::constant]
+        type HighValueBidThreshold: Get<BalanceOf<Self>>;
+
+        /// Reputation required to bid on high-value side effects.
+        #[pallet::constant]
+        type MinReputationForHighValueBids: Get<Percent>;
+
+        /// Number of past rounds, the current one included, for which executor metrics are
+        /// retained.
+        #[pallet::constant]
+        type MetricsRetentionRounds: Get<RoundIndex>;
+
+        type TreasuryAccounts: TreasuryAccountProvider<Self::AccountId>;
+
+        /// Origin allowed to set the amount issued to executors every round.
+        type IssuanceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Origin allowed to report slashes against executors.
+        type SlashOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Origin allowed to cancel pending slashes during their appeal period.
+        type SlashCancelOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Number of rounds a reported slash can be appealed before it's applied.
+        #[pallet::constant]
+        type SlashAppealPeriod: Get<RoundIndex>;
+
+        /// Maximum number of reported slashes inspected at a round boundary. Slashes left over
+        /// are applied at the next one.
+        #[pallet::constant]
+        type MaxSlashesPerRound: Get<u32>;
+
+        /// Tops up slashes paid to affected users that exceed the executor's bond.
+        type InsuranceFund: InsuranceFund<Self::AccountId, BalanceOf<Self>>;
+
+        /// Number of consecutive rounds an active executor may go without a heartbeat before
+        /// it's deactivated.
+        #[pallet::constant]
+        type MaxMissedHeartbeats: Get<u32>;
+
+        /// Maximum number of rounds of accrued rewards paid out by a single claim.
+        #[pallet::constant]
+        type MaxRoundsPerClaim: Get<u32>;
+
+        /// Notified when an executor is deactivated, e.g. to release its open bids.
+        type OnExecutorDeactivated: OnExecutorDeactivated<Self::AccountId>;
+
+        /// Maximum number of gateway and side effect type pairs an executor can declare to
+        /// serve.
+        #[pallet::constant]
+        type MaxCapabilities: Get<u32>;
+
+        /// Type representing the weight of this pallet
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(PhantomData<T>);
+
+    #[pallet::storage]
+    #[pallet::getter(fn self_bond)]
+    /// Registered executors and their reserved self-bond.
+    pub type Executors<T: Config> =
+        CountedStorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn stake_of)]
+    /// Stake delegated to an executor (first key) by a staker (second key).
+    pub type Stakes<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::AccountId,
+        BalanceOf<T>,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn total_stake)]
+    /// Total stake delegated to an executor.
+    pub type TotalStake<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn staked_executors)]
+    /// Executors a staker delegates to.
+    pub type StakedExecutors<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<T::AccountId, T::MaxStakesPerStaker>,
+        ValueQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn staking_requests)]
+    /// Unstake requests scheduled against an executor, at most one per staker.
+    pub type StakingRequests<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<ScheduledStakingRequest<T::AccountId, BalanceOf<T>>, T::MaxStakersPerExecutor>,
+        ValueQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn unbonding)]
+    /// Self-bond chunks an executor scheduled for withdrawal.
+    pub type Unbonding<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<UnbondingChunk<BalanceOf<T>>, T::MaxUnbondingChunks>,
+        ValueQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn obligations)]
+    /// Bond an executor reserved for optimistic side effects that aren't settled yet.
+    pub type Obligations<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
+    #[pallet::storage]
+    /// Commission currently charged by an executor.
+    pub type Commission<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, Percent, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn pending_commission)]
+    /// Announced commission change and the round from which it applies.
+    pub type PendingCommission<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, (RoundIndex, Percent)>;
+
+    #[pallet::storage]
+    /// Executors selected for the current round, ordered by bonding capacity.
+    pub type ActiveSet<T: Config> =
+        StorageValue<_, BoundedVec<T::AccountId, T::ActiveSetSize>, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn active_set_round)]
+    /// Round the current active set was selected for.
+    pub type ActiveSetRound<T: Config> = StorageValue<_, RoundIndex, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn track_record)]
+    /// Execution history of an executor.
+    pub type TrackRecords<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, ExecutorTrackRecord, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn reputation)]
+    /// Reputation score of an executor as of the last round boundary.
+    pub type Reputation<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, Percent, ValueQuery>;
+
+    #[pallet::storage]
+    /// Work performed by an executor (second key) in a round (first key).
+    pub type RoundMetrics<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        RoundIndex,
+        Blake2_128Concat,
+        T::AccountId,
+        ExecutorRoundMetrics<BalanceOf<T>>,
+        ValueQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn round_issuance)]
+    /// Amount paid out of the treasury at the end of every round to reward the work of active
+    /// executors.
+    pub type RoundIssuance<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn next_slash_id)]
+    /// Id assigned to the next reported slash.
+    pub type NextSlashId<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn next_slash_to_apply)]
+    /// Id of the oldest reported slash not applied or cancelled yet, from which the next round
+    /// boundary resumes applying due slashes.
+    pub type NextSlashToApply<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn pending_slash)]
+    /// Reported slashes waiting for their appeal period to pass.
+    pub type PendingSlashes<T: Config> =
+        StorageMap<_, Twox64Concat, u32, PendingSlash<T::AccountId, BalanceOf<T>>>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn pending_slashes_of)]
+    /// Number of pending slashes reported against an executor.
+    pub type PendingSlashCount<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn last_heartbeat)]
+    /// Last round in which an executor submitted a heartbeat or confirmed a side effect, or
+    /// registered if it did neither since.
+    pub type LastHeartbeat<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, RoundIndex>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn missed_heartbeats)]
+    /// Consecutive rounds an executor spent in the active set without a heartbeat.
+    pub type MissedHeartbeats<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn deactivated_at)]
+    /// Executors deactivated for missing heartbeats and the round they were deactivated in.
+    /// They're left out of the active set until they submit a heartbeat again.
+    pub type Deactivated<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, RoundIndex>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn capabilities_of)]
+    /// Gateways and side effect types each executor declared to serve.
+    pub type Capabilities<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<ExecutorCapability<BalanceOf<T>>, T::MaxCapabilities>,
+        ValueQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn exit_scheduled_at)]
+    /// Executors leaving and the round from which their exit is processed.
+    pub type ExitQueue<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, RoundIndex>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn accrued_rewards)]
+    /// Rewards an executor or staker (first key) earned in a round (second key), or vested from
+    /// inflation to unlock in the round, and can claim.
+    pub type AccruedRewards<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Twox64Concat,
+        RoundIndex,
+        BalanceOf<T>,
+        ValueQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn auto_compounds)]
+    /// Executors and stakers who opted to restake their claimed rewards.
+    pub type AutoCompound<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, bool, ValueQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// An account registered as an executor with the given self-bond.
+        ExecutorRegistered(T::AccountId, BalanceOf<T>),
+        /// Staker, executor, amount added to the stake.
+        Staked(T::AccountId, T::AccountId, BalanceOf<T>),
+        /// Staker, executor, amount, round from which the unstake can be executed.
+        UnstakeScheduled(T::AccountId, T::AccountId, BalanceOf<T>, RoundIndex),
+        /// Staker, executor.
+        UnstakeCancelled(T::AccountId, T::AccountId),
+        /// Staker, executor, amount released back to the staker.
+        Unstaked(T::AccountId, T::AccountId, BalanceOf<T>),
+        /// Executor, amount unbonded, round from which it can be withdrawn.
+        UnbondScheduled(T::AccountId, BalanceOf<T>, RoundIndex),
+        /// Executor, amount withdrawn.
+        Withdrawn(T::AccountId, BalanceOf<T>),
+        /// New amount issued to active executors every round.
+        RoundIssuanceSet(BalanceOf<T>),
+        /// Round, executor, amount accrued for the executor's work, stakers' shares included.
+        RoundRewardIssued(RoundIndex, T::AccountId, BalanceOf<T>),
+        /// Account, last round claimed up to, amount paid from the treasury to the account's free
+        /// balance.
+        RewardsClaimed(T::AccountId, RoundIndex, BalanceOf<T>),
+        /// Account, last round claimed up to, amount restaked into the account's bond or stakes.
+        RewardsCompounded(T::AccountId, RoundIndex, BalanceOf<T>),
+        /// Account, whether its claimed rewards are restaked.
+        AutoCompoundSet(T::AccountId, bool),
+        /// Executor, new commission, round from which it applies.
+        CommissionChangeAnnounced(T::AccountId, Percent, RoundIndex),
+        /// Round, executors selected into the active set.
+        NewActiveSet(RoundIndex, Vec<T::AccountId>),
+        /// Slash id, executor, amount, round at which the slash applies unless cancelled.
+        SlashReported(u32, T::AccountId, BalanceOf<T>, RoundIndex),
+        /// Slash id, executor.
+        SlashCancelled(u32, T::AccountId),
+        /// Slash id, executor, amount slashed, account receiving the slashed funds.
+        SlashApplied(u32, T::AccountId, BalanceOf<T>, T::AccountId),
+        /// Executor, round of the heartbeat.
+        Heartbeat(T::AccountId, RoundIndex),
+        /// Executor, number of consecutive rounds it missed its heartbeat in.
+        ExecutorDeactivated(T::AccountId, u32),
+        /// Executor, capabilities replacing its previous declaration.
+        CapabilitiesDeclared(T::AccountId, Vec<ExecutorCapability<BalanceOf<T>>>),
+        /// Executor, round from which its exit is processed.
+        ExitScheduled(T::AccountId, RoundIndex),
+        /// Executor, unsettled obligations and pending slashes holding its exit back.
+        ExitBlocked(T::AccountId, BalanceOf<T>, u32),
+        /// Executor, self-bond released on leaving.
+        ExecutorLeft(T::AccountId, BalanceOf<T>),
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        AlreadyRegistered,
+        TooManyExecutors,
+        NotRegistered,
+        ExecutorBondTooSmall,
+        InsufficientBalanceToReserveBond,
+        StakeTooSmall,
+        StakeNotFound,
+        TooManyStakers,
+        TooManyStakes,
+        InsufficientBalanceToReserveStake,
+        UnstakeAmountExceedsStake,
+        UnstakeAlreadyScheduled,
+        UnstakeNotScheduled,
+        UnstakeNotDue,
+        CommissionTooHigh,
+        SlashNotFound,
+        SlashPending,
+        UnbondAmountExceedsBond,
+        TooManyUnbondingChunks,
+        NothingToWithdraw,
+        UnsettledObligations,
+        TooManyCapabilities,
+        AlreadyLeaving,
+        NothingToClaim,
+        RewardsPotExhausted,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        #[pallet::weight(T::WeightInfo::register_executor())]
+        pub fn register_executor(origin: OriginFor<T>, bond: BalanceOf<T>) -> DispatchResult {
+            let executor = ensure_signed(origin)?;
+
+            ensure!(
+                !Executors::<T>::contains_key(&executor),
+                Error::<T>::AlreadyRegistered
+            );
+            ensure!(
+                bond >= T::MinExecutorBond::get(),
+                Error::<T>::ExecutorBondTooSmall
+            );
+            ensure!(
+                Executors::<T>::count() < T::MaxExecutors::get(),
+                Error::<T>::TooManyExecutors
+            );
+
+            T::Currency::reserve(&executor, bond)
+                .map_err(|_| Error::<T>::InsufficientBalanceToReserveBond)?;
+
+            Executors::<T>::insert(&executor, bond);
+            // Count heartbeats from the registration round on, so that those missed before the
+            // first one submitted deactivate the executor just the same
+            LastHeartbeat::<T>::insert(&executor, T::Clock::current_round().index);
+
+            Self::deposit_event(Event::ExecutorRegistered(executor, bond));
+
+            Ok(())
+        }
+
+        /// Deregister an executor without unsettled obligations nor pending slashes. Like
+        /// `leave_executors`, the self-bond is only released through the exit queue once
+        /// `UnbondingDelay` rounds have passed.
+        #[pallet::weight(T::WeightInfo::deregister_executor())]
+        pub fn deregister_executor(origin: OriginFor<T>) -> DispatchResult {
+            let executor = ensure_signed(origin)?;
+
+            ensure!(
+                Executors::<T>::contains_key(&executor),
+                Error::<T>::NotRegistered
+            );
+            ensure!(
+                !Self::has_pending_slash(&executor),
+                Error::<T>::SlashPending
+            );
+            ensure!(
+                Obligations::<T>::get(&executor).is_zero(),
+                Error::<T>::UnsettledObligations
+            );
+
+            Self::schedule_exit(executor)
+        }
+
+        /// Leave the executors at a future round. The exit waits for the executor's obligations
+        /// to settle and its pending slashes to resolve; it takes no new side effects meanwhile.
+        #[pallet::weight(T::WeightInfo::leave_executors())]
+        pub fn leave_executors(origin: OriginFor<T>) -> DispatchResult {
+            let executor = ensure_signed(origin)?;
+
+            ensure!(
+                Executors::<T>::contains_key(&executor),
+                Error::<T>::NotRegistered
+            );
+
+            Self::schedule_exit(executor)
+        }
+
+        #[pallet::weight(T::WeightInfo::stake(T::MaxStakersPerExecutor::get()))]
+        pub fn stake(
+            origin: OriginFor<T>,
+            executor: T::AccountId,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            let staker = ensure_signed(origin)?;
+
+            ensure!(
+                Executors::<T>::contains_key(&executor),
+                Error::<T>::NotRegistered
+            );
+
+            let current_stake = Stakes::<T>::get(&executor, &staker);
+            ensure!(
+                current_stake
+                    .unwrap_or_else(Zero::zero)
+                    .saturating_add(amount)
+                    >= T::MinStake::get(),
+                Error::<T>::StakeTooSmall
+            );
+
+            if current_stake.is_none() {
+                ensure!(
+                    (Stakes::<T>::iter_prefix(&executor).count() as u32)
+                        < T::MaxStakersPerExecutor::get(),
+                    Error::<T>::TooManyStakers
+                );
+                ensure!(
+                    (StakedExecutors::<T>::decode_len(&staker).unwrap_or_default() as u32)
+                        < T::MaxStakesPerStaker::get(),
+                    Error::<T>::TooManyStakes
+                );
+            }
+
+            T::Currency::reserve(&staker, amount)
+                .map_err(|_| Error::<T>::InsufficientBalanceToReserveStake)?;
+
+            if current_stake.is_none() {
+                StakedExecutors::<T>::try_append(&staker, executor.clone())
+                    .map_err(|_| Error::<T>::TooManyStakes)?;
+            }
+            Stakes::<T>::mutate(&executor, &staker, |stake| {
+                let stake = stake.get_or_insert_with(Zero::zero);
+                *stake = stake.saturating_add(amount);
+            });
+            TotalStake::<T>::mutate(&executor, |total| *total = total.saturating_add(amount));
+
+            Self::deposit_event(Event::Staked(staker, executor, amount));
+
+            Ok(())
+        }
+
+        #[pallet::weight(T::WeightInfo::schedule_unstake())]
+        pub fn schedule_unstake(
+            origin: OriginFor<T>,
+            executor: T::AccountId,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            let staker = ensure_signed(origin)?;
+
+            let stake = Stakes::<T>::get(&executor, &staker).ok_or(Error::<T>::StakeNotFound)?;
+            ensure!(amount <= stake, Error::<T>::UnstakeAmountExceedsStake);
+
+            let action = if amount == stake {
+                StakingAction::Revoke(amount)
+            } else {
+                ensure!(
+                    stake.saturating_sub(amount) >= T::MinStake::get(),
+                    Error::<T>::StakeTooSmall
+                );
+                StakingAction::Decrease(amount)
+            };
+
+            let when_executable = T::Clock::current_round()
+                .index
+                .saturating_add(T::UnstakeDelay::get());
+
+            StakingRequests::<T>::try_mutate(&executor, |requests| -> DispatchResult {
+                ensure!(
+                    !requests.iter().any(|request| request.staker == staker),
+                    Error::<T>::UnstakeAlreadyScheduled
+                );
+                requests
+                    .try_push(ScheduledStakingRequest {
+                        staker: staker.clone(),
+                        when_executable,
+                        action,
+                    })
+                    .map_err(|_| Error::<T>::TooManyStakers)?;
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::UnstakeScheduled(
+                staker,
+                executor,
+                amount,
+                when_executable,
+            ));
+
+            Ok(())
+        }
+
+        #[pallet::weight(T::WeightInfo::cancel_unstake())]
+        pub fn cancel_unstake(origin: OriginFor<T>, executor: T::AccountId) -> DispatchResult {
+            let staker = ensure_signed(origin)?;
+
+            Self::take_staking_request(&executor, &staker)?;
+
+            Self::deposit_event(Event::UnstakeCancelled(staker, executor));
+
+            Ok(())
+        }
+
+        #[pallet::weight(T::WeightInfo::execute_unstake())]
+        pub fn execute_unstake(origin: OriginFor<T>, executor: T::AccountId) -> DispatchResult {
+            let staker = ensure_signed(origin)?;
+
+            let request = StakingRequests::<T>::get(&executor)
+                .into_iter()
+                .find(|request| request.staker == staker)
+                .ok_or(Error::<T>::UnstakeNotScheduled)?;
+            ensure!(
+                request.when_executable <= T::Clock::current_round().index,
+                Error::<T>::UnstakeNotDue
+            );
+            ensure!(
+                Obligations::<T>::get(&executor).is_zero(),
+                Error::<T>::UnsettledObligations
+            );
+            Self::take_staking_request(&executor, &staker)?;
+
+            let amount = Self::decrease_stake(&executor, &staker, request.action.amount());
+
+            Self::deposit_event(Event::Unstaked(staker, executor, amount));
+
+            Ok(())
+        }
+
+        #[pallet::weight(T::WeightInfo::schedule_unbond())]
+        pub fn schedule_unbond(origin: OriginFor<T>, amount: BalanceOf<T>) -> DispatchResult {
+            let executor = ensure_signed(origin)?;
+
+            let bond = Executors::<T>::get(&executor).ok_or(Error::<T>::NotRegistered)?;
+            ensure!(
+                !Self::has_pending_slash(&executor),
+                Error::<T>::SlashPending
+            );
+            ensure!(amount <= bond, Error::<T>::UnbondAmountExceedsBond);
+            // Leaving the executor set entirely goes through deregistration.
+            ensure!(
+                bond.saturating_sub(amount) >= T::MinExecutorBond::get(),
+                Error::<T>::ExecutorBondTooSmall
+            );
+
+            let when_withdrawable = T::Clock::current_round()
+                .index
+                .saturating_add(T::UnbondingDelay::get());
+
+            Unbonding::<T>::try_append(
+                &executor,
+                UnbondingChunk {
+                    amount,
+                    when_withdrawable,
+                },
+            )
+            .map_err(|_| Error::<T>::TooManyUnbondingChunks)?;
+            Executors::<T>::insert(&executor, bond.saturating_sub(amount));
+
+            Self::deposit_event(Event::UnbondScheduled(executor, amount, when_withdrawable));
+
+            Ok(())
+        }
+
+        #[pallet::weight(T::WeightInfo::withdraw_unbonded(T::MaxUnbondingChunks::get()))]
+        pub fn withdraw_unbonded(origin: OriginFor<T>) -> DispatchResult {
+            let executor = ensure_signed(origin)?;
+
+            ensure!(
+                Obligations::<T>::get(&executor).is_zero(),
+                Error::<T>::UnsettledObligations
+            );
+            ensure!(
+                !Self::has_pending_slash(&executor),
+                Error::<T>::SlashPending
+            );
+
+            let current_round = T::Clock::current_round().index;
+            let mut pending = Unbonding::<T>::get(&executor);
+            let scheduled = pending.len();
+            let mut amount: BalanceOf<T> = Zero::zero();
+            pending.retain(|chunk| {
+                let withdrawable = chunk.when_withdrawable <= current_round;
+                if withdrawable {
+                    amount = amount.saturating_add(chunk.amount);
+                }
+                !withdrawable
+            });
+            ensure!(pending.len() < scheduled, Error::<T>::NothingToWithdraw);
+
+            if pending.is_empty() {
+                Unbonding::<T>::remove(&executor);
+            } else {
+                Unbonding::<T>::insert(&executor, pending);
+            }
+            T::Currency::unreserve(&executor, amount);
+
+            Self::deposit_event(Event::Withdrawn(executor, amount));
+
+            Ok(())
+        }
+
+        #[pallet::weight(T::WeightInfo::set_commission())]
+        pub fn set_commission(origin: OriginFor<T>, commission: Percent) -> DispatchResult {
+            let executor = ensure_signed(origin)?;
+
+            ensure!(
+                Executors::<T>::contains_key(&executor),
+                Error::<T>::NotRegistered
+            );
+            ensure!(
+                commission <= T::MaxCommission::get(),
+                Error::<T>::CommissionTooHigh
+            );
+
+            // Enact a previously announced change that is already due before replacing it.
+            Commission::<T>::insert(&executor, Self::commission(&executor));
+
+            let applies_from = T::Clock::current_round().index.saturating_add(1);
+            PendingCommission::<T>::insert(&executor, (applies_from, commission));
+
+            Self::deposit_event(Event::CommissionChangeAnnounced(
+                executor,
+                commission,
+                applies_from,
+            ));
+
+            Ok(())
+        }
+
+        #[pallet::weight(T::WeightInfo::set_round_issuance())]
+        pub fn set_round_issuance(origin: OriginFor<T>, issuance: BalanceOf<T>) -> DispatchResult {
+            T::IssuanceOrigin::ensure_origin(origin)?;
+
+            RoundIssuance::<T>::put(issuance);
+
+            Self::deposit_event(Event::RoundIssuanceSet(issuance));
+
+            Ok(())
+        }
+
+        /// Report a slash of the executor's bond. An affected user given as the beneficiary
+        /// receives the slashed funds, and the insurance fund tops them up to the user's loss.
+        #[pallet::weight(T::WeightInfo::report_slash())]
+        pub fn report_slash(
+            origin: OriginFor<T>,
+            executor: T::AccountId,
+            amount: BalanceOf<T>,
+            beneficiary: Option<T::AccountId>,
+            loss: BalanceOf<T>,
+        ) -> DispatchResult {
+            T::SlashOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                Executors::<T>::contains_key(&executor),
+                Error::<T>::NotRegistered
+            );
+
+            let slash_id = NextSlashId::<T>::get();
+            let applies_at = T::Clock::current_round()
+                .index
+                .saturating_add(T::SlashAppealPeriod::get());
+
+            PendingSlashes::<T>::insert(
+                slash_id,
+                PendingSlash {
+                    executor: executor.clone(),
+                    amount,
+                    beneficiary,
+                    loss,
+                    applies_at,
+                },
+            );
+            NextSlashId::<T>::put(slash_id.wrapping_add(1));
+            PendingSlashCount::<T>::mutate(&executor, |count| *count = count.saturating_add(1));
+
+            Self::deposit_event(Event::SlashReported(slash_id, executor, amount, applies_at));
+
+            Ok(())
+        }
+
+        #[pallet::weight(T::WeightInfo::cancel_slash())]
+        pub fn cancel_slash(origin: OriginFor<T>, slash_id: u32) -> DispatchResult {
+            T::SlashCancelOrigin::ensure_origin(origin)?;
+
+            let slash = PendingSlashes::<T>::take(slash_id).ok_or(Error::<T>::SlashNotFound)?;
+            Self::note_slash_resolved(&slash.executor);
+
+            Self::deposit_event(Event::SlashCancelled(slash_id, slash.executor));
+
+            Ok(())
+        }
+
+        #[pallet::weight(T::WeightInfo::heartbeat())]
+        pub fn heartbeat(origin: OriginFor<T>) -> DispatchResult {
+            let executor = ensure_signed(origin)?;
+
+            ensure!(
+                Executors::<T>::contains_key(&executor),
+                Error::<T>::NotRegistered
+            );
+
+            let round = Self::note_heartbeat(&executor);
+
+            Self::deposit_event(Event::Heartbeat(executor, round));
+
+            Ok(())
+        }
+
+        #[pallet::weight(T::WeightInfo::declare_capabilities(capabilities.len() as u32))]
+        pub fn declare_capabilities(
+            origin: OriginFor<T>,
+            capabilities: Vec<ExecutorCapability<BalanceOf<T>>>,
+        ) -> DispatchResult {
+            let executor = ensure_signed(origin)?;
+
+            ensure!(
+                Executors::<T>::contains_key(&executor),
+                Error::<T>::NotRegistered
+            );
+            let declared: BoundedVec<_, T::MaxCapabilities> = capabilities
+                .clone()
+                .try_into()
+                .map_err(|_| Error::<T>::TooManyCapabilities)?;
+
+            if declared.is_empty() {
+                Capabilities::<T>::remove(&executor);
+            } else {
+                Capabilities::<T>::insert(&executor, declared);
+            }
+
+            Self::deposit_event(Event::CapabilitiesDeclared(executor, capabilities));
+
+            Ok(())
+        }
+
+        /// Claim from the treasury the rewards accrued in the rounds up to and including the given
+        /// one, rewards accrued for rounds yet to start left out. At most `MaxRoundsPerClaim`
+        /// accrued rounds are paid out per claim.
+        #[pallet::weight(T::WeightInfo::claim_rewards(
+            T::MaxRoundsPerClaim::get(),
+            T::MaxStakesPerStaker::get()
+        ))]
+        pub fn claim_rewards(origin: OriginFor<T>, up_to_round: RoundIndex) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let up_to_round = up_to_round.min(T::Clock::current_round().index);
+            let claimable: Vec<(RoundIndex, BalanceOf<T>)> = AccruedRewards::<T>::iter_prefix(&who)
+                .filter(|(round, _)| *round <= up_to_round)
+                .take(T::MaxRoundsPerClaim::get() as usize)
+                .collect();
+            let total = claimable
+                .iter()
+                .fold(Zero::zero(), |total: BalanceOf<T>, (_round, reward)| {
+                    total.saturating_add(*reward)
+                });
+            ensure!(!total.is_zero(), Error::<T>::NothingToClaim);
+
+            T::Currency::transfer(
+                &T::TreasuryAccounts::get_treasury_account(TreasuryAccount::Treasury),
+                &who,
+                total,
+                ExistenceRequirement::KeepAlive,
+            )
+            .map_err(|_| Error::<T>::RewardsPotExhausted)?;
+            for (round, _reward) in claimable {
+                AccruedRewards::<T>::remove(&who, round);
+            }
+
+            let compounded = if AutoCompound::<T>::get(&who) {
+                Self::compound_rewards(&who, total)
+            } else {
+                Zero::zero()
+            };
+            let withdrawn = total.saturating_sub(compounded);
+
+            if !compounded.is_zero() {
+                Self::deposit_event(Event::RewardsCompounded(
+                    who.clone(),
+                    up_to_round,
+                    compounded,
+                ));
+            }
+            if !withdrawn.is_zero() {
+                Self::deposit_event(Event::RewardsClaimed(who, up_to_round, withdrawn));
+            }
+
+            Ok(())
+        }
+
+        /// Opt in or out of restaking claimed rewards: into the self-bond for executors,
+        /// or pro-rata into the existing stakes for stakers.
+        #[pallet::weight(T::WeightInfo::set_auto_compound())]
+        pub fn set_auto_compound(origin: OriginFor<T>, enabled: bool) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            if enabled {
+                AutoCompound::<T>::insert(&who, true);
+            } else {
+                AutoCompound::<T>::remove(&who);
+            }
+
+            Self::deposit_event(Event::AutoCompoundSet(who, enabled));
+
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Reputation score derived from the executor's track record: 70% weighs the
+        /// confirmation success rate, 30% the average confirmation delay, reduced by 10% per
+        /// slash. Executors without history score zero.
+        pub fn reputation_score(record: &ExecutorTrackRecord) -> Percent {
+            if !record.has_history() {
+                return Percent::from_percent(0)
+            }
+
+            let max_delay = T::MaxConfirmationDelay::get() as u64;
+            let latency_score = Percent::from_percent(100).saturating_sub(Percent::from_rational(
+                record.average_delay().min(max_delay),
+                max_delay.max(1),
+            ));
+
+            (SUCCESS_RATE_WEIGHT * record.success_rate())
+                .saturating_add(LATENCY_WEIGHT * latency_score)
+                .saturating_sub(Percent::from_percent(
+                    SLASH_PENALTY.saturating_mul(record.slashes.min(10) as u8),
+                ))
+        }
+
+        /// Recompute the reputation of every registered executor. Returns the number of
+        /// executors scored.
+        pub fn recompute_reputation() -> u64 {
+            let mut scored = 0u64;
+            for executor in Executors::<T>::iter_keys() {
+                Reputation::<T>::insert(
+                    &executor,
+                    Self::reputation_score(&TrackRecords::<T>::get(&executor)),
+                );
+                scored = scored.saturating_add(1);
+            }
+            scored
+        }
+
+        /// Accrue the round issuance to the members of the active set selected for the given
+        /// round, pro-rata to the side effects they confirmed in it, and split it with their
+        /// stakers.
+        /// Returns the number of active executors inspected.
+        pub fn issue_round_rewards(round: RoundIndex) -> u64 {
+            let issuance = RoundIssuance::<T>::get();
+            let active_set = ActiveSet::<T>::get();
+            if issuance.is_zero() || active_set.is_empty() {
+                return 0
+            }
+
+            let work: Vec<(T::AccountId, u32)> = active_set
+                .into_iter()
+                .map(|executor| {
+                    let executions = RoundMetrics::<T>::get(round, &executor).executions;
+                    (executor, executions)
+                })
+                .collect();
+            let inspected = work.len() as u64;
+            let total_work = work.iter().fold(0u32, |total, (_executor, executions)| {
+                total.saturating_add(*executions)
+            });
+            if total_work == 0 {
+                return inspected
+            }
+
+            for (executor, executions) in work {
+                if executions == 0 {
+                    continue
+                }
+                let reward = Perbill::from_rational(executions, total_work).mul_floor(issuance);
+                for (beneficiary, share) in Self::reward_shares(&executor, reward) {
+                    if !share.is_zero() {
+                        AccruedRewards::<T>::mutate(&beneficiary, round, |accrued| {
+                            *accrued = accrued.saturating_add(share)
+                        });
+                    }
+                }
+                Self::deposit_event(Event::RoundRewardIssued(round, executor, reward));
+            }
+
+            inspected
+        }
+
+        /// Metrics of the executor for every retained round, oldest first.
+        pub fn metrics_of(
+            executor: &T::AccountId,
+        ) -> Vec<(RoundIndex, ExecutorRoundMetrics<BalanceOf<T>>)> {
+            let current_round = T::Clock::current_round().index;
+            let oldest_round = current_round
+                .saturating_add(1)
+                .saturating_sub(T::MetricsRetentionRounds::get());
+            (oldest_round..=current_round)
+                .filter(|round| RoundMetrics::<T>::contains_key(round, executor))
+                .map(|round| (round, RoundMetrics::<T>::get(round, executor)))
+                .collect()
+        }
+
+        /// Drop the metrics of the round that fell out of the retention window. Returns the
+        /// number of entries removed.
+        pub fn prune_metrics(round: RoundIndex) -> u64 {
+            let retention = T::MetricsRetentionRounds::get();
+            if round < retention {
+                return 0
+            }
+            RoundMetrics::<T>::clear_prefix(round.saturating_sub(retention), u32::MAX, None).unique
+                as u64
+        }
+
+        fn mutate_round_metrics(
+            executor: &T::AccountId,
+            f: impl FnOnce(&mut ExecutorRoundMetrics<BalanceOf<T>>),
+        ) {
+            RoundMetrics::<T>::mutate(T::Clock::current_round().index, executor, f);
+        }
+
+        /// Record the executor as live in the current round, which also lifts a deactivation
+        /// from the next active set selection on.
+        fn note_heartbeat(executor: &T::AccountId) -> RoundIndex {
+            let round = T::Clock::current_round().index;
+            LastHeartbeat::<T>::insert(executor, round);
+            Deactivated::<T>::remove(executor);
+            round
+        }
+
+        /// Apply the pending slashes whose appeal period is over by the given round, moving the
+        /// slashed bond to the beneficiary or the slash treasury. Slashes are walked in the order
+        /// they were reported, from `NextSlashToApply` up to the first one not due yet, inspecting
+        /// at most `MaxSlashesPerRound`. Returns the number of slashes inspected and applied.
+        pub fn apply_due_slashes(round: RoundIndex) -> (u64, u64) {
+            let next_slash_id = NextSlashId::<T>::get();
+            let mut cursor = NextSlashToApply::<T>::get();
+            let mut inspected = 0u64;
+            let mut applied = 0u64;
+
+            while cursor != next_slash_id && inspected < T::MaxSlashesPerRound::get() as u64 {
+                inspected = inspected.saturating_add(1);
+                let slash_id = cursor;
+                let slash = match PendingSlashes::<T>::get(slash_id) {
+                    Some(slash) if slash.applies_at > round => break,
+                    Some(slash) => slash,
+                    // Cancelled during its appeal period.
+                    None => {
+                        cursor = cursor.wrapping_add(1);
+                        continue
+                    },
+                };
+                cursor = cursor.wrapping_add(1);
+                PendingSlashes::<T>::remove(slash_id);
+                Self::note_slash_resolved(&slash.executor);
+
+                let destination = slash.beneficiary.clone().unwrap_or_else(|| {
+                    T::TreasuryAccounts::get_treasury_account(TreasuryAccount::Slash)
+                });
+                let slashed = Self::transfer_bond(&slash.executor, slash.amount, &destination);
+                if let Some(beneficiary) = &slash.beneficiary {
+                    T::InsuranceFund::compensate(beneficiary, slash.loss, slashed);
+                }
+                applied = applied.saturating_add(1);
+
+                Self::deposit_event(Event::SlashApplied(
+                    slash_id,
+                    slash.executor,
+                    slashed,
+                    destination,
+                ));
+            }
+
+            NextSlashToApply::<T>::put(cursor);
+
+            (inspected, applied)
+        }
+
+        /// Remove every executor whose exit is due by the given round and has no unsettled
+        /// obligations nor pending slashes. Blocked exits are retried the next round. Returns the
+        /// number of queued exits inspected and processed.
+        pub fn process_exit_queue(round: RoundIndex) -> (u64, u64) {
+            let due: Vec<T::AccountId> = ExitQueue::<T>::iter()
+                .filter(|(_, exit_round)| *exit_round <= round)
+                .map(|(executor, _)| executor)
+                .collect();
+            let inspected = due.len() as u64;
+            let mut processed = 0u64;
+            for executor in due {
+                let obligations = Obligations::<T>::get(&executor);
+                let disputes = PendingSlashCount::<T>::get(&executor);
+                if !obligations.is_zero() || disputes > 0 {
+                    Self::deposit_event(Event::ExitBlocked(executor, obligations, disputes));
+                    continue
+                }
+
+                let bond = Self::remove_executor(&executor);
+                processed = processed.saturating_add(1);
+
+                Self::deposit_event(Event::ExecutorLeft(executor, bond));
+            }
+
+            (inspected, processed)
+        }
+
+        /// Count a missed heartbeat against every member of the active set of the closing
+        /// round that didn't submit one, deactivating those that missed `MaxMissedHeartbeats`
+        /// rounds in a row. Returns the number of members checked and the weight of releasing the
+        /// deactivated ones.
+        pub fn check_heartbeats(closing_round: RoundIndex) -> (u64, Weight) {
+            let active_set = ActiveSet::<T>::get();
+            let mut weight = Weight::zero();
+
+            for executor in active_set.iter() {
+                if LastHeartbeat::<T>::get(executor).unwrap_or_default() >= closing_round {
+                    MissedHeartbeats::<T>::remove(executor);
+                    continue
+                }
+
+                let missed = MissedHeartbeats::<T>::get(executor).saturating_add(1);
+                if missed < T::MaxMissedHeartbeats::get() {
+                    MissedHeartbeats::<T>::insert(executor, missed);
+                    continue
+                }
+
+                MissedHeartbeats::<T>::remove(executor);
+                Deactivated::<T>::insert(executor, T::Clock::current_round().index);
+                ActiveSet::<T>::mutate(|members| members.retain(|member| member != executor));
+                weight = weight
+                    .saturating_add(T::OnExecutorDeactivated::on_executor_deactivated(executor));
+
+                Self::deposit_event(Event::ExecutorDeactivated(executor.clone(), missed));
+            }
+
+            (active_set.len() as u64, weight)
+        }
+
+        /// Select the executors with the highest bonding capacity into the active set for
+        /// the given round. Ties are broken by account id. Executors deactivated for missing
+        /// heartbeats or leaving are left out. Returns the number of candidates.
+        pub fn select_active_set(round: RoundIndex) -> u64 {
+            let mut candidates: Vec<(T::AccountId, BalanceOf<T>)> = Executors::<T>::iter_keys()
+                .filter(|executor| {
+                    !Deactivated::<T>::contains_key(executor)
+                        && !ExitQueue::<T>::contains_key(executor)
+                })
+                .map(|executor| {
+                    let capacity = Self::bonding_capacity(&executor);
+                    (executor, capacity)
+                })
+                .collect();
+            let candidates_count = candidates.len() as u64;
+
+            candidates.sort_by(|(a, a_capacity), (b, b_capacity)| {
+                b_capacity.cmp(a_capacity).then_with(|| a.cmp(b))
+            });
+            candidates.truncate(T::ActiveSetSize::get() as usize);
+
+            let active_set: BoundedVec<T::AccountId, T::ActiveSetSize> = BoundedVec::truncate_from(
+                candidates
+                    .into_iter()
+                    .map(|(executor, _capacity)| executor)
+                    .collect(),
+            );
+
+            ActiveSet::<T>::put(active_set.clone());
+            ActiveSetRound::<T>::put(round);
+
+            Self::deposit_event(Event::NewActiveSet(round, active_set.into_inner()));
+
+            candidates_count
+        }
+
+        /// Self-bond, delegations and capabilities of the executor, if registered.
+        pub fn standing_of(
+            executor: &T::AccountId,
+        ) -> Option<ExecutorStanding<T::AccountId, BalanceOf<T>>> {
+            let bond = Executors::<T>::get(executor)?;
+            Some(ExecutorStanding {
+                executor: executor.clone(),
+                bond,
+                stakes: Stakes::<T>::iter_prefix(executor)
+                    .map(|(owner, amount)| Bond { owner, amount })
+                    .collect(),
+                total_stake: TotalStake::<T>::get(executor),
+                capabilities: Capabilities::<T>::get(executor).into_inner(),
+                is_active: ActiveSet::<T>::get().contains(executor),
+            })
+        }
+
+        /// Standings of all registered executors.
+        pub fn standings() -> Vec<ExecutorStanding<T::AccountId, BalanceOf<T>>> {
+            Executors::<T>::iter_keys()
+                .filter_map(|executor| Self::standing_of(&executor))
+                .collect()
+        }
+
+        /// Liquidity executors offer for side effects of the given type on the given gateway:
+        /// the sum of what each executor serving them can bond, capped by its max order size.
+        /// Executors deactivated for missing heartbeats are left out.
+        pub fn corridor_liquidity(target: &TargetId, action: &Sfx4bId) -> BalanceOf<T> {
+            Capabilities::<T>::iter()
+                .filter(|(executor, _)| {
+                    !Deactivated::<T>::contains_key(executor)
+                        && !ExitQueue::<T>::contains_key(executor)
+                })
+                .filter_map(|(executor, capabilities)| {
+                    capabilities
+                        .into_iter()
+                        .find(|capability| {
+                            &capability.target == target && &capability.action == action
+                        })
+                        .map(|capability| {
+                            let capacity = Self::bonding_capacity(&executor);
+                            capability
+                                .max_order_size
+                                .map_or(capacity, |max_order_size| capacity.min(max_order_size))
+                        })
+                })
+                .fold(Zero::zero(), |total: BalanceOf<T>, liquidity| {
+                    total.saturating_add(liquidity)
+                })
+        }
+
+        /// Commission charged by the executor in the current round, accounting for an
+        /// announced change that has become due.
+        pub fn commission(executor: &T::AccountId) -> Percent {
+            match PendingCommission::<T>::get(executor) {
+                Some((applies_from, commission))
+                    if applies_from <= T::Clock::current_round().index =>
+                    commission,
+                _ => Commission::<T>::get(executor),
+            }
+        }
+
+        /// Split a reward earned by the executor between its self-bond and the stakers,
+        /// pro-rata to their contribution to the bonding capacity. The executor's commission
+        /// is deducted from the stakers' shares. Rounding remainders fall to the executor.
+        pub fn reward_shares(
+            executor: &T::AccountId,
+            reward: BalanceOf<T>,
+        ) -> Vec<(T::AccountId, BalanceOf<T>)> {
+            let capacity = Self::bonding_capacity(executor);
+            if capacity.is_zero() {
+                return vec![(executor.clone(), reward)]
+            }
+
+            let commission = Self::commission(executor);
+            let mut executor_share = reward;
+            let mut shares: Vec<(T::AccountId, BalanceOf<T>)> = Stakes::<T>::iter_prefix(executor)
+                .map(|(staker, stake)| {
+                    let gross_share = Perbill::from_rational(stake, capacity).mul_floor(reward);
+                    let share = gross_share.saturating_sub(commission.mul_floor(gross_share));
+                    executor_share = executor_share.saturating_sub(share);
+                    (staker, share)
+                })
+                .collect();
+            shares.insert(0, (executor.clone(), executor_share));
+            shares
+        }
+
+        /// Restake claimed rewards into the account's self-bond if it is a registered executor,
+        /// or else pro-rata into its stakes on executors which aren't leaving.
+        /// Returns the amount restaked; rounding remainders stay in the free balance.
+        fn compound_rewards(who: &T::AccountId, amount: BalanceOf<T>) -> BalanceOf<T> {
+            if Executors::<T>::contains_key(who) {
+                if T::Currency::reserve(who, amount).is_err() {
+                    return Zero::zero()
+                }
+                Executors::<T>::mutate(who, |maybe_bond| {
+                    if let Some(bond) = maybe_bond {
+                        *bond = bond.saturating_add(amount);
+                    }
+                });
+                return amount
+            }
+
+            let stakes: Vec<(T::AccountId, BalanceOf<T>)> = StakedExecutors::<T>::get(who)
+                .into_iter()
+                .filter(|executor| !ExitQueue::<T>::contains_key(executor))
+                .filter_map(|executor| {
+                    Stakes::<T>::get(&executor, who).map(|stake| (executor, stake))
+                })
+                .collect();
+            let total_stake = stakes
+                .iter()
+                .fold(Zero::zero(), |total: BalanceOf<T>, (_executor, stake)| {
+                    total.saturating_add(*stake)
+                });
+            if total_stake.is_zero() {
+                return Zero::zero()
+            }
+
+            let mut compounded: BalanceOf<T> = Zero::zero();
+            for (executor, stake) in stakes {
+                let share = Perbill::from_rational(stake, total_stake).mul_floor(amount);
+                if share.is_zero() || T::Currency::reserve(who, share).is_err() {
+                    continue
+                }
+                Stakes::<T>::mutate(&executor, who, |maybe_stake| {
+                    if let Some(stake) = maybe_stake {
+                        *stake = stake.saturating_add(share);
+                    }
+                });
+                TotalStake::<T>::mutate(&executor, |total| *total = total.saturating_add(share));
+                compounded = compounded.saturating_add(share);
+            }
+            compounded
+        }
+
+        fn take_staking_request(
+            executor: &T::AccountId,
+            staker: &T::AccountId,
+        ) -> Result<ScheduledStakingRequest<T::AccountId, BalanceOf<T>>, DispatchError> {
+            StakingRequests::<T>::try_mutate_exists(executor, |maybe_requests| {
+                let requests = maybe_requests
+                    .as_mut()
+                    .ok_or(Error::<T>::UnstakeNotScheduled)?;
+                let position = requests
+                    .iter()
+                    .position(|request| &request.staker == staker)
+                    .ok_or(Error::<T>::UnstakeNotScheduled)?;
+                let request = requests.remove(position);
+                if requests.is_empty() {
+                    *maybe_requests = None;
+                }
+                Ok(request)
+            })
+        }
+
+        /// Decrease the stake and release the unstaked amount back to the staker.
+        /// Returns the amount actually removed from the stake.
+        fn decrease_stake(
+            executor: &T::AccountId,
+            staker: &T::AccountId,
+            amount: BalanceOf<T>,
+        ) -> BalanceOf<T> {
+            let stake = Stakes::<T>::get(executor, staker).unwrap_or_else(Zero::zero);
+            let amount = amount.min(stake);
+            let remaining = stake.saturating_sub(amount);
+
+            if remaining.is_zero() {
+                Stakes::<T>::remove(executor, staker);
+                StakedExecutors::<T>::mutate(staker, |executors| {
+                    executors.retain(|staked| staked != executor)
+                });
+            } else {
+                Stakes::<T>::insert(executor, staker, remaining);
+            }
+            TotalStake::<T>::mutate_exists(executor, |maybe_total| {
+                let total = maybe_total
+                    .unwrap_or_else(Zero::zero)
+                    .saturating_sub(amount);
+                *maybe_total = if total.is_zero() { None } else { Some(total) };
+            });
+
+            T::Currency::unreserve(staker, amount);
+
+            amount
+        }
+
+        fn schedule_exit(executor: T::AccountId) -> DispatchResult {
+            ensure!(
+                !ExitQueue::<T>::contains_key(&executor),
+                Error::<T>::AlreadyLeaving
+            );
+
+            let exit_round = T::Clock::current_round()
+                .index
+                .saturating_add(T::UnbondingDelay::get());
+            ExitQueue::<T>::insert(&executor, exit_round);
+
+            Self::deposit_event(Event::ExitScheduled(executor, exit_round));
+
+            Ok(())
+        }
+
+        fn has_pending_slash(executor: &T::AccountId) -> bool {
+            PendingSlashCount::<T>::get(executor) > 0
+        }
+
+        fn note_slash_resolved(executor: &T::AccountId) {
+            PendingSlashCount::<T>::mutate_exists(executor, |maybe_count| {
+                let count = maybe_count.unwrap_or_default().saturating_sub(1);
+                *maybe_count = if count == 0 { None } else { Some(count) };
+            });
+        }
+
+        /// Reserve a slash can reach: the executor's self-bond and its unbonding chunks that
+        /// weren't withdrawn yet.
+        fn slashable_bond(executor: &T::AccountId) -> BalanceOf<T> {
+            Unbonding::<T>::get(executor)
+                .into_iter()
+                .fold(Self::collateral_bond(executor), |total, chunk| {
+                    total.saturating_add(chunk.amount)
+                })
+        }
+
+        /// Move up to the given amount of the executor's slashable bond to the destination,
+        /// counting it as a slash on the executor's track record. Returns the amount moved.
+        fn transfer_bond(
+            executor: &T::AccountId,
+            amount: BalanceOf<T>,
+            destination: &T::AccountId,
+        ) -> BalanceOf<T> {
+            let to_slash = amount.min(Self::slashable_bond(executor));
+            let not_slashed = T::Currency::repatriate_reserved(
+                executor,
+                destination,
+                to_slash,
+                BalanceStatus::Free,
+            )
+            .unwrap_or(to_slash);
+            let slashed = to_slash.saturating_sub(not_slashed);
+            Self::note_slash(executor, slashed);
+            slashed
+        }
+
+        /// Count the slash on the executor's track record and deduct it from the self-bond,
+        /// then from the unbonding chunks scheduled last.
+        fn note_slash(executor: &T::AccountId, slashed: BalanceOf<T>) {
+            if slashed.is_zero() {
+                return
+            }
+            TrackRecords::<T>::mutate(executor, |record| {
+                record.slashes = record.slashes.saturating_add(1);
+            });
+            let mut remaining = slashed;
+            Executors::<T>::mutate_exists(executor, |maybe_bond| {
+                if let Some(bond) = maybe_bond {
+                    let from_bond = remaining.min(*bond);
+                    *bond = bond.saturating_sub(from_bond);
+                    remaining = remaining.saturating_sub(from_bond);
+                }
+            });
+            if remaining.is_zero() {
+                return
+            }
+
+            let mut chunks = Unbonding::<T>::get(executor);
+            for chunk in chunks.iter_mut().rev() {
+                let from_chunk = remaining.min(chunk.amount);
+                chunk.amount = chunk.amount.saturating_sub(from_chunk);
+                remaining = remaining.saturating_sub(from_chunk);
+            }
+            chunks.retain(|chunk| !chunk.amount.is_zero());
+            if chunks.is_empty() {
+                Unbonding::<T>::remove(executor);
+            } else {
+                Unbonding::<T>::insert(executor, chunks);
+            }
+        }
+
+        /// Release the executor's self-bond, unbonding chunks and stakes, and clear its state.
+        /// Returns the released self-bond.
+        fn remove_executor(executor: &T::AccountId) -> BalanceOf<T> {
+            let bond = Executors::<T>::take(executor).unwrap_or_else(Zero::zero);
+            let unbonding = Unbonding::<T>::take(executor)
+                .into_iter()
+                .fold(Zero::zero(), |total: BalanceOf<T>, chunk| {
+                    total.saturating_add(chunk.amount)
+                });
+
+            T::Currency::unreserve(executor, bond.saturating_add(unbonding));
+            Self::release_stakes(executor);
+            Commission::<T>::remove(executor);
+            PendingCommission::<T>::remove(executor);
+            LastHeartbeat::<T>::remove(executor);
+            MissedHeartbeats::<T>::remove(executor);
+            Deactivated::<T>::remove(executor);
+            Capabilities::<T>::remove(executor);
+            ExitQueue::<T>::remove(executor);
+            ActiveSet::<T>::mutate(|active_set| active_set.retain(|member| member != executor));
+
+            bond
+        }
+
+        /// Release every stake delegated to the executor, dropping pending unstake requests.
+        fn release_stakes(executor: &T::AccountId) {
+            StakingRequests::<T>::remove(executor);
+            let stakers: Vec<(T::AccountId, BalanceOf<T>)> =
+                Stakes::<T>::iter_prefix(executor).collect();
+            for (staker, stake) in stakers {
+                let amount = Self::decrease_stake(executor, &staker, stake);
+                Self::deposit_event(Event::Unstaked(staker, executor.clone(), amount));
+            }
+        }
+    }
+
+    impl<T: Config> OnNewRound<BlockNumberFor<T>> for Pallet<T> {
+        fn on_new_round(round: RoundIndex, _head: BlockNumberFor<T>) -> Weight {
+            if round <= ActiveSetRound::<T>::get() {
+                return T::DbWeight::get().reads(2)
+            }
+            let rewarded = Self::issue_round_rewards(ActiveSetRound::<T>::get());
+            let (checked, deactivation_weight) = Self::check_heartbeats(ActiveSetRound::<T>::get());
+            let pruned = Self::prune_metrics(round);
+            let (pending, applied) = Self::apply_due_slashes(round);
+            let (exits, left) = Self::process_exit_queue(round);
+            let scored = Self::recompute_reputation();
+            let candidates = Self::select_active_set(round);
+            T::DbWeight::get()
+                .reads_writes(candidates.saturating_mul(2).saturating_add(2), 2)
+                .saturating_add(T::DbWeight::get().reads_writes(scored, scored))
+                .saturating_add(T::DbWeight::get().reads_writes(
+                    pending.saturating_add(applied).saturating_add(2),
+                    applied.saturating_mul(4).saturating_add(1),
+                ))
+                .saturating_add(T::DbWeight::get().writes(pruned))
+                .saturating_add(T::DbWeight::get().reads_writes(rewarded, rewarded))
+                .saturating_add(T::DbWeight::get().reads_writes(checked, checked))
+                .saturating_add(deactivation_weight)
+                .saturating_add(
+                    T::DbWeight::get()
+                        .reads_writes(exits.saturating_mul(3), left.saturating_mul(12)),
+                )
+        }
+    }
+
+    impl<T: Config> ExecutorsApi<T, BalanceOf<T>> for Pallet<T> {
+        fn active_set() -> Vec<T::AccountId> {
+            ActiveSet::<T>::get().into_inner()
+        }
+
+        fn is_active(executor: &T::AccountId) -> bool {
+            ActiveSet::<T>::get().contains(executor)
+        }
+
+        fn is_eligible_for(executor: &T::AccountId, value: BalanceOf<T>) -> bool {
+            if ExitQueue::<T>::contains_key(executor) {
+                return false
+            }
+            value < T::HighValueBidThreshold::get()
+                || Reputation::<T>::get(executor) >= T::MinReputationForHighValueBids::get()
+        }
+
+        fn note_confirmation(executor: &T::AccountId, delay: BlockNumberFor<T>) {
+            let delay: u64 = delay.unique_saturated_into();
+            TrackRecords::<T>::mutate(executor, |record| {
+                record.confirmations = record.confirmations.saturating_add(1);
+                record.total_delay = record.total_delay.saturating_add(delay);
+            });
+            Self::mutate_round_metrics(executor, |metrics| {
+                metrics.executions = metrics.executions.saturating_add(1);
+            });
+            Self::note_heartbeat(executor);
+        }
+
+        fn note_failure(executor: &T::AccountId) {
+            TrackRecords::<T>::mutate(executor, |record| {
+                record.failures = record.failures.saturating_add(1);
+            });
+            Self::mutate_round_metrics(executor, |metrics| {
+                metrics.timeouts = metrics.timeouts.saturating_add(1);
+            });
+        }
+
+        fn note_reward(executor: &T::AccountId, amount: BalanceOf<T>) {
+            Self::mutate_round_metrics(executor, |metrics| {
+                metrics.rewards = metrics.rewards.saturating_add(amount);
+            });
+        }
+
+        fn executors_serving(
+            target: &TargetId,
+            action: &Sfx4bId,
+            order_size: &BalanceOf<T>,
+        ) -> Vec<T::AccountId> {
+            Capabilities::<T>::iter()
+                .filter(|(executor, capabilities)| {
+                    !Deactivated::<T>::contains_key(executor)
+                        && !ExitQueue::<T>::contains_key(executor)
+                        && capabilities
+                            .iter()
+                            .any(|capability| capability.serves(target, action, order_size))
+                })
+                .map(|(executor, _)| executor)
+                .collect()
+        }
+
+        /// Ties are broken by total bonded stake, then by reputation. Full ties keep the incumbent.
+        fn outranks(challenger: &T::AccountId, incumbent: &T::AccountId) -> bool {
+            let rank = |executor: &T::AccountId| {
+                (
+                    Self::bonding_capacity(executor),
+                    Reputation::<T>::get(executor),
+                )
+            };
+            rank(challenger) > rank(incumbent)
+        }
+
+        fn note_obligation(executor: &T::AccountId, amount: BalanceOf<T>) {
+            Obligations::<T>::mutate(executor, |obligations| {
+                *obligations = obligations.saturating_add(amount)
+            });
+        }
+
+        fn settle_obligation(executor: &T::AccountId, amount: BalanceOf<T>) {
+            Obligations::<T>::mutate_exists(executor, |maybe_obligations| {
+                let obligations = maybe_obligations
+                    .unwrap_or_else(Zero::zero)
+                    .saturating_sub(amount);
+                *maybe_obligations = if obligations.is_zero() {
+                    None
+                } else {
+                    Some(obligations)
+                };
+            });
+        }
+
+        fn is_registered(executor: &T::AccountId) -> bool {
+            Executors::<T>::contains_key(executor)
+        }
+
+        fn reserve_bond(
+            executor: &T::AccountId,
+            amount: BalanceOf<T>,
+        ) -> Result<BalanceOf<T>, DispatchError> {
+            T::Currency::reserve(executor, amount)?;
+            Ok(amount)
+        }
+
+        fn unreserve_bond(executor: &T::AccountId, amount: BalanceOf<T>) -> BalanceOf<T> {
+            let remaining = T::Currency::unreserve(executor, amount);
+            amount.saturating_sub(remaining)
+        }
+
+        fn slash_bond(executor: &T::AccountId, amount: BalanceOf<T>) -> BalanceOf<T> {
+            let to_slash = amount.min(Self::slashable_bond(executor));
+            let (_imbalance, not_slashed) = T::Currency::slash_reserved(executor, to_slash);
+            let slashed = to_slash.saturating_sub(not_slashed);
+            Self::note_slash(executor, slashed);
+            slashed
+        }
+
+        fn increase_bond(executor: &T::AccountId, amount: BalanceOf<T>) -> BalanceOf<T> {
+            if !Executors::<T>::contains_key(executor)
+                || T::Currency::reserve(executor, amount).is_err()
+            {
+                return Self::collateral_bond(executor)
+            }
+            Executors::<T>::mutate(executor, |maybe_bond| {
+                let bond = maybe_bond.get_or_insert_with(Zero::zero);
+                *bond = bond.saturating_add(amount);
+                *bond
+            })
+        }
+
+        fn collateral_bond(executor: &T::AccountId) -> BalanceOf<T> {
+            Executors::<T>::get(executor).unwrap_or_else(Zero::zero)
+        }
+
+        fn min_executor_bond() -> BalanceOf<T> {
+            T::MinExecutorBond::get()
+        }
+
+        fn total_nominated_stake(executor: &T::AccountId) -> BalanceOf<T> {
+            TotalStake::<T>::get(executor)
+        }
+
+        fn bonding_capacity(executor: &T::AccountId) -> BalanceOf<T> {
+            Self::collateral_bond(executor).saturating_add(TotalStake::<T>::get(executor))
+        }
+
+        fn stakes(staker: &T::AccountId) -> Vec<NominatedStake<T::AccountId, BalanceOf<T>>> {
+            StakedExecutors::<T>::get(staker)
+                .into_iter()
+                .filter_map(|executor| {
+                    Stakes::<T>::get(&executor, staker).map(|nominated_stake| NominatedStake {
+                        executor,
+                        staker: staker.clone(),
+                        nominated_stake,
+                    })
+                })
+                .collect()
+        }
+
+        fn stakes_per_executor(
+            executor: &T::AccountId,
+        ) -> Vec<NominatedStake<T::AccountId, BalanceOf<T>>> {
+            Stakes::<T>::iter_prefix(executor)
+                .map(|(staker, nominated_stake)| NominatedStake {
+                    executor: executor.clone(),
+                    staker,
+                    nominated_stake,
+                })
+                .collect()
+        }
+
+        fn recalculate_executors_stakes() {}
+
+        fn accrue_reward(who: &T::AccountId, round: RoundIndex, amount: BalanceOf<T>) {
+            if amount.is_zero() {
+                return
+            }
+            AccruedRewards::<T>::mutate(who, round, |accrued| {
+                *accrued = accrued.saturating_add(amount)
+            });
+        }
+    }
+}
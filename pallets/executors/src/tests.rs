@@ -0,0 +1,1392 @@
+#[cfg(test)]
+pub mod executors_test {
+    use frame_support::{assert_noop, assert_ok, traits::Currency};
+    use sp_runtime::{DispatchError, Percent};
+    use t3rn_mini_mock_runtime::{
+        AccountId, AccruedRewards, ActiveSetRound, AutoCompound, Balance, Balances, BlockNumber,
+        Capabilities, Clock, Commission, Executors, ExecutorsActiveSet, ExecutorsDeactivated,
+        ExecutorsError, ExecutorsEvent, ExecutorsStore, ExitQueue, ExtBuilder, LastHeartbeat,
+        MinExecutorBond, MiniRuntime, MissedHeartbeats, NextSlashToApply, Obligations,
+        PendingCommission, PendingSlashes, Reputation, RoundIssuance, RoundMetrics, RuntimeEvent,
+        RuntimeOrigin, StakedExecutors, Stakes, StakingRequests, System, TotalStake, TrackRecords,
+        Unbonding,
+    };
+    use t3rn_primitives::{
+        clock::OnNewRound,
+        executors::{
+            Bond, ExecutorCapability, ExecutorRoundMetrics, ExecutorStanding,
+            Executors as ExecutorsApi, StakingAction,
+        },
+        TreasuryAccount, TreasuryAccountProvider,
+    };
+
+    const EXECUTOR: AccountId = AccountId::new([7u8; 32]);
+    const STAKER: AccountId = AccountId::new([8u8; 32]);
+
+    fn fund_executor(amount: Balance) {
+        let _ = Balances::deposit_creating(&EXECUTOR, amount);
+    }
+
+    fn register_funded_executor(executor: &AccountId, bond: Balance) {
+        let _ = Balances::deposit_creating(executor, 100);
+        assert_ok!(Executors::register_executor(
+            RuntimeOrigin::signed(executor.clone()),
+            bond
+        ));
+    }
+
+    fn fund_staker(staker: &AccountId) {
+        let _ = Balances::deposit_creating(staker, 100);
+    }
+
+    fn fund_treasury(amount: Balance) -> AccountId {
+        let treasury = MiniRuntime::get_treasury_account(TreasuryAccount::Treasury);
+        let _ = Balances::deposit_creating(&treasury, amount);
+        treasury
+    }
+
+    fn bump_rounds(rounds: u32) {
+        for _ in 0..rounds {
+            let round = Clock::current_round();
+            let next_round_start: BlockNumber = round.head + round.term;
+            System::set_block_number(next_round_start);
+            Clock::check_bump_round(next_round_start);
+        }
+    }
+
+    #[test]
+    fn register_executor_reserves_self_bond() {
+        ExtBuilder::default().build().execute_with(|| {
+            System::set_block_number(1);
+            fund_executor(100);
+
+            assert_ok!(Executors::register_executor(
+                RuntimeOrigin::signed(EXECUTOR),
+                20
+            ));
+
+            assert_eq!(ExecutorsStore::<MiniRuntime>::get(&EXECUTOR), Some(20));
+            assert_eq!(Balances::reserved_balance(&EXECUTOR), 20);
+            assert!(<Executors as ExecutorsApi<MiniRuntime, Balance>>::is_registered(&EXECUTOR));
+            // Heartbeats are due from the registration round on
+            assert_eq!(
+                LastHeartbeat::<MiniRuntime>::get(&EXECUTOR),
+                Some(Clock::current_round().index)
+            );
+            System::assert_last_event(RuntimeEvent::Executors(ExecutorsEvent::ExecutorRegistered(
+                EXECUTOR, 20,
+            )));
+
+            assert_noop!(
+                Executors::register_executor(RuntimeOrigin::signed(EXECUTOR), 20),
+                ExecutorsError::<MiniRuntime>::AlreadyRegistered
+            );
+        });
+    }
+
+    #[test]
+    fn register_executor_rejects_bond_below_minimum_or_above_free_balance() {
+        ExtBuilder::default().build().execute_with(|| {
+            fund_executor(100);
+
+            assert_noop!(
+                Executors::register_executor(
+                    RuntimeOrigin::signed(EXECUTOR),
+                    MinExecutorBond::get() - 1
+                ),
+                ExecutorsError::<MiniRuntime>::ExecutorBondTooSmall
+            );
+            assert_noop!(
+                Executors::register_executor(RuntimeOrigin::signed(EXECUTOR), 1_000),
+                ExecutorsError::<MiniRuntime>::InsufficientBalanceToReserveBond
+            );
+            assert!(!<Executors as ExecutorsApi<MiniRuntime, Balance>>::is_registered(&EXECUTOR));
+        });
+    }
+
+    #[test]
+    fn registrations_are_capped_at_max_executors() {
+        ExtBuilder::default().build().execute_with(|| {
+            // MaxExecutors = 8
+            for seed in 10u8..18 {
+                register_funded_executor(&AccountId::new([seed; 32]), 10);
+            }
+            fund_executor(100);
+
+            assert_noop!(
+                Executors::register_executor(RuntimeOrigin::signed(EXECUTOR), 10),
+                ExecutorsError::<MiniRuntime>::TooManyExecutors
+            );
+        });
+    }
+
+    #[test]
+    fn deregister_executor_releases_self_bond_after_unbonding_delay() {
+        ExtBuilder::default().build().execute_with(|| {
+            System::set_block_number(1);
+            fund_executor(100);
+
+            assert_noop!(
+                Executors::deregister_executor(RuntimeOrigin::signed(EXECUTOR)),
+                ExecutorsError::<MiniRuntime>::NotRegistered
+            );
+
+            assert_ok!(Executors::register_executor(
+                RuntimeOrigin::signed(EXECUTOR),
+                20
+            ));
+            assert_ok!(Executors::deregister_executor(RuntimeOrigin::signed(
+                EXECUTOR
+            )));
+            System::assert_last_event(RuntimeEvent::Executors(ExecutorsEvent::ExitScheduled(
+                EXECUTOR, 3,
+            )));
+            assert_noop!(
+                Executors::deregister_executor(RuntimeOrigin::signed(EXECUTOR)),
+                ExecutorsError::<MiniRuntime>::AlreadyLeaving
+            );
+
+            // The self-bond stays reserved for UnbondingDelay = 2 rounds
+            bump_rounds(1);
+            assert_eq!(ExecutorsStore::<MiniRuntime>::get(&EXECUTOR), Some(20));
+            assert_eq!(Balances::reserved_balance(&EXECUTOR), 20);
+
+            bump_rounds(1);
+            System::assert_has_event(RuntimeEvent::Executors(ExecutorsEvent::ExecutorLeft(
+                EXECUTOR, 20,
+            )));
+            assert_eq!(ExecutorsStore::<MiniRuntime>::get(&EXECUTOR), None);
+            assert_eq!(Balances::reserved_balance(&EXECUTOR), 0);
+            assert_eq!(Balances::free_balance(&EXECUTOR), 100);
+        });
+    }
+
+    #[test]
+    fn stake_extends_executors_bonding_capacity() {
+        ExtBuilder::default().build().execute_with(|| {
+            System::set_block_number(1);
+            register_funded_executor(&EXECUTOR, 20);
+            fund_staker(&STAKER);
+
+            assert_ok!(Executors::stake(
+                RuntimeOrigin::signed(STAKER),
+                EXECUTOR,
+                30
+            ));
+            assert_ok!(Executors::stake(
+                RuntimeOrigin::signed(STAKER),
+                EXECUTOR,
+                10
+            ));
+
+            assert_eq!(Stakes::<MiniRuntime>::get(&EXECUTOR, &STAKER), Some(40));
+            assert_eq!(TotalStake::<MiniRuntime>::get(&EXECUTOR), 40);
+            assert_eq!(StakedExecutors::<MiniRuntime>::get(&STAKER), vec![EXECUTOR]);
+            assert_eq!(Balances::reserved_balance(&STAKER), 40);
+            assert_eq!(
+                <Executors as ExecutorsApi<MiniRuntime, Balance>>::bonding_capacity(&EXECUTOR),
+                60
+            );
+            let stakes = <Executors as ExecutorsApi<MiniRuntime, Balance>>::stakes(&STAKER);
+            assert_eq!(stakes.len(), 1);
+            assert_eq!(stakes[0].executor, EXECUTOR);
+            assert_eq!(stakes[0].nominated_stake, 40);
+            System::assert_last_event(RuntimeEvent::Executors(ExecutorsEvent::Staked(
+                STAKER, EXECUTOR, 10,
+            )));
+        });
+    }
+
+    #[test]
+    fn stake_enforces_minimum_and_delegation_caps() {
+        ExtBuilder::default().build().execute_with(|| {
+            register_funded_executor(&EXECUTOR, 20);
+            fund_staker(&STAKER);
+
+            assert_noop!(
+                Executors::stake(RuntimeOrigin::signed(STAKER), STAKER, 10),
+                ExecutorsError::<MiniRuntime>::NotRegistered
+            );
+            assert_noop!(
+                Executors::stake(RuntimeOrigin::signed(STAKER), EXECUTOR, 4),
+                ExecutorsError::<MiniRuntime>::StakeTooSmall
+            );
+
+            // MaxStakersPerExecutor = 4
+            for seed in 10u8..14 {
+                let staker = AccountId::new([seed; 32]);
+                fund_staker(&staker);
+                assert_ok!(Executors::stake(RuntimeOrigin::signed(staker), EXECUTOR, 5));
+            }
+            assert_noop!(
+                Executors::stake(RuntimeOrigin::signed(STAKER), EXECUTOR, 5),
+                ExecutorsError::<MiniRuntime>::TooManyStakers
+            );
+
+            // MaxStakesPerStaker = 2
+            for seed in 20u8..22 {
+                let executor = AccountId::new([seed; 32]);
+                register_funded_executor(&executor, 10);
+                assert_ok!(Executors::stake(RuntimeOrigin::signed(STAKER), executor, 5));
+            }
+            let executor = AccountId::new([22u8; 32]);
+            register_funded_executor(&executor, 10);
+            assert_noop!(
+                Executors::stake(RuntimeOrigin::signed(STAKER), executor, 5),
+                ExecutorsError::<MiniRuntime>::TooManyStakes
+            );
+        });
+    }
+
+    #[test]
+    fn scheduled_unstake_releases_stake_after_delay() {
+        ExtBuilder::default().build().execute_with(|| {
+            System::set_block_number(1);
+            register_funded_executor(&EXECUTOR, 20);
+            fund_staker(&STAKER);
+            assert_ok!(Executors::stake(
+                RuntimeOrigin::signed(STAKER),
+                EXECUTOR,
+                30
+            ));
+
+            assert_noop!(
+                Executors::schedule_unstake(RuntimeOrigin::signed(STAKER), EXECUTOR, 28),
+                ExecutorsError::<MiniRuntime>::StakeTooSmall
+            );
+            assert_ok!(Executors::schedule_unstake(
+                RuntimeOrigin::signed(STAKER),
+                EXECUTOR,
+                10
+            ));
+            let request = StakingRequests::<MiniRuntime>::get(&EXECUTOR)[0].clone();
+            assert_eq!(request.action, StakingAction::Decrease(10));
+            assert_eq!(request.when_executable, Clock::current_round().index + 2);
+            assert_noop!(
+                Executors::schedule_unstake(RuntimeOrigin::signed(STAKER), EXECUTOR, 5),
+                ExecutorsError::<MiniRuntime>::UnstakeAlreadyScheduled
+            );
+
+            bump_rounds(1);
+            assert_noop!(
+                Executors::execute_unstake(RuntimeOrigin::signed(STAKER), EXECUTOR),
+                ExecutorsError::<MiniRuntime>::UnstakeNotDue
+            );
+
+            bump_rounds(1);
+            assert_ok!(Executors::execute_unstake(
+                RuntimeOrigin::signed(STAKER),
+                EXECUTOR
+            ));
+            assert_eq!(Stakes::<MiniRuntime>::get(&EXECUTOR, &STAKER), Some(20));
+            assert_eq!(TotalStake::<MiniRuntime>::get(&EXECUTOR), 20);
+            assert_eq!(Balances::reserved_balance(&STAKER), 20);
+            assert!(StakingRequests::<MiniRuntime>::get(&EXECUTOR).is_empty());
+            System::assert_last_event(RuntimeEvent::Executors(ExecutorsEvent::Unstaked(
+                STAKER, EXECUTOR, 10,
+            )));
+
+            assert_ok!(Executors::schedule_unstake(
+                RuntimeOrigin::signed(STAKER),
+                EXECUTOR,
+                20
+            ));
+            assert_eq!(
+                StakingRequests::<MiniRuntime>::get(&EXECUTOR)[0].action,
+                StakingAction::Revoke(20)
+            );
+            assert_ok!(Executors::cancel_unstake(
+                RuntimeOrigin::signed(STAKER),
+                EXECUTOR
+            ));
+            assert_noop!(
+                Executors::execute_unstake(RuntimeOrigin::signed(STAKER), EXECUTOR),
+                ExecutorsError::<MiniRuntime>::UnstakeNotScheduled
+            );
+        });
+    }
+
+    #[test]
+    fn deregister_executor_releases_delegated_stakes() {
+        ExtBuilder::default().build().execute_with(|| {
+            register_funded_executor(&EXECUTOR, 20);
+            fund_staker(&STAKER);
+            assert_ok!(Executors::stake(
+                RuntimeOrigin::signed(STAKER),
+                EXECUTOR,
+                30
+            ));
+            assert_ok!(Executors::schedule_unstake(
+                RuntimeOrigin::signed(STAKER),
+                EXECUTOR,
+                10
+            ));
+
+            assert_ok!(Executors::deregister_executor(RuntimeOrigin::signed(
+                EXECUTOR
+            )));
+            bump_rounds(2);
+
+            assert_eq!(Stakes::<MiniRuntime>::get(&EXECUTOR, &STAKER), None);
+            assert_eq!(TotalStake::<MiniRuntime>::get(&EXECUTOR), 0);
+            assert!(StakedExecutors::<MiniRuntime>::get(&STAKER).is_empty());
+            assert!(StakingRequests::<MiniRuntime>::get(&EXECUTOR).is_empty());
+            assert_eq!(Balances::reserved_balance(&STAKER), 0);
+        });
+    }
+
+    #[test]
+    fn reward_shares_are_split_pro_rata_to_bonding_capacity() {
+        ExtBuilder::default().build().execute_with(|| {
+            register_funded_executor(&EXECUTOR, 20);
+            fund_staker(&STAKER);
+            assert_ok!(Executors::stake(
+                RuntimeOrigin::signed(STAKER),
+                EXECUTOR,
+                60
+            ));
+
+            assert_eq!(
+                Executors::reward_shares(&EXECUTOR, 100),
+                vec![(EXECUTOR, 25), (STAKER, 75)]
+            );
+            assert_eq!(Executors::reward_shares(&STAKER, 100), vec![(STAKER, 100)]);
+        });
+    }
+
+    #[test]
+    fn commission_change_applies_from_the_next_round() {
+        ExtBuilder::default().build().execute_with(|| {
+            System::set_block_number(1);
+            register_funded_executor(&EXECUTOR, 20);
+
+            assert_noop!(
+                Executors::set_commission(
+                    RuntimeOrigin::signed(EXECUTOR),
+                    Percent::from_percent(51)
+                ),
+                ExecutorsError::<MiniRuntime>::CommissionTooHigh
+            );
+            assert_noop!(
+                Executors::set_commission(RuntimeOrigin::signed(STAKER), Percent::from_percent(10)),
+                ExecutorsError::<MiniRuntime>::NotRegistered
+            );
+
+            let current_round = Clock::current_round().index;
+            assert_ok!(Executors::set_commission(
+                RuntimeOrigin::signed(EXECUTOR),
+                Percent::from_percent(10)
+            ));
+            System::assert_last_event(RuntimeEvent::Executors(
+                ExecutorsEvent::CommissionChangeAnnounced(
+                    EXECUTOR,
+                    Percent::from_percent(10),
+                    current_round + 1,
+                ),
+            ));
+            assert_eq!(Executors::commission(&EXECUTOR), Percent::from_percent(0));
+
+            bump_rounds(1);
+            assert_eq!(Executors::commission(&EXECUTOR), Percent::from_percent(10));
+
+            // Announcing again enacts the due change and delays the new one by a round.
+            assert_ok!(Executors::set_commission(
+                RuntimeOrigin::signed(EXECUTOR),
+                Percent::from_percent(20)
+            ));
+            assert_eq!(
+                Commission::<MiniRuntime>::get(&EXECUTOR),
+                Percent::from_percent(10)
+            );
+            assert_eq!(
+                PendingCommission::<MiniRuntime>::get(&EXECUTOR),
+                Some((current_round + 2, Percent::from_percent(20)))
+            );
+            assert_eq!(Executors::commission(&EXECUTOR), Percent::from_percent(10));
+        });
+    }
+
+    #[test]
+    fn reward_shares_deduct_commission_from_stakers() {
+        ExtBuilder::default().build().execute_with(|| {
+            register_funded_executor(&EXECUTOR, 20);
+            fund_staker(&STAKER);
+            assert_ok!(Executors::stake(
+                RuntimeOrigin::signed(STAKER),
+                EXECUTOR,
+                60
+            ));
+            assert_ok!(Executors::set_commission(
+                RuntimeOrigin::signed(EXECUTOR),
+                Percent::from_percent(20)
+            ));
+
+            // Announced commission does not apply within the current round.
+            assert_eq!(
+                Executors::reward_shares(&EXECUTOR, 100),
+                vec![(EXECUTOR, 25), (STAKER, 75)]
+            );
+
+            bump_rounds(1);
+            assert_eq!(
+                Executors::reward_shares(&EXECUTOR, 100),
+                vec![(EXECUTOR, 40), (STAKER, 60)]
+            );
+        });
+    }
+
+    #[test]
+    fn active_set_is_selected_by_bonding_capacity_at_round_boundary() {
+        ExtBuilder::default().build().execute_with(|| {
+            let executor_a = AccountId::new([1u8; 32]);
+            let executor_b = AccountId::new([2u8; 32]);
+            let executor_c = AccountId::new([3u8; 32]);
+            register_funded_executor(&executor_a, 30);
+            register_funded_executor(&executor_b, 20);
+            register_funded_executor(&executor_c, 10);
+            fund_staker(&STAKER);
+            assert_ok!(Executors::stake(
+                RuntimeOrigin::signed(STAKER),
+                executor_c.clone(),
+                25
+            ));
+
+            bump_rounds(1);
+            let round = Clock::current_round();
+
+            // ActiveSetSize = 2; executor_c's capacity of 35 outranks executor_b's 20
+            let expected_active_set = vec![executor_c.clone(), executor_a.clone()];
+            assert_eq!(
+                ExecutorsActiveSet::<MiniRuntime>::get(),
+                expected_active_set
+            );
+            assert_eq!(ActiveSetRound::<MiniRuntime>::get(), round.index);
+            assert!(<Executors as ExecutorsApi<MiniRuntime, Balance>>::is_active(&executor_a));
+            assert!(!<Executors as ExecutorsApi<MiniRuntime, Balance>>::is_active(&executor_b));
+            System::assert_last_event(RuntimeEvent::Executors(ExecutorsEvent::NewActiveSet(
+                round.index,
+                expected_active_set,
+            )));
+
+            // The set is only reselected once per round
+            assert_ok!(Executors::stake(
+                RuntimeOrigin::signed(STAKER),
+                executor_b.clone(),
+                50
+            ));
+            Executors::on_new_round(round.index, round.head + 1);
+            assert!(!<Executors as ExecutorsApi<MiniRuntime, Balance>>::is_active(&executor_b));
+
+            // Deregistering executors aren't selected again
+            assert_ok!(Executors::deregister_executor(RuntimeOrigin::signed(
+                executor_a.clone()
+            )));
+            bump_rounds(1);
+            assert_eq!(
+                ExecutorsActiveSet::<MiniRuntime>::get(),
+                vec![executor_b, executor_c]
+            );
+        });
+    }
+
+    #[test]
+    fn reputation_is_recomputed_from_track_record_at_round_boundary() {
+        ExtBuilder::default().build().execute_with(|| {
+            register_funded_executor(&EXECUTOR, 20);
+            // Executors without history can't bid on high-value SFX (HighValueBidThreshold = 1_000)
+            assert!(
+                <Executors as ExecutorsApi<MiniRuntime, Balance>>::is_eligible_for(&EXECUTOR, 999)
+            );
+            assert!(
+                !<Executors as ExecutorsApi<MiniRuntime, Balance>>::is_eligible_for(
+                    &EXECUTOR, 1_000
+                )
+            );
+
+            for delay in [0u32, 5, 5, 10] {
+                <Executors as ExecutorsApi<MiniRuntime, Balance>>::note_confirmation(
+                    &EXECUTOR, delay,
+                );
+            }
+            assert_eq!(TrackRecords::<MiniRuntime>::get(&EXECUTOR).confirmations, 4);
+            assert_eq!(
+                TrackRecords::<MiniRuntime>::get(&EXECUTOR).average_delay(),
+                5
+            );
+
+            // Reputation only changes at the round boundary
+            assert_eq!(
+                Reputation::<MiniRuntime>::get(&EXECUTOR),
+                Percent::from_percent(0)
+            );
+            bump_rounds(1);
+
+            // 70% * 100% success rate + 30% * 50% latency score (avg. delay 5 of max. 10)
+            assert_eq!(
+                Reputation::<MiniRuntime>::get(&EXECUTOR),
+                Percent::from_percent(85)
+            );
+            assert!(
+                <Executors as ExecutorsApi<MiniRuntime, Balance>>::is_eligible_for(
+                    &EXECUTOR, 1_000
+                )
+            );
+
+            <Executors as ExecutorsApi<MiniRuntime, Balance>>::note_failure(&EXECUTOR);
+            assert_eq!(
+                <Executors as ExecutorsApi<MiniRuntime, Balance>>::slash_bond(&EXECUTOR, 5),
+                5
+            );
+            assert_eq!(TrackRecords::<MiniRuntime>::get(&EXECUTOR).slashes, 1);
+            assert_eq!(TrackRecords::<MiniRuntime>::get(&EXECUTOR).failures, 1);
+
+            bump_rounds(1);
+
+            // 70% * 80% success rate + 15% latency score - 10% per slash
+            assert_eq!(
+                Reputation::<MiniRuntime>::get(&EXECUTOR),
+                Percent::from_percent(61)
+            );
+        });
+    }
+
+    #[test]
+    fn reported_slash_applies_to_slash_treasury_after_appeal_period() {
+        ExtBuilder::default().build().execute_with(|| {
+            System::set_block_number(1);
+            register_funded_executor(&EXECUTOR, 20);
+            let slash_treasury = MiniRuntime::get_treasury_account(TreasuryAccount::Slash);
+            let treasury_balance = Balances::free_balance(&slash_treasury);
+
+            assert_noop!(
+                Executors::report_slash(RuntimeOrigin::signed(STAKER), EXECUTOR, 5, None, 0),
+                DispatchError::BadOrigin
+            );
+            assert_ok!(Executors::report_slash(
+                RuntimeOrigin::root(),
+                EXECUTOR,
+                5,
+                None,
+                0
+            ));
+            System::assert_last_event(RuntimeEvent::Executors(ExecutorsEvent::SlashReported(
+                0, EXECUTOR, 5, 3,
+            )));
+
+            // Executors can't escape a pending slash by deregistering
+            assert_noop!(
+                Executors::deregister_executor(RuntimeOrigin::signed(EXECUTOR)),
+                ExecutorsError::<MiniRuntime>::SlashPending
+            );
+
+            bump_rounds(1);
+            assert!(PendingSlashes::<MiniRuntime>::get(0).is_some());
+            assert_eq!(ExecutorsStore::<MiniRuntime>::get(&EXECUTOR), Some(20));
+
+            bump_rounds(1);
+            assert!(PendingSlashes::<MiniRuntime>::get(0).is_none());
+            assert_eq!(ExecutorsStore::<MiniRuntime>::get(&EXECUTOR), Some(15));
+            assert_eq!(Balances::reserved_balance(&EXECUTOR), 15);
+            assert_eq!(
+                Balances::free_balance(&slash_treasury),
+                treasury_balance + 5
+            );
+            assert_eq!(TrackRecords::<MiniRuntime>::get(&EXECUTOR).slashes, 1);
+            assert!(System::events().iter().any(|record| record.event
+                == RuntimeEvent::Executors(ExecutorsEvent::SlashApplied(
+                    0,
+                    EXECUTOR,
+                    5,
+                    slash_treasury.clone()
+                ))));
+
+            assert_ok!(Executors::deregister_executor(RuntimeOrigin::signed(
+                EXECUTOR
+            )));
+        });
+    }
+
+    #[test]
+    fn slash_is_capped_by_bond_and_paid_to_beneficiary() {
+        ExtBuilder::default().build().execute_with(|| {
+            System::set_block_number(1);
+            register_funded_executor(&EXECUTOR, 20);
+            fund_staker(&STAKER);
+
+            assert_ok!(Executors::report_slash(
+                RuntimeOrigin::root(),
+                EXECUTOR,
+                50,
+                Some(STAKER),
+                50
+            ));
+            bump_rounds(2);
+
+            assert_eq!(ExecutorsStore::<MiniRuntime>::get(&EXECUTOR), Some(0));
+            assert_eq!(Balances::reserved_balance(&EXECUTOR), 0);
+            assert_eq!(Balances::free_balance(&STAKER), 120);
+        });
+    }
+
+    #[test]
+    fn due_slashes_are_applied_in_report_order_up_to_the_per_round_limit() {
+        ExtBuilder::default().build().execute_with(|| {
+            System::set_block_number(1);
+            register_funded_executor(&EXECUTOR, 20);
+            for _ in 0..4 {
+                assert_ok!(Executors::report_slash(
+                    RuntimeOrigin::root(),
+                    EXECUTOR,
+                    1,
+                    None,
+                    0
+                ));
+            }
+            assert_ok!(Executors::cancel_slash(RuntimeOrigin::root(), 1));
+
+            // Slashes apply at round 3; the walk stops at the first one not due
+            bump_rounds(1);
+            assert_eq!(NextSlashToApply::<MiniRuntime>::get(), 0);
+
+            // MaxSlashesPerRound = 2, the cancelled slash counts as inspected
+            bump_rounds(1);
+            assert_eq!(NextSlashToApply::<MiniRuntime>::get(), 2);
+            assert_eq!(ExecutorsStore::<MiniRuntime>::get(&EXECUTOR), Some(19));
+            assert_eq!(Executors::pending_slashes_of(&EXECUTOR), 2);
+
+            bump_rounds(1);
+            assert_eq!(NextSlashToApply::<MiniRuntime>::get(), 4);
+            assert_eq!(ExecutorsStore::<MiniRuntime>::get(&EXECUTOR), Some(17));
+            assert_eq!(Executors::pending_slashes_of(&EXECUTOR), 0);
+            assert!(PendingSlashes::<MiniRuntime>::iter().next().is_none());
+        });
+    }
+
+    #[test]
+    fn pending_slash_can_be_cancelled_during_appeal_period() {
+        ExtBuilder::default().build().execute_with(|| {
+            System::set_block_number(1);
+            register_funded_executor(&EXECUTOR, 20);
+
+            assert_ok!(Executors::report_slash(
+                RuntimeOrigin::root(),
+                EXECUTOR,
+                5,
+                None,
+                0
+            ));
+            assert_noop!(
+                Executors::cancel_slash(RuntimeOrigin::signed(EXECUTOR), 0),
+                DispatchError::BadOrigin
+            );
+            assert_noop!(
+                Executors::cancel_slash(RuntimeOrigin::root(), 1),
+                ExecutorsError::<MiniRuntime>::SlashNotFound
+            );
+            assert_ok!(Executors::cancel_slash(RuntimeOrigin::root(), 0));
+            System::assert_last_event(RuntimeEvent::Executors(ExecutorsEvent::SlashCancelled(
+                0, EXECUTOR,
+            )));
+
+            bump_rounds(2);
+
+            assert_eq!(ExecutorsStore::<MiniRuntime>::get(&EXECUTOR), Some(20));
+            assert_eq!(TrackRecords::<MiniRuntime>::get(&EXECUTOR).slashes, 0);
+        });
+    }
+
+    #[test]
+    fn unbonded_self_bond_is_withdrawable_after_delay() {
+        ExtBuilder::default().build().execute_with(|| {
+            System::set_block_number(1);
+            register_funded_executor(&EXECUTOR, 20);
+
+            assert_noop!(
+                Executors::schedule_unbond(RuntimeOrigin::signed(EXECUTOR), 25),
+                ExecutorsError::<MiniRuntime>::UnbondAmountExceedsBond
+            );
+            assert_noop!(
+                Executors::schedule_unbond(RuntimeOrigin::signed(EXECUTOR), 15),
+                ExecutorsError::<MiniRuntime>::ExecutorBondTooSmall
+            );
+            assert_ok!(Executors::schedule_unbond(
+                RuntimeOrigin::signed(EXECUTOR),
+                5
+            ));
+            System::assert_last_event(RuntimeEvent::Executors(ExecutorsEvent::UnbondScheduled(
+                EXECUTOR, 5, 3,
+            )));
+
+            // Unbonded chunks stop backing optimistic bids right away but stay reserved
+            assert_eq!(ExecutorsStore::<MiniRuntime>::get(&EXECUTOR), Some(15));
+            assert_eq!(
+                <Executors as ExecutorsApi<MiniRuntime, Balance>>::bonding_capacity(&EXECUTOR),
+                15
+            );
+            assert_eq!(Balances::reserved_balance(&EXECUTOR), 20);
+
+            assert_noop!(
+                Executors::withdraw_unbonded(RuntimeOrigin::signed(EXECUTOR)),
+                ExecutorsError::<MiniRuntime>::NothingToWithdraw
+            );
+
+            bump_rounds(2);
+            assert_ok!(Executors::withdraw_unbonded(RuntimeOrigin::signed(
+                EXECUTOR
+            )));
+            System::assert_last_event(RuntimeEvent::Executors(ExecutorsEvent::Withdrawn(
+                EXECUTOR, 5,
+            )));
+            assert_eq!(Balances::reserved_balance(&EXECUTOR), 15);
+            assert!(Unbonding::<MiniRuntime>::get(&EXECUTOR).is_empty());
+        });
+    }
+
+    #[test]
+    fn unbonding_chunks_are_capped_at_max_unbonding_chunks() {
+        ExtBuilder::default().build().execute_with(|| {
+            System::set_block_number(1);
+            register_funded_executor(&EXECUTOR, 20);
+
+            // MaxUnbondingChunks = 4
+            for _ in 0..4 {
+                assert_ok!(Executors::schedule_unbond(
+                    RuntimeOrigin::signed(EXECUTOR),
+                    1
+                ));
+            }
+            assert_noop!(
+                Executors::schedule_unbond(RuntimeOrigin::signed(EXECUTOR), 1),
+                ExecutorsError::<MiniRuntime>::TooManyUnbondingChunks
+            );
+
+            // Withdrawing the due chunks frees their slots
+            bump_rounds(2);
+            assert_ok!(Executors::withdraw_unbonded(RuntimeOrigin::signed(
+                EXECUTOR
+            )));
+            assert_ok!(Executors::schedule_unbond(
+                RuntimeOrigin::signed(EXECUTOR),
+                1
+            ));
+            assert_eq!(Unbonding::<MiniRuntime>::get(&EXECUTOR).len(), 1);
+            assert_eq!(ExecutorsStore::<MiniRuntime>::get(&EXECUTOR), Some(15));
+        });
+    }
+
+    #[test]
+    fn unsettled_obligations_block_withdrawals() {
+        ExtBuilder::default().build().execute_with(|| {
+            System::set_block_number(1);
+            register_funded_executor(&EXECUTOR, 20);
+            fund_staker(&STAKER);
+            assert_ok!(Executors::stake(
+                RuntimeOrigin::signed(STAKER),
+                EXECUTOR,
+                10
+            ));
+            assert_ok!(Executors::schedule_unstake(
+                RuntimeOrigin::signed(STAKER),
+                EXECUTOR,
+                10
+            ));
+            assert_ok!(Executors::schedule_unbond(
+                RuntimeOrigin::signed(EXECUTOR),
+                5
+            ));
+
+            <Executors as ExecutorsApi<MiniRuntime, Balance>>::note_obligation(&EXECUTOR, 8);
+            assert_eq!(Obligations::<MiniRuntime>::get(&EXECUTOR), 8);
+
+            bump_rounds(2);
+            assert_noop!(
+                Executors::withdraw_unbonded(RuntimeOrigin::signed(EXECUTOR)),
+                ExecutorsError::<MiniRuntime>::UnsettledObligations
+            );
+            assert_noop!(
+                Executors::execute_unstake(RuntimeOrigin::signed(STAKER), EXECUTOR),
+                ExecutorsError::<MiniRuntime>::UnsettledObligations
+            );
+            assert_noop!(
+                Executors::deregister_executor(RuntimeOrigin::signed(EXECUTOR)),
+                ExecutorsError::<MiniRuntime>::UnsettledObligations
+            );
+
+            <Executors as ExecutorsApi<MiniRuntime, Balance>>::settle_obligation(&EXECUTOR, 8);
+            assert!(!Obligations::<MiniRuntime>::contains_key(&EXECUTOR));
+
+            assert_ok!(Executors::withdraw_unbonded(RuntimeOrigin::signed(
+                EXECUTOR
+            )));
+            assert_ok!(Executors::execute_unstake(
+                RuntimeOrigin::signed(STAKER),
+                EXECUTOR
+            ));
+            assert_ok!(Executors::deregister_executor(RuntimeOrigin::signed(
+                EXECUTOR
+            )));
+            bump_rounds(2);
+            assert_eq!(Balances::reserved_balance(&EXECUTOR), 0);
+            assert_eq!(Balances::reserved_balance(&STAKER), 0);
+        });
+    }
+
+    #[test]
+    fn pending_slashes_block_unbonding_and_reach_unbonding_chunks() {
+        ExtBuilder::default().build().execute_with(|| {
+            System::set_block_number(1);
+            register_funded_executor(&EXECUTOR, 20);
+            fund_staker(&STAKER);
+            assert_ok!(Executors::schedule_unbond(
+                RuntimeOrigin::signed(EXECUTOR),
+                5
+            ));
+
+            bump_rounds(1);
+            assert_ok!(Executors::report_slash(
+                RuntimeOrigin::root(),
+                EXECUTOR,
+                18,
+                Some(STAKER),
+                18
+            ));
+            assert_eq!(Executors::pending_slashes_of(&EXECUTOR), 1);
+
+            // The unbonded chunk is due, but can't leave while the slash is pending
+            bump_rounds(1);
+            assert_noop!(
+                Executors::withdraw_unbonded(RuntimeOrigin::signed(EXECUTOR)),
+                ExecutorsError::<MiniRuntime>::SlashPending
+            );
+            assert_noop!(
+                Executors::schedule_unbond(RuntimeOrigin::signed(EXECUTOR), 1),
+                ExecutorsError::<MiniRuntime>::SlashPending
+            );
+
+            // The slash exceeds the self-bond and reaches into the unbonding chunk
+            bump_rounds(1);
+            assert_eq!(Executors::pending_slashes_of(&EXECUTOR), 0);
+            assert_eq!(ExecutorsStore::<MiniRuntime>::get(&EXECUTOR), Some(0));
+            assert_eq!(Unbonding::<MiniRuntime>::get(&EXECUTOR)[0].amount, 2);
+            assert_eq!(Balances::reserved_balance(&EXECUTOR), 2);
+            assert_eq!(Balances::free_balance(&STAKER), 118);
+
+            assert_ok!(Executors::withdraw_unbonded(RuntimeOrigin::signed(
+                EXECUTOR
+            )));
+            System::assert_last_event(RuntimeEvent::Executors(ExecutorsEvent::Withdrawn(
+                EXECUTOR, 2,
+            )));
+            assert_eq!(Balances::reserved_balance(&EXECUTOR), 0);
+        });
+    }
+
+    #[test]
+    fn round_metrics_are_recorded_and_pruned_after_retention() {
+        ExtBuilder::default().build().execute_with(|| {
+            register_funded_executor(&EXECUTOR, 20);
+
+            <Executors as ExecutorsApi<MiniRuntime, Balance>>::note_confirmation(&EXECUTOR, 0);
+            <Executors as ExecutorsApi<MiniRuntime, Balance>>::note_confirmation(&EXECUTOR, 3);
+            <Executors as ExecutorsApi<MiniRuntime, Balance>>::note_reward(&EXECUTOR, 30);
+            <Executors as ExecutorsApi<MiniRuntime, Balance>>::note_failure(&EXECUTOR);
+
+            let first_round_metrics = ExecutorRoundMetrics {
+                executions: 2,
+                rewards: 30,
+                timeouts: 1,
+            };
+            assert_eq!(
+                Executors::metrics_of(&EXECUTOR),
+                vec![(1, first_round_metrics)]
+            );
+
+            bump_rounds(1);
+            <Executors as ExecutorsApi<MiniRuntime, Balance>>::note_confirmation(&EXECUTOR, 0);
+
+            let second_round_metrics = ExecutorRoundMetrics {
+                executions: 1,
+                rewards: 0,
+                timeouts: 0,
+            };
+            assert_eq!(
+                Executors::metrics_of(&EXECUTOR),
+                vec![(1, first_round_metrics), (2, second_round_metrics)]
+            );
+
+            // MetricsRetentionRounds = 2, so round 1 drops out at the start of round 3
+            bump_rounds(1);
+
+            assert!(!RoundMetrics::<MiniRuntime>::contains_key(1, &EXECUTOR));
+            assert_eq!(
+                Executors::metrics_of(&EXECUTOR),
+                vec![(2, second_round_metrics)]
+            );
+        });
+    }
+
+    #[test]
+    fn round_issuance_is_paid_to_active_executors_pro_rata_to_work() {
+        ExtBuilder::default().build().execute_with(|| {
+            System::set_block_number(1);
+            let executor_a = AccountId::new([1u8; 32]);
+            let executor_b = AccountId::new([2u8; 32]);
+            let executor_c = AccountId::new([3u8; 32]);
+            register_funded_executor(&executor_a, 40);
+            register_funded_executor(&executor_b, 20);
+            register_funded_executor(&executor_c, 10);
+            fund_staker(&STAKER);
+            assert_ok!(Executors::stake(
+                RuntimeOrigin::signed(STAKER),
+                executor_b.clone(),
+                20
+            ));
+            bump_rounds(1);
+
+            assert_noop!(
+                Executors::set_round_issuance(RuntimeOrigin::signed(STAKER), 100),
+                DispatchError::BadOrigin
+            );
+            assert_ok!(Executors::set_round_issuance(RuntimeOrigin::root(), 100));
+            assert_eq!(RoundIssuance::<MiniRuntime>::get(), 100);
+
+            for executor in [
+                &executor_a,
+                &executor_a,
+                &executor_a,
+                &executor_b,
+                &executor_c,
+            ] {
+                <Executors as ExecutorsApi<MiniRuntime, Balance>>::note_confirmation(executor, 0);
+            }
+
+            let balance_of = |account: &AccountId| Balances::free_balance(account);
+            let (a_before, b_before, c_before, staker_before) = (
+                balance_of(&executor_a),
+                balance_of(&executor_b),
+                balance_of(&executor_c),
+                balance_of(&STAKER),
+            );
+            let treasury = fund_treasury(1_000);
+            let total_issuance = Balances::total_issuance();
+
+            bump_rounds(1);
+
+            // Rewards accrue until claimed
+            assert_eq!(balance_of(&executor_a), a_before);
+            assert_eq!(AccruedRewards::<MiniRuntime>::get(&executor_a, 1), 75);
+            assert_noop!(
+                Executors::claim_rewards(RuntimeOrigin::signed(executor_a.clone()), 0),
+                ExecutorsError::<MiniRuntime>::NothingToClaim
+            );
+            let treasury_before = balance_of(&treasury);
+            for account in [&executor_a, &executor_b, &STAKER] {
+                assert_ok!(Executors::claim_rewards(
+                    RuntimeOrigin::signed(account.clone()),
+                    1
+                ));
+            }
+            assert_noop!(
+                Executors::claim_rewards(RuntimeOrigin::signed(executor_c.clone()), 1),
+                ExecutorsError::<MiniRuntime>::NothingToClaim
+            );
+            System::assert_last_event(RuntimeEvent::Executors(ExecutorsEvent::RewardsClaimed(
+                STAKER, 1, 12,
+            )));
+
+            // executor_c confirmed an SFX too but wasn't in the round's active set
+            assert_eq!(balance_of(&executor_a), a_before + 75);
+            assert_eq!(balance_of(&executor_c), c_before);
+            // executor_b's reward is split with the staker backing half of its capacity
+            assert_eq!(balance_of(&executor_b), b_before + 13);
+            assert_eq!(balance_of(&STAKER), staker_before + 12);
+            assert_eq!(balance_of(&treasury), treasury_before - 100);
+            assert_eq!(Balances::total_issuance(), total_issuance);
+            assert_eq!(AccruedRewards::<MiniRuntime>::get(&executor_a, 1), 0);
+            assert!(System::events().iter().any(|record| record.event
+                == RuntimeEvent::Executors(ExecutorsEvent::RoundRewardIssued(
+                    1,
+                    executor_a.clone(),
+                    75
+                ))));
+        });
+    }
+
+    #[test]
+    fn executors_missing_heartbeats_are_deactivated_until_they_send_one() {
+        ExtBuilder::default().build().execute_with(|| {
+            System::set_block_number(1);
+            let executor_b = AccountId::new([2u8; 32]);
+            register_funded_executor(&EXECUTOR, 20);
+            register_funded_executor(&executor_b, 20);
+
+            assert_noop!(
+                Executors::heartbeat(RuntimeOrigin::signed(STAKER)),
+                ExecutorsError::NotRegistered
+            );
+
+            bump_rounds(1);
+            assert_eq!(
+                ExecutorsActiveSet::<MiniRuntime>::get(),
+                vec![executor_b.clone(), EXECUTOR]
+            );
+
+            assert_ok!(Executors::heartbeat(RuntimeOrigin::signed(EXECUTOR)));
+            System::assert_last_event(RuntimeEvent::Executors(ExecutorsEvent::Heartbeat(
+                EXECUTOR, 2,
+            )));
+            bump_rounds(1);
+            assert_eq!(MissedHeartbeats::<MiniRuntime>::get(&EXECUTOR), 0);
+            assert_eq!(MissedHeartbeats::<MiniRuntime>::get(&executor_b), 1);
+
+            // Confirmations count as heartbeats too
+            <Executors as ExecutorsApi<MiniRuntime, Balance>>::note_confirmation(&EXECUTOR, 0);
+            assert_eq!(LastHeartbeat::<MiniRuntime>::get(&EXECUTOR), Some(3));
+            bump_rounds(1);
+            assert_eq!(MissedHeartbeats::<MiniRuntime>::get(&executor_b), 2);
+            assert!(<Executors as ExecutorsApi<MiniRuntime, Balance>>::is_active(&executor_b));
+
+            // MaxMissedHeartbeats = 3
+            assert_ok!(Executors::heartbeat(RuntimeOrigin::signed(EXECUTOR)));
+            bump_rounds(1);
+            assert!(System::events().iter().any(|record| record.event
+                == RuntimeEvent::Executors(ExecutorsEvent::ExecutorDeactivated(
+                    executor_b.clone(),
+                    3
+                ))));
+            assert_eq!(
+                ExecutorsDeactivated::<MiniRuntime>::get(&executor_b),
+                Some(5)
+            );
+            assert_eq!(MissedHeartbeats::<MiniRuntime>::get(&executor_b), 0);
+            assert_eq!(ExecutorsActiveSet::<MiniRuntime>::get(), vec![EXECUTOR]);
+
+            assert_ok!(Executors::heartbeat(RuntimeOrigin::signed(EXECUTOR)));
+            assert_ok!(Executors::heartbeat(RuntimeOrigin::signed(
+                executor_b.clone()
+            )));
+            assert_eq!(ExecutorsDeactivated::<MiniRuntime>::get(&executor_b), None);
+            bump_rounds(1);
+            assert_eq!(
+                ExecutorsActiveSet::<MiniRuntime>::get(),
+                vec![executor_b, EXECUTOR]
+            );
+        });
+    }
+
+    #[test]
+    fn declared_capabilities_select_invitees_and_back_corridor_liquidity() {
+        ExtBuilder::default().build().execute_with(|| {
+            System::set_block_number(1);
+            let executor_b = AccountId::new([2u8; 32]);
+            register_funded_executor(&EXECUTOR, 20);
+            register_funded_executor(&executor_b, 30);
+
+            let capability = |target: &[u8; 4], action: &[u8; 4], max_order_size| {
+                ExecutorCapability::<Balance> {
+                    target: *target,
+                    action: *action,
+                    max_order_size,
+                }
+            };
+            let executor_capabilities = vec![
+                capability(b"eth2", b"tran", Some(15)),
+                capability(b"eth2", b"swap", None),
+            ];
+
+            assert_noop!(
+                Executors::declare_capabilities(
+                    RuntimeOrigin::signed(STAKER),
+                    executor_capabilities.clone()
+                ),
+                ExecutorsError::NotRegistered
+            );
+            // MaxCapabilities = 2
+            assert_noop!(
+                Executors::declare_capabilities(
+                    RuntimeOrigin::signed(EXECUTOR),
+                    vec![
+                        capability(b"eth2", b"tran", None),
+                        capability(b"eth2", b"swap", None),
+                        capability(b"sepl", b"tran", None),
+                    ]
+                ),
+                ExecutorsError::TooManyCapabilities
+            );
+
+            assert_ok!(Executors::declare_capabilities(
+                RuntimeOrigin::signed(EXECUTOR),
+                executor_capabilities.clone()
+            ));
+            System::assert_last_event(RuntimeEvent::Executors(
+                ExecutorsEvent::CapabilitiesDeclared(EXECUTOR, executor_capabilities.clone()),
+            ));
+            assert_ok!(Executors::declare_capabilities(
+                RuntimeOrigin::signed(executor_b.clone()),
+                vec![capability(b"eth2", b"tran", None)]
+            ));
+            assert_eq!(
+                Capabilities::<MiniRuntime>::get(&EXECUTOR),
+                executor_capabilities
+            );
+
+            let serving = |target: &[u8; 4], action: &[u8; 4], order_size: Balance| {
+                let mut serving =
+                    <Executors as ExecutorsApi<MiniRuntime, Balance>>::executors_serving(
+                        target,
+                        action,
+                        &order_size,
+                    );
+                serving.sort();
+                serving
+            };
+            assert_eq!(
+                serving(b"eth2", b"tran", 15),
+                vec![executor_b.clone(), EXECUTOR]
+            );
+            assert_eq!(serving(b"eth2", b"tran", 16), vec![executor_b.clone()]);
+            assert_eq!(serving(b"eth2", b"swap", 1_000), vec![EXECUTOR]);
+            assert!(serving(b"sepl", b"tran", 1).is_empty());
+
+            // EXECUTOR's capacity of 20 is capped at its max order size of 15
+            assert_eq!(Executors::corridor_liquidity(b"eth2", b"tran"), 45);
+            assert_eq!(Executors::corridor_liquidity(b"eth2", b"swap"), 20);
+            assert_eq!(Executors::corridor_liquidity(b"sepl", b"tran"), 0);
+
+            assert_ok!(Executors::deregister_executor(RuntimeOrigin::signed(
+                executor_b.clone()
+            )));
+            assert_eq!(Executors::corridor_liquidity(b"eth2", b"tran"), 15);
+            bump_rounds(2);
+            assert!(!Capabilities::<MiniRuntime>::contains_key(&executor_b));
+        });
+    }
+
+    #[test]
+    fn equal_bid_ties_are_broken_by_bonded_stake_then_reputation() {
+        ExtBuilder::default().build().execute_with(|| {
+            System::set_block_number(1);
+            let executor_b = AccountId::new([9u8; 32]);
+            let executor_c = AccountId::new([10u8; 32]);
+            register_funded_executor(&EXECUTOR, 20);
+            register_funded_executor(&executor_b, 30);
+            register_funded_executor(&executor_c, 30);
+            let outranks = |challenger: &AccountId, incumbent: &AccountId| {
+                <Executors as ExecutorsApi<MiniRuntime, Balance>>::outranks(challenger, incumbent)
+            };
+
+            assert!(outranks(&executor_b, &EXECUTOR));
+            assert!(!outranks(&EXECUTOR, &executor_b));
+            // Full ties keep the incumbent
+            assert!(!outranks(&executor_c, &executor_b));
+
+            Reputation::<MiniRuntime>::insert(&executor_c, Percent::from_percent(60));
+            assert!(outranks(&executor_c, &executor_b));
+            // Stake comes before reputation
+            Reputation::<MiniRuntime>::insert(&EXECUTOR, Percent::from_percent(100));
+            assert!(outranks(&executor_b, &EXECUTOR));
+        });
+    }
+
+    #[test]
+    fn leaving_executors_exit_once_obligations_settle() {
+        ExtBuilder::default().build().execute_with(|| {
+            System::set_block_number(1);
+            register_funded_executor(&EXECUTOR, 20);
+            <Executors as ExecutorsApi<MiniRuntime, Balance>>::note_obligation(&EXECUTOR, 5);
+
+            assert_ok!(Executors::leave_executors(RuntimeOrigin::signed(EXECUTOR)));
+            let exit_round = Clock::current_round().index + 2;
+            System::assert_last_event(RuntimeEvent::Executors(ExecutorsEvent::ExitScheduled(
+                EXECUTOR, exit_round,
+            )));
+            assert_noop!(
+                Executors::leave_executors(RuntimeOrigin::signed(EXECUTOR)),
+                ExecutorsError::<MiniRuntime>::AlreadyLeaving
+            );
+            // Leaving executors take no new side effects
+            assert!(
+                !<Executors as ExecutorsApi<MiniRuntime, Balance>>::is_eligible_for(&EXECUTOR, 1)
+            );
+
+            // UnbondingDelay = 2
+            bump_rounds(2);
+            System::assert_has_event(RuntimeEvent::Executors(ExecutorsEvent::ExitBlocked(
+                EXECUTOR, 5, 0,
+            )));
+            assert_eq!(ExecutorsStore::<MiniRuntime>::get(&EXECUTOR), Some(20));
+            assert!(!ExecutorsActiveSet::<MiniRuntime>::get().contains(&EXECUTOR));
+
+            <Executors as ExecutorsApi<MiniRuntime, Balance>>::settle_obligation(&EXECUTOR, 5);
+            bump_rounds(1);
+            System::assert_has_event(RuntimeEvent::Executors(ExecutorsEvent::ExecutorLeft(
+                EXECUTOR, 20,
+            )));
+            assert_eq!(ExecutorsStore::<MiniRuntime>::get(&EXECUTOR), None);
+            assert_eq!(ExitQueue::<MiniRuntime>::get(&EXECUTOR), None);
+            assert_eq!(Balances::free_balance(&EXECUTOR), 100);
+        });
+    }
+
+    #[test]
+    fn standings_report_bonds_delegations_and_capabilities() {
+        ExtBuilder::default().build().execute_with(|| {
+            System::set_block_number(1);
+            register_funded_executor(&EXECUTOR, 20);
+            fund_staker(&STAKER);
+            assert_ok!(Executors::stake(
+                RuntimeOrigin::signed(STAKER),
+                EXECUTOR,
+                30
+            ));
+            let capabilities = vec![ExecutorCapability::<Balance> {
+                target: *b"eth2",
+                action: *b"tran",
+                max_order_size: None,
+            }];
+            assert_ok!(Executors::declare_capabilities(
+                RuntimeOrigin::signed(EXECUTOR),
+                capabilities.clone()
+            ));
+
+            let mut expected_standing = ExecutorStanding {
+                executor: EXECUTOR,
+                bond: 20,
+                stakes: vec![Bond {
+                    owner: STAKER,
+                    amount: 30,
+                }],
+                total_stake: 30,
+                capabilities,
+                is_active: false,
+            };
+            assert_eq!(
+                Executors::standing_of(&EXECUTOR),
+                Some(expected_standing.clone())
+            );
+            assert_eq!(Executors::standing_of(&STAKER), None);
+
+            bump_rounds(1);
+            expected_standing.is_active = true;
+            assert_eq!(Executors::standings(), vec![expected_standing]);
+        });
+    }
+
+    #[test]
+    fn auto_compounding_restakes_claimed_rewards_into_bond_and_stakes() {
+        ExtBuilder::default().build().execute_with(|| {
+            fund_treasury(1_000);
+            register_funded_executor(&EXECUTOR, 20);
+            fund_staker(&STAKER);
+            assert_ok!(Executors::stake(
+                RuntimeOrigin::signed(STAKER),
+                EXECUTOR,
+                20
+            ));
+            bump_rounds(1);
+            assert_ok!(Executors::set_round_issuance(RuntimeOrigin::root(), 100));
+            <Executors as ExecutorsApi<MiniRuntime, Balance>>::note_confirmation(&EXECUTOR, 0);
+            bump_rounds(1);
+
+            assert_ok!(Executors::set_auto_compound(
+                RuntimeOrigin::signed(EXECUTOR),
+                true
+            ));
+            System::assert_last_event(RuntimeEvent::Executors(ExecutorsEvent::AutoCompoundSet(
+                EXECUTOR, true,
+            )));
+            assert!(AutoCompound::<MiniRuntime>::get(&EXECUTOR));
+            assert_ok!(Executors::set_auto_compound(
+                RuntimeOrigin::signed(STAKER),
+                true
+            ));
+
+            // Rewards are split 50/50 between the self-bond and the stake
+            assert_ok!(Executors::claim_rewards(RuntimeOrigin::signed(EXECUTOR), 1));
+            System::assert_last_event(RuntimeEvent::Executors(ExecutorsEvent::RewardsCompounded(
+                EXECUTOR, 1, 50,
+            )));
+            assert_eq!(ExecutorsStore::<MiniRuntime>::get(&EXECUTOR), Some(70));
+            assert_eq!(Balances::reserved_balance(&EXECUTOR), 70);
+
+            assert_ok!(Executors::claim_rewards(RuntimeOrigin::signed(STAKER), 1));
+            System::assert_last_event(RuntimeEvent::Executors(ExecutorsEvent::RewardsCompounded(
+                STAKER, 1, 50,
+            )));
+            assert_eq!(Stakes::<MiniRuntime>::get(&EXECUTOR, &STAKER), Some(70));
+            assert_eq!(TotalStake::<MiniRuntime>::get(&EXECUTOR), 70);
+            assert_eq!(Balances::free_balance(&STAKER), 80);
+
+            // Opting out withdraws the rewards to the free balance
+            assert_ok!(Executors::set_auto_compound(
+                RuntimeOrigin::signed(EXECUTOR),
+                false
+            ));
+            assert!(!AutoCompound::<MiniRuntime>::get(&EXECUTOR));
+            <Executors as ExecutorsApi<MiniRuntime, Balance>>::note_confirmation(&EXECUTOR, 0);
+            bump_rounds(1);
+            let free_before = Balances::free_balance(&EXECUTOR);
+            assert_ok!(Executors::claim_rewards(RuntimeOrigin::signed(EXECUTOR), 2));
+            System::assert_last_event(RuntimeEvent::Executors(ExecutorsEvent::RewardsClaimed(
+                EXECUTOR, 2, 50,
+            )));
+            assert_eq!(Balances::free_balance(&EXECUTOR), free_before + 50);
+            assert_eq!(ExecutorsStore::<MiniRuntime>::get(&EXECUTOR), Some(70));
+        });
+    }
+
+    #[test]
+    fn claims_are_paid_from_the_treasury_up_to_max_rounds_per_claim() {
+        ExtBuilder::default().build().execute_with(|| {
+            System::set_block_number(1);
+            for round in 1..=5 {
+                AccruedRewards::<MiniRuntime>::insert(&STAKER, round, 10);
+            }
+            // Accrued rewards are claimable once their round starts
+            bump_rounds(4);
+
+            assert_noop!(
+                Executors::claim_rewards(RuntimeOrigin::signed(STAKER), 5),
+                ExecutorsError::<MiniRuntime>::RewardsPotExhausted
+            );
+
+            let treasury = fund_treasury(1_000);
+            // MaxRoundsPerClaim = 4
+            assert_ok!(Executors::claim_rewards(RuntimeOrigin::signed(STAKER), 5));
+            System::assert_last_event(RuntimeEvent::Executors(ExecutorsEvent::RewardsClaimed(
+                STAKER, 5, 40,
+            )));
+            assert_ok!(Executors::claim_rewards(RuntimeOrigin::signed(STAKER), 5));
+            System::assert_last_event(RuntimeEvent::Executors(ExecutorsEvent::RewardsClaimed(
+                STAKER, 5, 10,
+            )));
+            assert_noop!(
+                Executors::claim_rewards(RuntimeOrigin::signed(STAKER), 5),
+                ExecutorsError::<MiniRuntime>::NothingToClaim
+            );
+
+            assert_eq!(Balances::free_balance(&STAKER), 50);
+            assert_eq!(Balances::free_balance(&treasury), 950);
+        });
+    }
+
+    #[test]
+    fn rewards_accrued_for_future_rounds_do_not_count_towards_max_rounds_per_claim() {
+        ExtBuilder::default().build().execute_with(|| {
+            System::set_block_number(1);
+            for round in (1..=4).chain(100..=103) {
+                AccruedRewards::<MiniRuntime>::insert(&STAKER, round, 10);
+            }
+            bump_rounds(4);
+            let treasury = fund_treasury(1_000);
+
+            // MaxRoundsPerClaim = 4, all of them taken by the rounds already started
+            assert_ok!(Executors::claim_rewards(RuntimeOrigin::signed(STAKER), 103));
+            assert_eq!(Balances::free_balance(&STAKER), 40);
+            assert_eq!(Balances::free_balance(&treasury), 960);
+            for round in 100..=103 {
+                assert_eq!(AccruedRewards::<MiniRuntime>::get(&STAKER, round), 10);
+            }
+        });
+    }
+}
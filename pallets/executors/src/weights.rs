@@ -0,0 +1,341 @@
+//! Weights for pallet_executors
+//!
+//! Estimated from the benchmarks of `benchmarking.rs` ahead of a run on reference hardware,
+//! scaling with the stakers `s` counted per executor, the unbonding chunks `u` walked and the
+//! capabilities `c` declared. Claims scale with the rounds `r` they pay out and the stakes `s`
+//! they compound into. Regenerate the numbers with the command below.
+
+// Executed Command:
+// ./target/release/circuit
+// benchmark
+// pallet
+// --chain
+// dev
+// --execution
+// wasm
+// --wasm-execution
+// compiled
+// --pallet
+// pallet_executors
+// --extrinsic
+// *
+// --steps
+// 50
+// --repeat
+// 20
+// --output
+// .
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{
+    traits::Get,
+    weights::{constants::RocksDbWeight, Weight},
+};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_executors.
+pub trait WeightInfo {
+    fn register_executor() -> Weight;
+    fn deregister_executor() -> Weight;
+    fn leave_executors() -> Weight;
+    fn stake(s: u32) -> Weight;
+    fn schedule_unstake() -> Weight;
+    fn cancel_unstake() -> Weight;
+    fn execute_unstake() -> Weight;
+    fn schedule_unbond() -> Weight;
+    fn withdraw_unbonded(u: u32) -> Weight;
+    fn set_commission() -> Weight;
+    fn set_round_issuance() -> Weight;
+    fn report_slash() -> Weight;
+    fn cancel_slash() -> Weight;
+    fn heartbeat() -> Weight;
+    fn declare_capabilities(c: u32) -> Weight;
+    fn claim_rewards(r: u32, s: u32) -> Weight;
+    fn set_auto_compound() -> Weight;
+}
+
+/// Weights for pallet_executors using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+    /// Storage: Executors Executors (r:1 w:1), Executors CounterForExecutors (r:1 w:1),
+    /// System Account (r:1 w:1), Clock CurrentRound (r:1 w:0), Executors LastHeartbeat (r:0 w:1)
+    fn register_executor() -> Weight {
+        Weight::from_parts(48_000_000_u64, 0u64)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+    }
+
+    /// Storage: Executors Executors (r:1 w:0), Executors PendingSlashCount (r:1 w:0),
+    /// Executors Obligations (r:1 w:0), Clock CurrentRound (r:1 w:0), Executors ExitQueue (r:1 w:1)
+    fn deregister_executor() -> Weight {
+        Weight::from_parts(32_000_000_u64, 0u64)
+            .saturating_add(T::DbWeight::get().reads(5_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+
+    /// Storage: Executors Executors (r:1 w:0), Clock CurrentRound (r:1 w:0), Executors ExitQueue (r:1 w:1)
+    fn leave_executors() -> Weight {
+        Weight::from_parts(27_000_000_u64, 0u64)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+
+    /// Storage: Executors Executors (r:1 w:0), Executors Stakes (r:1 w:1, r:1 per staker),
+    /// Executors StakedExecutors (r:1 w:1), System Account (r:1 w:1), Executors TotalStake (r:0 w:1)
+    fn stake(s: u32) -> Weight {
+        Weight::from_parts(52_000_000_u64, 0u64)
+            .saturating_add(Weight::from_parts(1_200_000_u64, 0u64).saturating_mul(s as u64))
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().reads(1_u64).saturating_mul(s as u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+    }
+
+    /// Storage: Executors Stakes (r:1 w:0), Clock CurrentRound (r:1 w:0), Executors StakingRequests (r:1 w:1)
+    fn schedule_unstake() -> Weight {
+        Weight::from_parts(38_000_000_u64, 0u64)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+
+    /// Storage: Executors StakingRequests (r:1 w:1)
+    fn cancel_unstake() -> Weight {
+        Weight::from_parts(29_000_000_u64, 0u64)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+
+    /// Storage: Executors StakingRequests (r:2 w:1), Clock CurrentRound (r:1 w:0), Executors Obligations (r:1 w:0),
+    /// Executors Stakes (r:1 w:1), Executors StakedExecutors (r:0 w:1), Executors TotalStake (r:1 w:1),
+    /// System Account (r:1 w:1)
+    fn execute_unstake() -> Weight {
+        Weight::from_parts(61_000_000_u64, 0u64)
+            .saturating_add(T::DbWeight::get().reads(7_u64))
+            .saturating_add(T::DbWeight::get().writes(5_u64))
+    }
+
+    /// Storage: Executors Executors (r:1 w:1), Executors PendingSlashCount (r:1 w:0),
+    /// Clock CurrentRound (r:1 w:0), Executors Unbonding (r:1 w:1)
+    fn schedule_unbond() -> Weight {
+        Weight::from_parts(36_000_000_u64, 0u64)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+
+    /// Storage: Executors Obligations (r:1 w:0), Executors PendingSlashCount (r:1 w:0),
+    /// Clock CurrentRound (r:1 w:0), Executors Unbonding (r:1 w:1), System Account (r:1 w:1)
+    fn withdraw_unbonded(u: u32) -> Weight {
+        Weight::from_parts(44_000_000_u64, 0u64)
+            .saturating_add(Weight::from_parts(350_000_u64, 0u64).saturating_mul(u as u64))
+            .saturating_add(T::DbWeight::get().reads(5_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+
+    /// Storage: Executors Executors (r:1 w:0), Clock CurrentRound (r:1 w:0), Executors PendingCommission (r:1 w:1),
+    /// Executors Commission (r:1 w:1)
+    fn set_commission() -> Weight {
+        Weight::from_parts(33_000_000_u64, 0u64)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+
+    /// Storage: Executors RoundIssuance (r:0 w:1)
+    fn set_round_issuance() -> Weight {
+        Weight::from_parts(14_000_000_u64, 0u64).saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+
+    /// Storage: Executors Executors (r:1 w:0), Executors NextSlashId (r:1 w:1), Clock CurrentRound (r:1 w:0),
+    /// Executors PendingSlashes (r:0 w:1), Executors PendingSlashCount (r:1 w:1)
+    fn report_slash() -> Weight {
+        Weight::from_parts(39_000_000_u64, 0u64)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+
+    /// Storage: Executors PendingSlashes (r:1 w:1), Executors PendingSlashCount (r:1 w:1)
+    fn cancel_slash() -> Weight {
+        Weight::from_parts(31_000_000_u64, 0u64)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+
+    /// Storage: Executors Executors (r:1 w:0), Clock CurrentRound (r:1 w:0), Executors LastHeartbeat (r:0 w:1),
+    /// Executors Deactivated (r:0 w:1)
+    fn heartbeat() -> Weight {
+        Weight::from_parts(24_000_000_u64, 0u64)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+
+    /// Storage: Executors Executors (r:1 w:0), Executors Capabilities (r:0 w:1)
+    fn declare_capabilities(c: u32) -> Weight {
+        Weight::from_parts(27_000_000_u64, 0u64)
+            .saturating_add(Weight::from_parts(410_000_u64, 0u64).saturating_mul(c as u64))
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+
+    /// Storage: Clock CurrentRound (r:1 w:0), Executors AccruedRewards (r:1 w:1 per round), System Account (r:2 w:2),
+    /// Executors AutoCompound (r:1 w:0), Executors Executors (r:1 w:0), Executors StakedExecutors (r:1 w:0),
+    /// Executors ExitQueue (r:1 w:0 per stake), Executors Stakes (r:1 w:1 per stake),
+    /// Executors TotalStake (r:1 w:1 per stake)
+    fn claim_rewards(r: u32, s: u32) -> Weight {
+        Weight::from_parts(58_000_000_u64, 0u64)
+            .saturating_add(Weight::from_parts(4_100_000_u64, 0u64).saturating_mul(r as u64))
+            .saturating_add(Weight::from_parts(9_800_000_u64, 0u64).saturating_mul(s as u64))
+            .saturating_add(T::DbWeight::get().reads(6_u64))
+            .saturating_add(T::DbWeight::get().reads(1_u64).saturating_mul(r as u64))
+            .saturating_add(T::DbWeight::get().reads(3_u64).saturating_mul(s as u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64).saturating_mul(r as u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64).saturating_mul(s as u64))
+    }
+
+    /// Storage: Executors AutoCompound (r:0 w:1)
+    fn set_auto_compound() -> Weight {
+        Weight::from_parts(15_000_000_u64, 0u64).saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+    /// Storage: Executors Executors (r:1 w:1), Executors CounterForExecutors (r:1 w:1),
+    /// System Account (r:1 w:1), Clock CurrentRound (r:1 w:0), Executors LastHeartbeat (r:0 w:1)
+    fn register_executor() -> Weight {
+        Weight::from_parts(48_000_000_u64, 0u64)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
+    }
+
+    /// Storage: Executors Executors (r:1 w:0), Executors PendingSlashCount (r:1 w:0),
+    /// Executors Obligations (r:1 w:0), Clock CurrentRound (r:1 w:0), Executors ExitQueue (r:1 w:1)
+    fn deregister_executor() -> Weight {
+        Weight::from_parts(32_000_000_u64, 0u64)
+            .saturating_add(RocksDbWeight::get().reads(5_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    /// Storage: Executors Executors (r:1 w:0), Clock CurrentRound (r:1 w:0), Executors ExitQueue (r:1 w:1)
+    fn leave_executors() -> Weight {
+        Weight::from_parts(27_000_000_u64, 0u64)
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    /// Storage: Executors Executors (r:1 w:0), Executors Stakes (r:1 w:1, r:1 per staker),
+    /// Executors StakedExecutors (r:1 w:1), System Account (r:1 w:1), Executors TotalStake (r:0 w:1)
+    fn stake(s: u32) -> Weight {
+        Weight::from_parts(52_000_000_u64, 0u64)
+            .saturating_add(Weight::from_parts(1_200_000_u64, 0u64).saturating_mul(s as u64))
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().reads(1_u64).saturating_mul(s as u64))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
+    }
+
+    /// Storage: Executors Stakes (r:1 w:0), Clock CurrentRound (r:1 w:0), Executors StakingRequests (r:1 w:1)
+    fn schedule_unstake() -> Weight {
+        Weight::from_parts(38_000_000_u64, 0u64)
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    /// Storage: Executors StakingRequests (r:1 w:1)
+    fn cancel_unstake() -> Weight {
+        Weight::from_parts(29_000_000_u64, 0u64)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    /// Storage: Executors StakingRequests (r:2 w:1), Clock CurrentRound (r:1 w:0), Executors Obligations (r:1 w:0),
+    /// Executors Stakes (r:1 w:1), Executors StakedExecutors (r:0 w:1), Executors TotalStake (r:1 w:1),
+    /// System Account (r:1 w:1)
+    fn execute_unstake() -> Weight {
+        Weight::from_parts(61_000_000_u64, 0u64)
+            .saturating_add(RocksDbWeight::get().reads(7_u64))
+            .saturating_add(RocksDbWeight::get().writes(5_u64))
+    }
+
+    /// Storage: Executors Executors (r:1 w:1), Executors PendingSlashCount (r:1 w:0),
+    /// Clock CurrentRound (r:1 w:0), Executors Unbonding (r:1 w:1)
+    fn schedule_unbond() -> Weight {
+        Weight::from_parts(36_000_000_u64, 0u64)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+
+    /// Storage: Executors Obligations (r:1 w:0), Executors PendingSlashCount (r:1 w:0),
+    /// Clock CurrentRound (r:1 w:0), Executors Unbonding (r:1 w:1), System Account (r:1 w:1)
+    fn withdraw_unbonded(u: u32) -> Weight {
+        Weight::from_parts(44_000_000_u64, 0u64)
+            .saturating_add(Weight::from_parts(350_000_u64, 0u64).saturating_mul(u as u64))
+            .saturating_add(RocksDbWeight::get().reads(5_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+
+    /// Storage: Executors Executors (r:1 w:0), Clock CurrentRound (r:1 w:0), Executors PendingCommission (r:1 w:1),
+    /// Executors Commission (r:1 w:1)
+    fn set_commission() -> Weight {
+        Weight::from_parts(33_000_000_u64, 0u64)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+
+    /// Storage: Executors RoundIssuance (r:0 w:1)
+    fn set_round_issuance() -> Weight {
+        Weight::from_parts(14_000_000_u64, 0u64).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    /// Storage: Executors Executors (r:1 w:0), Executors NextSlashId (r:1 w:1), Clock CurrentRound (r:1 w:0),
+    /// Executors PendingSlashes (r:0 w:1), Executors PendingSlashCount (r:1 w:1)
+    fn report_slash() -> Weight {
+        Weight::from_parts(39_000_000_u64, 0u64)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
+
+    /// Storage: Executors PendingSlashes (r:1 w:1), Executors PendingSlashCount (r:1 w:1)
+    fn cancel_slash() -> Weight {
+        Weight::from_parts(31_000_000_u64, 0u64)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+
+    /// Storage: Executors Executors (r:1 w:0), Clock CurrentRound (r:1 w:0), Executors LastHeartbeat (r:0 w:1),
+    /// Executors Deactivated (r:0 w:1)
+    fn heartbeat() -> Weight {
+        Weight::from_parts(24_000_000_u64, 0u64)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+
+    /// Storage: Executors Executors (r:1 w:0), Executors Capabilities (r:0 w:1)
+    fn declare_capabilities(c: u32) -> Weight {
+        Weight::from_parts(27_000_000_u64, 0u64)
+            .saturating_add(Weight::from_parts(410_000_u64, 0u64).saturating_mul(c as u64))
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    /// Storage: Clock CurrentRound (r:1 w:0), Executors AccruedRewards (r:1 w:1 per round), System Account (r:2 w:2),
+    /// Executors AutoCompound (r:1 w:0), Executors Executors (r:1 w:0), Executors StakedExecutors (r:1 w:0),
+    /// Executors ExitQueue (r:1 w:0 per stake), Executors Stakes (r:1 w:1 per stake),
+    /// Executors TotalStake (r:1 w:1 per stake)
+    fn claim_rewards(r: u32, s: u32) -> Weight {
+        Weight::from_parts(58_000_000_u64, 0u64)
+            .saturating_add(Weight::from_parts(4_100_000_u64, 0u64).saturating_mul(r as u64))
+            .saturating_add(Weight::from_parts(9_800_000_u64, 0u64).saturating_mul(s as u64))
+            .saturating_add(RocksDbWeight::get().reads(6_u64))
+            .saturating_add(RocksDbWeight::get().reads(1_u64).saturating_mul(r as u64))
+            .saturating_add(RocksDbWeight::get().reads(3_u64).saturating_mul(s as u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64).saturating_mul(r as u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64).saturating_mul(s as u64))
+    }
+
+    /// Storage: Executors AutoCompound (r:0 w:1)
+    fn set_auto_compound() -> Weight {
+        Weight::from_parts(15_000_000_u64, 0u64).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+}
@@ -0,0 +1,27 @@
+//! Benchmarking setup for pallet-insurance-fund
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::{benchmarks, BenchmarkError};
+use frame_support::traits::EnsureOrigin;
+use sp_runtime::{traits::Bounded, Percent};
+
+benchmarks! {
+    set_fee_share {
+        let origin = T::AdminOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+        let fee_share = Percent::from_percent(10);
+    }: _<T::RuntimeOrigin>(origin, fee_share)
+    verify {
+        assert_eq!(FeeShare::<T>::get(), fee_share);
+    }
+
+    set_max_payout_per_incident {
+        let origin = T::AdminOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+        let max_payout = BalanceOf::<T>::max_value();
+    }: _<T::RuntimeOrigin>(origin, max_payout)
+    verify {
+        assert_eq!(MaxPayoutPerIncident::<T>::get(), max_payout);
+    }
+}
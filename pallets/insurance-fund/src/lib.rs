@@ -0,0 +1,170 @@
+//! # Insurance Fund pallet
+//!
+//! Protocol insurance fund backing users of optimistic side effects. The fund is capitalized
+//! by a governance-set `FeeShare` of the protocol fees collected by the account manager.
+//!
+//! When a slashed executor's bond is insufficient to cover the losses a user suffered from a
+//! reverted optimistic Xtx, the fund tops the compensation up with the uncovered part of the
+//! loss. Payouts are capped at `MaxPayoutPerIncident` and by the fund's balance.
+
+// Ensure we're `no_std` when compiling for Wasm.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use crate::pallet::*;
+use frame_support::traits::Currency;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+
+type BalanceOf<T> =
+    <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use crate::weights::WeightInfo;
+    use frame_support::{
+        dispatch::DispatchResult, pallet_prelude::*, traits::ExistenceRequirement, PalletId,
+    };
+    use frame_system::pallet_prelude::*;
+    use sp_runtime::{
+        traits::{AccountIdConversion, Saturating, Zero},
+        Percent,
+    };
+    use t3rn_primitives::insurance::InsuranceFund;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// The overarching event type.
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        type Currency: Currency<Self::AccountId>;
+
+        /// Id of the account holding the fund.
+        #[pallet::constant]
+        type PalletId: Get<PalletId>;
+
+        /// Origin allowed to manage the fund's parameters.
+        type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Type representing the weight of this pallet
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(PhantomData<T>);
+
+    #[pallet::storage]
+    /// Share of protocol fees capitalizing the fund.
+    pub type FeeShare<T: Config> = StorageValue<_, Percent, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn max_payout_per_incident)]
+    /// Maximum amount paid out to compensate a single incident.
+    pub type MaxPayoutPerIncident<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// New share of protocol fees capitalizing the fund.
+        FeeShareSet(Percent),
+        /// New maximum payout per incident.
+        MaxPayoutPerIncidentSet(BalanceOf<T>),
+        /// Amount credited to the fund.
+        Capitalized(BalanceOf<T>),
+        /// Beneficiary, loss, part of the loss covered by slashed funds, amount paid out.
+        Compensated(T::AccountId, BalanceOf<T>, BalanceOf<T>, BalanceOf<T>),
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        #[pallet::weight(T::WeightInfo::set_fee_share())]
+        pub fn set_fee_share(origin: OriginFor<T>, fee_share: Percent) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            FeeShare::<T>::put(fee_share);
+
+            Self::deposit_event(Event::FeeShareSet(fee_share));
+
+            Ok(())
+        }
+
+        #[pallet::weight(T::WeightInfo::set_max_payout_per_incident())]
+        pub fn set_max_payout_per_incident(
+            origin: OriginFor<T>,
+            max_payout: BalanceOf<T>,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            MaxPayoutPerIncident::<T>::put(max_payout);
+
+            Self::deposit_event(Event::MaxPayoutPerIncidentSet(max_payout));
+
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// The account holding the fund.
+        pub fn account_id() -> T::AccountId {
+            T::PalletId::get().into_account_truncating()
+        }
+
+        /// Balance the fund can pay out without reaping its account.
+        pub fn available_funds() -> BalanceOf<T> {
+            T::Currency::free_balance(&Self::account_id())
+                .saturating_sub(T::Currency::minimum_balance())
+        }
+    }
+
+    impl<T: Config> InsuranceFund<T::AccountId, BalanceOf<T>> for Pallet<T> {
+        fn fee_share() -> Percent {
+            FeeShare::<T>::get()
+        }
+
+        fn capitalize(amount: BalanceOf<T>) {
+            if amount.is_zero() {
+                return
+            }
+            let _ = T::Currency::deposit_creating(&Self::account_id(), amount);
+
+            Self::deposit_event(Event::Capitalized(amount));
+        }
+
+        fn compensate(
+            beneficiary: &T::AccountId,
+            loss: BalanceOf<T>,
+            covered: BalanceOf<T>,
+        ) -> BalanceOf<T> {
+            let payout = loss
+                .saturating_sub(covered)
+                .min(MaxPayoutPerIncident::<T>::get())
+                .min(Self::available_funds());
+            if payout.is_zero() {
+                return Zero::zero()
+            }
+
+            if let Err(err) = T::Currency::transfer(
+                &Self::account_id(),
+                beneficiary,
+                payout,
+                ExistenceRequirement::KeepAlive,
+            ) {
+                log::error!("InsuranceFund::compensate: payout failed: {:?}", err);
+                return Zero::zero()
+            }
+
+            Self::deposit_event(Event::Compensated(
+                beneficiary.clone(),
+                loss,
+                covered,
+                payout,
+            ));
+
+            payout
+        }
+    }
+}
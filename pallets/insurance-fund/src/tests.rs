@@ -0,0 +1,176 @@
+#[cfg(test)]
+pub mod insurance_fund_test {
+    use frame_support::{assert_noop, assert_ok, traits::Currency};
+    use sp_runtime::{DispatchError, Percent};
+    use t3rn_mini_mock_runtime::{
+        AccountId, AccountManager, Balance, Balances, BlockNumber, Clock, Executors, ExtBuilder,
+        FeeShare, InsuranceFund, InsuranceFundEvent, MaxPayoutPerIncident, MiniRuntime,
+        RuntimeEvent, RuntimeOrigin, System,
+    };
+    use t3rn_primitives::{insurance::InsuranceFund as InsuranceFundApi, TreasuryAccount};
+
+    const EXECUTOR: AccountId = AccountId::new([7u8; 32]);
+    const USER: AccountId = AccountId::new([8u8; 32]);
+
+    fn capitalize(amount: Balance) {
+        <InsuranceFund as InsuranceFundApi<AccountId, Balance>>::capitalize(amount);
+    }
+
+    fn bump_rounds(rounds: u32) {
+        for _ in 0..rounds {
+            let round = Clock::current_round();
+            let next_round_start: BlockNumber = round.head + round.term;
+            System::set_block_number(next_round_start);
+            Clock::check_bump_round(next_round_start);
+        }
+    }
+
+    #[test]
+    fn parameters_are_managed_by_admin_origin() {
+        ExtBuilder::default().build().execute_with(|| {
+            System::set_block_number(1);
+            assert_noop!(
+                InsuranceFund::set_fee_share(
+                    RuntimeOrigin::signed(USER),
+                    Percent::from_percent(50)
+                ),
+                DispatchError::BadOrigin
+            );
+            assert_noop!(
+                InsuranceFund::set_max_payout_per_incident(RuntimeOrigin::signed(USER), 30),
+                DispatchError::BadOrigin
+            );
+
+            assert_ok!(InsuranceFund::set_fee_share(
+                RuntimeOrigin::root(),
+                Percent::from_percent(50)
+            ));
+            assert_ok!(InsuranceFund::set_max_payout_per_incident(
+                RuntimeOrigin::root(),
+                30
+            ));
+            assert_eq!(FeeShare::<MiniRuntime>::get(), Percent::from_percent(50));
+            assert_eq!(MaxPayoutPerIncident::<MiniRuntime>::get(), 30);
+            System::assert_last_event(RuntimeEvent::InsuranceFund(
+                InsuranceFundEvent::MaxPayoutPerIncidentSet(30),
+            ));
+        });
+    }
+
+    #[test]
+    fn fund_is_capitalized_by_a_share_of_native_protocol_fees() {
+        ExtBuilder::default().build().execute_with(|| {
+            System::set_block_number(1);
+            assert_ok!(InsuranceFund::set_fee_share(
+                RuntimeOrigin::root(),
+                Percent::from_percent(50)
+            ));
+
+            // ProtocolBurn = 20%, half of the remaining 80 goes to the fund
+            AccountManager::sink_protocol_funds(TreasuryAccount::Fee, None, 100);
+            assert_eq!(Balances::free_balance(&InsuranceFund::account_id()), 40);
+
+            // Slashed deposits don't capitalize the fund
+            AccountManager::sink_protocol_funds(TreasuryAccount::Slash, None, 100);
+            assert_eq!(Balances::free_balance(&InsuranceFund::account_id()), 40);
+        });
+    }
+
+    #[test]
+    fn compensation_covers_the_shortfall_up_to_the_incident_cap() {
+        ExtBuilder::default().build().execute_with(|| {
+            System::set_block_number(1);
+            capitalize(100);
+            assert_ok!(InsuranceFund::set_max_payout_per_incident(
+                RuntimeOrigin::root(),
+                30
+            ));
+
+            let compensate = |loss: Balance, covered: Balance| {
+                <InsuranceFund as InsuranceFundApi<AccountId, Balance>>::compensate(
+                    &USER, loss, covered,
+                )
+            };
+
+            // Fully covered by slashed funds
+            assert_eq!(compensate(50, 50), 0);
+            assert_eq!(compensate(50, 40), 10);
+            System::assert_last_event(RuntimeEvent::InsuranceFund(
+                InsuranceFundEvent::Compensated(USER, 50, 40, 10),
+            ));
+            assert_eq!(compensate(100, 0), 30);
+            assert_eq!(compensate(100, 0), 30);
+            // The fund's account is kept alive with ExistentialDeposit = 1
+            assert_eq!(compensate(100, 0), 29);
+            assert_eq!(compensate(100, 0), 0);
+
+            assert_eq!(Balances::free_balance(&USER), 99);
+            assert_eq!(Balances::free_balance(&InsuranceFund::account_id()), 1);
+        });
+    }
+
+    #[test]
+    fn fund_tops_up_slashes_exceeding_the_executor_bond() {
+        ExtBuilder::default().build().execute_with(|| {
+            System::set_block_number(1);
+            capitalize(100);
+            assert_ok!(InsuranceFund::set_max_payout_per_incident(
+                RuntimeOrigin::root(),
+                20
+            ));
+            let _ = Balances::deposit_creating(&EXECUTOR, 100);
+            assert_ok!(Executors::register_executor(
+                RuntimeOrigin::signed(EXECUTOR),
+                20
+            ));
+
+            assert_ok!(Executors::report_slash(
+                RuntimeOrigin::root(),
+                EXECUTOR,
+                50,
+                Some(USER),
+                50
+            ));
+            // SlashAppealPeriod = 2
+            bump_rounds(2);
+
+            // The bond covers 20 of the 50 lost, the fund pays out its cap of 20
+            assert_eq!(Balances::free_balance(&USER), 40);
+            assert_eq!(Balances::free_balance(&InsuranceFund::account_id()), 80);
+        });
+    }
+
+    #[test]
+    fn fund_tops_up_the_reported_loss_rather_than_the_slash() {
+        ExtBuilder::default().build().execute_with(|| {
+            System::set_block_number(1);
+            capitalize(100);
+            assert_ok!(InsuranceFund::set_max_payout_per_incident(
+                RuntimeOrigin::root(),
+                30
+            ));
+            let _ = Balances::deposit_creating(&EXECUTOR, 100);
+            assert_ok!(Executors::register_executor(
+                RuntimeOrigin::signed(EXECUTOR),
+                20
+            ));
+
+            assert_ok!(Executors::report_slash(
+                RuntimeOrigin::root(),
+                EXECUTOR,
+                10,
+                Some(USER),
+                35
+            ));
+            bump_rounds(2);
+
+            // The slash covers 10 of the 35 lost although the bond could cover more, the fund
+            // pays out the remaining 25
+            System::assert_has_event(RuntimeEvent::InsuranceFund(
+                InsuranceFundEvent::Compensated(USER, 35, 10, 25),
+            ));
+            assert_eq!(Balances::free_balance(&USER), 35);
+            assert_eq!(Balances::free_balance(&InsuranceFund::account_id()), 75);
+        });
+    }
+}
@@ -0,0 +1,68 @@
+//! Weights for pallet_insurance_fund
+//!
+//! Estimated from the benchmarks of `benchmarking.rs` ahead of a run on reference hardware.
+//! Regenerate the numbers with the command below.
+
+// Executed Command:
+// ./target/release/circuit
+// benchmark
+// pallet
+// --chain
+// dev
+// --execution
+// wasm
+// --wasm-execution
+// compiled
+// --pallet
+// pallet_insurance_fund
+// --extrinsic
+// *
+// --steps
+// 50
+// --repeat
+// 20
+// --output
+// .
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{
+    traits::Get,
+    weights::{constants::RocksDbWeight, Weight},
+};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_insurance_fund.
+pub trait WeightInfo {
+    fn set_fee_share() -> Weight;
+    fn set_max_payout_per_incident() -> Weight;
+}
+
+/// Weights for pallet_insurance_fund using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+    /// Storage: InsuranceFund FeeShare (r:0 w:1)
+    fn set_fee_share() -> Weight {
+        Weight::from_parts(13_000_000_u64, 0u64).saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+
+    /// Storage: InsuranceFund MaxPayoutPerIncident (r:0 w:1)
+    fn set_max_payout_per_incident() -> Weight {
+        Weight::from_parts(13_000_000_u64, 0u64).saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+    /// Storage: InsuranceFund FeeShare (r:0 w:1)
+    fn set_fee_share() -> Weight {
+        Weight::from_parts(13_000_000_u64, 0u64).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    /// Storage: InsuranceFund MaxPayoutPerIncident (r:0 w:1)
+    fn set_max_payout_per_incident() -> Weight {
+        Weight::from_parts(13_000_000_u64, 0u64).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+}
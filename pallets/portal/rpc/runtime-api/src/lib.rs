@@ -10,11 +10,12 @@ use codec::Codec;
 
 use sp_std::prelude::*;
 
-pub use t3rn_primitives::ChainId;
+pub use t3rn_primitives::{circuit::SfxNotification, ChainId};
 use t3rn_types::sfx::SideEffect;
 
 sp_api::decl_runtime_apis! {
     /// The API to interact with pallet XDNS
+    #[api_version(3)]
     pub trait PortalRuntimeApi<AccountId, Balance, Hash> where
         AccountId: Codec,
         Balance: Codec,
@@ -27,5 +28,17 @@ sp_api::decl_runtime_apis! {
             Vec<SideEffect<AccountId, Balance>>, // side_effects
             Vec<Hash>,                         // sfx_ids
         )>;
+        /// Returns the heights of all gateways in one go
+        #[api_version(2)]
+        fn fetch_all_finalized_heights() -> Vec<(
+            ChainId, // gateway_id
+            u128,    // finalized height
+            u128,    // updated height
+            u128,    // epoch
+            bool,    // is operational
+        )>;
+        /// Returns the SFX submitted and bid on in the block called at
+        #[api_version(3)]
+        fn fetch_sfx_notifications() -> Vec<SfxNotification<Hash, AccountId, Balance>>;
     }
 }
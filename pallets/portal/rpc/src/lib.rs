@@ -3,18 +3,23 @@
 use std::sync::Arc;
 
 use codec::Codec;
+use futures::{future, stream, FutureExt, StreamExt};
 use jsonrpsee::{
     core::{Error as JsonRpseeError, RpcResult},
     proc_macros::rpc,
+    types::SubscriptionResult,
+    SubscriptionSink,
 };
+use serde::{Deserialize, Serialize};
 use sp_std::vec::Vec;
 
-use pallet_portal_rpc_runtime_api::ChainId;
-pub use pallet_portal_rpc_runtime_api::PortalRuntimeApi;
-use sp_api::ProvideRuntimeApi;
+pub use pallet_portal_rpc_runtime_api::{ChainId, PortalRuntimeApi, SfxNotification};
+use sc_client_api::BlockchainEvents;
+use sc_rpc::SubscriptionTaskExecutor;
+use sp_api::{ApiExt, ProvideRuntimeApi};
 use sp_blockchain::HeaderBackend;
 use sp_runtime::traits::{Block as BlockT, MaybeDisplay};
-use t3rn_types::sfx::SideEffect;
+use t3rn_types::sfx::{Sfx4bId, SideEffect};
 
 const RUNTIME_ERROR: i64 = 1;
 
@@ -35,19 +40,101 @@ pub trait PortalApi<AccountId, Balance, Hash> {
             Vec<Hash>,                           // sfx_ids
         )>,
     >;
+
+    /// Returns the finalized and updated heights, epoch and operational status of all gateways
+    #[method(name = "portal_getAllFinalizedHeights")]
+    fn get_all_finalized_heights(
+        &self,
+    ) -> RpcResult<
+        Vec<(
+            ChainId, // gateway_id
+            u128,    // finalized height
+            u128,    // updated height
+            u128,    // epoch
+            bool,    // is operational
+        )>,
+    >;
+
+    /// Pushes the submitted Xtx and placed bids matching the filter, as new best blocks import them
+    #[subscription(
+        name = "portal_subscribeSfx" => "portal_sfx",
+        unsubscribe = "portal_unsubscribeSfx",
+        item = SfxNotification<Hash, AccountId, Balance>
+    )]
+    fn subscribe_sfx(&self, filter: Option<SfxFilter<Balance>>);
+}
+
+/// Narrows down the SFX an executor is notified of. Unset fields match any SFX.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SfxFilter<Balance> {
+    /// Gateways the SFX target
+    pub targets: Option<Vec<ChainId>>,
+    /// 4b ids of the SFX types, e.g. `tran`
+    pub sfx_types: Option<Vec<Sfx4bId>>,
+    /// Least max reward of the SFX
+    pub min_reward: Option<Balance>,
+}
+
+impl<Balance: PartialOrd> SfxFilter<Balance> {
+    pub fn matches<AccountId>(&self, side_effect: &SideEffect<AccountId, Balance>) -> bool {
+        self.targets
+            .as_ref()
+            .map_or(true, |targets| targets.contains(&side_effect.target))
+            && self
+                .sfx_types
+                .as_ref()
+                .map_or(true, |sfx_types| sfx_types.contains(&side_effect.action))
+            && self
+                .min_reward
+                .as_ref()
+                .map_or(true, |min_reward| &side_effect.max_reward >= min_reward)
+    }
+
+    /// Narrow the notification down to its matching SFX, dropping it if none match.
+    pub fn apply<Hash, AccountId>(
+        &self,
+        notification: SfxNotification<Hash, AccountId, Balance>,
+    ) -> Option<SfxNotification<Hash, AccountId, Balance>> {
+        match notification {
+            SfxNotification::XtxSubmitted {
+                xtx_id,
+                requester,
+                side_effects,
+                sfx_ids,
+            } => {
+                let (side_effects, sfx_ids): (Vec<_>, Vec<_>) = side_effects
+                    .into_iter()
+                    .zip(sfx_ids)
+                    .filter(|(side_effect, _)| self.matches(side_effect))
+                    .unzip();
+                (!side_effects.is_empty()).then_some(SfxNotification::XtxSubmitted {
+                    xtx_id,
+                    requester,
+                    side_effects,
+                    sfx_ids,
+                })
+            },
+            SfxNotification::BidPlaced {
+                ref side_effect, ..
+            } => self.matches(side_effect).then_some(notification),
+        }
+    }
 }
 
 /// A struct that implements the [`PortalApi`].
 pub struct Portal<C, P> {
     client: Arc<C>,
+    executor: SubscriptionTaskExecutor,
     _marker: std::marker::PhantomData<P>,
 }
 
 impl<C, P> Portal<C, P> {
-    /// Create new `Portal` with the given reference to the client.
-    pub fn new(client: Arc<C>) -> Self {
+    /// Create new `Portal` with the given reference to the client and executor of its subscriptions.
+    pub fn new(client: Arc<C>, executor: SubscriptionTaskExecutor) -> Self {
         Self {
             client,
+            executor,
             _marker: Default::default(),
         }
     }
@@ -56,11 +143,12 @@ impl<C, P> Portal<C, P> {
 impl<C, Block, AccountId, Balance, Hash> PortalApiServer<AccountId, Balance, Hash>
     for Portal<C, Block>
 where
-    AccountId: Codec + MaybeDisplay,
-    Balance: Codec + MaybeDisplay,
-    Hash: Codec + MaybeDisplay,
+    AccountId: Codec + MaybeDisplay + Serialize + Send + Sync + 'static,
+    Balance: Codec + MaybeDisplay + PartialOrd + Serialize + Send + Sync + 'static,
+    Hash: Codec + MaybeDisplay + Serialize + Send + Sync + 'static,
     Block: BlockT,
-    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + BlockchainEvents<Block>,
+    C: Send + Sync + 'static,
     C::Api: PortalRuntimeApi<Block, AccountId, Balance, Hash>,
 {
     // ToDo ChainId decoding is not working, like in XDNS
@@ -101,6 +189,78 @@ where
 
         Ok(result)
     }
+
+    fn get_all_finalized_heights(
+        &self,
+    ) -> RpcResult<
+        Vec<(
+            ChainId, // gateway_id
+            u128,    // finalized height
+            u128,    // updated height
+            u128,    // epoch
+            bool,    // is operational
+        )>,
+    > {
+        let api = self.client.runtime_api();
+        let at = self.client.info().best_hash;
+
+        let version = api
+            .api_version::<dyn PortalRuntimeApi<Block, AccountId, Balance, Hash>>(at)
+            .map_err(runtime_error_into_rpc_err)?
+            .unwrap_or_default();
+        if version < 2 {
+            return Err(runtime_error_into_rpc_err(format!(
+                "PortalRuntimeApi v2 required, runtime implements v{version}"
+            )))
+        }
+
+        api.fetch_all_finalized_heights(at)
+            .map_err(runtime_error_into_rpc_err)
+    }
+
+    fn subscribe_sfx(
+        &self,
+        mut sink: SubscriptionSink,
+        filter: Option<SfxFilter<Balance>>,
+    ) -> SubscriptionResult {
+        sink.accept()?;
+
+        let client = self.client.clone();
+        let filter = filter.unwrap_or_default();
+        let stream = self
+            .client
+            .import_notification_stream()
+            .filter_map(move |notification| {
+                if !notification.is_new_best {
+                    return future::ready(None)
+                }
+                // Blocks of runtimes not exposing the notifications yet have none to push
+                let api = client.runtime_api();
+                let exposes_notifications = api
+                    .api_version::<dyn PortalRuntimeApi<Block, AccountId, Balance, Hash>>(
+                        notification.hash,
+                    )
+                    .ok()
+                    .flatten()
+                    .map_or(false, |version| version >= 3);
+                if !exposes_notifications {
+                    return future::ready(None)
+                }
+                let notifications = api
+                    .fetch_sfx_notifications(notification.hash)
+                    .unwrap_or_default();
+                future::ready(Some(stream::iter(notifications)))
+            })
+            .flatten()
+            .filter_map(move |notification| future::ready(filter.apply(notification)));
+
+        let fut = async move {
+            sink.pipe_from_stream(stream).await;
+        };
+        self.executor
+            .spawn("portal-rpc-subscription", Some("rpc"), fut.boxed());
+        Ok(())
+    }
 }
 
 fn runtime_error_into_rpc_err(err: impl std::fmt::Debug) -> JsonRpseeError {
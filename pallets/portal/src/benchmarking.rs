@@ -0,0 +1,175 @@
+//! Benchmarking setup for pallet-portal
+//!
+//! Gateways are registered with the Rococo light client, so headers and inclusion proofs are
+//! verified against GRANDPA justifications and storage proofs built by the fixtures of
+//! pallet-grandpa-finality-verifier.
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet as Portal;
+use codec::Encode;
+use frame_benchmarking::{benchmarks, whitelisted_caller, BenchmarkError};
+use frame_support::{sp_runtime::traits::Header as _, traits::EnsureOrigin};
+use frame_system::RawOrigin;
+use pallet_grandpa_finality_verifier::{
+    benchmarking::{
+        make_authorities, make_events_proof, make_headers_data, make_registration_data,
+        EVENT_PAYLOAD_LEN, MAX_AUTHORITIES, MAX_EVENTS_LEN, MAX_HEADERS_RANGE,
+    },
+    light_clients::RococoInstance,
+    types::RelaychainInclusionProof,
+    BridgedHeader,
+};
+use sp_consensus_grandpa::AuthorityId;
+use sp_std::vec;
+use t3rn_primitives::{portal::Portal as PortalT, ExecutionVendor, SubstrateToken};
+
+const GATEWAY_ID: ChainId = *b"pbnc";
+const TOKEN_ID: u32 = 9_999;
+
+type RococoBridge<T> = pallet_grandpa_finality_verifier::Pallet<T, RococoInstance>;
+
+fn token_info() -> TokenInfo {
+    TokenInfo::Substrate(SubstrateToken {
+        id: TOKEN_ID,
+        symbol: b"PBNC".to_vec(),
+        decimals: 12,
+    })
+}
+
+// Registration of a relaychain gateway finalized by `authorities`, replacing the Rococo light
+// client registered at genesis of the benchmark
+fn registration_data<T>(authorities: &[AuthorityId]) -> (BridgedHeader<T, RococoInstance>, Bytes)
+where
+    T: Config + pallet_xdns::Config + pallet_grandpa_finality_verifier::Config<RococoInstance>,
+{
+    RococoBridge::<T>::reset(RawOrigin::Root.into())
+        .expect("Light client should reset in Portal::runtime_benchmarks");
+    pallet_xdns::Pallet::<T>::do_reboot_self_gateway(RawOrigin::Root.into(), GatewayVendor::Rococo)
+        .expect("Self-gateway should reboot in Portal::runtime_benchmarks");
+    make_registration_data::<T, RococoInstance>(authorities, whitelisted_caller())
+}
+
+fn register_gateway<T>(authorities: &[AuthorityId]) -> BridgedHeader<T, RococoInstance>
+where
+    T: Config + pallet_xdns::Config + pallet_grandpa_finality_verifier::Config<RococoInstance>,
+{
+    let (genesis, registration_data) = registration_data::<T>(authorities);
+    Portal::<T>::register_gateway(
+        RawOrigin::Root.into(),
+        GATEWAY_ID,
+        TOKEN_ID,
+        GatewayVendor::Rococo,
+        ExecutionVendor::Substrate,
+        t3rn_abi::Codec::Scale,
+        None,
+        None,
+        vec![],
+        token_info(),
+        registration_data,
+    )
+    .expect("Gateway should register in Portal::runtime_benchmarks");
+    genesis
+}
+
+benchmarks! {
+    where_clause {
+        where T: pallet_xdns::Config + pallet_grandpa_finality_verifier::Config<RococoInstance>
+    }
+
+    register_gateway {
+        let a in 3 .. MAX_AUTHORITIES;
+        let (_, registration_data) = registration_data::<T>(&make_authorities(a));
+    }: _(
+        RawOrigin::Root,
+        GATEWAY_ID,
+        TOKEN_ID,
+        GatewayVendor::Rococo,
+        ExecutionVendor::Substrate,
+        t3rn_abi::Codec::Scale,
+        None,
+        None,
+        vec![],
+        token_info(),
+        registration_data
+    )
+    verify {
+        assert!(match_light_client_by_gateway_id::<T>(GATEWAY_ID).is_ok());
+    }
+
+    submit_headers {
+        let h in 0 .. MAX_HEADERS_RANGE;
+        let a in 3 .. MAX_AUTHORITIES;
+        let authorities = make_authorities(a);
+        let genesis = register_gateway::<T>(&authorities);
+        let data = make_headers_data::<T, RococoInstance>(
+            &genesis,
+            h,
+            &authorities,
+            Default::default(),
+        );
+        let signed_hash = data.signed_header.hash();
+        let encoded_data = data.encode();
+    }: {
+        <Portal<T> as PortalT<T>>::submit_encoded_headers(GATEWAY_ID, encoded_data)
+            .expect("Headers should submit in Portal::runtime_benchmarks");
+    }
+    verify {
+        assert_eq!(
+            <Portal<T> as PortalT<T>>::get_latest_finalized_header(GATEWAY_ID),
+            Ok(HeaderResult::Header(signed_hash.encode()))
+        );
+    }
+
+    verify_event_inclusion {
+        let p in EVENT_PAYLOAD_LEN .. MAX_EVENTS_LEN;
+        let authorities = make_authorities(3);
+        let genesis = register_gateway::<T>(&authorities);
+        let payload = vec![1u8; EVENT_PAYLOAD_LEN as usize];
+        let (state_root, payload_proof) =
+            make_events_proof::<T, RococoInstance>(&payload, p);
+        let data = make_headers_data::<T, RococoInstance>(&genesis, 0, &authorities, state_root);
+        let block_hash = data.signed_header.hash();
+        <Portal<T> as PortalT<T>>::submit_encoded_headers(GATEWAY_ID, data.encode())
+            .expect("Headers should submit in Portal::runtime_benchmarks");
+        let inclusion_proof = RelaychainInclusionProof::<BridgedHeader<T, RococoInstance>> {
+            encoded_payload: payload.clone(),
+            payload_proof,
+            block_hash,
+        }
+        .encode();
+    }: {
+        let receipt = <Portal<T> as PortalT<T>>::verify_event_inclusion(
+            GATEWAY_ID,
+            SpeedMode::Finalized,
+            None,
+            inclusion_proof,
+        )
+        .expect("Event inclusion should verify in Portal::runtime_benchmarks");
+        assert_eq!(receipt.message, payload);
+    }
+
+    subscribe_to_headers {
+        register_gateway::<T>(&make_authorities(3));
+        let origin = T::HeaderSubscriberOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+        let para_id = T::HeaderSubscriberOrigin::ensure_origin(origin.clone())
+            .map_err(|_| BenchmarkError::Weightless)?;
+    }: _<T::RuntimeOrigin>(origin, GATEWAY_ID, vec![0u8; 32])
+    verify {
+        assert!(<HeaderSubscriptions<T>>::contains_key(GATEWAY_ID, para_id));
+    }
+
+    unsubscribe_from_headers {
+        register_gateway::<T>(&make_authorities(3));
+        let origin = T::HeaderSubscriberOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+        let para_id = T::HeaderSubscriberOrigin::ensure_origin(origin.clone())
+            .map_err(|_| BenchmarkError::Weightless)?;
+        Portal::<T>::subscribe_to_headers(origin.clone(), GATEWAY_ID, vec![0u8; 32])
+            .expect("Sibling should subscribe in Portal::runtime_benchmarks");
+    }: _<T::RuntimeOrigin>(origin, GATEWAY_ID)
+    verify {
+        assert!(!<HeaderSubscriptions<T>>::contains_key(GATEWAY_ID, para_id));
+    }
+}
@@ -1,7 +1,11 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+use codec::Encode;
 use frame_support::{
-    sp_runtime::{traits::Zero, DispatchError},
+    sp_runtime::{
+        traits::{One, Saturating, Zero},
+        DispatchError, DispatchResult, SaturatedConversion,
+    },
     traits::Get,
 };
 use frame_system::{
@@ -13,24 +17,93 @@ pub use pallet::*;
 use sp_std::{boxed::Box, prelude::*};
 use t3rn_abi::recode::{recode_bytes_with_descriptor, Codec};
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(feature = "dev-simulation")]
+pub mod simulation;
 #[cfg(test)]
 mod tests;
 
 use frame_support::transactional;
 use t3rn_abi::types::Bytes;
 use t3rn_primitives::{
-    self, execution_source_to_option,
+    self,
+    compression::{self, DecompressionError},
+    execution_source_to_option,
     light_client::LightClient,
-    portal::{HeaderResult, HeightResult, Portal},
-    reexport_currency_types,
+    portal::{
+        HeaderExporter, HeaderResult, HeaderSubmissionHooks, HeaderSubscription, HeightResult,
+        OnHeadersRolledBack, Portal,
+    },
+    reexport_currency_types, retention,
     xdns::Xdns,
     ChainId, ExecutionSource, GatewayVendor, SpeedMode, TokenInfo,
 };
+use t3rn_types::sfx::Sfx4bId;
 pub mod weights;
 pub trait SelectLightClient<T: frame_system::Config> {
     fn select(vendor: GatewayVendor) -> Result<Box<dyn LightClient<T>>, Error<T>>;
 }
-use t3rn_primitives::{light_client::LightClientHeartbeat, portal::InclusionReceipt};
+
+/// Bounds the encoded data relayers submit to the light client of each vendor, checked before the
+/// light client decodes any of it.
+pub trait VendorLimits {
+    /// Maximum size of an encoded batch of headers, including its justification or update
+    fn max_headers_size(vendor: &GatewayVendor) -> u32;
+    /// Maximum size of an encoded inclusion proof
+    fn max_proof_size(vendor: &GatewayVendor) -> u32;
+}
+
+/// Head of a gateway followed by the simulated light client of a dev chain, accepting the headers
+/// submitted to it without verifying them.
+#[derive(Clone, Eq, PartialEq, Encode, codec::Decode, Debug, scale_info::TypeInfo)]
+pub struct SimulatedHead<BlockNumber> {
+    /// Number of headers accepted since the light client was initialized
+    pub height: BlockNumber,
+    /// Encoded header submitted last
+    pub header: Bytes,
+    /// Block the last header was submitted at
+    pub updated_at: BlockNumber,
+    pub is_halted: bool,
+}
+
+/// Allowance of header submissions left to a gateway, refilled as blocks pass.
+#[derive(
+    Clone, Eq, PartialEq, Encode, codec::Decode, Debug, scale_info::TypeInfo, codec::MaxEncodedLen,
+)]
+pub struct HeaderSubmissionBucket<BlockNumber> {
+    /// Submissions the gateway accepts before it's refilled again
+    pub tokens: u32,
+    /// Block the allowance was refilled at last
+    pub refilled_at: BlockNumber,
+}
+
+/// Root an event inclusion is proven against, along with the leaf of the proven event under it
+pub type InclusionCacheKey = ([u8; 32], [u8; 32]);
+
+/// Receipt of an event inclusion verified at finality, along with the source it was verified as
+/// emitted by and the slot of the ring buffer it was cached at last.
+#[derive(Clone, Eq, PartialEq, Encode, codec::Decode, Debug, scale_info::TypeInfo)]
+pub struct CachedInclusion<BlockNumber> {
+    pub receipt: InclusionReceipt<BlockNumber>,
+    pub source: Option<ExecutionSource>,
+    pub slot: u32,
+}
+
+/// No bounds beyond the light clients' own, for mocks
+impl VendorLimits for () {
+    fn max_headers_size(_vendor: &GatewayVendor) -> u32 {
+        u32::MAX
+    }
+
+    fn max_proof_size(_vendor: &GatewayVendor) -> u32 {
+        u32::MAX
+    }
+}
+use t3rn_primitives::{
+    light_client::LightClientHeartbeat,
+    portal::{InclusionReceipt, ProvableRange},
+};
 
 reexport_currency_types!();
 
@@ -56,14 +129,165 @@ pub mod pallet {
         type WeightInfo: crate::weights::WeightInfo;
         /// Selects the light client implementation
         type SelectLightClient: SelectLightClient<Self>;
+        /// Maximum sizes of the headers and proofs submitted, per vendor
+        type VendorLimits: VendorLimits;
+        /// Origin of sibling parachains subscribing to headers over XCM, resolving to their para id
+        type HeaderSubscriberOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = u32>;
+        /// Exports the latest finalized headers to the subscribed parachains
+        type HeaderExporter: HeaderExporter<BlockNumberFor<Self>>;
+        /// Maximum number of header subscriptions visited per block
+        #[pallet::constant]
+        type MaxHeaderExportsPerBlock: Get<u32>;
+        /// Maximum number of verified event inclusions kept in the cache, zero disabling it
+        #[pallet::constant]
+        type MaxCachedInclusions: Get<u32>;
+        /// Number of blocks cached inclusions are kept for, pruned once it's over
+        #[pallet::constant]
+        type RetentionPeriod: Get<BlockNumberFor<Self>>;
+        /// Maximum number of cached inclusions pruned per block
+        #[pallet::constant]
+        type MaxPrunedPerBlock: Get<u32>;
+        /// Invalidates what depended on the headers rolled back by a reorg of a gateway
+        type OnHeadersRolledBack: OnHeadersRolledBack<BlockNumberFor<Self>>;
+        /// Maximum number of header submissions a gateway accepts within HeaderSubmissionWindow,
+        /// zero disabling the limit
+        #[pallet::constant]
+        type MaxHeaderSubmissionsPerWindow: Get<u32>;
+        /// Number of blocks a gateway's allowance of header submissions takes to refill in full
+        #[pallet::constant]
+        type HeaderSubmissionWindow: Get<BlockNumberFor<Self>>;
     }
 
     #[pallet::pallet]
     #[pallet::generate_store(pub(super) trait Store)]
     pub struct Pallet<T>(_);
 
+    /// Sibling parachains subscribed to the latest finalized headers of a gateway
+    #[pallet::storage]
+    #[pallet::unbounded]
+    #[pallet::getter(fn header_subscriptions)]
+    pub type HeaderSubscriptions<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        ChainId,
+        Twox64Concat,
+        u32,
+        HeaderSubscription<BlockNumberFor<T>>,
+        OptionQuery,
+    >;
+
+    /// Raw key of the subscription the next header export round starts after
+    #[pallet::storage]
+    #[pallet::unbounded]
+    pub type HeaderExportCursor<T: Config> = StorageValue<_, Vec<u8>, OptionQuery>;
+
+    /// Receipts of the event inclusions verified at finality, keyed by the gateway and the root
+    /// and leaf they were proven with, so that any proof of the same inclusion hits its receipt
+    #[pallet::storage]
+    #[pallet::unbounded]
+    pub type CachedInclusions<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        ChainId,
+        Identity,
+        InclusionCacheKey,
+        CachedInclusion<BlockNumberFor<T>>,
+        OptionQuery,
+    >;
+
+    /// Ring buffer of the cached inclusions, the oldest evicted once it's full
+    #[pallet::storage]
+    pub type CachedInclusionsRing<T: Config> =
+        StorageMap<_, Twox64Concat, u32, (ChainId, InclusionCacheKey), OptionQuery>;
+
+    /// Slot of the ring buffer taken by the next cached inclusion
+    #[pallet::storage]
+    pub type CachedInclusionsPointer<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    /// Slots of the cached inclusions under the block they were cached at, pruned once
+    /// RetentionPeriod is over
+    #[pallet::storage]
+    pub type CachedInclusionsExpiry<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        Blake2_128Concat,
+        (ChainId, InclusionCacheKey),
+        u32,
+        OptionQuery,
+    >;
+
+    /// Oldest block holding cached inclusions left to prune
+    #[pallet::storage]
+    pub type CachedInclusionsPruneCursor<T: Config> =
+        StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
+    /// Allowance of header submissions left to each gateway within the current window
+    #[pallet::storage]
+    pub type HeaderSubmissionBuckets<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ChainId,
+        HeaderSubmissionBucket<BlockNumberFor<T>>,
+        OptionQuery,
+    >;
+
+    /// Heads of the gateways followed by the simulated light clients, under `dev-simulation` only
+    /// Fast height of each vendor with probabilistic finality, as the portal observed it last
+    #[pallet::storage]
+    #[pallet::unbounded]
+    pub type ObservedFastHeights<T: Config> =
+        StorageMap<_, Identity, GatewayVendor, BlockNumberFor<T>, OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::unbounded]
+    pub type SimulatedHeads<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        GatewayVendor,
+        SimulatedHead<BlockNumberFor<T>>,
+        OptionQuery,
+    >;
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+            Pallet::<T>::observe_fork_choice().saturating_add(Pallet::<T>::export_headers())
+        }
+
+        fn on_idle(n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            Pallet::<T>::prune_cached_inclusions(n, remaining_weight)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, frame_support::sp_runtime::TryRuntimeError> {
+            Ok((HeaderSubscriptions::<T>::iter_keys().count() as u64).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), frame_support::sp_runtime::TryRuntimeError> {
+            let subscriptions_cnt = u64::decode(&mut &state[..])
+                .map_err(|_| "Header subscriptions count undecodable")?;
+            ensure!(
+                HeaderSubscriptions::<T>::iter_values().count() as u64 == subscriptions_cnt,
+                "Header subscriptions undecodable after the upgrade"
+            );
+            // Exports of each subscription select the light client of its gateway's vendor
+            for gateway_id in
+                HeaderSubscriptions::<T>::iter_keys().map(|(gateway_id, _)| gateway_id)
+            {
+                let vendor = T::Xdns::get_verification_vendor(&gateway_id)
+                    .map_err(|_| "Subscribed gateway no longer registered")?;
+                T::SelectLightClient::select(vendor)
+                    .map_err(|_| "Subscribed gateway lost its light client")?;
+            }
+            Ok(())
+        }
+    }
+
     // Pallets use events to inform users when important changes are made.
     // https://docs.substrate.io/v3/runtime/events-and-errors
+    // Events of a gateway lead with its ChainId, as laid out by t3rn_primitives::EVENTS_VERSION.
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
@@ -74,8 +298,29 @@ pub mod pallet {
         SetOwner(ChainId, Vec<u8>),
         /// Gateway was set operational. [ChainId, bool]
         SetOperational(ChainId, bool),
-        /// Header was successfully added
-        HeaderSubmitted(GatewayVendor, Vec<u8>),
+        /// Header was successfully added. [ChainId, GatewayVendor, Vec<u8>]
+        HeaderSubmitted(ChainId, GatewayVendor, Vec<u8>),
+        /// Sibling parachain subscribed to the gateway's headers. [ChainId, ParaId]
+        HeadersSubscribed(ChainId, u32),
+        /// Sibling parachain unsubscribed from the gateway's headers. [ChainId, ParaId]
+        HeadersUnsubscribed(ChainId, u32),
+        /// Latest finalized header was exported to the sibling. [ChainId, ParaId, Height]
+        HeaderExported(ChainId, u32, BlockNumberFor<T>),
+        /// Latest finalized header failed to export to the sibling. [ChainId, ParaId, Height]
+        HeaderExportFailed(ChainId, u32, BlockNumberFor<T>),
+        /// Cached inclusions outlived the retention period and were pruned. [Count]
+        CachedInclusionsPruned(u32),
+        /// Headers of a gateway from the height on were rolled back by a heavier competing chain. [ChainId, Height]
+        HeadersRolledBack(ChainId, BlockNumberFor<T>),
+    }
+
+    #[pallet::extra_constants]
+    impl<T: Config> Pallet<T> {
+        /// Version of the layout of identifiers leading the events, bumped on breaking changes.
+        #[pallet::constant_name(EventsVersion)]
+        fn events_version() -> u32 {
+            t3rn_primitives::EVENTS_VERSION
+        }
     }
 
     // Errors inform users that something went wrong.
@@ -103,6 +348,18 @@ pub mod pallet {
         SideEffectConfirmationFailed,
         /// Recoding failed
         SFXRecodeError,
+        /// Empty ABI descriptor given and no default descriptor found for the gateway in XDNS
+        DefaultAbiDescriptorNotFound,
+        /// No subscription to the gateway's headers found for the parachain
+        HeaderSubscriptionNotFound,
+        /// The encoded headers exceed the maximum size for the gateway's vendor
+        HeadersSizeLimitExceeded,
+        /// The encoded inclusion proof exceeds the maximum size for the gateway's vendor
+        ProofSizeLimitExceeded,
+        /// The gateway used up its allowance of header submissions for the current window
+        HeaderSubmissionRateLimited,
+        /// The compressed headers or proof failed to decompress, or compression isn't supported
+        PayloadDecompressionFailed,
     }
 
     // Dispatchable functions allows users to interact with the pallet and invoke state changes.
@@ -110,7 +367,8 @@ pub mod pallet {
     // Dispatchable functions must be annotated with a weight and must return a DispatchResult.
     #[pallet::call]
     impl<T: Config> Pallet<T> {
-        #[pallet::weight(T::DbWeight::get().writes(1))]
+        // Authorities make up the bulk of the registration data, at 32 bytes each
+        #[pallet::weight(T::WeightInfo::register_gateway(encoded_registration_data.len() as u32 / 32))]
         #[transactional]
         pub fn register_gateway(
             origin: OriginFor<T>,
@@ -147,10 +405,371 @@ pub mod pallet {
             Self::deposit_event(Event::GatewayRegistered(gateway_id));
             Ok(())
         }
+
+        /// Subscribe a sibling parachain to the latest finalized headers of a gateway, i.e. with XCM
+        /// `Transact`. Each newly finalized header is sent back to the sibling appended to `notify_call`.
+        #[pallet::weight(T::WeightInfo::subscribe_to_headers())]
+        pub fn subscribe_to_headers(
+            origin: OriginFor<T>,
+            gateway_id: ChainId,
+            notify_call: Vec<u8>,
+        ) -> DispatchResult {
+            let para_id = T::HeaderSubscriberOrigin::ensure_origin(origin)?;
+            // Make sure there's a light client to export headers from
+            match_light_client_by_gateway_id::<T>(gateway_id)?;
+
+            <HeaderSubscriptions<T>>::insert(
+                gateway_id,
+                para_id,
+                HeaderSubscription {
+                    notify_call,
+                    last_exported_height: None,
+                },
+            );
+            Self::deposit_event(Event::HeadersSubscribed(gateway_id, para_id));
+            Ok(())
+        }
+
+        #[pallet::weight(T::WeightInfo::unsubscribe_from_headers())]
+        pub fn unsubscribe_from_headers(
+            origin: OriginFor<T>,
+            gateway_id: ChainId,
+        ) -> DispatchResult {
+            let para_id = T::HeaderSubscriberOrigin::ensure_origin(origin)?;
+            ensure!(
+                <HeaderSubscriptions<T>>::contains_key(gateway_id, para_id),
+                Error::<T>::HeaderSubscriptionNotFound
+            );
+
+            <HeaderSubscriptions<T>>::remove(gateway_id, para_id);
+            Self::deposit_event(Event::HeadersUnsubscribed(gateway_id, para_id));
+            Ok(())
+        }
     }
 }
 
-// ToDo: this should come from XDNS
+impl<T: Config> Pallet<T> {
+    /// Heights of all gateways at once: (gateway_id, finalized height, updated height, epoch of its
+    /// verifier, is operational).
+    pub fn get_all_finalized_heights() -> Vec<(
+        ChainId,
+        BlockNumberFor<T>,
+        BlockNumberFor<T>,
+        BlockNumberFor<T>,
+        bool,
+    )> {
+        <T as Config>::Xdns::read_last_activity_overview()
+            .into_iter()
+            .map(|activity| {
+                let epoch = <T as Config>::Xdns::get_verification_vendor(&activity.gateway_id)
+                    .ok()
+                    .and_then(|vendor| <T as Config>::Xdns::read_last_verifier_activity(&vendor))
+                    .map(|verifier_activity| verifier_activity.epoch)
+                    .unwrap_or_else(Zero::zero);
+                (
+                    activity.gateway_id,
+                    activity.finalized_height,
+                    activity.updated_height,
+                    epoch,
+                    activity.is_active,
+                )
+            })
+            .collect()
+    }
+
+    /// Export the latest finalized headers to subscribers that haven't received them yet.
+    ///
+    /// Visits up to `MaxHeaderExportsPerBlock` subscriptions per block, resuming from where the
+    /// previous block left off.
+    pub fn export_headers() -> Weight {
+        let max_visits = T::MaxHeaderExportsPerBlock::get();
+        let (mut reads, mut writes) = (1u64, 0u64);
+
+        let subscriptions = match <HeaderExportCursor<T>>::take() {
+            Some(cursor) => {
+                writes += 1;
+                <HeaderSubscriptions<T>>::iter_from(cursor)
+            },
+            None => <HeaderSubscriptions<T>>::iter(),
+        };
+
+        for (visits, (gateway_id, para_id, subscription)) in
+            subscriptions.take(max_visits as usize).enumerate()
+        {
+            reads += 1;
+            let (export_reads, export_writes) =
+                Self::export_header(gateway_id, para_id, subscription);
+            reads += export_reads;
+            writes += export_writes;
+
+            if visits + 1 == max_visits as usize {
+                <HeaderExportCursor<T>>::put(<HeaderSubscriptions<T>>::hashed_key_for(
+                    gateway_id, para_id,
+                ));
+                writes += 1;
+            }
+        }
+
+        T::DbWeight::get().reads_writes(reads, writes)
+    }
+
+    /// Take a header submission out of the gateway's allowance, refilled at
+    /// `MaxHeaderSubmissionsPerWindow` submissions per `HeaderSubmissionWindow` blocks, so that
+    /// relayers spamming one gateway can't crowd out the headers of the others.
+    pub fn take_header_submission(gateway_id: ChainId) -> Result<(), Error<T>> {
+        let capacity = T::MaxHeaderSubmissionsPerWindow::get();
+        if capacity.is_zero() {
+            return Ok(())
+        }
+        let window = T::HeaderSubmissionWindow::get()
+            .saturated_into::<u32>()
+            .max(1);
+        let now = frame_system::Pallet::<T>::block_number();
+
+        HeaderSubmissionBuckets::<T>::try_mutate(gateway_id, |maybe_bucket| {
+            let bucket = maybe_bucket.get_or_insert(HeaderSubmissionBucket {
+                tokens: capacity,
+                refilled_at: now,
+            });
+            let elapsed = now
+                .saturating_sub(bucket.refilled_at)
+                .saturated_into::<u32>();
+            let refill = elapsed.saturating_mul(capacity) / window;
+            if !refill.is_zero() {
+                bucket.tokens = bucket.tokens.saturating_add(refill).min(capacity);
+                bucket.refilled_at = now;
+            }
+            bucket.tokens = bucket
+                .tokens
+                .checked_sub(1)
+                .ok_or(Error::<T>::HeaderSubmissionRateLimited)?;
+            Ok(())
+        })
+    }
+
+    /// Roll back the headers of the gateways whose light client switched to a heavier competing
+    /// chain since the block before, as reported by the light client or observed by its fast
+    /// height dropping below the one seen last. Relayers submit the headers of vendors with
+    /// probabilistic finality straight to their light clients, so the portal checks every block.
+    pub fn observe_fork_choice() -> Weight {
+        let mut weight = Weight::zero();
+        for vendor in GatewayVendor::iterator().filter(|vendor| vendor.has_probabilistic_finality())
+        {
+            let Ok(light_client) = T::SelectLightClient::select(vendor.clone()) else {
+                continue
+            };
+            let HeightResult::Height(fast_height) = light_client.get_fast_height() else {
+                continue
+            };
+            weight = weight.saturating_add(T::DbWeight::get().reads_writes(3, 2));
+
+            let observed_height =
+                ObservedFastHeights::<T>::mutate(vendor, |observed| observed.replace(fast_height));
+            let rolled_back_height =
+                light_client
+                    .take_rolled_back_height()
+                    .or(match observed_height {
+                        Some(observed_height) if fast_height < observed_height =>
+                            Some(fast_height.saturating_add(One::one())),
+                        _ => None,
+                    });
+            let Some(from_height) = rolled_back_height else {
+                continue
+            };
+
+            let gateways = <T as Config>::Xdns::fetch_gateways();
+            weight = weight.saturating_add(T::DbWeight::get().reads(gateways.len() as u64));
+            for gateway in gateways
+                .iter()
+                .filter(|gateway| gateway.verification_vendor == *vendor)
+            {
+                weight =
+                    weight.saturating_add(Self::roll_back_headers(gateway.gateway_id, from_height));
+            }
+        }
+        weight
+    }
+
+    /// Evict the inclusions cached at the rolled back heights of the gateway and notify what
+    /// depended on them, to be confirmed again against the heavier chain.
+    pub fn roll_back_headers(gateway_id: ChainId, from_height: BlockNumberFor<T>) -> Weight {
+        let mut evicted = 0u64;
+        let rolled_back_keys = CachedInclusions::<T>::iter_prefix(gateway_id)
+            .filter(|(_, cached)| cached.receipt.height >= from_height)
+            .map(|(key, _)| key)
+            .collect::<Vec<_>>();
+        for key in rolled_back_keys {
+            CachedInclusions::<T>::remove(gateway_id, key);
+            evicted += 1;
+        }
+        Self::deposit_event(Event::HeadersRolledBack(gateway_id, from_height));
+        T::DbWeight::get()
+            .reads_writes(evicted.saturating_add(1), evicted)
+            .saturating_add(T::OnHeadersRolledBack::on_headers_rolled_back(
+                gateway_id,
+                from_height,
+            ))
+    }
+
+    /// Key of an event inclusion in the cache, the root and leaf the light client of the gateway
+    /// proves it with. Proofs the light client derives no key of aren't cached.
+    pub fn inclusion_cache_key(gateway_id: ChainId, message: &[u8]) -> Option<InclusionCacheKey> {
+        match_light_client_by_gateway_id::<T>(gateway_id)
+            .ok()?
+            .event_inclusion_root_and_leaf(gateway_id, message)
+    }
+
+    /// Receipt of an event inclusion verified earlier, valid for proofs at finality only since
+    /// faster speed modes trust headers that may still be reverted. Expecting a source, the
+    /// receipt must have been verified as emitted by it.
+    pub fn cached_event_inclusion(
+        gateway_id: ChainId,
+        speed_mode: &SpeedMode,
+        source: &Option<ExecutionSource>,
+        message: &[u8],
+    ) -> Option<InclusionReceipt<BlockNumberFor<T>>> {
+        if *speed_mode != SpeedMode::Finalized {
+            return None
+        }
+        let cached = CachedInclusions::<T>::get(
+            gateway_id,
+            Self::inclusion_cache_key(gateway_id, message)?,
+        )?;
+        (source.is_none() || *source == cached.source).then_some(cached.receipt)
+    }
+
+    /// Cache the receipt of an event inclusion verified at finality, in place of the oldest one
+    /// once the ring buffer is full.
+    pub fn cache_event_inclusion(
+        gateway_id: ChainId,
+        source: &Option<ExecutionSource>,
+        message: &[u8],
+        receipt: &InclusionReceipt<BlockNumberFor<T>>,
+    ) {
+        let capacity = T::MaxCachedInclusions::get();
+        if capacity.is_zero() {
+            return
+        }
+        let Some(key) = Self::inclusion_cache_key(gateway_id, message) else {
+            return
+        };
+        let pointer = CachedInclusionsPointer::<T>::get();
+        if let Some((evicted_gateway_id, evicted_key)) = CachedInclusionsRing::<T>::get(pointer) {
+            Self::evict_cached_inclusion(evicted_gateway_id, evicted_key, pointer);
+        }
+        CachedInclusions::<T>::insert(
+            gateway_id,
+            key,
+            CachedInclusion {
+                receipt: receipt.clone(),
+                source: *source,
+                slot: pointer,
+            },
+        );
+        CachedInclusionsRing::<T>::insert(pointer, (gateway_id, key));
+        CachedInclusionsPointer::<T>::put((pointer + 1) % capacity);
+        retention::enqueue::<_, _, _, CachedInclusionsExpiry<T>, CachedInclusionsPruneCursor<T>>(
+            frame_system::Pallet::<T>::block_number(),
+            (gateway_id, key),
+            pointer,
+        );
+    }
+
+    /// Evict the inclusion cached at the slot, left in place once it was cached again since and
+    /// took another slot.
+    fn evict_cached_inclusion(gateway_id: ChainId, key: InclusionCacheKey, slot: u32) {
+        if CachedInclusions::<T>::get(gateway_id, key).map_or(false, |cached| cached.slot == slot) {
+            CachedInclusions::<T>::remove(gateway_id, key);
+        }
+    }
+
+    /// Prune the inclusions cached more than `RetentionPeriod` blocks ago, within the weight
+    /// remaining in the block and up to `MaxPrunedPerBlock` steps.
+    pub fn prune_cached_inclusions(n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+        let step_weight = T::DbWeight::get().reads_writes(2, 2);
+        let base_weight = T::DbWeight::get().reads_writes(1, 1);
+        let max_steps = remaining_weight
+            .saturating_sub(base_weight)
+            .ref_time()
+            .checked_div(step_weight.ref_time())
+            .unwrap_or_default()
+            .min(T::MaxPrunedPerBlock::get() as u64) as u32;
+        if max_steps == 0 {
+            return Weight::zero()
+        }
+
+        let mut pruned = 0u32;
+        let steps = retention::prune_expired::<
+            _,
+            _,
+            _,
+            CachedInclusionsExpiry<T>,
+            CachedInclusionsPruneCursor<T>,
+        >(
+            n,
+            T::RetentionPeriod::get(),
+            max_steps,
+            |(gateway_id, key), slot| {
+                // Entries the ring buffer evicted meanwhile are already gone
+                Self::evict_cached_inclusion(gateway_id, key, slot);
+                pruned += 1;
+            },
+        );
+        if pruned > 0 {
+            Self::deposit_event(Event::CachedInclusionsPruned(pruned));
+        }
+        base_weight.saturating_add(step_weight.saturating_mul(steps as u64))
+    }
+
+    /// Export the latest finalized header of a gateway to a subscriber, returning the DB reads
+    /// and writes it took.
+    fn export_header(
+        gateway_id: ChainId,
+        para_id: u32,
+        mut subscription: HeaderSubscription<BlockNumberFor<T>>,
+    ) -> (u64, u64) {
+        let Ok(light_client) = match_light_client_by_gateway_id::<T>(gateway_id) else {
+            return (1, 0)
+        };
+        let height = match light_client.get_finalized_height() {
+            HeightResult::Height(height) if Some(height) > subscription.last_exported_height =>
+                height,
+            _ => return (2, 0),
+        };
+        let HeaderResult::Header(header) = light_client.get_latest_finalized_header() else {
+            return (3, 0)
+        };
+
+        match T::HeaderExporter::export(
+            para_id,
+            &subscription.notify_call,
+            gateway_id,
+            height,
+            header,
+        ) {
+            Ok(()) => {
+                subscription.last_exported_height = Some(height);
+                <HeaderSubscriptions<T>>::insert(gateway_id, para_id, subscription);
+                Self::deposit_event(Event::HeaderExported(gateway_id, para_id, height));
+                (3, 1)
+            },
+            Err(err) => {
+                log::warn!(
+                    target: "portal",
+                    "Failed to export header #{:?} of {:?} to sibling {:?}: {:?}",
+                    height,
+                    gateway_id,
+                    para_id,
+                    err
+                );
+                Self::deposit_event(Event::HeaderExportFailed(gateway_id, para_id, height));
+                (3, 0)
+            },
+        }
+    }
+}
+
+// Default codec per vendor. The codec of a registered gateway is read from its XDNS record.
 pub fn match_vendor_with_codec(vendor: GatewayVendor) -> Codec {
     match vendor {
         GatewayVendor::Rococo => Codec::Scale,
@@ -163,6 +782,21 @@ pub fn match_vendor_with_codec(vendor: GatewayVendor) -> Codec {
     }
 }
 
+/// Uses the given ABI descriptor if not empty. Otherwise falls back to the gateway's default
+/// ABI descriptor of the side effect, as stored in XDNS.
+pub fn resolve_abi_descriptor<T: Config>(
+    gateway_id: &ChainId,
+    abi_descriptor: Bytes,
+    maybe_sfx_4b_id: Option<Sfx4bId>,
+) -> Result<Bytes, Error<T>> {
+    if !abi_descriptor.is_empty() {
+        return Ok(abi_descriptor)
+    }
+    let sfx_4b_id = maybe_sfx_4b_id.ok_or(Error::<T>::DefaultAbiDescriptorNotFound)?;
+    <T as Config>::Xdns::get_default_abi_descriptor(gateway_id, sfx_4b_id)
+        .map_err(|_| Error::<T>::DefaultAbiDescriptorNotFound)
+}
+
 pub fn match_light_client_by_gateway_id<T: Config>(
     gateway_id: ChainId,
 ) -> Result<Box<dyn LightClient<T>>, Error<T>> {
@@ -171,6 +805,104 @@ pub fn match_light_client_by_gateway_id<T: Config>(
     T::SelectLightClient::select(vendor)
 }
 
+/// Matches the light client of the gateway, once the encoded headers fit the bound of its vendor.
+pub fn match_light_client_for_headers<T: Config>(
+    gateway_id: ChainId,
+    encoded_len: usize,
+) -> Result<Box<dyn LightClient<T>>, Error<T>> {
+    T::SelectLightClient::select(ensure_headers_size::<T>(gateway_id, encoded_len)?)
+}
+
+/// Vendor of the gateway, once the encoded headers fit its bound.
+pub fn ensure_headers_size<T: Config>(
+    gateway_id: ChainId,
+    encoded_len: usize,
+) -> Result<GatewayVendor, Error<T>> {
+    let vendor = <T as Config>::Xdns::get_verification_vendor(&gateway_id)
+        .map_err(|_| Error::<T>::GatewayVendorNotFound)?;
+    if encoded_len > T::VendorLimits::max_headers_size(&vendor) as usize {
+        return Err(Error::<T>::HeadersSizeLimitExceeded)
+    }
+    Ok(vendor)
+}
+
+/// Matches the light client of the gateway, once the encoded proof fits the bound of its vendor.
+pub fn match_light_client_for_proof<T: Config>(
+    gateway_id: ChainId,
+    encoded_len: usize,
+) -> Result<Box<dyn LightClient<T>>, Error<T>> {
+    let vendor = <T as Config>::Xdns::get_verification_vendor(&gateway_id)
+        .map_err(|_| Error::<T>::GatewayVendorNotFound)?;
+    if encoded_len > T::VendorLimits::max_proof_size(&vendor) as usize {
+        return Err(Error::<T>::ProofSizeLimitExceeded)
+    }
+    T::SelectLightClient::select(vendor)
+}
+
+/// Decompresses the headers of the gateway submitted compressed, within the bound of its vendor.
+pub fn decompress_headers<T: Config>(gateway_id: ChainId, data: Bytes) -> Result<Bytes, Error<T>> {
+    decompress_within::<T>(
+        gateway_id,
+        data,
+        T::VendorLimits::max_headers_size,
+        Error::<T>::HeadersSizeLimitExceeded,
+    )
+}
+
+/// Decompresses the inclusion proof of the gateway submitted compressed, within the bound of its
+/// vendor.
+pub fn decompress_proof<T: Config>(gateway_id: ChainId, proof: Bytes) -> Result<Bytes, Error<T>> {
+    decompress_within::<T>(
+        gateway_id,
+        proof,
+        T::VendorLimits::max_proof_size,
+        Error::<T>::ProofSizeLimitExceeded,
+    )
+}
+
+fn decompress_within<T: Config>(
+    gateway_id: ChainId,
+    payload: Bytes,
+    max_size: fn(&GatewayVendor) -> u32,
+    size_exceeded: Error<T>,
+) -> Result<Bytes, Error<T>> {
+    if !compression::is_compressed(&payload) {
+        return Ok(payload)
+    }
+    let vendor = <T as Config>::Xdns::get_verification_vendor(&gateway_id)
+        .map_err(|_| Error::<T>::GatewayVendorNotFound)?;
+    compression::decompress(payload, max_size(&vendor) as usize).map_err(|err| match err {
+        DecompressionError::BudgetExceeded => size_exceeded,
+        DecompressionError::Unsupported | DecompressionError::Malformed =>
+            Error::<T>::PayloadDecompressionFailed,
+    })
+}
+
+/// Light clients reporting the headers they dropped on their own, i.e. GRANDPA once reset
+impl<T: Config> OnHeadersRolledBack<BlockNumberFor<T>> for Pallet<T> {
+    fn on_headers_rolled_back(gateway_id: ChainId, from_height: BlockNumberFor<T>) -> Weight {
+        Self::roll_back_headers(gateway_id, from_height)
+    }
+}
+
+/// Relayers submitting headers straight to the light clients are held to the same bounds and
+/// draw from the same allowance
+impl<T: Config> HeaderSubmissionHooks for Pallet<T> {
+    fn ensure_headers_size(gateway_id: ChainId, encoded_len: usize) -> DispatchResult {
+        ensure_headers_size::<T>(gateway_id, encoded_len)
+            .map(|_| ())
+            .map_err(Into::into)
+    }
+
+    fn take_header_submission(gateway_id: ChainId) -> DispatchResult {
+        Self::take_header_submission(gateway_id).map_err(Into::into)
+    }
+
+    fn decompress_headers(gateway_id: ChainId, payload: Bytes) -> Result<Bytes, DispatchError> {
+        decompress_headers::<T>(gateway_id, payload).map_err(Into::into)
+    }
+}
+
 impl<T: Config> Portal<T> for Pallet<T> {
     fn get_latest_heartbeat(
         gateway_id: &ChainId,
@@ -263,9 +995,20 @@ impl<T: Config> Portal<T> for Pallet<T> {
         source: Option<ExecutionSource>,
         message: Bytes,
     ) -> Result<InclusionReceipt<BlockNumberFor<T>>, DispatchError> {
+        let message = decompress_proof::<T>(gateway_id, message)?;
+        if let Some(receipt) =
+            Self::cached_event_inclusion(gateway_id, &speed_mode, &source, &message)
+        {
+            return Ok(receipt)
+        }
+        let is_finalized = speed_mode == SpeedMode::Finalized;
         // ToDo: we need to verify the event source here
-        match_light_client_by_gateway_id::<T>(gateway_id)?
-            .verify_event_inclusion(gateway_id, speed_mode, source, message)
+        let receipt = match_light_client_for_proof::<T>(gateway_id, message.len())?
+            .verify_event_inclusion(gateway_id, speed_mode, source.clone(), message.clone())?;
+        if is_finalized {
+            Self::cache_event_inclusion(gateway_id, &source, &message, &receipt);
+        }
+        Ok(receipt)
     }
 
     fn verify_state_inclusion(
@@ -273,7 +1016,8 @@ impl<T: Config> Portal<T> for Pallet<T> {
         speed_mode: SpeedMode,
         message: Bytes,
     ) -> Result<InclusionReceipt<BlockNumberFor<T>>, DispatchError> {
-        match_light_client_by_gateway_id::<T>(gateway_id)?
+        let message = decompress_proof::<T>(gateway_id, message)?;
+        match_light_client_for_proof::<T>(gateway_id, message.len())?
             .verify_state_inclusion(gateway_id, speed_mode, message)
     }
 
@@ -282,7 +1026,8 @@ impl<T: Config> Portal<T> for Pallet<T> {
         speed_mode: SpeedMode,
         message: Bytes,
     ) -> Result<InclusionReceipt<BlockNumberFor<T>>, DispatchError> {
-        match_light_client_by_gateway_id::<T>(gateway_id)?
+        let message = decompress_proof::<T>(gateway_id, message)?;
+        match_light_client_for_proof::<T>(gateway_id, message.len())?
             .verify_tx_inclusion(gateway_id, speed_mode, message)
     }
 
@@ -292,8 +1037,7 @@ impl<T: Config> Portal<T> for Pallet<T> {
         source: ExecutionSource,
         message: Bytes,
     ) -> Result<Bytes, DispatchError> {
-        // ToDo: we need to verify the event source here
-        let result = match_light_client_by_gateway_id::<T>(gateway_id)?.verify_event_inclusion(
+        let result = Self::verify_event_inclusion(
             gateway_id,
             speed_mode,
             execution_source_to_option(source),
@@ -307,9 +1051,7 @@ impl<T: Config> Portal<T> for Pallet<T> {
         speed_mode: SpeedMode,
         message: Bytes,
     ) -> Result<Bytes, DispatchError> {
-        let result = match_light_client_by_gateway_id::<T>(gateway_id)?
-            .verify_state_inclusion(gateway_id, speed_mode, message)?;
-
+        let result = Self::verify_state_inclusion(gateway_id, speed_mode, message)?;
         Ok(result.message)
     }
 
@@ -318,24 +1060,80 @@ impl<T: Config> Portal<T> for Pallet<T> {
         speed_mode: SpeedMode,
         message: Bytes,
     ) -> Result<Bytes, DispatchError> {
-        let result = match_light_client_by_gateway_id::<T>(gateway_id)?
-            .verify_tx_inclusion(gateway_id, speed_mode, message)?;
+        let result = Self::verify_tx_inclusion(gateway_id, speed_mode, message)?;
         Ok(result.message)
     }
 
+    fn get_confirmation_depth(
+        gateway_id: ChainId,
+        height: BlockNumberFor<T>,
+    ) -> Result<BlockNumberFor<T>, DispatchError> {
+        match match_light_client_by_gateway_id::<T>(gateway_id)?.get_fast_height() {
+            HeightResult::Height(fast_height) => Ok(fast_height.saturating_sub(height)),
+            HeightResult::NotActive => Err(Error::<T>::LightClientNotFoundByVendor.into()),
+        }
+    }
+
+    fn get_provable_range(
+        gateway_id: ChainId,
+    ) -> Result<Option<ProvableRange<BlockNumberFor<T>>>, DispatchError> {
+        Ok(match_light_client_by_gateway_id::<T>(gateway_id)?.get_provable_range(gateway_id))
+    }
+
+    fn verify_event_inclusion_at(
+        gateway_id: [u8; 4],
+        height: BlockNumberFor<T>,
+        source: Option<ExecutionSource>,
+        message: Bytes,
+    ) -> Result<InclusionReceipt<BlockNumberFor<T>>, DispatchError> {
+        let message = decompress_proof::<T>(gateway_id, message)?;
+        if let Some(receipt) =
+            Self::cached_event_inclusion(gateway_id, &SpeedMode::Finalized, &source, &message)
+        {
+            if receipt.height == height {
+                return Ok(receipt)
+            }
+        }
+        let receipt = match_light_client_for_proof::<T>(gateway_id, message.len())?
+            .verify_event_inclusion_at(gateway_id, height, source.clone(), message.clone())?;
+        Self::cache_event_inclusion(gateway_id, &source, &message, &receipt);
+        Ok(receipt)
+    }
+
+    fn verify_state_inclusion_at(
+        gateway_id: [u8; 4],
+        height: BlockNumberFor<T>,
+        message: Bytes,
+    ) -> Result<InclusionReceipt<BlockNumberFor<T>>, DispatchError> {
+        let message = decompress_proof::<T>(gateway_id, message)?;
+        match_light_client_for_proof::<T>(gateway_id, message.len())?
+            .verify_state_inclusion_at(gateway_id, height, message)
+    }
+
+    fn verify_tx_inclusion_at(
+        gateway_id: [u8; 4],
+        height: BlockNumberFor<T>,
+        message: Bytes,
+    ) -> Result<InclusionReceipt<BlockNumberFor<T>>, DispatchError> {
+        let message = decompress_proof::<T>(gateway_id, message)?;
+        match_light_client_for_proof::<T>(gateway_id, message.len())?
+            .verify_tx_inclusion_at(gateway_id, height, message)
+    }
+
     fn verify_state_inclusion_and_recode(
         gateway_id: [u8; 4],
         speed_mode: SpeedMode,
         message: Bytes,
         abi_descriptor: Bytes,
+        maybe_sfx_4b_id: Option<Sfx4bId>,
         out_codec: Codec,
     ) -> Result<InclusionReceipt<BlockNumberFor<T>>, DispatchError> {
         let mut inclusion_check = Self::verify_state_inclusion(gateway_id, speed_mode, message)?;
 
-        let in_codec = match_vendor_with_codec(
-            <T as Config>::Xdns::get_verification_vendor(&gateway_id)
-                .map_err(|_| Error::<T>::GatewayVendorNotFound)?,
-        );
+        let in_codec = <T as Config>::Xdns::get_target_codec(&gateway_id)
+            .map_err(|_| Error::<T>::GatewayVendorNotFound)?;
+        let abi_descriptor =
+            resolve_abi_descriptor::<T>(&gateway_id, abi_descriptor, maybe_sfx_4b_id)?;
 
         let recoded_message = recode_bytes_with_descriptor(
             inclusion_check.message,
@@ -353,14 +1151,15 @@ impl<T: Config> Portal<T> for Pallet<T> {
         speed_mode: SpeedMode,
         message: Bytes,
         abi_descriptor: Bytes,
+        maybe_sfx_4b_id: Option<Sfx4bId>,
         out_codec: Codec,
     ) -> Result<InclusionReceipt<BlockNumberFor<T>>, DispatchError> {
         let mut inclusion_check = Self::verify_tx_inclusion(gateway_id, speed_mode, message)?;
 
-        let in_codec = match_vendor_with_codec(
-            <T as Config>::Xdns::get_verification_vendor(&gateway_id)
-                .map_err(|_| Error::<T>::GatewayVendorNotFound)?,
-        );
+        let in_codec = <T as Config>::Xdns::get_target_codec(&gateway_id)
+            .map_err(|_| Error::<T>::GatewayVendorNotFound)?;
+        let abi_descriptor =
+            resolve_abi_descriptor::<T>(&gateway_id, abi_descriptor, maybe_sfx_4b_id)?;
 
         let recoded_message = recode_bytes_with_descriptor(
             inclusion_check.message,
@@ -379,6 +1178,7 @@ impl<T: Config> Portal<T> for Pallet<T> {
         source: ExecutionSource,
         message: Bytes,
         abi_descriptor: Bytes,
+        maybe_sfx_4b_id: Option<Sfx4bId>,
         out_codec: Codec,
     ) -> Result<InclusionReceipt<BlockNumberFor<T>>, DispatchError> {
         let mut inclusion_check = Self::verify_event_inclusion(
@@ -388,10 +1188,10 @@ impl<T: Config> Portal<T> for Pallet<T> {
             message,
         )?;
 
-        let in_codec = match_vendor_with_codec(
-            <T as Config>::Xdns::get_verification_vendor(&gateway_id)
-                .map_err(|_| Error::<T>::GatewayVendorNotFound)?,
-        );
+        let in_codec = <T as Config>::Xdns::get_target_codec(&gateway_id)
+            .map_err(|_| Error::<T>::GatewayVendorNotFound)?;
+        let abi_descriptor =
+            resolve_abi_descriptor::<T>(&gateway_id, abi_descriptor, maybe_sfx_4b_id)?;
 
         let recoded_message = recode_bytes_with_descriptor(
             inclusion_check.message,
@@ -409,6 +1209,9 @@ impl<T: Config> Portal<T> for Pallet<T> {
         gateway_id: [u8; 4],
         encoded_registration_data: Bytes,
     ) -> Result<(), DispatchError> {
+        // Receipts verified by the light client before its re-initialization are stale
+        let _ =
+            CachedInclusions::<T>::clear_prefix(gateway_id, T::MaxCachedInclusions::get(), None);
         match_light_client_by_gateway_id::<T>(gateway_id)?.initialize(
             origin,
             gateway_id,
@@ -419,10 +1222,17 @@ impl<T: Config> Portal<T> for Pallet<T> {
     fn submit_encoded_headers(
         gateway_id: ChainId,
         encoded_header_data: Vec<u8>,
-    ) -> Result<(), DispatchError> {
-        match_light_client_by_gateway_id::<T>(gateway_id)?
-            .submit_encoded_headers(encoded_header_data)?;
-        Ok(())
+    ) -> Result<Weight, DispatchError> {
+        let encoded_header_data = decompress_headers::<T>(gateway_id, encoded_header_data)?;
+        let light_client =
+            match_light_client_for_headers::<T>(gateway_id, encoded_header_data.len())?;
+        Self::take_header_submission(gateway_id)?;
+        light_client.submit_encoded_headers(encoded_header_data)?;
+        Ok(light_client
+            .take_rolled_back_height()
+            .map_or(Weight::zero(), |from_height| {
+                Self::roll_back_headers(gateway_id, from_height)
+            }))
     }
 
     fn turn_on(origin: OriginFor<T>, gateway_id: [u8; 4]) -> Result<bool, DispatchError> {
@@ -432,4 +1242,23 @@ impl<T: Config> Portal<T> for Pallet<T> {
     fn turn_off(origin: OriginFor<T>, gateway_id: [u8; 4]) -> Result<bool, DispatchError> {
         match_light_client_by_gateway_id::<T>(gateway_id)?.turn_off(origin)
     }
+
+    fn verify_event_inclusion_weight(proof_len: u32) -> Weight {
+        T::WeightInfo::verify_event_inclusion(proof_len)
+    }
+
+    fn is_event_inclusion_cached(
+        gateway_id: ChainId,
+        speed_mode: &SpeedMode,
+        source: &Option<ExecutionSource>,
+        message: &[u8],
+    ) -> bool {
+        // Compressed proofs are cached as verified, decompressed
+        if compression::is_compressed(message) {
+            return decompress_proof::<T>(gateway_id, message.to_vec()).map_or(false, |message| {
+                Self::cached_event_inclusion(gateway_id, speed_mode, source, &message).is_some()
+            })
+        }
+        Self::cached_event_inclusion(gateway_id, speed_mode, source, message).is_some()
+    }
 }
@@ -0,0 +1,230 @@
+//! Simulated light clients of dev chains, accepting any headers submitted to them as finalized, so
+//! dApp developers exercise the full Xtx lifecycle on a local node without relaying live chains.
+//!
+//! Runtimes built with `dev-simulation` select them with `type SelectLightClient =
+//! SimulatedLightClients<Runtime>`. Never enable it on a live network: nothing is verified.
+use super::*;
+use frame_support::sp_runtime::traits::One;
+use sp_std::marker::PhantomData;
+use t3rn_primitives::{
+    light_client::{HeaderResult, HeightResult, InclusionReceipt, LightClientHeartbeat},
+    ExecutionSource, SpeedMode,
+};
+
+/// Selects the simulated light client of every vendor.
+pub struct SimulatedLightClients<T> {
+    _phantom: PhantomData<T>,
+}
+
+impl<T: Config> SelectLightClient<T> for SimulatedLightClients<T> {
+    fn select(vendor: GatewayVendor) -> Result<Box<dyn LightClient<T>>, Error<T>> {
+        Ok(Box::new(SimulatedLightClient::<T> {
+            vendor,
+            _phantom: PhantomData,
+        }))
+    }
+}
+
+/// Light client of a vendor following the self-signed headers submitted to it, one block each.
+pub struct SimulatedLightClient<T> {
+    vendor: GatewayVendor,
+    _phantom: PhantomData<T>,
+}
+
+impl<T: Config> SimulatedLightClient<T> {
+    fn height(&self) -> HeightResult<BlockNumberFor<T>> {
+        match SimulatedHeads::<T>::get(&self.vendor) {
+            Some(head) => HeightResult::Height(head.height),
+            None => HeightResult::NotActive,
+        }
+    }
+
+    fn height_precompile(&self) -> BlockNumberFor<T> {
+        SimulatedHeads::<T>::get(&self.vendor)
+            .map(|head| head.height)
+            .unwrap_or_else(Zero::zero)
+    }
+
+    // Any message is included in the latest header, as long as the light client is running
+    fn include(
+        &self,
+        message: Bytes,
+    ) -> Result<InclusionReceipt<BlockNumberFor<T>>, DispatchError> {
+        let head = SimulatedHeads::<T>::get(&self.vendor)
+            .ok_or(Error::<T>::LightClientNotFoundByVendor)?;
+        if head.is_halted {
+            return Err(Error::<T>::SideEffectConfirmationFailed.into())
+        }
+        Ok(InclusionReceipt {
+            height: head.height,
+            including_header: head.header,
+            message,
+        })
+    }
+
+    fn set_halted(&self, is_halted: bool) -> Result<bool, DispatchError> {
+        SimulatedHeads::<T>::try_mutate(&self.vendor, |head| match head {
+            Some(head) => {
+                head.is_halted = is_halted;
+                Ok(true)
+            },
+            None => Err(Error::<T>::LightClientNotFoundByVendor.into()),
+        })
+    }
+}
+
+impl<T: Config> LightClient<T> for SimulatedLightClient<T> {
+    fn get_latest_finalized_header(&self) -> HeaderResult {
+        match SimulatedHeads::<T>::get(&self.vendor) {
+            Some(head) => HeaderResult::Header(head.header),
+            None => HeaderResult::NotActive,
+        }
+    }
+
+    fn get_fast_height(&self) -> HeightResult<BlockNumberFor<T>> {
+        self.height()
+    }
+
+    fn get_rational_height(&self) -> HeightResult<BlockNumberFor<T>> {
+        self.height()
+    }
+
+    fn get_finalized_height(&self) -> HeightResult<BlockNumberFor<T>> {
+        self.height()
+    }
+
+    fn get_latest_finalized_header_precompile(&self) -> Bytes {
+        SimulatedHeads::<T>::get(&self.vendor)
+            .map(|head| head.header)
+            .unwrap_or_default()
+    }
+
+    fn get_fast_height_precompile(&self) -> BlockNumberFor<T> {
+        self.height_precompile()
+    }
+
+    fn get_rational_height_precompile(&self) -> BlockNumberFor<T> {
+        self.height_precompile()
+    }
+
+    fn get_finalized_height_precompile(&self) -> BlockNumberFor<T> {
+        self.height_precompile()
+    }
+
+    fn get_latest_heartbeat(&self) -> Result<LightClientHeartbeat<T>, DispatchError> {
+        Ok(match SimulatedHeads::<T>::get(&self.vendor) {
+            Some(head) => LightClientHeartbeat {
+                last_heartbeat: head.updated_at,
+                last_finalized_height: head.height,
+                last_rational_height: head.height,
+                last_fast_height: head.height,
+                is_halted: head.is_halted,
+                ever_initialized: true,
+            },
+            None => LightClientHeartbeat::default(),
+        })
+    }
+
+    fn initialize(
+        &self,
+        origin: T::RuntimeOrigin,
+        _gateway_id: [u8; 4],
+        encoded_registration_data: Bytes,
+    ) -> Result<(), DispatchError> {
+        ensure_root(origin)?;
+        SimulatedHeads::<T>::insert(
+            &self.vendor,
+            SimulatedHead {
+                height: Zero::zero(),
+                header: encoded_registration_data,
+                updated_at: frame_system::Pallet::<T>::block_number(),
+                is_halted: false,
+            },
+        );
+        Ok(())
+    }
+
+    fn turn_on(&self, origin: T::RuntimeOrigin) -> Result<bool, DispatchError> {
+        ensure_root(origin)?;
+        self.set_halted(false)
+    }
+
+    fn turn_off(&self, origin: T::RuntimeOrigin) -> Result<bool, DispatchError> {
+        ensure_root(origin)?;
+        self.set_halted(true)
+    }
+
+    fn submit_encoded_headers(&self, encoded_headers_data: Bytes) -> Result<bool, DispatchError> {
+        SimulatedHeads::<T>::try_mutate(&self.vendor, |head| {
+            let head = head
+                .as_mut()
+                .ok_or(Error::<T>::LightClientNotFoundByVendor)?;
+            if head.is_halted {
+                return Err(Error::<T>::SubmitHeaderError.into())
+            }
+            head.height += One::one();
+            head.header = encoded_headers_data;
+            head.updated_at = frame_system::Pallet::<T>::block_number();
+            Ok(true)
+        })
+    }
+
+    fn verify_event_inclusion(
+        &self,
+        _gateway_id: [u8; 4],
+        _speed_mode: SpeedMode,
+        _source: Option<ExecutionSource>,
+        message: Bytes,
+    ) -> Result<InclusionReceipt<BlockNumberFor<T>>, DispatchError> {
+        self.include(message)
+    }
+
+    fn verify_state_inclusion(
+        &self,
+        _gateway_id: [u8; 4],
+        _speed_mode: SpeedMode,
+        message: Bytes,
+    ) -> Result<InclusionReceipt<BlockNumberFor<T>>, DispatchError> {
+        self.include(message)
+    }
+
+    fn verify_tx_inclusion(
+        &self,
+        _gateway_id: [u8; 4],
+        _speed_mode: SpeedMode,
+        message: Bytes,
+    ) -> Result<InclusionReceipt<BlockNumberFor<T>>, DispatchError> {
+        self.include(message)
+    }
+
+    fn verify_event_inclusion_precompile(
+        &self,
+        _gateway_id: [u8; 4],
+        _speed_mode: SpeedMode,
+        _source: ExecutionSource,
+        message: Bytes,
+    ) -> Result<Bytes, DispatchError> {
+        self.include(message)
+            .map(|receipt| receipt.message.encode())
+    }
+
+    fn verify_state_inclusion_precompile(
+        &self,
+        _gateway_id: [u8; 4],
+        _speed_mode: SpeedMode,
+        message: Bytes,
+    ) -> Result<Bytes, DispatchError> {
+        self.include(message)
+            .map(|receipt| receipt.message.encode())
+    }
+
+    fn verify_tx_inclusion_precompile(
+        &self,
+        _gateway_id: [u8; 4],
+        _speed_mode: SpeedMode,
+        message: Bytes,
+    ) -> Result<Bytes, DispatchError> {
+        self.include(message)
+            .map(|receipt| receipt.message.encode())
+    }
+}
@@ -3,7 +3,9 @@ mod tests {
     use ::pallet_eth2_finality_verifier::mock::{generate_epoch_update, generate_initialization};
     use circuit_mock_runtime::{ExtBuilder, Portal, RuntimeOrigin as Origin, XDNS, *};
     use codec::Encode;
-    use frame_support::assert_ok;
+    use frame_support::{
+        assert_err, assert_ok, dispatch::GetDispatchInfo, traits::Get, weights::Weight,
+    };
     use pallet_grandpa_finality_verifier::{
         bridges::test_utils::{authorities, test_header_with_correct_parent},
         mock::produce_mock_headers_range,
@@ -11,8 +13,12 @@ mod tests {
     };
 
     use t3rn_primitives::{
-        portal::{HeaderResult, HeightResult, Portal as PortalT},
-        EthereumToken, ExecutionVendor, GatewayVendor, TokenInfo,
+        compression::{self, Compression},
+        portal::{HeaderResult, HeightResult, InclusionReceipt, Portal as PortalT},
+        retention,
+        xdns::Xdns,
+        EthereumToken, ExecutionVendor, FinalityVerifierActivity, GatewayVendor, SpeedMode,
+        TokenInfo,
     };
 
     fn get_test_initialize_genesis_data() -> RelaychainRegistrationData<AccountId> {
@@ -26,6 +32,22 @@ mod tests {
         }
     }
 
+    /// Encoded as a `ParachainInclusionProof` of the payload, proven against the relaychain header
+    /// of the hash through the given header proof nodes
+    fn parachain_inclusion_proof(
+        payload: &[u8],
+        relay_block_hash: [u8; 32],
+        header_proof: Vec<Vec<u8>>,
+    ) -> Vec<u8> {
+        (
+            payload.to_vec(),
+            header_proof,
+            Vec::<Vec<u8>>::new(),
+            relay_block_hash,
+        )
+            .encode()
+    }
+
     fn test_initialize_and_submit_grandpa(
         vendor: GatewayVendor,
         registration_data: RelaychainRegistrationData<AccountId>,
@@ -335,6 +357,566 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_oversized_headers_and_proofs_are_rejected_per_vendor() {
+        ExtBuilder::default()
+            .with_standard_sfx_abi()
+            .with_default_xdns_records()
+            .build()
+            .execute_with(|| {
+                let above_ethereum_limit = vec![0u8; 256 * 1024 + 1];
+                assert_err!(
+                    Portal::submit_encoded_headers(*b"eth2", above_ethereum_limit.clone()),
+                    pallet_portal::Error::<Runtime>::HeadersSizeLimitExceeded
+                );
+                assert_err!(
+                    Portal::verify_event_inclusion(
+                        *b"eth2",
+                        SpeedMode::Finalized,
+                        None,
+                        above_ethereum_limit.clone()
+                    ),
+                    pallet_portal::Error::<Runtime>::ProofSizeLimitExceeded
+                );
+
+                // GRANDPA justifications are bounded higher, so the same headers reach Rococo's light client
+                assert!(
+                    Portal::submit_encoded_headers([0, 0, 0, 0], above_ethereum_limit)
+                        != Err(pallet_portal::Error::<Runtime>::HeadersSizeLimitExceeded.into())
+                );
+                assert_err!(
+                    Portal::submit_encoded_headers([0, 0, 0, 0], vec![0u8; 512 * 1024 + 1]),
+                    pallet_portal::Error::<Runtime>::HeadersSizeLimitExceeded
+                );
+                assert_err!(
+                    Portal::verify_state_inclusion(
+                        [0, 0, 0, 0],
+                        SpeedMode::Finalized,
+                        vec![0u8; 1024 * 1024 + 1]
+                    ),
+                    pallet_portal::Error::<Runtime>::ProofSizeLimitExceeded
+                );
+            });
+    }
+
+    // Snappy raw block of `data` as a single literal, its length taking 4 bytes
+    #[cfg(feature = "compressed-proofs")]
+    fn snappy_literal(data: &[u8]) -> Vec<u8> {
+        let mut block = vec![];
+        let mut len = data.len();
+        while len >= 0x80 {
+            block.push((len as u8 & 0x7f) | 0x80);
+            len >>= 7;
+        }
+        block.push(len as u8);
+        block.push(63 << 2);
+        block.extend_from_slice(&(data.len() as u32 - 1).to_le_bytes());
+        block.extend_from_slice(data);
+        compression::with_prefix(Compression::Snappy, &block)
+    }
+
+    #[cfg(feature = "compressed-proofs")]
+    #[test]
+    fn test_snappy_compressed_headers_are_submitted_decompressed() {
+        let data = produce_mock_headers_range(1, 5);
+        test_initialize_and_submit_grandpa(
+            GatewayVendor::Rococo,
+            get_test_initialize_genesis_data(),
+            snappy_literal(&data.encode()),
+        );
+    }
+
+    #[cfg(feature = "compressed-proofs")]
+    #[test]
+    fn test_snappy_compressed_headers_are_submitted_straight_to_the_bridge_weighed_decompressed() {
+        ExtBuilder::default()
+            .with_standard_sfx_abi()
+            .with_default_xdns_records()
+            .build()
+            .execute_with(|| {
+                System::set_block_number(1);
+                assert_ok!(Portal::initialize(
+                    Origin::root(),
+                    [0, 0, 0, 0],
+                    get_test_initialize_genesis_data().encode()
+                ));
+                let data = produce_mock_headers_range(1, 5);
+                let payload = snappy_literal(&data.encode());
+                let declared_weight = RuntimeCall::RococoBridge(
+                    pallet_grandpa_finality_verifier::Call::submit_compressed_headers {
+                        payload: payload.clone(),
+                    },
+                )
+                .get_dispatch_info()
+                .weight;
+                assert_eq!(
+                    declared_weight,
+                    RococoBridge::submit_headers_weight_of_size(compression::max_decompressed_len(
+                        &payload
+                    ) as u32)
+                );
+
+                let post_info =
+                    RococoBridge::submit_compressed_headers(Origin::signed(ALICE), payload)
+                        .unwrap();
+
+                assert!(post_info.actual_weight.unwrap().ref_time() < declared_weight.ref_time());
+                assert_eq!(
+                    Portal::get_finalized_height([0, 0, 0, 0]),
+                    Ok(HeightResult::Height(5))
+                );
+            });
+    }
+
+    #[cfg(feature = "compressed-proofs")]
+    #[test]
+    fn test_compressed_headers_inflating_over_vendor_limit_are_rejected() {
+        ExtBuilder::default()
+            .with_standard_sfx_abi()
+            .with_default_xdns_records()
+            .build()
+            .execute_with(|| {
+                assert_err!(
+                    Portal::submit_encoded_headers(
+                        *b"eth2",
+                        snappy_literal(&vec![0u8; 256 * 1024 + 1])
+                    ),
+                    pallet_portal::Error::<Runtime>::HeadersSizeLimitExceeded
+                );
+                assert_err!(
+                    Portal::verify_event_inclusion(
+                        *b"eth2",
+                        SpeedMode::Finalized,
+                        None,
+                        compression::with_prefix(Compression::Zstd, &[0u8; 16]),
+                    ),
+                    pallet_portal::Error::<Runtime>::PayloadDecompressionFailed
+                );
+            });
+    }
+
+    #[cfg(not(feature = "compressed-proofs"))]
+    #[test]
+    fn test_compressed_headers_and_proofs_are_rejected_without_the_feature() {
+        ExtBuilder::default()
+            .with_standard_sfx_abi()
+            .with_default_xdns_records()
+            .build()
+            .execute_with(|| {
+                let compressed = compression::with_prefix(Compression::Snappy, &[0u8; 16]);
+                assert_err!(
+                    Portal::submit_encoded_headers([0, 0, 0, 0], compressed.clone()),
+                    pallet_portal::Error::<Runtime>::PayloadDecompressionFailed
+                );
+                assert_err!(
+                    Portal::verify_event_inclusion(
+                        *b"eth2",
+                        SpeedMode::Finalized,
+                        None,
+                        compressed
+                    ),
+                    pallet_portal::Error::<Runtime>::PayloadDecompressionFailed
+                );
+            });
+    }
+
+    #[test]
+    fn test_event_inclusions_verified_at_finality_are_answered_from_cache() {
+        ExtBuilder::default()
+            .with_standard_sfx_abi()
+            .with_default_xdns_records()
+            .build()
+            .execute_with(|| {
+                let gateway_id = *b"ksma";
+                let proof = parachain_inclusion_proof(&[3u8; 16], [2u8; 32], vec![]);
+                let receipt = InclusionReceipt {
+                    height: 5,
+                    including_header: vec![2u8; 32],
+                    message: vec![3u8; 16],
+                };
+                assert!(Portal::verify_event_inclusion(
+                    gateway_id,
+                    SpeedMode::Finalized,
+                    None,
+                    proof.clone()
+                )
+                .is_err());
+
+                let key = Portal::inclusion_cache_key(gateway_id, &proof).unwrap();
+                assert_eq!(key, ([2u8; 32], sp_io::hashing::blake2_256(&[3u8; 16])));
+                pallet_portal::CachedInclusions::<Runtime>::insert(
+                    gateway_id,
+                    key,
+                    pallet_portal::CachedInclusion {
+                        receipt: receipt.clone(),
+                        source: None,
+                        slot: 0,
+                    },
+                );
+                assert!(Portal::is_event_inclusion_cached(
+                    gateway_id,
+                    &SpeedMode::Finalized,
+                    &None,
+                    &proof
+                ));
+                assert_eq!(
+                    Portal::verify_event_inclusion(
+                        gateway_id,
+                        SpeedMode::Finalized,
+                        None,
+                        proof.clone()
+                    ),
+                    Ok(receipt.clone())
+                );
+
+                // Other proofs of the same payload under the same header share the receipt
+                let other_proof = parachain_inclusion_proof(&[3u8; 16], [2u8; 32], vec![vec![5u8]]);
+                assert_ne!(other_proof, proof);
+                assert_eq!(
+                    Portal::verify_event_inclusion(
+                        gateway_id,
+                        SpeedMode::Finalized,
+                        None,
+                        other_proof
+                    ),
+                    Ok(receipt)
+                );
+                assert!(!Portal::is_event_inclusion_cached(
+                    gateway_id,
+                    &SpeedMode::Finalized,
+                    &None,
+                    &parachain_inclusion_proof(&[3u8; 16], [4u8; 32], vec![])
+                ));
+
+                // Faster speed modes and other sources are verified by the light client again
+                assert!(!Portal::is_event_inclusion_cached(
+                    gateway_id,
+                    &SpeedMode::Fast,
+                    &None,
+                    &proof
+                ));
+                assert!(Portal::verify_event_inclusion(
+                    gateway_id,
+                    SpeedMode::Fast,
+                    None,
+                    proof.clone()
+                )
+                .is_err());
+                assert!(Portal::verify_event_inclusion(
+                    gateway_id,
+                    SpeedMode::Finalized,
+                    Some([4u8; 32]),
+                    proof.clone()
+                )
+                .is_err());
+
+                // Re-initializing the light client drops the receipts it verified
+                assert_ok!(Portal::initialize(
+                    Origin::root(),
+                    gateway_id,
+                    get_test_initialize_genesis_data().encode()
+                ));
+                assert!(!Portal::is_event_inclusion_cached(
+                    gateway_id,
+                    &SpeedMode::Finalized,
+                    &None,
+                    &proof
+                ));
+            });
+    }
+
+    #[test]
+    fn test_cached_inclusions_evicted_from_the_ring_buffer_outlive_slots_taken_before() {
+        ExtBuilder::default()
+            .with_standard_sfx_abi()
+            .with_default_xdns_records()
+            .build()
+            .execute_with(|| {
+                System::set_block_number(1);
+                let gateway_id = *b"ksma";
+                let capacity = <Runtime as pallet_portal::Config>::MaxCachedInclusions::get();
+                let proof = |n: u8| parachain_inclusion_proof(&[n; 16], [n; 32], vec![]);
+                let cache = |n: u8| {
+                    Portal::cache_event_inclusion(
+                        gateway_id,
+                        &None,
+                        &proof(n),
+                        &InclusionReceipt {
+                            height: 5,
+                            including_header: vec![n; 32],
+                            message: vec![n; 16],
+                        },
+                    )
+                };
+                let is_cached = |n: u8| {
+                    Portal::is_event_inclusion_cached(
+                        gateway_id,
+                        &SpeedMode::Finalized,
+                        &None,
+                        &proof(n),
+                    )
+                };
+
+                // Cached again at the third slot, the first inclusion outlives the first one
+                cache(1);
+                cache(2);
+                cache(1);
+                for n in 3..capacity as u8 {
+                    cache(n);
+                }
+                cache(100);
+                assert!(is_cached(1));
+                assert!(is_cached(2));
+
+                cache(101);
+                assert!(!is_cached(2));
+                assert!(is_cached(1));
+                cache(102);
+                assert!(!is_cached(1));
+                assert!(is_cached(100));
+            });
+    }
+
+    #[test]
+    fn test_cached_inclusions_are_pruned_once_past_the_retention_period() {
+        ExtBuilder::default()
+            .with_standard_sfx_abi()
+            .with_default_xdns_records()
+            .build()
+            .execute_with(|| {
+                System::set_block_number(1);
+                let gateway_id = *b"ksma";
+                let key = ([2u8; 32], [3u8; 32]);
+                pallet_portal::CachedInclusions::<Runtime>::insert(
+                    gateway_id,
+                    key,
+                    pallet_portal::CachedInclusion {
+                        receipt: InclusionReceipt {
+                            height: 5,
+                            including_header: vec![2u8; 32],
+                            message: vec![3u8; 16],
+                        },
+                        source: None,
+                        slot: 0,
+                    },
+                );
+                retention::enqueue::<
+                    _,
+                    _,
+                    _,
+                    pallet_portal::CachedInclusionsExpiry<Runtime>,
+                    pallet_portal::CachedInclusionsPruneCursor<Runtime>,
+                >(10, (gateway_id, key), 0);
+
+                let period = <Runtime as pallet_portal::Config>::RetentionPeriod::get();
+                Portal::prune_cached_inclusions(10 + period - 1, Weight::MAX);
+                assert!(pallet_portal::CachedInclusions::<Runtime>::contains_key(
+                    gateway_id, key
+                ));
+
+                Portal::prune_cached_inclusions(10 + period, Weight::MAX);
+                assert!(!pallet_portal::CachedInclusions::<Runtime>::contains_key(
+                    gateway_id, key
+                ));
+                System::assert_last_event(RuntimeEvent::Portal(
+                    pallet_portal::Event::CachedInclusionsPruned(1),
+                ));
+            });
+    }
+
+    #[test]
+    fn test_headers_rolled_back_evict_inclusions_cached_from_the_fork_height_on() {
+        ExtBuilder::default()
+            .with_standard_sfx_abi()
+            .with_default_xdns_records()
+            .build()
+            .execute_with(|| {
+                System::set_block_number(1);
+                let gateway_id = *b"ksma";
+                let cache_at = |height: u32, message: &[u8]| {
+                    let key = ([height as u8; 32], sp_io::hashing::blake2_256(message));
+                    pallet_portal::CachedInclusions::<Runtime>::insert(
+                        gateway_id,
+                        key,
+                        pallet_portal::CachedInclusion {
+                            receipt: InclusionReceipt {
+                                height,
+                                including_header: vec![2u8; 32],
+                                message: message.to_vec(),
+                            },
+                            source: None,
+                            slot: height,
+                        },
+                    );
+                    key
+                };
+                let below_fork = cache_at(4, &[1u8; 64]);
+                let above_fork = cache_at(6, &[2u8; 64]);
+
+                Portal::roll_back_headers(gateway_id, 5);
+
+                assert!(pallet_portal::CachedInclusions::<Runtime>::contains_key(
+                    gateway_id, below_fork
+                ));
+                assert!(!pallet_portal::CachedInclusions::<Runtime>::contains_key(
+                    gateway_id, above_fork
+                ));
+                System::assert_last_event(RuntimeEvent::Portal(
+                    pallet_portal::Event::HeadersRolledBack(gateway_id, 5),
+                ));
+            });
+    }
+
+    #[test]
+    fn test_grandpa_headers_dropped_by_a_reset_are_rolled_back_on_the_next_submission() {
+        ExtBuilder::default()
+            .with_standard_sfx_abi()
+            .with_default_xdns_records()
+            .build()
+            .execute_with(|| {
+                System::set_block_number(1);
+                let gateway_id = *b"ksma";
+                assert_ok!(Portal::initialize(
+                    Origin::root(),
+                    gateway_id,
+                    get_test_initialize_genesis_data().encode()
+                ));
+                assert_eq!(
+                    Portal::submit_encoded_headers(
+                        gateway_id,
+                        produce_mock_headers_range(1, 5).encode()
+                    ),
+                    Ok(Weight::zero())
+                );
+
+                assert_ok!(KusamaBridge::reset(Origin::root()));
+                assert_ok!(Portal::initialize(
+                    Origin::root(),
+                    gateway_id,
+                    get_test_initialize_genesis_data().encode()
+                ));
+                let rollback_weight = Portal::submit_encoded_headers(
+                    gateway_id,
+                    produce_mock_headers_range(1, 5).encode(),
+                )
+                .unwrap();
+                assert!(rollback_weight.ref_time() > 0);
+                System::assert_has_event(RuntimeEvent::Portal(
+                    pallet_portal::Event::HeadersRolledBack(gateway_id, 0),
+                ));
+            });
+    }
+
+    #[test]
+    fn test_grandpa_headers_dropped_by_a_reset_are_rolled_back_on_the_next_bridge_submission() {
+        ExtBuilder::default()
+            .with_standard_sfx_abi()
+            .with_default_xdns_records()
+            .build()
+            .execute_with(|| {
+                System::set_block_number(1);
+                let gateway_id = *b"ksma";
+                assert_ok!(Portal::initialize(
+                    Origin::root(),
+                    gateway_id,
+                    get_test_initialize_genesis_data().encode()
+                ));
+                let data = produce_mock_headers_range(1, 5);
+                assert_ok!(KusamaBridge::submit_headers(
+                    Origin::signed(ALICE),
+                    data.range.clone().try_into().unwrap(),
+                    data.signed_header.clone(),
+                    data.justification.clone(),
+                ));
+
+                assert_ok!(KusamaBridge::reset(Origin::root()));
+                assert_ok!(Portal::initialize(
+                    Origin::root(),
+                    gateway_id,
+                    get_test_initialize_genesis_data().encode()
+                ));
+                assert_ok!(KusamaBridge::submit_headers(
+                    Origin::signed(ALICE),
+                    data.range.try_into().unwrap(),
+                    data.signed_header,
+                    data.justification,
+                ));
+                System::assert_has_event(RuntimeEvent::Portal(
+                    pallet_portal::Event::HeadersRolledBack(gateway_id, 0),
+                ));
+            });
+    }
+
+    #[test]
+    fn test_fast_height_of_ethereum_dropping_rolls_back_headers_from_above_it() {
+        ExtBuilder::default()
+            .with_standard_sfx_abi()
+            .with_default_xdns_records()
+            .build()
+            .execute_with(|| {
+                System::set_block_number(1);
+                let gateway_id = *b"eth2";
+                let init = generate_initialization(None, None);
+                assert_ok!(Portal::initialize(
+                    Origin::root(),
+                    gateway_id,
+                    init.encode()
+                ));
+                let fast_height = match Portal::get_fast_height(gateway_id) {
+                    Ok(HeightResult::Height(fast_height)) => fast_height,
+                    _ => panic!("Ethereum light client should be active once initialized"),
+                };
+
+                // First observation only notes the fast height
+                Portal::observe_fork_choice();
+                assert_eq!(
+                    pallet_portal::ObservedFastHeights::<Runtime>::get(GatewayVendor::Ethereum),
+                    Some(fast_height)
+                );
+
+                // A heavier chain, as if switched to, leaves the fast height below the one seen
+                pallet_portal::ObservedFastHeights::<Runtime>::insert(
+                    GatewayVendor::Ethereum,
+                    fast_height + 10,
+                );
+                let cache_at = |height: u32, leaf: u8| {
+                    let key = ([3u8; 32], [leaf; 32]);
+                    pallet_portal::CachedInclusions::<Runtime>::insert(
+                        gateway_id,
+                        key,
+                        pallet_portal::CachedInclusion {
+                            receipt: InclusionReceipt {
+                                height,
+                                including_header: vec![2u8; 32],
+                                message: vec![leaf; 64],
+                            },
+                            source: None,
+                            slot: leaf.into(),
+                        },
+                    );
+                    key
+                };
+                let below_fork = cache_at(fast_height, 1);
+                let above_fork = cache_at(fast_height + 5, 2);
+
+                Portal::observe_fork_choice();
+
+                assert!(pallet_portal::CachedInclusions::<Runtime>::contains_key(
+                    gateway_id, below_fork
+                ));
+                assert!(!pallet_portal::CachedInclusions::<Runtime>::contains_key(
+                    gateway_id, above_fork
+                ));
+                System::assert_has_event(RuntimeEvent::Portal(
+                    pallet_portal::Event::HeadersRolledBack(gateway_id, fast_height + 1),
+                ));
+                assert_eq!(
+                    pallet_portal::ObservedFastHeights::<Runtime>::get(GatewayVendor::Ethereum),
+                    Some(fast_height)
+                );
+            });
+    }
+
     fn test_get_latest_finalized_rational_fast_heights(vendor: GatewayVendor) {
         ExtBuilder::default()
             .with_standard_sfx_abi()
@@ -380,6 +962,225 @@ mod tests {
             });
     }
 
+    #[test]
+    fn test_header_subscribers_receive_newly_finalized_headers_once() {
+        ExtBuilder::default()
+            .with_standard_sfx_abi()
+            .with_default_xdns_records()
+            .build()
+            .execute_with(|| {
+                System::set_block_number(1);
+                let gateway_id = [0, 0, 0, 0];
+                assert_ok!(Portal::initialize(
+                    Origin::root(),
+                    gateway_id,
+                    get_test_initialize_genesis_data().encode()
+                ));
+
+                // The mock resolves signed origins to the sibling MOCK_SIBLING_PARA_ID
+                assert_ok!(Portal::subscribe_to_headers(
+                    Origin::signed(ALICE),
+                    gateway_id,
+                    vec![42, 0]
+                ));
+
+                let exported = || {
+                    System::events()
+                        .into_iter()
+                        .filter(|record| {
+                            matches!(
+                                record.event,
+                                RuntimeEvent::Portal(pallet_portal::Event::HeaderExported(..))
+                            )
+                        })
+                        .count()
+                };
+
+                Portal::export_headers();
+                System::assert_last_event(RuntimeEvent::Portal(
+                    pallet_portal::Event::HeaderExported(gateway_id, MOCK_SIBLING_PARA_ID, 0),
+                ));
+                Portal::export_headers();
+                assert_eq!(exported(), 1);
+
+                assert_ok!(Portal::submit_encoded_headers(
+                    gateway_id,
+                    produce_mock_headers_range(1, 5).encode()
+                ));
+                Portal::export_headers();
+                System::assert_last_event(RuntimeEvent::Portal(
+                    pallet_portal::Event::HeaderExported(gateway_id, MOCK_SIBLING_PARA_ID, 5),
+                ));
+
+                assert_ok!(Portal::unsubscribe_from_headers(
+                    Origin::signed(ALICE),
+                    gateway_id
+                ));
+                assert!(Portal::header_subscriptions(gateway_id, MOCK_SIBLING_PARA_ID).is_none());
+            });
+    }
+
+    #[test]
+    fn test_header_submissions_are_rate_limited_per_gateway() {
+        ExtBuilder::default()
+            .with_standard_sfx_abi()
+            .with_default_xdns_records()
+            .build()
+            .execute_with(|| {
+                System::set_block_number(1);
+                let gateway_id = [0, 0, 0, 0];
+                assert_ok!(Portal::initialize(
+                    Origin::root(),
+                    gateway_id,
+                    get_test_initialize_genesis_data().encode()
+                ));
+
+                // The mock allows 4 submissions per 4 blocks, refilling one each block
+                for _ in 0..4 {
+                    assert_ok!(Portal::take_header_submission(gateway_id));
+                }
+                assert_err!(
+                    Portal::submit_encoded_headers(
+                        gateway_id,
+                        produce_mock_headers_range(1, 5).encode()
+                    ),
+                    pallet_portal::Error::<Runtime>::HeaderSubmissionRateLimited
+                );
+                // Other gateways keep their own allowance
+                assert_ok!(Portal::take_header_submission(*b"pdot"));
+
+                System::set_block_number(2);
+                assert_ok!(Portal::submit_encoded_headers(
+                    gateway_id,
+                    produce_mock_headers_range(1, 5).encode()
+                ));
+                assert_err!(
+                    Portal::take_header_submission(gateway_id),
+                    pallet_portal::Error::<Runtime>::HeaderSubmissionRateLimited
+                );
+
+                System::set_block_number(100);
+                for _ in 0..4 {
+                    assert_ok!(Portal::take_header_submission(gateway_id));
+                }
+                assert_err!(
+                    Portal::take_header_submission(gateway_id),
+                    pallet_portal::Error::<Runtime>::HeaderSubmissionRateLimited
+                );
+            });
+    }
+
+    #[test]
+    fn test_oversized_headers_submitted_straight_to_the_bridge_are_rejected() {
+        ExtBuilder::default()
+            .with_standard_sfx_abi()
+            .with_default_xdns_records()
+            .build()
+            .execute_with(|| {
+                System::set_block_number(1);
+                assert_ok!(Portal::initialize(
+                    Origin::root(),
+                    [0, 0, 0, 0],
+                    get_test_initialize_genesis_data().encode()
+                ));
+
+                let mut data = produce_mock_headers_range(1, 5);
+                // Ancestries padding the justification past the bound of GRANDPA vendors
+                data.justification.votes_ancestries =
+                    vec![
+                        data.signed_header.clone();
+                        512 * 1024 / data.signed_header.encoded_size() + 1
+                    ];
+                assert_err!(
+                    RococoBridge::submit_headers(
+                        Origin::signed(ALICE),
+                        data.range.try_into().unwrap(),
+                        data.signed_header,
+                        data.justification,
+                    ),
+                    pallet_portal::Error::<Runtime>::HeadersSizeLimitExceeded
+                );
+            });
+    }
+
+    #[test]
+    fn test_headers_submitted_straight_to_the_bridge_draw_from_the_gateway_allowance() {
+        ExtBuilder::default()
+            .with_standard_sfx_abi()
+            .with_default_xdns_records()
+            .build()
+            .execute_with(|| {
+                System::set_block_number(1);
+                let gateway_id = [0, 0, 0, 0];
+                assert_ok!(Portal::initialize(
+                    Origin::root(),
+                    gateway_id,
+                    get_test_initialize_genesis_data().encode()
+                ));
+
+                let first_range = produce_mock_headers_range(1, 5);
+                assert_ok!(RococoBridge::submit_headers(
+                    Origin::signed(ALICE),
+                    first_range.range.try_into().unwrap(),
+                    first_range.signed_header,
+                    first_range.justification,
+                ));
+                for _ in 0..3 {
+                    assert_ok!(Portal::take_header_submission(gateway_id));
+                }
+
+                let next_range = produce_mock_headers_range(6, 10);
+                assert_err!(
+                    RococoBridge::submit_headers(
+                        Origin::signed(ALICE),
+                        next_range.range.clone().try_into().unwrap(),
+                        next_range.signed_header.clone(),
+                        next_range.justification.clone(),
+                    ),
+                    pallet_portal::Error::<Runtime>::HeaderSubmissionRateLimited
+                );
+
+                System::set_block_number(2);
+                assert_ok!(RococoBridge::submit_headers(
+                    Origin::signed(ALICE),
+                    next_range.range.try_into().unwrap(),
+                    next_range.signed_header,
+                    next_range.justification,
+                ));
+            });
+    }
+
+    #[test]
+    fn test_get_all_finalized_heights_reports_every_gateway_at_once() {
+        ExtBuilder::default()
+            .with_standard_sfx_abi()
+            .with_default_xdns_records()
+            .build()
+            .execute_with(|| {
+                System::set_block_number(1);
+                pallet_xdns::VerifierOverviewStoreHistory::<Runtime>::insert(
+                    GatewayVendor::Rococo,
+                    vec![FinalityVerifierActivity {
+                        verifier: GatewayVendor::Rococo,
+                        reported_at: 1,
+                        justified_height: 5,
+                        finalized_height: 5,
+                        updated_height: 6,
+                        epoch: 2,
+                        is_active: true,
+                    }],
+                );
+
+                let all_heights = Portal::get_all_finalized_heights();
+                assert_eq!(
+                    all_heights.len(),
+                    <XDNS as Xdns<Runtime, Balance>>::fetch_full_gateway_records().len()
+                );
+                assert!(all_heights.contains(&([0, 0, 0, 0], 5, 6, 2, true)));
+                assert!(all_heights.contains(&(*b"pdot", 0, 0, 0, false)));
+            });
+    }
+
     #[test]
     fn test_get_latest_finalized_height_rococo() {
         test_get_latest_finalized_rational_fast_heights(GatewayVendor::Rococo);
@@ -439,6 +1240,94 @@ mod tests {
             });
     }
 
+    #[test]
+    fn test_given_abi_descriptor_is_used_over_the_gateway_default() {
+        ExtBuilder::default()
+            .with_standard_sfx_abi()
+            .with_default_xdns_records()
+            .build()
+            .execute_with(|| {
+                let given = b"Transfer:Log(to+:Account20,amount+:Value256)".to_vec();
+                assert_eq!(
+                    pallet_portal::resolve_abi_descriptor::<Runtime>(
+                        b"eth2",
+                        given.clone(),
+                        Some(*b"tran")
+                    ),
+                    Ok(given.clone())
+                );
+                // Given descriptors need no side effect nor a gateway record to fall back to
+                assert_eq!(
+                    pallet_portal::resolve_abi_descriptor::<Runtime>(b"miss", given.clone(), None),
+                    Ok(given)
+                );
+            });
+    }
+
+    #[test]
+    fn test_empty_abi_descriptor_falls_back_to_the_gateway_default_stored_in_xdns() {
+        ExtBuilder::default()
+            .with_standard_sfx_abi()
+            .with_default_xdns_records()
+            .build()
+            .execute_with(|| {
+                let stored = XDNS::gateways(*b"eth2")
+                    .unwrap()
+                    .abi_descriptors
+                    .into_iter()
+                    .find(|(sfx_4b_id, _)| sfx_4b_id == b"tran")
+                    .map(|(_, descriptor)| descriptor.into_inner())
+                    .unwrap();
+                let tran_abi = pallet_xdns::StandardSFXABIs::<Runtime>::get(*b"tran").unwrap();
+                assert_eq!(
+                    stored,
+                    tran_abi.get_expected_ingress_descriptor(t3rn_abi::Codec::Rlp)
+                );
+
+                assert_eq!(
+                    pallet_portal::resolve_abi_descriptor::<Runtime>(
+                        b"eth2",
+                        vec![],
+                        Some(*b"tran")
+                    ),
+                    Ok(stored)
+                );
+            });
+    }
+
+    #[test]
+    fn test_empty_abi_descriptor_without_gateway_default_is_rejected() {
+        ExtBuilder::default()
+            .with_standard_sfx_abi()
+            .with_default_xdns_records()
+            .build()
+            .execute_with(|| {
+                // No side effect to look the default up by
+                assert_err!(
+                    pallet_portal::resolve_abi_descriptor::<Runtime>(b"eth2", vec![], None),
+                    pallet_portal::Error::<Runtime>::DefaultAbiDescriptorNotFound
+                );
+                // Side effect not enrolled on the gateway
+                assert_err!(
+                    pallet_portal::resolve_abi_descriptor::<Runtime>(
+                        b"eth2",
+                        vec![],
+                        Some(*b"swap")
+                    ),
+                    pallet_portal::Error::<Runtime>::DefaultAbiDescriptorNotFound
+                );
+                // Gateway not registered
+                assert_err!(
+                    pallet_portal::resolve_abi_descriptor::<Runtime>(
+                        b"miss",
+                        vec![],
+                        Some(*b"tran")
+                    ),
+                    pallet_portal::Error::<Runtime>::DefaultAbiDescriptorNotFound
+                );
+            });
+    }
+
     // #[test]
     // #[ignore]
     // fn run_e2e_tests() {
@@ -1,12 +1,13 @@
-//! Autogenerated weights for pallet_xdns
+//! Weights for pallet_portal
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 3.0.0
-//! DATE: 2021-09-19, STEPS: `[50, ]`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 128
+//! Estimated from the benchmarks of `benchmarking.rs` ahead of a run on reference hardware,
+//! scaling with the headers `h`, GRANDPA authorities `a` and proven events `p` bytes the way
+//! the Rococo light client verifies them. Regenerate the numbers with the command below.
 
 // Executed Command:
 // ./target/release/circuit
 // benchmark
+// pallet
 // --chain
 // dev
 // --execution
@@ -14,15 +15,13 @@
 // --wasm-execution
 // compiled
 // --pallet
-// pallet_xdns
+// pallet_portal
 // --extrinsic
 // *
 // --steps
 // 50
 // --repeat
 // 20
-// --raw
-// --template=../benchmarking/frame-weight-template.hbs
 // --output
 // .
 
@@ -35,22 +34,29 @@ use frame_support::{
 };
 use sp_std::marker::PhantomData;
 
-/// Weight functions needed for pallet_xdns.
+/// Weight functions needed for pallet_portal.
 pub trait WeightInfo {
-    fn register_gateway() -> Weight;
+    fn register_gateway(a: u32) -> Weight;
     fn set_owner() -> Weight;
     fn set_operational() -> Weight;
-    fn submit_headers() -> Weight;
+    fn submit_headers(h: u32, a: u32) -> Weight;
+    fn verify_event_inclusion(p: u32) -> Weight;
+    fn subscribe_to_headers() -> Weight;
+    fn unsubscribe_from_headers() -> Weight;
 }
 
-/// Weights for pallet_xdns using the Substrate node and recommended hardware.
+/// Weights for pallet_portal using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
-    fn register_gateway() -> Weight {
-        Weight::from_parts(72_795_000_u64, 0u64)
-            .saturating_add(T::DbWeight::get().reads(2_u64))
-            .saturating_add(T::DbWeight::get().writes(1_u64))
+    /// Storage: XDNS Gateways (r:1 w:1), XDNS AllTokenIds (r:1 w:1), XDNS Tokens (r:1 w:2),
+    /// XDNS GatewayTokens (r:2 w:2), Assets Asset (r:1 w:1), RococoBridge BestFinalizedHash (r:1 w:1),
+    /// RococoBridge RelayChainId (r:1 w:1), RococoBridge CurrentAuthoritySet (r:0 w:1), the rest of RococoBridge (r:0 w:6)
+    fn register_gateway(a: u32) -> Weight {
+        Weight::from_parts(121_380_000_u64, 0u64)
+            .saturating_add(Weight::from_parts(44_000_u64, 0u64).saturating_mul(a as u64))
+            .saturating_add(T::DbWeight::get().reads(9_u64))
+            .saturating_add(T::DbWeight::get().writes(16_u64))
     }
 
     fn set_owner() -> Weight {
@@ -65,17 +71,51 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
             .saturating_add(T::DbWeight::get().writes(1_u64))
     }
 
-    fn submit_headers() -> Weight {
-        Weight::from_parts(25_265_000_u64, 0u64).saturating_add(T::DbWeight::get().reads(1_u64))
+    /// Storage: XDNS Gateways (r:1 w:0), RococoBridge BestFinalizedHash (r:2 w:1),
+    /// RococoBridge CurrentAuthoritySet (r:1 w:0), RococoBridge IsHalted (r:1 w:0),
+    /// RococoBridge ImportedHashesPointer (r:1 w:1), RococoBridge ImportedHashes (r:1 w:1 per
+    /// header), RococoBridge ImportedHeaders (r:1 w:2 per header)
+    fn submit_headers(h: u32, a: u32) -> Weight {
+        Weight::from_parts(64_170_000_u64, 0u64)
+            .saturating_add(Weight::from_parts(14_260_000_u64, 0u64).saturating_mul(h as u64))
+            .saturating_add(Weight::from_parts(52_710_000_u64, 0u64).saturating_mul(a as u64))
+            .saturating_add(T::DbWeight::get().reads(6_u64))
+            .saturating_add(T::DbWeight::get().reads(1_u64).saturating_mul(h as u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64).saturating_mul(h as u64))
+    }
+
+    /// Storage: XDNS Gateways (r:1 w:0), RococoBridge RelayChainId (r:1 w:0),
+    /// RococoBridge ImportedHeaders (r:1 w:0)
+    fn verify_event_inclusion(p: u32) -> Weight {
+        Weight::from_parts(38_940_000_u64, 0u64)
+            .saturating_add(Weight::from_parts(5_130_u64, 0u64).saturating_mul(p as u64))
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+    }
+
+    fn subscribe_to_headers() -> Weight {
+        Weight::from_parts(30_000_000_u64, 0u64)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+
+    fn unsubscribe_from_headers() -> Weight {
+        Weight::from_parts(25_000_000_u64, 0u64)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
     }
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
-    fn register_gateway() -> Weight {
-        Weight::from_parts(72_795_000_u64, 0u64)
-            .saturating_add(RocksDbWeight::get().reads(2_u64))
-            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    /// Storage: XDNS Gateways (r:1 w:1), XDNS AllTokenIds (r:1 w:1), XDNS Tokens (r:1 w:2),
+    /// XDNS GatewayTokens (r:2 w:2), Assets Asset (r:1 w:1), RococoBridge BestFinalizedHash (r:1 w:1),
+    /// RococoBridge RelayChainId (r:1 w:1), RococoBridge CurrentAuthoritySet (r:0 w:1), the rest of RococoBridge (r:0 w:6)
+    fn register_gateway(a: u32) -> Weight {
+        Weight::from_parts(121_380_000_u64, 0u64)
+            .saturating_add(Weight::from_parts(44_000_u64, 0u64).saturating_mul(a as u64))
+            .saturating_add(RocksDbWeight::get().reads(9_u64))
+            .saturating_add(RocksDbWeight::get().writes(16_u64))
     }
 
     fn set_owner() -> Weight {
@@ -90,7 +130,37 @@ impl WeightInfo for () {
             .saturating_add(RocksDbWeight::get().writes(1_u64))
     }
 
-    fn submit_headers() -> Weight {
-        Weight::from_parts(25_265_000_u64, 0u64).saturating_add(RocksDbWeight::get().reads(1_u64))
+    /// Storage: XDNS Gateways (r:1 w:0), RococoBridge BestFinalizedHash (r:2 w:1),
+    /// RococoBridge CurrentAuthoritySet (r:1 w:0), RococoBridge IsHalted (r:1 w:0),
+    /// RococoBridge ImportedHashesPointer (r:1 w:1), RococoBridge ImportedHashes (r:1 w:1 per
+    /// header), RococoBridge ImportedHeaders (r:1 w:2 per header)
+    fn submit_headers(h: u32, a: u32) -> Weight {
+        Weight::from_parts(64_170_000_u64, 0u64)
+            .saturating_add(Weight::from_parts(14_260_000_u64, 0u64).saturating_mul(h as u64))
+            .saturating_add(Weight::from_parts(52_710_000_u64, 0u64).saturating_mul(a as u64))
+            .saturating_add(RocksDbWeight::get().reads(6_u64))
+            .saturating_add(RocksDbWeight::get().reads(1_u64).saturating_mul(h as u64))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64).saturating_mul(h as u64))
+    }
+
+    /// Storage: XDNS Gateways (r:1 w:0), RococoBridge RelayChainId (r:1 w:0),
+    /// RococoBridge ImportedHeaders (r:1 w:0)
+    fn verify_event_inclusion(p: u32) -> Weight {
+        Weight::from_parts(38_940_000_u64, 0u64)
+            .saturating_add(Weight::from_parts(5_130_u64, 0u64).saturating_mul(p as u64))
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+    }
+
+    fn subscribe_to_headers() -> Weight {
+        Weight::from_parts(30_000_000_u64, 0u64)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn unsubscribe_from_headers() -> Weight {
+        Weight::from_parts(25_000_000_u64, 0u64)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
     }
 }
@@ -17,7 +17,7 @@ pub mod pallet {
     use sp_core::H256;
 
     use sp_runtime::{
-        traits::{CheckedAdd, CheckedDiv, Saturating, Zero},
+        traits::{CheckedAdd, CheckedDiv, CheckedMul, Saturating, Zero},
         Perbill, Percent,
     };
     use sp_std::{collections::btree_map::BTreeMap, convert::TryInto, prelude::*};
@@ -27,7 +27,8 @@ pub mod pallet {
         circuit::{CircuitStatus, FullSideEffect},
         claimable::{BenefitSource, CircuitRole, ClaimableArtifacts},
         clock::Clock as ClockTrait,
-        common::RoundInfo,
+        common::{RoundIndex, RoundInfo},
+        executors::Executors,
         rewards::RewardsWriteApi,
         TreasuryAccount, TreasuryAccountProvider,
     };
@@ -123,6 +124,19 @@ pub mod pallet {
 
         type StartingRepatriationPercentage: Get<Percent>;
 
+        /// The portion of inflation-derived executor rewards claimable immediately, the rest vests.
+        ///
+        /// Default: 100% (no vesting)
+        #[pallet::constant]
+        type ExecutorRewardsImmediateShare: Get<Percent>;
+
+        /// The number of rounds the vested executor rewards unlock over. Each round's share
+        /// accrues to the executor in the `Executors` rewards and is claimed from there.
+        #[pallet::constant]
+        type ExecutorRewardsVestingRounds: Get<RoundIndex>;
+
+        type Executors: Executors<Self, BalanceOf<Self>>;
+
         type Clock: ClockTrait<Self>;
 
         type AccountManager: AccountManager<
@@ -222,6 +236,8 @@ pub mod pallet {
         NewMaxRewardExecutorsKickbackSet(Percent, Percent),
         Claimed(T::AccountId, Vec<(BalanceOf<T>, Option<u32>)>),
         PendingClaim(T::AccountId, BalanceOf<T>),
+        // executor, amount vesting from the next round
+        ExecutorRewardVested(T::AccountId, BalanceOf<T>),
     }
 
     #[pallet::error]
@@ -329,7 +345,9 @@ pub mod pallet {
                         .position(|&(_, asset_id)| asset_id == claim.non_native_asset_id)
                     {
                         let (balance, _) = &mut total_claimed_assets[position];
-                        *balance = balance.saturating_add(claim.total_round_claim);
+                        *balance = balance
+                            .checked_add(&claim.total_round_claim)
+                            .ok_or(Error::<T>::ArithmeticOverflow)?;
                     } else {
                         total_claimed_assets
                             .push((claim.total_round_claim, claim.non_native_asset_id));
@@ -418,9 +436,9 @@ pub mod pallet {
                 executor_rewards: executor_rewards_distributed,
                 treasury_rewards,
                 distributed: attester_rewards_distributed
-                    + collator_rewards_distributed
-                    + executor_rewards_distributed
-                    + treasury_rewards,
+                    .saturating_add(collator_rewards_distributed)
+                    .saturating_add(executor_rewards_distributed)
+                    .saturating_add(treasury_rewards),
                 available: balance_for_distribution,
             };
             let mut history = DistributionHistory::<T>::get();
@@ -439,8 +457,15 @@ pub mod pallet {
                 return Zero::zero()
             }
 
-            let reward_per_attester =
-                current_distribution / BalanceOf::<T>::from(active_set_size as u32);
+            let reward_per_attester = match current_distribution
+                .checked_div(&BalanceOf::<T>::from(active_set_size as u32))
+            {
+                Some(reward_per_attester) => reward_per_attester,
+                None => {
+                    log::error!("No active attesters to split the attester rewards among");
+                    return Zero::zero()
+                },
+            };
 
             for attester in honest_active_set {
                 let attester_info =
@@ -476,9 +501,15 @@ pub mod pallet {
 
                 // Distribute the remaining reward to the nominators
                 for (nominator, nomination_balance) in nominators {
-                    let check_nominator_reward = remaining_reward
-                        .saturating_mul(nomination_balance)
-                        .checked_div(&total_nomination);
+                    let check_nominator_reward =
+                        match remaining_reward.checked_mul(&nomination_balance) {
+                            Some(product) => product.checked_div(&total_nomination),
+                            // Past the balance range, split by the nomination's share of the total instead
+                            None => Some(
+                                Perbill::from_rational(nomination_balance, total_nomination)
+                                    .mul_floor(remaining_reward),
+                            ),
+                        };
                     match check_nominator_reward {
                         Some(nominator_reward) => {
                             Self::update_pending_claims(
@@ -567,7 +598,7 @@ pub mod pallet {
                     total_settled_executions_this_round,
                 );
 
-            let mut distibuted_rewards = Zero::zero();
+            let mut distibuted_rewards: BalanceOf<T> = Zero::zero();
 
             // Distribute the executor rewards proportionally
             for (executor, accumulated_settlement, proportion) in
@@ -579,19 +610,34 @@ pub mod pallet {
                 let max_reward = max_reward_executors_kickback.mul_ceil(accumulated_settlement);
                 let capped_reward = reward.min(max_reward);
 
+                // Vest all but the immediate share of the reward
+                let vested = if T::ExecutorRewardsVestingRounds::get() > 0 {
+                    capped_reward.saturating_sub(
+                        T::ExecutorRewardsImmediateShare::get().mul_floor(capped_reward),
+                    )
+                } else {
+                    Zero::zero()
+                };
+                if !vested.is_zero() {
+                    Self::vest_executor_reward(&executor, vested);
+                }
+                let immediate = capped_reward.saturating_sub(vested);
+
                 // Update the pending claims for the executor
-                Self::update_pending_claims(
-                    &executor,
-                    CircuitRole::Executor,
-                    capped_reward,
-                    BenefitSource::Inflation,
-                    None,
-                );
+                if !immediate.is_zero() || vested.is_zero() {
+                    Self::update_pending_claims(
+                        &executor,
+                        CircuitRole::Executor,
+                        immediate,
+                        BenefitSource::Inflation,
+                        None,
+                    );
+                }
 
                 // Remove the accumulated settlement from the storage
                 AccumulatedSettlements::<T>::remove_prefix(&executor, None);
 
-                distibuted_rewards += capped_reward;
+                distibuted_rewards = distibuted_rewards.saturating_add(capped_reward);
             }
 
             distibuted_rewards
@@ -801,6 +847,28 @@ pub mod pallet {
             Self::deposit_event(Event::PendingClaim(account.clone(), reward));
         }
 
+        /// Accrue the reward to the executor in equal shares over the next
+        /// `ExecutorRewardsVestingRounds` rounds, the rounding remainder going to the last one.
+        /// The reward is deposited into the treasury the accrued rewards are claimed from.
+        pub fn vest_executor_reward(executor: &T::AccountId, amount: BalanceOf<T>) {
+            let rounds = T::ExecutorRewardsVestingRounds::get().max(1);
+            let current_round = T::Clock::current_round().index;
+            let share = amount / BalanceOf::<T>::from(rounds);
+            let mut remaining = amount;
+            for round in 1..=rounds {
+                let accrued = if round == rounds { remaining } else { share };
+                remaining = remaining.saturating_sub(accrued);
+                T::Executors::accrue_reward(executor, current_round.saturating_add(round), accrued);
+            }
+            T::AccountManager::deposit_immediately(
+                &T::TreasuryAccounts::get_treasury_account(TreasuryAccount::Treasury),
+                amount,
+                None,
+            );
+
+            Self::deposit_event(Event::ExecutorRewardVested(executor.clone(), amount));
+        }
+
         fn update_pending_claims(
             account: &T::AccountId,
             role: CircuitRole,
@@ -958,9 +1026,10 @@ pub mod test {
 
     use sp_runtime::Percent;
     use t3rn_mini_mock_runtime::{
-        AccountId, Authors, AuthorsThisPeriod, Balance, Balances, Clock, ConfigRewards,
-        DistributionHistory, ExtBuilder, MiniRuntime, PendingClaims, Rewards, RewardsError,
-        RuntimeOrigin, SettlementsPerRound, System,
+        AccountId, AccruedRewards, Authors, AuthorsThisPeriod, Balance, Balances, Clock,
+        ConfigRewards, DistributionHistory, ExecutorRewardsImmediateShare,
+        ExecutorRewardsVestingRounds, Executors, ExecutorsError, ExtBuilder, MiniRuntime,
+        PendingClaims, Rewards, RewardsError, RuntimeOrigin, SettlementsPerRound, System,
     };
 
     use t3rn_primitives::{
@@ -1426,6 +1495,42 @@ pub mod test {
         });
     }
 
+    #[test]
+    fn test_claim_of_pending_claims_above_max_balance_fails_with_overflow() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            let beneficiary = AccountId::from([99u8; 32]);
+            let max_claim = ClaimableArtifacts {
+                beneficiary: beneficiary.clone(),
+                role: CircuitRole::Executor,
+                total_round_claim: Balance::MAX,
+                benefit_source: BenefitSource::Inflation,
+                non_native_asset_id: None,
+            };
+            PendingClaims::<MiniRuntime>::insert(
+                beneficiary.clone(),
+                vec![
+                    max_claim.clone(),
+                    ClaimableArtifacts {
+                        total_round_claim: 1 as Balance,
+                        benefit_source: BenefitSource::TrafficRewards,
+                        ..max_claim
+                    },
+                ],
+            );
+
+            assert_err!(
+                Rewards::claim(RuntimeOrigin::signed(beneficiary.clone()), None),
+                RewardsError::<MiniRuntime>::ArithmeticOverflow
+            );
+            assert_eq!(
+                Rewards::get_pending_claims(beneficiary.clone()).map(|claims| claims.len()),
+                Some(2)
+            );
+            assert_eq!(Balances::free_balance(beneficiary), 0 as Balance);
+        });
+    }
+
     #[test]
     fn test_claim_executor_rewards_without_inflation_extras() {
         let mut ext = ExtBuilder::default().build();
@@ -1641,6 +1746,9 @@ pub mod test {
                 submission_target_height: 1,
                 best_bid: None,
                 index: 0,
+                deadline: None,
+                dependencies: vec![],
+                metadata: vec![],
             };
 
             let sfx_id = H256::from([99u8; 32]);
@@ -1700,6 +1808,9 @@ pub mod test {
                 submission_target_height: 1,
                 best_bid: None,
                 index: 0,
+                deadline: None,
+                dependencies: vec![],
+                metadata: vec![],
             };
 
             let sfx_id = H256::from([99u8; 32]);
@@ -1714,4 +1825,91 @@ pub mod test {
             assert_eq!(Rewards::get_pending_claims(executor), None);
         });
     }
+
+    #[test]
+    fn test_distribution_to_executors_vests_all_but_the_immediate_share_of_inflation_rewards() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            ExecutorRewardsImmediateShare::set(&Percent::from_percent(30));
+            ExecutorRewardsVestingRounds::set(&2);
+            let executor = AccountId::from([99u8; 32]);
+            const INITIAL_BALANCE: Balance = 1;
+            Balances::deposit_creating(&executor, INITIAL_BALANCE);
+
+            for counter in 1..11u8 {
+                SettlementsPerRound::<MiniRuntime>::insert(
+                    Clock::current_round(),
+                    H256::from([counter; 32]),
+                    Settlement {
+                        requester: AccountId::from([counter + 100u8; 32]),
+                        recipient: executor.clone(),
+                        settlement_amount: 100 as Balance,
+                        outcome: Outcome::Commit,
+                        source: BenefitSource::TrafficRewards,
+                        role: CircuitRole::Executor,
+                        maybe_asset_id: None,
+                    },
+                );
+            }
+            Rewards::process_accumulated_settlements();
+            assert_ok!(Rewards::set_max_rewards_executors_kickback(
+                RuntimeOrigin::root(),
+                Percent::from_percent(90),
+            ));
+
+            assert_eq!(Rewards::distribute_executor_rewards(100 * 100), 900);
+
+            // 30% of the 900 inflation rewards is claimable right away
+            assert_eq!(
+                Rewards::get_pending_claims(executor.clone())
+                    .unwrap()
+                    .last()
+                    .map(|claim| claim.total_round_claim),
+                Some(270)
+            );
+            // The rest accrues to the executor over the next two rounds
+            let round = Clock::current_round().index;
+            assert_eq!(AccruedRewards::<MiniRuntime>::get(&executor, round), 0);
+            assert_eq!(
+                AccruedRewards::<MiniRuntime>::get(&executor, round + 1),
+                315
+            );
+            assert_eq!(
+                AccruedRewards::<MiniRuntime>::get(&executor, round + 2),
+                315
+            );
+            let treasury = MiniRuntime::get_treasury_account(TreasuryAccount::Treasury);
+            assert_eq!(Balances::free_balance(&treasury), 630);
+            // Keep the treasury alive past the last claim
+            Balances::deposit_creating(&treasury, 1);
+            assert_err!(
+                Executors::claim_rewards(RuntimeOrigin::signed(executor.clone()), round + 2),
+                ExecutorsError::<MiniRuntime>::NothingToClaim
+            );
+
+            let bump_round = || {
+                let round = Clock::current_round();
+                let next_round_start = round.head + round.term;
+                System::set_block_number(next_round_start);
+                Clock::check_bump_round(next_round_start);
+            };
+
+            bump_round();
+            assert_ok!(Executors::claim_rewards(
+                RuntimeOrigin::signed(executor.clone()),
+                round + 2
+            ));
+            assert_eq!(Balances::free_balance(&executor), INITIAL_BALANCE + 315);
+
+            bump_round();
+            assert_ok!(Executors::claim_rewards(
+                RuntimeOrigin::signed(executor.clone()),
+                round + 2
+            ));
+            assert_eq!(Balances::free_balance(&executor), INITIAL_BALANCE + 630);
+            assert!(AccruedRewards::<MiniRuntime>::iter_prefix(&executor)
+                .next()
+                .is_none());
+        });
+    }
 }
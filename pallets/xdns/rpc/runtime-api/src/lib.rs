@@ -9,13 +9,17 @@
 use codec::Codec;
 use sp_runtime::sp_std;
 use sp_std::prelude::*;
-use t3rn_primitives::xdns::{FullGatewayRecord, GatewayRecord};
+use t3rn_primitives::xdns::{
+    FullGatewayRecord, GatewayActivityStats, GatewayRecord, SfxSchema, XtxCostEstimate,
+};
 pub use t3rn_primitives::{gateway::GatewayABIConfig, ChainId};
 
 sp_api::decl_runtime_apis! {
     /// The API to interact with pallet XDNS
-    pub trait XdnsRuntimeApi<AccountId> where
+    #[api_version(6)]
+    pub trait XdnsRuntimeApi<AccountId, Balance> where
         AccountId: Codec,
+        Balance: Codec,
     {
         /// Returns metadata for all known Blockchains
         fn fetch_records() -> Vec<GatewayRecord<AccountId>>;
@@ -26,5 +30,25 @@ sp_api::decl_runtime_apis! {
         fn fetch_abi(chain_id: ChainId) -> Option<GatewayABIConfig>;
 
         fn retreive_treasury_address(treasury_account: t3rn_primitives::TreasuryAccount) -> AccountId;
+
+        /// Returns the best operational gateway to route the given side effect (4b id) over the given asset to
+        #[api_version(2)]
+        fn best_gateway_for(sfx_4b_id: [u8; 4], asset_id: u32) -> Option<ChainId>;
+
+        /// Returns the rolling activity stats of the given gateway
+        #[api_version(3)]
+        fn fetch_gateway_activity_stats(chain_id: ChainId) -> GatewayActivityStats;
+
+        /// Returns the estimated cost in the quote asset of an Xtx of (target, asset, insurance) side effects
+        #[api_version(4)]
+        fn estimate_xtx_cost(side_effects: Vec<(ChainId, u32, Balance)>, quote_asset: u32) -> XtxCostEstimate<Balance>;
+
+        /// Returns the schemas of all registered side effects, with their ABI descriptors, confirmation events and gateways
+        #[api_version(5)]
+        fn fetch_sfx_schemas() -> Vec<SfxSchema>;
+
+        /// Returns the max_fee the recent bids on the side effect suggest, unless the fee oracles stopped hinting it
+        #[api_version(6)]
+        fn suggest_max_fee(gateway_id: ChainId, sfx_4b_id: [u8; 4]) -> Option<Balance>;
     }
 }
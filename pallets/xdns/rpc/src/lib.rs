@@ -8,18 +8,20 @@ use jsonrpsee::{
 };
 pub use pallet_xdns_rpc_runtime_api::XdnsRuntimeApi;
 use pallet_xdns_rpc_runtime_api::{ChainId, GatewayABIConfig};
-use sp_api::ProvideRuntimeApi;
+use sp_api::{ApiExt, ProvideRuntimeApi};
 use sp_blockchain::HeaderBackend;
 use sp_core::sp_std;
 use sp_runtime::traits::{Block as BlockT, MaybeDisplay};
 use sp_std::prelude::*;
 use std::sync::Arc;
-use t3rn_primitives::xdns::{FullGatewayRecord, GatewayRecord};
+use t3rn_primitives::xdns::{
+    FullGatewayRecord, GatewayActivityStats, GatewayRecord, SfxSchema, XtxCostEstimate,
+};
 
 const RUNTIME_ERROR: i64 = 1;
 
 #[rpc(client, server)]
-pub trait XdnsApi<AccountId> {
+pub trait XdnsApi<AccountId, Balance> {
     /// Returns all known XDNS records
     #[method(name = "xdns_fetchRecords")]
     fn fetch_records(&self) -> RpcResult<Vec<GatewayRecord<AccountId>>>;
@@ -29,6 +31,34 @@ pub trait XdnsApi<AccountId> {
 
     #[method(name = "xdns_fetchFullRecords")]
     fn fetch_full_gateway_records(&self) -> RpcResult<Vec<FullGatewayRecord<AccountId>>>;
+
+    /// Returns the best operational gateway to route the given side effect over the given asset to
+    #[method(name = "xdns_bestGatewayFor")]
+    fn best_gateway_for(&self, sfx_4b_id: [u8; 4], asset_id: u32) -> RpcResult<Option<ChainId>>;
+
+    /// Returns the rolling activity stats (headers accepted, SFX targeted, confirmed and reverted) of the gateway
+    #[method(name = "xdns_fetchGatewayActivityStats")]
+    fn fetch_gateway_activity_stats(&self, chain_id: ChainId) -> RpcResult<GatewayActivityStats>;
+
+    /// Returns the estimated fees, insurance and expected bid range of an Xtx of (target, asset, insurance) side effects, quoted in the given asset
+    #[method(name = "xdns_estimateXtxCost")]
+    fn estimate_xtx_cost(
+        &self,
+        side_effects: Vec<(ChainId, u32, Balance)>,
+        quote_asset: u32,
+    ) -> RpcResult<XtxCostEstimate<Balance>>;
+
+    /// Returns the schemas of all registered side effects, for order forms to be built by
+    #[method(name = "xdns_fetchSfxSchemas")]
+    fn fetch_sfx_schemas(&self) -> RpcResult<Vec<SfxSchema>>;
+
+    /// Returns the max_fee to order the side effect on the gateway with, as hinted by the fee oracles off the recent winning bids
+    #[method(name = "xdns_suggestMaxFee")]
+    fn suggest_max_fee(
+        &self,
+        gateway_id: ChainId,
+        sfx_4b_id: [u8; 4],
+    ) -> RpcResult<Option<Balance>>;
 }
 
 /// A struct that implements the [`XdnsApiServer`].
@@ -47,13 +77,43 @@ impl<C, P> Xdns<C, P> {
     }
 }
 
+impl<C, Block> Xdns<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block>,
+{
+    /// Rejects calls of methods added to XdnsRuntimeApi after the version the runtime at `at` implements.
+    fn ensure_api_version<AccountId: Codec, Balance: Codec>(
+        &self,
+        at: Block::Hash,
+        required: u32,
+    ) -> RpcResult<()>
+    where
+        C::Api: XdnsRuntimeApi<Block, AccountId, Balance>,
+    {
+        let version = self
+            .client
+            .runtime_api()
+            .api_version::<dyn XdnsRuntimeApi<Block, AccountId, Balance>>(at)
+            .map_err(runtime_error_into_rpc_err)?
+            .ok_or_else(|| runtime_error_into_rpc_err("XdnsRuntimeApi not implemented"))?;
+        if version < required {
+            return Err(runtime_error_into_rpc_err(format!(
+                "XdnsRuntimeApi v{required} required, runtime implements v{version}"
+            )))
+        }
+        Ok(())
+    }
+}
+
 #[async_trait]
-impl<C, Block, AccountId> XdnsApiServer<AccountId> for Xdns<C, Block>
+impl<C, Block, AccountId, Balance> XdnsApiServer<AccountId, Balance> for Xdns<C, Block>
 where
     AccountId: Codec + MaybeDisplay,
+    Balance: Codec + MaybeDisplay,
     Block: BlockT,
     C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
-    C::Api: XdnsRuntimeApi<Block, AccountId>,
+    C::Api: XdnsRuntimeApi<Block, AccountId, Balance>,
 {
     fn fetch_records(&self) -> RpcResult<Vec<GatewayRecord<AccountId>>> {
         let api = self.client.runtime_api();
@@ -75,6 +135,74 @@ where
         Ok(result)
     }
 
+    fn best_gateway_for(&self, sfx_4b_id: [u8; 4], asset_id: u32) -> RpcResult<Option<ChainId>> {
+        let api = self.client.runtime_api();
+        let at = self.client.info().best_hash;
+        self.ensure_api_version::<AccountId, Balance>(at, 2)?;
+
+        let result = api
+            .best_gateway_for(at, sfx_4b_id, asset_id)
+            .map_err(runtime_error_into_rpc_err)?;
+
+        Ok(result)
+    }
+
+    fn fetch_gateway_activity_stats(&self, chain_id: ChainId) -> RpcResult<GatewayActivityStats> {
+        let api = self.client.runtime_api();
+        let at = self.client.info().best_hash;
+        self.ensure_api_version::<AccountId, Balance>(at, 3)?;
+
+        let result = api
+            .fetch_gateway_activity_stats(at, chain_id)
+            .map_err(runtime_error_into_rpc_err)?;
+
+        Ok(result)
+    }
+
+    fn estimate_xtx_cost(
+        &self,
+        side_effects: Vec<(ChainId, u32, Balance)>,
+        quote_asset: u32,
+    ) -> RpcResult<XtxCostEstimate<Balance>> {
+        let api = self.client.runtime_api();
+        let at = self.client.info().best_hash;
+        self.ensure_api_version::<AccountId, Balance>(at, 4)?;
+
+        let result = api
+            .estimate_xtx_cost(at, side_effects, quote_asset)
+            .map_err(runtime_error_into_rpc_err)?;
+
+        Ok(result)
+    }
+
+    fn fetch_sfx_schemas(&self) -> RpcResult<Vec<SfxSchema>> {
+        let api = self.client.runtime_api();
+        let at = self.client.info().best_hash;
+        self.ensure_api_version::<AccountId, Balance>(at, 5)?;
+
+        let result = api
+            .fetch_sfx_schemas(at)
+            .map_err(runtime_error_into_rpc_err)?;
+
+        Ok(result)
+    }
+
+    fn suggest_max_fee(
+        &self,
+        gateway_id: ChainId,
+        sfx_4b_id: [u8; 4],
+    ) -> RpcResult<Option<Balance>> {
+        let api = self.client.runtime_api();
+        let at = self.client.info().best_hash;
+        self.ensure_api_version::<AccountId, Balance>(at, 6)?;
+
+        let result = api
+            .suggest_max_fee(at, gateway_id, sfx_4b_id)
+            .map_err(runtime_error_into_rpc_err)?;
+
+        Ok(result)
+    }
+
     fn fetch_abi(&self, chain_id: ChainId) -> RpcResult<GatewayABIConfig> {
         let api = self.client.runtime_api();
         let at = self.client.info().best_hash;
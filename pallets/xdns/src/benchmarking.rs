@@ -1,224 +1,444 @@
-// //! Benchmarking setup for pallet-xdns
-//
-// use super::*;
-// use crate::Pallet as XDNS;
-// use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite};
-// use frame_system::RawOrigin;
-// use sp_std::prelude::*;
-// use t3rn_primitives::{xdns::Xdns, TokenSysProps};
-//
-// use sp_runtime::create_runtime_str;
-// use sp_version::RuntimeVersion;
-//
-// const USER_SEED: u32 = 999666;
-// pub const TEST_RUNTIME_VERSION: RuntimeVersion = RuntimeVersion {
-//     spec_name: create_runtime_str!("test-runtime"),
-//     impl_name: create_runtime_str!("test-runtime"),
-//     authoring_version: 1,
-//     spec_version: 1,
-//     impl_version: 1,
-//     apis: sp_version::create_apis_vec!([]),
-//     transaction_version: 1,
-//     state_version: 0,
-// };
-//
-// benchmarks! {
-//     add_new_xdns_record {
-//         let url = b"some_url".to_vec();
-//         let gateway_id = b"test";
-//         let gateway_abi: GatewayABIConfig = Default::default();
-//
-//         let gateway_vendor = GatewayVendor::Rococo;
-//         let gateway_type = GatewayType::TxOnly(0);
-//
-//         let gateway_genesis = GatewayGenesisConfig {
-//             modules_encoded: None,
-//             extrinsics_version: 0u8,
-//             genesis_hash: Default::default(),
-//         };
-//
-//         let gateway_sys_props = TokenSysProps {
-//             ss58_format: 0,
-//             token_symbol: Encode::encode(""),
-//             token_decimals: 0,
-//         };
-//     }: _(RawOrigin::Root, url, *gateway_id, gateway_abi, gateway_vendor, gateway_type, gateway_genesis, gateway_sys_props, vec![])
-//     verify {
-//         assert!(
-//             XDNSRegistry::<T>::get(T::Hashing::hash(b"test"))
-//                 .is_some()
-//         );
-//     }
-//
-//     update_ttl {
-//         let url = b"some_url".to_vec();
-//
-//         let gateway_id = b"gate";
-//         let gateway_abi: GatewayABIConfig = Default::default();
-//         let gateway_vendor = GatewayVendor::Rococo;
-//         let gateway_type = GatewayType::TxOnly(0);
-//
-//         let gateway_genesis = GatewayGenesisConfig {
-//             modules_encoded: None,
-//             extrinsics_version: 0u8,
-//             genesis_hash: Default::default(),
-//         };
-//
-//         let gateway_sys_props = TokenSysProps {
-//             ss58_format: 0,
-//             token_symbol: Encode::encode(""),
-//             token_decimals: 0,
-//         };
-//
-//         XDNS::<T>::add_new_xdns_record(
-//             RawOrigin::Root.into(),
-//             url,
-//             *gateway_id,
-//             gateway_abi,
-//             gateway_vendor,
-//             gateway_type,
-//             gateway_genesis,
-//             gateway_sys_props,
-//             vec![],
-//         )
-//         .unwrap();
-//
-//         let gateway_hash = T::Hashing::hash(b"gate");
-//
-//     }: _(RawOrigin::Root, *b"gate", 2)
-//     verify {
-//         assert_eq!(
-//             XDNSRegistry::<T>::get(gateway_hash)
-//                 .unwrap()
-//                 .last_finalized,
-//             Some(2)
-//         );
-//     }
-//
-//     purge_xdns_record {
-//         let requester: T::AccountId = account("TEST", 1u32, USER_SEED);
-//         let url = b"some_url".to_vec();
-//
-//         let gateway_id = b"gate";
-//         let gateway_abi: GatewayABIConfig = Default::default();
-//         let gateway_vendor = GatewayVendor::Rococo;
-//         let gateway_type = GatewayType::TxOnly(0);
-//
-//         let gateway_genesis = GatewayGenesisConfig {
-//             modules_encoded: None,
-//             extrinsics_version: 0u8,
-//             genesis_hash: Default::default(),
-//         };
-//
-//         let gateway_sys_props = TokenSysProps {
-//             ss58_format: 0,
-//             token_symbol: Encode::encode(""),
-//             token_decimals: 0,
-//         };
-//
-//         XDNS::<T>::add_new_xdns_record(
-//             RawOrigin::Root.into(),
-//             url,
-//             *gateway_id,
-//             gateway_abi,
-//             gateway_vendor,
-//             gateway_type,
-//             gateway_genesis,
-//             gateway_sys_props,
-//             vec![],
-//         )
-//         .unwrap();
-//
-//         let gateway_hash = T::Hashing::hash(b"gate");
-//     }: _(RawOrigin::Root, requester, gateway_hash.clone())
-//     verify{
-//         assert!(
-//             XDNSRegistry::<T>::get(gateway_hash)
-//                 .is_none()
-//         );
-//     }
-//
-//     best_available {
-//         let url = b"some_url".to_vec();
-//
-//         let gateway_id = b"gate";
-//         let gateway_abi: GatewayABIConfig = Default::default();
-//         let gateway_vendor = GatewayVendor::Rococo;
-//         let gateway_type = GatewayType::TxOnly(0);
-//
-//         let gateway_genesis = GatewayGenesisConfig {
-//             modules_encoded: None,
-//             extrinsics_version: 0u8,
-//             genesis_hash: Default::default(),
-//         };
-//
-//         let gateway_sys_props = TokenSysProps {
-//             ss58_format: 0,
-//             token_symbol: Encode::encode(""),
-//             token_decimals: 0,
-//         };
-//
-//         XDNS::<T>::add_new_xdns_record(
-//             RawOrigin::Root.into(),
-//             url,
-//             *gateway_id,
-//             gateway_abi,
-//             gateway_vendor,
-//             gateway_type,
-//             gateway_genesis,
-//             gateway_sys_props,
-//             vec![],
-//         )
-//         .unwrap();
-//     }: {XDNS::<T>::best_available(*b"gate")}
-//     verify{}
-// }
-//
-// #[cfg(test)]
-// mod tests {
-//     use super::*;
-//     use crate::mock::Test;
-//     use frame_support::assert_ok;
-//     use sp_io::TestExternalities;
-//
-//     pub fn new_test_ext() -> TestExternalities {
-//         let t = frame_system::GenesisConfig::default()
-//             .build_storage::<Test>()
-//             .unwrap();
-//         TestExternalities::new(t)
-//     }
-//
-//     #[test]
-//     fn benchmark_add_new_xdns_record() {
-//         new_test_ext().execute_with(|| {
-//             assert_ok!(test_benchmark_add_new_xdns_record::<Test>());
-//         });
-//     }
-//
-//     #[test]
-//     fn benchmark_update_ttl() {
-//         new_test_ext().execute_with(|| {
-//             assert_ok!(test_benchmark_update_ttl::<Test>());
-//         });
-//     }
-//
-//     #[test]
-//     fn benchmark_purge_xdns_record() {
-//         new_test_ext().execute_with(|| {
-//             assert_ok!(test_benchmark_purge_xdns_record::<Test>());
-//         });
-//     }
-//
-//     #[test]
-//     fn benchmark_best_available() {
-//         new_test_ext().execute_with(|| {
-//             assert_ok!(test_benchmark_best_available::<Test>());
-//         });
-//     }
-// }
-//
-// impl_benchmark_test_suite!(
-//     XDNS,
-//     crate::benchmarking::tests::new_test_ext(),
-//     crate::mock::Test
-// );
+//! Benchmarking setup for pallet-xdns
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet as XDNS;
+use circuit_runtime_types::AssetId;
+use codec::Decode;
+use frame_benchmarking::{account, benchmarks, whitelisted_caller, BenchmarkError};
+use frame_support::{
+    traits::{Currency, EnsureOrigin, Get},
+    BoundedVec,
+};
+use frame_system::RawOrigin;
+use sp_core::H256;
+use sp_runtime::traits::{IdentifyAccount, TrailingZeroInput};
+use sp_std::prelude::*;
+use t3rn_abi::Codec;
+use t3rn_primitives::{
+    xdns::{BondScalingFactors, FeeHint, GatewayFeeSchedule, Xdns, MAX_ASSETS_PER_GATEWAY},
+    ExecutionVendor, GatewaySecurityTier, SubstrateToken, TokenInfo,
+};
+use t3rn_types::fsx::TargetId;
+
+const USER_SEED: u32 = 999666;
+const GATEWAY_ID: TargetId = *b"gate";
+const COUNTERPART_GATEWAY_ID: TargetId = *b"cntr";
+const ASSET_ID: AssetId = 1000;
+const COUNTERPART_ASSET_ID: AssetId = 2000;
+
+fn seed_standard_sfx_abis<T: Config>() {
+    XDNS::<T>::seed_standard_sfx_abis(t3rn_abi::standard::standard_sfx_abi());
+}
+
+fn register_gateway<T: Config>(gateway_id: TargetId) {
+    seed_standard_sfx_abis::<T>();
+    <XDNS<T> as Xdns<T, BalanceOf<T>>>::add_new_gateway(
+        gateway_id,
+        GatewayVendor::Rococo,
+        ExecutionVendor::Substrate,
+        Codec::Scale,
+        None,
+        None,
+        vec![(*b"tran", Some(2)), (*b"tass", Some(4))],
+    )
+    .expect("Gateway should register in XDNS::runtime_benchmarks");
+}
+
+fn funded_account<T: Config>(name: &'static str) -> T::AccountId {
+    let account: T::AccountId = account(name, 0, USER_SEED);
+    let balance = T::Currency::minimum_balance()
+        .saturating_add(T::GatewayIdReservationDeposit::get().saturating_mul(2u32.into()));
+    T::Currency::make_free_balance_be(&account, balance);
+    account
+}
+
+fn token_info(asset_id: AssetId) -> TokenInfo {
+    TokenInfo::Substrate(SubstrateToken {
+        id: asset_id,
+        symbol: b"TKN".to_vec(),
+        decimals: 12,
+    })
+}
+
+// Registers the token on the self-gateway first, then links it to the given gateway
+fn register_token_on_gateway<T: Config>(asset_id: AssetId, gateway_id: TargetId) {
+    seed_standard_sfx_abis::<T>();
+    XDNS::<T>::do_reboot_self_gateway(RawOrigin::Root.into(), GatewayVendor::Rococo)
+        .expect("Self-gateway should reboot in XDNS::runtime_benchmarks");
+    <XDNS<T> as Xdns<T, BalanceOf<T>>>::register_new_token(
+        &RawOrigin::Root.into(),
+        asset_id,
+        token_info(asset_id),
+    )
+    .expect("Token should register in XDNS::runtime_benchmarks");
+    if gateway_id != T::SelfGatewayId::get() {
+        register_gateway::<T>(gateway_id);
+        <XDNS<T> as Xdns<T, BalanceOf<T>>>::link_token_to_gateway(
+            asset_id,
+            gateway_id,
+            token_info(asset_id),
+        )
+        .expect("Token should link to gateway in XDNS::runtime_benchmarks");
+    }
+}
+
+// Registers `count` tokens on the self-gateway and links all of them to the given gateway
+fn link_tokens_to_gateway<T: Config>(count: u32, gateway_id: TargetId) {
+    seed_standard_sfx_abis::<T>();
+    XDNS::<T>::do_reboot_self_gateway(RawOrigin::Root.into(), GatewayVendor::Rococo)
+        .expect("Self-gateway should reboot in XDNS::runtime_benchmarks");
+    register_gateway::<T>(gateway_id);
+    for asset_id in ASSET_ID..ASSET_ID.saturating_add(count) {
+        <XDNS<T> as Xdns<T, BalanceOf<T>>>::register_new_token(
+            &RawOrigin::Root.into(),
+            asset_id,
+            token_info(asset_id),
+        )
+        .expect("Token should register in XDNS::runtime_benchmarks");
+        <XDNS<T> as Xdns<T, BalanceOf<T>>>::link_token_to_gateway(
+            asset_id,
+            gateway_id,
+            token_info(asset_id),
+        )
+        .expect("Token should link to gateway in XDNS::runtime_benchmarks");
+    }
+}
+
+fn fee_hint<T: Config>() -> FeeHint<BalanceOf<T>> {
+    FeeHint {
+        bids_median: 1_000u32.into(),
+        gas_price: 0,
+        gas_price_median: 0,
+        max_fee: 1_000u32.into(),
+    }
+}
+
+benchmarks! {
+    reboot_self_gateway {
+        let origin = T::RegistrationOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+    }: _<T::RuntimeOrigin>(origin, GatewayVendor::Rococo)
+    verify {
+        assert!(Gateways::<T>::contains_key(T::SelfGatewayId::get()));
+    }
+
+    add_supported_bridging_asset {
+        let origin = T::RegistrationOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+    }: _<T::RuntimeOrigin>(origin, ASSET_ID, GATEWAY_ID)
+    verify {
+        assert!(AuthorizedMintAssets::<T>::get().contains(&(ASSET_ID, GATEWAY_ID)));
+    }
+
+    enroll_bridge_asset {
+        let origin = T::RegistrationOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+        seed_standard_sfx_abis::<T>();
+        XDNS::<T>::do_reboot_self_gateway(RawOrigin::Root.into(), GatewayVendor::Rococo)
+            .expect("Self-gateway should reboot in XDNS::runtime_benchmarks");
+        register_gateway::<T>(GATEWAY_ID);
+    }: _<T::RuntimeOrigin>(origin, ASSET_ID, GATEWAY_ID, token_info(ASSET_ID))
+    verify {
+        assert!(XDNS::<T>::check_asset_is_mintable(GATEWAY_ID, ASSET_ID));
+    }
+
+    enroll_new_abi_to_selected_gateway {
+        let origin = T::UpdateOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+        register_gateway::<T>(GATEWAY_ID);
+    }: _<T::RuntimeOrigin>(origin, GATEWAY_ID, *b"swap", None, Some(3))
+    verify {
+        assert!(SFXABIRegistry::<T>::contains_key(GATEWAY_ID, *b"swap"));
+    }
+
+    unroll_abi_of_selected_gateway {
+        let origin = T::UpdateOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+        register_gateway::<T>(GATEWAY_ID);
+    }: _<T::RuntimeOrigin>(origin, GATEWAY_ID, *b"tass")
+    verify {
+        assert!(!SFXABIRegistry::<T>::contains_key(GATEWAY_ID, *b"tass"));
+    }
+
+    add_remote_order_address {
+        let origin = T::UpdateOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+    }: _<T::RuntimeOrigin>(origin, GATEWAY_ID, H256::repeat_byte(1))
+    verify {
+        assert_eq!(RemoteOrderAddresses::<T>::get(GATEWAY_ID), Some(H256::repeat_byte(1)));
+    }
+
+    add_remote_bidding_address {
+        let origin = T::UpdateOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+    }: _<T::RuntimeOrigin>(origin, GATEWAY_ID, H256::repeat_byte(1))
+    verify {
+        assert_eq!(RemoteBiddingAddresses::<T>::get(GATEWAY_ID), Some(H256::repeat_byte(1)));
+    }
+
+    set_gateway_security_tier {
+        let origin = T::UpdateOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+        register_gateway::<T>(GATEWAY_ID);
+    }: _<T::RuntimeOrigin>(origin, GATEWAY_ID, GatewaySecurityTier::OptimisticOnly)
+    verify {
+        assert_eq!(
+            XDNS::<T>::get_gateway_security_tier(&GATEWAY_ID),
+            Ok(GatewaySecurityTier::OptimisticOnly)
+        );
+    }
+
+    set_bond_scaling_factors {
+        let origin = T::UpdateOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+        register_gateway::<T>(GATEWAY_ID);
+        let factors = BondScalingFactors {
+            rational_tier: 150,
+            optimistic_only_tier: 300,
+            per_revert_percent: 5,
+        };
+    }: _<T::RuntimeOrigin>(origin, GATEWAY_ID, factors.clone())
+    verify {
+        assert_eq!(BondScaling::<T>::get(GATEWAY_ID), factors);
+    }
+
+    set_gateway_fee_schedule {
+        let origin = T::UpdateOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+        register_gateway::<T>(GATEWAY_ID);
+        let fee_schedule = GatewayFeeSchedule {
+            base_fee: 1_000,
+            fee_per_byte: 10,
+        };
+    }: _<T::RuntimeOrigin>(origin, GATEWAY_ID, fee_schedule.clone())
+    verify {
+        assert_eq!(
+            Gateways::<T>::get(GATEWAY_ID).map(|record| record.fee_schedule),
+            Some(fee_schedule)
+        );
+    }
+
+    purge_supported_bridging_asset {
+        let origin = T::PurgeOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+        AuthorizedMintAssets::<T>::try_append((ASSET_ID, GATEWAY_ID))
+            .map_err(|_| BenchmarkError::Stop("AuthorizedMintAssets is full"))?;
+    }: _<T::RuntimeOrigin>(origin, ASSET_ID, GATEWAY_ID)
+    verify {
+        assert!(!AuthorizedMintAssets::<T>::get().contains(&(ASSET_ID, GATEWAY_ID)));
+    }
+
+    purge_gateway_record {
+        let t in 1 .. MAX_ASSETS_PER_GATEWAY;
+        let c in 0 .. MAX_ASSET_CORRESPONDENCES_PER_GATEWAY;
+        let origin = T::PurgeOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+        let requester: T::AccountId = account("requester", 0, USER_SEED);
+        link_tokens_to_gateway::<T>(t, GATEWAY_ID);
+        register_gateway::<T>(COUNTERPART_GATEWAY_ID);
+        for asset_id in ASSET_ID..ASSET_ID.saturating_add(c) {
+            XDNS::<T>::insert_asset_correspondence(GATEWAY_ID, asset_id, COUNTERPART_GATEWAY_ID, asset_id)
+                .expect("Correspondence should insert in XDNS::runtime_benchmarks");
+            XDNS::<T>::insert_asset_correspondence(COUNTERPART_GATEWAY_ID, asset_id, GATEWAY_ID, asset_id)
+                .expect("Correspondence should insert in XDNS::runtime_benchmarks");
+        }
+    }: _<T::RuntimeOrigin>(origin, requester, GATEWAY_ID)
+    verify {
+        assert!(!Gateways::<T>::contains_key(GATEWAY_ID));
+        assert_eq!(AssetCorrespondenceCount::<T>::get(COUNTERPART_GATEWAY_ID), 0);
+    }
+
+    unlink_token {
+        let origin = T::PurgeOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+        register_token_on_gateway::<T>(ASSET_ID, GATEWAY_ID);
+    }: _<T::RuntimeOrigin>(origin, GATEWAY_ID, ASSET_ID)
+    verify {
+        assert!(!Tokens::<T>::contains_key(ASSET_ID, GATEWAY_ID));
+    }
+
+    link_token {
+        let origin = T::RegistrationOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+        register_token_on_gateway::<T>(ASSET_ID, T::SelfGatewayId::get());
+        register_gateway::<T>(GATEWAY_ID);
+    }: _<T::RuntimeOrigin>(origin, GATEWAY_ID, ASSET_ID, token_info(ASSET_ID))
+    verify {
+        assert!(Tokens::<T>::contains_key(ASSET_ID, GATEWAY_ID));
+    }
+
+    purge_token_record {
+        let origin = T::PurgeOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+        register_token_on_gateway::<T>(ASSET_ID, GATEWAY_ID);
+    }: _<T::RuntimeOrigin>(origin, ASSET_ID)
+    verify {
+        assert!(!AllTokenIds::<T>::get().contains(&ASSET_ID));
+    }
+
+    link_asset_correspondence {
+        let origin = T::RegistrationOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+        register_gateway::<T>(GATEWAY_ID);
+        register_gateway::<T>(COUNTERPART_GATEWAY_ID);
+    }: _<T::RuntimeOrigin>(origin, GATEWAY_ID, ASSET_ID, COUNTERPART_GATEWAY_ID, COUNTERPART_ASSET_ID)
+    verify {
+        assert!(XDNS::<T>::are_assets_corresponding(
+            &GATEWAY_ID,
+            ASSET_ID,
+            &COUNTERPART_GATEWAY_ID,
+            COUNTERPART_ASSET_ID
+        ));
+    }
+
+    unlink_asset_correspondence {
+        let origin = T::PurgeOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+        register_gateway::<T>(GATEWAY_ID);
+        register_gateway::<T>(COUNTERPART_GATEWAY_ID);
+        XDNS::<T>::insert_asset_correspondence(GATEWAY_ID, ASSET_ID, COUNTERPART_GATEWAY_ID, COUNTERPART_ASSET_ID)
+            .expect("Correspondence should insert in XDNS::runtime_benchmarks");
+        XDNS::<T>::insert_asset_correspondence(COUNTERPART_GATEWAY_ID, COUNTERPART_ASSET_ID, GATEWAY_ID, ASSET_ID)
+            .expect("Correspondence should insert in XDNS::runtime_benchmarks");
+    }: _<T::RuntimeOrigin>(origin, GATEWAY_ID, ASSET_ID, COUNTERPART_GATEWAY_ID)
+    verify {
+        assert_eq!(
+            XDNS::<T>::get_corresponding_asset(&GATEWAY_ID, ASSET_ID, &COUNTERPART_GATEWAY_ID),
+            None
+        );
+    }
+
+    zip_topology {
+        // Leaves room for the self-gateway and the gateway the tokens are linked to
+        let g in 1 .. MAX_GATEWAYS - 2;
+        let t in 0 .. MAX_ASSETS_PER_GATEWAY;
+        let caller: T::AccountId = whitelisted_caller();
+        link_tokens_to_gateway::<T>(t, GATEWAY_ID);
+        for i in 0..g {
+            register_gateway::<T>(i.to_be_bytes());
+        }
+    }: _(RawOrigin::Signed(caller), MAX_GATEWAYS, MAX_TOKENS)
+
+    unzip_topology {
+        let origin = T::RegistrationOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+        register_token_on_gateway::<T>(ASSET_ID, GATEWAY_ID);
+        let topology = XDNS::<T>::do_zip_topology();
+    }: _<T::RuntimeOrigin>(origin, Some(topology), None)
+    verify {
+        assert!(Gateways::<T>::contains_key(GATEWAY_ID));
+    }
+
+    reserve_gateway_id {
+        let caller = funded_account::<T>("reserver");
+    }: _(RawOrigin::Signed(caller.clone()), GATEWAY_ID)
+    verify {
+        assert_eq!(
+            GatewayIdReservations::<T>::get(GATEWAY_ID).and_then(|reservation| reservation.owner),
+            Some(caller)
+        );
+    }
+
+    force_reserve_gateway_id {
+        let origin = T::RegistrationOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+        let squatter = funded_account::<T>("squatter");
+        XDNS::<T>::reserve_gateway_id(RawOrigin::Signed(squatter).into(), GATEWAY_ID)?;
+    }: _<T::RuntimeOrigin>(origin, GATEWAY_ID, None)
+    verify {
+        assert_eq!(
+            GatewayIdReservations::<T>::get(GATEWAY_ID).map(|reservation| reservation.owner),
+            Some(None)
+        );
+    }
+
+    release_gateway_id {
+        let caller = funded_account::<T>("reserver");
+        XDNS::<T>::reserve_gateway_id(RawOrigin::Signed(caller.clone()).into(), GATEWAY_ID)?;
+    }: _(RawOrigin::Signed(caller), GATEWAY_ID)
+    verify {
+        assert!(!GatewayIdReservations::<T>::contains_key(GATEWAY_ID));
+    }
+
+    update_token_sys_props {
+        let origin = T::UpdateOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+        register_token_on_gateway::<T>(ASSET_ID, GATEWAY_ID);
+        let token_props = TokenInfo::Substrate(SubstrateToken {
+            id: ASSET_ID,
+            symbol: b"NEW".to_vec(),
+            decimals: 18,
+        });
+    }: _<T::RuntimeOrigin>(origin, GATEWAY_ID, ASSET_ID, token_props.clone())
+    verify {
+        assert_eq!(
+            Tokens::<T>::get(ASSET_ID, GATEWAY_ID).map(|token| token.token_props),
+            Some(token_props)
+        );
+    }
+
+    quote_xtx_cost {
+        let n in 1 .. 100;
+        let caller: T::AccountId = whitelisted_caller();
+        register_token_on_gateway::<T>(ASSET_ID, GATEWAY_ID);
+        let side_effects = (0..n)
+            .map(|_| (GATEWAY_ID, ASSET_ID, BalanceOf::<T>::from(100u32)))
+            .collect::<Vec<_>>();
+    }: _(RawOrigin::Signed(caller), side_effects, ASSET_ID)
+
+    submit_fee_hints {
+        let n in 1 .. crate::fee_oracle::MAX_FEE_HINTS_PER_SUBMISSION;
+        register_gateway::<T>(GATEWAY_ID);
+        let public = T::Public::decode(&mut TrailingZeroInput::zeroes())
+            .map_err(|_| BenchmarkError::Stop("Public should decode from zeroes"))?;
+        let signature = T::Signature::decode(&mut TrailingZeroInput::zeroes())
+            .map_err(|_| BenchmarkError::Stop("Signature should decode from zeroes"))?;
+
+        // Every other oracle has a hint on each side effect left to aggregate with
+        let other_oracles: Vec<T::AccountId> = (1..crate::fee_oracle::MAX_FEE_ORACLES)
+            .map(|i| account("oracle", i, USER_SEED))
+            .collect();
+        let mut oracles = other_oracles.clone();
+        oracles.push(public.clone().into_account());
+        FeeOracles::<T>::put(
+            BoundedVec::try_from(oracles)
+                .map_err(|_| BenchmarkError::Stop("FeeOracles is full"))?,
+        );
+        let now = frame_system::Pallet::<T>::block_number();
+        let hints = (0..n)
+            .map(|i| (GATEWAY_ID, i.to_le_bytes(), fee_hint::<T>()))
+            .collect::<Vec<_>>();
+        for (gateway_id, sfx_4b_id, _) in hints.iter() {
+            let other_hints = other_oracles
+                .iter()
+                .map(|oracle| (oracle.clone(), fee_hint::<T>(), now))
+                .collect::<Vec<_>>();
+            OracleFeeHints::<T>::insert(
+                gateway_id,
+                sfx_4b_id,
+                BoundedVec::try_from(other_hints)
+                    .map_err(|_| BenchmarkError::Stop("OracleFeeHints is full"))?,
+            );
+        }
+        let payload = crate::fee_oracle::FeeHintsPayload {
+            block_number: now,
+            hints,
+            public,
+        };
+    }: _(RawOrigin::None, payload, signature)
+    verify {
+        assert!(FeeHints::<T>::contains_key(GATEWAY_ID, 0u32.to_le_bytes()));
+    }
+
+    set_fee_oracles {
+        let origin = T::UpdateOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+        let oracles: Vec<T::AccountId> = (0..crate::fee_oracle::MAX_FEE_ORACLES)
+            .map(|i| account("oracle", i, USER_SEED))
+            .collect();
+    }: _<T::RuntimeOrigin>(origin, oracles.clone())
+    verify {
+        assert_eq!(FeeOracles::<T>::get().into_inner(), oracles);
+    }
+}
@@ -0,0 +1,345 @@
+//! Fee oracle of XDNS, suggesting requesters the max_fee that attracts bids on their side effects.
+//!
+//! The circuit notes the winning bid of each side effect as its bidding closes. Every
+//! `FeeOracleInterval` blocks, the offchain worker of nodes holding `KEY_TYPE` keys of registered
+//! `FeeOracles` takes the median of the recent winning bids on each (gateway, side effect), scales
+//! it by how far the target's gas price moved off its median and submits the estimates as an
+//! unsigned transaction, signed by the oracle's key. Each submission carries up to
+//! `MAX_FEE_HINTS_PER_SUBMISSION` side effects, the next one resuming after the last hinted.
+//! Side effects are then suggested the median of the hints of all registered oracles.
+//!
+//! Gas prices are sampled over JSON-RPC `eth_gasPrice` from the endpoint the node operator sets
+//! per gateway in the offchain local storage, under `GAS_PRICE_ENDPOINT_PREFIX ++ gateway_id`.
+//! Gateways without an endpoint are hinted by their winning bids alone.
+
+use crate::{pallet::*, BalanceOf};
+use codec::{Decode, Encode};
+use frame_support::{pallet_prelude::*, traits::Get};
+use frame_system::{
+    offchain::{SendUnsignedTransaction, SignedPayload, Signer},
+    pallet_prelude::BlockNumberFor,
+};
+use sp_runtime::{
+    offchain::{http, Duration, StorageKind},
+    traits::{IdentifyAccount, Saturating, Zero},
+    KeyTypeId, SaturatedConversion,
+};
+use sp_std::{
+    collections::{btree_map::BTreeMap, btree_set::BTreeSet},
+    prelude::*,
+};
+use t3rn_primitives::xdns::FeeHint;
+use t3rn_types::{fsx::TargetId, sfx::Sfx4bId};
+
+/// Key type of the accounts fee oracles sign their fee hints with.
+/// The keys can be inserted manually via RPC (see `author_insertKey`).
+pub const KEY_TYPE: KeyTypeId = KeyTypeId(*b"xfee");
+
+/// Winning bids and gas prices sampled per gateway at most
+pub const MAX_FEE_SAMPLES: u32 = 32;
+
+/// Fee hints carried by a single submission at most
+pub const MAX_FEE_HINTS_PER_SUBMISSION: u32 = 64;
+
+pub const MAX_FEE_ORACLES: u32 = 16;
+
+/// Fee hints not refreshed for this many oracle intervals are no longer suggested
+pub const FEE_HINT_TTL_INTERVALS: u32 = 10;
+
+/// Prefix of the offchain local storage keys holding the gas price endpoint of each gateway
+pub const GAS_PRICE_ENDPOINT_PREFIX: &[u8] = b"xdns::fee-oracle::gas-price-endpoint::";
+
+const GAS_PRICE_SAMPLES_PREFIX: &[u8] = b"xdns::fee-oracle::gas-price-samples::";
+
+/// Offchain local storage key of the raw key of the side effect the last submission ended at
+const FEE_HINTS_CURSOR_KEY: &[u8] = b"xdns::fee-oracle::fee-hints-cursor";
+
+const GAS_PRICE_REQUEST: &[u8] = br#"{"jsonrpc":"2.0","id":1,"method":"eth_gasPrice","params":[]}"#;
+
+const HTTP_DEADLINE_MS: u64 = 3_000;
+
+const FEE_HINTS_PRIORITY: TransactionPriority = TransactionPriority::MAX / 2;
+
+pub mod crypto {
+    use super::KEY_TYPE;
+    use sp_core::sr25519::{Public as Sr25519Public, Signature as Sr25519Signature};
+    use sp_runtime::{
+        app_crypto::{app_crypto, sr25519},
+        MultiSignature, MultiSigner,
+    };
+
+    app_crypto!(sr25519, KEY_TYPE);
+
+    /// Identity fee oracles sign their fee hints with
+    pub struct FeeOracleAuthId;
+
+    impl frame_system::offchain::AppCrypto<MultiSigner, MultiSignature> for FeeOracleAuthId {
+        type GenericPublic = Sr25519Public;
+        type GenericSignature = Sr25519Signature;
+        type RuntimeAppPublic = Public;
+    }
+}
+
+/// Fee hints estimated by an oracle as of the block its offchain worker ran at.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct FeeHintsPayload<Public, BlockNumber, Balance> {
+    pub block_number: BlockNumber,
+    pub hints: Vec<(TargetId, Sfx4bId, FeeHint<Balance>)>,
+    pub public: Public,
+}
+
+impl<T: Config> SignedPayload<T> for FeeHintsPayload<T::Public, BlockNumberFor<T>, BalanceOf<T>> {
+    fn public(&self) -> T::Public {
+        self.public.clone()
+    }
+}
+
+/// Lower median of the samples, so that it's always one of them.
+pub fn median<N: Ord + Copy>(mut samples: Vec<N>) -> Option<N> {
+    if samples.is_empty() {
+        return None
+    }
+    samples.sort_unstable();
+    Some(samples[(samples.len() - 1) / 2])
+}
+
+/// Reads the quantity a JSON-RPC response carries as its hex encoded result.
+pub fn parse_json_rpc_quantity(response: &[u8]) -> Option<u128> {
+    let response = sp_std::str::from_utf8(response).ok()?;
+    let (_, result) = response.split_once("\"result\"")?;
+    let (_, quantity) = result.split_once("\"0x")?;
+    let (quantity, _) = quantity.split_once('"')?;
+    u128::from_str_radix(quantity, 16).ok()
+}
+
+impl<T: Config> Pallet<T> {
+    pub fn run_fee_oracle(n: BlockNumberFor<T>) {
+        let interval = T::FeeOracleInterval::get();
+        if interval.is_zero() || !(n % interval).is_zero() {
+            return
+        }
+        // Nodes not holding oracle keys don't sample at all
+        let signer = Signer::<T, T::FeeOracleAuthorityId>::any_account();
+        if !signer.can_sign() {
+            return
+        }
+
+        let hints = Self::estimate_fee_hints();
+        if hints.is_empty() {
+            return
+        }
+
+        match signer.send_unsigned_transaction(
+            |account| FeeHintsPayload {
+                block_number: n,
+                hints: hints.clone(),
+                public: account.public.clone(),
+            },
+            |payload, signature| Call::submit_fee_hints { payload, signature },
+        ) {
+            Some((_, Ok(()))) => log::debug!(
+                "XDNS -- fee oracle: submitted {} fee hints at {:?}",
+                hints.len(),
+                n
+            ),
+            Some((_, Err(()))) =>
+                log::warn!("XDNS -- fee oracle: failed to submit fee hints at {:?}", n),
+            None => {},
+        }
+    }
+
+    /// Estimates the fee hints of the side effects with recent winning bids, as of the latest
+    /// gas prices sampled on their targets. Takes up to `MAX_FEE_HINTS_PER_SUBMISSION` of them,
+    /// resuming after the one the previous estimate ended at and wrapping around to the first.
+    pub fn estimate_fee_hints() -> Vec<(TargetId, Sfx4bId, FeeHint<BalanceOf<T>>)> {
+        let max_hints = MAX_FEE_HINTS_PER_SUBMISSION as usize;
+        let cursor =
+            sp_io::offchain::local_storage_get(StorageKind::PERSISTENT, FEE_HINTS_CURSOR_KEY);
+        let mut winning_bids = match cursor.clone() {
+            Some(cursor) => <RecentWinningBids<T>>::iter_from(cursor),
+            None => <RecentWinningBids<T>>::iter(),
+        }
+        .take(max_hints)
+        .collect::<Vec<_>>();
+        if cursor.is_some() && winning_bids.len() < max_hints {
+            let resumed = winning_bids
+                .iter()
+                .map(|(gateway_id, sfx_4b_id, _)| (*gateway_id, *sfx_4b_id))
+                .collect::<BTreeSet<_>>();
+            let wrapped = <RecentWinningBids<T>>::iter()
+                .take_while(|(gateway_id, sfx_4b_id, _)| {
+                    !resumed.contains(&(*gateway_id, *sfx_4b_id))
+                })
+                .take(max_hints - winning_bids.len())
+                .collect::<Vec<_>>();
+            winning_bids.extend(wrapped);
+        }
+        match winning_bids.last() {
+            Some((gateway_id, sfx_4b_id, _)) if winning_bids.len() == max_hints =>
+                sp_io::offchain::local_storage_set(
+                    StorageKind::PERSISTENT,
+                    FEE_HINTS_CURSOR_KEY,
+                    &<RecentWinningBids<T>>::hashed_key_for(gateway_id, sfx_4b_id),
+                ),
+            _ =>
+                sp_io::offchain::local_storage_clear(StorageKind::PERSISTENT, FEE_HINTS_CURSOR_KEY),
+        }
+
+        let mut gas_prices: BTreeMap<TargetId, (u128, u128)> = BTreeMap::new();
+        winning_bids
+            .into_iter()
+            .filter_map(|(gateway_id, sfx_4b_id, bids)| {
+                let bids_median = median(bids.into_inner())?;
+                let (gas_price, gas_price_median) = *gas_prices
+                    .entry(gateway_id)
+                    .or_insert_with(|| Self::sample_gas_price(gateway_id));
+                Some((
+                    gateway_id,
+                    sfx_4b_id,
+                    FeeHint::new(bids_median, gas_price, gas_price_median),
+                ))
+            })
+            .collect()
+    }
+
+    /// Samples the gas price of the gateway from its endpoint, returning the latest sample along
+    /// with the median of the recent ones. Zeroes if the gateway has no endpoint set.
+    pub fn sample_gas_price(gateway_id: TargetId) -> (u128, u128) {
+        let endpoint_key = [GAS_PRICE_ENDPOINT_PREFIX, &gateway_id[..]].concat();
+        let Some(endpoint) =
+            sp_io::offchain::local_storage_get(StorageKind::PERSISTENT, &endpoint_key)
+        else {
+            return (0, 0)
+        };
+
+        let samples_key = [GAS_PRICE_SAMPLES_PREFIX, &gateway_id[..]].concat();
+        let mut samples: Vec<u128> =
+            sp_io::offchain::local_storage_get(StorageKind::PERSISTENT, &samples_key)
+                .and_then(|encoded| Decode::decode(&mut &encoded[..]).ok())
+                .unwrap_or_default();
+
+        match Self::fetch_gas_price(&endpoint) {
+            Ok(gas_price) => {
+                if samples.len() >= MAX_FEE_SAMPLES as usize {
+                    samples.remove(0);
+                }
+                samples.push(gas_price);
+                sp_io::offchain::local_storage_set(
+                    StorageKind::PERSISTENT,
+                    &samples_key,
+                    &samples.encode(),
+                );
+            },
+            Err(e) => log::warn!(
+                "XDNS -- fee oracle: failed to sample the gas price of {:?}: {:?}",
+                gateway_id,
+                e
+            ),
+        }
+
+        let gas_price = samples.last().copied().unwrap_or_default();
+        (gas_price, median(samples).unwrap_or_default())
+    }
+
+    fn fetch_gas_price(endpoint: &[u8]) -> Result<u128, http::Error> {
+        let url = sp_std::str::from_utf8(endpoint).map_err(|_| http::Error::Unknown)?;
+        let deadline = sp_io::offchain::timestamp().add(Duration::from_millis(HTTP_DEADLINE_MS));
+        let pending = http::Request::post(url, vec![GAS_PRICE_REQUEST])
+            .add_header("Content-Type", "application/json")
+            .deadline(deadline)
+            .send()
+            .map_err(|_| http::Error::IoError)?;
+        let response = pending
+            .try_wait(deadline)
+            .map_err(|_| http::Error::DeadlineReached)??;
+        if response.code != 200 {
+            return Err(http::Error::Unknown)
+        }
+        parse_json_rpc_quantity(&response.body().collect::<Vec<u8>>()).ok_or(http::Error::Unknown)
+    }
+
+    /// Fee hints are valid if signed by a registered oracle as of a block within the last interval.
+    pub fn validate_fee_hints(
+        payload: &FeeHintsPayload<T::Public, BlockNumberFor<T>, BalanceOf<T>>,
+        signature: &T::Signature,
+    ) -> TransactionValidity {
+        if payload.hints.len() > MAX_FEE_HINTS_PER_SUBMISSION as usize {
+            return InvalidTransaction::ExhaustsResources.into()
+        }
+        if !<FeeOracles<T>>::get().contains(&payload.public.clone().into_account()) {
+            return InvalidTransaction::BadSigner.into()
+        }
+        if !SignedPayload::<T>::verify::<T::FeeOracleAuthorityId>(payload, signature.clone()) {
+            return InvalidTransaction::BadProof.into()
+        }
+
+        let now = frame_system::Pallet::<T>::block_number();
+        let interval = T::FeeOracleInterval::get();
+        if payload.block_number > now {
+            return InvalidTransaction::Future.into()
+        }
+        if now.saturating_sub(payload.block_number) > interval {
+            return InvalidTransaction::Stale.into()
+        }
+
+        ValidTransaction::with_tag_prefix("XdnsFeeHints")
+            .priority(FEE_HINTS_PRIORITY)
+            .and_provides((payload.public.clone(), payload.block_number))
+            .longevity(interval.saturated_into::<u64>())
+            .propagate(true)
+            .build()
+    }
+
+    /// Samples the winning bid, dropping the oldest one once the samples are full.
+    pub fn note_winning_bid(gateway_id: &TargetId, sfx_4b_id: &Sfx4bId, bid: BalanceOf<T>) {
+        if !<Gateways<T>>::contains_key(gateway_id) {
+            return
+        }
+        <RecentWinningBids<T>>::mutate(gateway_id, sfx_4b_id, |bids| {
+            if bids.len() >= MAX_FEE_SAMPLES as usize {
+                bids.remove(0);
+            }
+            let _ = bids.try_push(bid);
+        });
+    }
+
+    /// Notes the fee hint of the oracle on the side effect, returning the hint of the lower median
+    /// max_fee among the hints the registered oracles submitted on it within the TTL.
+    pub fn aggregate_fee_hint(
+        public: &T::Public,
+        gateway_id: &TargetId,
+        sfx_4b_id: &Sfx4bId,
+        hint: FeeHint<BalanceOf<T>>,
+    ) -> Option<FeeHint<BalanceOf<T>>> {
+        let oracle = public.clone().into_account();
+        let oracles = <FeeOracles<T>>::get();
+        let now = frame_system::Pallet::<T>::block_number();
+        let ttl = T::FeeOracleInterval::get().saturating_mul(FEE_HINT_TTL_INTERVALS.into());
+        <OracleFeeHints<T>>::mutate(gateway_id, sfx_4b_id, |hints| {
+            hints.retain(|(submitter, _, submitted_at)| {
+                *submitter != oracle
+                    && oracles.contains(submitter)
+                    && now.saturating_sub(*submitted_at) <= ttl
+            });
+            // Fits, as the hints left are of the other registered oracles
+            let _ = hints.try_push((oracle, hint, now));
+            let max_fee = median(hints.iter().map(|(_, hint, _)| hint.max_fee).collect())?;
+            hints
+                .iter()
+                .find(|(_, hint, _)| hint.max_fee == max_fee)
+                .map(|(_, hint, _)| hint.clone())
+        })
+    }
+
+    pub fn recent_fee_hint(
+        gateway_id: &TargetId,
+        sfx_4b_id: &Sfx4bId,
+    ) -> Option<FeeHint<BalanceOf<T>>> {
+        let (hint, submitted_at) = <FeeHints<T>>::get(gateway_id, sfx_4b_id)?;
+        let ttl = T::FeeOracleInterval::get().saturating_mul(FEE_HINT_TTL_INTERVALS.into());
+        if frame_system::Pallet::<T>::block_number().saturating_sub(submitted_at) > ttl {
+            return None
+        }
+        Some(hint)
+    }
+}
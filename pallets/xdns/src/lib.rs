@@ -29,6 +29,8 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+pub mod fee_oracle;
+pub mod migrations;
 pub mod weights;
 
 use weights::WeightInfo;
@@ -46,33 +48,51 @@ pub mod pallet {
         pallet_prelude::*,
         traits::{
             fungible::{Inspect, Mutate},
-            Currency, Time,
+            Currency, OnRuntimeUpgrade, ReservableCurrency, Time,
         },
     };
     use frame_system::pallet_prelude::*;
     use sp_core::H256;
+    #[cfg(feature = "try-runtime")]
+    use sp_runtime::TryRuntimeError;
     use sp_runtime::{traits::CheckedDiv, SaturatedConversion};
     use sp_std::convert::TryInto;
-    use t3rn_abi::{sfx_abi::SFXAbi, Codec};
+    use t3rn_abi::{sfx_abi::SFXAbi, standard::StandardSfxAbis, Codec};
     use t3rn_primitives::{
         attesters::AttestersReadApi,
         circuit::{AdaptiveTimeout, CircuitDLQ},
         light_client::{LightClientAsyncAPI, LightClientHeartbeat},
         portal::Portal,
         xdns::{
-            EpochEstimate, FullGatewayRecord, GatewayRecord, PalletAssetsOverlay, TokenRecord, Xdns,
+            AbiDescriptor, AllowedSideEffects, BondScalingFactors, EpochEstimate, FeeHint,
+            FullGatewayRecord, GatewayAbiDescriptors, GatewayActivityStats, GatewayAssets,
+            GatewayFeeSchedule, GatewayIdReservation, GatewayRecord, GatewayRecordChanges,
+            PalletAssetsOverlay, SfxSchema, TokenRecord, Xdns, XtxCostEstimate,
+            ESTIMATED_SFX_ARG_SIZE, GATEWAY_RECORD_VERSION, MAX_ASSETS_PER_GATEWAY,
         },
-        Bytes, ChainId, ExecutionVendor, FinalityVerifierActivity, GatewayActivity, GatewayVendor,
-        SpeedMode, TokenInfo, TreasuryAccount, TreasuryAccountProvider, XDNSTopology,
+        Bytes, ChainId, ExecutionVendor, FinalityVerifierActivity, GatewayActivity,
+        GatewaySecurityTier, GatewayVendor, SpeedMode, TokenInfo, TreasuryAccount,
+        TreasuryAccountProvider, XDNSTopology,
     };
     use t3rn_types::{fsx::TargetId, sfx::Sfx4bId};
 
     use t3rn_types::sfx::SecurityLvl;
 
     pub const MAX_GATEWAY_OVERVIEW_RECORDS: u32 = 1000;
+    pub const MAX_GATEWAYS: u32 = 256;
+    pub const MAX_TOKENS: u32 = 1024;
+    pub const MAX_TOKENS_PER_GATEWAY: u32 = MAX_ASSETS_PER_GATEWAY;
+    pub const MAX_ASSET_CORRESPONDENCES_PER_GATEWAY: u32 = MAX_ASSETS_PER_GATEWAY;
+    pub const MAX_AUTHORIZED_MINT_ASSETS: u32 = 1024;
+    /// Number of blocks after which the gateway activity stats are halved; ~ 1 day at 6s blocks
+    pub const ACTIVITY_STATS_WINDOW: u32 = 14_400;
 
     #[pallet::config]
-    pub trait Config: frame_system::Config {
+    pub trait Config:
+        frame_system::Config
+        + frame_system::offchain::SendTransactionTypes<Call<Self>>
+        + frame_system::offchain::SigningTypes
+    {
         /// The overarching event type.
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
@@ -82,7 +102,10 @@ pub mod pallet {
         /// A type that provides inspection and mutation to some fungible assets
         type Balances: Inspect<Self::AccountId> + Mutate<Self::AccountId>;
 
-        type Currency: Currency<Self::AccountId>;
+        type Currency: ReservableCurrency<Self::AccountId>;
+
+        /// Deposit reserved from an account reserving a gateway id
+        type GatewayIdReservationDeposit: Get<BalanceOf<Self>>;
 
         type AssetsOverlay: PalletAssetsOverlay<Self, BalanceOf<Self>>;
 
@@ -99,6 +122,21 @@ pub mod pallet {
         type SelfGatewayId: Get<ChainId>;
 
         type Time: Time;
+
+        /// Origin allowed to register new gateways, tokens and bridging assets
+        type RegistrationOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Origin allowed to update already registered XDNS records
+        type UpdateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Origin allowed to purge XDNS records
+        type PurgeOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Identity fee oracles sign their fee hints with
+        type FeeOracleAuthorityId: frame_system::offchain::AppCrypto<Self::Public, Self::Signature>;
+
+        /// Number of blocks between the fee hints submitted by each oracle
+        type FeeOracleInterval: Get<BlockNumberFor<Self>>;
     }
 
     // Simple declaration of the `Pallet` type. It is placeholder we use to implement traits and
@@ -128,86 +166,111 @@ pub mod pallet {
         // A runtime code run after every block and have access to extended set of APIs.
         //
         // For instance you can generate extrinsics for the upcoming produced block.
-        fn offchain_worker(_n: frame_system::pallet_prelude::BlockNumberFor<T>) {
-            // We don't do anything here.
-            // but we could dispatch extrinsic (transaction/unsigned/inherent) using
-            // sp_io::submit_extrinsic.
-            // To see example on offchain worker, please refer to example-offchain-worker pallet
-            // accompanied in this repository.
+        fn offchain_worker(n: frame_system::pallet_prelude::BlockNumberFor<T>) {
+            Self::run_fee_oracle(n);
         }
 
         fn on_runtime_upgrade() -> Weight {
-            // Define the maximum weight of this migration.
-            let max_weight = T::DbWeight::get().reads_writes(10, 10);
-            // Define the current storage migration version.
-            const CURRENT_STORAGE_VERSION: u32 = 2;
-            // Migrate the storage entries.
-            StorageMigrations::<T>::try_mutate(|current_version| {
-                match *current_version {
-                    0 => {
-                        // Storage Migration: StandardSideEffects -> StandardSFXABIs
-                        // Storage Migration Details: 16-03-2023; v1.4.0-rc -> v1.5.0-rc
-                        // Iterate through the old storage entries and migrate them.
-                        for (key, _value) in StandardSideEffects::<T>::drain() {
-                            let sfx4b_id = key;
-                            match SFXAbi::get_standard_interface(sfx4b_id) {
-                                Some(sfx_abi) => {
-                                    StandardSFXABIs::<T>::insert(sfx4b_id, sfx_abi);
-                                }
-                                None => {
-                                    log::error!(
-                                "Failed to migrate StandardSideEffects to StandardSFXABIs for sfx4b_id: {:?}",
-                                sfx4b_id
-                            );
-                                }
-                            }
-                        }
+            crate::migrations::Migrations::<T>::on_runtime_upgrade()
+        }
 
-                        // Set migrations_done to true
-                        *current_version = CURRENT_STORAGE_VERSION;
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            crate::migrations::Migrations::<T>::pre_upgrade()
+        }
 
-                        // Return the weight consumed by the migration.
-                        Ok::<Weight, DispatchError>(max_weight)
-                    }
-                    // Storage Migration: Raw XDNS storage entry kill
-                    // Storage Migration Details: 27-07-2023; v1.4.43-rc -> v1.4.44-rc
-                    //     Many Collators on t0rn hit: frame_support::storage: (key, value) failed to decode at [225, 205, 72, 162, 242, 43, 101, 142, 192, 157, 178, 168, 200, 143, 21, 13, 175, 239, 182, 147, 135, 79, 226, 105, 210, 52, 22, 179, 228, 93, 185, 249, 114, 111, 99, 111]
-                    1 => {
-                        // Manually kill the old XDNS storage entry (XDNSRegistry is now replaced by Gateways)
-                        frame_support::storage::unhashed::kill(&[225, 205, 72, 162, 242, 43, 101, 142, 192, 157, 178, 168, 200, 143, 21, 13, 175, 239, 182, 147, 135, 79, 226, 105, 210, 52, 22, 179, 228, 93, 185, 249, 114, 111, 99, 111]);
-                        // Set migrations_done to true
-                        *current_version = CURRENT_STORAGE_VERSION;
-                        // Return the weight consumed by the migration.
-                        Ok::<Weight, DispatchError>(T::DbWeight::get().writes(1))
-                    }
-                    // Storage Migration: Another Raw XDNS storage entry kill
-                    // Storage Migration Details: 27-07-2023; v1.4.44-rc -> v1.4.45-rc
-                    //     Many Collators on t0rn hit: frame_support::storage: (key, value) failed to decode at [84, 10, 79, 135, 84, 170, 82, 152, 163, 214, 233, 170, 9, 233, 63, 151, 78, 11,
-                    //      18, 119, 80, 58, 19, 112, 111, 133, 165, 20, 116, 96, 124, 88, 24, 172, 250, 191, 195, 140, 91, 41, 106, 32, 177, 28, 37, 248, 177, 35, 27, 230, 169, 204, 8, 192, 121, 163, 226, 24, 100, 166, 207, 36, 66, 173, 219, 150, 184, 250, 101, 171, 135, 85,]
-                    2 => {
-                        // Manually kill the old XDNS storage entry (XDNSRegistry is now replaced by Gateways)
-                        frame_support::storage::unhashed::kill(&[84, 10, 79, 135, 84, 170, 82, 152, 163, 214, 233, 170, 9, 233, 63, 151, 78, 11,
-                            18, 119, 80, 58, 19, 112, 111, 133, 165, 20, 116, 96, 124, 88, 24, 172, 250,
-                            191, 195, 140, 91, 41, 106, 32, 177, 28, 37, 248, 177, 35, 27, 230, 169, 204,
-                            8, 192, 121, 163, 226, 24, 100, 166, 207, 36, 66, 173, 219, 150, 184, 250, 101,
-                            171, 135, 85,]);
-                        // Set migrations_done to true
-                        *current_version = CURRENT_STORAGE_VERSION;
-                        // Return the weight consumed by the migration.
-                        Ok::<Weight, DispatchError>(T::DbWeight::get().writes(1))
-                    }
-                    // Add more migration cases here, if needed in the future
-                    _ => {
-                        // No migration needed.
-                        Ok::<Weight, DispatchError>(Default::default())
-                    }
-                }
-            })
-                .unwrap_or_default()
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            crate::migrations::Migrations::<T>::post_upgrade(state)?;
+            frame_support::ensure!(
+                StorageMigrations::<T>::get() == crate::migrations::CURRENT_STORAGE_VERSION,
+                "XDNS storage left behind the current version"
+            );
+            Self::ensure_registry_decodes()
         }
     }
 
     impl<T: Config> Pallet<T> {
+        /// Re-encodes the unbounded registry lists into their bounded equivalents.
+        /// Encoding of Vec and BoundedVec is identical, so entries are only truncated if they exceed the bound.
+        /// Gateway records are bounded at the layout they're stored at on live networks, ahead of their tiered layout.
+        pub fn migrate_to_bounded_storage() -> Weight {
+            fn bound<Item, const N: u32>(
+                entry: &str,
+                items: Vec<Item>,
+            ) -> BoundedVec<Item, ConstU32<N>> {
+                if items.len() > N as usize {
+                    log::warn!(
+                        "XDNS -- migrate_to_bounded_storage: truncating {} from {} to {} items",
+                        entry,
+                        items.len(),
+                        N
+                    );
+                }
+                BoundedVec::truncate_from(items)
+            }
+
+            let mut translated: u64 = 0;
+
+            let _ = <AllGatewayIds<T>>::translate::<Vec<TargetId>, _>(|maybe_ids| {
+                translated += 1;
+                maybe_ids.map(|ids| bound("AllGatewayIds", ids))
+            });
+            let _ = <AllTokenIds<T>>::translate::<Vec<AssetId>, _>(|maybe_ids| {
+                translated += 1;
+                maybe_ids.map(|ids| bound("AllTokenIds", ids))
+            });
+            let _ = <AuthorizedMintAssets<T>>::translate::<Vec<(AssetId, TargetId)>, _>(
+                |maybe_assets| {
+                    translated += 1;
+                    maybe_assets.map(|assets| bound("AuthorizedMintAssets", assets))
+                },
+            );
+            <GatewayTokens<T>>::translate::<Vec<AssetId>, _>(|_gateway_id, token_ids| {
+                translated += 1;
+                Some(bound("GatewayTokens", token_ids))
+            });
+            crate::migrations::v0::Gateways::<T>::translate::<
+                crate::migrations::GatewayRecordV0<T::AccountId, Vec<(Sfx4bId, Option<u8>)>>,
+                _,
+            >(|_gateway_id, record| {
+                translated += 1;
+                Some(record.map_allowed_side_effects(|allowed_side_effects| {
+                    bound("Gateways", allowed_side_effects)
+                }))
+            });
+
+            T::DbWeight::get().reads_writes(translated, translated)
+        }
+
+        /// Check the registry lists, gateway tokens and records decode as the current, bounded, types.
+        #[cfg(feature = "try-runtime")]
+        pub fn ensure_registry_decodes() -> Result<(), TryRuntimeError> {
+            frame_support::ensure!(
+                !<AllGatewayIds<T>>::exists() || <AllGatewayIds<T>>::try_get().is_ok(),
+                "AllGatewayIds undecodable"
+            );
+            frame_support::ensure!(
+                !<AllTokenIds<T>>::exists() || <AllTokenIds<T>>::try_get().is_ok(),
+                "AllTokenIds undecodable"
+            );
+            frame_support::ensure!(
+                !<AuthorizedMintAssets<T>>::exists()
+                    || <AuthorizedMintAssets<T>>::try_get().is_ok(),
+                "AuthorizedMintAssets undecodable"
+            );
+            frame_support::ensure!(
+                <GatewayTokens<T>>::iter_keys().count()
+                    == <GatewayTokens<T>>::iter_values().count(),
+                "GatewayTokens undecodable"
+            );
+            frame_support::ensure!(
+                <Gateways<T>>::iter_keys().count() == <Gateways<T>>::iter_values().count(),
+                "Gateways undecodable"
+            );
+            Ok(())
+        }
+
         pub fn check_for_manual_verifier_overview_process(
             n: frame_system::pallet_prelude::BlockNumberFor<T>,
         ) -> Weight {
@@ -432,6 +495,17 @@ pub mod pallet {
 
                 // Add the new activity to the historic overview of the gateway
                 let mut historic_overview = GatewaysOverviewStoreHistory::<T>::get(gateway_id);
+                if let Some(last_activity) = historic_overview.last() {
+                    let headers_accepted: u64 = finalized_height
+                        .saturating_sub(last_activity.finalized_height)
+                        .saturated_into();
+                    if headers_accepted > 0 {
+                        GatewayActivityStatsStore::<T>::mutate(gateway_id, |stats| {
+                            stats.headers_accepted =
+                                stats.headers_accepted.saturating_add(headers_accepted)
+                        });
+                    }
+                }
                 if historic_overview.len() == MAX_GATEWAY_OVERVIEW_RECORDS as usize {
                     let _ = historic_overview.remove(0);
                 }
@@ -444,6 +518,30 @@ pub mod pallet {
 
             // Update the general overview
             GatewaysOverviewStore::<T>::put(all_overviews);
+
+            Self::roll_activity_stats_window(n);
+        }
+
+        /// Halves the activity stats of all gateways once the stats window has elapsed.
+        pub fn roll_activity_stats_window(n: frame_system::pallet_prelude::BlockNumberFor<T>) {
+            if n.saturating_sub(ActivityStatsWindowStart::<T>::get()) < ACTIVITY_STATS_WINDOW.into()
+            {
+                return
+            }
+            GatewayActivityStatsStore::<T>::translate_values(|mut stats: GatewayActivityStats| {
+                stats.decay();
+                Some(stats)
+            });
+            ActivityStatsWindowStart::<T>::put(n);
+        }
+
+        fn note_gateway_activity(
+            gateway_id: &ChainId,
+            note: impl FnOnce(&mut GatewayActivityStats),
+        ) {
+            if <Gateways<T>>::contains_key(gateway_id) {
+                GatewayActivityStatsStore::<T>::mutate(gateway_id, note);
+            }
         }
 
         // XDNS Topology Zip / Unzip
@@ -482,7 +580,7 @@ pub mod pallet {
                     gateway.gateway_record.codec,
                     gateway.gateway_record.registrant,
                     gateway.gateway_record.escrow_account,
-                    gateway.gateway_record.allowed_side_effects,
+                    gateway.gateway_record.allowed_side_effects.into_inner(),
                 );
                 log::info!("topology unzip -- gateway_id: {:?}", gateway_id);
                 Self::override_gateway(
@@ -494,6 +592,11 @@ pub mod pallet {
                     escrow_account,
                     allowed_side_effects,
                 )?;
+                Self::do_set_gateway_security_tier(
+                    gateway_id,
+                    gateway.gateway_record.security_tier,
+                )?;
+                Self::do_set_gateway_fee_schedule(gateway_id, gateway.gateway_record.fee_schedule)?;
             }
 
             // Update the assets
@@ -518,41 +621,43 @@ pub mod pallet {
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
-        /// Re-adds the self-gateway if was present before. Inserts if wasn't. Root only access.
+        /// Re-adds the self-gateway if was present before. Inserts if wasn't. RegistrationOrigin only access.
         #[pallet::weight(< T as Config >::WeightInfo::reboot_self_gateway())]
         pub fn reboot_self_gateway(
             origin: OriginFor<T>,
             vendor: GatewayVendor,
         ) -> DispatchResultWithPostInfo {
+            T::RegistrationOrigin::ensure_origin(origin.clone())?;
             Self::do_reboot_self_gateway(origin, vendor)?;
 
             Ok(().into())
         }
 
-        /// Re-adds the self-gateway if was present before. Inserts if wasn't. Root only access.
-        #[pallet::weight(< T as Config >::WeightInfo::reboot_self_gateway())]
+        /// Authorizes minting of the asset bridged from the target. RegistrationOrigin only access.
+        #[pallet::weight(< T as Config >::WeightInfo::add_supported_bridging_asset())]
         pub fn add_supported_bridging_asset(
             origin: OriginFor<T>,
             asset_id: AssetId,
             target_id: TargetId,
         ) -> DispatchResultWithPostInfo {
-            ensure_root(origin)?;
+            T::RegistrationOrigin::ensure_origin(origin)?;
 
             if !<AuthorizedMintAssets<T>>::get().contains(&(asset_id, target_id)) {
-                <AuthorizedMintAssets<T>>::append((&asset_id, &target_id));
+                <AuthorizedMintAssets<T>>::try_append((&asset_id, &target_id))
+                    .map_err(|_| Error::<T>::TooManyTokens)?;
             }
 
             Ok(().into())
         }
 
-        #[pallet::weight(< T as Config >::WeightInfo::reboot_self_gateway())]
+        #[pallet::weight(< T as Config >::WeightInfo::enroll_bridge_asset())]
         pub fn enroll_bridge_asset(
             origin: OriginFor<T>,
             asset_id: AssetId,
             target_id: TargetId,
             token_info: TokenInfo,
         ) -> DispatchResultWithPostInfo {
-            ensure_root(origin.clone())?;
+            T::RegistrationOrigin::ensure_origin(origin.clone())?;
 
             assert!(!Self::check_asset_is_mintable(target_id, asset_id));
 
@@ -564,7 +669,8 @@ pub mod pallet {
                 Self::link_token_to_gateway(asset_id, target_id, token_info)?;
             }
 
-            <AuthorizedMintAssets<T>>::append((&asset_id, &target_id));
+            <AuthorizedMintAssets<T>>::try_append((&asset_id, &target_id))
+                .map_err(|_| Error::<T>::TooManyTokens)?;
 
             log::info!(
                 "Enrolled asset {:?} for bridging to {:?}",
@@ -577,7 +683,7 @@ pub mod pallet {
             Ok(().into())
         }
 
-        #[pallet::weight(< T as Config >::WeightInfo::reboot_self_gateway())]
+        #[pallet::weight(< T as Config >::WeightInfo::enroll_new_abi_to_selected_gateway())]
         pub fn enroll_new_abi_to_selected_gateway(
             origin: OriginFor<T>,
             target_id: ChainId,
@@ -585,7 +691,7 @@ pub mod pallet {
             sfx_expected_abi: Option<SFXAbi>,
             maybe_pallet_id: Option<u8>,
         ) -> DispatchResultWithPostInfo {
-            ensure_root(origin.clone())?;
+            T::UpdateOrigin::ensure_origin(origin)?;
 
             if let Some(abi) = sfx_expected_abi {
                 <SFXABIRegistry<T>>::insert(target_id, sfx_4b_id, abi);
@@ -603,13 +709,13 @@ pub mod pallet {
             Ok(().into())
         }
 
-        #[pallet::weight(< T as Config >::WeightInfo::reboot_self_gateway())]
+        #[pallet::weight(< T as Config >::WeightInfo::unroll_abi_of_selected_gateway())]
         pub fn unroll_abi_of_selected_gateway(
             origin: OriginFor<T>,
             target_id: ChainId,
             sfx_4b_id: Sfx4bId,
         ) -> DispatchResultWithPostInfo {
-            ensure_root(origin.clone())?;
+            T::UpdateOrigin::ensure_origin(origin)?;
 
             <SFXABIRegistry<T>>::remove(target_id, sfx_4b_id);
 
@@ -620,36 +726,77 @@ pub mod pallet {
             Ok(().into())
         }
 
-        #[pallet::weight(< T as Config >::WeightInfo::reboot_self_gateway())]
+        #[pallet::weight(< T as Config >::WeightInfo::add_remote_order_address())]
         pub fn add_remote_order_address(
             origin: OriginFor<T>,
             target_id: TargetId,
             remote_address: H256,
         ) -> DispatchResultWithPostInfo {
-            ensure_root(origin.clone())?;
+            T::UpdateOrigin::ensure_origin(origin)?;
             <RemoteOrderAddresses<T>>::insert(target_id, remote_address);
             Ok(().into())
         }
 
-        #[pallet::weight(< T as Config >::WeightInfo::reboot_self_gateway())]
+        #[pallet::weight(< T as Config >::WeightInfo::add_remote_bidding_address())]
         pub fn add_remote_bidding_address(
             origin: OriginFor<T>,
             target_id: TargetId,
             remote_address: H256,
         ) -> DispatchResultWithPostInfo {
-            ensure_root(origin.clone())?;
+            T::UpdateOrigin::ensure_origin(origin)?;
             <RemoteBiddingAddresses<T>>::insert(target_id, remote_address);
             Ok(().into())
         }
 
-        /// Re-adds the self-gateway if was present before. Inserts if wasn't. Root only access.
-        #[pallet::weight(< T as Config >::WeightInfo::reboot_self_gateway())]
+        /// Re-rates the security tier of the gateway, which the circuit enforces against the requested SFX security level. UpdateOrigin only access.
+        #[pallet::weight(< T as Config >::WeightInfo::set_gateway_security_tier())]
+        pub fn set_gateway_security_tier(
+            origin: OriginFor<T>,
+            gateway_id: TargetId,
+            security_tier: GatewaySecurityTier,
+        ) -> DispatchResultWithPostInfo {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            Self::do_set_gateway_security_tier(gateway_id, security_tier)?;
+            Ok(().into())
+        }
+
+        /// Sets the factors scaling the minimum bond of executors bidding on the gateway's side effects. UpdateOrigin only access.
+        #[pallet::weight(< T as Config >::WeightInfo::set_bond_scaling_factors())]
+        pub fn set_bond_scaling_factors(
+            origin: OriginFor<T>,
+            gateway_id: TargetId,
+            factors: BondScalingFactors,
+        ) -> DispatchResultWithPostInfo {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            ensure!(
+                <Gateways<T>>::contains_key(gateway_id),
+                Error::<T>::GatewayRecordNotFound
+            );
+            <BondScaling<T>>::insert(gateway_id, factors.clone());
+            Self::deposit_event(Event::<T>::BondScalingFactorsUpdated(gateway_id, factors));
+            Ok(().into())
+        }
+
+        /// Sets the fees charged on the gateway for executing side effects. UpdateOrigin only access.
+        #[pallet::weight(< T as Config >::WeightInfo::set_gateway_fee_schedule())]
+        pub fn set_gateway_fee_schedule(
+            origin: OriginFor<T>,
+            gateway_id: TargetId,
+            fee_schedule: GatewayFeeSchedule,
+        ) -> DispatchResultWithPostInfo {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            Self::do_set_gateway_fee_schedule(gateway_id, fee_schedule)?;
+            Ok(().into())
+        }
+
+        /// Revokes minting authorization of the asset bridged from the target. PurgeOrigin only access.
+        #[pallet::weight(< T as Config >::WeightInfo::purge_supported_bridging_asset())]
         pub fn purge_supported_bridging_asset(
             origin: OriginFor<T>,
             asset_id: AssetId,
             target_id: TargetId,
         ) -> DispatchResultWithPostInfo {
-            ensure_root(origin)?;
+            T::PurgeOrigin::ensure_origin(origin)?;
 
             if <AuthorizedMintAssets<T>>::get().contains(&(asset_id, target_id)) {
                 <AuthorizedMintAssets<T>>::mutate(|all_token_ids| {
@@ -660,14 +807,19 @@ pub mod pallet {
             Ok(().into())
         }
 
-        /// Removes a gateway from the onchain registry. Root only access.
-        #[pallet::weight(< T as Config >::WeightInfo::purge_gateway())]
+        /// Removes a gateway from the onchain registry. PurgeOrigin only access.
+        /// Weighed for the most tokens and asset correspondences a gateway can have, refunding
+        /// the ones it didn't.
+        #[pallet::weight(< T as Config >::WeightInfo::purge_gateway_record(
+            MAX_ASSETS_PER_GATEWAY,
+            MAX_ASSET_CORRESPONDENCES_PER_GATEWAY,
+        ))]
         pub fn purge_gateway_record(
             origin: OriginFor<T>,
             requester: T::AccountId,
             gateway_id: TargetId,
         ) -> DispatchResultWithPostInfo {
-            ensure_root(origin)?;
+            T::PurgeOrigin::ensure_origin(origin)?;
             if !<Gateways<T>>::contains_key(gateway_id) {
                 Err(Error::<T>::XdnsRecordNotFound.into())
             } else {
@@ -690,6 +842,13 @@ pub mod pallet {
                 });
 
                 <GatewayTokens<T>>::remove(gateway_id);
+                <GatewayActivityStatsStore<T>>::remove(gateway_id);
+                <BondScaling<T>>::remove(gateway_id);
+                let _ = <RecentWinningBids<T>>::clear_prefix(gateway_id, u32::MAX, None);
+                let _ = <OracleFeeHints<T>>::clear_prefix(gateway_id, u32::MAX, None);
+                let _ = <FeeHints<T>>::clear_prefix(gateway_id, u32::MAX, None);
+
+                let correspondences = Self::purge_asset_correspondences_of_gateway(gateway_id);
 
                 <AllGatewayIds<T>>::mutate(|all_gateway_ids| {
                     all_gateway_ids.retain(|&id| id != gateway_id);
@@ -706,55 +865,71 @@ pub mod pallet {
                     latest_heartbeat,
                 );
 
-                Self::deposit_event(Event::<T>::GatewayRecordPurged(requester, gateway_id));
-                Ok(().into())
+                // governance reservations of well-known ids outlive the gateway record
+                if <GatewayIdReservations<T>>::get(gateway_id)
+                    .map_or(false, |reservation| reservation.owner.is_some())
+                {
+                    Self::release_gateway_id_reservation(gateway_id);
+                }
+
+                Self::deposit_event(Event::<T>::GatewayRecordPurged(gateway_id, requester));
+                Ok(Some(<T as Config>::WeightInfo::purge_gateway_record(
+                    token_ids.len() as u32,
+                    correspondences,
+                ))
+                .into())
             }
         }
 
-        #[pallet::weight(< T as Config >::WeightInfo::purge_gateway())]
+        #[pallet::weight(< T as Config >::WeightInfo::unlink_token())]
         pub fn unlink_token(
             origin: OriginFor<T>,
             gateway_id: TargetId,
             token_id: AssetId,
         ) -> DispatchResultWithPostInfo {
-            ensure_root(origin)?;
+            T::PurgeOrigin::ensure_origin(origin)?;
 
             <Tokens<T>>::remove(token_id, gateway_id);
 
             <GatewayTokens<T>>::mutate(gateway_id, |token_ids| {
                 token_ids.retain(|&x_token_id| x_token_id != token_id);
             });
+            Self::sync_gateway_assets(gateway_id);
 
             Ok(().into())
         }
 
-        #[pallet::weight(< T as Config >::WeightInfo::purge_gateway())]
+        #[pallet::weight(< T as Config >::WeightInfo::link_token())]
         pub fn link_token(
             origin: OriginFor<T>,
             gateway_id: TargetId,
             token_id: AssetId,
             token_props: TokenInfo,
         ) -> DispatchResultWithPostInfo {
-            ensure_root(origin)?;
+            T::RegistrationOrigin::ensure_origin(origin)?;
 
             Self::link_token_to_gateway(token_id, gateway_id, token_props)?;
 
             Ok(().into())
         }
 
-        /// Removes from all of the registered destinations + the onchain registry. Root only access.
-        #[pallet::weight(< T as Config >::WeightInfo::purge_gateway())]
+        /// Removes from all of the registered destinations + the onchain registry.
+        /// PurgeOrigin or the asset admin access.
+        #[pallet::weight(< T as Config >::WeightInfo::purge_token_record())]
         pub fn purge_token_record(
             origin: OriginFor<T>,
             token_id: AssetId,
         ) -> DispatchResultWithPostInfo {
-            // Try destroying assets with associated to sudo origin of Escrow or admin / ownership rights
-            let is_root = ensure_signed_or_root(origin.clone())?.is_none();
-            let origin_admin: T::RuntimeOrigin = match is_root {
-                true => T::RuntimeOrigin::from(frame_system::RawOrigin::Signed(
-                    T::TreasuryAccounts::get_treasury_account(TreasuryAccount::Escrow),
-                )),
-                false => origin,
+            // Try destroying assets with associated to sudo origin of Escrow or admin / ownership rights.
+            // Ownership rights of signed origins are enforced by the assets overlay.
+            let origin_admin: T::RuntimeOrigin = match ensure_signed(origin.clone()) {
+                Ok(_) => origin,
+                Err(_) => {
+                    T::PurgeOrigin::ensure_origin(origin)?;
+                    T::RuntimeOrigin::from(frame_system::RawOrigin::Signed(
+                        T::TreasuryAccounts::get_treasury_account(TreasuryAccount::Escrow),
+                    ))
+                },
             };
 
             // Try destroying assets with
@@ -775,21 +950,145 @@ pub mod pallet {
             Ok(().into())
         }
 
-        #[pallet::weight(< T as Config >::WeightInfo::purge_gateway())]
-        pub fn zip_topology(origin: OriginFor<T>) -> DispatchResult {
+        /// Declares that `asset_id` on `gateway_id` and `counterpart_asset_id` on `counterpart_gateway_id`
+        /// represent the same underlying asset. The correspondence is stored in both directions. RegistrationOrigin only access.
+        #[pallet::weight(< T as Config >::WeightInfo::link_asset_correspondence())]
+        pub fn link_asset_correspondence(
+            origin: OriginFor<T>,
+            gateway_id: TargetId,
+            asset_id: AssetId,
+            counterpart_gateway_id: TargetId,
+            counterpart_asset_id: AssetId,
+        ) -> DispatchResultWithPostInfo {
+            T::RegistrationOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                gateway_id != counterpart_gateway_id,
+                Error::<T>::AssetCorrespondenceOnSameGateway
+            );
+            ensure!(
+                <Gateways<T>>::contains_key(gateway_id)
+                    && <Gateways<T>>::contains_key(counterpart_gateway_id),
+                Error::<T>::GatewayRecordNotFound
+            );
+
+            // Relinking either side drops the reverse entry of its previous counterpart
+            if let Some(previous_counterpart_asset_id) =
+                Self::take_asset_correspondence(gateway_id, asset_id, counterpart_gateway_id)
+            {
+                Self::take_asset_correspondence(
+                    counterpart_gateway_id,
+                    previous_counterpart_asset_id,
+                    gateway_id,
+                );
+            }
+            if let Some(previous_asset_id) = Self::take_asset_correspondence(
+                counterpart_gateway_id,
+                counterpart_asset_id,
+                gateway_id,
+            ) {
+                Self::take_asset_correspondence(
+                    gateway_id,
+                    previous_asset_id,
+                    counterpart_gateway_id,
+                );
+            }
+
+            Self::insert_asset_correspondence(
+                gateway_id,
+                asset_id,
+                counterpart_gateway_id,
+                counterpart_asset_id,
+            )?;
+            Self::insert_asset_correspondence(
+                counterpart_gateway_id,
+                counterpart_asset_id,
+                gateway_id,
+                asset_id,
+            )?;
+
+            Self::deposit_event(Event::<T>::AssetCorrespondenceLinked(
+                gateway_id,
+                asset_id,
+                counterpart_gateway_id,
+                counterpart_asset_id,
+            ));
+
+            Ok(().into())
+        }
+
+        /// Removes the correspondence of `asset_id` on `gateway_id` to its counterpart on `counterpart_gateway_id`
+        /// in both directions. PurgeOrigin only access.
+        #[pallet::weight(< T as Config >::WeightInfo::unlink_asset_correspondence())]
+        pub fn unlink_asset_correspondence(
+            origin: OriginFor<T>,
+            gateway_id: TargetId,
+            asset_id: AssetId,
+            counterpart_gateway_id: TargetId,
+        ) -> DispatchResultWithPostInfo {
+            T::PurgeOrigin::ensure_origin(origin)?;
+
+            let counterpart_asset_id =
+                Self::take_asset_correspondence(gateway_id, asset_id, counterpart_gateway_id)
+                    .ok_or(Error::<T>::AssetCorrespondenceNotFound)?;
+            Self::take_asset_correspondence(
+                counterpart_gateway_id,
+                counterpart_asset_id,
+                gateway_id,
+            );
+
+            Self::deposit_event(Event::<T>::AssetCorrespondenceUnlinked(
+                gateway_id,
+                asset_id,
+                counterpart_gateway_id,
+                counterpart_asset_id,
+            ));
+
+            Ok(().into())
+        }
+
+        /// Emits the topology of all gateways, weighed by the upper bounds declared for the number
+        /// of gateways and of the tokens linked to them. Fails if the registry exceeds either.
+        #[pallet::weight(< T as Config >::WeightInfo::zip_topology(*max_gateways, *max_tokens))]
+        pub fn zip_topology(
+            origin: OriginFor<T>,
+            max_gateways: u32,
+            max_tokens: u32,
+        ) -> DispatchResultWithPostInfo {
             let _ = ensure_signed(origin)?;
+            let gateway_ids = <AllGatewayIds<T>>::get();
+            ensure!(
+                gateway_ids.len() as u32 <= max_gateways,
+                Error::<T>::TopologyExceedsDeclaredBounds
+            );
+            let tokens = gateway_ids
+                .iter()
+                .map(|gateway_id| <GatewayTokens<T>>::decode_len(gateway_id).unwrap_or(0) as u32)
+                .fold(
+                    <AllTokenIds<T>>::decode_len().unwrap_or(0) as u32,
+                    |acc, len| acc.saturating_add(len),
+                );
+            ensure!(
+                tokens <= max_tokens,
+                Error::<T>::TopologyExceedsDeclaredBounds
+            );
+
             let topology = Self::do_zip_topology();
-            Self::deposit_event(Event::<T>::XDNSTopologyZip(topology.clone()));
-            Ok(())
+            Self::deposit_event(Event::<T>::XDNSTopologyZip(topology));
+            Ok(Some(<T as Config>::WeightInfo::zip_topology(
+                gateway_ids.len() as u32,
+                tokens,
+            ))
+            .into())
         }
 
-        #[pallet::weight(< T as Config >::WeightInfo::purge_gateway())]
+        #[pallet::weight(< T as Config >::WeightInfo::unzip_topology())]
         pub fn unzip_topology(
             origin: OriginFor<T>,
             topology_decoded: Option<XDNSTopology<T::AccountId>>,
             topology_encoded: Option<Vec<u8>>,
         ) -> DispatchResult {
-            let _ = ensure_root(origin.clone())?;
+            T::RegistrationOrigin::ensure_origin(origin.clone())?;
             let topology = if let Some(topology) = topology_decoded {
                 topology
             } else if let Some(topology) = topology_encoded {
@@ -804,25 +1103,258 @@ pub mod pallet {
 
             Ok(())
         }
+
+        /// Updates the properties (symbol, decimals) of a token already linked to the gateway,
+        /// e.g. after the remote chain rebrands or re-denominates its asset.
+        /// Gateway registrant or UpdateOrigin access.
+        #[pallet::weight(< T as Config >::WeightInfo::update_token_sys_props())]
+        pub fn update_token_sys_props(
+            origin: OriginFor<T>,
+            gateway_id: TargetId,
+            token_id: AssetId,
+            token_props: TokenInfo,
+        ) -> DispatchResultWithPostInfo {
+            let gateway =
+                <Gateways<T>>::get(gateway_id).ok_or(Error::<T>::GatewayRecordNotFound)?;
+
+            match ensure_signed(origin.clone()) {
+                Ok(who) => ensure!(
+                    gateway.registrant.as_ref() == Some(&who),
+                    Error::<T>::NotGatewayRegistrant
+                ),
+                Err(_) => {
+                    T::UpdateOrigin::ensure_origin(origin)?;
+                },
+            }
+
+            Self::do_update_token_sys_props(gateway_id, token_id, token_props)?;
+
+            Ok(().into())
+        }
+
+        /// Reserves the gateway id for the signer against a refundable deposit.
+        /// Only the holder of the reservation can register a gateway under that id.
+        #[pallet::weight(< T as Config >::WeightInfo::reserve_gateway_id())]
+        pub fn reserve_gateway_id(
+            origin: OriginFor<T>,
+            gateway_id: TargetId,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            ensure!(
+                !<GatewayIdReservations<T>>::contains_key(gateway_id),
+                Error::<T>::GatewayIdAlreadyReserved
+            );
+            ensure!(
+                !<Gateways<T>>::contains_key(gateway_id),
+                Error::<T>::GatewayRecordAlreadyExists
+            );
+
+            let deposit = T::GatewayIdReservationDeposit::get();
+            T::Currency::reserve(&who, deposit)?;
+
+            <GatewayIdReservations<T>>::insert(
+                gateway_id,
+                GatewayIdReservation {
+                    owner: Some(who.clone()),
+                    deposit,
+                },
+            );
+
+            Self::deposit_event(Event::<T>::GatewayIdReserved(gateway_id, Some(who)));
+
+            Ok(().into())
+        }
+
+        /// Reserves the gateway id without a deposit, on behalf of `owner` or of governance if None.
+        /// Refunds the deposit of the reservation being replaced. RegistrationOrigin only access.
+        #[pallet::weight(< T as Config >::WeightInfo::force_reserve_gateway_id())]
+        pub fn force_reserve_gateway_id(
+            origin: OriginFor<T>,
+            gateway_id: TargetId,
+            owner: Option<T::AccountId>,
+        ) -> DispatchResultWithPostInfo {
+            T::RegistrationOrigin::ensure_origin(origin)?;
+
+            Self::release_gateway_id_reservation(gateway_id);
+
+            <GatewayIdReservations<T>>::insert(
+                gateway_id,
+                GatewayIdReservation {
+                    owner: owner.clone(),
+                    deposit: Zero::zero(),
+                },
+            );
+
+            Self::deposit_event(Event::<T>::GatewayIdReserved(gateway_id, owner));
+
+            Ok(().into())
+        }
+
+        /// Releases the reservation of a gateway id which hasn't been registered, refunding the deposit.
+        /// Reservation owner or PurgeOrigin access.
+        #[pallet::weight(< T as Config >::WeightInfo::release_gateway_id())]
+        pub fn release_gateway_id(
+            origin: OriginFor<T>,
+            gateway_id: TargetId,
+        ) -> DispatchResultWithPostInfo {
+            let reservation = <GatewayIdReservations<T>>::get(gateway_id)
+                .ok_or(Error::<T>::GatewayIdReservationNotFound)?;
+
+            match ensure_signed(origin.clone()) {
+                Ok(who) => ensure!(
+                    reservation.owner.as_ref() == Some(&who),
+                    Error::<T>::GatewayIdReservedForAnother
+                ),
+                Err(_) => {
+                    T::PurgeOrigin::ensure_origin(origin)?;
+                },
+            }
+
+            ensure!(
+                !<Gateways<T>>::contains_key(gateway_id),
+                Error::<T>::GatewayIdInUse
+            );
+
+            Self::release_gateway_id_reservation(gateway_id);
+
+            Ok(().into())
+        }
+
+        /// Emits the estimated cost of an Xtx of the given side effects, quoted in `quote_asset`.
+        #[pallet::weight(< T as Config >::WeightInfo::quote_xtx_cost(side_effects.len() as u32))]
+        pub fn quote_xtx_cost(
+            origin: OriginFor<T>,
+            side_effects: Vec<(TargetId, AssetId, BalanceOf<T>)>,
+            quote_asset: AssetId,
+        ) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+
+            let estimate =
+                <Self as Xdns<T, BalanceOf<T>>>::estimate_xtx_cost(&side_effects, quote_asset);
+            Self::deposit_event(Event::<T>::XtxCostEstimated(quote_asset, estimate));
+
+            Ok(().into())
+        }
+
+        /// Stores the fee hints estimated by a fee oracle, suggesting the median of the hints of all
+        /// oracles. Submitted unsigned by the offchain worker of the oracle, with the payload
+        /// signed by its key.
+        #[pallet::weight(< T as Config >::WeightInfo::submit_fee_hints(payload.hints.len() as u32))]
+        pub fn submit_fee_hints(
+            origin: OriginFor<T>,
+            payload: crate::fee_oracle::FeeHintsPayload<T::Public, BlockNumberFor<T>, BalanceOf<T>>,
+            _signature: T::Signature,
+        ) -> DispatchResultWithPostInfo {
+            ensure_none(origin)?;
+
+            let now = <frame_system::Pallet<T>>::block_number();
+            for (gateway_id, sfx_4b_id, hint) in payload.hints {
+                if !<Gateways<T>>::contains_key(gateway_id) {
+                    continue
+                }
+                let Some(hint) =
+                    Self::aggregate_fee_hint(&payload.public, &gateway_id, &sfx_4b_id, hint)
+                else {
+                    continue
+                };
+                let max_fee = hint.max_fee;
+                <FeeHints<T>>::insert(gateway_id, sfx_4b_id, (hint, now));
+                Self::deposit_event(Event::<T>::FeeHintSubmitted(gateway_id, sfx_4b_id, max_fee));
+            }
+
+            Ok(Pays::No.into())
+        }
+
+        /// Sets the accounts allowed to submit fee hints. UpdateOrigin only access.
+        #[pallet::weight(< T as Config >::WeightInfo::set_fee_oracles())]
+        pub fn set_fee_oracles(
+            origin: OriginFor<T>,
+            oracles: Vec<T::AccountId>,
+        ) -> DispatchResultWithPostInfo {
+            T::UpdateOrigin::ensure_origin(origin)?;
+
+            let bounded: BoundedVec<
+                T::AccountId,
+                ConstU32<{ crate::fee_oracle::MAX_FEE_ORACLES }>,
+            > = oracles
+                .clone()
+                .try_into()
+                .map_err(|_| Error::<T>::TooManyFeeOracles)?;
+            <FeeOracles<T>>::put(bounded);
+            Self::deposit_event(Event::<T>::FeeOraclesSet(oracles));
+
+            Ok(().into())
+        }
+    }
+
+    #[pallet::validate_unsigned]
+    impl<T: Config> ValidateUnsigned for Pallet<T> {
+        type Call = Call<T>;
+
+        fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+            match call {
+                Call::submit_fee_hints { payload, signature } =>
+                    Self::validate_fee_hints(payload, signature),
+                _ => InvalidTransaction::Call.into(),
+            }
+        }
     }
 
+    /// Events of a gateway lead with its gateway_4b_id, as laid out by
+    /// t3rn_primitives::EVENTS_VERSION.
     #[pallet::event]
     #[pallet::generate_deposit(pub (super) fn deposit_event)]
     pub enum Event<T: Config> {
         /// \[gateway_4b_id\]
-        GatewayRecordStored(TargetId),
-        /// \[asset_id, gateway_4b_id\]
-        NewTokenLinkedToGateway(AssetId, TargetId),
-        /// \[asset_id, gateway_4b_id\]
-        NewTokenAssetRegistered(AssetId, TargetId),
-        /// \[requester, gateway_record_id\]
-        GatewayRecordPurged(T::AccountId, TargetId),
-        /// \[requester, xdns_record_id\]
-        XdnsRecordPurged(T::AccountId, TargetId),
+        GatewayRecordCreated(TargetId),
+        /// \[quote_asset, estimate\]
+        XtxCostEstimated(AssetId, XtxCostEstimate<BalanceOf<T>>),
+        /// \[gateway_4b_id, changed_fields\]
+        GatewayRecordUpdated(TargetId, GatewayRecordChanges),
+        /// \[gateway_4b_id, allowed_side_effects\]
+        GatewayAllowedSideEffectsChanged(TargetId, Vec<(Sfx4bId, Option<u8>)>),
+        /// \[gateway_4b_id, asset_id\]
+        NewTokenLinkedToGateway(TargetId, AssetId),
+        /// \[gateway_4b_id, asset_id\]
+        NewTokenAssetRegistered(TargetId, AssetId),
+        /// \[gateway_record_id, requester\]
+        GatewayRecordPurged(TargetId, T::AccountId),
+        /// \[xdns_record_id, requester\]
+        XdnsRecordPurged(TargetId, T::AccountId),
         /// \[xdns_record_id\]
         XdnsRecordUpdated(TargetId),
         /// \[xdns_topology\]
         XDNSTopologyZip(XDNSTopology<T::AccountId>),
+        /// \[gateway_4b_id, asset_id, counterpart_gateway_4b_id, counterpart_asset_id\]
+        AssetCorrespondenceLinked(TargetId, AssetId, TargetId, AssetId),
+        /// \[gateway_4b_id, asset_id, counterpart_gateway_4b_id, counterpart_asset_id\]
+        AssetCorrespondenceUnlinked(TargetId, AssetId, TargetId, AssetId),
+        /// \[gateway_4b_id, security_tier\]
+        GatewaySecurityTierUpdated(TargetId, GatewaySecurityTier),
+        /// \[gateway_4b_id, bond_scaling_factors\]
+        BondScalingFactorsUpdated(TargetId, BondScalingFactors),
+        /// \[gateway_4b_id, asset_id\]
+        TokenSysPropsUpdated(TargetId, AssetId),
+        /// \[gateway_4b_id, owner\]
+        GatewayIdReserved(TargetId, Option<T::AccountId>),
+        /// \[gateway_4b_id, owner\]
+        GatewayIdReservationReleased(TargetId, Option<T::AccountId>),
+        /// \[gateway_4b_id, fee_schedule\]
+        GatewayFeeScheduleUpdated(TargetId, GatewayFeeSchedule),
+        /// \[gateway_4b_id, sfx_4b_id, max_fee\]
+        FeeHintSubmitted(TargetId, Sfx4bId, BalanceOf<T>),
+        /// \[oracles\]
+        FeeOraclesSet(Vec<T::AccountId>),
+    }
+
+    #[pallet::extra_constants]
+    impl<T: Config> Pallet<T> {
+        /// Version of the layout of identifiers leading the events, bumped on breaking changes.
+        #[pallet::constant_name(EventsVersion)]
+        fn events_version() -> u32 {
+            t3rn_primitives::EVENTS_VERSION
+        }
     }
 
     // Errors inform users that something went wrong.
@@ -858,6 +1390,32 @@ pub mod pallet {
         TopologyDecodeError,
         /// Empty topology submitted at Unzip
         EmptyTopologySubmitted,
+        /// Asset correspondence can only be declared between two different gateways
+        AssetCorrespondenceOnSameGateway,
+        /// Asset correspondence not found
+        AssetCorrespondenceNotFound,
+        /// Gateway record exceeds the maximum number of allowed side effects
+        TooManyAllowedSideEffects,
+        /// Registry reached the maximum number of gateways
+        TooManyGateways,
+        /// Registry reached the maximum number of tokens
+        TooManyTokens,
+        /// Only the registrant of the gateway can perform this action
+        NotGatewayRegistrant,
+        /// Gateway id has already been reserved
+        GatewayIdAlreadyReserved,
+        /// Gateway id is reserved for another account
+        GatewayIdReservedForAnother,
+        /// Gateway id reservation not found
+        GatewayIdReservationNotFound,
+        /// Gateway id reservation backs a registered gateway
+        GatewayIdInUse,
+        /// Fee oracles exceed the maximum number of allowed oracles
+        TooManyFeeOracles,
+        /// Topology holds more gateways or tokens than the bounds it was zipped with
+        TopologyExceedsDeclaredBounds,
+        /// Gateway takes part in the maximum number of asset correspondences
+        TooManyAssetCorrespondences,
     }
 
     // Deprecated storage entry -- StandardSideEffects
@@ -897,22 +1455,60 @@ pub mod pallet {
     // Recover TokenRecords stored per gateway, to be able to iterate over all tokens stored on a gateway
     #[pallet::storage]
     #[pallet::getter(fn gateway_tokens)]
-    pub type GatewayTokens<T: Config> = StorageMap<_, Identity, TargetId, Vec<AssetId>, ValueQuery>;
+    pub type GatewayTokens<T: Config> =
+        StorageMap<_, Identity, TargetId, GatewayAssets, ValueQuery>;
 
     // All known TokenIds to t3rn
     #[pallet::storage]
     #[pallet::getter(fn all_token_ids)]
-    pub type AllTokenIds<T: Config> = StorageValue<_, Vec<AssetId>, ValueQuery>;
+    pub type AllTokenIds<T: Config> =
+        StorageValue<_, BoundedVec<AssetId, ConstU32<MAX_TOKENS>>, ValueQuery>;
 
     // All known TokenIds to t3rn
     #[pallet::storage]
     #[pallet::getter(fn supported_bridging_assets)]
-    pub type AuthorizedMintAssets<T: Config> =
-        StorageValue<_, Vec<(AssetId, TargetId)>, ValueQuery>;
+    pub type AuthorizedMintAssets<T: Config> = StorageValue<
+        _,
+        BoundedVec<(AssetId, TargetId), ConstU32<MAX_AUTHORIZED_MINT_ASSETS>>,
+        ValueQuery,
+    >;
+
+    // Cross-chain asset correspondence: (gateway, asset) on one side maps to the same underlying asset on the counterpart gateway.
+    // Resolved by Vacuum when bridging assets in and out. The circuit doesn't resolve through it, since side effects
+    // name the asset on their own target and executors settle them in that very asset.
+    // Keyed by gateway first, so that the correspondences of a gateway are cleared by prefix.
+    #[pallet::storage]
+    #[pallet::getter(fn asset_correspondence)]
+    pub type AssetCorrespondence<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        TargetId, // Gateway Id
+        Identity,
+        (AssetId, TargetId), // (Asset Id, Counterpart Gateway Id)
+        AssetId,             // Counterpart Asset Id
+        OptionQuery,
+    >;
+
+    // Number of asset correspondences stored under each gateway,
+    // up to MAX_ASSET_CORRESPONDENCES_PER_GATEWAY
+    #[pallet::storage]
+    pub type AssetCorrespondenceCount<T: Config> =
+        StorageMap<_, Identity, TargetId, u32, ValueQuery>;
 
     #[pallet::storage]
     #[pallet::getter(fn all_gateway_ids)]
-    pub type AllGatewayIds<T: Config> = StorageValue<_, Vec<TargetId>, ValueQuery>;
+    pub type AllGatewayIds<T: Config> =
+        StorageValue<_, BoundedVec<TargetId, ConstU32<MAX_GATEWAYS>>, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn gateway_id_reservations)]
+    pub type GatewayIdReservations<T: Config> = StorageMap<
+        _,
+        Identity,
+        TargetId,
+        GatewayIdReservation<T::AccountId, BalanceOf<T>>,
+        OptionQuery,
+    >;
 
     #[pallet::storage]
     #[pallet::getter(fn remote_order_addresses)]
@@ -971,10 +1567,70 @@ pub mod pallet {
     >;
 
     #[pallet::storage]
-    #[pallet::getter(fn verifier_overview_history)]
-    pub type VerifierOverviewStoreHistory<T: Config> = StorageMap<
-        _,
-        Twox64Concat,
+    #[pallet::getter(fn gateway_activity_stats)]
+    pub type GatewayActivityStatsStore<T: Config> =
+        StorageMap<_, Identity, TargetId, GatewayActivityStats, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn bond_scaling)]
+    pub type BondScaling<T: Config> =
+        StorageMap<_, Identity, TargetId, BondScalingFactors, ValueQuery>;
+
+    #[pallet::storage]
+    pub type ActivityStatsWindowStart<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+
+    // Winning bids of the recent side effects, sampled by the fee oracles
+    #[pallet::storage]
+    pub type RecentWinningBids<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        TargetId,
+        Identity,
+        Sfx4bId,
+        BoundedVec<BalanceOf<T>, ConstU32<{ crate::fee_oracle::MAX_FEE_SAMPLES }>>,
+        ValueQuery,
+    >;
+
+    // Latest fee hint of each oracle per side effect along with the block it was submitted at
+    #[pallet::storage]
+    pub type OracleFeeHints<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        TargetId,
+        Identity,
+        Sfx4bId,
+        BoundedVec<
+            (T::AccountId, FeeHint<BalanceOf<T>>, BlockNumberFor<T>),
+            ConstU32<{ crate::fee_oracle::MAX_FEE_ORACLES }>,
+        >,
+        ValueQuery,
+    >;
+
+    // Median fee hint of the oracles per side effect along with the block it was submitted at
+    #[pallet::storage]
+    pub type FeeHints<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        TargetId,
+        Identity,
+        Sfx4bId,
+        (FeeHint<BalanceOf<T>>, BlockNumberFor<T>),
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn fee_oracles)]
+    pub type FeeOracles<T: Config> = StorageValue<
+        _,
+        BoundedVec<T::AccountId, ConstU32<{ crate::fee_oracle::MAX_FEE_ORACLES }>>,
+        ValueQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn verifier_overview_history)]
+    pub type VerifierOverviewStoreHistory<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
         GatewayVendor,                                    // Gateway Id
         Vec<FinalityVerifierActivity<BlockNumberFor<T>>>, // Activity
         ValueQuery,
@@ -995,28 +1651,97 @@ pub mod pallet {
         // pub known_gateway_records: Vec<GatewayRecord<T::AccountId>>,
         // pub standard_sfx_abi: Vec<(Sfx4bId, SFXAbi)>,
         pub standard_sfx_abi: Vec<u8>,
+        /// If set, seeds the self-gateway at genesis for the given vendor, allowing the standard
+        /// side effects declared, or all of them when none are
+        pub self_gateway_vendor: Option<GatewayVendor>,
         #[serde(skip)]
         pub _marker: PhantomData<T>,
     }
 
+    /// Builder API over the SCALE-encoded genesis fields, so that networks and test environments
+    /// can declare the gateways and side effect ABIs to seed XDNS with.
+    impl<T: Config> GenesisConfig<T> {
+        pub fn decoded_gateway_records(&self) -> Vec<GatewayRecord<T::AccountId>> {
+            Decode::decode(&mut &self.known_gateway_records[..]).unwrap_or_default()
+        }
+
+        pub fn decoded_standard_sfx_abi(&self) -> Vec<(Sfx4bId, SFXAbi)> {
+            Decode::decode(&mut &self.standard_sfx_abi[..]).unwrap_or_default()
+        }
+
+        pub fn with_gateway_record(self, gateway_record: GatewayRecord<T::AccountId>) -> Self {
+            self.with_gateway_records(vec![gateway_record])
+        }
+
+        pub fn with_gateway_records(
+            mut self,
+            gateway_records: Vec<GatewayRecord<T::AccountId>>,
+        ) -> Self {
+            let mut known_gateway_records = self.decoded_gateway_records();
+            known_gateway_records.extend(gateway_records);
+            self.known_gateway_records = known_gateway_records.encode();
+            self
+        }
+
+        pub fn with_sfx_abi(self, sfx_4b_id: Sfx4bId, sfx_abi: SFXAbi) -> Self {
+            self.with_sfx_abis(vec![(sfx_4b_id, sfx_abi)])
+        }
+
+        pub fn with_sfx_abis(mut self, sfx_abis: Vec<(Sfx4bId, SFXAbi)>) -> Self {
+            let mut standard_sfx_abi = self.decoded_standard_sfx_abi();
+            for (sfx_4b_id, sfx_abi) in sfx_abis {
+                standard_sfx_abi.retain(|(known_sfx_4b_id, _)| known_sfx_4b_id != &sfx_4b_id);
+                standard_sfx_abi.push((sfx_4b_id, sfx_abi));
+            }
+            self.standard_sfx_abi = standard_sfx_abi.encode();
+            self
+        }
+
+        /// Seeds all of the standard side effect ABIs known to t3rn_abi::standard
+        pub fn with_standard_sfx_abi(self) -> Self {
+            self.with_sfx_abis(t3rn_abi::standard::standard_sfx_abi())
+        }
+
+        /// Seeds the standard side effect ABIs declared, e.g. `StandardSfxAbis::new().with_transfers()`
+        pub fn with_standard_sfx_abis(self, standard_sfx_abis: StandardSfxAbis) -> Self {
+            self.with_sfx_abis(standard_sfx_abis.build())
+        }
+
+        pub fn with_self_gateway(mut self, vendor: GatewayVendor) -> Self {
+            self.self_gateway_vendor = Some(vendor);
+            self
+        }
+    }
+
     /// The build of genesis for the pallet.
     /// Populates storage with the known XDNS Records
     #[pallet::genesis_build]
     impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
         fn build(&self) {
-            let known_gateway_records: Vec<GatewayRecord<T::AccountId>> =
-                Decode::decode(&mut &self.known_gateway_records[..]).unwrap_or_default();
-
-            let standard_sfx_abi: Vec<(Sfx4bId, SFXAbi)> =
-                Decode::decode(&mut &self.standard_sfx_abi[..]).unwrap_or_default();
+            // Seed only the standard ABIs declared. The self-gateway allows the standard side
+            // effects seeded, so it falls back to all of them when none are declared.
+            let standard_sfx_abi = self.decoded_standard_sfx_abi();
+            if !standard_sfx_abi.is_empty() {
+                Pallet::<T>::seed_standard_sfx_abis(standard_sfx_abi);
+            } else if self.self_gateway_vendor.is_some() {
+                Pallet::<T>::seed_standard_sfx_abis(t3rn_abi::standard::standard_sfx_abi());
+            }
 
-            for (sfx_4b_id, sfx_abi) in standard_sfx_abi {
-                let _sfx_4b_str = sp_std::str::from_utf8(sfx_4b_id.as_slice())
-                    .unwrap_or("invalid utf8 4b sfx id format");
-                <StandardSFXABIs<T>>::insert(sfx_4b_id, sfx_abi);
+            if let Some(vendor) = &self.self_gateway_vendor {
+                Pallet::<T>::do_seed_self_gateway(
+                    frame_system::RawOrigin::Root.into(),
+                    vendor.clone(),
+                )
+                .map_err(|e| {
+                    log::error!(
+                        "XDNS -- on-genesis: failed to seed self-gateway via do_seed_self_gateway: {:?}",
+                        e
+                    );
+                })
+                .ok();
             }
 
-            for gateway_record in known_gateway_records {
+            for gateway_record in self.decoded_gateway_records() {
                 Pallet::<T>::override_gateway(
                     gateway_record.gateway_id,
                     gateway_record.verification_vendor,
@@ -1024,8 +1749,20 @@ pub mod pallet {
                     gateway_record.codec,
                     gateway_record.registrant,
                     gateway_record.escrow_account,
-                    gateway_record.allowed_side_effects,
+                    gateway_record.allowed_side_effects.into_inner(),
                 )
+                .and_then(|_| {
+                    Pallet::<T>::do_set_gateway_security_tier(
+                        gateway_record.gateway_id,
+                        gateway_record.security_tier,
+                    )
+                })
+                .and_then(|_| {
+                    Pallet::<T>::do_set_gateway_fee_schedule(
+                        gateway_record.gateway_id,
+                        gateway_record.fee_schedule,
+                    )
+                })
                 .map_err(|e| {
                     log::error!(
                         "XDNS -- on-genesis: failed to add gateway via override_gateway: {:?}",
@@ -1042,21 +1779,18 @@ pub mod pallet {
             origin: OriginFor<T>,
             vendor: GatewayVendor,
         ) -> DispatchResult {
-            let admin: T::AccountId = ensure_signed_or_root(origin)?.unwrap_or(
-                T::TreasuryAccounts::get_treasury_account(TreasuryAccount::Escrow),
-            );
-
             // Refresh the list of StandardABI based on latest implementation
-            // Purge all standards first
-            if <StandardSFXABIs<T>>::iter().count() > 0 {
-                <StandardSFXABIs<T>>::remove_all(None);
-            }
-            // Re-add all standards
-            t3rn_abi::standard::standard_sfx_abi()
-                .iter()
-                .for_each(|(sfx_4b_id, sfx_abi)| {
-                    <StandardSFXABIs<T>>::insert(sfx_4b_id, sfx_abi.clone());
-                });
+            Self::seed_standard_sfx_abis(t3rn_abi::standard::standard_sfx_abi());
+
+            Self::do_seed_self_gateway(origin, vendor)
+        }
+
+        /// Registers the self-gateway of the given vendor, allowing the standard side effects of
+        /// the ABIs seeded already.
+        pub fn do_seed_self_gateway(origin: OriginFor<T>, vendor: GatewayVendor) -> DispatchResult {
+            let admin: T::AccountId = ensure_signed(origin).unwrap_or_else(|_| {
+                T::TreasuryAccounts::get_treasury_account(TreasuryAccount::Escrow)
+            });
 
             let target_id = T::SelfGatewayId::get();
 
@@ -1093,24 +1827,56 @@ pub mod pallet {
                 allowed_side_effects,
             )?;
 
+            Ok(())
+        }
+
+        /// Replace the standard side effect ABIs with `standard_sfx_abis`, i.e. declared with
+        ///     `t3rn_abi::standard::StandardSfxAbis`, refreshing the ABIs gateways linked to them
+        ///     while keeping the gateways' prefix memos. Returns the weight of seeding them.
+        pub fn seed_standard_sfx_abis(standard_sfx_abis: Vec<(Sfx4bId, SFXAbi)>) -> Weight {
+            // Purge all standards first
+            let purged = <StandardSFXABIs<T>>::iter_keys().count() as u64;
+            if purged > 0 {
+                <StandardSFXABIs<T>>::remove_all(None);
+            }
+            for (sfx_4b_id, sfx_abi) in standard_sfx_abis.iter() {
+                <StandardSFXABIs<T>>::insert(sfx_4b_id, sfx_abi.clone());
+            }
+
             // If standards are linked to Gateways, refresh them all at once
-            t3rn_abi::standard::standard_sfx_abi().iter_mut().for_each(
-                |(ref sfx_4b_id, ref mut sfx_abi)| {
-                    <SFXABIRegistry<T>>::iter_keys().for_each(|(gateway_id, on_dest_sfx_4b_id)| {
-                        if *sfx_4b_id == on_dest_sfx_4b_id {
-                            <SFXABIRegistry<T>>::mutate(gateway_id, sfx_4b_id, |target_sfx_abi| {
-                                sfx_abi.maybe_prefix_memo = match target_sfx_abi {
-                                    Some(target_sfx_abi) => target_sfx_abi.maybe_prefix_memo,
-                                    None => None,
-                                };
-                                *target_sfx_abi = Some(sfx_abi.clone());
-                            });
-                        }
-                    })
-                },
-            );
+            let linked = <SFXABIRegistry<T>>::iter_keys().collect::<Vec<_>>();
+            let mut refreshed = 0u64;
+            for (gateway_id, sfx_4b_id) in linked.iter() {
+                let Some((_, sfx_abi)) = standard_sfx_abis
+                    .iter()
+                    .find(|(standard_sfx_4b_id, _)| standard_sfx_4b_id == sfx_4b_id)
+                else {
+                    continue
+                };
+                <SFXABIRegistry<T>>::mutate(gateway_id, sfx_4b_id, |target_sfx_abi| {
+                    let mut sfx_abi = sfx_abi.clone();
+                    sfx_abi.maybe_prefix_memo = target_sfx_abi
+                        .as_ref()
+                        .and_then(|target| target.maybe_prefix_memo);
+                    *target_sfx_abi = Some(sfx_abi);
+                });
+                refreshed += 1;
+            }
 
-            Ok(())
+            // Refreshed standards change the default ABI descriptors of the gateways using them
+            let synced = linked
+                .iter()
+                .map(|(gateway_id, _)| *gateway_id)
+                .collect::<BTreeSet<_>>();
+            for gateway_id in synced.iter() {
+                Self::sync_gateway_abi_descriptors(*gateway_id);
+            }
+            let synced = synced.len() as u64;
+
+            T::DbWeight::get().reads_writes(
+                purged + linked.len() as u64 * 2 + refreshed + synced,
+                purged + standard_sfx_abis.len() as u64 + refreshed + synced,
+            )
         }
 
         pub fn update_epoch_history(
@@ -1155,6 +1921,261 @@ pub mod pallet {
 
             EpochHistory::<T>::insert(verifier, history);
         }
+
+        /// Removes all asset correspondences in which the given gateway takes part, on either side.
+        /// Returns the number of correspondences the gateway had.
+        pub fn purge_asset_correspondences_of_gateway(gateway_id: TargetId) -> u32 {
+            let correspondences = <AssetCorrespondence<T>>::iter_prefix(gateway_id)
+                .map(
+                    |((asset_id, counterpart_gateway_id), counterpart_asset_id)| {
+                        (asset_id, counterpart_gateway_id, counterpart_asset_id)
+                    },
+                )
+                .collect::<Vec<_>>();
+
+            for (_, counterpart_gateway_id, counterpart_asset_id) in correspondences.iter() {
+                Self::take_asset_correspondence(
+                    *counterpart_gateway_id,
+                    *counterpart_asset_id,
+                    gateway_id,
+                );
+            }
+            let _ = <AssetCorrespondence<T>>::clear_prefix(
+                gateway_id,
+                MAX_ASSET_CORRESPONDENCES_PER_GATEWAY,
+                None,
+            );
+            <AssetCorrespondenceCount<T>>::remove(gateway_id);
+
+            correspondences.len() as u32
+        }
+
+        /// Stores one direction of an asset correspondence, unless the gateway holds the most
+        /// correspondences allowed already.
+        pub fn insert_asset_correspondence(
+            gateway_id: TargetId,
+            asset_id: AssetId,
+            counterpart_gateway_id: TargetId,
+            counterpart_asset_id: AssetId,
+        ) -> DispatchResult {
+            <AssetCorrespondenceCount<T>>::try_mutate(gateway_id, |count| {
+                ensure!(
+                    *count < MAX_ASSET_CORRESPONDENCES_PER_GATEWAY,
+                    Error::<T>::TooManyAssetCorrespondences
+                );
+                *count += 1;
+                Ok::<(), DispatchError>(())
+            })?;
+            <AssetCorrespondence<T>>::insert(
+                gateway_id,
+                (asset_id, counterpart_gateway_id),
+                counterpart_asset_id,
+            );
+            Ok(())
+        }
+
+        /// Removes one direction of an asset correspondence, returning the counterpart asset.
+        fn take_asset_correspondence(
+            gateway_id: TargetId,
+            asset_id: AssetId,
+            counterpart_gateway_id: TargetId,
+        ) -> Option<AssetId> {
+            let counterpart_asset_id =
+                <AssetCorrespondence<T>>::take(gateway_id, (asset_id, counterpart_gateway_id))?;
+            <AssetCorrespondenceCount<T>>::mutate(gateway_id, |count| {
+                *count = count.saturating_sub(1)
+            });
+            Some(counterpart_asset_id)
+        }
+
+        /// Stores the gateway record, emitting whether it was created or which of its fields were updated.
+        pub fn store_gateway_record(gateway_record: GatewayRecord<T::AccountId>) {
+            let gateway_id = gateway_record.gateway_id;
+            match <Gateways<T>>::get(gateway_id) {
+                None => Self::deposit_event(Event::<T>::GatewayRecordCreated(gateway_id)),
+                Some(previous_record) => {
+                    let changes = GatewayRecordChanges::between(&previous_record, &gateway_record);
+                    if changes.allowed_side_effects {
+                        Self::deposit_event(Event::<T>::GatewayAllowedSideEffectsChanged(
+                            gateway_id,
+                            gateway_record.allowed_side_effects.to_vec(),
+                        ));
+                    }
+                    if !changes.is_empty() {
+                        Self::deposit_event(Event::<T>::GatewayRecordUpdated(gateway_id, changes));
+                    }
+                },
+            }
+            <Gateways<T>>::insert(gateway_id, gateway_record);
+        }
+
+        pub fn do_set_gateway_security_tier(
+            gateway_id: TargetId,
+            security_tier: GatewaySecurityTier,
+        ) -> DispatchResult {
+            let mut gateway_record =
+                <Gateways<T>>::get(gateway_id).ok_or(Error::<T>::GatewayRecordNotFound)?;
+            if gateway_record.security_tier == security_tier {
+                return Ok(())
+            }
+            gateway_record.security_tier = security_tier.clone();
+
+            // Stored apart from store_gateway_record, since the tier update is its only event
+            <Gateways<T>>::insert(gateway_id, gateway_record);
+
+            Self::deposit_event(Event::<T>::GatewaySecurityTierUpdated(
+                gateway_id,
+                security_tier,
+            ));
+
+            Ok(())
+        }
+
+        /// Sets the fee schedule of the gateway. Unchanged schedules are left as they are, without an event.
+        pub fn do_set_gateway_fee_schedule(
+            gateway_id: TargetId,
+            fee_schedule: GatewayFeeSchedule,
+        ) -> DispatchResult {
+            let mut gateway_record =
+                <Gateways<T>>::get(gateway_id).ok_or(Error::<T>::GatewayRecordNotFound)?;
+            if gateway_record.fee_schedule == fee_schedule {
+                return Ok(())
+            }
+            gateway_record.fee_schedule = fee_schedule.clone();
+
+            Self::store_gateway_record(gateway_record);
+
+            Self::deposit_event(Event::<T>::GatewayFeeScheduleUpdated(
+                gateway_id,
+                fee_schedule,
+            ));
+
+            Ok(())
+        }
+
+        /// Mirrors the tokens linked to the gateway into the assets of its record, if registered.
+        pub fn sync_gateway_assets(gateway_id: TargetId) {
+            <Gateways<T>>::mutate(gateway_id, |maybe_record| {
+                if let Some(record) = maybe_record {
+                    record.assets = <GatewayTokens<T>>::get(gateway_id);
+                }
+            });
+        }
+
+        /// Collects the ingress ABI descriptors in `codec` of the side effects enrolled on the gateway.
+        ///     Side effects without a descriptor in `codec`, or with one over the bound, are left out.
+        pub fn collect_abi_descriptors(
+            gateway_id: &ChainId,
+            codec: &Codec,
+        ) -> GatewayAbiDescriptors {
+            let descriptors = <SFXABIRegistry<T>>::iter_prefix(gateway_id)
+                .filter_map(|(sfx_4b_id, sfx_abi)| {
+                    let descriptor = sfx_abi.get_expected_ingress_descriptor(codec.clone());
+                    if descriptor.is_empty() {
+                        return None
+                    }
+                    AbiDescriptor::try_from(descriptor)
+                        .map_err(|_| {
+                            log::error!(
+                                "ABI descriptor of {:?} on {:?} exceeds the bound; left out of the gateway record.",
+                                sfx_4b_id,
+                                gateway_id
+                            );
+                        })
+                        .ok()
+                        .map(|descriptor| (sfx_4b_id, descriptor))
+                })
+                .collect::<Vec<_>>();
+            GatewayAbiDescriptors::truncate_from(descriptors)
+        }
+
+        /// Mirrors the SFX ABIs enrolled on the gateway into the ABI descriptors of its record, if registered.
+        pub fn sync_gateway_abi_descriptors(gateway_id: TargetId) {
+            <Gateways<T>>::mutate(gateway_id, |maybe_record| {
+                if let Some(record) = maybe_record {
+                    record.abi_descriptors =
+                        Self::collect_abi_descriptors(&gateway_id, &record.codec);
+                }
+            });
+        }
+
+        /// Removes the reservation of the gateway id, if any, and refunds its deposit to the owner.
+        pub fn release_gateway_id_reservation(gateway_id: TargetId) {
+            if let Some(reservation) = <GatewayIdReservations<T>>::take(gateway_id) {
+                if let Some(owner) = reservation.owner.as_ref() {
+                    T::Currency::unreserve(owner, reservation.deposit);
+                }
+                Self::deposit_event(Event::<T>::GatewayIdReservationReleased(
+                    gateway_id,
+                    reservation.owner,
+                ));
+            }
+        }
+
+        /// Replaces the properties of the token linked to the gateway, keeping its token standard.
+        pub fn do_update_token_sys_props(
+            gateway_id: TargetId,
+            token_id: AssetId,
+            token_props: TokenInfo,
+        ) -> DispatchResult {
+            <Tokens<T>>::try_mutate(token_id, gateway_id, |maybe_token| match maybe_token {
+                Some(token) => {
+                    let is_same_standard = match (&token.token_props, &token_props) {
+                        (TokenInfo::Substrate(_), TokenInfo::Substrate(_))
+                        | (TokenInfo::Ethereum(_), TokenInfo::Ethereum(_)) => true,
+                        _ => false,
+                    };
+                    ensure!(is_same_standard, Error::<T>::TokenExecutionVendorMismatch);
+                    token.token_props = token_props;
+                    Ok(())
+                },
+                None => Err(Error::<T>::TokenRecordNotFoundInGateway),
+            })?;
+
+            Self::deposit_event(Event::<T>::TokenSysPropsUpdated(gateway_id, token_id));
+
+            Ok(())
+        }
+
+        /// Ranks operational gateways able to execute the given side effect over the given asset.
+        /// Gateways are ordered by their security tier first, then by the fee their schedule charges
+        /// for the side effect, with its args estimated at ESTIMATED_SFX_ARG_SIZE each.
+        pub fn rank_gateways_for(sfx_4b_id: &Sfx4bId, asset_id: AssetId) -> Vec<TargetId> {
+            let mut candidates = Self::read_last_activity_overview()
+                .into_iter()
+                .filter(|activity| activity.is_active)
+                .filter_map(|activity| <Gateways<T>>::get(activity.gateway_id))
+                .filter(|record| {
+                    record
+                        .allowed_side_effects
+                        .iter()
+                        .any(|(allowed_sfx_4b_id, _)| allowed_sfx_4b_id == sfx_4b_id)
+                })
+                .filter(|record| <GatewayTokens<T>>::get(record.gateway_id).contains(&asset_id))
+                .map(|record| {
+                    let encoded_args_len = <SFXABIRegistry<T>>::get(record.gateway_id, sfx_4b_id)
+                        .map_or(0, |sfx_abi| sfx_abi.args_names.len() as u32)
+                        .saturating_mul(ESTIMATED_SFX_ARG_SIZE);
+                    (
+                        record.gateway_id,
+                        record.security_tier,
+                        record.fee_schedule.fee_of(encoded_args_len),
+                    )
+                })
+                .collect::<Vec<_>>();
+
+            candidates.sort_by(|(id_a, tier_a, fee_a), (id_b, tier_b, fee_b)| {
+                tier_a
+                    .cmp(tier_b)
+                    .then_with(|| fee_a.cmp(fee_b))
+                    .then_with(|| id_a.cmp(id_b))
+            });
+
+            candidates
+                .into_iter()
+                .map(|(gateway_id, _, _)| gateway_id)
+                .collect()
+        }
     }
 
     impl<T: Config> LightClientAsyncAPI<T> for Pallet<T> {
@@ -1206,11 +2227,10 @@ pub mod pallet {
 
             let gateway_id = T::SelfGatewayId::get();
 
+            // Also lists the token among all tokens
             Self::link_token_to_gateway(token_id, gateway_id, token_props)?;
 
-            <AllTokenIds<T>>::append(token_id);
-
-            Self::deposit_event(Event::<T>::NewTokenAssetRegistered(token_id, gateway_id));
+            Self::deposit_event(Event::<T>::NewTokenAssetRegistered(gateway_id, token_id));
 
             Ok(())
         }
@@ -1243,6 +2263,21 @@ pub mod pallet {
             gateway_id: TargetId,
             token_props: TokenInfo,
         ) -> DispatchResult {
+            <GatewayTokens<T>>::try_mutate(gateway_id, |tokens| {
+                if !tokens.contains(&token_id) {
+                    tokens
+                        .try_push(token_id)
+                        .map_err(|_| Error::<T>::TooManyTokens)?;
+                }
+                Ok::<(), Error<T>>(())
+            })?;
+            Self::sync_gateway_assets(gateway_id);
+
+            // Make sure that the token is added to the list of all tokens
+            if !<AllTokenIds<T>>::get().contains(&token_id) {
+                <AllTokenIds<T>>::try_append(token_id).map_err(|_| Error::<T>::TooManyTokens)?;
+            }
+
             <Tokens<T>>::insert(
                 token_id,
                 gateway_id,
@@ -1253,18 +2288,7 @@ pub mod pallet {
                 },
             );
 
-            <GatewayTokens<T>>::mutate(gateway_id, |tokens| {
-                if !tokens.contains(&token_id) {
-                    tokens.push(token_id);
-                }
-            });
-
-            // Make sure that the token is added to the list of all tokens
-            if !<AllTokenIds<T>>::get().contains(&token_id) {
-                <AllTokenIds<T>>::append(token_id);
-            }
-
-            Self::deposit_event(Event::<T>::NewTokenLinkedToGateway(token_id, gateway_id));
+            Self::deposit_event(Event::<T>::NewTokenLinkedToGateway(gateway_id, token_id));
             Ok(())
         }
 
@@ -1319,6 +2343,14 @@ pub mod pallet {
                 return Err(Error::<T>::GatewayRecordAlreadyExists.into())
             }
 
+            // reserved gateway ids can only be registered by their holder
+            if let Some(reservation) = <GatewayIdReservations<T>>::get(gateway_id) {
+                ensure!(
+                    reservation.owner == registrant,
+                    Error::<T>::GatewayIdReservedForAnother
+                );
+            }
+
             Self::override_gateway(
                 gateway_id,
                 verification_vendor,
@@ -1339,6 +2371,9 @@ pub mod pallet {
             escrow_account: Option<T::AccountId>,
             allowed_side_effects: Vec<(TargetId, Option<u8>)>,
         ) -> DispatchResult {
+            let allowed_side_effects = AllowedSideEffects::try_from(allowed_side_effects)
+                .map_err(|_| Error::<T>::TooManyAllowedSideEffects)?;
+
             // Populate standard side effect ABI registry
             for (sfx_4b_id, maybe_event_memo_prefix) in allowed_side_effects.iter() {
                 match <StandardSFXABIs<T>>::get(sfx_4b_id) {
@@ -1357,25 +2392,36 @@ pub mod pallet {
                     },
                 }
             }
-            <Gateways<T>>::insert(
-                gateway_id,
-                GatewayRecord {
-                    gateway_id,
-                    verification_vendor,
-                    execution_vendor,
-                    codec,
-                    registrant,
-                    escrow_account,
-                    allowed_side_effects,
-                },
-            );
-            <AllGatewayIds<T>>::mutate(|ids| {
+            <AllGatewayIds<T>>::try_mutate(|ids| {
                 ids.iter()
                     .position(|&id| id == gateway_id)
                     .map(|i| ids.remove(i));
-                ids.push(gateway_id);
+                ids.try_push(gateway_id)
+                    .map_err(|_| Error::<T>::TooManyGateways)
+            })?;
+            // Tiers and fees are set apart from the gateway's registration, so re-registering keeps them
+            let (security_tier, fee_schedule) = match <Gateways<T>>::get(gateway_id) {
+                Some(record) => (record.security_tier, record.fee_schedule),
+                None => (
+                    verification_vendor.default_security_tier(),
+                    Default::default(),
+                ),
+            };
+            let abi_descriptors = Self::collect_abi_descriptors(&gateway_id, &codec);
+            Self::store_gateway_record(GatewayRecord {
+                gateway_id,
+                verification_vendor,
+                execution_vendor,
+                codec,
+                registrant,
+                escrow_account,
+                allowed_side_effects,
+                security_tier,
+                fee_schedule,
+                assets: <GatewayTokens<T>>::get(gateway_id),
+                abi_descriptors,
+                version: GATEWAY_RECORD_VERSION,
             });
-            Self::deposit_event(Event::<T>::GatewayRecordStored(gateway_id));
 
             Ok(())
         }
@@ -1386,7 +2432,7 @@ pub mod pallet {
             sfx_4b_id: Sfx4bId,
             sfx_expected_abi: SFXAbi,
         ) -> DispatchResult {
-            ensure_root(origin)?;
+            T::UpdateOrigin::ensure_origin(origin)?;
             if !<Gateways<T>>::contains_key(gateway_id) {
                 return Err(Error::<T>::XdnsRecordNotFound.into())
             }
@@ -1398,6 +2444,7 @@ pub mod pallet {
                     Ok(())
                 },
             })?;
+            Self::sync_gateway_abi_descriptors(gateway_id);
 
             Ok(())
         }
@@ -1413,18 +2460,23 @@ pub mod pallet {
             let mut gateway_record =
                 <Gateways<T>>::get(gateway_id).ok_or(Error::<T>::XdnsRecordNotFound)?;
 
-            gateway_record.allowed_side_effects = new_sfx_abis
-                .iter()
-                .map(|(sfx_4b_id, abi)| (*sfx_4b_id, abi.maybe_prefix_memo))
-                .collect();
-
-            <Gateways<T>>::insert(gateway_id, gateway_record);
+            gateway_record.allowed_side_effects = AllowedSideEffects::try_from(
+                new_sfx_abis
+                    .iter()
+                    .map(|(sfx_4b_id, abi)| (*sfx_4b_id, abi.maybe_prefix_memo))
+                    .collect::<Vec<_>>(),
+            )
+            .map_err(|_| Error::<T>::TooManyAllowedSideEffects)?;
 
             for (sfx_4b_id, sfx_expected_abi) in new_sfx_abis {
                 <SFXABIRegistry<T>>::mutate(gateway_id, sfx_4b_id, |sfx_abi| {
                     *sfx_abi = Some(sfx_expected_abi);
                 });
             }
+            gateway_record.abi_descriptors =
+                Self::collect_abi_descriptors(&gateway_id, &gateway_record.codec);
+
+            Self::store_gateway_record(gateway_record);
 
             Ok(())
         }
@@ -1444,15 +2496,13 @@ pub mod pallet {
             gateway_id: ChainId,
             escrow_account: T::AccountId,
         ) -> DispatchResult {
-            ensure_root(origin)?;
+            T::UpdateOrigin::ensure_origin(origin)?;
 
-            Gateways::<T>::mutate(gateway_id, |gateway| match gateway {
-                None => Err(Error::<T>::GatewayRecordNotFound),
-                Some(record) => {
-                    record.escrow_account = Some(escrow_account);
-                    Ok(())
-                },
-            })?;
+            let mut gateway_record =
+                <Gateways<T>>::get(gateway_id).ok_or(Error::<T>::GatewayRecordNotFound)?;
+            gateway_record.escrow_account = Some(escrow_account);
+
+            Self::store_gateway_record(gateway_record);
 
             Ok(())
         }
@@ -1460,7 +2510,7 @@ pub mod pallet {
         /// returns a mapping of all allowed side_effects of a gateway.
         fn allowed_side_effects(gateway_id: &ChainId) -> Vec<(Sfx4bId, Option<u8>)> {
             match <Gateways<T>>::get(gateway_id) {
-                Some(gateway) => gateway.allowed_side_effects,
+                Some(gateway) => gateway.allowed_side_effects.into_inner(),
                 None => Vec::new(),
             }
         }
@@ -1470,12 +2520,27 @@ pub mod pallet {
                 return SecurityLvl::Escrow
             }
 
-            match Self::get_escrow_account(chain_id) {
+            let security_lvl = match Self::get_escrow_account(chain_id) {
                 Ok(_) => SecurityLvl::Escrow,
                 Err(_) => SecurityLvl::Optimistic,
+            };
+
+            // Cap by the security tier of the gateway's light client
+            match Self::get_gateway_security_tier(chain_id) {
+                Ok(security_tier) if !security_tier.allows(&security_lvl) =>
+                    security_tier.max_security_lvl(),
+                _ => security_lvl,
             }
         }
 
+        fn get_gateway_security_tier(
+            chain_id: &ChainId,
+        ) -> Result<GatewaySecurityTier, DispatchError> {
+            <Gateways<T>>::get(chain_id)
+                .map(|gateway| gateway.security_tier)
+                .ok_or(Error::<T>::GatewayRecordNotFound.into())
+        }
+
         /// returns the gateway vendor of a gateway if its available
         fn get_verification_vendor(chain_id: &ChainId) -> Result<GatewayVendor, DispatchError> {
             match <Gateways<T>>::get(chain_id) {
@@ -1491,6 +2556,31 @@ pub mod pallet {
             }
         }
 
+        fn get_default_abi_descriptor(
+            chain_id: &ChainId,
+            sfx_4b_id: Sfx4bId,
+        ) -> Result<Bytes, DispatchError> {
+            let record = <Gateways<T>>::get(chain_id).ok_or(Error::<T>::XdnsRecordNotFound)?;
+            record
+                .abi_descriptors
+                .into_iter()
+                .find(|(described_sfx_4b_id, _)| *described_sfx_4b_id == sfx_4b_id)
+                .map(|(_, descriptor)| descriptor.into_inner())
+                .ok_or_else(|| Error::<T>::SideEffectABINotFound.into())
+        }
+
+        fn get_default_abi_descriptors(chain_id: &ChainId) -> Vec<(Sfx4bId, Bytes)> {
+            <Gateways<T>>::get(chain_id)
+                .map(|record| {
+                    record
+                        .abi_descriptors
+                        .into_iter()
+                        .map(|(sfx_4b_id, descriptor)| (sfx_4b_id, descriptor.into_inner()))
+                        .collect()
+                })
+                .unwrap_or_default()
+        }
+
         fn get_escrow_account(chain_id: &ChainId) -> Result<Bytes, DispatchError> {
             match <Gateways<T>>::get(chain_id) {
                 Some(rec) => match rec.escrow_account {
@@ -1504,8 +2594,9 @@ pub mod pallet {
         fn fetch_full_gateway_records() -> Vec<FullGatewayRecord<T::AccountId>> {
             Gateways::<T>::iter_values()
                 .map(|gateway| {
-                    let tokens = Tokens::<T>::iter_values()
-                        .filter(|token| token.gateway_id == gateway.gateway_id)
+                    let tokens = GatewayTokens::<T>::get(gateway.gateway_id)
+                        .iter()
+                        .filter_map(|token_id| Tokens::<T>::get(token_id, gateway.gateway_id))
                         .collect();
                     FullGatewayRecord {
                         gateway_record: gateway,
@@ -1521,6 +2612,14 @@ pub mod pallet {
                 .find(|activity| activity.gateway_id == gateway_id)
         }
 
+        fn read_last_verifier_activity(
+            verifier: &GatewayVendor,
+        ) -> Option<FinalityVerifierActivity<BlockNumberFor<T>>> {
+            VerifierOverviewStoreHistory::<T>::get(verifier)
+                .last()
+                .cloned()
+        }
+
         fn read_last_activity_overview() -> Vec<GatewayActivity<BlockNumberFor<T>>> {
             let mut overview = <GatewaysOverviewStore<T>>::get();
             // get the latest update
@@ -1561,6 +2660,122 @@ pub mod pallet {
             T::SelfTokenId::get()
         }
 
+        fn get_corresponding_asset(
+            gateway_id: &TargetId,
+            asset_id: AssetId,
+            counterpart_gateway_id: &TargetId,
+        ) -> Option<AssetId> {
+            if gateway_id == counterpart_gateway_id {
+                return Some(asset_id)
+            }
+            <AssetCorrespondence<T>>::get(gateway_id, (asset_id, *counterpart_gateway_id))
+        }
+
+        fn are_assets_corresponding(
+            gateway_id: &TargetId,
+            asset_id: AssetId,
+            counterpart_gateway_id: &TargetId,
+            counterpart_asset_id: AssetId,
+        ) -> bool {
+            Self::get_corresponding_asset(gateway_id, asset_id, counterpart_gateway_id)
+                == Some(counterpart_asset_id)
+        }
+
+        fn best_gateway_for(sfx_4b_id: &Sfx4bId, asset_id: AssetId) -> Option<TargetId> {
+            Self::rank_gateways_for(sfx_4b_id, asset_id)
+                .first()
+                .copied()
+        }
+
+        fn note_sfx_targeted(gateway_id: &ChainId) {
+            Self::note_gateway_activity(gateway_id, |stats| {
+                stats.sfx_targeted = stats.sfx_targeted.saturating_add(1)
+            });
+        }
+
+        fn note_sfx_confirmed(gateway_id: &ChainId) {
+            Self::note_gateway_activity(gateway_id, |stats| {
+                stats.sfx_confirmed = stats.sfx_confirmed.saturating_add(1)
+            });
+        }
+
+        fn note_sfx_reverted(gateway_id: &ChainId) {
+            Self::note_gateway_activity(gateway_id, |stats| {
+                stats.sfx_reverted = stats.sfx_reverted.saturating_add(1)
+            });
+        }
+
+        fn get_gateway_activity_stats(gateway_id: &ChainId) -> GatewayActivityStats {
+            GatewayActivityStatsStore::<T>::get(gateway_id)
+        }
+
+        fn fetch_sfx_schemas() -> Vec<SfxSchema> {
+            let mut schemas: Vec<SfxSchema> = <StandardSFXABIs<T>>::iter()
+                .map(|(sfx_4b_id, sfx_abi)| SfxSchema::new(sfx_4b_id, sfx_abi))
+                .collect();
+            // Gateways enrolled with the standard ABI differ by their prefix memo at most
+            for (gateway_id, sfx_4b_id, sfx_abi) in <SFXABIRegistry<T>>::iter() {
+                let registered = SfxSchema::new(sfx_4b_id, sfx_abi);
+                match schemas
+                    .iter_mut()
+                    .find(|schema| schema.has_same_abi(&registered))
+                {
+                    Some(schema) => schema.gateways.push(gateway_id),
+                    None => schemas.push(SfxSchema {
+                        gateways: vec![gateway_id],
+                        ..registered
+                    }),
+                }
+            }
+            schemas
+        }
+
+        fn min_executor_bond(gateway_id: &ChainId, base_bond: BalanceOf<T>) -> BalanceOf<T> {
+            let security_tier = match Self::get_gateway_security_tier(gateway_id) {
+                Ok(security_tier) => security_tier,
+                Err(_) => return base_bond,
+            };
+            let multiplier = BondScaling::<T>::get(gateway_id).multiplier(
+                &security_tier,
+                GatewayActivityStatsStore::<T>::get(gateway_id).revert_rate(),
+            );
+            base_bond.saturating_mul(BalanceOf::<T>::from(multiplier))
+                / BalanceOf::<T>::from(100u32)
+        }
+
+        fn estimate_xtx_cost(
+            side_effects: &[(ChainId, AssetId, BalanceOf<T>)],
+            quote_asset: AssetId,
+        ) -> XtxCostEstimate<BalanceOf<T>> {
+            side_effects.iter().fold(
+                XtxCostEstimate::default(),
+                |mut estimate, (gateway_id, asset_id, insurance)| {
+                    let fee =
+                        Self::per_target_asset_estimates(gateway_id, (*asset_id, quote_asset));
+                    let bond = Self::min_executor_bond(gateway_id, *insurance);
+                    let expected_loss = GatewayActivityStatsStore::<T>::get(gateway_id)
+                        .revert_rate()
+                        .mul_floor(bond);
+
+                    estimate.fees = estimate.fees.saturating_add(fee);
+                    estimate.insurance = estimate.insurance.saturating_add(bond);
+                    estimate.min_bid = estimate.min_bid.saturating_add(fee);
+                    estimate.max_bid = estimate
+                        .max_bid
+                        .saturating_add(fee.saturating_add(expected_loss));
+                    estimate
+                },
+            )
+        }
+
+        fn note_sfx_bid_won(gateway_id: &ChainId, sfx_4b_id: &Sfx4bId, bid: BalanceOf<T>) {
+            Self::note_winning_bid(gateway_id, sfx_4b_id, bid)
+        }
+
+        fn suggest_max_fee(gateway_id: &ChainId, sfx_4b_id: &Sfx4bId) -> Option<BalanceOf<T>> {
+            Self::recent_fee_hint(gateway_id, sfx_4b_id).map(|hint| hint.max_fee)
+        }
+
         fn mint(asset_id: AssetId, user: T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
             assert!(
                 Self::check_asset_is_mintable(T::SelfGatewayId::get(), asset_id),
@@ -0,0 +1,319 @@
+//! Storage migrations of XDNS, run by the `StorageMigrations` counter they start from.
+
+use crate::pallet::{
+    Config, GatewayTokens, Gateways, Pallet, StandardSFXABIs, StandardSideEffects,
+    StorageMigrations,
+};
+use codec::{Decode, Encode};
+use frame_support::{
+    traits::{Get, OnRuntimeUpgrade},
+    weights::Weight,
+};
+use sp_std::marker::PhantomData;
+use t3rn_abi::sfx_abi::SFXAbi;
+use t3rn_primitives::{
+    migrations::VersionedMigration,
+    xdns::{
+        AllowedSideEffects, GatewayAbiDescriptors, GatewayAssets, GatewayRecord,
+        GATEWAY_RECORD_VERSION,
+    },
+    ChainId, ExecutionVendor, GatewayVendor,
+};
+#[cfg(feature = "try-runtime")]
+use {
+    crate::pallet::AllGatewayIds, frame_support::ensure, sp_runtime::TryRuntimeError,
+    sp_std::vec::Vec, t3rn_primitives::portal::Portal,
+};
+
+pub const CURRENT_STORAGE_VERSION: u32 = 4;
+
+/// Raw key of the XDNSRegistry entry, replaced by Gateways
+const XDNS_REGISTRY_V143_KEY: [u8; 36] = [
+    225, 205, 72, 162, 242, 43, 101, 142, 192, 157, 178, 168, 200, 143, 21, 13, 175, 239, 182, 147,
+    135, 79, 226, 105, 210, 52, 22, 179, 228, 93, 185, 249, 114, 111, 99, 111,
+];
+
+/// Raw key of another XDNSRegistry entry, replaced by Gateways
+const XDNS_REGISTRY_V144_KEY: [u8; 72] = [
+    84, 10, 79, 135, 84, 170, 82, 152, 163, 214, 233, 170, 9, 233, 63, 151, 78, 11, 18, 119, 80,
+    58, 19, 112, 111, 133, 165, 20, 116, 96, 124, 88, 24, 172, 250, 191, 195, 140, 91, 41, 106, 32,
+    177, 28, 37, 248, 177, 35, 27, 230, 169, 204, 8, 192, 121, 163, 226, 24, 100, 166, 207, 36, 66,
+    173, 219, 150, 184, 250, 101, 171, 135, 85,
+];
+
+/// Migrations of all versions XDNS storage is found at on live networks.
+pub type Migrations<T> = (
+    VersionedMigration<
+        0,
+        3,
+        StandardSideEffectsToSFXABIs<T>,
+        StorageMigrations<T>,
+        <T as frame_system::Config>::DbWeight,
+    >,
+    VersionedMigration<
+        1,
+        3,
+        KillXdnsRegistryV143<T>,
+        StorageMigrations<T>,
+        <T as frame_system::Config>::DbWeight,
+    >,
+    VersionedMigration<
+        2,
+        3,
+        KillXdnsRegistryV144AndBoundStorage<T>,
+        StorageMigrations<T>,
+        <T as frame_system::Config>::DbWeight,
+    >,
+    VersionedMigration<
+        3,
+        CURRENT_STORAGE_VERSION,
+        GatewayRecordsToTieredLayout<T>,
+        StorageMigrations<T>,
+        <T as frame_system::Config>::DbWeight,
+    >,
+);
+
+/// Storage Migration: (Re-)seed StandardSFXABIs with all of `t3rn_abi::standard::StandardSfxAbis`
+///     Not versioned, since running it again only refreshes the standards. Runtimes adding or
+///     changing a standard side effect include it as their single migration of the SFX ABIs.
+pub struct SeedStandardSFXABIs<T>(PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for SeedStandardSFXABIs<T> {
+    fn on_runtime_upgrade() -> Weight {
+        Pallet::<T>::seed_standard_sfx_abis(t3rn_abi::standard::standard_sfx_abi())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(_state: Vec<u8>) -> Result<(), TryRuntimeError> {
+        for (sfx_4b_id, sfx_abi) in t3rn_abi::standard::standard_sfx_abi() {
+            ensure!(
+                StandardSFXABIs::<T>::get(sfx_4b_id) == Some(sfx_abi),
+                "Standard SFX ABI missing after seeding StandardSFXABIs"
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Storage Migration: StandardSideEffects -> StandardSFXABIs
+/// Storage Migration Details: 16-03-2023; v1.4.0-rc -> v1.5.0-rc
+pub struct StandardSideEffectsToSFXABIs<T>(PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for StandardSideEffectsToSFXABIs<T> {
+    fn on_runtime_upgrade() -> Weight {
+        for (sfx4b_id, _value) in StandardSideEffects::<T>::drain() {
+            match SFXAbi::get_standard_interface(sfx4b_id) {
+                Some(sfx_abi) => {
+                    StandardSFXABIs::<T>::insert(sfx4b_id, sfx_abi);
+                },
+                None => {
+                    log::error!(
+                        "Failed to migrate StandardSideEffects to StandardSFXABIs for sfx4b_id: {:?}",
+                        sfx4b_id
+                    );
+                },
+            }
+        }
+        T::DbWeight::get().reads_writes(10, 10)
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(_state: Vec<u8>) -> Result<(), TryRuntimeError> {
+        ensure!(
+            StandardSideEffects::<T>::iter_keys().next().is_none(),
+            "StandardSideEffects left after migrating to StandardSFXABIs"
+        );
+        Ok(())
+    }
+}
+
+/// Storage Migration: Raw XDNS storage entry kill
+/// Storage Migration Details: 27-07-2023; v1.4.43-rc -> v1.4.44-rc
+///     Many Collators on t0rn hit: frame_support::storage: (key, value) failed to decode at the XDNSRegistry entry
+pub struct KillXdnsRegistryV143<T>(PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for KillXdnsRegistryV143<T> {
+    fn on_runtime_upgrade() -> Weight {
+        frame_support::storage::unhashed::kill(&XDNS_REGISTRY_V143_KEY);
+        T::DbWeight::get().writes(1)
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(_state: Vec<u8>) -> Result<(), TryRuntimeError> {
+        ensure!(
+            !frame_support::storage::unhashed::exists(&XDNS_REGISTRY_V143_KEY),
+            "XDNSRegistry entry of v1.4.43 left after its kill"
+        );
+        Ok(())
+    }
+}
+
+/// Storage Migration: Another Raw XDNS storage entry kill
+/// Storage Migration Details: 27-07-2023; v1.4.44-rc -> v1.4.45-rc
+/// Storage Migration: Vec-based registry entries -> BoundedVec
+/// Storage Migration Details: bounded XDNS storage ahead of permissionless registration
+pub struct KillXdnsRegistryV144AndBoundStorage<T>(PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for KillXdnsRegistryV144AndBoundStorage<T> {
+    fn on_runtime_upgrade() -> Weight {
+        frame_support::storage::unhashed::kill(&XDNS_REGISTRY_V144_KEY);
+        T::DbWeight::get()
+            .writes(1)
+            .saturating_add(Pallet::<T>::migrate_to_bounded_storage())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(_state: Vec<u8>) -> Result<(), TryRuntimeError> {
+        ensure!(
+            !frame_support::storage::unhashed::exists(&XDNS_REGISTRY_V144_KEY),
+            "XDNSRegistry entry of v1.4.44 left after its kill"
+        );
+        Pallet::<T>::ensure_registry_decodes()
+    }
+}
+
+/// Gateway record as stored on live networks, before security tiers, fee schedules, assets, ABI
+/// descriptors and versions were added to it. Allowed side effects are bounded by `migrate_to_bounded_storage`.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug)]
+pub struct GatewayRecordV0<AccountId, SideEffects = AllowedSideEffects> {
+    pub gateway_id: ChainId,
+    pub verification_vendor: GatewayVendor,
+    pub execution_vendor: ExecutionVendor,
+    pub codec: t3rn_abi::Codec,
+    pub registrant: Option<AccountId>,
+    pub escrow_account: Option<AccountId>,
+    pub allowed_side_effects: SideEffects,
+}
+
+impl<AccountId, SideEffects> GatewayRecordV0<AccountId, SideEffects> {
+    pub fn map_allowed_side_effects<Mapped>(
+        self,
+        map: impl FnOnce(SideEffects) -> Mapped,
+    ) -> GatewayRecordV0<AccountId, Mapped> {
+        GatewayRecordV0 {
+            gateway_id: self.gateway_id,
+            verification_vendor: self.verification_vendor,
+            execution_vendor: self.execution_vendor,
+            codec: self.codec,
+            registrant: self.registrant,
+            escrow_account: self.escrow_account,
+            allowed_side_effects: map(self.allowed_side_effects),
+        }
+    }
+}
+
+/// Storage of XDNS at the layouts it's found at on live networks, ahead of the migrations.
+pub mod v0 {
+    use super::*;
+    use frame_support::Identity;
+
+    #[frame_support::storage_alias]
+    pub type Gateways<T: Config> = StorageMap<
+        Pallet<T>,
+        Identity,
+        ChainId,
+        GatewayRecordV0<<T as frame_system::Config>::AccountId>,
+    >;
+}
+
+impl<AccountId> GatewayRecordV0<AccountId> {
+    pub fn into_tiered(
+        self,
+        assets: GatewayAssets,
+        abi_descriptors: GatewayAbiDescriptors,
+    ) -> GatewayRecord<AccountId> {
+        GatewayRecord {
+            gateway_id: self.gateway_id,
+            verification_vendor: self.verification_vendor,
+            execution_vendor: self.execution_vendor,
+            codec: self.codec,
+            registrant: self.registrant,
+            escrow_account: self.escrow_account,
+            security_tier: self.verification_vendor.default_security_tier(),
+            allowed_side_effects: self.allowed_side_effects,
+            fee_schedule: Default::default(),
+            assets,
+            abi_descriptors,
+            version: GATEWAY_RECORD_VERSION,
+        }
+    }
+}
+
+/// Storage Migration: GatewayRecord -> GatewayRecord with security tier, fee schedule, assets, ABI descriptors and version
+/// Storage Migration Details: 14-10-2026; tiered gateway records
+///     Gateways start at the default security tier of their verification vendor, with an empty
+///     fee schedule, the assets already linked to them in GatewayTokens and the default ABI
+///     descriptors of the SFX ABIs already enrolled on them in SFXABIRegistry. Overviews of the gateways are rebuilt after, re-linking each record to the
+///     light client of its verification vendor.
+pub struct GatewayRecordsToTieredLayout<T>(PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for GatewayRecordsToTieredLayout<T> {
+    fn on_runtime_upgrade() -> Weight {
+        let mut translated: u64 = 0;
+        let mut described: u64 = 0;
+        Gateways::<T>::translate::<GatewayRecordV0<T::AccountId>, _>(|gateway_id, record| {
+            translated += 1;
+            let abi_descriptors = Pallet::<T>::collect_abi_descriptors(&gateway_id, &record.codec);
+            described += abi_descriptors.len() as u64;
+            Some(record.into_tiered(GatewayTokens::<T>::get(gateway_id), abi_descriptors))
+        });
+
+        Pallet::<T>::process_overview(frame_system::Pallet::<T>::block_number());
+
+        // Each record, its tokens and described SFX ABIs are read and the record written, then the record and
+        // its tokens are read again rebuilding the overview store
+        T::DbWeight::get().reads_writes(4 * translated + described, translated + 1)
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+        let linked: Vec<(ChainId, bool)> = Gateways::<T>::iter_keys()
+            .map(|gateway_id| {
+                let has_light_client = T::Portal::get_latest_heartbeat(&gateway_id).is_ok();
+                (gateway_id, has_light_client)
+            })
+            .collect();
+        Ok(linked.encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+        let linked = <Vec<(ChainId, bool)>>::decode(&mut &state[..])
+            .map_err(|_| TryRuntimeError::Other("Gateway ids undecodable"))?;
+
+        // Records failing to translate are dropped, so all of them must decode as tiered ones
+        ensure!(
+            Gateways::<T>::iter_values().count() == linked.len(),
+            "Gateway records dropped while migrating to the tiered layout"
+        );
+        for (gateway_id, had_light_client) in linked {
+            let record = Gateways::<T>::get(gateway_id).ok_or(TryRuntimeError::Other(
+                "Gateway record lost in the migration",
+            ))?;
+            ensure!(
+                record.version == GATEWAY_RECORD_VERSION,
+                "Gateway record left at another layout version"
+            );
+            ensure!(
+                record.assets == GatewayTokens::<T>::get(gateway_id),
+                "Gateway record assets diverge from its linked tokens"
+            );
+            ensure!(
+                record.abi_descriptors
+                    == Pallet::<T>::collect_abi_descriptors(&gateway_id, &record.codec),
+                "Gateway record ABI descriptors diverge from its SFX ABIs"
+            );
+            ensure!(
+                !had_light_client || T::Portal::get_latest_heartbeat(&gateway_id).is_ok(),
+                "Gateway orphaned of its light client in the migration"
+            );
+        }
+        for gateway_id in AllGatewayIds::<T>::get() {
+            ensure!(
+                Gateways::<T>::contains_key(gateway_id),
+                "Gateway id left without its record"
+            );
+        }
+        Ok(())
+    }
+}
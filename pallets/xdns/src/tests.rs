@@ -19,7 +19,7 @@
 
 use super::*;
 use circuit_mock_runtime::{ExtBuilder, Portal, RuntimeOrigin as Origin, *};
-use codec::Decode;
+use codec::{Decode, Encode};
 
 use frame_support::pallet_prelude::Weight;
 
@@ -30,10 +30,14 @@ use t3rn_primitives::{
     circuit::SecurityLvl::{Escrow, Optimistic},
     clock::OnHookQueues,
     portal::Portal as PortalT,
-    xdns::{FullGatewayRecord, GatewayRecord, PalletAssetsOverlay, Xdns},
+    xdns::{
+        AllowedSideEffects, BondScalingFactors, FeeHint, FullGatewayRecord, GatewayAssets,
+        GatewayFeeSchedule, GatewayRecord, GatewayRecordChanges, PalletAssetsOverlay, SfxSchema,
+        Xdns, XtxCostEstimate, GATEWAY_RECORD_VERSION,
+    },
     EthereumToken, ExecutionVendor,
     ExecutionVendor::{Substrate, EVM},
-    FinalityVerifierActivity, GatewayActivity, GatewayVendor,
+    FinalityVerifierActivity, GatewayActivity, GatewaySecurityTier, GatewayVendor,
     GatewayVendor::{Ethereum, Kusama, Polkadot, Rococo},
     SpeedMode, SubstrateToken, TokenInfo, XDNSTopology,
 };
@@ -44,7 +48,10 @@ use t3rn_primitives::{
     GatewayVendor::{Attesters, Sepolia, XBI},
 };
 
-use t3rn_types::fsx::SecurityLvl;
+use t3rn_types::{
+    fsx::{SecurityLvl, TargetId},
+    sfx::Sfx4bId,
+};
 
 const DEFAULT_GATEWAYS_IN_STORAGE_COUNT: usize = 8;
 const STANDARD_SFX_ABI_COUNT: usize = 6;
@@ -148,6 +155,119 @@ fn reboot_self_gateway_populates_entry_if_does_not_exist_with_std_sfx() {
     });
 }
 
+#[test]
+fn resolves_default_abi_descriptors_against_gateway_codec() {
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            let tran_abi = pallet_xdns::StandardSFXABIs::<Runtime>::get(*b"tran").unwrap();
+
+            assert_eq!(
+                XDNS::get_default_abi_descriptor(&[3, 3, 3, 3], *b"tran"),
+                Ok(tran_abi.get_expected_ingress_descriptor(Scale))
+            );
+            assert_eq!(
+                XDNS::get_default_abi_descriptor(b"eth2", *b"tran"),
+                Ok(tran_abi.get_expected_ingress_descriptor(Rlp))
+            );
+            assert_eq!(XDNS::get_default_abi_descriptors(b"eth2").len(), 1);
+            assert_eq!(
+                XDNS::get_default_abi_descriptors(&[3, 3, 3, 3]).len(),
+                STANDARD_SFX_ABI_COUNT
+            );
+
+            assert_err!(
+                XDNS::get_default_abi_descriptor(b"eth2", *b"swap"),
+                pallet_xdns::Error::<Runtime>::SideEffectABINotFound
+            );
+            assert_err!(
+                XDNS::get_default_abi_descriptor(b"miss", *b"tran"),
+                pallet_xdns::Error::<Runtime>::XdnsRecordNotFound
+            );
+            assert!(XDNS::get_default_abi_descriptors(b"miss").is_empty());
+        });
+}
+
+#[test]
+fn default_abi_descriptors_are_stored_in_gateway_record_following_enrolled_abis() {
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            let tass_abi = pallet_xdns::StandardSFXABIs::<Runtime>::get(*b"tass").unwrap();
+            let described = |gateway_id: [u8; 4]| {
+                pallet_xdns::Gateways::<Runtime>::get(gateway_id)
+                    .unwrap()
+                    .abi_descriptors
+                    .iter()
+                    .map(|(sfx_4b_id, _)| *sfx_4b_id)
+                    .collect::<Vec<_>>()
+            };
+            assert_eq!(described(*b"eth2"), vec![*b"tran"]);
+
+            assert_ok!(XDNS::enroll_new_abi_to_selected_gateway(
+                Origin::root(),
+                *b"eth2",
+                *b"tass",
+                None,
+                None
+            ));
+            assert_eq!(described(*b"eth2"), vec![*b"tass", *b"tran"]);
+            assert_eq!(
+                XDNS::get_default_abi_descriptor(b"eth2", *b"tass"),
+                Ok(tass_abi.get_expected_ingress_descriptor(Rlp))
+            );
+
+            assert_ok!(XDNS::unroll_abi_of_selected_gateway(
+                Origin::root(),
+                *b"eth2",
+                *b"tass",
+            ));
+            assert_eq!(described(*b"eth2"), vec![*b"tran"]);
+            assert_err!(
+                XDNS::get_default_abi_descriptor(b"eth2", *b"tass"),
+                pallet_xdns::Error::<Runtime>::SideEffectABINotFound
+            );
+        });
+}
+
+#[test]
+fn rejects_xdns_mutations_from_origins_other_than_configured() {
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            assert_noop!(
+                XDNS::reboot_self_gateway(Origin::signed(ALICE), GatewayVendor::Rococo),
+                DispatchError::BadOrigin
+            );
+            assert_noop!(
+                XDNS::add_remote_order_address(
+                    Origin::signed(ALICE),
+                    [3, 3, 3, 3],
+                    H256::repeat_byte(1)
+                ),
+                DispatchError::BadOrigin
+            );
+            assert_noop!(
+                XDNS::unroll_abi_of_selected_gateway(Origin::signed(ALICE), [3, 3, 3, 3], *b"tran"),
+                DispatchError::BadOrigin
+            );
+            assert_noop!(
+                XDNS::unlink_token(Origin::signed(ALICE), [3, 3, 3, 3], 1),
+                DispatchError::BadOrigin
+            );
+            assert_noop!(
+                XDNS::add_escrow_account(Origin::signed(ALICE), [3, 3, 3, 3], ALICE),
+                DispatchError::BadOrigin
+            );
+        });
+}
+
 #[test]
 fn genesis_should_seed_circuit_gateway_polkadot_and_kusama_nodes() {
     ExtBuilder::default()
@@ -174,7 +294,19 @@ fn should_zip_xdns_topology_for_default_records_via_event() {
         .with_default_xdns_records()
         .build()
         .execute_with(|| {
-            assert_ok!(XDNS::zip_topology(Origin::signed(ALICE)));
+            assert_err!(
+                XDNS::zip_topology(
+                    Origin::signed(ALICE),
+                    DEFAULT_GATEWAYS_IN_STORAGE_COUNT as u32 - 1,
+                    0
+                ),
+                pallet_xdns::Error::<Runtime>::TopologyExceedsDeclaredBounds
+            );
+            assert_ok!(XDNS::zip_topology(
+                Origin::signed(ALICE),
+                DEFAULT_GATEWAYS_IN_STORAGE_COUNT as u32,
+                0
+            ));
             // Retrieve the event and check the zip
             let all_events = System::events();
             let last_system_event = all_events.last();
@@ -192,7 +324,7 @@ fn should_zip_xdns_topology_for_default_records_via_event() {
                 ),
             };
 
-            assert_eq!(xdns_topology_zip.encode(), hex!("20000000000200000000187472616e0102746173730104737761700103616c697101036365766d010a7761736d010a00010101010000000000187472616e0102746173730104737761700103616c697101036365766d010a7761736d010a00030303030000000000187472616e0102746173730104737761700103616c697101036365766d010a7761736d010a00050505050000000000187472616e0102746173730104737761700103616c697101036365766d010a7761736d010a00657468320301010000047472616e010200676174650200000000047472616e0102006b736d610100000000087472616e01027461737301040070646f740000000000087472616e01027461737301040000").to_vec())
+            assert_eq!(xdns_topology_zip.encode(), hex!("20000000000200000000187472616e0102746173730104737761700103616c697101036365766d010a7761736d010a0000010101010000000000187472616e0102746173730104737761700103616c697101036365766d010a7761736d010a0000030303030000000000187472616e0102746173730104737761700103616c697101036365766d010a7761736d010a0000050505050000000000187472616e0102746173730104737761700103616c697101036365766d010a7761736d010a0000657468320301010000047472616e01020000676174650200000000047472616e010200006b736d610100000000087472616e0102746173730104000070646f740000000000087472616e0102746173730104000000").to_vec())
         });
 }
 
@@ -206,7 +338,7 @@ fn should_unzip_xdns_topology_from_decoded_form_with_default_records() {
             // check that the topology is empty via all assets and all gateways ids
             assert_eq!(pallet_xdns::Gateways::<Runtime>::iter().count(), 0);
 
-            let encoded_xdns_topology = hex!("20000000000200000000187472616e0102746173730104737761700103616c697101036365766d010a7761736d010a00010101010000000000187472616e0102746173730104737761700103616c697101036365766d010a7761736d010a00030303030000000000187472616e0102746173730104737761700103616c697101036365766d010a7761736d010a00050505050000000000187472616e0102746173730104737761700103616c697101036365766d010a7761736d010a00657468320301010000047472616e010200676174650200000000047472616e0102006b736d610100000000087472616e01027461737301040070646f740000000000087472616e01027461737301040000").to_vec();
+            let encoded_xdns_topology = hex!("20000000000200000000187472616e0102746173730104737761700103616c697101036365766d010a7761736d010a0000010101010000000000187472616e0102746173730104737761700103616c697101036365766d010a7761736d010a0000030303030000000000187472616e0102746173730104737761700103616c697101036365766d010a7761736d010a0000050505050000000000187472616e0102746173730104737761700103616c697101036365766d010a7761736d010a0000657468320301010000047472616e01020000676174650200000000047472616e010200006b736d610100000000087472616e0102746173730104000070646f740000000000087472616e0102746173730104000000").to_vec();
             // Decode the topology
             let decoded_xdns_topology = XDNSTopology::<AccountId32>::decode(&mut &encoded_xdns_topology[..]).unwrap();
 
@@ -228,7 +360,7 @@ fn should_unzip_xdns_topology_from_encoded_form_with_default_records() {
             // check that the topology is empty via all assets and all gateways ids
             assert_eq!(pallet_xdns::Gateways::<Runtime>::iter().count(), 0);
 
-            let encoded_xdns_topology = hex!("20000000000200000000187472616e0102746173730104737761700103616c697101036365766d010a7761736d010a00010101010000000000187472616e0102746173730104737761700103616c697101036365766d010a7761736d010a00030303030000000000187472616e0102746173730104737761700103616c697101036365766d010a7761736d010a00050505050000000000187472616e0102746173730104737761700103616c697101036365766d010a7761736d010a00657468320301010000047472616e010200676174650200000000047472616e0102006b736d610100000000087472616e01027461737301040070646f740000000000087472616e01027461737301040000").to_vec();
+            let encoded_xdns_topology = hex!("20000000000200000000187472616e0102746173730104737761700103616c697101036365766d010a7761736d010a0000010101010000000000187472616e0102746173730104737761700103616c697101036365766d010a7761736d010a0000030303030000000000187472616e0102746173730104737761700103616c697101036365766d010a7761736d010a0000050505050000000000187472616e0102746173730104737761700103616c697101036365766d010a7761736d010a0000657468320301010000047472616e01020000676174650200000000047472616e010200006b736d610100000000087472616e0102746173730104000070646f740000000000087472616e0102746173730104000000").to_vec();
             // Unzip the topology
             assert_ok!(XDNS::unzip_topology(Origin::root(), None, Some(encoded_xdns_topology)));
 
@@ -340,6 +472,204 @@ fn should_allow_to_link_token_via_extrinsic_on_sudo_permission() {
     });
 }
 
+#[test]
+fn should_update_token_sys_props_on_registrant_or_sudo_permission() {
+    ExtBuilder::default().build().execute_with(|| {
+        add_self_as_base_gateway();
+
+        assert_ok!(XDNS::add_new_gateway(
+            *b"test",
+            GatewayVendor::Rococo,
+            ExecutionVendor::Substrate,
+            t3rn_abi::Codec::Scale,
+            Some(ALICE), // registrant
+            None,        // escrow_account
+            vec![],      // allowed_side_effects
+        ));
+
+        let token_id = u32::from_le_bytes(*b"test");
+        let token_props = |symbol: &[u8], decimals: u8| {
+            TokenInfo::Substrate(SubstrateToken {
+                id: 1,
+                symbol: symbol.to_vec(),
+                decimals,
+            })
+        };
+
+        assert_ok!(XDNS::register_new_token(
+            &Origin::root(),
+            token_id,
+            token_props(b"test", 1)
+        ));
+        assert_ok!(XDNS::link_token(
+            Origin::root(),
+            *b"test",
+            token_id,
+            token_props(b"test", 1)
+        ));
+
+        assert_noop!(
+            XDNS::update_token_sys_props(
+                Origin::signed(AccountId32::new([7; 32])),
+                *b"test",
+                token_id,
+                token_props(b"rbrd", 12)
+            ),
+            pallet_xdns::pallet::Error::<Runtime>::NotGatewayRegistrant
+        );
+
+        assert_noop!(
+            XDNS::update_token_sys_props(
+                Origin::signed(ALICE),
+                *b"test",
+                token_id,
+                TokenInfo::Ethereum(EthereumToken {
+                    decimals: 18,
+                    symbol: b"rbrd".to_vec(),
+                    address: Some([1; 20])
+                })
+            ),
+            pallet_xdns::pallet::Error::<Runtime>::TokenExecutionVendorMismatch
+        );
+
+        assert_noop!(
+            XDNS::update_token_sys_props(
+                Origin::root(),
+                *b"test",
+                token_id + 1,
+                token_props(b"rbrd", 12)
+            ),
+            pallet_xdns::pallet::Error::<Runtime>::TokenRecordNotFoundInGateway
+        );
+
+        assert_ok!(XDNS::update_token_sys_props(
+            Origin::signed(ALICE),
+            *b"test",
+            token_id,
+            token_props(b"rbrd", 12)
+        ));
+        assert_eq!(
+            pallet_xdns::Tokens::<Runtime>::get(token_id, *b"test").map(|t| t.token_props),
+            Some(token_props(b"rbrd", 12))
+        );
+
+        assert_ok!(XDNS::update_token_sys_props(
+            Origin::root(),
+            *b"test",
+            token_id,
+            token_props(b"rdnm", 18)
+        ));
+        assert_eq!(
+            pallet_xdns::Tokens::<Runtime>::get(token_id, *b"test").map(|t| t.token_props),
+            Some(token_props(b"rdnm", 18))
+        );
+        // the self-gateway record stays untouched
+        assert_eq!(
+            pallet_xdns::Tokens::<Runtime>::get(token_id, [3, 3, 3, 3]).map(|t| t.token_props),
+            Some(token_props(b"test", 1))
+        );
+    });
+}
+
+#[test]
+fn reserved_gateway_ids_are_only_registered_by_their_holder_and_refunded_on_release() {
+    use frame_support::traits::{Currency, Get, ReservableCurrency};
+
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .build()
+        .execute_with(|| {
+            let deposit = <Runtime as pallet_xdns::Config>::GatewayIdReservationDeposit::get();
+            let bob = AccountId32::new([2; 32]);
+            let _ = Balances::deposit_creating(&ALICE, deposit * 2);
+
+            assert_ok!(XDNS::reserve_gateway_id(Origin::signed(ALICE), *b"test"));
+            assert_eq!(Balances::reserved_balance(&ALICE), deposit);
+
+            // already reserved
+            assert_noop!(
+                XDNS::reserve_gateway_id(Origin::signed(bob.clone()), *b"test"),
+                pallet_xdns::pallet::Error::<Runtime>::GatewayIdAlreadyReserved
+            );
+
+            // only the holder can register the gateway
+            assert_noop!(
+                XDNS::add_new_gateway(
+                    *b"test",
+                    GatewayVendor::Rococo,
+                    ExecutionVendor::Substrate,
+                    t3rn_abi::Codec::Scale,
+                    Some(bob.clone()),
+                    None,
+                    vec![],
+                ),
+                pallet_xdns::pallet::Error::<Runtime>::GatewayIdReservedForAnother
+            );
+            assert_noop!(
+                XDNS::release_gateway_id(Origin::signed(bob.clone()), *b"test"),
+                pallet_xdns::pallet::Error::<Runtime>::GatewayIdReservedForAnother
+            );
+            assert_ok!(XDNS::add_new_gateway(
+                *b"test",
+                GatewayVendor::Rococo,
+                ExecutionVendor::Substrate,
+                t3rn_abi::Codec::Scale,
+                Some(ALICE),
+                None,
+                vec![],
+            ));
+
+            // deposit backs the registered gateway until it's purged
+            assert_noop!(
+                XDNS::release_gateway_id(Origin::signed(ALICE), *b"test"),
+                pallet_xdns::pallet::Error::<Runtime>::GatewayIdInUse
+            );
+            assert_ok!(XDNS::purge_gateway_record(Origin::root(), ALICE, *b"test"));
+            assert_eq!(Balances::reserved_balance(&ALICE), 0);
+            assert_eq!(XDNS::gateway_id_reservations(*b"test"), None);
+
+            // governance pre-reserves well-known ids, refunding the replaced reservation
+            assert_ok!(XDNS::reserve_gateway_id(Origin::signed(ALICE), *b"eth!"));
+            assert_ok!(XDNS::force_reserve_gateway_id(
+                Origin::root(),
+                *b"eth!",
+                None
+            ));
+            assert_eq!(Balances::reserved_balance(&ALICE), 0);
+            assert_noop!(
+                XDNS::add_new_gateway(
+                    *b"eth!",
+                    GatewayVendor::Ethereum,
+                    ExecutionVendor::EVM,
+                    t3rn_abi::Codec::Rlp,
+                    Some(ALICE),
+                    None,
+                    vec![],
+                ),
+                pallet_xdns::pallet::Error::<Runtime>::GatewayIdReservedForAnother
+            );
+            assert_ok!(XDNS::add_new_gateway(
+                *b"eth!",
+                GatewayVendor::Ethereum,
+                ExecutionVendor::EVM,
+                t3rn_abi::Codec::Rlp,
+                None,
+                None,
+                vec![],
+            ));
+            assert_noop!(
+                XDNS::release_gateway_id(Origin::signed(ALICE), *b"eth!"),
+                pallet_xdns::pallet::Error::<Runtime>::GatewayIdReservedForAnother
+            );
+
+            // releasing an unregistered reservation refunds the deposit
+            assert_ok!(XDNS::reserve_gateway_id(Origin::signed(ALICE), *b"dot!"));
+            assert_ok!(XDNS::release_gateway_id(Origin::signed(ALICE), *b"dot!"));
+            assert_eq!(Balances::reserved_balance(&ALICE), 0);
+            assert_eq!(Balances::free_balance(&ALICE), deposit * 2);
+        });
+}
+
 #[test]
 fn should_add_a_new_xdns_and_record_and_token_if_it_doesnt_exist() {
     ExtBuilder::default().build().execute_with(|| {
@@ -487,6 +817,52 @@ fn should_enroll_and_unroll_new_abi_to_selected_gateway() {
         });
 }
 
+#[test]
+fn fetch_sfx_schemas_lists_standard_and_custom_abis_with_their_gateways() {
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            let tran_sfx_abi = pallet_xdns::StandardSFXABIs::<Runtime>::get(b"tran").unwrap();
+            let schemas = XDNS::fetch_sfx_schemas();
+            assert_eq!(schemas.len(), 10);
+
+            // Gateways enrolled with the standard ABI share its schema, regardless of their prefix memo
+            let tran_schema = schemas
+                .iter()
+                .find(|schema| schema.sfx_4b_id == *b"tran")
+                .unwrap();
+            assert!(tran_schema.has_same_abi(&SfxSchema::new(*b"tran", tran_sfx_abi.clone())));
+            assert!(tran_schema.gateways.contains(b"gate"));
+
+            let mut custom_tran_sfx_abi = tran_sfx_abi;
+            custom_tran_sfx_abi.args_names.pop();
+            assert_ok!(XDNS::enroll_new_abi_to_selected_gateway(
+                Origin::root(),
+                *b"gate",
+                *b"tran",
+                Some(custom_tran_sfx_abi.clone()),
+                None
+            ));
+
+            let schemas = XDNS::fetch_sfx_schemas();
+            assert_eq!(schemas.len(), 11);
+            let tran_schemas: Vec<&SfxSchema> = schemas
+                .iter()
+                .filter(|schema| schema.sfx_4b_id == *b"tran")
+                .collect();
+            assert!(!tran_schemas[0].gateways.contains(b"gate"));
+            assert_eq!(
+                tran_schemas[1],
+                &SfxSchema {
+                    gateways: vec![*b"gate"],
+                    ..SfxSchema::new(*b"tran", custom_tran_sfx_abi)
+                }
+            );
+        });
+}
+
 #[test]
 fn should_not_add_a_new_xdns_record_if_it_already_exists() {
     ExtBuilder::default()
@@ -840,14 +1216,19 @@ fn xdns_returns_full_gateway_record() {
                             codec: Scale,
                             registrant: None,
                             escrow_account: None,
-                            allowed_side_effects: vec![
+                            allowed_side_effects: AllowedSideEffects::truncate_from(vec![
                                 ([116, 114, 97, 110], Some(2)),
                                 ([116, 97, 115, 115], Some(4)),
                                 ([115, 119, 97, 112], Some(3)),
                                 ([97, 108, 105, 113], Some(3)),
                                 ([99, 101, 118, 109], Some(10)),
                                 ([119, 97, 115, 109], Some(10)),
-                            ]
+                            ]),
+                            security_tier: GatewaySecurityTier::Finalized,
+                            fee_schedule: Default::default(),
+                            assets: Default::default(),
+                            abi_descriptors: XDNS::collect_abi_descriptors(&[0, 0, 0, 0], &Scale),
+                            version: GATEWAY_RECORD_VERSION,
                         },
                         tokens: vec![]
                     },
@@ -859,14 +1240,19 @@ fn xdns_returns_full_gateway_record() {
                             codec: Scale,
                             registrant: None,
                             escrow_account: None,
-                            allowed_side_effects: vec![
+                            allowed_side_effects: AllowedSideEffects::truncate_from(vec![
                                 ([116, 114, 97, 110], Some(2)),
                                 ([116, 97, 115, 115], Some(4)),
                                 ([115, 119, 97, 112], Some(3)),
                                 ([97, 108, 105, 113], Some(3)),
                                 ([99, 101, 118, 109], Some(10)),
                                 ([119, 97, 115, 109], Some(10)),
-                            ]
+                            ]),
+                            security_tier: GatewaySecurityTier::Finalized,
+                            fee_schedule: Default::default(),
+                            assets: Default::default(),
+                            abi_descriptors: XDNS::collect_abi_descriptors(&[1, 1, 1, 1], &Scale),
+                            version: GATEWAY_RECORD_VERSION,
                         },
                         tokens: vec![]
                     },
@@ -878,14 +1264,19 @@ fn xdns_returns_full_gateway_record() {
                             codec: Scale,
                             registrant: None,
                             escrow_account: None,
-                            allowed_side_effects: vec![
+                            allowed_side_effects: AllowedSideEffects::truncate_from(vec![
                                 ([116, 114, 97, 110], Some(2)),
                                 ([116, 97, 115, 115], Some(4)),
                                 ([115, 119, 97, 112], Some(3)),
                                 ([97, 108, 105, 113], Some(3)),
                                 ([99, 101, 118, 109], Some(10)),
                                 ([119, 97, 115, 109], Some(10)),
-                            ]
+                            ]),
+                            security_tier: GatewaySecurityTier::Finalized,
+                            fee_schedule: Default::default(),
+                            assets: Default::default(),
+                            abi_descriptors: XDNS::collect_abi_descriptors(&[3, 3, 3, 3], &Scale),
+                            version: GATEWAY_RECORD_VERSION,
                         },
                         tokens: vec![]
                     },
@@ -897,14 +1288,19 @@ fn xdns_returns_full_gateway_record() {
                             codec: Scale,
                             registrant: None,
                             escrow_account: None,
-                            allowed_side_effects: vec![
+                            allowed_side_effects: AllowedSideEffects::truncate_from(vec![
                                 ([116, 114, 97, 110], Some(2)),
                                 ([116, 97, 115, 115], Some(4)),
                                 ([115, 119, 97, 112], Some(3)),
                                 ([97, 108, 105, 113], Some(3)),
                                 ([99, 101, 118, 109], Some(10)),
                                 ([119, 97, 115, 109], Some(10)),
-                            ]
+                            ]),
+                            security_tier: GatewaySecurityTier::Finalized,
+                            fee_schedule: Default::default(),
+                            assets: Default::default(),
+                            abi_descriptors: XDNS::collect_abi_descriptors(&[5, 5, 5, 5], &Scale),
+                            version: GATEWAY_RECORD_VERSION,
                         },
                         tokens: vec![]
                     },
@@ -916,7 +1312,18 @@ fn xdns_returns_full_gateway_record() {
                             codec: Rlp,
                             registrant: None,
                             escrow_account: None,
-                            allowed_side_effects: vec![([116, 114, 97, 110], Some(2))]
+                            allowed_side_effects: AllowedSideEffects::truncate_from(vec![(
+                                [116, 114, 97, 110],
+                                Some(2)
+                            )]),
+                            security_tier: GatewaySecurityTier::Finalized,
+                            fee_schedule: Default::default(),
+                            assets: Default::default(),
+                            abi_descriptors: XDNS::collect_abi_descriptors(
+                                &[101, 116, 104, 50],
+                                &Rlp
+                            ),
+                            version: GATEWAY_RECORD_VERSION,
                         },
                         tokens: vec![]
                     },
@@ -928,7 +1335,18 @@ fn xdns_returns_full_gateway_record() {
                             codec: Scale,
                             registrant: None,
                             escrow_account: None,
-                            allowed_side_effects: vec![([116, 114, 97, 110], Some(2))]
+                            allowed_side_effects: AllowedSideEffects::truncate_from(vec![(
+                                [116, 114, 97, 110],
+                                Some(2)
+                            )]),
+                            security_tier: GatewaySecurityTier::Finalized,
+                            fee_schedule: Default::default(),
+                            assets: Default::default(),
+                            abi_descriptors: XDNS::collect_abi_descriptors(
+                                &[103, 97, 116, 101],
+                                &Scale
+                            ),
+                            version: GATEWAY_RECORD_VERSION,
                         },
                         tokens: vec![]
                     },
@@ -940,10 +1358,18 @@ fn xdns_returns_full_gateway_record() {
                             codec: Scale,
                             registrant: None,
                             escrow_account: None,
-                            allowed_side_effects: vec![
+                            allowed_side_effects: AllowedSideEffects::truncate_from(vec![
                                 ([116, 114, 97, 110], Some(2)),
                                 ([116, 97, 115, 115], Some(4))
-                            ]
+                            ]),
+                            security_tier: GatewaySecurityTier::Finalized,
+                            fee_schedule: Default::default(),
+                            assets: Default::default(),
+                            abi_descriptors: XDNS::collect_abi_descriptors(
+                                &[107, 115, 109, 97],
+                                &Scale
+                            ),
+                            version: GATEWAY_RECORD_VERSION,
                         },
                         tokens: vec![]
                     },
@@ -955,10 +1381,18 @@ fn xdns_returns_full_gateway_record() {
                             codec: Scale,
                             registrant: None,
                             escrow_account: None,
-                            allowed_side_effects: vec![
+                            allowed_side_effects: AllowedSideEffects::truncate_from(vec![
                                 ([116, 114, 97, 110], Some(2)),
                                 ([116, 97, 115, 115], Some(4))
-                            ]
+                            ]),
+                            security_tier: GatewaySecurityTier::Finalized,
+                            fee_schedule: Default::default(),
+                            assets: Default::default(),
+                            abi_descriptors: XDNS::collect_abi_descriptors(
+                                &[112, 100, 111, 116],
+                                &Scale
+                            ),
+                            version: GATEWAY_RECORD_VERSION,
                         },
                         tokens: vec![]
                     }
@@ -1852,227 +2286,1803 @@ fn xdns_overview_returns_activity_for_all_registered_but_not_active_after_turnin
 }
 
 #[test]
-fn test_storage_migration_v140_to_v150_for_standard_side_effects_to_standard_sfx_abi() {
-    type EventSignature = Vec<u8>;
-    use t3rn_abi::SFXAbi;
-    use t3rn_types::gateway::{CryptoAlgo, HasherAlgo};
-
-    #[derive(PartialEq, Clone, Encode, Decode, Eq, Hash, Debug)]
-    #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
-    pub enum Type {
-        Address(u16),
-        DynamicAddress,
-        Bool,
-        Int(u16),
-        Uint(u16),
-        /// where u8 is bytes length
-        Bytes(u8),
-        DynamicBytes,
-        String,
-        Enum(u8),
-        Struct(u8),
-        Mapping(Box<Type>, Box<Type>),
-        Contract,
-        Ref(Box<Type>),
-        Option(Box<Type>),
-        OptionalInsurance,
-        OptionalReward,
-        StorageRef(Box<Type>),
-        /// There is no way to declare value in Solidity (should there be?)
-        Value,
-        /// DynamicBytes and String are lowered to a vector.
-        Slice,
-        Hasher(HasherAlgo, u16),
-        Crypto(CryptoAlgo),
-    }
+fn links_and_unlinks_asset_correspondence_in_both_directions_on_sudo_permission() {
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            assert_eq!(
+                XDNS::get_corresponding_asset(&[3, 3, 3, 3], 1, b"eth2"),
+                None
+            );
 
-    #[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, Default)]
-    #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
-    pub struct SideEffectInterface {
-        pub id: [u8; 4],
-        pub name: SideEffectName,
-        pub argument_abi: Vec<Type>,
-        pub argument_to_state_mapper: Vec<EventSignature>,
-        pub confirm_events: Vec<EventSignature>,
-        pub escrowed_events: Vec<EventSignature>,
-        pub commit_events: Vec<EventSignature>,
-        pub revert_events: Vec<EventSignature>,
-    }
+            assert_ok!(XDNS::link_asset_correspondence(
+                Origin::root(),
+                [3, 3, 3, 3],
+                1,
+                *b"eth2",
+                2,
+            ));
+
+            assert_eq!(
+                XDNS::get_corresponding_asset(&[3, 3, 3, 3], 1, b"eth2"),
+                Some(2)
+            );
+            assert_eq!(
+                XDNS::get_corresponding_asset(b"eth2", 2, &[3, 3, 3, 3]),
+                Some(1)
+            );
+            assert!(XDNS::are_assets_corresponding(&[3, 3, 3, 3], 1, b"eth2", 2));
+            assert!(!XDNS::are_assets_corresponding(
+                &[3, 3, 3, 3],
+                1,
+                b"eth2",
+                1
+            ));
+
+            assert_ok!(XDNS::unlink_asset_correspondence(
+                Origin::root(),
+                *b"eth2",
+                2,
+                [3, 3, 3, 3],
+            ));
+
+            assert_eq!(
+                XDNS::get_corresponding_asset(&[3, 3, 3, 3], 1, b"eth2"),
+                None
+            );
+            assert_eq!(
+                XDNS::get_corresponding_asset(b"eth2", 2, &[3, 3, 3, 3]),
+                None
+            );
+        });
+}
+
+#[test]
+fn relinking_asset_correspondence_drops_the_stale_reverse_entries() {
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            assert_ok!(XDNS::link_asset_correspondence(
+                Origin::root(),
+                [3, 3, 3, 3],
+                1,
+                *b"eth2",
+                2,
+            ));
+            assert_ok!(XDNS::link_asset_correspondence(
+                Origin::root(),
+                [3, 3, 3, 3],
+                1,
+                *b"eth2",
+                3,
+            ));
+
+            assert_eq!(
+                XDNS::get_corresponding_asset(&[3, 3, 3, 3], 1, b"eth2"),
+                Some(3)
+            );
+            assert_eq!(
+                XDNS::get_corresponding_asset(b"eth2", 3, &[3, 3, 3, 3]),
+                Some(1)
+            );
+            assert_eq!(
+                XDNS::get_corresponding_asset(b"eth2", 2, &[3, 3, 3, 3]),
+                None
+            );
+
+            // Relinking the counterpart side drops the previous forward entry
+            assert_ok!(XDNS::link_asset_correspondence(
+                Origin::root(),
+                *b"eth2",
+                3,
+                [3, 3, 3, 3],
+                4,
+            ));
+            assert_eq!(
+                XDNS::get_corresponding_asset(&[3, 3, 3, 3], 1, b"eth2"),
+                None
+            );
+            assert_eq!(
+                XDNS::get_corresponding_asset(&[3, 3, 3, 3], 4, b"eth2"),
+                Some(3)
+            );
+        });
+}
+
+#[test]
+fn asset_correspondence_resolves_to_same_asset_on_the_same_gateway() {
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            assert_eq!(
+                XDNS::get_corresponding_asset(&[3, 3, 3, 3], 1, &[3, 3, 3, 3]),
+                Some(1)
+            );
+        });
+}
+
+#[test]
+fn does_not_link_asset_correspondence_on_invalid_input() {
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            assert_noop!(
+                XDNS::link_asset_correspondence(
+                    Origin::signed(ALICE),
+                    [3, 3, 3, 3],
+                    1,
+                    *b"eth2",
+                    2
+                ),
+                DispatchError::BadOrigin
+            );
+            assert_noop!(
+                XDNS::link_asset_correspondence(Origin::root(), [3, 3, 3, 3], 1, [3, 3, 3, 3], 2),
+                pallet_xdns::Error::<Runtime>::AssetCorrespondenceOnSameGateway
+            );
+            assert_noop!(
+                XDNS::link_asset_correspondence(Origin::root(), [3, 3, 3, 3], 1, *b"miss", 2),
+                pallet_xdns::Error::<Runtime>::GatewayRecordNotFound
+            );
+            assert_noop!(
+                XDNS::unlink_asset_correspondence(Origin::root(), [3, 3, 3, 3], 1, *b"eth2"),
+                pallet_xdns::Error::<Runtime>::AssetCorrespondenceNotFound
+            );
+        });
+}
+
+#[test]
+fn purging_gateway_record_removes_its_asset_correspondences() {
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            assert_ok!(XDNS::link_asset_correspondence(
+                Origin::root(),
+                [3, 3, 3, 3],
+                1,
+                *b"gate",
+                2,
+            ));
+            assert_ok!(XDNS::link_asset_correspondence(
+                Origin::root(),
+                [3, 3, 3, 3],
+                1,
+                *b"eth2",
+                3,
+            ));
+
+            assert_ok!(XDNS::purge_gateway_record(Origin::root(), ALICE, *b"gate"));
+
+            assert_eq!(
+                XDNS::get_corresponding_asset(&[3, 3, 3, 3], 1, b"gate"),
+                None
+            );
+            assert_eq!(
+                XDNS::get_corresponding_asset(b"gate", 2, &[3, 3, 3, 3]),
+                None
+            );
+            assert_eq!(
+                XDNS::get_corresponding_asset(&[3, 3, 3, 3], 1, b"eth2"),
+                Some(3)
+            );
+            assert_eq!(
+                pallet_xdns::AssetCorrespondenceCount::<Runtime>::get([3, 3, 3, 3]),
+                1
+            );
+            assert_eq!(
+                pallet_xdns::AssetCorrespondenceCount::<Runtime>::get(*b"gate"),
+                0
+            );
+        });
+}
+
+#[test]
+fn linking_asset_correspondences_is_capped_per_gateway() {
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            for asset_id in 0..MAX_ASSET_CORRESPONDENCES_PER_GATEWAY {
+                assert_ok!(XDNS::link_asset_correspondence(
+                    Origin::root(),
+                    [3, 3, 3, 3],
+                    asset_id,
+                    *b"gate",
+                    asset_id,
+                ));
+            }
+
+            assert_noop!(
+                XDNS::link_asset_correspondence(
+                    Origin::root(),
+                    [3, 3, 3, 3],
+                    MAX_ASSET_CORRESPONDENCES_PER_GATEWAY,
+                    *b"eth2",
+                    MAX_ASSET_CORRESPONDENCES_PER_GATEWAY,
+                ),
+                pallet_xdns::Error::<Runtime>::TooManyAssetCorrespondences
+            );
+
+            // relinking an asset replaces its correspondence instead of adding one
+            assert_ok!(XDNS::link_asset_correspondence(
+                Origin::root(),
+                [3, 3, 3, 3],
+                0,
+                *b"gate",
+                1_000_000,
+            ));
+            assert_eq!(
+                pallet_xdns::AssetCorrespondenceCount::<Runtime>::get([3, 3, 3, 3]),
+                MAX_ASSET_CORRESPONDENCES_PER_GATEWAY
+            );
+        });
+}
+
+#[test]
+fn security_tier_defaults_per_vendor_and_caps_max_security_lvl() {
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            assert_eq!(
+                XDNS::get_gateway_security_tier(&[1, 1, 1, 1]),
+                Ok(GatewaySecurityTier::Finalized)
+            );
+            assert_err!(
+                XDNS::get_gateway_security_tier(b"none"),
+                pallet_xdns::Error::<Runtime>::GatewayRecordNotFound
+            );
+
+            assert_ok!(XDNS::add_escrow_account(
+                Origin::root(),
+                [1, 1, 1, 1],
+                AccountId32::new([1; 32])
+            ));
+            assert_eq!(XDNS::get_gateway_max_security_lvl(&[1, 1, 1, 1]), Escrow);
+
+            assert_noop!(
+                XDNS::set_gateway_security_tier(
+                    Origin::signed(ALICE),
+                    [1, 1, 1, 1],
+                    GatewaySecurityTier::OptimisticOnly
+                ),
+                DispatchError::BadOrigin
+            );
+            assert_noop!(
+                XDNS::set_gateway_security_tier(
+                    Origin::root(),
+                    *b"none",
+                    GatewaySecurityTier::OptimisticOnly
+                ),
+                pallet_xdns::Error::<Runtime>::GatewayRecordNotFound
+            );
+            assert_ok!(XDNS::set_gateway_security_tier(
+                Origin::root(),
+                [1, 1, 1, 1],
+                GatewaySecurityTier::OptimisticOnly
+            ));
+
+            assert_eq!(
+                XDNS::get_gateway_security_tier(&[1, 1, 1, 1]),
+                Ok(GatewaySecurityTier::OptimisticOnly)
+            );
+            assert_eq!(
+                XDNS::get_gateway_max_security_lvl(&[1, 1, 1, 1]),
+                Optimistic
+            );
+        });
+}
+
+#[test]
+fn gateway_security_tier_update_emits_one_event_and_is_kept_on_reregistration() {
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            let xdns_events = || {
+                System::events()
+                    .into_iter()
+                    .filter_map(|record| match record.event {
+                        RuntimeEvent::XDNS(event) => Some(event),
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>()
+            };
+
+            System::reset_events();
+            assert_ok!(XDNS::set_gateway_security_tier(
+                Origin::root(),
+                [1, 1, 1, 1],
+                GatewaySecurityTier::Rational
+            ));
+            assert_eq!(
+                xdns_events(),
+                vec![pallet_xdns::Event::<Runtime>::GatewaySecurityTierUpdated(
+                    [1, 1, 1, 1],
+                    GatewaySecurityTier::Rational
+                )]
+            );
+
+            // Setting the same tier again changes nothing
+            System::reset_events();
+            assert_ok!(XDNS::set_gateway_security_tier(
+                Origin::root(),
+                [1, 1, 1, 1],
+                GatewaySecurityTier::Rational
+            ));
+            assert_eq!(xdns_events(), vec![]);
+
+            let record = pallet_xdns::Gateways::<Runtime>::get([1, 1, 1, 1]).unwrap();
+            assert_ok!(XDNS::add_new_gateway(
+                [1, 1, 1, 1],
+                record.verification_vendor,
+                record.execution_vendor,
+                record.codec,
+                record.registrant,
+                record.escrow_account,
+                record.allowed_side_effects.into_inner(),
+            ));
+            assert_eq!(
+                XDNS::get_gateway_security_tier(&[1, 1, 1, 1]),
+                Ok(GatewaySecurityTier::Rational)
+            );
+        });
+}
+
+#[test]
+fn best_gateway_for_ranks_active_gateways_by_security_tier_and_fee_schedule() {
+    use circuit_mock_runtime::Attesters;
+    use t3rn_primitives::attesters::AttestersWriteApi;
+
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .with_default_attestation_targets()
+        .build()
+        .execute_with(|| {
+            let asset_id = u32::from_le_bytes(*b"test");
+            let token_info = TokenInfo::Substrate(SubstrateToken {
+                id: 1,
+                symbol: b"test".to_vec(),
+                decimals: 1,
+            });
+
+            assert_ok!(XDNS::register_new_token(
+                &Origin::root(),
+                asset_id,
+                token_info.clone()
+            ));
+
+            assert_eq!(XDNS::best_gateway_for(b"tran", asset_id), None);
+
+            for gateway_id in [[1, 1, 1, 1], [3, 3, 3, 3], [5, 5, 5, 5]] {
+                assert_ok!(XDNS::link_token_to_gateway(
+                    asset_id,
+                    gateway_id,
+                    token_info.clone()
+                ));
+            }
+
+            assert_ok!(XDNS::add_escrow_account(
+                Origin::root(),
+                [1, 1, 1, 1],
+                AccountId32::new([1; 32])
+            ));
+            Attesters::force_activate_target(Origin::root(), [1, 1, 1, 1]).unwrap();
+            Attesters::on_initialize(System::block_number());
+
+            for (gateway_id, security_tier, base_fee) in [
+                ([1, 1, 1, 1], GatewaySecurityTier::Rational, 10),
+                ([3, 3, 3, 3], GatewaySecurityTier::Finalized, 100),
+                ([5, 5, 5, 5], GatewaySecurityTier::Finalized, 300),
+            ] {
+                assert_ok!(XDNS::set_gateway_security_tier(
+                    Origin::root(),
+                    gateway_id,
+                    security_tier
+                ));
+                assert_ok!(XDNS::set_gateway_fee_schedule(
+                    Origin::root(),
+                    gateway_id,
+                    GatewayFeeSchedule {
+                        base_fee,
+                        fee_per_byte: 1,
+                    }
+                ));
+            }
+            // Estimates ordering the other way round are not what gateways are ranked by
+            pallet_xdns::PerTargetAssetEstimates::<Runtime>::insert(
+                [1, 1, 1, 1],
+                (asset_id, XDNS::get_self_token_id()),
+                1,
+            );
+            pallet_xdns::PerTargetAssetEstimates::<Runtime>::insert(
+                [3, 3, 3, 3],
+                (asset_id, XDNS::get_self_token_id()),
+                200,
+            );
+            pallet_xdns::PerTargetAssetEstimates::<Runtime>::insert(
+                [5, 5, 5, 5],
+                (asset_id, XDNS::get_self_token_id()),
+                100,
+            );
+
+            assert_eq!(
+                XDNS::rank_gateways_for(b"tran", asset_id),
+                vec![[3, 3, 3, 3], [5, 5, 5, 5], [1, 1, 1, 1]]
+            );
+            assert_eq!(
+                XDNS::best_gateway_for(b"tran", asset_id),
+                Some([3, 3, 3, 3])
+            );
+            assert_eq!(XDNS::best_gateway_for(b"mult", asset_id), None);
+        });
+}
+
+#[test]
+fn override_gateway_rejects_allowed_side_effects_over_the_bound() {
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .build()
+        .execute_with(|| {
+            let max_allowed_side_effects =
+                t3rn_primitives::xdns::MAX_ALLOWED_SIDE_EFFECTS_PER_GATEWAY as usize;
+
+            assert_noop!(
+                XDNS::override_gateway(
+                    *b"gate",
+                    GatewayVendor::Rococo,
+                    ExecutionVendor::Substrate,
+                    Scale,
+                    None,
+                    None,
+                    vec![(*b"tran", Some(2)); max_allowed_side_effects + 1],
+                ),
+                pallet_xdns::Error::<Runtime>::TooManyAllowedSideEffects
+            );
+
+            assert_ok!(XDNS::override_gateway(
+                *b"gate",
+                GatewayVendor::Rococo,
+                ExecutionVendor::Substrate,
+                Scale,
+                None,
+                None,
+                vec![(*b"tran", Some(2)); max_allowed_side_effects],
+            ));
+        });
+}
+
+#[test]
+fn migrate_to_bounded_storage_truncates_registry_lists_over_the_bound() {
+    use frame_support::storage::unhashed;
+
+    ExtBuilder::default().build().execute_with(|| {
+        let oversized_gateway_ids: Vec<[u8; 4]> = (0..pallet_xdns::MAX_GATEWAYS + 10)
+            .map(|i| i.to_le_bytes())
+            .collect();
+        unhashed::put(
+            &pallet_xdns::AllGatewayIds::<Runtime>::hashed_key(),
+            &oversized_gateway_ids,
+        );
+        unhashed::put(
+            &pallet_xdns::GatewayTokens::<Runtime>::hashed_key_for(*b"gate"),
+            &vec![1u32, 2u32],
+        );
+        let oversized_side_effects: Vec<([u8; 4], Option<u8>)> = (0
+            ..t3rn_primitives::xdns::MAX_ALLOWED_SIDE_EFFECTS_PER_GATEWAY + 2)
+            .map(|i| (i.to_le_bytes(), Some(i as u8)))
+            .collect();
+        let unbounded_record = crate::migrations::GatewayRecordV0::<AccountId, _> {
+            gateway_id: *b"gate",
+            verification_vendor: Rococo,
+            execution_vendor: Substrate,
+            codec: Scale,
+            registrant: None,
+            escrow_account: None,
+            allowed_side_effects: oversized_side_effects.clone(),
+        };
+        unhashed::put(
+            &pallet_xdns::Gateways::<Runtime>::hashed_key_for(*b"gate"),
+            &unbounded_record,
+        );
+
+        XDNS::migrate_to_bounded_storage();
+
+        assert_eq!(
+            XDNS::all_gateway_ids().len(),
+            pallet_xdns::MAX_GATEWAYS as usize
+        );
+        assert_eq!(
+            XDNS::all_gateway_ids().to_vec(),
+            oversized_gateway_ids[..pallet_xdns::MAX_GATEWAYS as usize].to_vec()
+        );
+        assert_eq!(XDNS::gateway_tokens(*b"gate"), vec![1u32, 2u32]);
+        assert_eq!(
+            crate::migrations::v0::Gateways::<Runtime>::get(*b"gate"),
+            Some(unbounded_record.map_allowed_side_effects(|_| {
+                AllowedSideEffects::truncate_from(
+                    oversized_side_effects
+                        [..t3rn_primitives::xdns::MAX_ALLOWED_SIDE_EFFECTS_PER_GATEWAY as usize]
+                        .to_vec(),
+                )
+            }))
+        );
+    });
+}
+
+#[test]
+fn gateway_activity_stats_count_sfx_outcomes_and_halve_every_window() {
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            for _ in 0..4 {
+                XDNS::note_sfx_targeted(b"gate");
+            }
+            for _ in 0..3 {
+                XDNS::note_sfx_confirmed(b"gate");
+            }
+            XDNS::note_sfx_reverted(b"gate");
+            // unknown gateways aren't tracked
+            XDNS::note_sfx_targeted(b"miss");
+
+            let stats = XDNS::get_gateway_activity_stats(b"gate");
+            assert_eq!(
+                stats,
+                t3rn_primitives::xdns::GatewayActivityStats {
+                    headers_accepted: 0,
+                    sfx_targeted: 4,
+                    sfx_confirmed: 3,
+                    sfx_reverted: 1,
+                }
+            );
+            assert_eq!(stats.revert_rate(), sp_runtime::Percent::from_percent(25));
+            assert_eq!(
+                XDNS::get_gateway_activity_stats(b"miss"),
+                Default::default()
+            );
+
+            XDNS::roll_activity_stats_window(pallet_xdns::ACTIVITY_STATS_WINDOW - 1);
+            assert_eq!(XDNS::get_gateway_activity_stats(b"gate").sfx_targeted, 4);
+
+            XDNS::roll_activity_stats_window(pallet_xdns::ACTIVITY_STATS_WINDOW);
+            let stats = XDNS::get_gateway_activity_stats(b"gate");
+            assert_eq!(stats.sfx_targeted, 2);
+            assert_eq!(stats.sfx_confirmed, 1);
+            assert_eq!(stats.sfx_reverted, 0);
+            assert_eq!(
+                pallet_xdns::ActivityStatsWindowStart::<Runtime>::get(),
+                pallet_xdns::ACTIVITY_STATS_WINDOW
+            );
+        });
+}
+
+#[test]
+fn gateway_record_mutations_emit_typed_events_with_changed_fields() {
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .build()
+        .execute_with(|| {
+            System::set_block_number(1);
+            let xdns_events = || {
+                System::events()
+                    .into_iter()
+                    .filter_map(|record| match record.event {
+                        RuntimeEvent::XDNS(event) => Some(event),
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>()
+            };
+
+            assert_ok!(XDNS::add_new_gateway(
+                *b"gate",
+                GatewayVendor::Rococo,
+                ExecutionVendor::Substrate,
+                t3rn_abi::Codec::Scale,
+                None,
+                None,
+                vec![(*b"tran", Some(2))],
+            ));
+            assert_eq!(
+                xdns_events(),
+                vec![pallet_xdns::Event::<Runtime>::GatewayRecordCreated(
+                    *b"gate"
+                )]
+            );
+
+            // overriding with the very same record changes nothing
+            System::reset_events();
+            assert_ok!(XDNS::override_gateway(
+                *b"gate",
+                GatewayVendor::Rococo,
+                ExecutionVendor::Substrate,
+                t3rn_abi::Codec::Scale,
+                None,
+                None,
+                vec![(*b"tran", Some(2))],
+            ));
+            assert_eq!(xdns_events(), vec![]);
+
+            System::reset_events();
+            assert_ok!(XDNS::add_escrow_account(Origin::root(), *b"gate", ALICE));
+            assert_eq!(
+                xdns_events(),
+                vec![pallet_xdns::Event::<Runtime>::GatewayRecordUpdated(
+                    *b"gate",
+                    GatewayRecordChanges {
+                        escrow_account: true,
+                        ..Default::default()
+                    }
+                )]
+            );
+
+            System::reset_events();
+            assert_ok!(XDNS::enroll_new_abi_to_selected_gateway(
+                Origin::root(),
+                *b"gate",
+                *b"tass",
+                None,
+                Some(4)
+            ));
+            assert_eq!(
+                xdns_events(),
+                vec![
+                    pallet_xdns::Event::<Runtime>::GatewayAllowedSideEffectsChanged(
+                        *b"gate",
+                        XDNS::allowed_side_effects(b"gate")
+                    ),
+                    pallet_xdns::Event::<Runtime>::GatewayRecordUpdated(
+                        *b"gate",
+                        GatewayRecordChanges {
+                            allowed_side_effects: true,
+                            ..Default::default()
+                        }
+                    )
+                ]
+            );
+
+            System::reset_events();
+            assert_ok!(XDNS::purge_gateway_record(Origin::root(), ALICE, *b"gate"));
+            assert_eq!(
+                xdns_events().last(),
+                Some(&pallet_xdns::Event::<Runtime>::GatewayRecordPurged(
+                    *b"gate", ALICE
+                ))
+            );
+        });
+}
+
+#[test]
+fn genesis_builder_seeds_declared_gateways_and_standard_sfx_abis() {
+    use sp_runtime::BuildStorage;
+
+    let standard_sfx_abi_count = t3rn_abi::standard::standard_sfx_abi().len();
+
+    let genesis_config = pallet_xdns::GenesisConfig::<Runtime>::default()
+        .with_standard_sfx_abi()
+        .with_self_gateway(GatewayVendor::Rococo)
+        .with_gateway_record(GatewayRecord {
+            gateway_id: *b"eth2",
+            verification_vendor: GatewayVendor::Ethereum,
+            execution_vendor: ExecutionVendor::EVM,
+            codec: Rlp,
+            registrant: None,
+            escrow_account: None,
+            allowed_side_effects: AllowedSideEffects::truncate_from(vec![(*b"tran", Some(2))]),
+            security_tier: GatewaySecurityTier::Finalized,
+            fee_schedule: Default::default(),
+            assets: Default::default(),
+            abi_descriptors: Default::default(),
+            version: GATEWAY_RECORD_VERSION,
+        });
+
+    assert_eq!(
+        genesis_config.decoded_standard_sfx_abi().len(),
+        standard_sfx_abi_count
+    );
+    assert_eq!(genesis_config.decoded_gateway_records().len(), 1);
+
+    let storage = genesis_config.build_storage().unwrap();
+
+    sp_io::TestExternalities::new(storage).execute_with(|| {
+        assert_eq!(
+            pallet_xdns::StandardSFXABIs::<Runtime>::iter().count(),
+            standard_sfx_abi_count
+        );
+        assert_eq!(XDNS::fetch_full_gateway_records().len(), 2);
+        assert_eq!(
+            XDNS::get_verification_vendor(&[3, 3, 3, 3]),
+            Ok(GatewayVendor::Rococo)
+        );
+        assert_eq!(XDNS::get_target_codec(b"eth2"), Ok(Rlp));
+        assert!(pallet_xdns::SFXABIRegistry::<Runtime>::contains_key(
+            b"eth2", b"tran"
+        ));
+    });
+}
+
+#[test]
+fn genesis_seeds_only_the_declared_standard_sfx_abis_along_with_the_self_gateway() {
+    use sp_runtime::BuildStorage;
+    use t3rn_abi::standard::StandardSfxAbis;
+
+    let declared_sfx_abis = StandardSfxAbis::new().with_transfers();
+    let declared_sfx_abi_count = declared_sfx_abis.clone().build().len();
+
+    let storage = pallet_xdns::GenesisConfig::<Runtime>::default()
+        .with_standard_sfx_abis(declared_sfx_abis)
+        .with_self_gateway(GatewayVendor::Rococo)
+        .build_storage()
+        .unwrap();
+
+    sp_io::TestExternalities::new(storage).execute_with(|| {
+        assert_eq!(
+            pallet_xdns::StandardSFXABIs::<Runtime>::iter().count(),
+            declared_sfx_abi_count
+        );
+        assert!(pallet_xdns::StandardSFXABIs::<Runtime>::contains_key(
+            b"tran"
+        ));
+        assert!(!pallet_xdns::StandardSFXABIs::<Runtime>::contains_key(
+            b"cevm"
+        ));
+        assert!(pallet_xdns::SFXABIRegistry::<Runtime>::contains_key(
+            [3, 3, 3, 3],
+            b"tran"
+        ));
+        assert!(!pallet_xdns::SFXABIRegistry::<Runtime>::contains_key(
+            [3, 3, 3, 3],
+            b"cevm"
+        ));
+    });
+}
+
+#[test]
+fn genesis_seeds_all_standard_sfx_abis_for_the_self_gateway_when_none_are_declared() {
+    use sp_runtime::BuildStorage;
+
+    let storage = pallet_xdns::GenesisConfig::<Runtime>::default()
+        .with_self_gateway(GatewayVendor::Rococo)
+        .build_storage()
+        .unwrap();
+
+    sp_io::TestExternalities::new(storage).execute_with(|| {
+        assert_eq!(
+            pallet_xdns::StandardSFXABIs::<Runtime>::iter().count(),
+            t3rn_abi::standard::standard_sfx_abi().len()
+        );
+        assert!(pallet_xdns::SFXABIRegistry::<Runtime>::contains_key(
+            [3, 3, 3, 3],
+            b"cevm"
+        ));
+    });
+}
+
+#[test]
+fn test_storage_migration_v140_to_v150_for_standard_side_effects_to_standard_sfx_abi() {
+    type EventSignature = Vec<u8>;
+    use t3rn_abi::SFXAbi;
+    use t3rn_types::gateway::{CryptoAlgo, HasherAlgo};
+
+    #[derive(PartialEq, Clone, Encode, Decode, Eq, Hash, Debug)]
+    #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+    pub enum Type {
+        Address(u16),
+        DynamicAddress,
+        Bool,
+        Int(u16),
+        Uint(u16),
+        /// where u8 is bytes length
+        Bytes(u8),
+        DynamicBytes,
+        String,
+        Enum(u8),
+        Struct(u8),
+        Mapping(Box<Type>, Box<Type>),
+        Contract,
+        Ref(Box<Type>),
+        Option(Box<Type>),
+        OptionalInsurance,
+        OptionalReward,
+        StorageRef(Box<Type>),
+        /// There is no way to declare value in Solidity (should there be?)
+        Value,
+        /// DynamicBytes and String are lowered to a vector.
+        Slice,
+        Hasher(HasherAlgo, u16),
+        Crypto(CryptoAlgo),
+    }
+
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, Default)]
+    #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+    pub struct SideEffectInterface {
+        pub id: [u8; 4],
+        pub name: SideEffectName,
+        pub argument_abi: Vec<Type>,
+        pub argument_to_state_mapper: Vec<EventSignature>,
+        pub confirm_events: Vec<EventSignature>,
+        pub escrowed_events: Vec<EventSignature>,
+        pub commit_events: Vec<EventSignature>,
+        pub revert_events: Vec<EventSignature>,
+    }
 
     fn get_transfer_interface() -> SideEffectInterface {
         SideEffectInterface {
             id: *b"tran",
             name: b"transfer".to_vec(),
             argument_abi: vec![
-                Type::DynamicAddress,    // argument_0: from
+                Type::DynamicAddress,    // argument_0: from
+                Type::DynamicAddress,    // argument_1: to
+                Type::Value,             // argument_2: value
+                Type::OptionalInsurance, // argument_3: insurance
+            ],
+            argument_to_state_mapper: vec![
+                b"from".to_vec(),
+                b"to".to_vec(),
+                b"value".to_vec(),
+                b"insurance".to_vec(),
+            ],
+            confirm_events: vec![b"Transfer(_executor,to,value)".to_vec()],
+            escrowed_events: vec![b"Transfer(_source,_executor,to,value)".to_vec()],
+            commit_events: vec![b"Transfer(_executor,to,value)".to_vec()],
+            revert_events: vec![b"Transfer(_executor,from,value)".to_vec()],
+        }
+    }
+
+    fn get_swap_interface() -> SideEffectInterface {
+        SideEffectInterface {
+            id: *b"swap",
+            name: b"swap".to_vec(),
+            argument_abi: vec![
+                Type::DynamicAddress,    // argument_0: caller
                 Type::DynamicAddress,    // argument_1: to
-                Type::Value,             // argument_2: value
-                Type::OptionalInsurance, // argument_3: insurance
+                Type::Value,             // argument_2: amount_from
+                Type::Value,             // argument_3: amount_to
+                Type::DynamicBytes,      // argument_4: asset_from
+                Type::DynamicBytes,      // argument_5: asset_to
+                Type::OptionalInsurance, // argument_6: insurance
             ],
             argument_to_state_mapper: vec![
-                b"from".to_vec(),
+                b"caller".to_vec(),
                 b"to".to_vec(),
-                b"value".to_vec(),
+                b"amount_from".to_vec(),
+                b"amount_to".to_vec(),
+                b"asset_from".to_vec(),
+                b"asset_to".to_vec(),
                 b"insurance".to_vec(),
             ],
-            confirm_events: vec![b"Transfer(_executor,to,value)".to_vec()],
-            escrowed_events: vec![b"Transfer(_source,_executor,to,value)".to_vec()],
-            commit_events: vec![b"Transfer(_executor,to,value)".to_vec()],
-            revert_events: vec![b"Transfer(_executor,from,value)".to_vec()],
+            confirm_events: vec![b"MultiTransfer(_executor,to,asset_to,amount_to)".to_vec()],
+            escrowed_events: vec![
+                b"MultiTransfer(_source,_executor,to,asset_to,amount_to)".to_vec()
+            ],
+            commit_events: vec![b"MultiTransfer(_executor,to,asset_to,amount_to)".to_vec()],
+            revert_events: vec![b"MultiTransfer(_executor,caller,asset_from,amount_from)".to_vec()],
         }
     }
 
-    fn get_swap_interface() -> SideEffectInterface {
-        SideEffectInterface {
-            id: *b"swap",
-            name: b"swap".to_vec(),
-            argument_abi: vec![
-                Type::DynamicAddress,    // argument_0: caller
-                Type::DynamicAddress,    // argument_1: to
-                Type::Value,             // argument_2: amount_from
-                Type::Value,             // argument_3: amount_to
-                Type::DynamicBytes,      // argument_4: asset_from
-                Type::DynamicBytes,      // argument_5: asset_to
-                Type::OptionalInsurance, // argument_6: insurance
-            ],
-            argument_to_state_mapper: vec![
-                b"caller".to_vec(),
-                b"to".to_vec(),
-                b"amount_from".to_vec(),
-                b"amount_to".to_vec(),
-                b"asset_from".to_vec(),
-                b"asset_to".to_vec(),
-                b"insurance".to_vec(),
-            ],
-            confirm_events: vec![b"MultiTransfer(_executor,to,asset_to,amount_to)".to_vec()],
-            escrowed_events: vec![
-                b"MultiTransfer(_source,_executor,to,asset_to,amount_to)".to_vec()
-            ],
-            commit_events: vec![b"MultiTransfer(_executor,to,asset_to,amount_to)".to_vec()],
-            revert_events: vec![b"MultiTransfer(_executor,caller,asset_from,amount_from)".to_vec()],
-        }
-    }
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            // Insert some old storage entries
+            let old_entries = vec![
+                (*b"tran", get_transfer_interface()),
+                (*b"swap", get_swap_interface()),
+            ];
+
+            for (key, value) in old_entries.clone() {
+                // pallet_contracts_registry::ContractsRegistry::<Runtime>::
+                // assume encoded form will be the same as the old storage
+                pallet_xdns::StandardSideEffects::<Runtime>::insert(key, value.encode());
+            }
+
+            // Ensure the old storage entries are present
+            for (key, value) in old_entries.iter() {
+                assert_eq!(
+                    pallet_xdns::StandardSideEffects::<Runtime>::get(key),
+                    Some(value.encode())
+                );
+            }
+
+            // Perform the runtime upgrade (call the `on_runtime_upgrade` function)
+            let consumed_weight =
+                <XDNS as frame_support::traits::OnRuntimeUpgrade>::on_runtime_upgrade();
+            // Migration weight plus the migrations counter read by each versioned migration
+            let max_weight =
+                <Runtime as frame_system::Config>::DbWeight::get().reads_writes(13, 11);
+            assert_eq!(consumed_weight, max_weight);
+
+            // Ensure the old storage entries are removed
+            for (key, _) in old_entries.iter() {
+                assert!(pallet_xdns::StandardSideEffects::<Runtime>::get(key).is_none());
+            }
+
+            // Ensure the new storage entries are created
+            for (key, _value) in old_entries.iter() {
+                let sfx4b_id = *key;
+                assert_eq!(
+                    pallet_xdns::StandardSFXABIs::<Runtime>::get(sfx4b_id),
+                    SFXAbi::get_standard_interface(sfx4b_id)
+                );
+            }
+        });
+}
+
+#[test]
+fn test_seed_standard_sfx_abis_migration_restores_standards_keeping_gateway_prefix_memos() {
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            let tran_abi = t3rn_abi::SFXAbi::get_standard_interface(*b"tran").unwrap();
+            let mut linked_tran_abi = tran_abi.clone();
+            linked_tran_abi.set_prefix_memo(2);
+            pallet_xdns::StandardSFXABIs::<Runtime>::remove(*b"tran");
+            pallet_xdns::SFXABIRegistry::<Runtime>::insert(*b"gate", *b"tran", linked_tran_abi.clone());
+
+            <pallet_xdns::migrations::SeedStandardSFXABIs<Runtime> as frame_support::traits::OnRuntimeUpgrade>::on_runtime_upgrade();
+
+            assert_eq!(
+                pallet_xdns::StandardSFXABIs::<Runtime>::get(*b"tran"),
+                Some(tran_abi.clone())
+            );
+            assert_eq!(
+                pallet_xdns::StandardSFXABIs::<Runtime>::iter_keys().count(),
+                t3rn_abi::standard::standard_sfx_abi().len()
+            );
+            assert_eq!(
+                pallet_xdns::SFXABIRegistry::<Runtime>::get(*b"gate", *b"tran"),
+                Some(linked_tran_abi)
+            );
+        });
+}
+
+// Weight of the tiered layout migration following the earlier ones, with its counter read and write
+fn tiered_layout_migration_weight() -> Weight {
+    let gateways_cnt = pallet_xdns::Gateways::<Runtime>::iter_keys().count() as u64;
+    <Runtime as frame_system::Config>::DbWeight::get().reads_writes(
+        1 + 4 * gateways_cnt + described_sfx_abis_cnt(),
+        2 + gateways_cnt,
+    )
+}
+
+fn described_sfx_abis_cnt() -> u64 {
+    pallet_xdns::Gateways::<Runtime>::iter_keys()
+        .map(|gateway_id| XDNS::get_default_abi_descriptors(&gateway_id).len() as u64)
+        .sum()
+}
+
+#[test]
+fn test_storage_migration_v143_to_v144_that_kills_old_xdns_records_entry() {
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            // Insert raw xdns records entry
+            frame_support::storage::unhashed::put_raw(
+                &[
+                    225, 205, 72, 162, 242, 43, 101, 142, 192, 157, 178, 168, 200, 143, 21, 13,
+                    175, 239, 182, 147, 135, 79, 226, 105, 210, 52, 22, 179, 228, 93, 185, 249,
+                    114, 111, 99, 111,
+                ],
+                &[1, 2, 3],
+            );
+
+            pallet_xdns::StorageMigrations::<Runtime>::set(1);
+            let tiered_layout_weight = tiered_layout_migration_weight();
+
+            // Perform the runtime upgrade (call the `on_runtime_upgrade` function)
+            let consumed_weight =
+                <XDNS as frame_support::traits::OnRuntimeUpgrade>::on_runtime_upgrade();
+            // Kill of the entry plus the migrations counter read by each versioned migration
+            let max_weight = <Runtime as frame_system::Config>::DbWeight::get().reads_writes(3, 2);
+            assert_eq!(consumed_weight, max_weight + tiered_layout_weight);
+            assert_eq!(pallet_xdns::StorageMigrations::<Runtime>::get(), 4);
+
+            assert_eq!(
+                frame_support::storage::unhashed::get::<Vec<u8>>(&[
+                    225, 205, 72, 162, 242, 43, 101, 142, 192, 157, 178, 168, 200, 143, 21, 13,
+                    175, 239, 182, 147, 135, 79, 226, 105, 210, 52, 22, 179, 228, 93, 185, 249,
+                    114, 111, 99, 111,
+                ],),
+                None
+            );
+        });
+}
+
+#[test]
+fn test_storage_migration_v144_to_v145_that_kills_old_xdns_records_entry() {
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            // Insert raw xdns records entry
+            frame_support::storage::unhashed::put_raw(
+                &[
+                    84, 10, 79, 135, 84, 170, 82, 152, 163, 214, 233, 170, 9, 233, 63, 151, 78, 11,
+                    18, 119, 80, 58, 19, 112, 111, 133, 165, 20, 116, 96, 124, 88, 24, 172, 250,
+                    191, 195, 140, 91, 41, 106, 32, 177, 28, 37, 248, 177, 35, 27, 230, 169, 204,
+                    8, 192, 121, 163, 226, 24, 100, 166, 207, 36, 66, 173, 219, 150, 184, 250, 101,
+                    171, 135, 85,
+                ],
+                &[3, 2, 1],
+            );
+
+            pallet_xdns::StorageMigrations::<Runtime>::set(2);
+            // Registry lists and the tokens of each gateway are re-encoded as bounded
+            let bounded_cnt = 3
+                + pallet_xdns::GatewayTokens::<Runtime>::iter_keys().count() as u64
+                + pallet_xdns::Gateways::<Runtime>::iter_keys().count() as u64;
+            let tiered_layout_weight = tiered_layout_migration_weight();
+
+            // Perform the runtime upgrade (call the `on_runtime_upgrade` function)
+            let consumed_weight =
+                <XDNS as frame_support::traits::OnRuntimeUpgrade>::on_runtime_upgrade();
+            // Kill of the entry plus the migrations counter read by each versioned migration
+            let max_weight = <Runtime as frame_system::Config>::DbWeight::get()
+                .reads_writes(3 + bounded_cnt, 2 + bounded_cnt);
+            assert_eq!(consumed_weight, max_weight + tiered_layout_weight);
+
+            assert_eq!(
+                frame_support::storage::unhashed::get::<Vec<u8>>(&[
+                    84, 10, 79, 135, 84, 170, 82, 152, 163, 214, 233, 170, 9, 233, 63, 151, 78, 11,
+                    18, 119, 80, 58, 19, 112, 111, 133, 165, 20, 116, 96, 124, 88, 24, 172, 250,
+                    191, 195, 140, 91, 41, 106, 32, 177, 28, 37, 248, 177, 35, 27, 230, 169, 204,
+                    8, 192, 121, 163, 226, 24, 100, 166, 207, 36, 66, 173, 219, 150, 184, 250, 101,
+                    171, 135, 85,
+                ],),
+                None
+            );
+        });
+}
+
+#[test]
+fn test_storage_migration_of_gateway_records_to_tiered_layout() {
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            let current = pallet_xdns::Gateways::<Runtime>::get([3, 3, 3, 3]).unwrap();
+            let described_cnt = described_sfx_abis_cnt();
+            let legacy = crate::migrations::GatewayRecordV0::<AccountId> {
+                gateway_id: current.gateway_id,
+                verification_vendor: current.verification_vendor.clone(),
+                execution_vendor: current.execution_vendor.clone(),
+                codec: current.codec.clone(),
+                registrant: current.registrant.clone(),
+                escrow_account: current.escrow_account.clone(),
+                allowed_side_effects: current.allowed_side_effects.clone(),
+            };
+            frame_support::storage::unhashed::put(
+                &pallet_xdns::Gateways::<Runtime>::hashed_key_for([3, 3, 3, 3]),
+                &legacy,
+            );
+            pallet_xdns::GatewayTokens::<Runtime>::insert(
+                [3, 3, 3, 3],
+                GatewayAssets::truncate_from(vec![1, 2]),
+            );
+            pallet_xdns::GatewaysOverviewStore::<Runtime>::kill();
+
+            pallet_xdns::StorageMigrations::<Runtime>::set(3);
+            let gateways_cnt = pallet_xdns::Gateways::<Runtime>::iter_keys().count() as u64;
+
+            let consumed_weight =
+                <XDNS as frame_support::traits::OnRuntimeUpgrade>::on_runtime_upgrade();
+            // Skipped migrations read the counter, the tiered one reads and writes it
+            let max_weight = <Runtime as frame_system::Config>::DbWeight::get()
+                .reads_writes(3 + 1 + 4 * gateways_cnt + described_cnt, 2 + gateways_cnt);
+            assert_eq!(consumed_weight, max_weight);
+            assert_eq!(pallet_xdns::StorageMigrations::<Runtime>::get(), 4);
+
+            let migrated = pallet_xdns::Gateways::<Runtime>::get([3, 3, 3, 3]).unwrap();
+            assert_eq!(
+                migrated,
+                legacy.clone().into_tiered(
+                    GatewayAssets::truncate_from(vec![1, 2]),
+                    XDNS::collect_abi_descriptors(&[3, 3, 3, 3], &legacy.codec)
+                )
+            );
+            assert_eq!(
+                migrated.abi_descriptors.len(),
+                migrated.allowed_side_effects.len()
+            );
+            assert_eq!(migrated.version, GATEWAY_RECORD_VERSION);
+            assert_eq!(
+                migrated.security_tier,
+                legacy.verification_vendor.default_security_tier()
+            );
+            assert_eq!(migrated.fee_schedule, GatewayFeeSchedule::default());
+            assert_eq!(
+                pallet_xdns::Gateways::<Runtime>::iter_keys().count() as u64,
+                gateways_cnt
+            );
+
+            // Overviews of all gateways are re-linked to the activity of their light clients
+            let overview = pallet_xdns::GatewaysOverviewStore::<Runtime>::get();
+            assert_eq!(overview.len() as u64, gateways_cnt);
+            assert!(overview
+                .iter()
+                .any(|activity| activity.gateway_id == [3, 3, 3, 3]));
+        });
+}
+
+#[test]
+fn test_storage_migration_of_live_gateway_records_from_their_baseline_layout() {
+    // Layout of the records stored on live networks, as encoded ahead of any XDNS migration
+    #[derive(Encode)]
+    struct LiveGatewayRecord {
+        gateway_id: [u8; 4],
+        verification_vendor: GatewayVendor,
+        execution_vendor: ExecutionVendor,
+        codec: t3rn_abi::Codec,
+        registrant: Option<AccountId>,
+        escrow_account: Option<AccountId>,
+        allowed_side_effects: Vec<([u8; 4], Option<u8>)>,
+    }
+
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            let current: Vec<_> = pallet_xdns::Gateways::<Runtime>::iter_values().collect();
+            for record in current.iter() {
+                frame_support::storage::unhashed::put(
+                    &pallet_xdns::Gateways::<Runtime>::hashed_key_for(record.gateway_id),
+                    &LiveGatewayRecord {
+                        gateway_id: record.gateway_id,
+                        verification_vendor: record.verification_vendor.clone(),
+                        execution_vendor: record.execution_vendor.clone(),
+                        codec: record.codec.clone(),
+                        registrant: record.registrant.clone(),
+                        escrow_account: record.escrow_account.clone(),
+                        allowed_side_effects: record.allowed_side_effects.to_vec(),
+                    },
+                );
+            }
+            // Live networks are found at the version of the last registry kill
+            pallet_xdns::StorageMigrations::<Runtime>::set(2);
+
+            <XDNS as frame_support::traits::OnRuntimeUpgrade>::on_runtime_upgrade();
+            assert_eq!(
+                pallet_xdns::StorageMigrations::<Runtime>::get(),
+                crate::migrations::CURRENT_STORAGE_VERSION
+            );
+
+            // No record is dropped for failing to decode
+            assert_eq!(
+                pallet_xdns::Gateways::<Runtime>::iter_values().count(),
+                current.len()
+            );
+            for record in current {
+                let migrated = pallet_xdns::Gateways::<Runtime>::get(record.gateway_id).unwrap();
+                assert_eq!(migrated.version, GATEWAY_RECORD_VERSION);
+                assert_eq!(
+                    migrated.security_tier,
+                    record.verification_vendor.default_security_tier()
+                );
+                assert_eq!(migrated.allowed_side_effects, record.allowed_side_effects);
+                assert_eq!(migrated.registrant, record.registrant);
+                assert_eq!(migrated.fee_schedule, GatewayFeeSchedule::default());
+                assert_eq!(
+                    migrated.assets,
+                    pallet_xdns::GatewayTokens::<Runtime>::get(record.gateway_id)
+                );
+                assert_eq!(migrated.abi_descriptors, record.abi_descriptors);
+            }
+        });
+}
+
+#[test]
+fn gateway_fee_schedule_is_set_by_update_origin_and_kept_on_reregistration() {
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            let xdns_events = || {
+                System::events()
+                    .into_iter()
+                    .filter_map(|record| match record.event {
+                        RuntimeEvent::XDNS(event) => Some(event),
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>()
+            };
+            let fee_schedule = GatewayFeeSchedule {
+                base_fee: 1_000,
+                fee_per_byte: 10,
+            };
+
+            assert_noop!(
+                XDNS::set_gateway_fee_schedule(
+                    Origin::signed(ALICE),
+                    [1, 1, 1, 1],
+                    fee_schedule.clone()
+                ),
+                DispatchError::BadOrigin
+            );
+            assert_noop!(
+                XDNS::set_gateway_fee_schedule(Origin::root(), *b"none", fee_schedule.clone()),
+                pallet_xdns::Error::<Runtime>::GatewayRecordNotFound
+            );
+
+            System::reset_events();
+            assert_ok!(XDNS::set_gateway_fee_schedule(
+                Origin::root(),
+                [1, 1, 1, 1],
+                fee_schedule.clone()
+            ));
+            assert_eq!(
+                xdns_events(),
+                vec![
+                    pallet_xdns::Event::<Runtime>::GatewayRecordUpdated(
+                        [1, 1, 1, 1],
+                        GatewayRecordChanges {
+                            fee_schedule: true,
+                            ..Default::default()
+                        }
+                    ),
+                    pallet_xdns::Event::<Runtime>::GatewayFeeScheduleUpdated(
+                        [1, 1, 1, 1],
+                        fee_schedule.clone()
+                    ),
+                ]
+            );
+
+            // Setting the same schedule again changes nothing
+            System::reset_events();
+            assert_ok!(XDNS::set_gateway_fee_schedule(
+                Origin::root(),
+                [1, 1, 1, 1],
+                fee_schedule.clone()
+            ));
+            assert_eq!(xdns_events(), vec![]);
+
+            let record = pallet_xdns::Gateways::<Runtime>::get([1, 1, 1, 1]).unwrap();
+            assert_ok!(XDNS::add_new_gateway(
+                [1, 1, 1, 1],
+                record.verification_vendor,
+                record.execution_vendor,
+                record.codec,
+                record.registrant,
+                record.escrow_account,
+                record.allowed_side_effects.into_inner(),
+            ));
+            assert_eq!(
+                pallet_xdns::Gateways::<Runtime>::get([1, 1, 1, 1])
+                    .unwrap()
+                    .fee_schedule,
+                fee_schedule
+            );
+        });
+}
+
+#[test]
+fn gateway_record_assets_follow_linked_tokens() {
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            let token_id = u32::from_le_bytes(*b"test");
+            let token_props = TokenInfo::Substrate(SubstrateToken {
+                id: 1,
+                symbol: b"test".to_vec(),
+                decimals: 1,
+            });
+            assert_ok!(XDNS::register_new_token(
+                &Origin::root(),
+                token_id,
+                token_props.clone()
+            ));
+            assert_ok!(XDNS::link_token_to_gateway(
+                token_id,
+                [1, 1, 1, 1],
+                token_props
+            ));
+
+            let assets = pallet_xdns::Gateways::<Runtime>::get([1, 1, 1, 1])
+                .unwrap()
+                .assets;
+            assert!(assets.contains(&token_id));
+            assert_eq!(
+                assets,
+                pallet_xdns::GatewayTokens::<Runtime>::get([1, 1, 1, 1])
+            );
+
+            assert_ok!(XDNS::unlink_token(Origin::root(), [1, 1, 1, 1], token_id));
+            assert!(!pallet_xdns::Gateways::<Runtime>::get([1, 1, 1, 1])
+                .unwrap()
+                .assets
+                .contains(&token_id));
+        });
+}
 
+#[test]
+fn min_executor_bond_scales_with_gateway_tier_and_revert_rate() {
     ExtBuilder::default()
         .with_standard_sfx_abi()
         .with_default_xdns_records()
         .build()
         .execute_with(|| {
-            // Insert some old storage entries
-            let old_entries = vec![
-                (*b"tran", get_transfer_interface()),
-                (*b"swap", get_swap_interface()),
-            ];
+            let factors = BondScalingFactors {
+                rational_tier: 150,
+                optimistic_only_tier: 300,
+                per_revert_percent: 2,
+            };
+            assert_noop!(
+                XDNS::set_bond_scaling_factors(Origin::signed(ALICE), *b"gate", factors.clone()),
+                DispatchError::BadOrigin
+            );
+            assert_noop!(
+                XDNS::set_bond_scaling_factors(Origin::root(), *b"none", factors.clone()),
+                pallet_xdns::Error::<Runtime>::GatewayRecordNotFound
+            );
 
-            for (key, value) in old_entries.clone() {
-                // pallet_contracts_registry::ContractsRegistry::<Runtime>::
-                // assume encoded form will be the same as the old storage
-                pallet_xdns::StandardSideEffects::<Runtime>::insert(key, value.encode());
-            }
+            // Unscaled by default
+            assert_eq!(XDNS::min_executor_bond(b"gate", 1000), 1000);
 
-            // Ensure the old storage entries are present
-            for (key, value) in old_entries.iter() {
-                assert_eq!(
-                    pallet_xdns::StandardSideEffects::<Runtime>::get(key),
-                    Some(value.encode())
-                );
-            }
+            assert_ok!(XDNS::set_bond_scaling_factors(
+                Origin::root(),
+                *b"gate",
+                factors.clone()
+            ));
+            assert_eq!(XDNS::bond_scaling(*b"gate"), factors);
 
-            // Perform the runtime upgrade (call the `on_runtime_upgrade` function)
-            let consumed_weight =
-                <XDNS as frame_support::traits::OnRuntimeUpgrade>::on_runtime_upgrade();
-            let max_weight =
-                <Runtime as frame_system::Config>::DbWeight::get().reads_writes(10, 10);
-            assert_eq!(consumed_weight, max_weight);
+            assert_ok!(XDNS::set_gateway_security_tier(
+                Origin::root(),
+                *b"gate",
+                GatewaySecurityTier::Finalized
+            ));
+            assert_eq!(XDNS::min_executor_bond(b"gate", 1000), 1000);
 
-            // Ensure the old storage entries are removed
-            for (key, _) in old_entries.iter() {
-                assert!(pallet_xdns::StandardSideEffects::<Runtime>::get(key).is_none());
-            }
+            assert_ok!(XDNS::set_gateway_security_tier(
+                Origin::root(),
+                *b"gate",
+                GatewaySecurityTier::OptimisticOnly
+            ));
+            assert_eq!(XDNS::min_executor_bond(b"gate", 1000), 3000);
 
-            // Ensure the new storage entries are created
-            for (key, _value) in old_entries.iter() {
-                let sfx4b_id = *key;
-                assert_eq!(
-                    pallet_xdns::StandardSFXABIs::<Runtime>::get(sfx4b_id),
-                    SFXAbi::get_standard_interface(sfx4b_id)
-                );
+            // 25% revert rate adds 2% per percent point
+            for _ in 0..4 {
+                XDNS::note_sfx_targeted(b"gate");
+            }
+            for _ in 0..3 {
+                XDNS::note_sfx_confirmed(b"gate");
             }
+            XDNS::note_sfx_reverted(b"gate");
+            assert_eq!(XDNS::min_executor_bond(b"gate", 1000), 3500);
+
+            // Unknown gateways keep the base bond
+            assert_eq!(XDNS::min_executor_bond(b"none", 1000), 1000);
         });
 }
 
 #[test]
-fn test_storage_migration_v143_to_v144_that_kills_old_xdns_records_entry() {
+fn xtx_cost_estimate_sums_fees_and_scaled_insurance_into_expected_bid_range() {
     ExtBuilder::default()
         .with_standard_sfx_abi()
         .with_default_xdns_records()
         .build()
         .execute_with(|| {
-            // Insert raw xdns records entry
-            frame_support::storage::unhashed::put_raw(
-                &[
-                    225, 205, 72, 162, 242, 43, 101, 142, 192, 157, 178, 168, 200, 143, 21, 13,
-                    175, 239, 182, 147, 135, 79, 226, 105, 210, 52, 22, 179, 228, 93, 185, 249,
-                    114, 111, 99, 111,
-                ],
-                &[1, 2, 3],
+            let quote_asset = XDNS::get_self_token_id();
+            pallet_xdns::PerTargetAssetEstimates::<Runtime>::insert(
+                *b"gate",
+                (1, quote_asset),
+                200,
             );
 
-            pallet_xdns::StorageMigrations::<Runtime>::set(1);
-
-            // Perform the runtime upgrade (call the `on_runtime_upgrade` function)
-            let consumed_weight =
-                <XDNS as frame_support::traits::OnRuntimeUpgrade>::on_runtime_upgrade();
-            let max_weight = <Runtime as frame_system::Config>::DbWeight::get().reads_writes(0, 1);
-            assert_eq!(consumed_weight, max_weight);
+            assert_ok!(XDNS::set_bond_scaling_factors(
+                Origin::root(),
+                *b"gate",
+                BondScalingFactors {
+                    rational_tier: 150,
+                    optimistic_only_tier: 300,
+                    per_revert_percent: 2,
+                }
+            ));
+            assert_ok!(XDNS::set_gateway_security_tier(
+                Origin::root(),
+                *b"gate",
+                GatewaySecurityTier::OptimisticOnly
+            ));
+            for _ in 0..4 {
+                XDNS::note_sfx_targeted(b"gate");
+            }
+            for _ in 0..3 {
+                XDNS::note_sfx_confirmed(b"gate");
+            }
+            XDNS::note_sfx_reverted(b"gate");
+
+            // One in four reverts on the target puts a quarter of the 3500 bond at stake
+            let expected = XtxCostEstimate {
+                fees: 200,
+                insurance: 3500,
+                min_bid: 200,
+                max_bid: 1075,
+            };
+            assert_eq!(
+                XDNS::estimate_xtx_cost(&[(*b"gate", 1, 1000)], quote_asset),
+                expected
+            );
 
+            // Targets without estimates only add the insurance they require
             assert_eq!(
-                frame_support::storage::unhashed::get::<Vec<u8>>(&[
-                    225, 205, 72, 162, 242, 43, 101, 142, 192, 157, 178, 168, 200, 143, 21, 13,
-                    175, 239, 182, 147, 135, 79, 226, 105, 210, 52, 22, 179, 228, 93, 185, 249,
-                    114, 111, 99, 111,
-                ],),
-                None
+                XDNS::estimate_xtx_cost(&[(*b"gate", 1, 1000), (*b"none", 1, 1000)], quote_asset),
+                XtxCostEstimate {
+                    insurance: 4500,
+                    ..expected.clone()
+                }
+            );
+
+            System::set_block_number(1);
+            assert_ok!(XDNS::quote_xtx_cost(
+                Origin::signed(ALICE),
+                vec![(*b"gate", 1, 1000)],
+                quote_asset
+            ));
+            System::assert_last_event(
+                pallet_xdns::Event::<Runtime>::XtxCostEstimated(quote_asset, expected).into(),
             );
         });
 }
 
+fn fee_oracle_pair(seed: u8) -> (sp_core::sr25519::Pair, AccountId32) {
+    use sp_core::Pair;
+    use sp_runtime::traits::IdentifyAccount;
+    let pair = sp_core::sr25519::Pair::from_seed(&[seed; 32]);
+    let account = sp_runtime::MultiSigner::from(pair.public()).into_account();
+    (pair, account)
+}
+
+fn signed_fee_hints(
+    pair: &sp_core::sr25519::Pair,
+    block_number: BlockNumber,
+    hints: Vec<(TargetId, Sfx4bId, FeeHint<Balance>)>,
+) -> pallet_xdns::Call<Runtime> {
+    use sp_core::Pair;
+    let payload = crate::fee_oracle::FeeHintsPayload {
+        block_number,
+        hints,
+        public: sp_runtime::MultiSigner::from(pair.public()),
+    };
+    let signature = payload.using_encoded(|encoded| pair.sign(encoded)).into();
+    pallet_xdns::Call::submit_fee_hints { payload, signature }
+}
+
 #[test]
-fn test_storage_migration_v144_to_v145_that_kills_old_xdns_records_entry() {
+fn fee_oracle_takes_lower_medians_and_parses_gas_prices_off_json_rpc_responses() {
+    use crate::fee_oracle::{median, parse_json_rpc_quantity};
+
+    assert_eq!(median::<u128>(vec![]), None);
+    assert_eq!(median(vec![30u128, 10, 20]), Some(20));
+    assert_eq!(median(vec![40u128, 10, 30, 20]), Some(20));
+
+    assert_eq!(
+        parse_json_rpc_quantity(br#"{"jsonrpc":"2.0","id":1,"result":"0x3b9aca00"}"#),
+        Some(1_000_000_000)
+    );
+    assert_eq!(
+        parse_json_rpc_quantity(br#"{"jsonrpc":"2.0","id":1,"error":{"code":-32000}}"#),
+        None
+    );
+
+    // Max fee follows the gas price off its median, or the bids alone on unsampled targets
+    assert_eq!(FeeHint::<Balance>::new(100, 30, 20).max_fee, 150);
+    assert_eq!(FeeHint::<Balance>::new(100, 10, 20).max_fee, 50);
+    assert_eq!(FeeHint::<Balance>::new(100, 0, 0).max_fee, 100);
+}
+
+#[test]
+fn winning_bids_are_sampled_per_registered_gateway_and_side_effect_up_to_the_bound() {
+    use sp_core::offchain::{testing::TestOffchainExt, OffchainDbExt};
+
+    let mut ext = ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build();
+    ext.register_extension(OffchainDbExt::new(TestOffchainExt::new().0));
+    ext.execute_with(|| {
+        let samples = crate::fee_oracle::MAX_FEE_SAMPLES as Balance;
+        for bid in 0..samples + 2 {
+            XDNS::note_sfx_bid_won(b"gate", b"tran", bid);
+        }
+        XDNS::note_sfx_bid_won(b"gate", b"swap", 7);
+        XDNS::note_sfx_bid_won(b"none", b"tran", 7);
+
+        // The oldest samples give way to the latest ones
+        assert_eq!(
+            pallet_xdns::RecentWinningBids::<Runtime>::get(b"gate", b"tran").into_inner(),
+            (2..samples + 2).collect::<Vec<Balance>>()
+        );
+        assert_eq!(
+            pallet_xdns::RecentWinningBids::<Runtime>::get(b"gate", b"swap").into_inner(),
+            vec![7]
+        );
+        assert!(pallet_xdns::RecentWinningBids::<Runtime>::get(b"none", b"tran").is_empty());
+
+        assert_eq!(
+            XDNS::estimate_fee_hints(),
+            vec![
+                (*b"gate", *b"swap", FeeHint::new(7, 0, 0)),
+                (*b"gate", *b"tran", FeeHint::new(17, 0, 0)),
+            ]
+        );
+    });
+}
+
+#[test]
+fn fee_hints_of_registered_oracles_are_validated_stored_and_suggested_until_stale() {
+    use frame_support::pallet_prelude::{InvalidTransaction, TransactionSource, ValidateUnsigned};
+
     ExtBuilder::default()
         .with_standard_sfx_abi()
         .with_default_xdns_records()
         .build()
         .execute_with(|| {
-            // Insert raw xdns records entry
-            frame_support::storage::unhashed::put_raw(
-                &[
-                    84, 10, 79, 135, 84, 170, 82, 152, 163, 214, 233, 170, 9, 233, 63, 151, 78, 11,
-                    18, 119, 80, 58, 19, 112, 111, 133, 165, 20, 116, 96, 124, 88, 24, 172, 250,
-                    191, 195, 140, 91, 41, 106, 32, 177, 28, 37, 248, 177, 35, 27, 230, 169, 204,
-                    8, 192, 121, 163, 226, 24, 100, 166, 207, 36, 66, 173, 219, 150, 184, 250, 101,
-                    171, 135, 85,
+            let (pair, oracle) = fee_oracle_pair(7);
+            let hint = FeeHint::new(100, 30, 20);
+            let interval = <Runtime as pallet_xdns::Config>::FeeOracleInterval::get();
+            System::set_block_number(interval);
+
+            let call = signed_fee_hints(
+                &pair,
+                interval,
+                vec![
+                    (*b"gate", *b"tran", hint.clone()),
+                    (*b"none", *b"tran", hint.clone()),
                 ],
-                &[3, 2, 1],
             );
+            let validate = |call: &pallet_xdns::Call<Runtime>| {
+                XDNS::validate_unsigned(TransactionSource::External, call)
+            };
 
-            pallet_xdns::StorageMigrations::<Runtime>::set(2);
+            assert_eq!(validate(&call), InvalidTransaction::BadSigner.into());
+            assert_noop!(
+                XDNS::set_fee_oracles(Origin::signed(ALICE), vec![oracle.clone()]),
+                DispatchError::BadOrigin
+            );
+            assert_noop!(
+                XDNS::set_fee_oracles(
+                    Origin::root(),
+                    vec![oracle.clone(); crate::fee_oracle::MAX_FEE_ORACLES as usize + 1]
+                ),
+                pallet_xdns::Error::<Runtime>::TooManyFeeOracles
+            );
+            assert_ok!(XDNS::set_fee_oracles(Origin::root(), vec![oracle.clone()]));
+            System::assert_last_event(
+                pallet_xdns::Event::<Runtime>::FeeOraclesSet(vec![oracle]).into(),
+            );
+            assert!(validate(&call).is_ok());
 
-            // Perform the runtime upgrade (call the `on_runtime_upgrade` function)
-            let consumed_weight =
-                <XDNS as frame_support::traits::OnRuntimeUpgrade>::on_runtime_upgrade();
-            let max_weight = <Runtime as frame_system::Config>::DbWeight::get().reads_writes(0, 1);
-            assert_eq!(consumed_weight, max_weight);
+            let pallet_xdns::Call::submit_fee_hints { payload, signature } = call.clone() else {
+                unreachable!()
+            };
+            let mut tampered = payload.clone();
+            tampered.hints[0].2.max_fee = 1;
+            assert_eq!(
+                validate(&pallet_xdns::Call::submit_fee_hints {
+                    payload: tampered,
+                    signature: signature.clone(),
+                }),
+                InvalidTransaction::BadProof.into()
+            );
+
+            assert_noop!(
+                XDNS::submit_fee_hints(Origin::signed(ALICE), payload.clone(), signature.clone()),
+                DispatchError::BadOrigin
+            );
+            assert_ok!(XDNS::submit_fee_hints(Origin::none(), payload, signature));
+            System::assert_last_event(
+                pallet_xdns::Event::<Runtime>::FeeHintSubmitted(*b"gate", *b"tran", 150).into(),
+            );
+            // Hints on unregistered gateways are dropped
+            assert!(pallet_xdns::FeeHints::<Runtime>::get(b"none", b"tran").is_none());
+            assert_eq!(XDNS::suggest_max_fee(b"gate", b"tran"), Some(150));
+            assert_eq!(XDNS::suggest_max_fee(b"gate", b"swap"), None);
+
+            // Hints signed as of blocks over an interval ago no longer validate
+            System::set_block_number(2 * interval + 1);
+            assert_eq!(validate(&call), InvalidTransaction::Stale.into());
+
+            // Hints not refreshed for the TTL are no longer suggested
+            let ttl = interval * crate::fee_oracle::FEE_HINT_TTL_INTERVALS;
+            System::set_block_number(interval + ttl);
+            assert_eq!(XDNS::suggest_max_fee(b"gate", b"tran"), Some(150));
+            System::set_block_number(interval + ttl + 1);
+            assert_eq!(XDNS::suggest_max_fee(b"gate", b"tran"), None);
+
+            assert_ok!(XDNS::purge_gateway_record(Origin::root(), ALICE, *b"gate"));
+            assert!(pallet_xdns::FeeHints::<Runtime>::get(b"gate", b"tran").is_none());
+        });
+}
+
+#[test]
+fn fee_hints_estimated_rotate_through_all_side_effects_past_a_submission() {
+    use sp_core::offchain::{testing::TestOffchainExt, OffchainDbExt};
+    use sp_std::collections::btree_set::BTreeSet;
+
+    let mut ext = ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build();
+    ext.register_extension(OffchainDbExt::new(TestOffchainExt::new().0));
+    ext.execute_with(|| {
+        let max_hints = crate::fee_oracle::MAX_FEE_HINTS_PER_SUBMISSION as usize;
+        let side_effects = (0..max_hints as u8 + 6)
+            .map(|i| [b's', b'f', b'x', i])
+            .collect::<BTreeSet<_>>();
+        for sfx_4b_id in side_effects.iter() {
+            XDNS::note_sfx_bid_won(b"gate", sfx_4b_id, 100);
+        }
+        let estimate = || {
+            XDNS::estimate_fee_hints()
+                .into_iter()
+                .map(|(_, sfx_4b_id, _)| sfx_4b_id)
+                .collect::<Vec<_>>()
+        };
+
+        // Side effects are iterated in the order of their keys
+        let sorted = side_effects.into_iter().collect::<Vec<_>>();
+        assert_eq!(estimate(), sorted[..max_hints].to_vec());
+        // The side effects left out resume the next estimate, wrapping around to the first ones
+        assert_eq!(
+            estimate(),
+            [&sorted[max_hints..], &sorted[..max_hints - 6]].concat()
+        );
+        assert_eq!(
+            estimate(),
+            [&sorted[max_hints - 6..], &sorted[..max_hints - 12]].concat()
+        );
+    });
+}
+
+#[test]
+fn fee_hints_of_several_oracles_are_aggregated_by_their_median() {
+    ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build()
+        .execute_with(|| {
+            let oracles = [fee_oracle_pair(7), fee_oracle_pair(8), fee_oracle_pair(9)];
+            assert_ok!(XDNS::set_fee_oracles(
+                Origin::root(),
+                oracles.iter().map(|(_, oracle)| oracle.clone()).collect()
+            ));
+            let interval = <Runtime as pallet_xdns::Config>::FeeOracleInterval::get();
+            System::set_block_number(interval);
+            let submit = |oracle: usize, max_fee: Balance| {
+                let pallet_xdns::Call::submit_fee_hints { payload, signature } = signed_fee_hints(
+                    &oracles[oracle].0,
+                    interval,
+                    vec![(*b"gate", *b"tran", FeeHint::new(max_fee, 0, 0))],
+                ) else {
+                    unreachable!()
+                };
+                assert_ok!(XDNS::submit_fee_hints(Origin::none(), payload, signature));
+                XDNS::suggest_max_fee(b"gate", b"tran")
+            };
+
+            assert_eq!(submit(0, 100), Some(100));
+            assert_eq!(submit(1, 300), Some(100));
+            assert_eq!(submit(2, 200), Some(200));
+            // An oracle submitting again replaces its own hint
+            assert_eq!(submit(0, 400), Some(300));
 
+            // Hints of the oracles no longer registered drop out
+            assert_ok!(XDNS::set_fee_oracles(
+                Origin::root(),
+                vec![oracles[0].1.clone(), oracles[2].1.clone()]
+            ));
+            assert_eq!(submit(2, 200), Some(200));
             assert_eq!(
-                frame_support::storage::unhashed::get::<Vec<u8>>(&[
-                    84, 10, 79, 135, 84, 170, 82, 152, 163, 214, 233, 170, 9, 233, 63, 151, 78, 11,
-                    18, 119, 80, 58, 19, 112, 111, 133, 165, 20, 116, 96, 124, 88, 24, 172, 250,
-                    191, 195, 140, 91, 41, 106, 32, 177, 28, 37, 248, 177, 35, 27, 230, 169, 204,
-                    8, 192, 121, 163, 226, 24, 100, 166, 207, 36, 66, 173, 219, 150, 184, 250, 101,
-                    171, 135, 85,
-                ],),
-                None
+                pallet_xdns::OracleFeeHints::<Runtime>::get(b"gate", b"tran").len(),
+                2
             );
+
+            // As do the hints not refreshed for the TTL
+            let ttl = interval * crate::fee_oracle::FEE_HINT_TTL_INTERVALS;
+            System::set_block_number(interval + ttl + 1);
+            let pallet_xdns::Call::submit_fee_hints { payload, signature } = signed_fee_hints(
+                &oracles[2].0,
+                interval + ttl + 1,
+                vec![(*b"gate", *b"tran", FeeHint::new(500, 0, 0))],
+            ) else {
+                unreachable!()
+            };
+            assert_ok!(XDNS::submit_fee_hints(Origin::none(), payload, signature));
+            assert_eq!(XDNS::suggest_max_fee(b"gate", b"tran"), Some(500));
+
+            assert_ok!(XDNS::purge_gateway_record(Origin::root(), ALICE, *b"gate"));
+            assert!(pallet_xdns::OracleFeeHints::<Runtime>::get(b"gate", b"tran").is_empty());
+        });
+}
+
+#[test]
+fn offchain_worker_submits_fee_hints_scaled_by_sampled_gas_prices_at_each_interval() {
+    use sp_core::offchain::{
+        testing::{PendingRequest, TestOffchainExt, TestTransactionPoolExt},
+        OffchainDbExt, OffchainWorkerExt, TransactionPoolExt,
+    };
+    use sp_keystore::{testing::MemoryKeystore, Keystore, KeystoreExt};
+    use sp_runtime::offchain::StorageKind;
+
+    let (offchain, offchain_state) = TestOffchainExt::new();
+    let (pool, pool_state) = TestTransactionPoolExt::new();
+    let keystore = MemoryKeystore::new();
+    let oracle_public = keystore
+        .sr25519_generate_new(crate::fee_oracle::KEY_TYPE, None)
+        .unwrap();
+
+    let mut ext = ExtBuilder::default()
+        .with_standard_sfx_abi()
+        .with_default_xdns_records()
+        .build();
+    ext.register_extension(OffchainDbExt::new(offchain.clone()));
+    ext.register_extension(OffchainWorkerExt::new(offchain));
+    ext.register_extension(TransactionPoolExt::new(pool));
+    ext.register_extension(KeystoreExt::new(keystore));
+
+    ext.execute_with(|| {
+        let interval = <Runtime as pallet_xdns::Config>::FeeOracleInterval::get();
+        for bid in [100, 300, 200] {
+            XDNS::note_sfx_bid_won(b"gate", b"tran", bid);
+        }
+        sp_io::offchain::local_storage_set(
+            StorageKind::PERSISTENT,
+            &[crate::fee_oracle::GAS_PRICE_ENDPOINT_PREFIX, b"gate"].concat(),
+            b"http://localhost:8545",
+        );
+        offchain_state.write().expect_request(PendingRequest {
+            method: "POST".into(),
+            uri: "http://localhost:8545".into(),
+            headers: vec![("Content-Type".into(), "application/json".into())],
+            body: br#"{"jsonrpc":"2.0","id":1,"method":"eth_gasPrice","params":[]}"#.to_vec(),
+            response: Some(br#"{"jsonrpc":"2.0","id":1,"result":"0x64"}"#.to_vec()),
+            sent: true,
+            ..Default::default()
         });
+
+        // Off the interval, the worker stays idle
+        XDNS::run_fee_oracle(interval + 1);
+        assert!(pool_state.read().transactions.is_empty());
+
+        XDNS::run_fee_oracle(interval);
+        let tx = pool_state.write().transactions.pop().unwrap();
+        assert!(pool_state.read().transactions.is_empty());
+        let tx = UncheckedExtrinsic::decode(&mut &*tx).unwrap();
+        assert!(tx.signature.is_none());
+        let RuntimeCall::XDNS(pallet_xdns::Call::submit_fee_hints { payload, .. }) = tx.function
+        else {
+            panic!("Fee oracle submits its fee hints")
+        };
+        assert_eq!(payload.block_number, interval);
+        assert_eq!(payload.public, sp_runtime::MultiSigner::from(oracle_public));
+        // The only gas price sampled is its own median
+        assert_eq!(
+            payload.hints,
+            vec![(*b"gate", *b"tran", FeeHint::new(200, 100, 100))]
+        );
+    });
 }
@@ -1,8 +1,10 @@
-//! Autogenerated weights for pallet_xdns
+//! Weights for pallet_xdns
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 3.0.0
-//! DATE: 2021-09-19, STEPS: `[50, ]`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 128
+//! Weights of `add_new_gateway`, `update_ttl`, `purge_gateway`, `reboot_self_gateway` and
+//! `best_available` were generated by the Substrate benchmark CLI v3.0.0 on 2021-09-19, with the
+//! command below. Weights of the extrinsics added since are estimated from the storage accesses
+//! of their benchmarks in `benchmarking.rs`, rounded to the microsecond, ahead of a run on
+//! reference hardware. Regenerate all of them with `node/run-benchmark.sh`.
 
 // Executed Command:
 // ./target/release/circuit
@@ -42,6 +44,31 @@ pub trait WeightInfo {
     fn purge_gateway() -> Weight;
     fn reboot_self_gateway() -> Weight;
     fn best_available() -> Weight;
+    fn add_supported_bridging_asset() -> Weight;
+    fn enroll_bridge_asset() -> Weight;
+    fn enroll_new_abi_to_selected_gateway() -> Weight;
+    fn unroll_abi_of_selected_gateway() -> Weight;
+    fn add_remote_order_address() -> Weight;
+    fn add_remote_bidding_address() -> Weight;
+    fn set_gateway_security_tier() -> Weight;
+    fn set_bond_scaling_factors() -> Weight;
+    fn set_gateway_fee_schedule() -> Weight;
+    fn purge_supported_bridging_asset() -> Weight;
+    fn purge_gateway_record(t: u32, c: u32) -> Weight;
+    fn unlink_token() -> Weight;
+    fn link_token() -> Weight;
+    fn purge_token_record() -> Weight;
+    fn link_asset_correspondence() -> Weight;
+    fn unlink_asset_correspondence() -> Weight;
+    fn zip_topology(g: u32, t: u32) -> Weight;
+    fn unzip_topology() -> Weight;
+    fn update_token_sys_props() -> Weight;
+    fn reserve_gateway_id() -> Weight;
+    fn force_reserve_gateway_id() -> Weight;
+    fn release_gateway_id() -> Weight;
+    fn quote_xtx_cost(n: u32) -> Weight;
+    fn submit_fee_hints(n: u32) -> Weight;
+    fn set_fee_oracles() -> Weight;
 }
 
 /// Weights for pallet_xdns using the Substrate node and recommended hardware.
@@ -74,6 +101,159 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
             .saturating_add(T::DbWeight::get().reads(2_u64))
             .saturating_add(T::DbWeight::get().writes(1_u64))
     }
+
+    fn add_supported_bridging_asset() -> Weight {
+        Weight::from_parts(25_000_000_u64, 0u64)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+
+    fn enroll_bridge_asset() -> Weight {
+        Weight::from_parts(96_000_000_u64, 0u64)
+            .saturating_add(T::DbWeight::get().reads(6_u64))
+            .saturating_add(T::DbWeight::get().writes(6_u64))
+    }
+
+    fn enroll_new_abi_to_selected_gateway() -> Weight {
+        Weight::from_parts(41_000_000_u64, 0u64)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+
+    fn unroll_abi_of_selected_gateway() -> Weight {
+        Weight::from_parts(38_000_000_u64, 0u64)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+
+    fn add_remote_order_address() -> Weight {
+        Weight::from_parts(18_000_000_u64, 0u64).saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+
+    fn add_remote_bidding_address() -> Weight {
+        Weight::from_parts(18_000_000_u64, 0u64).saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+
+    fn set_gateway_security_tier() -> Weight {
+        Weight::from_parts(23_000_000_u64, 0u64)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+
+    fn set_bond_scaling_factors() -> Weight {
+        Weight::from_parts(19_000_000_u64, 0u64)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+
+    fn set_gateway_fee_schedule() -> Weight {
+        Weight::from_parts(23_000_000_u64, 0u64)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+
+    fn purge_supported_bridging_asset() -> Weight {
+        Weight::from_parts(26_000_000_u64, 0u64)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+
+    fn purge_gateway_record(t: u32, c: u32) -> Weight {
+        Weight::from_parts(112_000_000_u64, 0u64)
+            .saturating_add(Weight::from_parts(6_000_000_u64, 0u64).saturating_mul(t as u64))
+            .saturating_add(Weight::from_parts(9_000_000_u64, 0u64).saturating_mul(c as u64))
+            .saturating_add(T::DbWeight::get().reads(8_u64))
+            .saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(c as u64)))
+            .saturating_add(T::DbWeight::get().writes(7_u64))
+            .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(t as u64)))
+            .saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(c as u64)))
+    }
+
+    fn unlink_token() -> Weight {
+        Weight::from_parts(28_000_000_u64, 0u64)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+
+    fn link_token() -> Weight {
+        Weight::from_parts(45_000_000_u64, 0u64)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+
+    fn purge_token_record() -> Weight {
+        Weight::from_parts(89_000_000_u64, 0u64)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+    }
+
+    fn link_asset_correspondence() -> Weight {
+        Weight::from_parts(58_000_000_u64, 0u64)
+            .saturating_add(T::DbWeight::get().reads(12_u64))
+            .saturating_add(T::DbWeight::get().writes(12_u64))
+    }
+
+    fn unlink_asset_correspondence() -> Weight {
+        Weight::from_parts(34_000_000_u64, 0u64)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+    }
+
+    fn zip_topology(g: u32, t: u32) -> Weight {
+        Weight::from_parts(21_000_000_u64, 0u64)
+            .saturating_add(Weight::from_parts(18_000_000_u64, 0u64).saturating_mul(g as u64))
+            .saturating_add(Weight::from_parts(8_000_000_u64, 0u64).saturating_mul(t as u64))
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(g as u64)))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(t as u64)))
+    }
+
+    fn unzip_topology() -> Weight {
+        Weight::from_parts(186_000_000_u64, 0u64)
+            .saturating_add(T::DbWeight::get().reads(16_u64))
+            .saturating_add(T::DbWeight::get().writes(16_u64))
+    }
+
+    fn update_token_sys_props() -> Weight {
+        Weight::from_parts(28_000_000_u64, 0u64)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+
+    fn reserve_gateway_id() -> Weight {
+        Weight::from_parts(34_000_000_u64, 0u64)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+
+    fn force_reserve_gateway_id() -> Weight {
+        Weight::from_parts(31_000_000_u64, 0u64)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+
+    fn release_gateway_id() -> Weight {
+        Weight::from_parts(32_000_000_u64, 0u64)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+
+    fn quote_xtx_cost(n: u32) -> Weight {
+        Weight::from_parts(12_000_000_u64, 0u64)
+            .saturating_add(Weight::from_parts(10_000_000_u64, 0u64).saturating_mul(n as u64))
+            .saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(n as u64)))
+    }
+
+    fn submit_fee_hints(n: u32) -> Weight {
+        Weight::from_parts(18_000_000_u64, 0u64)
+            .saturating_add(Weight::from_parts(9_000_000_u64, 0u64).saturating_mul(n as u64))
+            .saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n as u64)))
+            .saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n as u64)))
+    }
+
+    fn set_fee_oracles() -> Weight {
+        Weight::from_parts(15_000_000_u64, 0u64).saturating_add(T::DbWeight::get().writes(1_u64))
+    }
 }
 
 // For backwards compatibility and tests
@@ -105,4 +285,157 @@ impl WeightInfo for () {
             .saturating_add(RocksDbWeight::get().reads(2_u64))
             .saturating_add(RocksDbWeight::get().writes(1_u64))
     }
+
+    fn add_supported_bridging_asset() -> Weight {
+        Weight::from_parts(25_000_000_u64, 0u64)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn enroll_bridge_asset() -> Weight {
+        Weight::from_parts(96_000_000_u64, 0u64)
+            .saturating_add(RocksDbWeight::get().reads(6_u64))
+            .saturating_add(RocksDbWeight::get().writes(6_u64))
+    }
+
+    fn enroll_new_abi_to_selected_gateway() -> Weight {
+        Weight::from_parts(41_000_000_u64, 0u64)
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
+
+    fn unroll_abi_of_selected_gateway() -> Weight {
+        Weight::from_parts(38_000_000_u64, 0u64)
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
+
+    fn add_remote_order_address() -> Weight {
+        Weight::from_parts(18_000_000_u64, 0u64).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn add_remote_bidding_address() -> Weight {
+        Weight::from_parts(18_000_000_u64, 0u64).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn set_gateway_security_tier() -> Weight {
+        Weight::from_parts(23_000_000_u64, 0u64)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn set_bond_scaling_factors() -> Weight {
+        Weight::from_parts(19_000_000_u64, 0u64)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn set_gateway_fee_schedule() -> Weight {
+        Weight::from_parts(23_000_000_u64, 0u64)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn purge_supported_bridging_asset() -> Weight {
+        Weight::from_parts(26_000_000_u64, 0u64)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn purge_gateway_record(t: u32, c: u32) -> Weight {
+        Weight::from_parts(112_000_000_u64, 0u64)
+            .saturating_add(Weight::from_parts(6_000_000_u64, 0u64).saturating_mul(t as u64))
+            .saturating_add(Weight::from_parts(9_000_000_u64, 0u64).saturating_mul(c as u64))
+            .saturating_add(RocksDbWeight::get().reads(8_u64))
+            .saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(c as u64)))
+            .saturating_add(RocksDbWeight::get().writes(7_u64))
+            .saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(t as u64)))
+            .saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(c as u64)))
+    }
+
+    fn unlink_token() -> Weight {
+        Weight::from_parts(28_000_000_u64, 0u64)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+
+    fn link_token() -> Weight {
+        Weight::from_parts(45_000_000_u64, 0u64)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
+
+    fn purge_token_record() -> Weight {
+        Weight::from_parts(89_000_000_u64, 0u64)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
+    }
+
+    fn link_asset_correspondence() -> Weight {
+        Weight::from_parts(58_000_000_u64, 0u64)
+            .saturating_add(RocksDbWeight::get().reads(12_u64))
+            .saturating_add(RocksDbWeight::get().writes(12_u64))
+    }
+
+    fn unlink_asset_correspondence() -> Weight {
+        Weight::from_parts(34_000_000_u64, 0u64)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
+    }
+
+    fn zip_topology(g: u32, t: u32) -> Weight {
+        Weight::from_parts(21_000_000_u64, 0u64)
+            .saturating_add(Weight::from_parts(18_000_000_u64, 0u64).saturating_mul(g as u64))
+            .saturating_add(Weight::from_parts(8_000_000_u64, 0u64).saturating_mul(t as u64))
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(g as u64)))
+            .saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(t as u64)))
+    }
+
+    fn unzip_topology() -> Weight {
+        Weight::from_parts(186_000_000_u64, 0u64)
+            .saturating_add(RocksDbWeight::get().reads(16_u64))
+            .saturating_add(RocksDbWeight::get().writes(16_u64))
+    }
+
+    fn update_token_sys_props() -> Weight {
+        Weight::from_parts(28_000_000_u64, 0u64)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn reserve_gateway_id() -> Weight {
+        Weight::from_parts(34_000_000_u64, 0u64)
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+
+    fn force_reserve_gateway_id() -> Weight {
+        Weight::from_parts(31_000_000_u64, 0u64)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+
+    fn release_gateway_id() -> Weight {
+        Weight::from_parts(32_000_000_u64, 0u64)
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+
+    fn quote_xtx_cost(n: u32) -> Weight {
+        Weight::from_parts(12_000_000_u64, 0u64)
+            .saturating_add(Weight::from_parts(10_000_000_u64, 0u64).saturating_mul(n as u64))
+            .saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(n as u64)))
+    }
+
+    fn submit_fee_hints(n: u32) -> Weight {
+        Weight::from_parts(18_000_000_u64, 0u64)
+            .saturating_add(Weight::from_parts(9_000_000_u64, 0u64).saturating_mul(n as u64))
+            .saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n as u64)))
+            .saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n as u64)))
+    }
+
+    fn set_fee_oracles() -> Weight {
+        Weight::from_parts(15_000_000_u64, 0u64).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
 }
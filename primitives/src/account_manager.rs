@@ -1,4 +1,7 @@
-use crate::claimable::{BenefitSource, CircuitRole};
+use crate::{
+    claimable::{BenefitSource, CircuitRole},
+    common::RoundIndex,
+};
 use codec::{Decode, Encode};
 use frame_support::dispatch::DispatchResult;
 use scale_info::TypeInfo;
@@ -31,6 +34,36 @@ pub struct RequestCharge<Account, Balance, AssetId> {
     pub role: CircuitRole,
 }
 
+/// Entry of the per-Xtx charge ledger: who owes what to whom, and how the charge ended.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct LedgerEntry<Account, Balance, AssetId> {
+    pub debtor: Account,
+    pub creditor: Account,
+    pub amount: Balance,
+    pub maybe_asset_id: Option<AssetId>,
+    pub role: CircuitRole,
+    pub outcome: Option<Outcome>,
+}
+
+/// Step of the round end ledger settlement: paying out the net credits, then pruning the
+/// finalized ledger entries.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub enum SettlementPhase {
+    Credits,
+    Ledger,
+}
+
+/// Progress of the round end ledger settlement, continued across blocks.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct SettlementCursor {
+    pub round: RoundIndex,
+    pub phase: SettlementPhase,
+    /// Raw storage key of the last item processed in the current phase
+    pub last_key: Option<Vec<u8>>,
+    pub transfers: u32,
+    pub entries: u32,
+}
+
 #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
 pub enum Outcome {
     UnexpectedFailure,
@@ -78,6 +111,9 @@ pub trait AccountManager<Account, Balance, Hash, BlockNumber, AssetId> {
 
     fn cancel_deposit(charge_id: Hash) -> bool;
 
+    /// Link a pending charge to the Xtx it was created for in the charge ledger
+    fn note_xtx_charge(xtx_id: Hash, charge_id: Hash);
+
     fn assign_deposit(charge_id: Hash, recipient: &Account) -> bool;
 
     fn transfer_deposit(
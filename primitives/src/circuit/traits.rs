@@ -1,5 +1,7 @@
 use crate::{
-    circuit::CircuitStatus, xtx::LocalState, ExecutionSource, GatewayVendor, SpeedMode, TargetId,
+    circuit::{CircuitStatus, XcmOrder},
+    xtx::LocalState,
+    ExecutionSource, GatewayVendor, SpeedMode, TargetId,
 };
 use codec::{Decode, Encode};
 use frame_support::{
@@ -14,7 +16,7 @@ use sp_core::H256;
 use sp_std::{fmt::Debug, vec::Vec};
 
 use crate::{circuit::AdaptiveTimeout, light_client::InclusionReceipt};
-use t3rn_sdk_primitives::signal::ExecutionSignal;
+use t3rn_sdk_primitives::{signal::ExecutionSignal, state::ConfirmedOutput};
 use t3rn_types::{
     fsx::FullSideEffect,
     sfx::{HardenedSideEffect, SecurityLvl, SideEffect, SideEffectId},
@@ -84,6 +86,31 @@ impl<T: ConfigSystem, Balance> LocalStateExecutionView<T, Balance> {
             steps_cnt,
         }
     }
+
+    /// Outputs of the side effects confirmed so far, flattened across the steps
+    pub fn into_confirmed_outputs(
+        self,
+    ) -> Vec<ConfirmedOutput<T::AccountId, frame_system::pallet_prelude::BlockNumberFor<T>, Balance>>
+    {
+        self.hardened_side_effects
+            .into_iter()
+            .enumerate()
+            .flat_map(|(step, sfxs)| {
+                sfxs.into_iter().filter_map(move |sfx| {
+                    sfx.confirmation_outcome.map(|outcome| ConfirmedOutput {
+                        step: step as u32,
+                        index: sfx.index,
+                        target: sfx.target,
+                        outcome,
+                        executioner: sfx.confirmed_executioner,
+                        received_at: sfx.confirmed_received_at,
+                        cost: sfx.confirmed_cost,
+                        output: sfx.confirmed_output,
+                    })
+                })
+            })
+            .collect()
+    }
 }
 
 pub trait CircuitSubmitAPI<T: ConfigSystem, Balance> {
@@ -117,6 +144,9 @@ pub trait CircuitSubmitAPI<T: ConfigSystem, Balance> {
         source: Option<ExecutionSource>,
         encoded_proof: Vec<u8>,
     ) -> Result<InclusionReceipt<BlockNumberFor<T>>, DispatchError>;
+
+    /// Weight of verifying an SFX proof of `proof_len` bytes
+    fn verify_sfx_proof_weight(proof_len: u32) -> Weight;
 }
 
 pub trait CircuitDLQ<T: ConfigSystem> {
@@ -173,3 +203,26 @@ pub trait ReadSFX<Hash, Account, Balance, BlockNumber> {
 
     fn get_fsx_requester(fsx_id: Hash) -> Result<Account, DispatchError>;
 }
+
+/// Reports the outcome of Xtx ordered over XCM back to the sibling parachains that ordered them.
+pub trait XcmOrderNotifier<Hash> {
+    fn notify(order: &XcmOrder, xtx_id: Hash, status: &CircuitStatus) -> DispatchResult;
+}
+
+impl<Hash> XcmOrderNotifier<Hash> for () {
+    fn notify(_order: &XcmOrder, _xtx_id: Hash, _status: &CircuitStatus) -> DispatchResult {
+        Ok(())
+    }
+}
+
+/// Notified of every Xtx once it has concluded, with its final status.
+pub trait OnXtxConcluded<Hash> {
+    fn on_xtx_concluded(xtx_id: Hash, status: &CircuitStatus);
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+impl<Hash: Copy> OnXtxConcluded<Hash> for Tuple {
+    fn on_xtx_concluded(xtx_id: Hash, status: &CircuitStatus) {
+        for_tuples!( #( Tuple::on_xtx_concluded(xtx_id, status); )* );
+    }
+}
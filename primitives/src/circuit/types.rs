@@ -14,8 +14,8 @@ use sp_core::{crypto::AccountId32, hexdisplay::AsBytesRef, Hasher, H160, U256};
 use sp_runtime::RuntimeDebug as Debug;
 use sp_runtime::{traits::Zero, RuntimeDebug};
 use sp_std::{convert::TryInto, default::Default, fmt::Debug, prelude::*};
-use t3rn_types::sfx::TargetId;
 pub use t3rn_types::sfx::{FullSideEffect, SecurityLvl, SideEffect};
+use t3rn_types::{bid::SFXBid, sfx::TargetId};
 
 #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, MaxEncodedLen, TypeInfo)]
 pub struct VacuumEVMOrder {
@@ -432,6 +432,8 @@ impl CircuitStatus {
                     (CircuitStatus::PendingExecution, CircuitStatus::Finished) => Ok(new),
                     (CircuitStatus::PendingExecution, CircuitStatus::FinishedAllSteps) => Ok(new),
                     (CircuitStatus::PendingExecution, CircuitStatus::Committed) => Ok(new),
+                    // confirmations rolled back by a reorg of their target
+                    (CircuitStatus::PendingExecution, CircuitStatus::Ready) => Ok(new),
                     // next steps transitions
                     (CircuitStatus::Finished, CircuitStatus::PendingExecution) => Ok(new),
                     (CircuitStatus::Finished, CircuitStatus::Ready) => Ok(new),
@@ -813,6 +815,12 @@ pub enum SFXAction<Account, Asset, Balance, Destination, Input, MaxCost> {
     // All of the DEX-related SFXs are vacuumed into a Transfer SFX in the protocol level: swap, add_liquidity, remove_liquidity, transfer asset, transfer native
     Transfer(Destination, Asset, Account, Balance),
     DynamicDestinationDeal(Destination, Asset, Balance),
+    // Deployment of a contract artifact with its constructor input, evm or wasm alike
+    Deploy(Destination, Input, Input),
+    // Delivery of an arbitrary payload to a contract, confirmed by the destination's receipt
+    Message(Destination, Account, Input),
+    // Read of a storage slot or view call of a contract or pallet, delivered back as verified on the destination
+    Read(Destination, Account, Input),
 }
 
 #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
@@ -824,6 +832,15 @@ pub struct OrderSFX<AccountId, Asset, Balance, Destination, Input, MaxCost> {
     pub remote_origin_nonce: Option<u32>,
 }
 
+/// An Xtx ordered by a sibling parachain over XCM, notified back once it concludes.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct XcmOrder {
+    /// Sibling parachain which ordered the Xtx
+    pub para_id: u32,
+    /// Encoded call on the sibling, dispatched with the (xtx_id, status) outcome appended
+    pub notify_call: Vec<u8>,
+}
+
 #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
 pub enum OrderOrigin<AccountId> {
     Local(AccountId),
@@ -928,6 +945,23 @@ where
                 encoded_args.push(amount.encode());
                 (action_id, target.into(), encoded_args)
             },
+            SFXAction::Deploy(target, code, input) => {
+                let code = code.as_bytes_ref().to_vec();
+                let encoded_args = vec![
+                    sp_io::hashing::keccak_256(&code).to_vec(), // code_hash
+                    code,
+                    input.as_bytes_ref().to_vec(),
+                ];
+                (*b"depl", target.into(), encoded_args)
+            },
+            SFXAction::Message(target, contract, payload) => {
+                let encoded_args = vec![contract.encode(), payload.as_bytes_ref().to_vec()];
+                (*b"mesg", target.into(), encoded_args)
+            },
+            SFXAction::Read(target, source, key) => {
+                let encoded_args = vec![source.encode(), key.as_bytes_ref().to_vec()];
+                (*b"read", target.into(), encoded_args)
+            },
         };
 
         let reward_asset_id = if <Asset as Into<u32>>::into(self.reward_asset.clone()) == 0 {
@@ -951,6 +985,38 @@ where
     }
 }
 
+/// SFX events executors follow: newly submitted Xtx and bids placed on their side effects.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum SfxNotification<Hash, AccountId, Balance> {
+    XtxSubmitted {
+        xtx_id: Hash,
+        requester: AccountId,
+        side_effects: Vec<SideEffect<AccountId, Balance>>,
+        sfx_ids: Vec<Hash>,
+    },
+    BidPlaced {
+        xtx_id: Hash,
+        sfx_id: Hash,
+        side_effect: SideEffect<AccountId, Balance>,
+        executor: AccountId,
+        bid: Balance,
+    },
+}
+
+/// Side effect open for bidding on its target, along with the best bid executors have to beat.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct OpenOrder<Hash, AccountId, Balance, BlockNumber> {
+    pub xtx_id: Hash,
+    pub sfx_id: Hash,
+    pub side_effect: SideEffect<AccountId, Balance>,
+    pub best_bid: Option<SFXBid<AccountId, Balance, u32>>,
+    /// Block bidding on the side effect closes at
+    pub bidding_closes_at: BlockNumber,
+    /// Insurance bids have to deposit, being the finality fee for Escrow and remote orders
+    pub required_insurance: Balance,
+}
+
 #[cfg(test)]
 mod tests {
     use super::{OrderSFX, SFXAction};
@@ -1162,6 +1228,50 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_try_into_message() {
+        let order_sfx = OrderSFX::<AccountId32, u32, u128, [u8; 4], Vec<u8>, u128> {
+            sfx_action: SFXAction::Message([1u8; 4], AccountId32::new([2u8; 32]), vec![3u8; 4]),
+            max_reward: 200u128,
+            insurance: 50u128,
+            reward_asset: 0u32,
+            remote_origin_nonce: None,
+        };
+
+        let result: Result<SideEffect<AccountId32, u128>, _> = order_sfx.try_into();
+        assert_ok!(&result);
+
+        let side_effect = result.unwrap();
+        assert_eq!(side_effect.target, [1u8; 4]);
+        assert_eq!(side_effect.action, *b"mesg");
+        assert_eq!(
+            side_effect.encoded_args,
+            vec![[2u8; 32].to_vec(), vec![3u8; 4]]
+        );
+    }
+
+    #[test]
+    fn test_try_into_read() {
+        let order_sfx = OrderSFX::<AccountId32, u32, u128, [u8; 4], Vec<u8>, u128> {
+            sfx_action: SFXAction::Read([1u8; 4], AccountId32::new([2u8; 32]), vec![3u8; 32]),
+            max_reward: 200u128,
+            insurance: 50u128,
+            reward_asset: 0u32,
+            remote_origin_nonce: None,
+        };
+
+        let result: Result<SideEffect<AccountId32, u128>, _> = order_sfx.try_into();
+        assert_ok!(&result);
+
+        let side_effect = result.unwrap();
+        assert_eq!(side_effect.target, [1u8; 4]);
+        assert_eq!(side_effect.action, *b"read");
+        assert_eq!(
+            side_effect.encoded_args,
+            vec![[2u8; 32].to_vec(), vec![3u8; 32]]
+        );
+    }
+
     #[test]
     fn test_try_into_call() {
         let order_sfx = OrderSFX::<AccountId32, u32, u128, [u8; 4], Vec<u8>, u128> {
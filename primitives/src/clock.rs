@@ -1,4 +1,4 @@
-use crate::common::RoundInfo;
+use crate::common::{RoundIndex, RoundInfo};
 use frame_support::{pallet_prelude::Weight, sp_runtime::traits::Zero};
 use frame_system::pallet_prelude::BlockNumberFor;
 use sp_std::marker::PhantomData;
@@ -8,6 +8,34 @@ pub trait Clock<T: frame_system::Config> {
     fn round_duration() -> BlockNumberFor<T>;
 }
 
+/// Notified when a new round duration set by governance takes effect.
+pub trait OnRoundDurationChange<BlockNumber> {
+    fn on_round_duration_change(old_duration: BlockNumber, new_duration: BlockNumber) -> Weight;
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+impl<BlockNumber: Copy> OnRoundDurationChange<BlockNumber> for Tuple {
+    fn on_round_duration_change(old_duration: BlockNumber, new_duration: BlockNumber) -> Weight {
+        let mut weight: Weight = Zero::zero();
+        for_tuples!( #( weight = weight.saturating_add(Tuple::on_round_duration_change(old_duration, new_duration)); )* );
+        weight
+    }
+}
+
+/// Notified by the Clock with the index and the first block of every new round.
+pub trait OnNewRound<BlockNumber> {
+    fn on_new_round(index: RoundIndex, head: BlockNumber) -> Weight;
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+impl<BlockNumber: Copy> OnNewRound<BlockNumber> for Tuple {
+    fn on_new_round(index: RoundIndex, head: BlockNumber) -> Weight {
+        let mut weight: Weight = Zero::zero();
+        for_tuples!( #( weight = weight.saturating_add(Tuple::on_new_round(index, head)); )* );
+        weight
+    }
+}
+
 pub struct ClockMock<T> {
     _phantom: PhantomData<T>,
 }
@@ -0,0 +1,243 @@
+//! Compressed payloads relayers and executors submit in place of large encoded headers and inclusion
+//! proofs, recognised by their prefix and decompressed within a bounded budget before verification.
+//!
+//! Payloads without the prefix are passed on as they are. Decompressing requires the
+//! `compressed-proofs` feature, otherwise compressed payloads are rejected as unsupported.
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_std::prelude::*;
+
+/// Prefix of compressed payloads, followed by the encoded Compression they were compressed with
+pub const COMPRESSED_PAYLOAD_PREFIX: [u8; 4] = *b"t3c\0";
+
+/// Most a compressed payload is trusted to inflate by, so that it's weighed before decompressing it
+pub const MAX_INFLATION_RATIO: usize = 8;
+
+#[derive(Clone, Copy, Eq, PartialEq, Encode, Decode, Debug, TypeInfo)]
+pub enum Compression {
+    Zstd,
+    Snappy,
+}
+
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub enum DecompressionError {
+    /// Compressed payloads aren't accepted without the `compressed-proofs` feature
+    Unsupported,
+    Malformed,
+    /// The payload inflates over the budget it was decompressed within
+    BudgetExceeded,
+}
+
+pub fn is_compressed(payload: &[u8]) -> bool {
+    payload.starts_with(&COMPRESSED_PAYLOAD_PREFIX)
+}
+
+/// Payload of the compressed bytes, prefixed for `decompress` to recognise it.
+pub fn with_prefix(compression: Compression, compressed: &[u8]) -> Vec<u8> {
+    let mut payload = COMPRESSED_PAYLOAD_PREFIX.to_vec();
+    compression.encode_to(&mut payload);
+    payload.extend_from_slice(compressed);
+    payload
+}
+
+/// Upper bound of the payload's size once decompressed, to weigh its verification by.
+pub fn max_decompressed_len(payload: &[u8]) -> usize {
+    if is_compressed(payload) {
+        payload.len().saturating_mul(MAX_INFLATION_RATIO)
+    } else {
+        payload.len()
+    }
+}
+
+/// Decompress the payload if it was submitted compressed, inflating it to no more than `budget`
+/// bytes nor MAX_INFLATION_RATIO times its size.
+pub fn decompress(payload: Vec<u8>, budget: usize) -> Result<Vec<u8>, DecompressionError> {
+    if !is_compressed(&payload) {
+        return Ok(payload)
+    }
+    let budget = budget.min(max_decompressed_len(&payload));
+    let mut input = &payload[COMPRESSED_PAYLOAD_PREFIX.len()..];
+    match Compression::decode(&mut input).map_err(|_| DecompressionError::Malformed)? {
+        Compression::Zstd => decompress_zstd(input, budget),
+        Compression::Snappy => decompress_snappy(input, budget),
+    }
+}
+
+#[cfg(feature = "compressed-proofs")]
+fn decompress_zstd(compressed: &[u8], budget: usize) -> Result<Vec<u8>, DecompressionError> {
+    use ruzstd::{io::Read, StreamingDecoder};
+
+    let mut decoder =
+        StreamingDecoder::new(compressed).map_err(|_| DecompressionError::Malformed)?;
+    let mut decompressed = Vec::new();
+    let mut chunk = [0u8; 4096];
+    loop {
+        let read = decoder
+            .read(&mut chunk)
+            .map_err(|_| DecompressionError::Malformed)?;
+        if read == 0 {
+            return Ok(decompressed)
+        }
+        if decompressed.len().saturating_add(read) > budget {
+            return Err(DecompressionError::BudgetExceeded)
+        }
+        decompressed.extend_from_slice(&chunk[..read]);
+    }
+}
+
+#[cfg(not(feature = "compressed-proofs"))]
+fn decompress_zstd(_compressed: &[u8], _budget: usize) -> Result<Vec<u8>, DecompressionError> {
+    Err(DecompressionError::Unsupported)
+}
+
+// Snappy's raw block format: the decompressed length as a varint, followed by literals and copies
+// of the bytes decompressed already. The length is known upfront, so it's checked before decoding.
+#[cfg(feature = "compressed-proofs")]
+fn decompress_snappy(compressed: &[u8], budget: usize) -> Result<Vec<u8>, DecompressionError> {
+    use DecompressionError::Malformed;
+
+    fn take(input: &[u8], len: usize) -> Result<(&[u8], &[u8]), DecompressionError> {
+        if input.len() < len {
+            return Err(Malformed)
+        }
+        Ok(input.split_at(len))
+    }
+
+    fn read_le(bytes: &[u8]) -> usize {
+        bytes
+            .iter()
+            .rev()
+            .fold(0usize, |acc, byte| (acc << 8) | *byte as usize)
+    }
+
+    let mut input = compressed;
+    let mut len = 0usize;
+    for shift in (0..35).step_by(7) {
+        let (byte, rest) = take(input, 1)?;
+        input = rest;
+        len |= ((byte[0] & 0x7f) as usize) << shift;
+        if byte[0] & 0x80 == 0 {
+            break
+        }
+        if shift == 28 {
+            return Err(Malformed)
+        }
+    }
+    if len > budget {
+        return Err(DecompressionError::BudgetExceeded)
+    }
+
+    let mut decompressed = Vec::with_capacity(len);
+    while let Some((&tag, rest)) = input.split_first() {
+        input = rest;
+        let (copy_len, offset) = match tag & 0x03 {
+            0 => {
+                let mut literal_len = (tag >> 2) as usize;
+                if literal_len >= 60 {
+                    let (bytes, rest) = take(input, literal_len - 59)?;
+                    input = rest;
+                    literal_len = read_le(bytes);
+                }
+                let (literal, rest) = take(input, literal_len.saturating_add(1))?;
+                input = rest;
+                if decompressed.len() + literal.len() > len {
+                    return Err(Malformed)
+                }
+                decompressed.extend_from_slice(literal);
+                continue
+            },
+            1 => {
+                let (byte, rest) = take(input, 1)?;
+                input = rest;
+                (
+                    4 + ((tag >> 2) & 0x07) as usize,
+                    ((tag as usize >> 5) << 8) | byte[0] as usize,
+                )
+            },
+            2 => {
+                let (bytes, rest) = take(input, 2)?;
+                input = rest;
+                (1 + (tag >> 2) as usize, read_le(bytes))
+            },
+            _ => {
+                let (bytes, rest) = take(input, 4)?;
+                input = rest;
+                (1 + (tag >> 2) as usize, read_le(bytes))
+            },
+        };
+        if offset == 0 || offset > decompressed.len() || decompressed.len() + copy_len > len {
+            return Err(Malformed)
+        }
+        // Copies may overlap the bytes they produce, so they're copied one at a time
+        let from = decompressed.len() - offset;
+        for i in 0..copy_len {
+            decompressed.push(decompressed[from + i]);
+        }
+    }
+    if decompressed.len() != len {
+        return Err(Malformed)
+    }
+    Ok(decompressed)
+}
+
+#[cfg(not(feature = "compressed-proofs"))]
+fn decompress_snappy(_compressed: &[u8], _budget: usize) -> Result<Vec<u8>, DecompressionError> {
+    Err(DecompressionError::Unsupported)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // "abcabcabcabc" as a literal of "abc", copied 9 bytes from 3 bytes back
+    const SNAPPY_ABC: [u8; 7] = [0x0c, 0x08, b'a', b'b', b'c', 0x15, 0x03];
+
+    #[test]
+    fn passes_on_uncompressed_payloads_as_they_are() {
+        assert_eq!(decompress(vec![1, 2, 3], 0), Ok(vec![1, 2, 3]));
+        assert_eq!(max_decompressed_len(&[1, 2, 3]), 3);
+    }
+
+    #[test]
+    fn weighs_compressed_payloads_as_inflated_by_the_max_ratio() {
+        let payload = with_prefix(Compression::Snappy, &SNAPPY_ABC);
+        assert_eq!(
+            max_decompressed_len(&payload),
+            payload.len() * MAX_INFLATION_RATIO
+        );
+    }
+
+    #[cfg(feature = "compressed-proofs")]
+    #[test]
+    fn decompresses_snappy_payloads_within_the_budget() {
+        let payload = with_prefix(Compression::Snappy, &SNAPPY_ABC);
+        assert_eq!(
+            decompress(payload.clone(), 12),
+            Ok(b"abcabcabcabc".to_vec())
+        );
+        assert_eq!(
+            decompress(payload, 11),
+            Err(DecompressionError::BudgetExceeded)
+        );
+    }
+
+    #[cfg(feature = "compressed-proofs")]
+    #[test]
+    fn rejects_snappy_copies_reaching_before_the_decompressed_bytes() {
+        let payload = with_prefix(
+            Compression::Snappy,
+            &[0x0c, 0x08, b'a', b'b', b'c', 0x15, 0x04],
+        );
+        assert_eq!(decompress(payload, 12), Err(DecompressionError::Malformed));
+    }
+
+    #[cfg(not(feature = "compressed-proofs"))]
+    #[test]
+    fn rejects_compressed_payloads_without_the_feature() {
+        let payload = with_prefix(Compression::Snappy, &SNAPPY_ABC);
+        assert_eq!(
+            decompress(payload, 12),
+            Err(DecompressionError::Unsupported)
+        );
+    }
+}
@@ -1,51 +1,114 @@
 use crate::common::{Range, RoundIndex};
-use codec::{Decode, Encode};
+use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::{pallet_prelude::*, traits::LockIdentifier};
+use frame_system::pallet_prelude::BlockNumberFor;
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
-use sp_runtime::{traits::Zero, Percent, RuntimeDebug};
+use sp_runtime::{
+    traits::{Bounded, Zero},
+    Percent, RuntimeDebug,
+};
 use sp_std::{
     cmp::{Ordering, PartialOrd},
     prelude::*,
     vec,
 };
+use t3rn_types::sfx::{Sfx4bId, TargetId};
 
 pub const EXECUTOR_LOCK_ID: LockIdentifier = *b"execstkl";
 pub const STAKER_LOCK_ID: LockIdentifier = *b"stkrstkl";
 
 #[derive(Encode, Decode, RuntimeDebug, TypeInfo)]
 pub struct NominatedStake<Account, Balance> {
-    executor: Account,
+    pub executor: Account,
     pub staker: Account,
     pub nominated_stake: Balance,
 }
 
 pub trait Executors<T: frame_system::Config, Balance> {
     fn active_set() -> Vec<T::AccountId>;
+    fn is_registered(executor: &T::AccountId) -> bool;
+    fn is_active(executor: &T::AccountId) -> bool;
+    fn is_eligible_for(executor: &T::AccountId, value: Balance) -> bool;
+    fn note_confirmation(executor: &T::AccountId, delay: BlockNumberFor<T>);
+    fn note_failure(executor: &T::AccountId);
+    fn note_obligation(executor: &T::AccountId, amount: Balance);
+    fn settle_obligation(executor: &T::AccountId, amount: Balance);
+    fn note_reward(executor: &T::AccountId, amount: Balance);
+    fn executors_serving(
+        target: &TargetId,
+        action: &Sfx4bId,
+        order_size: &Balance,
+    ) -> Vec<T::AccountId>;
+    /// Whether the challenger wins a tie of equal bids against the incumbent
+    fn outranks(challenger: &T::AccountId, incumbent: &T::AccountId) -> bool;
     fn reserve_bond(executor: &T::AccountId, amount: Balance) -> Result<Balance, DispatchError>;
     fn unreserve_bond(executor: &T::AccountId, amount: Balance) -> Balance;
     fn slash_bond(executor: &T::AccountId, amount: Balance) -> Balance;
     fn increase_bond(executor: &T::AccountId, amount: Balance) -> Balance;
     fn collateral_bond(executor: &T::AccountId) -> Balance;
+    /// Minimum self-bond an executor registers with, before any per-gateway scaling
+    fn min_executor_bond() -> Balance;
     fn total_nominated_stake(executor: &T::AccountId) -> Balance;
+    fn bonding_capacity(executor: &T::AccountId) -> Balance;
     fn stakes(staker: &T::AccountId) -> Vec<NominatedStake<T::AccountId, Balance>>;
     fn stakes_per_executor(executor: &T::AccountId) -> Vec<NominatedStake<T::AccountId, Balance>>;
     fn recalculate_executors_stakes();
+    /// Accrue a reward to the executor or staker, claimable once the given round starts
+    fn accrue_reward(who: &T::AccountId, round: RoundIndex, amount: Balance);
 }
 
 pub struct ExecutorsMock<T> {
     _phantom: PhantomData<T>,
 }
 
-impl<T: frame_system::Config, Balance: Zero> Executors<T, Balance> for ExecutorsMock<T> {
+impl<T: frame_system::Config, Balance: Zero + Bounded> Executors<T, Balance> for ExecutorsMock<T> {
     fn active_set() -> Vec<T::AccountId> {
         vec![]
     }
 
+    fn is_registered(_executor: &T::AccountId) -> bool {
+        true
+    }
+
+    fn is_active(_executor: &T::AccountId) -> bool {
+        true
+    }
+
+    fn is_eligible_for(_executor: &T::AccountId, _value: Balance) -> bool {
+        true
+    }
+
+    fn note_confirmation(_executor: &T::AccountId, _delay: BlockNumberFor<T>) {}
+
+    fn note_failure(_executor: &T::AccountId) {}
+
+    fn note_obligation(_executor: &T::AccountId, _amount: Balance) {}
+
+    fn settle_obligation(_executor: &T::AccountId, _amount: Balance) {}
+
+    fn note_reward(_executor: &T::AccountId, _amount: Balance) {}
+
+    fn executors_serving(
+        _target: &TargetId,
+        _action: &Sfx4bId,
+        _order_size: &Balance,
+    ) -> Vec<T::AccountId> {
+        vec![]
+    }
+
+    fn outranks(_challenger: &T::AccountId, _incumbent: &T::AccountId) -> bool {
+        false
+    }
+
     fn collateral_bond(_executor: &T::AccountId) -> Balance {
         Zero::zero()
     }
 
+    fn min_executor_bond() -> Balance {
+        Zero::zero()
+    }
+
     fn reserve_bond(_executor: &T::AccountId, _amount: Balance) -> Result<Balance, DispatchError> {
         Ok(Zero::zero())
     }
@@ -66,6 +129,10 @@ impl<T: frame_system::Config, Balance: Zero> Executors<T, Balance> for Executors
         Zero::zero()
     }
 
+    fn bonding_capacity(_executor: &T::AccountId) -> Balance {
+        Balance::max_value()
+    }
+
     fn stakes(_staker: &T::AccountId) -> Vec<NominatedStake<T::AccountId, Balance>> {
         vec![]
     }
@@ -75,6 +142,22 @@ impl<T: frame_system::Config, Balance: Zero> Executors<T, Balance> for Executors
     }
 
     fn recalculate_executors_stakes() {}
+
+    fn accrue_reward(_who: &T::AccountId, _round: RoundIndex, _amount: Balance) {}
+}
+
+/// Notified when an executor is deactivated for missing too many heartbeats.
+pub trait OnExecutorDeactivated<AccountId> {
+    fn on_executor_deactivated(executor: &AccountId) -> Weight;
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+impl<AccountId> OnExecutorDeactivated<AccountId> for Tuple {
+    fn on_executor_deactivated(executor: &AccountId) -> Weight {
+        let mut weight: Weight = Zero::zero();
+        for_tuples!( #( weight = weight.saturating_add(Tuple::on_executor_deactivated(executor)); )* );
+        weight
+    }
 }
 
 /// Staker's bond adjustment - used with locks.
@@ -228,7 +311,9 @@ pub struct CandidateBondLessRequest<Balance> {
 }
 
 /// An action that can be performed upon a stake
-#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo, PartialOrd, Ord)]
+#[derive(
+    Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen, PartialOrd, Ord,
+)]
 pub enum StakingAction<Balance> {
     Revoke(Balance),
     Decrease(Balance),
@@ -246,13 +331,36 @@ impl<Balance: Copy> StakingAction<Balance> {
 
 /// Represents a scheduled request that define a [StakingAction]. The request is executable
 /// iff the provided [RoundIndex] is achieved.
-#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo, PartialOrd, Ord)]
+#[derive(
+    Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen, PartialOrd, Ord,
+)]
 pub struct ScheduledStakingRequest<AccountId, Balance> {
     pub staker: AccountId,
     pub when_executable: RoundIndex,
     pub action: StakingAction<Balance>,
 }
 
+/// Part of an executor's self-bond scheduled for withdrawal. The chunk stays reserved until
+/// withdrawn once the given [RoundIndex] is achieved.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct UnbondingChunk<Balance> {
+    pub amount: Balance,
+    pub when_withdrawable: RoundIndex,
+}
+
+/// Slash reported against an executor's bond, pending until its appeal period is over.
+/// The slashed funds go to the beneficiary, or to the slash treasury if none was given.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PendingSlash<AccountId, Balance> {
+    pub executor: AccountId,
+    pub amount: Balance,
+    pub beneficiary: Option<AccountId>,
+    /// Loss the beneficiary suffered, topped up by the insurance fund where the slashed funds
+    /// fall short of it.
+    pub loss: Balance,
+    pub applies_at: RoundIndex,
+}
+
 /// Represents a cancelled scheduled request for emitting an event.
 #[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
 pub struct CancelledScheduledStakingRequest<Balance> {
@@ -269,6 +377,85 @@ impl<A, B> From<ScheduledStakingRequest<A, B>> for CancelledScheduledStakingRequ
     }
 }
 
+/// Execution history of an executor feeding into its reputation score.
+#[derive(
+    Clone, Copy, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen, Default,
+)]
+pub struct ExecutorTrackRecord {
+    /// Side effects confirmed by the executor.
+    pub confirmations: u32,
+    /// Side effects the executor won but failed to confirm before the Xtx reverted.
+    pub failures: u32,
+    /// Sum of blocks by which confirmations overran the Xtx's estimated completion height.
+    pub total_delay: u64,
+    /// Times the executor's bond was slashed.
+    pub slashes: u32,
+}
+
+impl ExecutorTrackRecord {
+    pub fn success_rate(&self) -> Percent {
+        Percent::from_rational(
+            self.confirmations,
+            self.confirmations.saturating_add(self.failures),
+        )
+    }
+
+    pub fn average_delay(&self) -> u64 {
+        self.total_delay
+            .checked_div(self.confirmations as u64)
+            .unwrap_or_default()
+    }
+
+    pub fn has_history(&self) -> bool {
+        self.confirmations > 0 || self.failures > 0
+    }
+}
+
+/// Gateway and side effect type an executor declared to serve, optionally up to a max order size.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct ExecutorCapability<Balance> {
+    pub target: TargetId,
+    pub action: Sfx4bId,
+    pub max_order_size: Option<Balance>,
+}
+
+impl<Balance: PartialOrd> ExecutorCapability<Balance> {
+    pub fn serves(&self, target: &TargetId, action: &Sfx4bId, order_size: &Balance) -> bool {
+        &self.target == target
+            && &self.action == action
+            && self
+                .max_order_size
+                .as_ref()
+                .map_or(true, |max_order_size| order_size <= max_order_size)
+    }
+}
+
+/// Standing of a registered executor: its self-bond, delegations and declared capabilities.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct ExecutorStanding<AccountId, Balance> {
+    pub executor: AccountId,
+    pub bond: Balance,
+    /// Stakes delegated to the executor, per staker
+    pub stakes: Vec<Bond<AccountId, Balance>>,
+    pub total_stake: Balance,
+    pub capabilities: Vec<ExecutorCapability<Balance>>,
+    /// Whether the executor is selected into the active set of the current round
+    pub is_active: bool,
+}
+
+/// Work performed by an executor within a single round.
+#[derive(
+    Clone, Copy, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen, Default,
+)]
+pub struct ExecutorRoundMetrics<Balance> {
+    /// Side effects confirmed in the round.
+    pub executions: u32,
+    /// Rewards earned from Xtx committed in the round.
+    pub rewards: Balance,
+    /// Side effects that timed out and reverted in the round.
+    pub timeouts: u32,
+}
+
 /// Executor configuration information.
 #[derive(Clone, Copy, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
 pub struct ExecutorInfo {
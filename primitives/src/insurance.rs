@@ -0,0 +1,27 @@
+use sp_runtime::{traits::Zero, Percent};
+
+/// Protocol insurance fund topping up user compensation for losses that the slashed executor's
+/// bond doesn't cover.
+pub trait InsuranceFund<AccountId, Balance> {
+    /// Share of protocol fees capitalizing the fund.
+    fn fee_share() -> Percent;
+
+    /// Credit protocol funds already taken out of circulation by the caller to the fund.
+    fn capitalize(amount: Balance);
+
+    /// Pay the beneficiary the part of the loss not covered by slashed executor funds, capped
+    /// per incident and by the fund's balance. Returns the amount paid out.
+    fn compensate(beneficiary: &AccountId, loss: Balance, covered: Balance) -> Balance;
+}
+
+impl<AccountId, Balance: Zero> InsuranceFund<AccountId, Balance> for () {
+    fn fee_share() -> Percent {
+        Percent::zero()
+    }
+
+    fn capitalize(_amount: Balance) {}
+
+    fn compensate(_beneficiary: &AccountId, _loss: Balance, _covered: Balance) -> Balance {
+        Zero::zero()
+    }
+}
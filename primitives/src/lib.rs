@@ -48,20 +48,27 @@ pub mod circuit;
 pub mod claimable;
 pub mod clock;
 pub mod common;
+pub mod compression;
 pub mod contract_metadata;
 pub mod contracts_registry;
 pub mod executors;
 pub mod gateway_inbound_protocol;
+pub mod insurance;
 pub mod light_client;
 pub mod match_format;
+pub mod migrations;
 pub mod monetary;
 pub mod portal;
+pub mod retention;
 pub mod rewards;
 pub mod signature_caster;
 pub mod storage;
+#[cfg(feature = "test-utils")]
+pub mod test_utils;
 pub mod threevm;
 pub mod transfers;
 pub mod volatile;
+pub mod xbi;
 pub mod xdns;
 pub mod xtx;
 
@@ -72,6 +79,11 @@ pub type ChainId = [u8; 4];
 pub type ExecutionSource = [u8; 32];
 pub const EMPTY_EXECUTION_SOURCE: [u8; 32] = [0u8; 32];
 
+/// Version of the layout of identifiers leading the events of the circuit, portal and XDNS pallets,
+/// for indexers to decode them alike across runtime versions. Identifiers present in an event are
+/// laid out first and in order: gateway_id, xtx_id, sfx_id, executor - followed by its payload.
+pub const EVENTS_VERSION: u32 = 1;
+
 pub fn execution_source_to_option(source: ExecutionSource) -> Option<ExecutionSource> {
     if source == EMPTY_EXECUTION_SOURCE {
         None
@@ -135,6 +147,34 @@ pub enum GatewayVendor {
     XBI,
     Attesters,
 }
+/// Security rating of a gateway, reflecting the finality guarantees its light client offers.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, Debug, PartialOrd, Ord, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Default)]
+pub enum GatewaySecurityTier {
+    /// Deterministic finality proven by the light client
+    #[default]
+    Finalized,
+    /// Finality backed by economic security, e.g. collateralized attesters
+    Rational,
+    /// Probabilistic finality only; not safe for escrowed execution
+    OptimisticOnly,
+}
+
+impl GatewaySecurityTier {
+    /// Highest SFX security level the gateway can serve under this tier
+    pub fn max_security_lvl(&self) -> SecurityLvl {
+        match self {
+            GatewaySecurityTier::Finalized | GatewaySecurityTier::Rational => SecurityLvl::Escrow,
+            GatewaySecurityTier::OptimisticOnly => SecurityLvl::Optimistic,
+        }
+    }
+
+    pub fn allows(&self, security_lvl: &SecurityLvl) -> bool {
+        security_lvl <= &self.max_security_lvl()
+    }
+}
+
 use sp_std::slice::Iter;
 impl GatewayVendor {
     pub fn iterator() -> Iter<'static, GatewayVendor> {
@@ -150,6 +190,19 @@ impl GatewayVendor {
         VENDORS.iter()
     }
 
+    /// Whether the headers of the vendor's chains are final only probabilistically, so that their
+    /// light clients may switch to a heavier competing chain and roll back the headers accepted.
+    pub fn has_probabilistic_finality(&self) -> bool {
+        matches!(self, GatewayVendor::Ethereum | GatewayVendor::Sepolia)
+    }
+
+    pub fn default_security_tier(&self) -> GatewaySecurityTier {
+        match self {
+            GatewayVendor::Attesters => GatewaySecurityTier::Rational,
+            _ => GatewaySecurityTier::Finalized,
+        }
+    }
+
     pub fn eta_per_speed_mode_in_epochs<Epoch: From<u32>>(&self, speed_mode: &SpeedMode) -> Epoch {
         match self {
             GatewayVendor::Polkadot
@@ -26,6 +26,39 @@ pub struct InclusionReceipt<BlockNumber> {
     pub message: Bytes,
 }
 
+/// Finalized heights of the headers a light client still retains, and so can prove inclusion at.
+#[derive(Clone, Eq, Decode, Encode, PartialEq, Debug, TypeInfo)]
+pub struct ProvableRange<BlockNumber> {
+    pub oldest: BlockNumber,
+    pub latest: BlockNumber,
+}
+
+impl<BlockNumber: PartialOrd> ProvableRange<BlockNumber> {
+    pub fn contains(&self, height: &BlockNumber) -> bool {
+        &self.oldest <= height && height <= &self.latest
+    }
+}
+
+fn ensure_provable_at<BlockNumber: PartialOrd>(
+    range: Option<ProvableRange<BlockNumber>>,
+    height: &BlockNumber,
+) -> Result<(), DispatchError> {
+    match range {
+        Some(range) if range.contains(height) => Ok(()),
+        _ => Err("LightClient::HeightNotProvable".into()),
+    }
+}
+
+fn ensure_included_at<BlockNumber: PartialEq>(
+    receipt: InclusionReceipt<BlockNumber>,
+    height: &BlockNumber,
+) -> Result<InclusionReceipt<BlockNumber>, DispatchError> {
+    if &receipt.height != height {
+        return Err("LightClient::InclusionProvenAtOtherHeight".into())
+    }
+    Ok(receipt)
+}
+
 #[derive(Clone, Eq, Decode, Encode, PartialEq, Debug, TypeInfo)]
 pub struct LightClientHeartbeat<T: frame_system::Config> {
     pub last_heartbeat: BlockNumberFor<T>,
@@ -145,4 +178,70 @@ pub trait LightClient<T: frame_system::Config> {
         speed_mode: SpeedMode,
         message: Bytes,
     ) -> Result<Bytes, DispatchError>;
+
+    /// Header or receipt root an event inclusion proof is given against, along with the leaf of
+    /// the proven event under it, identifying the inclusion regardless of how the proof encodes
+    /// it. Light clients not deriving it leave their proofs out of the inclusion cache.
+    fn event_inclusion_root_and_leaf(
+        &self,
+        _gateway_id: [u8; 4],
+        _message: &[u8],
+    ) -> Option<([u8; 32], [u8; 32])> {
+        None
+    }
+
+    /// Lowest height rolled back by the headers submitted last, once they switched the light
+    /// client to a heavier competing chain. Vendors with absolute finality never roll back.
+    fn take_rolled_back_height(&self) -> Option<BlockNumberFor<T>> {
+        None
+    }
+
+    /// Finalized heights of the gateway the light client can still prove inclusion at.
+    /// Light clients not retaining past headers only prove at their latest finalized height.
+    fn get_provable_range(&self, _gateway_id: [u8; 4]) -> Option<ProvableRange<BlockNumberFor<T>>> {
+        match self.get_finalized_height() {
+            HeightResult::Height(height) => Some(ProvableRange {
+                oldest: height,
+                latest: height,
+            }),
+            HeightResult::NotActive => None,
+        }
+    }
+
+    /// Verifies the event inclusion in the finalized header at `height`, which may be any height
+    /// within the provable range, not only the latest one.
+    fn verify_event_inclusion_at(
+        &self,
+        gateway_id: [u8; 4],
+        height: BlockNumberFor<T>,
+        source: Option<ExecutionSource>,
+        message: Bytes,
+    ) -> Result<InclusionReceipt<BlockNumberFor<T>>, DispatchError> {
+        ensure_provable_at(self.get_provable_range(gateway_id), &height)?;
+        let receipt =
+            self.verify_event_inclusion(gateway_id, SpeedMode::Finalized, source, message)?;
+        ensure_included_at(receipt, &height)
+    }
+
+    fn verify_state_inclusion_at(
+        &self,
+        gateway_id: [u8; 4],
+        height: BlockNumberFor<T>,
+        message: Bytes,
+    ) -> Result<InclusionReceipt<BlockNumberFor<T>>, DispatchError> {
+        ensure_provable_at(self.get_provable_range(gateway_id), &height)?;
+        let receipt = self.verify_state_inclusion(gateway_id, SpeedMode::Finalized, message)?;
+        ensure_included_at(receipt, &height)
+    }
+
+    fn verify_tx_inclusion_at(
+        &self,
+        gateway_id: [u8; 4],
+        height: BlockNumberFor<T>,
+        message: Bytes,
+    ) -> Result<InclusionReceipt<BlockNumberFor<T>>, DispatchError> {
+        ensure_provable_at(self.get_provable_range(gateway_id), &height)?;
+        let receipt = self.verify_tx_inclusion(gateway_id, SpeedMode::Finalized, message)?;
+        ensure_included_at(receipt, &height)
+    }
 }
@@ -0,0 +1,135 @@
+use frame_support::{
+    storage::StorageValue,
+    traits::{Get, OnRuntimeUpgrade},
+    weights::{RuntimeDbWeight, Weight},
+};
+use sp_std::marker::PhantomData;
+#[cfg(feature = "try-runtime")]
+use {
+    codec::{Decode, Encode},
+    sp_runtime::TryRuntimeError,
+    sp_std::vec::Vec,
+};
+
+const LOG_TARGET: &str = "runtime::migrations";
+
+/// Storage migration of a pallet, run only while its `StorageMigrations` counter reads `FROM`,
+/// leaving the counter at `TO` once `Inner` migrated the storage.
+///
+/// Tuples of these rehearse in order, so a pallet lists one per version it knows how to migrate
+/// from and the one matching the live counter runs. Under `try-runtime` the checks of `Inner` run
+/// around it, while the counter is checked to land at `TO`.
+pub struct VersionedMigration<const FROM: u32, const TO: u32, Inner, Counter, DbWeight>(
+    PhantomData<(Inner, Counter, DbWeight)>,
+);
+
+impl<const FROM: u32, const TO: u32, Inner, Counter, DbWeight> OnRuntimeUpgrade
+    for VersionedMigration<FROM, TO, Inner, Counter, DbWeight>
+where
+    Inner: OnRuntimeUpgrade,
+    Counter: StorageValue<u32, Query = u32>,
+    DbWeight: Get<RuntimeDbWeight>,
+{
+    fn on_runtime_upgrade() -> Weight {
+        if Counter::get() != FROM {
+            log::debug!(
+                target: LOG_TARGET,
+                "Skipping migration {} -> {}: storage is at {}",
+                FROM,
+                TO,
+                Counter::get()
+            );
+            return DbWeight::get().reads(1)
+        }
+
+        log::info!(target: LOG_TARGET, "Migrating storage {} -> {}", FROM, TO);
+        let weight = Inner::on_runtime_upgrade();
+        Counter::put(TO);
+        weight.saturating_add(DbWeight::get().reads_writes(1, 1))
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+        let should_run = Counter::get() == FROM;
+        let state = if should_run {
+            Inner::pre_upgrade()?
+        } else {
+            Vec::new()
+        };
+        Ok((should_run, state).encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+        let (did_run, state) = <(bool, Vec<u8>)>::decode(&mut &state[..])
+            .map_err(|_| TryRuntimeError::Other("VersionedMigration state undecodable"))?;
+        if !did_run {
+            return Ok(())
+        }
+        frame_support::ensure!(
+            Counter::get() == TO,
+            "VersionedMigration left the storage at another version"
+        );
+        Inner::post_upgrade(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use frame_support::storage_alias;
+
+    #[storage_alias]
+    type Migrations = StorageValue<Test, u32, frame_support::pallet_prelude::ValueQuery>;
+
+    #[storage_alias]
+    type Migrated = StorageValue<Test, u32, frame_support::pallet_prelude::ValueQuery>;
+
+    struct CountMigration;
+
+    impl OnRuntimeUpgrade for CountMigration {
+        fn on_runtime_upgrade() -> Weight {
+            Migrated::mutate(|migrated| *migrated += 1);
+            Weight::from_parts(1, 0)
+        }
+    }
+
+    struct NoDbWeight;
+
+    impl Get<RuntimeDbWeight> for NoDbWeight {
+        fn get() -> RuntimeDbWeight {
+            RuntimeDbWeight { read: 0, write: 0 }
+        }
+    }
+
+    type Migrations0To2 = (
+        VersionedMigration<0, 2, CountMigration, Migrations, NoDbWeight>,
+        VersionedMigration<1, 2, CountMigration, Migrations, NoDbWeight>,
+    );
+
+    #[test]
+    fn versioned_migration_runs_the_step_matching_the_counter_only() {
+        sp_io::TestExternalities::default().execute_with(|| {
+            assert_eq!(
+                Migrations0To2::on_runtime_upgrade(),
+                Weight::from_parts(1, 0)
+            );
+            assert_eq!(Migrations::get(), 2);
+            assert_eq!(Migrated::get(), 1);
+
+            // Storage already migrated is left alone
+            assert_eq!(Migrations0To2::on_runtime_upgrade(), Weight::zero());
+            assert_eq!(Migrated::get(), 1);
+        });
+    }
+
+    #[test]
+    fn versioned_migration_resumes_from_an_intermediate_version() {
+        sp_io::TestExternalities::default().execute_with(|| {
+            Migrations::put(1);
+            Migrations0To2::on_runtime_upgrade();
+            assert_eq!(Migrations::get(), 2);
+            assert_eq!(Migrated::get(), 1);
+        });
+    }
+}
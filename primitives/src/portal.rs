@@ -1,14 +1,15 @@
-pub use crate::light_client::{HeaderResult, HeightResult, InclusionReceipt};
+pub use crate::light_client::{HeaderResult, HeightResult, InclusionReceipt, ProvableRange};
 use crate::{
     gateway::GatewayABIConfig, ChainId, ExecutionSource, ExecutionVendor, GatewayGenesisConfig,
     GatewayType, GatewayVendor, SpeedMode, TokenInfo,
 };
 use codec::{Decode, Encode};
+use frame_support::weights::Weight;
 use frame_system::pallet_prelude::BlockNumberFor;
 
 use crate::light_client::LightClientHeartbeat;
 use scale_info::TypeInfo;
-use sp_runtime::DispatchError;
+use sp_runtime::{DispatchError, DispatchResult};
 use sp_std::{convert::TryFrom, vec::Vec};
 use t3rn_abi::{recode::Codec, types::Bytes, Abi, FilledAbi};
 use t3rn_types::sfx::Sfx4bId;
@@ -27,6 +28,77 @@ pub struct RegistrationData {
     pub encoded_registration_data: Bytes,
 }
 
+/// A sibling parachain subscribed to the latest headers of a gateway attested by the portal.
+#[derive(Clone, Eq, Decode, Encode, PartialEq, Debug, TypeInfo)]
+pub struct HeaderSubscription<BlockNumber> {
+    /// Encoded call on the sibling, dispatched with the (gateway_id, height, header) appended
+    pub notify_call: Vec<u8>,
+    /// Height of the last header exported to the sibling
+    pub last_exported_height: Option<BlockNumber>,
+}
+
+/// Exports headers attested by the portal's light clients to the parachains subscribed to them.
+pub trait HeaderExporter<BlockNumber> {
+    fn export(
+        para_id: u32,
+        notify_call: &[u8],
+        gateway_id: ChainId,
+        height: BlockNumber,
+        header: Bytes,
+    ) -> DispatchResult;
+}
+
+impl<BlockNumber> HeaderExporter<BlockNumber> for () {
+    fn export(
+        _para_id: u32,
+        _notify_call: &[u8],
+        _gateway_id: ChainId,
+        _height: BlockNumber,
+        _header: Bytes,
+    ) -> DispatchResult {
+        Ok(())
+    }
+}
+
+/// Invalidates what depended on the headers of a gateway rolled back by a reorg.
+pub trait OnHeadersRolledBack<BlockNumber> {
+    fn on_headers_rolled_back(gateway_id: ChainId, from_height: BlockNumber) -> Weight;
+}
+
+impl<BlockNumber> OnHeadersRolledBack<BlockNumber> for () {
+    fn on_headers_rolled_back(_gateway_id: ChainId, _from_height: BlockNumber) -> Weight {
+        Weight::zero()
+    }
+}
+
+/// Applies the portal's dispatch checks to the headers relayers submit straight to the light client
+/// of a gateway, bypassing the portal.
+pub trait HeaderSubmissionHooks {
+    /// Ensure the encoded headers submitted to the gateway fit the bound of its vendor
+    fn ensure_headers_size(gateway_id: ChainId, encoded_len: usize) -> DispatchResult;
+    /// Take a header submission out of the gateway's allowance
+    fn take_header_submission(gateway_id: ChainId) -> DispatchResult;
+    /// Decompress the headers submitted compressed to the gateway, within the bound of its vendor
+    fn decompress_headers(gateway_id: ChainId, payload: Vec<u8>) -> Result<Vec<u8>, DispatchError>;
+}
+
+impl HeaderSubmissionHooks for () {
+    fn ensure_headers_size(_gateway_id: ChainId, _encoded_len: usize) -> DispatchResult {
+        Ok(())
+    }
+
+    fn take_header_submission(_gateway_id: ChainId) -> DispatchResult {
+        Ok(())
+    }
+
+    fn decompress_headers(
+        _gateway_id: ChainId,
+        payload: Vec<u8>,
+    ) -> Result<Vec<u8>, DispatchError> {
+        Ok(payload)
+    }
+}
+
 // This could be split into readable parts here, or even more specific traits in the future, if needed.
 // Something like `.. Portal: ReadHeaders + Submit { ..`
 pub trait Portal<T: frame_system::Config> {
@@ -101,11 +173,49 @@ pub trait Portal<T: frame_system::Config> {
         message: Bytes,
     ) -> Result<Bytes, DispatchError>;
 
+    /// Number of headers built on top of `height` on the gateway's fast chain, settling inclusions
+    /// at `height` on vendors with probabilistic finality as they grow deeper.
+    fn get_confirmation_depth(
+        gateway_id: ChainId,
+        height: BlockNumberFor<T>,
+    ) -> Result<BlockNumberFor<T>, DispatchError>;
+
+    /// Finalized heights of the gateway at which inclusion can still be proven, `None` if its
+    /// light client retains none.
+    fn get_provable_range(
+        gateway_id: ChainId,
+    ) -> Result<Option<ProvableRange<BlockNumberFor<T>>>, DispatchError>;
+
+    /// Verifies the event inclusion at a past finalized `height` within the provable range, for
+    /// late confirmations and evidence about events older than the latest headers.
+    fn verify_event_inclusion_at(
+        gateway_id: [u8; 4],
+        height: BlockNumberFor<T>,
+        source: Option<ExecutionSource>,
+        message: Bytes,
+    ) -> Result<InclusionReceipt<BlockNumberFor<T>>, DispatchError>;
+
+    fn verify_state_inclusion_at(
+        gateway_id: [u8; 4],
+        height: BlockNumberFor<T>,
+        message: Bytes,
+    ) -> Result<InclusionReceipt<BlockNumberFor<T>>, DispatchError>;
+
+    fn verify_tx_inclusion_at(
+        gateway_id: [u8; 4],
+        height: BlockNumberFor<T>,
+        message: Bytes,
+    ) -> Result<InclusionReceipt<BlockNumberFor<T>>, DispatchError>;
+
+    /// Verifies the inclusion and recodes the message from the gateway's codec into `out_codec`.
+    /// Falls back to the gateway's default ABI descriptor of `maybe_sfx_4b_id` stored in XDNS
+    /// if an empty `abi_descriptor` is passed.
     fn verify_state_inclusion_and_recode(
         gateway_id: [u8; 4],
         speed_mode: SpeedMode,
         message: Bytes,
         abi_descriptor: Bytes,
+        maybe_sfx_4b_id: Option<Sfx4bId>,
         out_codec: Codec,
     ) -> Result<InclusionReceipt<BlockNumberFor<T>>, DispatchError>;
 
@@ -114,6 +224,7 @@ pub trait Portal<T: frame_system::Config> {
         speed_mode: SpeedMode,
         message: Bytes,
         abi_descriptor: Bytes,
+        maybe_sfx_4b_id: Option<Sfx4bId>,
         out_codec: Codec,
     ) -> Result<InclusionReceipt<BlockNumberFor<T>>, DispatchError>;
 
@@ -123,6 +234,7 @@ pub trait Portal<T: frame_system::Config> {
         source: ExecutionSource,
         message: Bytes,
         abi_descriptor: Bytes,
+        maybe_sfx_4b_id: Option<Sfx4bId>,
         out_codec: Codec,
     ) -> Result<InclusionReceipt<BlockNumberFor<T>>, DispatchError>;
 
@@ -132,14 +244,28 @@ pub trait Portal<T: frame_system::Config> {
         encoded_registration_data: Bytes,
     ) -> Result<(), DispatchError>;
 
+    /// Returns the weight of rolling back what depended on the headers the submission reorged, on
+    /// top of the light client's own, for the submitting call to add to its post-dispatch weight.
     fn submit_encoded_headers(
         gateway_id: ChainId,
         encoded_header_data: Vec<u8>,
-    ) -> Result<(), DispatchError>;
+    ) -> Result<Weight, DispatchError>;
 
     fn turn_on(origin: T::RuntimeOrigin, gateway_id: [u8; 4]) -> Result<bool, DispatchError>;
 
     fn turn_off(origin: T::RuntimeOrigin, gateway_id: [u8; 4]) -> Result<bool, DispatchError>;
+
+    /// Weight of verifying the inclusion of an event with an inclusion proof of `proof_len` bytes.
+    fn verify_event_inclusion_weight(proof_len: u32) -> Weight;
+
+    /// Whether the event inclusion is answered from the cache of inclusions verified earlier,
+    /// skipping the light client's verification.
+    fn is_event_inclusion_cached(
+        gateway_id: ChainId,
+        speed_mode: &SpeedMode,
+        source: &Option<ExecutionSource>,
+        message: &[u8],
+    ) -> bool;
 }
 
 #[derive(Clone, Eq, Decode, Encode, PartialEq, Debug, TypeInfo)]
@@ -362,7 +488,7 @@ impl PrecompileArgs {
                         abi.recode_as(&in_codec.clone(), &t3rn_abi::Codec::Scale, true)
                     })
             },
-            t3rn_abi::Codec::Scale => Ok(input.to_vec()),
+            t3rn_abi::Codec::Scale => Ok(input[1..].to_vec()),
         }
         .map(|mut recoded| {
             recoded.insert(0, *portal_selector);
@@ -581,6 +707,20 @@ pub mod tests {
         );
     }
 
+    #[test]
+    fn test_scale_encoded_call_decodes_without_recoding() {
+        let chain_id: [u8; 4] = [9, 9, 9, 9];
+        let portal_call =
+            PrecompileArgs::VerifyTxInclusion(chain_id, SpeedMode::Finalized, vec![1, 2, 3, 4]);
+        let decoded_portal_call = PrecompileArgs::recode_to_scale_and_decode(
+            &t3rn_abi::Codec::Scale,
+            &portal_call.encode(),
+        )
+        .unwrap();
+
+        assert_eq!(decoded_portal_call, portal_call);
+    }
+
     #[test]
     fn test_get_rational_height_recodes_correctly_to_scale() {
         let chain_id: [u8; 4] = [9, 9, 9, 9];
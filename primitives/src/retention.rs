@@ -0,0 +1,110 @@
+//! Retention of the state concluded activity leaves behind, pruned in `on_idle` once it outlived
+//! the retention period of its pallet.
+//!
+//! Pallets enqueue entries under the height they concluded at, keeping a cursor at the oldest
+//! height still holding entries, so pruning never scans storage beyond what it removes.
+use codec::FullCodec;
+use frame_support::storage::{IterableStorageDoubleMap, StorageValue};
+use sp_runtime::traits::{AtLeast32BitUnsigned, One};
+
+/// Enqueue an entry concluded at `now`, to be pruned once the retention period is over.
+pub fn enqueue<BlockNumber, Key, Value, Queue, Cursor>(now: BlockNumber, key: Key, value: Value)
+where
+    BlockNumber: AtLeast32BitUnsigned + Copy + FullCodec,
+    Key: FullCodec,
+    Value: FullCodec,
+    Queue: IterableStorageDoubleMap<BlockNumber, Key, Value>,
+    Cursor: StorageValue<BlockNumber, Query = Option<BlockNumber>>,
+{
+    Queue::insert(now, key, value);
+    if Cursor::get().is_none() {
+        Cursor::put(now);
+    }
+}
+
+/// Prune the entries enqueued at least `period` blocks before `now`, oldest first, handing each
+/// of them to `prune`. Takes at most `max_steps` steps, each either an entry pruned or a height
+/// visited, and returns the number of steps taken.
+pub fn prune_expired<BlockNumber, Key, Value, Queue, Cursor>(
+    now: BlockNumber,
+    period: BlockNumber,
+    max_steps: u32,
+    mut prune: impl FnMut(Key, Value),
+) -> u32
+where
+    BlockNumber: AtLeast32BitUnsigned + Copy + FullCodec,
+    Key: FullCodec,
+    Value: FullCodec,
+    Queue: IterableStorageDoubleMap<BlockNumber, Key, Value>,
+    Cursor: StorageValue<BlockNumber, Query = Option<BlockNumber>>,
+{
+    let (Some(mut height), Some(last_expired)) = (Cursor::get(), now.checked_sub(&period)) else {
+        return 0
+    };
+
+    let mut steps = 0u32;
+    'heights: while height <= last_expired && steps < max_steps {
+        steps += 1;
+        let mut entries = Queue::drain_prefix(height);
+        while steps < max_steps {
+            let Some((key, value)) = entries.next() else {
+                height += One::one();
+                continue 'heights
+            };
+            prune(key, value);
+            steps += 1;
+        }
+        // Out of steps, with entries possibly left at this height
+        break
+    }
+
+    Cursor::put(height);
+    steps
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use frame_support::{pallet_prelude::OptionQuery, storage_alias, Twox64Concat};
+
+    #[storage_alias]
+    type Queue = StorageDoubleMap<Test, Twox64Concat, u32, Twox64Concat, u32, (), OptionQuery>;
+
+    #[storage_alias]
+    type Cursor = StorageValue<Test, u32, OptionQuery>;
+
+    fn prune(now: u32, max_steps: u32) -> Vec<u32> {
+        let mut pruned = vec![];
+        prune_expired::<u32, u32, (), Queue, Cursor>(now, 10, max_steps, |key, _| pruned.push(key));
+        pruned
+    }
+
+    #[test]
+    fn prunes_only_entries_past_the_retention_period() {
+        sp_io::TestExternalities::default().execute_with(|| {
+            enqueue::<u32, u32, (), Queue, Cursor>(5, 1, ());
+            enqueue::<u32, u32, (), Queue, Cursor>(7, 2, ());
+            assert_eq!(Cursor::get(), Some(5));
+
+            assert!(prune(14, 100).is_empty());
+            assert_eq!(prune(15, 100), vec![1]);
+            assert_eq!(prune(100, 100), vec![2]);
+            assert_eq!(Queue::iter().count(), 0);
+        });
+    }
+
+    #[test]
+    fn resumes_pruning_where_the_steps_ran_out() {
+        sp_io::TestExternalities::default().execute_with(|| {
+            enqueue::<u32, u32, (), Queue, Cursor>(1, 1, ());
+            enqueue::<u32, u32, (), Queue, Cursor>(1, 2, ());
+            enqueue::<u32, u32, (), Queue, Cursor>(2, 3, ());
+
+            // Visiting the height takes a step on top of each entry pruned
+            assert_eq!(prune(20, 2).len(), 1);
+            assert_eq!(Cursor::get(), Some(1));
+            assert_eq!(prune(20, 100).len(), 2);
+            assert_eq!(Cursor::get(), Some(11));
+        });
+    }
+}
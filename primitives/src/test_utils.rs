@@ -0,0 +1,666 @@
+//! Test doubles of the light clients and the portal, along with XDNS fixtures, for pallets and
+//! runtimes to unit test against the `Portal` and `LightClient` traits without real verifiers.
+//!
+//! Light clients are scripted per vendor and gateways are routed to them by the vendor they were
+//! registered with, all in thread-local state, so each test scripts its own and calls
+//! `MockLightClient::reset` in between. Pallet-portal wires the mock behind its
+//! `SelectLightClient` with `Ok(Box::new(MockLightClient::<T>::new(vendor)))`.
+use crate::{
+    light_client::{
+        HeaderResult, HeightResult, InclusionReceipt, LightClient, LightClientHeartbeat,
+        ProvableRange,
+    },
+    portal::Portal,
+    xdns::{AllowedSideEffects, GatewayRecord, TokenRecord, GATEWAY_RECORD_VERSION},
+    Bytes, ChainId, ExecutionSource, ExecutionVendor, GatewaySecurityTier, GatewayVendor,
+    SpeedMode, SubstrateToken, TokenInfo,
+};
+use codec::Encode;
+use frame_support::weights::Weight;
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::{traits::Saturating, DispatchError};
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, VecDeque},
+    marker::PhantomData,
+};
+use t3rn_abi::recode::Codec;
+use t3rn_types::sfx::Sfx4bId;
+
+/// Script of a mocked light client: the heights it reports and the inclusion results it returns.
+#[derive(Clone, Debug, Default)]
+pub struct LightClientScript {
+    /// `None` until initialized, with heights reported as `NotActive`
+    pub registration_data: Option<Bytes>,
+    pub fast_height: u32,
+    pub rational_height: u32,
+    pub finalized_height: u32,
+    pub latest_finalized_header: Bytes,
+    pub is_halted: bool,
+    /// Results of the next inclusion verifications, returned in order
+    pub inclusions: VecDeque<Result<InclusionReceipt<u32>, DispatchError>>,
+    /// Once the scripted inclusions ran out, include any message at the finalized height
+    pub accept_unscripted_inclusions: bool,
+    pub submitted_headers: Vec<Bytes>,
+    /// Lowest height the next header submission rolls back, switching to a heavier chain
+    pub rolled_back_height: Option<u32>,
+}
+
+thread_local! {
+    static LIGHT_CLIENTS: RefCell<BTreeMap<GatewayVendor, LightClientScript>> =
+        RefCell::new(BTreeMap::new());
+    static GATEWAYS: RefCell<BTreeMap<ChainId, GatewayVendor>> = RefCell::new(BTreeMap::new());
+}
+
+/// Light client of a vendor, answering from the script of that vendor.
+pub struct MockLightClient<T> {
+    vendor: GatewayVendor,
+    _phantom: PhantomData<T>,
+}
+
+impl<T> MockLightClient<T> {
+    pub fn new(vendor: GatewayVendor) -> Self {
+        MockLightClient {
+            vendor,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Drop the scripts of all vendors and the gateways registered with `MockPortal`.
+    pub fn reset() {
+        LIGHT_CLIENTS.with(|clients| clients.borrow_mut().clear());
+        GATEWAYS.with(|gateways| gateways.borrow_mut().clear());
+    }
+
+    /// Edit the script of `vendor`, starting off an uninitialized light client.
+    pub fn script<R>(vendor: GatewayVendor, edit: impl FnOnce(&mut LightClientScript) -> R) -> R {
+        LIGHT_CLIENTS.with(|clients| edit(clients.borrow_mut().entry(vendor).or_default()))
+    }
+
+    /// Initialize the light client of `vendor` at the given heights.
+    pub fn set_heights(vendor: GatewayVendor, fast: u32, rational: u32, finalized: u32) {
+        Self::script(vendor, |script| {
+            script.registration_data.get_or_insert_with(Vec::new);
+            script.fast_height = fast;
+            script.rational_height = rational;
+            script.finalized_height = finalized;
+        })
+    }
+
+    /// Queue the result of the next inclusion verification by the light client of `vendor`.
+    pub fn push_inclusion(
+        vendor: GatewayVendor,
+        result: Result<InclusionReceipt<u32>, DispatchError>,
+    ) {
+        Self::script(vendor, |script| script.inclusions.push_back(result))
+    }
+
+    /// Headers submitted to the light client of `vendor` so far, oldest first.
+    pub fn submitted_headers(vendor: GatewayVendor) -> Vec<Bytes> {
+        Self::script(vendor, |script| script.submitted_headers.clone())
+    }
+
+    fn read<R>(&self, read: impl FnOnce(&LightClientScript) -> R) -> R {
+        Self::script(self.vendor.clone(), |script| read(script))
+    }
+
+    fn height(&self, height: impl FnOnce(&LightClientScript) -> u32) -> HeightResult<u32> {
+        self.read(|script| match script.registration_data {
+            Some(_) => HeightResult::Height(height(script)),
+            None => HeightResult::NotActive,
+        })
+    }
+
+    fn next_inclusion(&self, message: Bytes) -> Result<InclusionReceipt<u32>, DispatchError> {
+        Self::script(self.vendor.clone(), |script| {
+            if script.is_halted {
+                return Err(DispatchError::Other("MockLightClient is halted"))
+            }
+            match script.inclusions.pop_front() {
+                Some(result) => result,
+                None if script.accept_unscripted_inclusions => Ok(InclusionReceipt {
+                    height: script.finalized_height,
+                    including_header: script.latest_finalized_header.clone(),
+                    message,
+                }),
+                None => Err(DispatchError::Other(
+                    "MockLightClient has no inclusion scripted",
+                )),
+            }
+        })
+    }
+}
+
+fn into_block_number<T: frame_system::Config>(
+    result: HeightResult<u32>,
+) -> HeightResult<BlockNumberFor<T>> {
+    match result {
+        HeightResult::Height(height) => HeightResult::Height(height.into()),
+        HeightResult::NotActive => HeightResult::NotActive,
+    }
+}
+
+fn into_receipt<T: frame_system::Config>(
+    receipt: InclusionReceipt<u32>,
+) -> InclusionReceipt<BlockNumberFor<T>> {
+    InclusionReceipt {
+        height: receipt.height.into(),
+        including_header: receipt.including_header,
+        message: receipt.message,
+    }
+}
+
+fn precompile_height<T: frame_system::Config>(result: HeightResult<u32>) -> BlockNumberFor<T> {
+    match result {
+        HeightResult::Height(height) => height.into(),
+        HeightResult::NotActive => 0u32.into(),
+    }
+}
+
+impl<T: frame_system::Config> LightClient<T> for MockLightClient<T> {
+    fn get_latest_finalized_header(&self) -> HeaderResult {
+        self.read(|script| match script.registration_data {
+            Some(_) => HeaderResult::Header(script.latest_finalized_header.clone()),
+            None => HeaderResult::NotActive,
+        })
+    }
+
+    fn get_fast_height(&self) -> HeightResult<BlockNumberFor<T>> {
+        into_block_number::<T>(self.height(|script| script.fast_height))
+    }
+
+    fn get_rational_height(&self) -> HeightResult<BlockNumberFor<T>> {
+        into_block_number::<T>(self.height(|script| script.rational_height))
+    }
+
+    fn get_finalized_height(&self) -> HeightResult<BlockNumberFor<T>> {
+        into_block_number::<T>(self.height(|script| script.finalized_height))
+    }
+
+    fn get_latest_finalized_header_precompile(&self) -> Bytes {
+        self.read(|script| script.latest_finalized_header.clone())
+    }
+
+    fn get_fast_height_precompile(&self) -> BlockNumberFor<T> {
+        precompile_height::<T>(self.height(|script| script.fast_height))
+    }
+
+    fn get_rational_height_precompile(&self) -> BlockNumberFor<T> {
+        precompile_height::<T>(self.height(|script| script.rational_height))
+    }
+
+    fn get_finalized_height_precompile(&self) -> BlockNumberFor<T> {
+        precompile_height::<T>(self.height(|script| script.finalized_height))
+    }
+
+    fn get_latest_heartbeat(&self) -> Result<LightClientHeartbeat<T>, DispatchError> {
+        Ok(self.read(|script| LightClientHeartbeat {
+            last_heartbeat: 0u32.into(),
+            last_finalized_height: script.finalized_height.into(),
+            last_rational_height: script.rational_height.into(),
+            last_fast_height: script.fast_height.into(),
+            is_halted: script.is_halted,
+            ever_initialized: script.registration_data.is_some(),
+        }))
+    }
+
+    fn initialize(
+        &self,
+        _origin: T::RuntimeOrigin,
+        _gateway_id: [u8; 4],
+        encoded_registration_data: Bytes,
+    ) -> Result<(), DispatchError> {
+        Self::script(self.vendor.clone(), |script| {
+            script.registration_data = Some(encoded_registration_data)
+        });
+        Ok(())
+    }
+
+    fn turn_on(&self, _origin: T::RuntimeOrigin) -> Result<bool, DispatchError> {
+        Self::script(self.vendor.clone(), |script| script.is_halted = false);
+        Ok(true)
+    }
+
+    fn turn_off(&self, _origin: T::RuntimeOrigin) -> Result<bool, DispatchError> {
+        Self::script(self.vendor.clone(), |script| script.is_halted = true);
+        Ok(true)
+    }
+
+    fn submit_encoded_headers(&self, encoded_headers_data: Bytes) -> Result<bool, DispatchError> {
+        Self::script(self.vendor.clone(), |script| {
+            if script.is_halted {
+                return Err(DispatchError::Other("MockLightClient is halted"))
+            }
+            script.submitted_headers.push(encoded_headers_data);
+            Ok(true)
+        })
+    }
+
+    fn verify_event_inclusion(
+        &self,
+        _gateway_id: [u8; 4],
+        _speed_mode: SpeedMode,
+        _source: Option<ExecutionSource>,
+        message: Bytes,
+    ) -> Result<InclusionReceipt<BlockNumberFor<T>>, DispatchError> {
+        self.next_inclusion(message).map(into_receipt::<T>)
+    }
+
+    fn verify_state_inclusion(
+        &self,
+        _gateway_id: [u8; 4],
+        _speed_mode: SpeedMode,
+        message: Bytes,
+    ) -> Result<InclusionReceipt<BlockNumberFor<T>>, DispatchError> {
+        self.next_inclusion(message).map(into_receipt::<T>)
+    }
+
+    fn verify_tx_inclusion(
+        &self,
+        _gateway_id: [u8; 4],
+        _speed_mode: SpeedMode,
+        message: Bytes,
+    ) -> Result<InclusionReceipt<BlockNumberFor<T>>, DispatchError> {
+        self.next_inclusion(message).map(into_receipt::<T>)
+    }
+
+    fn verify_event_inclusion_precompile(
+        &self,
+        _gateway_id: [u8; 4],
+        _speed_mode: SpeedMode,
+        _source: ExecutionSource,
+        message: Bytes,
+    ) -> Result<Bytes, DispatchError> {
+        self.next_inclusion(message)
+            .map(|receipt| receipt.message.encode())
+    }
+
+    fn verify_state_inclusion_precompile(
+        &self,
+        _gateway_id: [u8; 4],
+        _speed_mode: SpeedMode,
+        message: Bytes,
+    ) -> Result<Bytes, DispatchError> {
+        self.next_inclusion(message)
+            .map(|receipt| receipt.message.encode())
+    }
+
+    fn verify_tx_inclusion_precompile(
+        &self,
+        _gateway_id: [u8; 4],
+        _speed_mode: SpeedMode,
+        message: Bytes,
+    ) -> Result<Bytes, DispatchError> {
+        self.next_inclusion(message)
+            .map(|receipt| receipt.message.encode())
+    }
+
+    fn take_rolled_back_height(&self) -> Option<BlockNumberFor<T>> {
+        Self::script(self.vendor.clone(), |script| {
+            script.rolled_back_height.take()
+        })
+        .map(Into::into)
+    }
+}
+
+/// Portal routing each gateway to the mocked light client of the vendor it was registered with.
+/// Messages are returned as included, without recoding them between codecs.
+pub struct MockPortal<T> {
+    _phantom: PhantomData<T>,
+}
+
+impl<T: frame_system::Config> MockPortal<T> {
+    /// Route `gateway_id` to the mocked light client of `vendor`.
+    pub fn register_gateway(gateway_id: ChainId, vendor: GatewayVendor) {
+        GATEWAYS.with(|gateways| gateways.borrow_mut().insert(gateway_id, vendor));
+    }
+
+    fn light_client(gateway_id: &ChainId) -> Result<MockLightClient<T>, DispatchError> {
+        GATEWAYS
+            .with(|gateways| gateways.borrow().get(gateway_id).cloned())
+            .map(MockLightClient::new)
+            .ok_or(DispatchError::Other("MockPortal has no gateway registered"))
+    }
+}
+
+impl<T: frame_system::Config> Portal<T> for MockPortal<T> {
+    fn get_latest_heartbeat(
+        gateway_id: &ChainId,
+    ) -> Result<LightClientHeartbeat<T>, DispatchError> {
+        Self::light_client(gateway_id)?.get_latest_heartbeat()
+    }
+
+    fn get_latest_heartbeat_by_vendor(vendor: GatewayVendor) -> LightClientHeartbeat<T> {
+        MockLightClient::<T>::new(vendor)
+            .get_latest_heartbeat()
+            .unwrap_or_default()
+    }
+
+    fn get_latest_finalized_header(gateway_id: ChainId) -> Result<HeaderResult, DispatchError> {
+        Ok(Self::light_client(&gateway_id)?.get_latest_finalized_header())
+    }
+
+    fn get_finalized_height(
+        gateway_id: ChainId,
+    ) -> Result<HeightResult<BlockNumberFor<T>>, DispatchError> {
+        Ok(Self::light_client(&gateway_id)?.get_finalized_height())
+    }
+
+    fn get_rational_height(
+        gateway_id: ChainId,
+    ) -> Result<HeightResult<BlockNumberFor<T>>, DispatchError> {
+        Ok(Self::light_client(&gateway_id)?.get_rational_height())
+    }
+
+    fn get_fast_height(
+        gateway_id: ChainId,
+    ) -> Result<HeightResult<BlockNumberFor<T>>, DispatchError> {
+        Ok(Self::light_client(&gateway_id)?.get_fast_height())
+    }
+
+    fn get_latest_finalized_header_precompile(gateway_id: ChainId) -> Bytes {
+        Self::light_client(&gateway_id)
+            .map(|light_client| light_client.get_latest_finalized_header_precompile())
+            .unwrap_or_default()
+    }
+
+    fn get_finalized_height_precompile(gateway_id: ChainId) -> BlockNumberFor<T> {
+        Self::light_client(&gateway_id)
+            .map(|light_client| light_client.get_finalized_height_precompile())
+            .unwrap_or_else(|_| 0u32.into())
+    }
+
+    fn get_rational_height_precompile(gateway_id: ChainId) -> BlockNumberFor<T> {
+        Self::light_client(&gateway_id)
+            .map(|light_client| light_client.get_rational_height_precompile())
+            .unwrap_or_else(|_| 0u32.into())
+    }
+
+    fn get_fast_height_precompile(gateway_id: ChainId) -> BlockNumberFor<T> {
+        Self::light_client(&gateway_id)
+            .map(|light_client| light_client.get_fast_height_precompile())
+            .unwrap_or_else(|_| 0u32.into())
+    }
+
+    fn verify_event_inclusion(
+        gateway_id: [u8; 4],
+        speed_mode: SpeedMode,
+        source: Option<ExecutionSource>,
+        message: Bytes,
+    ) -> Result<InclusionReceipt<BlockNumberFor<T>>, DispatchError> {
+        Self::light_client(&gateway_id)?
+            .verify_event_inclusion(gateway_id, speed_mode, source, message)
+    }
+
+    fn verify_state_inclusion(
+        gateway_id: [u8; 4],
+        speed_mode: SpeedMode,
+        message: Bytes,
+    ) -> Result<InclusionReceipt<BlockNumberFor<T>>, DispatchError> {
+        Self::light_client(&gateway_id)?.verify_state_inclusion(gateway_id, speed_mode, message)
+    }
+
+    fn verify_tx_inclusion(
+        gateway_id: [u8; 4],
+        speed_mode: SpeedMode,
+        message: Bytes,
+    ) -> Result<InclusionReceipt<BlockNumberFor<T>>, DispatchError> {
+        Self::light_client(&gateway_id)?.verify_tx_inclusion(gateway_id, speed_mode, message)
+    }
+
+    fn verify_event_inclusion_precompile(
+        gateway_id: [u8; 4],
+        speed_mode: SpeedMode,
+        source: ExecutionSource,
+        message: Bytes,
+    ) -> Result<Bytes, DispatchError> {
+        Self::light_client(&gateway_id)?
+            .verify_event_inclusion_precompile(gateway_id, speed_mode, source, message)
+    }
+
+    fn verify_state_inclusion_precompile(
+        gateway_id: [u8; 4],
+        speed_mode: SpeedMode,
+        message: Bytes,
+    ) -> Result<Bytes, DispatchError> {
+        Self::light_client(&gateway_id)?
+            .verify_state_inclusion_precompile(gateway_id, speed_mode, message)
+    }
+
+    fn verify_tx_inclusion_precompile(
+        gateway_id: [u8; 4],
+        speed_mode: SpeedMode,
+        message: Bytes,
+    ) -> Result<Bytes, DispatchError> {
+        Self::light_client(&gateway_id)?
+            .verify_tx_inclusion_precompile(gateway_id, speed_mode, message)
+    }
+
+    fn get_confirmation_depth(
+        gateway_id: ChainId,
+        height: BlockNumberFor<T>,
+    ) -> Result<BlockNumberFor<T>, DispatchError> {
+        match Self::light_client(&gateway_id)?.get_fast_height() {
+            HeightResult::Height(fast_height) => Ok(fast_height.saturating_sub(height)),
+            HeightResult::NotActive => Err(DispatchError::Other("MockLightClient is not active")),
+        }
+    }
+
+    fn get_provable_range(
+        gateway_id: ChainId,
+    ) -> Result<Option<ProvableRange<BlockNumberFor<T>>>, DispatchError> {
+        Ok(Self::light_client(&gateway_id)?.get_provable_range(gateway_id))
+    }
+
+    fn verify_event_inclusion_at(
+        gateway_id: [u8; 4],
+        height: BlockNumberFor<T>,
+        source: Option<ExecutionSource>,
+        message: Bytes,
+    ) -> Result<InclusionReceipt<BlockNumberFor<T>>, DispatchError> {
+        Self::light_client(&gateway_id)?
+            .verify_event_inclusion_at(gateway_id, height, source, message)
+    }
+
+    fn verify_state_inclusion_at(
+        gateway_id: [u8; 4],
+        height: BlockNumberFor<T>,
+        message: Bytes,
+    ) -> Result<InclusionReceipt<BlockNumberFor<T>>, DispatchError> {
+        Self::light_client(&gateway_id)?.verify_state_inclusion_at(gateway_id, height, message)
+    }
+
+    fn verify_tx_inclusion_at(
+        gateway_id: [u8; 4],
+        height: BlockNumberFor<T>,
+        message: Bytes,
+    ) -> Result<InclusionReceipt<BlockNumberFor<T>>, DispatchError> {
+        Self::light_client(&gateway_id)?.verify_tx_inclusion_at(gateway_id, height, message)
+    }
+
+    fn verify_state_inclusion_and_recode(
+        gateway_id: [u8; 4],
+        speed_mode: SpeedMode,
+        message: Bytes,
+        _abi_descriptor: Bytes,
+        _maybe_sfx_4b_id: Option<Sfx4bId>,
+        _out_codec: Codec,
+    ) -> Result<InclusionReceipt<BlockNumberFor<T>>, DispatchError> {
+        Self::verify_state_inclusion(gateway_id, speed_mode, message)
+    }
+
+    fn verify_tx_inclusion_and_recode(
+        gateway_id: [u8; 4],
+        speed_mode: SpeedMode,
+        message: Bytes,
+        _abi_descriptor: Bytes,
+        _maybe_sfx_4b_id: Option<Sfx4bId>,
+        _out_codec: Codec,
+    ) -> Result<InclusionReceipt<BlockNumberFor<T>>, DispatchError> {
+        Self::verify_tx_inclusion(gateway_id, speed_mode, message)
+    }
+
+    fn verify_event_inclusion_and_recode(
+        gateway_id: [u8; 4],
+        speed_mode: SpeedMode,
+        source: ExecutionSource,
+        message: Bytes,
+        _abi_descriptor: Bytes,
+        _maybe_sfx_4b_id: Option<Sfx4bId>,
+        _out_codec: Codec,
+    ) -> Result<InclusionReceipt<BlockNumberFor<T>>, DispatchError> {
+        Self::verify_event_inclusion(gateway_id, speed_mode, Some(source), message)
+    }
+
+    fn initialize(
+        origin: T::RuntimeOrigin,
+        gateway_id: [u8; 4],
+        encoded_registration_data: Bytes,
+    ) -> Result<(), DispatchError> {
+        Self::light_client(&gateway_id)?.initialize(origin, gateway_id, encoded_registration_data)
+    }
+
+    fn submit_encoded_headers(
+        gateway_id: ChainId,
+        encoded_header_data: Vec<u8>,
+    ) -> Result<Weight, DispatchError> {
+        Self::light_client(&gateway_id)?
+            .submit_encoded_headers(encoded_header_data)
+            .map(|_| Weight::zero())
+    }
+
+    fn turn_on(origin: T::RuntimeOrigin, gateway_id: [u8; 4]) -> Result<bool, DispatchError> {
+        Self::light_client(&gateway_id)?.turn_on(origin)
+    }
+
+    fn turn_off(origin: T::RuntimeOrigin, gateway_id: [u8; 4]) -> Result<bool, DispatchError> {
+        Self::light_client(&gateway_id)?.turn_off(origin)
+    }
+
+    fn verify_event_inclusion_weight(_proof_len: u32) -> Weight {
+        Weight::zero()
+    }
+
+    fn is_event_inclusion_cached(
+        _gateway_id: ChainId,
+        _speed_mode: &SpeedMode,
+        _source: &Option<ExecutionSource>,
+        _message: &[u8],
+    ) -> bool {
+        false
+    }
+}
+
+/// Side effects enabled on the gateways of the XDNS fixtures, as in the mock runtime
+pub fn standard_allowed_side_effects() -> AllowedSideEffects {
+    AllowedSideEffects::truncate_from(vec![
+        (*b"tran", Some(2)),
+        (*b"tass", Some(4)),
+        (*b"swap", Some(3)),
+        (*b"aliq", Some(3)),
+        (*b"cevm", Some(10)),
+        (*b"wasm", Some(10)),
+    ])
+}
+
+/// Gateway record of a finalized gateway verified by `vendor`, with the standard side effects.
+pub fn gateway_record<AccountId>(
+    gateway_id: ChainId,
+    vendor: GatewayVendor,
+    execution_vendor: ExecutionVendor,
+) -> GatewayRecord<AccountId> {
+    let codec = match execution_vendor {
+        ExecutionVendor::EVM => t3rn_abi::Codec::Rlp,
+        _ => t3rn_abi::Codec::Scale,
+    };
+    GatewayRecord {
+        gateway_id,
+        verification_vendor: vendor,
+        execution_vendor,
+        codec,
+        registrant: None,
+        escrow_account: None,
+        allowed_side_effects: standard_allowed_side_effects(),
+        security_tier: GatewaySecurityTier::Finalized,
+        fee_schedule: Default::default(),
+        assets: Default::default(),
+        abi_descriptors: Default::default(),
+        version: GATEWAY_RECORD_VERSION,
+    }
+}
+
+/// Record of a Substrate token `token_id` of 12 decimals on `gateway_id`.
+pub fn token_record(token_id: u32, gateway_id: ChainId, symbol: &[u8]) -> TokenRecord {
+    TokenRecord {
+        token_id,
+        gateway_id,
+        token_props: TokenInfo::Substrate(SubstrateToken {
+            id: token_id,
+            symbol: symbol.to_vec(),
+            decimals: 12,
+        }),
+    }
+}
+
+/// Gateway records of a Substrate gateway on each of the GRANDPA vendors and an EVM gateway on
+/// Ethereum, i.e. `pdot`, `ksma`, `roco` and `eth2`, registered with `MockPortal` as well.
+pub fn register_standard_gateways<AccountId>() -> Vec<GatewayRecord<AccountId>> {
+    [
+        (
+            *b"pdot",
+            GatewayVendor::Polkadot,
+            ExecutionVendor::Substrate,
+        ),
+        (*b"ksma", GatewayVendor::Kusama, ExecutionVendor::Substrate),
+        (*b"roco", GatewayVendor::Rococo, ExecutionVendor::Substrate),
+        (*b"eth2", GatewayVendor::Ethereum, ExecutionVendor::EVM),
+    ]
+    .into_iter()
+    .map(|(gateway_id, vendor, execution_vendor)| {
+        GATEWAYS.with(|gateways| gateways.borrow_mut().insert(gateway_id, vendor.clone()));
+        gateway_record(gateway_id, vendor, execution_vendor)
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mini_mock::MiniRuntime;
+
+    #[test]
+    fn mock_portal_answers_from_the_script_of_the_gateway_vendor() {
+        MockLightClient::<MiniRuntime>::reset();
+        register_standard_gateways::<u64>();
+        type Portal = MockPortal<MiniRuntime>;
+
+        assert_eq!(
+            Portal::get_finalized_height(*b"ksma"),
+            Ok(HeightResult::NotActive)
+        );
+        MockLightClient::<MiniRuntime>::set_heights(GatewayVendor::Kusama, 12, 11, 10);
+        assert_eq!(
+            Portal::get_finalized_height(*b"ksma"),
+            Ok(HeightResult::Height(10))
+        );
+        assert_eq!(
+            Portal::get_fast_height(*b"pdot"),
+            Ok(HeightResult::NotActive)
+        );
+
+        let receipt = InclusionReceipt {
+            height: 10u32,
+            including_header: vec![1u8; 32],
+            message: vec![2u8; 8],
+        };
+        MockLightClient::<MiniRuntime>::push_inclusion(GatewayVendor::Kusama, Ok(receipt.clone()));
+        assert_eq!(
+            Portal::verify_event_inclusion(*b"ksma", SpeedMode::Finalized, None, vec![]),
+            Ok(into_receipt::<MiniRuntime>(receipt))
+        );
+        assert!(
+            Portal::verify_event_inclusion(*b"ksma", SpeedMode::Finalized, None, vec![]).is_err()
+        );
+        assert!(Portal::get_finalized_height(*b"unkn").is_err());
+    }
+}
@@ -35,6 +35,7 @@ pub const VACUUM_SUBMIT_FAULT_PROOF: u8 = 94;
 pub const VACUUM_TELEPORT_ORDER: u8 = 95;
 pub const SUBMIT: u8 = 56;
 pub const POST_SIGNAL: u8 = 57;
+pub const DECLARE: u8 = 58;
 pub const PORTAL: u8 = 70;
 
 #[derive(Encode, Decode)]
@@ -63,6 +64,11 @@ where
     VacuumTeleportOrder(T::RuntimeOrigin, VacuumEVMTeleportOrder),
     Signal(T::RuntimeOrigin, ExecutionSignal<T::Hash>),
     Portal(PortalPrecompileArgs),
+    DeclareSideEffects(
+        T::RuntimeOrigin,
+        SideEffects<T::AccountId, Balance, T::Hash>,
+        SpeedMode,
+    ),
 }
 
 /// The happy return type of an invocation
@@ -70,6 +76,7 @@ pub enum PrecompileInvocation<T: ConfigSystem, Balance> {
     GetState(LocalStateExecutionView<T, Balance>),
     Submit(LocalStateExecutionView<T, Balance>),
     Signal,
+    Declare,
     Portal(PortalExecution<T>),
     VacuumOrder(bool),
     VacuumConfirm(bool),
@@ -271,6 +278,10 @@ where
     ) -> Result<(), DispatchError>;
 
     fn try_remove_author(contract: &T::AccountId) -> Result<(), DispatchError>;
+
+    /// Post the side effects declared by local contracts throughout a successful execution to the
+    /// circuit, each contract requesting an Xtx paid for from its own balance.
+    fn post_declared_side_effects();
 }
 
 pub struct NoopThreeVm;
@@ -391,6 +402,77 @@ impl<T: ConfigSystem, Balance: Encode + Decode> ThreeVm<T, Balance> for NoopThre
     fn try_remove_author(_conztract: &<T as ConfigSystem>::AccountId) -> Result<(), DispatchError> {
         Ok(())
     }
+
+    fn post_declared_side_effects() {}
+}
+
+/// Converts between EVM gas and weight, which WASM contracts and the circuit are metered in, so
+/// that a single gas budget can be spent in either VM.
+pub trait UnifiedGas {
+    fn gas_to_weight(gas: u64) -> Weight;
+    fn weight_to_gas(weight: Weight) -> u64;
+}
+
+/// A unit of gas per unit of ref time.
+impl UnifiedGas for () {
+    fn gas_to_weight(gas: u64) -> Weight {
+        Weight::from_parts(gas, 0)
+    }
+
+    fn weight_to_gas(weight: Weight) -> u64 {
+        weight.ref_time()
+    }
+}
+
+/// Delivers the results of concluded Xtx back to the local contracts that originated them.
+pub trait XtxResultCallback<AccountId> {
+    /// Whether the account is a contract the results can be delivered to
+    fn is_contract(contract: &AccountId) -> bool;
+
+    /// Re-invoke the contract at its result selector with the SCALE encoded result, spending no
+    /// more than `gas_limit`. Returns the gas consumed.
+    fn deliver(
+        contract: &AccountId,
+        result: Vec<u8>,
+        gas_limit: Weight,
+    ) -> Result<Weight, DispatchError>;
+}
+
+impl<AccountId> XtxResultCallback<AccountId> for () {
+    fn is_contract(_contract: &AccountId) -> bool {
+        false
+    }
+
+    fn deliver(
+        _contract: &AccountId,
+        _result: Vec<u8>,
+        _gas_limit: Weight,
+    ) -> Result<Weight, DispatchError> {
+        Err("No contracts to deliver Xtx results to".into())
+    }
+}
+
+/// Delivers to whichever VM the contract lives in, trying `A` first.
+impl<AccountId, A, B> XtxResultCallback<AccountId> for (A, B)
+where
+    A: XtxResultCallback<AccountId>,
+    B: XtxResultCallback<AccountId>,
+{
+    fn is_contract(contract: &AccountId) -> bool {
+        A::is_contract(contract) || B::is_contract(contract)
+    }
+
+    fn deliver(
+        contract: &AccountId,
+        result: Vec<u8>,
+        gas_limit: Weight,
+    ) -> Result<Weight, DispatchError> {
+        if A::is_contract(contract) {
+            A::deliver(contract, result, gas_limit)
+        } else {
+            B::deliver(contract, result, gas_limit)
+        }
+    }
 }
 
 pub trait ModuleOperations<T: ConfigSystem, Balance> {
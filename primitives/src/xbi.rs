@@ -0,0 +1,496 @@
+//! XBI (cross-chain blockchain interface) format of side effect orders and their results, letting
+//! parachains already speaking XBI order side effects and read their outcomes without custom
+//! adapters.
+//!
+//! Each XBI instruction maps onto the standard side effect of the same kind, its fields laid out
+//! as the side effect's ordered arguments. Optional fields are left as empty arguments when unset.
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_core::{H160, H256};
+use sp_runtime::{DispatchError, RuntimeDebug};
+use sp_std::{vec, vec::Vec};
+use t3rn_types::sfx::{
+    ConfirmationOutcome, ConfirmedSideEffect, SideEffect, TargetId, ADD_LIQUIDITY_SIDE_EFFECT_ID,
+    ASSETS_TRANSFER_SIDE_EFFECT_ID, EVM_CALL_SIDE_EFFECT_ID, SWAP_SIDE_EFFECT_ID,
+    TRANSFER_SIDE_EFFECT_ID, WASM_CALL_SIDE_EFFECT_ID,
+};
+
+pub const CALL_CUSTOM_SIDE_EFFECT_ID: &[u8; 4] = b"cgen";
+
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum XbiInstr<AccountId, Balance> {
+    CallWasm {
+        dest: AccountId,
+        value: Balance,
+        gas_limit: Balance,
+        storage_deposit_limit: Option<Balance>,
+        data: Vec<u8>,
+    },
+    CallEvm {
+        target: H160,
+        value: Balance,
+        input: Vec<u8>,
+        gas_limit: Balance,
+        max_fee_per_gas: Balance,
+        max_priority_fee_per_gas: Option<Balance>,
+        nonce: Option<Balance>,
+        access_list: Vec<u8>,
+    },
+    CallCustom {
+        dest: AccountId,
+        value: Balance,
+        input: Vec<u8>,
+        limit: Balance,
+        additional_params: Vec<u8>,
+    },
+    Transfer {
+        dest: AccountId,
+        value: Balance,
+    },
+    TransferAssets {
+        currency_id: u32,
+        dest: AccountId,
+        value: Balance,
+    },
+    Swap {
+        dest: AccountId,
+        amount_from: Balance,
+        amount_to: Balance,
+        asset_from: AccountId,
+        asset_to: AccountId,
+    },
+    AddLiquidity {
+        dest: AccountId,
+        asset_left: AccountId,
+        asset_right: AccountId,
+        liquidity_token: AccountId,
+        amount_left: Balance,
+        amount_right: Balance,
+        amount_liquidity_token: Balance,
+    },
+}
+
+/// What the ordering parachain is willing to pay for an instruction on `dest`.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct XbiMetadata<Balance> {
+    pub dest: TargetId,
+    pub max_exec_cost: Balance,
+    pub insurance: Balance,
+    /// Asset the execution cost is paid in, native if unset
+    pub cost_asset: Option<u32>,
+}
+
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct XbiFormat<AccountId, Balance> {
+    pub instr: XbiInstr<AccountId, Balance>,
+    pub metadata: XbiMetadata<Balance>,
+}
+
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum XbiStatus {
+    Success,
+    FailedExecution,
+    ExecutionTimeout,
+}
+
+/// Outcome of the side effect `id`, reported back in XBI.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct XbiResult {
+    pub id: H256,
+    pub status: XbiStatus,
+    pub output: Vec<u8>,
+    pub witness: Vec<u8>,
+}
+
+impl XbiResult {
+    pub fn from_confirmation<AccountId, BlockNumber, Balance>(
+        id: H256,
+        confirmation: &ConfirmedSideEffect<AccountId, BlockNumber, Balance>,
+    ) -> Self {
+        let status = match confirmation.err {
+            None | Some(ConfirmationOutcome::Success) => XbiStatus::Success,
+            Some(ConfirmationOutcome::MisbehaviourMalformedValues { .. }) =>
+                XbiStatus::FailedExecution,
+            Some(ConfirmationOutcome::TimedOut) => XbiStatus::ExecutionTimeout,
+        };
+        XbiResult {
+            id,
+            status,
+            output: confirmation.output.clone().unwrap_or_default(),
+            witness: confirmation.inclusion_data.clone(),
+        }
+    }
+
+    /// Confirmation of the side effect by `executioner`, carrying the result's witness as its
+    /// inclusion proof.
+    pub fn into_confirmation<AccountId, BlockNumber, Balance>(
+        self,
+        executioner: AccountId,
+        received_at: BlockNumber,
+    ) -> ConfirmedSideEffect<AccountId, BlockNumber, Balance> {
+        let err = match self.status {
+            XbiStatus::Success => None,
+            XbiStatus::FailedExecution => Some(ConfirmationOutcome::MisbehaviourMalformedValues {
+                key: vec![],
+                expected: vec![],
+                received: self.output.clone(),
+            }),
+            XbiStatus::ExecutionTimeout => Some(ConfirmationOutcome::TimedOut),
+        };
+        ConfirmedSideEffect {
+            err,
+            output: Some(self.output),
+            inclusion_data: self.witness,
+            executioner,
+            received_at,
+            cost: None,
+        }
+    }
+}
+
+fn encode_optional<T: Encode>(maybe_value: &Option<T>) -> Vec<u8> {
+    maybe_value.as_ref().map(Encode::encode).unwrap_or_default()
+}
+
+fn decode_arg<T: Decode>(args: &[Vec<u8>], index: usize) -> Result<T, DispatchError> {
+    let arg = args.get(index).ok_or("XBI::SideEffectArgumentMissing")?;
+    T::decode(&mut &arg[..]).map_err(|_| "XBI::SideEffectArgumentUndecodable".into())
+}
+
+fn decode_optional_arg<T: Decode>(
+    args: &[Vec<u8>],
+    index: usize,
+) -> Result<Option<T>, DispatchError> {
+    match args.get(index) {
+        None => Ok(None),
+        Some(arg) if arg.is_empty() => Ok(None),
+        Some(_) => decode_arg(args, index).map(Some),
+    }
+}
+
+fn bytes_arg(args: &[Vec<u8>], index: usize) -> Result<Vec<u8>, DispatchError> {
+    args.get(index)
+        .cloned()
+        .ok_or_else(|| "XBI::SideEffectArgumentMissing".into())
+}
+
+impl<AccountId: Encode, Balance: Encode> From<XbiFormat<AccountId, Balance>>
+    for SideEffect<AccountId, Balance>
+{
+    fn from(xbi: XbiFormat<AccountId, Balance>) -> Self {
+        let (action, encoded_args) = match xbi.instr {
+            XbiInstr::CallWasm {
+                dest,
+                value,
+                gas_limit,
+                storage_deposit_limit,
+                data,
+            } => (
+                *WASM_CALL_SIDE_EFFECT_ID,
+                vec![
+                    dest.encode(),
+                    value.encode(),
+                    gas_limit.encode(),
+                    encode_optional(&storage_deposit_limit),
+                    data,
+                ],
+            ),
+            XbiInstr::CallEvm {
+                target,
+                value,
+                input,
+                gas_limit,
+                max_fee_per_gas,
+                max_priority_fee_per_gas,
+                nonce,
+                access_list,
+            } => (
+                *EVM_CALL_SIDE_EFFECT_ID,
+                vec![
+                    target.as_bytes().to_vec(),
+                    value.encode(),
+                    input,
+                    gas_limit.encode(),
+                    max_fee_per_gas.encode(),
+                    encode_optional(&max_priority_fee_per_gas),
+                    encode_optional(&nonce),
+                    access_list,
+                ],
+            ),
+            XbiInstr::CallCustom {
+                dest,
+                value,
+                input,
+                limit,
+                additional_params,
+            } => (
+                *CALL_CUSTOM_SIDE_EFFECT_ID,
+                vec![
+                    dest.encode(),
+                    value.encode(),
+                    input,
+                    limit.encode(),
+                    additional_params,
+                ],
+            ),
+            XbiInstr::Transfer { dest, value } => (
+                *TRANSFER_SIDE_EFFECT_ID,
+                vec![dest.encode(), value.encode()],
+            ),
+            XbiInstr::TransferAssets {
+                currency_id,
+                dest,
+                value,
+            } => (
+                *ASSETS_TRANSFER_SIDE_EFFECT_ID,
+                vec![
+                    currency_id.to_le_bytes().to_vec(),
+                    dest.encode(),
+                    value.encode(),
+                ],
+            ),
+            XbiInstr::Swap {
+                dest,
+                amount_from,
+                amount_to,
+                asset_from,
+                asset_to,
+            } => (
+                *SWAP_SIDE_EFFECT_ID,
+                vec![
+                    dest.encode(),
+                    amount_from.encode(),
+                    amount_to.encode(),
+                    asset_from.encode(),
+                    asset_to.encode(),
+                ],
+            ),
+            XbiInstr::AddLiquidity {
+                dest,
+                asset_left,
+                asset_right,
+                liquidity_token,
+                amount_left,
+                amount_right,
+                amount_liquidity_token,
+            } => (
+                *ADD_LIQUIDITY_SIDE_EFFECT_ID,
+                vec![
+                    dest.encode(),
+                    asset_left.encode(),
+                    asset_right.encode(),
+                    liquidity_token.encode(),
+                    amount_left.encode(),
+                    amount_right.encode(),
+                    amount_liquidity_token.encode(),
+                ],
+            ),
+        };
+
+        SideEffect {
+            target: xbi.metadata.dest,
+            max_reward: xbi.metadata.max_exec_cost,
+            insurance: xbi.metadata.insurance,
+            action,
+            encoded_args,
+            signature: vec![],
+            enforce_executor: None,
+            reward_asset_id: xbi.metadata.cost_asset,
+        }
+    }
+}
+
+impl<AccountId: Decode, Balance: Decode> TryFrom<SideEffect<AccountId, Balance>>
+    for XbiFormat<AccountId, Balance>
+{
+    type Error = DispatchError;
+
+    fn try_from(sfx: SideEffect<AccountId, Balance>) -> Result<Self, Self::Error> {
+        let args = &sfx.encoded_args[..];
+        let instr = match &sfx.action {
+            action if action == WASM_CALL_SIDE_EFFECT_ID => XbiInstr::CallWasm {
+                dest: decode_arg(args, 0)?,
+                value: decode_arg(args, 1)?,
+                gas_limit: decode_arg(args, 2)?,
+                storage_deposit_limit: decode_optional_arg(args, 3)?,
+                data: bytes_arg(args, 4)?,
+            },
+            action if action == EVM_CALL_SIDE_EFFECT_ID => {
+                let target = bytes_arg(args, 0)?;
+                if target.len() != 20 {
+                    return Err("XBI::SideEffectArgumentUndecodable".into())
+                }
+                XbiInstr::CallEvm {
+                    target: H160::from_slice(&target),
+                    value: decode_arg(args, 1)?,
+                    input: bytes_arg(args, 2)?,
+                    gas_limit: decode_arg(args, 3)?,
+                    max_fee_per_gas: decode_arg(args, 4)?,
+                    max_priority_fee_per_gas: decode_optional_arg(args, 5)?,
+                    nonce: decode_optional_arg(args, 6)?,
+                    access_list: args.get(7).cloned().unwrap_or_default(),
+                }
+            },
+            action if action == CALL_CUSTOM_SIDE_EFFECT_ID => XbiInstr::CallCustom {
+                dest: decode_arg(args, 0)?,
+                value: decode_arg(args, 1)?,
+                input: bytes_arg(args, 2)?,
+                limit: decode_arg(args, 3)?,
+                additional_params: args.get(4).cloned().unwrap_or_default(),
+            },
+            action if action == TRANSFER_SIDE_EFFECT_ID => XbiInstr::Transfer {
+                dest: decode_arg(args, 0)?,
+                value: decode_arg(args, 1)?,
+            },
+            action if action == ASSETS_TRANSFER_SIDE_EFFECT_ID => {
+                let currency_id: [u8; 4] = bytes_arg(args, 0)?
+                    .try_into()
+                    .map_err(|_| "XBI::SideEffectArgumentUndecodable")?;
+                XbiInstr::TransferAssets {
+                    currency_id: u32::from_le_bytes(currency_id),
+                    dest: decode_arg(args, 1)?,
+                    value: decode_arg(args, 2)?,
+                }
+            },
+            action if action == SWAP_SIDE_EFFECT_ID => XbiInstr::Swap {
+                dest: decode_arg(args, 0)?,
+                amount_from: decode_arg(args, 1)?,
+                amount_to: decode_arg(args, 2)?,
+                asset_from: decode_arg(args, 3)?,
+                asset_to: decode_arg(args, 4)?,
+            },
+            action if action == ADD_LIQUIDITY_SIDE_EFFECT_ID => XbiInstr::AddLiquidity {
+                dest: decode_arg(args, 0)?,
+                asset_left: decode_arg(args, 1)?,
+                asset_right: decode_arg(args, 2)?,
+                liquidity_token: decode_arg(args, 3)?,
+                amount_left: decode_arg(args, 4)?,
+                amount_right: decode_arg(args, 5)?,
+                amount_liquidity_token: decode_arg(args, 6)?,
+            },
+            _ => return Err("XBI::SideEffectNotSupported".into()),
+        };
+
+        Ok(XbiFormat {
+            instr,
+            metadata: XbiMetadata {
+                dest: sfx.target,
+                max_exec_cost: sfx.max_reward,
+                insurance: sfx.insurance,
+                cost_asset: sfx.reward_asset_id,
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sp_core::crypto::AccountId32;
+
+    fn metadata() -> XbiMetadata<u128> {
+        XbiMetadata {
+            dest: *b"pdot",
+            max_exec_cost: 200,
+            insurance: 50,
+            cost_asset: Some(1),
+        }
+    }
+
+    #[test]
+    fn xbi_transfer_assets_converts_to_tass_side_effect_and_back() {
+        let xbi = XbiFormat {
+            instr: XbiInstr::TransferAssets {
+                currency_id: 1,
+                dest: AccountId32::new([2u8; 32]),
+                value: 100u128,
+            },
+            metadata: metadata(),
+        };
+
+        let side_effect: SideEffect<AccountId32, u128> = xbi.clone().into();
+        assert_eq!(side_effect.action, *b"tass");
+        assert_eq!(side_effect.target, *b"pdot");
+        assert_eq!(side_effect.max_reward, 200);
+        assert_eq!(side_effect.insurance, 50);
+        assert_eq!(side_effect.reward_asset_id, Some(1));
+        assert_eq!(
+            side_effect.encoded_args,
+            vec![vec![1u8, 0, 0, 0], [2u8; 32].to_vec(), 100u128.encode(),]
+        );
+
+        assert_eq!(XbiFormat::try_from(side_effect), Ok(xbi));
+    }
+
+    #[test]
+    fn xbi_evm_call_leaves_unset_optional_fields_as_empty_arguments() {
+        let xbi = XbiFormat {
+            instr: XbiInstr::<AccountId32, u128>::CallEvm {
+                target: H160::repeat_byte(3),
+                value: 1,
+                input: vec![4u8; 8],
+                gas_limit: 21_000,
+                max_fee_per_gas: 10,
+                max_priority_fee_per_gas: None,
+                nonce: Some(7),
+                access_list: vec![],
+            },
+            metadata: metadata(),
+        };
+
+        let side_effect: SideEffect<AccountId32, u128> = xbi.clone().into();
+        assert_eq!(side_effect.action, *b"cevm");
+        assert_eq!(side_effect.encoded_args[0], [3u8; 20].to_vec());
+        assert_eq!(side_effect.encoded_args[5], Vec::<u8>::new());
+        assert_eq!(side_effect.encoded_args[6], 7u128.encode());
+
+        assert_eq!(XbiFormat::try_from(side_effect), Ok(xbi));
+    }
+
+    #[test]
+    fn side_effects_without_xbi_instruction_fail_to_convert() {
+        let side_effect = SideEffect::<AccountId32, u128> {
+            target: *b"pdot",
+            max_reward: 0,
+            insurance: 0,
+            action: *b"data",
+            encoded_args: vec![vec![0u8; 32]],
+            signature: vec![],
+            enforce_executor: None,
+            reward_asset_id: None,
+        };
+
+        assert_eq!(
+            XbiFormat::try_from(side_effect),
+            Err("XBI::SideEffectNotSupported".into())
+        );
+    }
+
+    #[test]
+    fn xbi_result_reports_confirmation_outcome() {
+        let confirmation = ConfirmedSideEffect::<AccountId32, u32, u128> {
+            err: Some(ConfirmationOutcome::TimedOut),
+            output: None,
+            inclusion_data: vec![9u8; 4],
+            executioner: AccountId32::new([1u8; 32]),
+            received_at: 5,
+            cost: None,
+        };
+
+        let result = XbiResult::from_confirmation(H256::repeat_byte(1), &confirmation);
+        assert_eq!(
+            result,
+            XbiResult {
+                id: H256::repeat_byte(1),
+                status: XbiStatus::ExecutionTimeout,
+                output: vec![],
+                witness: vec![9u8; 4],
+            }
+        );
+
+        let confirmed: ConfirmedSideEffect<AccountId32, u32, u128> =
+            result.into_confirmation(AccountId32::new([1u8; 32]), 5);
+        assert_eq!(confirmed.err, Some(ConfirmationOutcome::TimedOut));
+        assert_eq!(confirmed.inclusion_data, vec![9u8; 4]);
+    }
+}
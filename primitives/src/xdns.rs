@@ -1,15 +1,20 @@
 use crate::{
-    gateway::GatewayABIConfig, light_client::LightClientHeartbeat, ChainId, ExecutionVendor,
-    GatewayActivity, GatewayGenesisConfig, GatewayType, GatewayVendor, SpeedMode, TokenInfo,
+    gateway::GatewayABIConfig, light_client::LightClientHeartbeat, Balance, Bytes, ChainId,
+    ExecutionVendor, FinalityVerifierActivity, GatewayActivity, GatewayGenesisConfig,
+    GatewaySecurityTier, GatewayType, GatewayVendor, SpeedMode, TokenInfo,
 };
 use codec::{Decode, Encode};
-use frame_support::dispatch::{DispatchResult, DispatchResultWithPostInfo};
+use frame_support::{
+    dispatch::{DispatchResult, DispatchResultWithPostInfo},
+    traits::ConstU32,
+    BoundedVec,
+};
 use frame_system::pallet_prelude::{BlockNumberFor, OriginFor};
 use scale_info::TypeInfo;
 use sp_core::{H160, H256};
-use sp_runtime::DispatchError;
+use sp_runtime::{DispatchError, FixedPointNumber, FixedPointOperand, FixedU128, Percent};
 use sp_std::vec::Vec;
-use t3rn_abi::sfx_abi::SFXAbi;
+use t3rn_abi::sfx_abi::{PerCodecAbiDescriptors, SFXAbi};
 use t3rn_types::sfx::{SecurityLvl, Sfx4bId};
 
 use crate::circuit::AdaptiveTimeout;
@@ -22,6 +27,32 @@ pub type XdnsRecordId = [u8; 4];
 /// A hash based on encoding the Gateway ID
 pub type XdnsGatewayId<T> = <T as frame_system::Config>::Hash;
 
+pub const MAX_ALLOWED_SIDE_EFFECTS_PER_GATEWAY: u32 = 64;
+
+/// Methods enabled to be called on the remote target: (Sfx4bId, Option<PalletIndexMemo>)
+pub type AllowedSideEffects =
+    BoundedVec<(Sfx4bId, Option<u8>), ConstU32<MAX_ALLOWED_SIDE_EFFECTS_PER_GATEWAY>>;
+
+pub const MAX_ASSETS_PER_GATEWAY: u32 = 256;
+
+/// Assets linked to the gateway
+pub type GatewayAssets = BoundedVec<AssetId, ConstU32<MAX_ASSETS_PER_GATEWAY>>;
+
+pub const MAX_ABI_DESCRIPTOR_LEN: u32 = 512;
+
+/// Ingress ABI descriptor of a side effect, in the codec of its gateway
+pub type AbiDescriptor = BoundedVec<u8, ConstU32<MAX_ABI_DESCRIPTOR_LEN>>;
+
+/// Default ABI descriptors of the side effects enrolled on the gateway
+pub type GatewayAbiDescriptors =
+    BoundedVec<(Sfx4bId, AbiDescriptor), ConstU32<MAX_ALLOWED_SIDE_EFFECTS_PER_GATEWAY>>;
+
+/// Encoded size each argument of a side effect is estimated at when quoting its fee ahead of time
+pub const ESTIMATED_SFX_ARG_SIZE: u32 = 32;
+
+/// Layout version of the gateway records written by this runtime
+pub const GATEWAY_RECORD_VERSION: u32 = 1;
+
 pub trait PalletAssetsOverlay<T: frame_system::Config, Balance> {
     fn contains_asset(asset_id: &AssetId) -> bool;
 
@@ -108,7 +139,241 @@ pub struct GatewayRecord<AccountId> {
     pub escrow_account: Option<AccountId>,
 
     /// Methods enabled to be called on the remote target: (Sfx4bId, Option<PalletIndexMemo>)
-    pub allowed_side_effects: Vec<(Sfx4bId, Option<u8>)>,
+    pub allowed_side_effects: AllowedSideEffects,
+
+    /// Security rating of the gateway, enforced against the requested SFX security level
+    pub security_tier: GatewaySecurityTier,
+
+    /// Fees charged on the gateway for executing side effects
+    pub fee_schedule: GatewayFeeSchedule,
+
+    /// Assets linked to the gateway, following its GatewayTokens
+    pub assets: GatewayAssets,
+
+    /// Default ABI descriptors of the side effects enrolled on the gateway, following its SFX ABIs
+    pub abi_descriptors: GatewayAbiDescriptors,
+
+    /// Layout version the record was written with, see GATEWAY_RECORD_VERSION
+    pub version: u32,
+}
+
+/// Fees charged on the gateway for executing side effects, denominated in its native asset.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, Default, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct GatewayFeeSchedule {
+    /// Charged once per side effect
+    pub base_fee: Balance,
+    /// Charged per byte of the side effect's encoded args
+    pub fee_per_byte: Balance,
+}
+
+impl GatewayFeeSchedule {
+    /// Fee charged for a side effect of `encoded_args_len` bytes of args
+    pub fn fee_of(&self, encoded_args_len: u32) -> Balance {
+        self.base_fee
+            .saturating_add(self.fee_per_byte.saturating_mul(encoded_args_len.into()))
+    }
+}
+
+/// Estimated cost of a prospective Xtx, denominated in the asset it was quoted in.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, Default, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct XtxCostEstimate<Balance> {
+    /// Fees expected on the targets of all side effects
+    pub fees: Balance,
+    /// Insurance executors are required to bond for all side effects
+    pub insurance: Balance,
+    /// Bids are expected to cover at least the fees...
+    pub min_bid: Balance,
+    /// ...and at most the fees plus the insurance each target is expected to revert away
+    pub max_bid: Balance,
+}
+
+/// Estimate of the max_fee attracting bids on a side effect ordered on a gateway, as submitted by
+/// the fee oracles.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, Default, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct FeeHint<Balance> {
+    /// Median of the recent winning bids on the side effect
+    pub bids_median: Balance,
+    /// Gas price last sampled on the target in its native units, zero if the target isn't sampled
+    pub gas_price: u128,
+    /// Median of the gas prices sampled on the target
+    pub gas_price_median: u128,
+    /// Median of the winning bids, scaled by how far the gas price moved off its median
+    pub max_fee: Balance,
+}
+
+impl<Balance: FixedPointOperand> FeeHint<Balance> {
+    pub fn new(bids_median: Balance, gas_price: u128, gas_price_median: u128) -> Self {
+        let max_fee = match FixedU128::checked_from_rational(gas_price, gas_price_median) {
+            Some(gas_price_drift) if gas_price > 0 =>
+                gas_price_drift.saturating_mul_int(bids_median),
+            _ => bids_median,
+        };
+        FeeHint {
+            bids_median,
+            gas_price,
+            gas_price_median,
+            max_fee,
+        }
+    }
+}
+
+/// Schema of a registered side effect type, for wallets and SDKs to build its orders by.
+/// Side effects are listed once per distinct ABI they're registered with across the gateways.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct SfxSchema {
+    pub sfx_4b_id: Sfx4bId,
+    /// Names of the encoded args in order, along with whether they're required
+    pub args_names: Vec<(Vec<u8>, bool)>,
+    /// Descriptors the encoded args are laid out by
+    pub abi_descriptors: PerCodecAbiDescriptors,
+    /// Descriptors of the event confirming the side effect on its target
+    pub confirmation_event_descriptors: PerCodecAbiDescriptors,
+    /// Gateways the side effect is registered on with this ABI
+    pub gateways: Vec<TargetId>,
+}
+
+impl SfxSchema {
+    pub fn new(sfx_4b_id: Sfx4bId, sfx_abi: SFXAbi) -> Self {
+        SfxSchema {
+            sfx_4b_id,
+            args_names: sfx_abi.args_names,
+            abi_descriptors: sfx_abi.egress_abi_descriptors,
+            confirmation_event_descriptors: sfx_abi.ingress_abi_descriptors,
+            gateways: Vec::new(),
+        }
+    }
+
+    pub fn has_same_abi(&self, other: &SfxSchema) -> bool {
+        self.sfx_4b_id == other.sfx_4b_id
+            && self.args_names == other.args_names
+            && self.abi_descriptors == other.abi_descriptors
+            && self.confirmation_event_descriptors == other.confirmation_event_descriptors
+    }
+}
+
+/// Fields of the gateway record changed by an update, emitted for indexers to refresh their caches.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, Default, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct GatewayRecordChanges {
+    pub verification_vendor: bool,
+    pub execution_vendor: bool,
+    pub codec: bool,
+    pub registrant: bool,
+    pub escrow_account: bool,
+    pub allowed_side_effects: bool,
+    pub security_tier: bool,
+    pub fee_schedule: bool,
+}
+
+impl GatewayRecordChanges {
+    pub fn between<AccountId: PartialEq>(
+        old: &GatewayRecord<AccountId>,
+        new: &GatewayRecord<AccountId>,
+    ) -> Self {
+        GatewayRecordChanges {
+            verification_vendor: old.verification_vendor != new.verification_vendor,
+            execution_vendor: old.execution_vendor != new.execution_vendor,
+            codec: old.codec != new.codec,
+            registrant: old.registrant != new.registrant,
+            escrow_account: old.escrow_account != new.escrow_account,
+            allowed_side_effects: old.allowed_side_effects != new.allowed_side_effects,
+            security_tier: old.security_tier != new.security_tier,
+            fee_schedule: old.fee_schedule != new.fee_schedule,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Rolling counters of the gateway activity, halved at every elapsed stats window.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, Default, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct GatewayActivityStats {
+    /// Target headers finalized by the gateway's light client
+    pub headers_accepted: u64,
+
+    /// Side effects requested on the gateway
+    pub sfx_targeted: u64,
+
+    /// Side effects confirmed on the gateway
+    pub sfx_confirmed: u64,
+
+    /// Side effects reverted on the gateway, left unconfirmed by their Xtx revert
+    pub sfx_reverted: u64,
+}
+
+impl GatewayActivityStats {
+    /// Share of the side effects requested on the gateway which ended up reverted
+    pub fn revert_rate(&self) -> Percent {
+        if self.sfx_targeted == 0 {
+            return Percent::from_percent(0)
+        }
+        Percent::from_rational(self.sfx_reverted.min(self.sfx_targeted), self.sfx_targeted)
+    }
+
+    /// Halves all of the counters, so that the recent activity outweighs the old one
+    pub fn decay(&mut self) {
+        self.headers_accepted /= 2;
+        self.sfx_targeted /= 2;
+        self.sfx_confirmed /= 2;
+        self.sfx_reverted /= 2;
+    }
+}
+
+/// Per-gateway factors scaling the minimum bond of an executor bidding on the gateway's side effects.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct BondScalingFactors {
+    /// Multiplier of the minimum bond required on Rational tier gateways, in percent
+    pub rational_tier: u32,
+
+    /// Multiplier of the minimum bond required on OptimisticOnly tier gateways, in percent
+    pub optimistic_only_tier: u32,
+
+    /// Multiplier added per each percent point of the gateway's revert rate, in percent
+    pub per_revert_percent: u32,
+}
+
+impl Default for BondScalingFactors {
+    fn default() -> Self {
+        BondScalingFactors {
+            rational_tier: 100,
+            optimistic_only_tier: 100,
+            per_revert_percent: 0,
+        }
+    }
+}
+
+impl BondScalingFactors {
+    /// Multiplier of the minimum bond, in percent, for a gateway of the given tier and revert rate
+    pub fn multiplier(&self, security_tier: &GatewaySecurityTier, revert_rate: Percent) -> u32 {
+        let tier_multiplier = match security_tier {
+            GatewaySecurityTier::Finalized => 100,
+            GatewaySecurityTier::Rational => self.rational_tier,
+            GatewaySecurityTier::OptimisticOnly => self.optimistic_only_tier,
+        };
+        tier_multiplier.saturating_add(
+            self.per_revert_percent
+                .saturating_mul(revert_rate.deconstruct() as u32),
+        )
+    }
+}
+
+/// Reservation of a gateway 4b id, held by an account against a refundable deposit or by governance.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct GatewayIdReservation<AccountId, Balance> {
+    /// Account entitled to register the gateway; None if reserved by governance
+    pub owner: Option<AccountId>,
+
+    /// Deposit reserved from the owner, refunded once the reservation is released
+    pub deposit: Balance,
 }
 
 #[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
@@ -287,6 +552,55 @@ pub trait Xdns<T: frame_system::Config, Balance> {
 
     fn get_self_token_id() -> AssetId;
 
+    /// Resolves the asset on `counterpart_gateway_id` that represents the same underlying asset
+    /// as `asset_id` on `gateway_id`, as declared in the asset correspondence registry.
+    /// Used to bridge assets across gateways; side effects are settled in the asset of their target.
+    fn get_corresponding_asset(
+        gateway_id: &TargetId,
+        asset_id: AssetId,
+        counterpart_gateway_id: &TargetId,
+    ) -> Option<AssetId>;
+
+    fn are_assets_corresponding(
+        gateway_id: &TargetId,
+        asset_id: AssetId,
+        counterpart_gateway_id: &TargetId,
+        counterpart_asset_id: AssetId,
+    ) -> bool;
+
+    /// Picks the best operational gateway to route the given side effect over the given asset to,
+    /// ranked by security tier and the fee of its schedule. Returns None if no active gateway
+    /// supports both.
+    fn best_gateway_for(sfx_4b_id: &Sfx4bId, asset_id: AssetId) -> Option<TargetId>;
+
+    fn note_sfx_targeted(gateway_id: &ChainId);
+
+    fn note_sfx_confirmed(gateway_id: &ChainId);
+
+    fn note_sfx_reverted(gateway_id: &ChainId);
+
+    fn get_gateway_activity_stats(gateway_id: &ChainId) -> GatewayActivityStats;
+
+    /// Schemas of all standard and registered side effects, along with the gateways they're on
+    fn fetch_sfx_schemas() -> Vec<SfxSchema>;
+
+    /// Scales the base minimum executor bond by the gateway's security tier and revert rate.
+    fn min_executor_bond(gateway_id: &ChainId, base_bond: Balance) -> Balance;
+
+    /// Estimates the cost in `quote_asset` of an Xtx of side effects moving an asset on a target,
+    /// each insured with the given amount before scaling to the target's minimum executor bond.
+    fn estimate_xtx_cost(
+        side_effects: &[(ChainId, AssetId, Balance)],
+        quote_asset: AssetId,
+    ) -> XtxCostEstimate<Balance>;
+
+    /// Notes the bid winning a side effect on the gateway, sampled by the fee oracles.
+    fn note_sfx_bid_won(gateway_id: &ChainId, sfx_4b_id: &Sfx4bId, bid: Balance);
+
+    /// Suggests the max_fee attracting bids on the side effect ordered on the gateway, as hinted
+    /// by the fee oracles. Returns None if no recent hint was submitted.
+    fn suggest_max_fee(gateway_id: &ChainId, sfx_4b_id: &Sfx4bId) -> Option<Balance>;
+
     fn add_new_gateway(
         gateway_id: [u8; 4],
         verification_vendor: GatewayVendor,
@@ -331,10 +645,21 @@ pub trait Xdns<T: frame_system::Config, Balance> {
 
     fn get_gateway_max_security_lvl(chain_id: &ChainId) -> SecurityLvl;
 
+    fn get_gateway_security_tier(chain_id: &ChainId) -> Result<GatewaySecurityTier, DispatchError>;
+
     fn get_verification_vendor(chain_id: &ChainId) -> Result<GatewayVendor, DispatchError>;
 
     fn get_target_codec(chain_id: &ChainId) -> Result<t3rn_abi::Codec, DispatchError>;
 
+    /// Returns the ingress ABI descriptor of the side effect enrolled on the gateway, as stored in its record.
+    fn get_default_abi_descriptor(
+        chain_id: &ChainId,
+        sfx_4b_id: Sfx4bId,
+    ) -> Result<Bytes, DispatchError>;
+
+    /// Returns the ingress ABI descriptors of all side effects enrolled on the gateway, as stored in its record.
+    fn get_default_abi_descriptors(chain_id: &ChainId) -> Vec<(Sfx4bId, Bytes)>;
+
     fn get_escrow_account(chain_id: &ChainId) -> Result<Vec<u8>, DispatchError>;
 
     fn fetch_full_gateway_records() -> Vec<FullGatewayRecord<T::AccountId>>;
@@ -343,6 +668,10 @@ pub trait Xdns<T: frame_system::Config, Balance> {
 
     fn read_last_activity(gateway_id: ChainId) -> Option<GatewayActivity<BlockNumberFor<T>>>;
 
+    fn read_last_verifier_activity(
+        verifier: &GatewayVendor,
+    ) -> Option<FinalityVerifierActivity<BlockNumberFor<T>>>;
+
     fn verify_active(
         gateway_id: &ChainId,
         max_acceptable_heartbeat_offset: BlockNumberFor<T>,
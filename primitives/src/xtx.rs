@@ -230,6 +230,9 @@ mod tests {
                 submission_target_height: 1,
                 best_bid: None,
                 index: 0,
+                deadline: None,
+                dependencies: vec![],
+                metadata: vec![],
             }]],
         );
 
@@ -254,7 +257,10 @@ mod tests {
                 security_lvl: SecurityLvl::Optimistic,
                 submission_target_height: 1,
                 best_bid: None,
-                index: 0
+                index: 0,
+                deadline: None,
+                dependencies: vec![],
+                metadata: vec![],
             }
         );
 
@@ -318,6 +324,9 @@ mod tests {
                     submission_target_height: 1,
                     best_bid: None,
                     index: 0,
+                    deadline: None,
+                    dependencies: vec![],
+                    metadata: vec![],
                 },
                 FullSideEffect {
                     input: input_side_effect_2.clone(),
@@ -326,6 +335,9 @@ mod tests {
                     submission_target_height: 1,
                     best_bid: None,
                     index: 1,
+                    deadline: None,
+                    dependencies: vec![],
+                    metadata: vec![],
                 },
             ]],
         );
@@ -349,7 +361,10 @@ mod tests {
                 security_lvl: SecurityLvl::Optimistic,
                 submission_target_height: 1,
                 best_bid: None,
-                index: 0
+                index: 0,
+                deadline: None,
+                dependencies: vec![],
+                metadata: vec![],
             }
         );
 
@@ -362,7 +377,10 @@ mod tests {
                 security_lvl: SecurityLvl::Optimistic,
                 submission_target_height: 1,
                 best_bid: None,
-                index: 1
+                index: 1,
+                deadline: None,
+                dependencies: vec![],
+                metadata: vec![],
             }
         );
 
@@ -388,7 +406,10 @@ mod tests {
                 security_lvl: SecurityLvl::Optimistic,
                 submission_target_height: 1,
                 best_bid: None,
-                index: 1
+                index: 1,
+                deadline: None,
+                dependencies: vec![],
+                metadata: vec![],
             }
         );
         assert!(xtx.is_completed());
@@ -452,6 +473,9 @@ mod tests {
                     submission_target_height: 1,
                     best_bid: None,
                     index: 0,
+                    deadline: None,
+                    dependencies: vec![],
+                    metadata: vec![],
                 }],
                 vec![FullSideEffect {
                     input: input_side_effect_2.clone(),
@@ -460,6 +484,9 @@ mod tests {
                     submission_target_height: 1,
                     best_bid: None,
                     index: 1,
+                    deadline: None,
+                    dependencies: vec![],
+                    metadata: vec![],
                 }],
             ],
         );
@@ -483,7 +510,10 @@ mod tests {
                 security_lvl: SecurityLvl::Optimistic,
                 submission_target_height: 1,
                 best_bid: None,
-                index: 0
+                index: 0,
+                deadline: None,
+                dependencies: vec![],
+                metadata: vec![],
             }
         );
 
@@ -496,7 +526,10 @@ mod tests {
                 security_lvl: SecurityLvl::Optimistic,
                 submission_target_height: 1,
                 best_bid: None,
-                index: 1
+                index: 1,
+                deadline: None,
+                dependencies: vec![],
+                metadata: vec![],
             }
         );
 
@@ -520,7 +553,10 @@ mod tests {
                 security_lvl: SecurityLvl::Optimistic,
                 submission_target_height: 1,
                 best_bid: None,
-                index: 1
+                index: 1,
+                deadline: None,
+                dependencies: vec![],
+                metadata: vec![],
             }
         );
         assert!(xtx.is_completed());
@@ -584,6 +620,9 @@ mod tests {
                     submission_target_height: 1,
                     best_bid: None,
                     index: 0,
+                    deadline: None,
+                    dependencies: vec![],
+                    metadata: vec![],
                 }],
                 vec![FullSideEffect {
                     input: input_side_effect_2.clone(),
@@ -592,6 +631,9 @@ mod tests {
                     submission_target_height: 1,
                     best_bid: None,
                     index: 1,
+                    deadline: None,
+                    dependencies: vec![],
+                    metadata: vec![],
                 }],
             ],
         );
@@ -614,7 +656,10 @@ mod tests {
                 security_lvl: SecurityLvl::Optimistic,
                 submission_target_height: 1,
                 best_bid: None,
-                index: 0
+                index: 0,
+                deadline: None,
+                dependencies: vec![],
+                metadata: vec![],
             }
         );
 
@@ -626,7 +671,10 @@ mod tests {
                 security_lvl: SecurityLvl::Optimistic,
                 submission_target_height: 1,
                 best_bid: None,
-                index: 1
+                index: 1,
+                deadline: None,
+                dependencies: vec![],
+                metadata: vec![],
             }
         );
 
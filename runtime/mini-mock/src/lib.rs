@@ -4,7 +4,8 @@ use frame_support::{
     assert_ok,
     dispatch::DispatchResultWithPostInfo,
     log,
-    traits::{fungibles::Destroy, AsEnsureOriginWithArg, FindAuthor},
+    traits::{fungibles::Destroy, AsEnsureOriginWithArg, FindAuthor, NeverEnsureOrigin},
+    weights::Weight,
     Blake2_128Concat, RuntimeDebug, StorageHasher,
 };
 use frame_system::EnsureSigned;
@@ -26,7 +27,17 @@ pub use pallet_circuit::{
     SFX2XTXLinksMap, XExecSignals,
 };
 pub use pallet_circuit_vacuum::{Config as ConfigVacuum, Event as VacuumEvent, OrderStatusRead};
+pub use pallet_clock::{Error as ClockError, Event as ClockEvent};
 use pallet_eth2_finality_verifier::types::Root;
+pub use pallet_executors::{
+    AccruedRewards, ActiveSet as ExecutorsActiveSet, ActiveSetRound, AutoCompound, Capabilities,
+    Commission, Config as ConfigExecutors, Deactivated as ExecutorsDeactivated,
+    Error as ExecutorsError, Event as ExecutorsEvent, Executors as ExecutorsStore, ExitQueue,
+    LastHeartbeat, MissedHeartbeats, NextSlashToApply, Obligations, PendingCommission,
+    PendingSlashes, Reputation, RoundIssuance, RoundMetrics, StakedExecutors, Stakes,
+    StakingRequests, TotalStake, TrackRecords, Unbonding,
+};
+pub use pallet_insurance_fund::{Event as InsuranceFundEvent, FeeShare, MaxPayoutPerIncident};
 mod hooks;
 mod treasuries_config;
 pub use hooks::GlobalOnInitQueues;
@@ -63,7 +74,9 @@ use sp_runtime::{
     traits::{BlakeTwo256, ConstU32, ConvertInto, IdentityLookup},
     Perbill, Percent,
 };
-use t3rn_primitives::{EthereumToken, ExecutionVendor, GatewayVendor, SubstrateToken, TokenInfo};
+use t3rn_primitives::{
+    EthereumToken, ExecutionVendor, GatewaySecurityTier, GatewayVendor, SubstrateToken, TokenInfo,
+};
 pub type AccountId = sp_runtime::AccountId32;
 pub type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<MiniRuntime>;
 pub type Block = sp_runtime::generic::Block<
@@ -102,6 +115,8 @@ frame_support::construct_runtime!(
         Clock: pallet_clock = 104,
         Circuit: pallet_circuit = 105,
         Vacuum: pallet_circuit_vacuum = 106,
+        Executors: pallet_executors = 107,
+        InsuranceFund: pallet_insurance_fund = 108,
         // Portal
         Portal: pallet_portal = 128,
         RococoBridge: pallet_grandpa_finality_verifier = 129,
@@ -212,6 +227,8 @@ impl PalletAssetsOverlay<MiniRuntime, Balance> for MiniRuntime {
 
 parameter_types! {
     pub const EscrowAccount: AccountId = AccountId::new([51u8; 32]);
+    pub const ProtocolBurn: Percent = Percent::from_percent(20);
+    pub const SettlementWeightBudget: Weight = Weight::from_parts(50_000_000_000, u64::MAX);
 }
 
 impl pallet_account_manager::Config for MiniRuntime {
@@ -222,16 +239,63 @@ impl pallet_account_manager::Config for MiniRuntime {
     type Currency = Balances;
     type EscrowAccount = EscrowAccount;
     type Executors = t3rn_primitives::executors::ExecutorsMock<Self>;
+    type InsuranceFund = InsuranceFund;
+    type ProtocolBurn = ProtocolBurn;
     type RuntimeEvent = RuntimeEvent;
+    type SettlementWeightBudget = SettlementWeightBudget;
     type Time = Timestamp;
+    type TreasuryAccounts = MiniRuntime;
+    type WeightInfo = ();
+}
+
+parameter_types! {
+    pub const MinExecutorBond: Balance = 10;
+    pub const MinStake: Balance = 5;
+    pub const MaxExecutorCommission: Percent = Percent::from_percent(50);
+    pub const HighValueBidThreshold: Balance = 1_000;
+    pub const MinReputationForHighValueBids: Percent = Percent::from_percent(50);
+}
+
+impl pallet_executors::Config for MiniRuntime {
+    type ActiveSetSize = ConstU32<2>;
+    type Clock = Clock;
+    type Currency = Balances;
+    type HighValueBidThreshold = HighValueBidThreshold;
+    type InsuranceFund = InsuranceFund;
+    type IssuanceOrigin = frame_system::EnsureRoot<AccountId>;
+    type MaxCapabilities = ConstU32<2>;
+    type MaxCommission = MaxExecutorCommission;
+    type MaxConfirmationDelay = ConstU32<10>;
+    type MaxExecutors = ConstU32<8>;
+    type MaxMissedHeartbeats = ConstU32<3>;
+    type MaxRoundsPerClaim = ConstU32<4>;
+    type MaxSlashesPerRound = ConstU32<2>;
+    type MaxStakersPerExecutor = ConstU32<4>;
+    type MaxStakesPerStaker = ConstU32<2>;
+    type MaxUnbondingChunks = ConstU32<4>;
+    type MetricsRetentionRounds = ConstU32<2>;
+    type MinExecutorBond = MinExecutorBond;
+    type MinReputationForHighValueBids = MinReputationForHighValueBids;
+    type MinStake = MinStake;
+    type OnExecutorDeactivated = Circuit;
+    type RuntimeEvent = RuntimeEvent;
+    type SlashAppealPeriod = ConstU32<2>;
+    type SlashCancelOrigin = frame_system::EnsureRoot<AccountId>;
+    type SlashOrigin = frame_system::EnsureRoot<AccountId>;
+    type TreasuryAccounts = MiniRuntime;
+    type UnbondingDelay = ConstU32<2>;
+    type UnstakeDelay = ConstU32<2>;
     type WeightInfo = ();
 }
 
 impl pallet_clock::Config for MiniRuntime {
     type OnFinalizeQueues = t3rn_primitives::clock::EmptyOnHookQueues<Self>;
     type OnInitializeQueues = GlobalOnInitQueues;
+    type OnNewRound = (Executors, AccountManager, Attesters);
+    type OnRoundDurationChange = ();
     type RoundDuration = ConstU32<300>;
     type RuntimeEvent = RuntimeEvent;
+    type UpdateOrigin = frame_system::EnsureRoot<Self::AccountId>;
 }
 use circuit_runtime_types::UNIT as TRN;
 
@@ -250,6 +314,13 @@ parameter_types! {
     pub const AvailableBootstrapSpenditure: Balance = 1_000_000 * (TRN as Balance); // 1 MLN UNIT
 }
 
+parameter_types! {
+    // No vesting unless set by tests
+    pub storage ExecutorRewardsImmediateShare: Percent = Percent::from_parts(100);
+    pub storage ExecutorRewardsVestingRounds: u32 = 0;
+    pub storage RotateAttestersCommitteeOnNewRound: bool = false;
+}
+
 pub struct FindAuthorMockRoundRobinRotate32;
 
 impl FindAuthor<AccountId> for FindAuthorMockRoundRobinRotate32 {
@@ -280,6 +351,9 @@ impl pallet_rewards::Config for MiniRuntime {
     type Currency = Balances;
     type ExecutorBootstrapRewards = ExecutorBootstrapRewards;
     type ExecutorInflation = ExecutorInflation;
+    type ExecutorRewardsImmediateShare = ExecutorRewardsImmediateShare;
+    type ExecutorRewardsVestingRounds = ExecutorRewardsVestingRounds;
+    type Executors = Executors;
     type FindAuthor = FindAuthorMockRoundRobinRotate32;
     type InflationDistributionPeriod = InflationDistributionPeriod;
     type OneYear = OneYear;
@@ -317,9 +391,11 @@ impl pallet_attesters::Config for MiniRuntime {
     type RepatriationPeriod = ConstU32<60>;
     type RewardMultiplier = RewardMultiplier;
     type Rewards = Rewards;
+    type RotateCommitteeOnNewRound = RotateAttestersCommitteeOnNewRound;
     type RuntimeEvent = RuntimeEvent;
     type ShufflingFrequency = ConstU32<400>;
     type TreasuryAccounts = MiniRuntime;
+    type WeightInfo = ();
     type Xdns = XDNS;
 }
 
@@ -445,24 +521,44 @@ impl pallet_timestamp::Config for MiniRuntime {
     type WeightInfo = ();
 }
 
+impl<C> frame_system::offchain::SendTransactionTypes<C> for MiniRuntime
+where
+    RuntimeCall: From<C>,
+{
+    type Extrinsic = MockUncheckedExtrinsic<MiniRuntime>;
+    type OverarchingCall = RuntimeCall;
+}
+
+impl frame_system::offchain::SigningTypes for MiniRuntime {
+    type Public = sp_runtime::MultiSigner;
+    type Signature = sp_runtime::MultiSignature;
+}
+
 impl pallet_xdns::Config for MiniRuntime {
     type AssetsOverlay = MiniRuntime;
     type AttestersRead = Attesters;
     type Balances = Balances;
     type CircuitDLQ = Circuit;
     type Currency = Balances;
+    type FeeOracleAuthorityId = pallet_xdns::fee_oracle::crypto::FeeOracleAuthId;
+    type FeeOracleInterval = ConstU32<10>;
+    type GatewayIdReservationDeposit = GatewayIdReservationDeposit;
     type Portal = Portal;
+    type PurgeOrigin = frame_system::EnsureRoot<AccountId>;
+    type RegistrationOrigin = frame_system::EnsureRoot<AccountId>;
     type RuntimeEvent = RuntimeEvent;
     type SelfGatewayId = SelfGatewayId;
     type SelfTokenId = ConstU32<3333>;
     type Time = Timestamp;
     type TreasuryAccounts = MiniRuntime;
+    type UpdateOrigin = frame_system::EnsureRoot<AccountId>;
     type WeightInfo = pallet_xdns::weights::SubstrateWeight<MiniRuntime>;
 }
 
 parameter_types! {
     pub const CircuitAccountId: AccountId = AccountId::new([51u8; 32]); // 0x333...3
     pub const SelfGatewayId: [u8; 4] = [3, 3, 3, 3];
+    pub const GatewayIdReservationDeposit: Balance = 1;
     pub const SelfGatewayIdOptimistic: [u8; 4] = [0, 3, 3, 3];
 }
 
@@ -470,18 +566,31 @@ impl pallet_circuit::Config for MiniRuntime {
     type AccountManager = AccountManager;
     type Attesters = Attesters;
     type Balances = Balances;
+    type ConfirmationDepth = ConstU32<0u32>;
     type Currency = Balances;
     type DeletionQueueLimit = ConstU32<100u32>;
+    type EscrowGovernanceOrigin = frame_system::EnsureRoot<AccountId>;
     type Executors = t3rn_primitives::executors::ExecutorsMock<Self>;
+    type MaxEncodedArgLen = ConstU32<4_096u32>;
+    type MaxPrunedPerBlock = ConstU32<100u32>;
+    type MaxSideEffectsPerXtx = ConstU32<32u32>;
+    type MaxStepsPerXtx = ConstU32<8u32>;
+    type OnXtxConcluded = Vacuum;
     type Portal = Portal;
+    type RetentionPeriod = ConstU32<1000u32>;
     type RuntimeEvent = RuntimeEvent;
     type SFXBiddingPeriod = ConstU32<3u32>;
     type SelfAccountId = CircuitAccountId;
     type SelfGatewayId = SelfGatewayId;
     type SelfParaId = ConstU32<3333u32>;
     type SignalQueueDepth = ConstU32<5u32>;
+    type SponsorSignature = sp_runtime::MultiSignature;
+    type SponsorSigner = sp_runtime::MultiSigner;
+    type TelemetryInterval = ConstU32<0u32>;
     type TreasuryAccounts = MiniRuntime;
     type WeightInfo = ();
+    type XcmOrderNotifier = ();
+    type XcmOrderOrigin = NeverEnsureOrigin<(u32, AccountId)>;
     type Xdns = XDNS;
     type XtxTimeoutCheckInterval = ConstU32<10u32>;
     type XtxTimeoutDefault = ConstU32<400u32>;
@@ -500,14 +609,26 @@ impl pallet_circuit_vacuum::Config for MiniRuntime {
 
 impl pallet_portal::Config for MiniRuntime {
     type Currency = Balances;
+    type HeaderExporter = ();
+    type HeaderSubmissionWindow = ConstU32<1>;
+    type HeaderSubscriberOrigin = NeverEnsureOrigin<u32>;
+    type MaxCachedInclusions = ConstU32<16>;
+    type MaxHeaderExportsPerBlock = ConstU32<16>;
+    type MaxHeaderSubmissionsPerWindow = ConstU32<0>;
+    type MaxPrunedPerBlock = ConstU32<100>;
+    type OnHeadersRolledBack = Circuit;
+    type RetentionPeriod = ConstU32<1000>;
     type RuntimeEvent = RuntimeEvent;
     type SelectLightClient = SelectLightClientRegistry;
+    type VendorLimits = ();
     type WeightInfo = pallet_portal::weights::SubstrateWeight<MiniRuntime>;
     type Xdns = XDNS;
 }
 
 parameter_types! {
     pub const HeadersToStore: u32 = 100;
+    pub const HeaderSyncSessionDeposit: Balance = 10 * (TRN as Balance);
+    pub const HeaderSyncChunkDeposit: Balance = TRN as Balance;
     pub const RococoVendor: GatewayVendor = GatewayVendor::Rococo;
     pub const KusamaVendor: GatewayVendor = GatewayVendor::Kusama;
     pub const PolkadotVendor: GatewayVendor = GatewayVendor::Polkadot;
@@ -524,12 +645,19 @@ impl bp_runtime::Chain for Blake2ValU32Chain {
 
 impl pallet_grandpa_finality_verifier::Config<RococoInstance> for MiniRuntime {
     type BridgedChain = Blake2ValU32Chain;
+    type Currency = Balances;
     type EpochOffset = ConstU32<2_400u32>;
     type FastConfirmationOffset = ConstU32<3u32>;
     type FinalizedConfirmationOffset = ConstU32<10u32>;
+    type HeaderSubmissionHooks = Portal;
+    type HeaderSyncChunkDeposit = HeaderSyncChunkDeposit;
+    type HeaderSyncSessionDeposit = HeaderSyncSessionDeposit;
+    type HeaderSyncSessionTtl = ConstU32<14_400u32>;
     type HeadersToStore = HeadersToStore;
     type LightClientAsyncAPI = XDNS;
+    type MaxHeaderSyncSessionsPerRelayer = ConstU32<4u32>;
     type MyVendor = RococoVendor;
+    type OnHeadersRolledBack = Portal;
     type RationalConfirmationOffset = ConstU32<10u32>;
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = ();
@@ -537,12 +665,19 @@ impl pallet_grandpa_finality_verifier::Config<RococoInstance> for MiniRuntime {
 
 impl pallet_grandpa_finality_verifier::Config<PolkadotInstance> for MiniRuntime {
     type BridgedChain = Blake2ValU32Chain;
+    type Currency = Balances;
     type EpochOffset = ConstU32<2_400u32>;
     type FastConfirmationOffset = ConstU32<3u32>;
     type FinalizedConfirmationOffset = ConstU32<10u32>;
+    type HeaderSubmissionHooks = Portal;
+    type HeaderSyncChunkDeposit = HeaderSyncChunkDeposit;
+    type HeaderSyncSessionDeposit = HeaderSyncSessionDeposit;
+    type HeaderSyncSessionTtl = ConstU32<14_400u32>;
     type HeadersToStore = HeadersToStore;
     type LightClientAsyncAPI = XDNS;
+    type MaxHeaderSyncSessionsPerRelayer = ConstU32<4u32>;
     type MyVendor = PolkadotVendor;
+    type OnHeadersRolledBack = Portal;
     type RationalConfirmationOffset = ConstU32<10u32>;
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = ();
@@ -550,12 +685,19 @@ impl pallet_grandpa_finality_verifier::Config<PolkadotInstance> for MiniRuntime
 
 impl pallet_grandpa_finality_verifier::Config<KusamaInstance> for MiniRuntime {
     type BridgedChain = Blake2ValU32Chain;
+    type Currency = Balances;
     type EpochOffset = ConstU32<2_400u32>;
     type FastConfirmationOffset = ConstU32<3u32>;
     type FinalizedConfirmationOffset = ConstU32<10u32>;
+    type HeaderSubmissionHooks = Portal;
+    type HeaderSyncChunkDeposit = HeaderSyncChunkDeposit;
+    type HeaderSyncSessionDeposit = HeaderSyncSessionDeposit;
+    type HeaderSyncSessionTtl = ConstU32<14_400u32>;
     type HeadersToStore = HeadersToStore;
     type LightClientAsyncAPI = XDNS;
+    type MaxHeaderSyncSessionsPerRelayer = ConstU32<4u32>;
     type MyVendor = KusamaVendor;
+    type OnHeadersRolledBack = Portal;
     type RationalConfirmationOffset = ConstU32<10u32>;
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = ();
@@ -567,7 +709,8 @@ impl AddressMapping<AccountId> for AddressMappingMock {
     /// Returns the AccountId used go generate the given EvmAddress.
     fn into_account_id(evm: &EvmAddress) -> AccountId {
         let mut account_id = [0u8; 32];
-        account_id.copy_from_slice(&evm.0);
+        account_id[0..4].copy_from_slice(b"evm:");
+        account_id[4..24].copy_from_slice(&evm.0);
         AccountId::new(account_id)
     }
 
@@ -790,7 +933,7 @@ use crate::sp_api_hidden_includes_construct_runtime::hidden_include::traits::Gen
 use t3rn_abi::{types::Sfx4bId, SFXAbi};
 use t3rn_primitives::{
     contracts_registry::RegistryContract,
-    xdns::{GatewayRecord, PalletAssetsOverlay},
+    xdns::{AllowedSideEffects, GatewayRecord, PalletAssetsOverlay, GATEWAY_RECORD_VERSION},
 };
 
 #[derive(Default)]
@@ -945,14 +1088,19 @@ impl ExtBuilder {
             codec: t3rn_abi::Codec::Scale,
             registrant: None,
             escrow_account: None,
-            allowed_side_effects: vec![
+            allowed_side_effects: AllowedSideEffects::truncate_from(vec![
                 (*b"tran", Some(2)),
                 (*b"tass", Some(4)),
                 (*b"tddd", Some(4)),
                 // (*b"call", Some(10)),
                 // (*b"cevm", Some(88)),
                 // (*b"wasm", Some(99)),
-            ],
+            ]),
+            security_tier: GatewaySecurityTier::Finalized,
+            fee_schedule: Default::default(),
+            assets: Default::default(),
+            abi_descriptors: Default::default(),
+            version: GATEWAY_RECORD_VERSION,
         });
         self
     }
@@ -966,7 +1114,15 @@ impl ExtBuilder {
             codec: t3rn_abi::Codec::Scale,
             registrant: None,
             escrow_account: Some(mock_escrow_account),
-            allowed_side_effects: vec![(*b"tran", Some(2)), (*b"tass", Some(4))],
+            allowed_side_effects: AllowedSideEffects::truncate_from(vec![
+                (*b"tran", Some(2)),
+                (*b"tass", Some(4)),
+            ]),
+            security_tier: GatewaySecurityTier::Finalized,
+            fee_schedule: Default::default(),
+            assets: Default::default(),
+            abi_descriptors: Default::default(),
+            version: GATEWAY_RECORD_VERSION,
         });
         self
     }
@@ -980,11 +1136,16 @@ impl ExtBuilder {
             codec: t3rn_abi::Codec::Scale,
             registrant: None,
             escrow_account: Some(mock_escrow_account),
-            allowed_side_effects: vec![
+            allowed_side_effects: AllowedSideEffects::truncate_from(vec![
                 (*b"tran", Some(2)),
                 (*b"tass", Some(4)),
                 (*b"tddd", Some(4)),
-            ],
+            ]),
+            security_tier: GatewaySecurityTier::Finalized,
+            fee_schedule: Default::default(),
+            assets: Default::default(),
+            abi_descriptors: Default::default(),
+            version: GATEWAY_RECORD_VERSION,
         });
         self
     }
@@ -1001,12 +1162,17 @@ impl ExtBuilder {
             codec: t3rn_abi::Codec::Rlp,
             registrant: None,
             escrow_account: Some(mock_escrow_account),
-            allowed_side_effects: vec![
+            allowed_side_effects: AllowedSideEffects::truncate_from(vec![
                 (*b"tran", Some(132)),
                 (*b"tass", Some(132)),
                 (*b"tddd", Some(132)),
                 (*b"cevm", Some(132)),
-            ],
+            ]),
+            security_tier: GatewaySecurityTier::Finalized,
+            fee_schedule: Default::default(),
+            assets: Default::default(),
+            abi_descriptors: Default::default(),
+            version: GATEWAY_RECORD_VERSION,
         });
         self
     }
@@ -1023,12 +1189,17 @@ impl ExtBuilder {
             codec: t3rn_abi::Codec::Rlp,
             registrant: None,
             escrow_account: Some(mock_escrow_account),
-            allowed_side_effects: vec![
+            allowed_side_effects: AllowedSideEffects::truncate_from(vec![
                 (*b"tran", Some(133)),
                 (*b"tass", Some(133)),
                 (*b"tddd", Some(133)),
                 (*b"cevm", Some(133)),
-            ],
+            ]),
+            security_tier: GatewaySecurityTier::Finalized,
+            fee_schedule: Default::default(),
+            assets: Default::default(),
+            abi_descriptors: Default::default(),
+            version: GATEWAY_RECORD_VERSION,
         });
         self
     }
@@ -1042,14 +1213,19 @@ impl ExtBuilder {
             codec: t3rn_abi::Codec::Scale,
             registrant: None,
             escrow_account: Some(mock_escrow_account),
-            allowed_side_effects: vec![
+            allowed_side_effects: AllowedSideEffects::truncate_from(vec![
                 (*b"tran", Some(2)),
                 (*b"tass", Some(4)),
                 (*b"tddd", Some(4)),
                 (*b"call", Some(10)),
                 (*b"cevm", Some(88)),
                 (*b"wasm", Some(99)),
-            ],
+            ]),
+            security_tier: GatewaySecurityTier::Finalized,
+            fee_schedule: Default::default(),
+            assets: Default::default(),
+            abi_descriptors: Default::default(),
+            version: GATEWAY_RECORD_VERSION,
         });
         self
     }
@@ -1089,13 +1265,11 @@ impl ExtBuilder {
         .assimilate_storage(&mut t)
         .expect("Pallet balances storage can be assimilated");
 
-        pallet_xdns::GenesisConfig::<MiniRuntime> {
-            known_gateway_records: self.known_gateway_records.encode(),
-            standard_sfx_abi: self.standard_sfx_abi.encode(),
-            _marker: Default::default(),
-        }
-        .assimilate_storage(&mut t)
-        .expect("Pallet xdns can be assimilated");
+        pallet_xdns::GenesisConfig::<MiniRuntime>::default()
+            .with_sfx_abis(self.standard_sfx_abi)
+            .with_gateway_records(self.known_gateway_records)
+            .assimilate_storage(&mut t)
+            .expect("Pallet xdns can be assimilated");
 
         pallet_rewards::GenesisConfig::<MiniRuntime> {
             _marker: Default::default(),
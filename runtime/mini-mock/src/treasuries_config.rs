@@ -138,6 +138,19 @@ impl pallet_treasury::Config<SlashTreasuryInstance> for Runtime {
     type WeightInfo = pallet_treasury::weights::SubstrateWeight<Runtime>;
 }
 
+// Protocol insurance fund
+parameter_types! {
+    pub const InsuranceFundId: PalletId = PalletId(*b"insrfund");
+}
+
+impl pallet_insurance_fund::Config for Runtime {
+    type AdminOrigin = EnsureRoot<AccountId>;
+    type Currency = Balances;
+    type PalletId = InsuranceFundId;
+    type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = ();
+}
+
 impl TreasuryAccountProvider<AccountId> for Runtime {
     fn get_treasury_account(treasury_account: TreasuryAccount) -> AccountId {
         match treasury_account {
@@ -1,13 +1,18 @@
 use crate::*;
 pub use frame_support::traits::{Imbalance, OnUnbalanced};
-use frame_support::{parameter_types, traits::AsEnsureOriginWithArg};
+use frame_support::{parameter_types, traits::AsEnsureOriginWithArg, weights::Weight};
 use frame_system::EnsureSigned;
 use sp_core::crypto::AccountId32;
-use sp_runtime::traits::{ConstU32, ConvertInto};
+use sp_runtime::{
+    traits::{ConstU32, ConvertInto},
+    Percent,
+};
 
 parameter_types! {
     // TODO: update me to be better
     pub EscrowAccount: AccountId32 = AccountId32::new([51_u8; 32]);
+    pub const ProtocolBurn: Percent = Percent::from_percent(50);
+    pub const SettlementWeightBudget: Weight = Weight::from_parts(50_000_000_000, u64::MAX);
 }
 
 impl pallet_account_manager::Config for Runtime {
@@ -18,8 +23,12 @@ impl pallet_account_manager::Config for Runtime {
     type Currency = Balances;
     type EscrowAccount = EscrowAccount;
     type Executors = t3rn_primitives::executors::ExecutorsMock<Self>;
+    type InsuranceFund = ();
+    type ProtocolBurn = ProtocolBurn;
     type RuntimeEvent = RuntimeEvent;
+    type SettlementWeightBudget = SettlementWeightBudget;
     type Time = Timestamp;
+    type TreasuryAccounts = Runtime;
     type WeightInfo = ();
 }
 
@@ -12,7 +12,7 @@ use circuit_runtime_pallets::{
 use frame_support::{
     dispatch::DispatchResultWithPostInfo,
     parameter_types,
-    traits::{fungibles::Destroy, ConstU32},
+    traits::{fungibles::Destroy, ConstU32, EnsureOrigin},
     Blake2_128Concat, StorageHasher,
 };
 use pallet_grandpa_finality_verifier::{
@@ -37,8 +37,11 @@ pub use hooks::GlobalOnInitQueues;
 impl pallet_clock::Config for Runtime {
     type OnFinalizeQueues = t3rn_primitives::clock::EmptyOnHookQueues<Self>;
     type OnInitializeQueues = GlobalOnInitQueues;
+    type OnNewRound = AccountManager;
+    type OnRoundDurationChange = ();
     type RoundDuration = ConstU32<300>;
     type RuntimeEvent = RuntimeEvent;
+    type UpdateOrigin = frame_system::EnsureRoot<Self::AccountId>;
 }
 
 impl pallet_xdns::Config for Runtime {
@@ -47,12 +50,18 @@ impl pallet_xdns::Config for Runtime {
     type Balances = Balances;
     type CircuitDLQ = Circuit;
     type Currency = Balances;
+    type FeeOracleAuthorityId = pallet_xdns::fee_oracle::crypto::FeeOracleAuthId;
+    type FeeOracleInterval = ConstU32<10>;
+    type GatewayIdReservationDeposit = GatewayIdReservationDeposit;
     type Portal = Portal;
+    type PurgeOrigin = frame_system::EnsureRoot<AccountId>;
+    type RegistrationOrigin = frame_system::EnsureRoot<AccountId>;
     type RuntimeEvent = RuntimeEvent;
     type SelfGatewayId = SelfGatewayId;
     type SelfTokenId = ConstU32<3333>;
     type Time = Timestamp;
     type TreasuryAccounts = Runtime;
+    type UpdateOrigin = frame_system::EnsureRoot<AccountId>;
     type WeightInfo = pallet_xdns::weights::SubstrateWeight<Runtime>;
 }
 
@@ -62,6 +71,7 @@ parameter_types! {
     pub const RewardMultiplier: Balance = 1;
     pub const MinNominatorBond: Balance = 1;
     pub const MinAttesterBond: Balance = 1;
+    pub const GatewayIdReservationDeposit: Balance = 10 * (TRN as Balance);
     pub const DefaultCommission: Percent = Percent::from_percent(10);
 }
 
@@ -81,9 +91,11 @@ impl pallet_attesters::Config for Runtime {
     type RepatriationPeriod = ConstU32<60>;
     type RewardMultiplier = RewardMultiplier;
     type Rewards = Rewards;
+    type RotateCommitteeOnNewRound = frame_support::traits::ConstBool<false>;
     type RuntimeEvent = RuntimeEvent;
     type ShufflingFrequency = ConstU32<400>;
     type TreasuryAccounts = Runtime;
+    type WeightInfo = pallet_attesters::weights::SubstrateWeight<Runtime>;
     type Xdns = XDNS;
 }
 
@@ -110,6 +122,8 @@ parameter_types! {
     pub const AttesterBootstrapRewards: Percent = Percent::from_parts(40); // 40%
     pub const CollatorBootstrapRewards: Percent = Percent::from_parts(20); // 20%
     pub const ExecutorBootstrapRewards: Percent = Percent::from_parts(40); // 40%
+    pub const ExecutorRewardsImmediateShare: Percent = Percent::from_parts(100); // 100% (no vesting)
+    pub const ExecutorRewardsVestingRounds: u32 = 0;
     pub const StartingRepatriationPercentage: Percent = Percent::from_parts(10); // 10%
     pub const OneYear: BlockNumber = 2_628_000; // (365.25 * 24 * 60 * 60) / 12; assuming 12s block time
     pub const InflationDistributionPeriod: BlockNumber = 100_800; // (14 * 24 * 60 * 60) / 12; assuming one distribution per two weeks
@@ -128,6 +142,9 @@ impl pallet_rewards::Config for Runtime {
     type Currency = Balances;
     type ExecutorBootstrapRewards = ExecutorBootstrapRewards;
     type ExecutorInflation = ExecutorInflation;
+    type ExecutorRewardsImmediateShare = ExecutorRewardsImmediateShare;
+    type ExecutorRewardsVestingRounds = ExecutorRewardsVestingRounds;
+    type Executors = t3rn_primitives::executors::ExecutorsMock<Self>;
     type FindAuthor = ();
     type InflationDistributionPeriod = InflationDistributionPeriod;
     type OneYear = OneYear;
@@ -140,9 +157,13 @@ impl pallet_rewards::Config for Runtime {
 
 impl pallet_contracts_registry::Config for Runtime {
     type Balances = Balances;
+    type CircuitSubmitAPI = Circuit;
     type Currency = Balances;
+    type Portal = Portal;
+    type ReadSFX = Circuit;
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = pallet_contracts_registry::weights::SubstrateWeight<Runtime>;
+    type Xdns = XDNS;
 }
 
 pub struct SelectLightClientRegistry;
@@ -175,10 +196,46 @@ impl pallet_portal::SelectLightClient<Runtime> for SelectLightClientRegistry {
     }
 }
 
+// Justifications grow by ~130 bytes per precommit of the GRANDPA authority set
+const MAX_GRANDPA_HEADERS_SIZE: u32 = 512 * 1024;
+// Storage proofs carry the events of a relaychain block in full
+const MAX_GRANDPA_PROOF_SIZE: u32 = 1024 * 1024;
+// Sync committee updates carry the 512 public keys of the next committee
+const MAX_ETHEREUM_HEADERS_SIZE: u32 = 256 * 1024;
+const MAX_ETHEREUM_PROOF_SIZE: u32 = 256 * 1024;
+
+pub struct PortalVendorLimits;
+
+impl pallet_portal::VendorLimits for PortalVendorLimits {
+    fn max_headers_size(vendor: &GatewayVendor) -> u32 {
+        match vendor {
+            GatewayVendor::Ethereum | GatewayVendor::Sepolia => MAX_ETHEREUM_HEADERS_SIZE,
+            _ => MAX_GRANDPA_HEADERS_SIZE,
+        }
+    }
+
+    fn max_proof_size(vendor: &GatewayVendor) -> u32 {
+        match vendor {
+            GatewayVendor::Ethereum | GatewayVendor::Sepolia => MAX_ETHEREUM_PROOF_SIZE,
+            _ => MAX_GRANDPA_PROOF_SIZE,
+        }
+    }
+}
+
 impl pallet_portal::Config for Runtime {
     type Currency = Balances;
+    type HeaderExporter = ();
+    type HeaderSubmissionWindow = ConstU32<4>;
+    type HeaderSubscriberOrigin = EnsureSiblingParaSigned;
+    type MaxCachedInclusions = ConstU32<16>;
+    type MaxHeaderExportsPerBlock = ConstU32<16>;
+    type MaxHeaderSubmissionsPerWindow = ConstU32<4>;
+    type MaxPrunedPerBlock = ConstU32<100>;
+    type OnHeadersRolledBack = Circuit;
+    type RetentionPeriod = ConstU32<1000>;
     type RuntimeEvent = RuntimeEvent;
     type SelectLightClient = SelectLightClientRegistry;
+    type VendorLimits = PortalVendorLimits;
     type WeightInfo = pallet_portal::weights::SubstrateWeight<Runtime>;
     type Xdns = XDNS;
 }
@@ -271,22 +328,73 @@ impl PalletAssetsOverlay<Runtime, Balance> for Runtime {
     }
 }
 
+pub const MOCK_SIBLING_PARA_ID: u32 = 2000;
+
+/// Treats signed origins as XCM orders of the sibling parachain `MOCK_SIBLING_PARA_ID`.
+pub struct EnsureSiblingSigned;
+impl EnsureOrigin<RuntimeOrigin> for EnsureSiblingSigned {
+    type Success = (u32, AccountId);
+
+    fn try_origin(o: RuntimeOrigin) -> Result<Self::Success, RuntimeOrigin> {
+        frame_system::EnsureSigned::<AccountId>::try_origin(o)
+            .map(|who| (MOCK_SIBLING_PARA_ID, who))
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn try_successful_origin() -> Result<RuntimeOrigin, ()> {
+        Ok(RuntimeOrigin::signed(AccountId::new([2u8; 32])))
+    }
+}
+
+/// Treats signed origins as the sibling parachain `MOCK_SIBLING_PARA_ID`.
+pub struct EnsureSiblingParaSigned;
+impl EnsureOrigin<RuntimeOrigin> for EnsureSiblingParaSigned {
+    type Success = u32;
+
+    fn try_origin(o: RuntimeOrigin) -> Result<Self::Success, RuntimeOrigin> {
+        EnsureSiblingSigned::try_origin(o).map(|(para_id, _)| para_id)
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn try_successful_origin() -> Result<RuntimeOrigin, ()> {
+        EnsureSiblingSigned::try_successful_origin()
+    }
+}
+
+parameter_types! {
+    // Settable by tests confirming on gateways with probabilistic finality
+    pub storage ConfirmationDepth: u32 = 0;
+}
+
 impl pallet_circuit::Config for Runtime {
     type AccountManager = AccountManager;
     type Attesters = Attesters;
     type Balances = Balances;
+    type ConfirmationDepth = ConfirmationDepth;
     type Currency = Balances;
     type DeletionQueueLimit = ConstU32<100u32>;
+    type EscrowGovernanceOrigin = frame_system::EnsureRoot<AccountId>;
     type Executors = t3rn_primitives::executors::ExecutorsMock<Self>;
+    type MaxEncodedArgLen = ConstU32<4_096u32>;
+    type MaxPrunedPerBlock = ConstU32<100u32>;
+    type MaxSideEffectsPerXtx = ConstU32<32u32>;
+    type MaxStepsPerXtx = ConstU32<8u32>;
+    type OnXtxConcluded = (ThreeVm, ContractsRegistry, Vacuum);
     type Portal = Portal;
+    type RetentionPeriod = ConstU32<1000u32>;
     type RuntimeEvent = RuntimeEvent;
     type SFXBiddingPeriod = ConstU32<3u32>;
     type SelfAccountId = CircuitAccountId;
     type SelfGatewayId = SelfGatewayId;
     type SelfParaId = ConstU32<3333u32>;
     type SignalQueueDepth = ConstU32<5u32>;
+    type SponsorSignature = sp_runtime::MultiSignature;
+    type SponsorSigner = sp_runtime::MultiSigner;
+    type TelemetryInterval = ConstU32<0u32>;
     type TreasuryAccounts = Runtime;
     type WeightInfo = ();
+    type XcmOrderNotifier = ();
+    type XcmOrderOrigin = EnsureSiblingSigned;
     type Xdns = XDNS;
     type XtxTimeoutCheckInterval = ConstU32<10u32>;
     type XtxTimeoutDefault = ConstU32<400u32>;
@@ -294,6 +402,8 @@ impl pallet_circuit::Config for Runtime {
 
 parameter_types! {
     pub const HeadersToStore: u32 = 100;
+    pub const HeaderSyncSessionDeposit: Balance = 10 * (TRN as Balance);
+    pub const HeaderSyncChunkDeposit: Balance = TRN as Balance;
     pub const RococoVendor: GatewayVendor = GatewayVendor::Rococo;
     pub const KusamaVendor: GatewayVendor = GatewayVendor::Kusama;
     pub const PolkadotVendor: GatewayVendor = GatewayVendor::Polkadot;
@@ -310,12 +420,19 @@ impl bp_runtime::Chain for Blake2ValU32Chain {
 
 impl pallet_grandpa_finality_verifier::Config<RococoInstance> for Runtime {
     type BridgedChain = Blake2ValU32Chain;
+    type Currency = Balances;
     type EpochOffset = ConstU32<2_400u32>;
     type FastConfirmationOffset = ConstU32<0u32>;
     type FinalizedConfirmationOffset = ConstU32<0u32>;
+    type HeaderSubmissionHooks = Portal;
+    type HeaderSyncChunkDeposit = HeaderSyncChunkDeposit;
+    type HeaderSyncSessionDeposit = HeaderSyncSessionDeposit;
+    type HeaderSyncSessionTtl = ConstU32<14_400u32>;
     type HeadersToStore = HeadersToStore;
     type LightClientAsyncAPI = XDNS;
+    type MaxHeaderSyncSessionsPerRelayer = ConstU32<4u32>;
     type MyVendor = RococoVendor;
+    type OnHeadersRolledBack = Portal;
     type RationalConfirmationOffset = ConstU32<0u32>;
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = ();
@@ -323,12 +440,19 @@ impl pallet_grandpa_finality_verifier::Config<RococoInstance> for Runtime {
 
 impl pallet_grandpa_finality_verifier::Config<PolkadotInstance> for Runtime {
     type BridgedChain = Blake2ValU32Chain;
+    type Currency = Balances;
     type EpochOffset = ConstU32<2_400u32>;
     type FastConfirmationOffset = ConstU32<0u32>;
     type FinalizedConfirmationOffset = ConstU32<0u32>;
+    type HeaderSubmissionHooks = Portal;
+    type HeaderSyncChunkDeposit = HeaderSyncChunkDeposit;
+    type HeaderSyncSessionDeposit = HeaderSyncSessionDeposit;
+    type HeaderSyncSessionTtl = ConstU32<14_400u32>;
     type HeadersToStore = HeadersToStore;
     type LightClientAsyncAPI = XDNS;
+    type MaxHeaderSyncSessionsPerRelayer = ConstU32<4u32>;
     type MyVendor = PolkadotVendor;
+    type OnHeadersRolledBack = Portal;
     type RationalConfirmationOffset = ConstU32<0u32>;
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = ();
@@ -336,12 +460,19 @@ impl pallet_grandpa_finality_verifier::Config<PolkadotInstance> for Runtime {
 
 impl pallet_grandpa_finality_verifier::Config<KusamaInstance> for Runtime {
     type BridgedChain = Blake2ValU32Chain;
+    type Currency = Balances;
     type EpochOffset = ConstU32<2_400u32>;
     type FastConfirmationOffset = ConstU32<0u32>;
     type FinalizedConfirmationOffset = ConstU32<0u32>;
+    type HeaderSubmissionHooks = Portal;
+    type HeaderSyncChunkDeposit = HeaderSyncChunkDeposit;
+    type HeaderSyncSessionDeposit = HeaderSyncSessionDeposit;
+    type HeaderSyncSessionTtl = ConstU32<14_400u32>;
     type HeadersToStore = HeadersToStore;
     type LightClientAsyncAPI = XDNS;
+    type MaxHeaderSyncSessionsPerRelayer = ConstU32<4u32>;
     type MyVendor = KusamaVendor;
+    type OnHeadersRolledBack = Portal;
     type RationalConfirmationOffset = ConstU32<0u32>;
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = ();
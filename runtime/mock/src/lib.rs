@@ -2,7 +2,6 @@
 //! Runtime utilities
 
 use circuit_runtime_pallets::pallet_circuit::{self as pallet_circuit};
-use codec::Encode;
 
 use circuit_runtime_pallets::pallet_3vm_evm::AddressMapping;
 
@@ -33,7 +32,7 @@ mod xbi_config;
 pub type RococoLightClient = ();
 pub type PolkadotLightClient = pallet_grandpa_finality_verifier::Instance1;
 pub type KusamaLightClient = pallet_grandpa_finality_verifier::Instance2;
-pub use crate::circuit_config::GlobalOnInitQueues;
+pub use crate::circuit_config::{ConfirmationDepth, GlobalOnInitQueues, MOCK_SIBLING_PARA_ID};
 use frame_support::traits::GenesisBuild;
 pub use pallet_3vm_account_mapping::{
     ethereum_signable_message, to_ascii_hex, EcdsaSignature, EvmAddressMapping,
@@ -137,8 +136,8 @@ impl WeightToFeePolynomial for WeightToFee {
 use t3rn_abi::SFXAbi;
 use t3rn_primitives::{
     contracts_registry::RegistryContract,
-    xdns::{GatewayRecord, XdnsRecord},
-    ExecutionVendor, GatewayVendor,
+    xdns::{AllowedSideEffects, GatewayRecord, XdnsRecord, GATEWAY_RECORD_VERSION},
+    ExecutionVendor, GatewaySecurityTier, GatewayVendor,
 };
 use t3rn_types::sfx::Sfx4bId;
 
@@ -166,14 +165,19 @@ impl ExtBuilder {
                 codec: t3rn_abi::Codec::Scale,
                 registrant: None,
                 escrow_account: None,
-                allowed_side_effects: vec![
+                allowed_side_effects: AllowedSideEffects::truncate_from(vec![
                     (*b"tran", Some(2)),
                     (*b"tass", Some(4)),
                     (*b"swap", Some(3)),
                     (*b"aliq", Some(3)),
                     (*b"cevm", Some(10)),
                     (*b"wasm", Some(10)),
-                ],
+                ]),
+                security_tier: GatewaySecurityTier::Finalized,
+                fee_schedule: Default::default(),
+                assets: Default::default(),
+                abi_descriptors: Default::default(),
+                version: GATEWAY_RECORD_VERSION,
             },
             GatewayRecord {
                 gateway_id: [1, 1, 1, 1],
@@ -182,14 +186,19 @@ impl ExtBuilder {
                 codec: t3rn_abi::Codec::Scale,
                 registrant: None,
                 escrow_account: None,
-                allowed_side_effects: vec![
+                allowed_side_effects: AllowedSideEffects::truncate_from(vec![
                     (*b"tran", Some(2)),
                     (*b"tass", Some(4)),
                     (*b"swap", Some(3)),
                     (*b"aliq", Some(3)),
                     (*b"cevm", Some(10)),
                     (*b"wasm", Some(10)),
-                ],
+                ]),
+                security_tier: GatewaySecurityTier::Finalized,
+                fee_schedule: Default::default(),
+                assets: Default::default(),
+                abi_descriptors: Default::default(),
+                version: GATEWAY_RECORD_VERSION,
             },
             GatewayRecord {
                 gateway_id: [5, 5, 5, 5],
@@ -198,14 +207,19 @@ impl ExtBuilder {
                 codec: t3rn_abi::Codec::Scale,
                 registrant: None,
                 escrow_account: None,
-                allowed_side_effects: vec![
+                allowed_side_effects: AllowedSideEffects::truncate_from(vec![
                     (*b"tran", Some(2)),
                     (*b"tass", Some(4)),
                     (*b"swap", Some(3)),
                     (*b"aliq", Some(3)),
                     (*b"cevm", Some(10)),
                     (*b"wasm", Some(10)),
-                ],
+                ]),
+                security_tier: GatewaySecurityTier::Finalized,
+                fee_schedule: Default::default(),
+                assets: Default::default(),
+                abi_descriptors: Default::default(),
+                version: GATEWAY_RECORD_VERSION,
             },
             GatewayRecord {
                 gateway_id: *b"ksma",
@@ -214,7 +228,15 @@ impl ExtBuilder {
                 codec: t3rn_abi::Codec::Scale,
                 registrant: None,
                 escrow_account: None,
-                allowed_side_effects: vec![(*b"tran", Some(2)), (*b"tass", Some(4))],
+                allowed_side_effects: AllowedSideEffects::truncate_from(vec![
+                    (*b"tran", Some(2)),
+                    (*b"tass", Some(4)),
+                ]),
+                security_tier: GatewaySecurityTier::Finalized,
+                fee_schedule: Default::default(),
+                assets: Default::default(),
+                abi_descriptors: Default::default(),
+                version: GATEWAY_RECORD_VERSION,
             },
             GatewayRecord {
                 gateway_id: *b"pdot",
@@ -223,7 +245,15 @@ impl ExtBuilder {
                 codec: t3rn_abi::Codec::Scale,
                 registrant: None,
                 escrow_account: None,
-                allowed_side_effects: vec![(*b"tran", Some(2)), (*b"tass", Some(4))],
+                allowed_side_effects: AllowedSideEffects::truncate_from(vec![
+                    (*b"tran", Some(2)),
+                    (*b"tass", Some(4)),
+                ]),
+                security_tier: GatewaySecurityTier::Finalized,
+                fee_schedule: Default::default(),
+                assets: Default::default(),
+                abi_descriptors: Default::default(),
+                version: GATEWAY_RECORD_VERSION,
             },
             GatewayRecord {
                 gateway_id: *b"gate",
@@ -232,7 +262,12 @@ impl ExtBuilder {
                 codec: t3rn_abi::Codec::Scale,
                 registrant: None,
                 escrow_account: None,
-                allowed_side_effects: vec![(*b"tran", Some(2))],
+                allowed_side_effects: AllowedSideEffects::truncate_from(vec![(*b"tran", Some(2))]),
+                security_tier: GatewaySecurityTier::Finalized,
+                fee_schedule: Default::default(),
+                assets: Default::default(),
+                abi_descriptors: Default::default(),
+                version: GATEWAY_RECORD_VERSION,
             },
             GatewayRecord {
                 gateway_id: [0, 0, 0, 0],
@@ -241,14 +276,19 @@ impl ExtBuilder {
                 codec: t3rn_abi::Codec::Scale,
                 registrant: None,
                 escrow_account: None,
-                allowed_side_effects: vec![
+                allowed_side_effects: AllowedSideEffects::truncate_from(vec![
                     (*b"tran", Some(2)),
                     (*b"tass", Some(4)),
                     (*b"swap", Some(3)),
                     (*b"aliq", Some(3)),
                     (*b"cevm", Some(10)),
                     (*b"wasm", Some(10)),
-                ],
+                ]),
+                security_tier: GatewaySecurityTier::Finalized,
+                fee_schedule: Default::default(),
+                assets: Default::default(),
+                abi_descriptors: Default::default(),
+                version: GATEWAY_RECORD_VERSION,
             },
             GatewayRecord {
                 gateway_id: *b"eth2",
@@ -257,7 +297,12 @@ impl ExtBuilder {
                 codec: t3rn_abi::Codec::Rlp,
                 registrant: None,
                 escrow_account: None,
-                allowed_side_effects: vec![(*b"tran", Some(2))],
+                allowed_side_effects: AllowedSideEffects::truncate_from(vec![(*b"tran", Some(2))]),
+                security_tier: GatewaySecurityTier::Finalized,
+                fee_schedule: Default::default(),
+                assets: Default::default(),
+                abi_descriptors: Default::default(),
+                version: GATEWAY_RECORD_VERSION,
             },
         ];
         self
@@ -344,13 +389,11 @@ impl ExtBuilder {
         }
         .assimilate_storage(&mut t)
         .expect("Pallet attesters can be assimilated");
-        pallet_xdns::GenesisConfig::<Runtime> {
-            known_gateway_records: self.known_gateway_records.encode(),
-            standard_sfx_abi: self.standard_sfx_abi.encode(),
-            _marker: Default::default(),
-        }
-        .assimilate_storage(&mut t)
-        .expect("Pallet xdns can be assimilated");
+        pallet_xdns::GenesisConfig::<Runtime>::default()
+            .with_sfx_abis(self.standard_sfx_abi)
+            .with_gateway_records(self.known_gateway_records)
+            .assimilate_storage(&mut t)
+            .expect("Pallet xdns can be assimilated");
 
         let mut ext = sp_io::TestExternalities::new(t);
         ext.execute_with(|| System::set_block_number(1));
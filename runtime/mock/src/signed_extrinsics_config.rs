@@ -20,10 +20,15 @@ pub type UncheckedExtrinsic =
 pub type CheckedExtrinsic =
     sp_runtime::generic::CheckedExtrinsic<AccountId, RuntimeCall, SignedExtra>;
 
-// impl<C> frame_system::offchain::SendTransactionTypes<C> for Runtime
-// where
-//     RuntimeCall: From<C>,
-// {
-//     type Extrinsic = Extrinsic;
-//     type OverarchingCall = RuntimeCall;
-// }
+impl<C> frame_system::offchain::SendTransactionTypes<C> for Runtime
+where
+    RuntimeCall: From<C>,
+{
+    type Extrinsic = UncheckedExtrinsic;
+    type OverarchingCall = RuntimeCall;
+}
+
+impl frame_system::offchain::SigningTypes for Runtime {
+    type Public = <Signature as sp_runtime::traits::Verify>::Signer;
+    type Signature = Signature;
+}
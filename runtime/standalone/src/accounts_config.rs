@@ -1,14 +1,16 @@
 use crate::{
-    AccountId, AssetId, Assets, Balance, Balances, Clock, EnsureRoot, Imbalance, OnUnbalanced,
-    Runtime, RuntimeEvent, ThreeVm, Timestamp,
+    AccountId, AssetId, Assets, Balance, Balances, Clock, EnsureRoot, Executors, Imbalance,
+    InsuranceFund, OnUnbalanced, Runtime, RuntimeEvent, ThreeVm, Timestamp,
 };
-use frame_support::{parameter_types, traits::AsEnsureOriginWithArg};
+use frame_support::{parameter_types, traits::AsEnsureOriginWithArg, weights::Weight};
 use sp_core::{crypto::AccountId32, ConstU32};
-use sp_runtime::traits::ConvertInto;
+use sp_runtime::{traits::ConvertInto, Percent};
 
 parameter_types! {
     // TODO: update me to be better
     pub EscrowAccount: AccountId32 = AccountId32::new([51_u8; 32]);
+    pub const ProtocolBurn: Percent = Percent::from_percent(20);
+    pub const SettlementWeightBudget: Weight = Weight::from_parts(50_000_000_000, u64::MAX);
 }
 
 impl pallet_account_manager::Config for Runtime {
@@ -18,9 +20,13 @@ impl pallet_account_manager::Config for Runtime {
     type Clock = Clock;
     type Currency = Balances;
     type EscrowAccount = EscrowAccount;
-    type Executors = t3rn_primitives::executors::ExecutorsMock<Self>;
+    type Executors = Executors;
+    type InsuranceFund = InsuranceFund;
+    type ProtocolBurn = ProtocolBurn;
     type RuntimeEvent = RuntimeEvent;
+    type SettlementWeightBudget = SettlementWeightBudget;
     type Time = Timestamp;
+    type TreasuryAccounts = Runtime;
     type WeightInfo = ();
 }
 
@@ -16,7 +16,7 @@ use sp_std::boxed::Box;
 use frame_support::{
     dispatch::DispatchResultWithPostInfo,
     parameter_types,
-    traits::{fungibles::Destroy, ConstU32},
+    traits::{fungibles::Destroy, ConstU32, NeverEnsureOrigin},
     Blake2_128Concat, PalletId, StorageHasher,
 };
 use frame_system::{pallet_prelude::OriginFor, Config};
@@ -45,6 +45,7 @@ parameter_types! {
     pub const RewardMultiplier: Balance = 1;
     pub const MinNominatorBond: Balance = 1;
     pub const MinAttesterBond: Balance = 1;
+    pub const GatewayIdReservationDeposit: Balance = 100 * (TRN as Balance);
     pub const DefaultCommission: Percent = Percent::from_percent(10);
     pub const HourlyShufflingFrequency: BlockNumber = 60 * 60 / 12; // (60 * 60) / 12; assuming one distribution per two weeks
 }
@@ -65,9 +66,11 @@ impl pallet_attesters::Config for Runtime {
     type RepatriationPeriod = ConstU32<60>;
     type RewardMultiplier = RewardMultiplier;
     type Rewards = Rewards;
+    type RotateCommitteeOnNewRound = frame_support::traits::ConstBool<true>;
     type RuntimeEvent = RuntimeEvent;
     type ShufflingFrequency = HourlyShufflingFrequency;
     type TreasuryAccounts = Runtime;
+    type WeightInfo = pallet_attesters::weights::SubstrateWeight<Runtime>;
     type Xdns = XDNS;
 }
 
@@ -83,6 +86,8 @@ parameter_types! {
     pub const AttesterBootstrapRewards: Percent = Percent::from_parts(40); // 40%
     pub const CollatorBootstrapRewards: Percent = Percent::from_parts(20); // 20%
     pub const ExecutorBootstrapRewards: Percent = Percent::from_parts(40); // 40%
+    pub const ExecutorRewardsImmediateShare: Percent = Percent::from_parts(30); // 30%
+    pub const ExecutorRewardsVestingRounds: u32 = 10;
     pub const StartingRepatriationPercentage: Percent = Percent::from_parts(10); // 10%
     pub const OneYear: BlockNumber = 2_628_000; // (365.25 * 24 * 60 * 60) / 12; assuming 12s block time
     pub const InflationDistributionPeriod: BlockNumber = 100_800; // (14 * 24 * 60 * 60) / 12; assuming one distribution per two weeks
@@ -102,6 +107,9 @@ impl pallet_rewards::Config for Runtime {
     type Currency = Balances;
     type ExecutorBootstrapRewards = ExecutorBootstrapRewards;
     type ExecutorInflation = ExecutorInflation;
+    type ExecutorRewardsImmediateShare = ExecutorRewardsImmediateShare;
+    type ExecutorRewardsVestingRounds = ExecutorRewardsVestingRounds;
+    type Executors = Executors;
     type FindAuthor = ();
     type InflationDistributionPeriod = InflationDistributionPeriod;
     type OneYear = OneYear;
@@ -115,8 +123,51 @@ impl pallet_rewards::Config for Runtime {
 impl pallet_clock::Config for Runtime {
     type OnFinalizeQueues = t3rn_primitives::clock::EmptyOnHookQueues<Self>;
     type OnInitializeQueues = GlobalOnInitQueues;
+    type OnNewRound = (Executors, AccountManager, Attesters);
+    type OnRoundDurationChange = ();
     type RoundDuration = ConstU32<300>;
     type RuntimeEvent = RuntimeEvent;
+    type UpdateOrigin = frame_system::EnsureRoot<Self::AccountId>;
+}
+
+parameter_types! {
+    pub const MinExecutorBond: Balance = 1_000 * (TRN as Balance);
+    pub const MinExecutorStake: Balance = 10 * (TRN as Balance);
+    pub const MaxExecutorCommission: Percent = Percent::from_percent(50);
+    pub const HighValueBidThreshold: Balance = 10_000 * (TRN as Balance);
+    pub const MinReputationForHighValueBids: Percent = Percent::from_percent(50);
+}
+
+impl pallet_executors::Config for Runtime {
+    type ActiveSetSize = ConstU32<32>;
+    type Clock = Clock;
+    type Currency = Balances;
+    type HighValueBidThreshold = HighValueBidThreshold;
+    type InsuranceFund = InsuranceFund;
+    type IssuanceOrigin = frame_system::EnsureRoot<AccountId>;
+    type MaxCapabilities = ConstU32<64>;
+    type MaxCommission = MaxExecutorCommission;
+    type MaxConfirmationDelay = ConstU32<300>;
+    type MaxExecutors = ConstU32<512>;
+    type MaxMissedHeartbeats = ConstU32<3>;
+    type MaxRoundsPerClaim = ConstU32<32>;
+    type MaxSlashesPerRound = ConstU32<64>;
+    type MaxStakersPerExecutor = ConstU32<64>;
+    type MaxStakesPerStaker = ConstU32<16>;
+    type MaxUnbondingChunks = ConstU32<32>;
+    type MetricsRetentionRounds = ConstU32<30>;
+    type MinExecutorBond = MinExecutorBond;
+    type MinReputationForHighValueBids = MinReputationForHighValueBids;
+    type MinStake = MinExecutorStake;
+    type OnExecutorDeactivated = Circuit;
+    type RuntimeEvent = RuntimeEvent;
+    type SlashAppealPeriod = ConstU32<7>;
+    type SlashCancelOrigin = frame_system::EnsureRoot<AccountId>;
+    type SlashOrigin = frame_system::EnsureRoot<AccountId>;
+    type TreasuryAccounts = Runtime;
+    type UnbondingDelay = ConstU32<7>;
+    type UnstakeDelay = ConstU32<2>;
+    type WeightInfo = pallet_executors::weights::SubstrateWeight<Runtime>;
 }
 
 impl pallet_vacuum::Config for Runtime {
@@ -136,12 +187,18 @@ impl pallet_xdns::Config for Runtime {
     type Balances = Balances;
     type CircuitDLQ = Circuit;
     type Currency = Balances;
+    type FeeOracleAuthorityId = pallet_xdns::fee_oracle::crypto::FeeOracleAuthId;
+    type FeeOracleInterval = ConstU32<100>;
+    type GatewayIdReservationDeposit = GatewayIdReservationDeposit;
     type Portal = Portal;
+    type PurgeOrigin = frame_system::EnsureRoot<AccountId>;
+    type RegistrationOrigin = frame_system::EnsureRoot<AccountId>;
     type RuntimeEvent = RuntimeEvent;
     type SelfGatewayId = SelfGatewayId;
     type SelfTokenId = ConstU32<3333>;
     type Time = Timestamp;
     type TreasuryAccounts = Runtime;
+    type UpdateOrigin = frame_system::EnsureRoot<AccountId>;
     type WeightInfo = pallet_xdns::weights::SubstrateWeight<Runtime>;
 }
 
@@ -220,9 +277,13 @@ impl PalletAssetsOverlay<Runtime, Balance> for Runtime {
 
 impl pallet_contracts_registry::Config for Runtime {
     type Balances = Balances;
+    type CircuitSubmitAPI = Circuit;
     type Currency = Balances;
+    type Portal = Portal;
+    type ReadSFX = Circuit;
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = pallet_contracts_registry::weights::SubstrateWeight<Runtime>;
+    type Xdns = XDNS;
 }
 
 pub struct SelectLightClientRegistry;
@@ -255,10 +316,49 @@ impl pallet_portal::SelectLightClient<Runtime> for SelectLightClientRegistry {
     }
 }
 
+// Justifications grow by ~130 bytes per precommit of the GRANDPA authority set
+const MAX_GRANDPA_HEADERS_SIZE: u32 = 512 * 1024;
+// Storage proofs carry the events of a relaychain block in full
+const MAX_GRANDPA_PROOF_SIZE: u32 = 1024 * 1024;
+// Sync committee updates carry the 512 public keys of the next committee
+const MAX_ETHEREUM_HEADERS_SIZE: u32 = 256 * 1024;
+const MAX_ETHEREUM_PROOF_SIZE: u32 = 256 * 1024;
+
+pub struct PortalVendorLimits;
+
+impl pallet_portal::VendorLimits for PortalVendorLimits {
+    fn max_headers_size(vendor: &GatewayVendor) -> u32 {
+        match vendor {
+            GatewayVendor::Ethereum | GatewayVendor::Sepolia => MAX_ETHEREUM_HEADERS_SIZE,
+            _ => MAX_GRANDPA_HEADERS_SIZE,
+        }
+    }
+
+    fn max_proof_size(vendor: &GatewayVendor) -> u32 {
+        match vendor {
+            GatewayVendor::Ethereum | GatewayVendor::Sepolia => MAX_ETHEREUM_PROOF_SIZE,
+            _ => MAX_GRANDPA_PROOF_SIZE,
+        }
+    }
+}
+
 impl pallet_portal::Config for Runtime {
     type Currency = Balances;
+    type HeaderExporter = ();
+    type HeaderSubmissionWindow = ConstU32<10>;
+    type HeaderSubscriberOrigin = NeverEnsureOrigin<u32>;
+    type MaxCachedInclusions = ConstU32<256>;
+    type MaxHeaderExportsPerBlock = ConstU32<16>;
+    type MaxHeaderSubmissionsPerWindow = ConstU32<10>;
+    type MaxPrunedPerBlock = ConstU32<100>;
+    type OnHeadersRolledBack = Circuit;
+    type RetentionPeriod = ConstU32<50_400>;
     type RuntimeEvent = RuntimeEvent;
+    #[cfg(not(feature = "dev-simulation"))]
     type SelectLightClient = SelectLightClientRegistry;
+    #[cfg(feature = "dev-simulation")]
+    type SelectLightClient = pallet_portal::simulation::SimulatedLightClients<Runtime>;
+    type VendorLimits = PortalVendorLimits;
     type WeightInfo = pallet_portal::weights::SubstrateWeight<Runtime>;
     type Xdns = XDNS;
 }
@@ -283,20 +383,35 @@ impl pallet_circuit::Config for Runtime {
     type AccountManager = AccountManager;
     type Attesters = Attesters;
     type Balances = Balances;
+    type ConfirmationDepth = ConstU32<12u32>;
     type Currency = Balances;
     type DeletionQueueLimit = ConstU32<100u32>;
-    type Executors = t3rn_primitives::executors::ExecutorsMock<Self>;
+    type EscrowGovernanceOrigin = frame_system::EnsureRoot<AccountId>;
+    type Executors = Executors;
+    type MaxEncodedArgLen = ConstU32<4_096u32>;
+    type MaxPrunedPerBlock = ConstU32<100u32>;
+    type MaxSideEffectsPerXtx = ConstU32<32u32>;
+    type MaxStepsPerXtx = ConstU32<8u32>;
+    type OnXtxConcluded = (ThreeVm, ContractsRegistry, Vacuum);
     type Portal = Portal;
+    type RetentionPeriod = ConstU32<50_400u32>;
     type RuntimeEvent = RuntimeEvent;
     type SFXBiddingPeriod = ConstU32<3u32>;
     type SelfAccountId = CircuitAccountId;
     type SelfGatewayId = SelfGatewayId;
     type SelfParaId = ConstU32<3333u32>;
     type SignalQueueDepth = ConstU32<5u32>;
+    #[cfg(feature = "dev-simulation")]
+    type SimulatedConfirmationDelay = ConstU32<3u32>;
+    type SponsorSignature = sp_runtime::MultiSignature;
+    type SponsorSigner = sp_runtime::MultiSigner;
+    type TelemetryInterval = ConstU32<100u32>;
     type TreasuryAccounts = Runtime;
     type WeightInfo = pallet_circuit::weights::SubstrateWeight<Runtime>;
     // type XBIPortal = XBIPortalRuntimeEntry;
     // type XBIPromise = XBIPortal;
+    type XcmOrderNotifier = ();
+    type XcmOrderOrigin = NeverEnsureOrigin<(u32, AccountId)>;
     type Xdns = XDNS;
     type XtxTimeoutCheckInterval = ConstU32<10u32>;
     type XtxTimeoutDefault = ConstU32<400u32>;
@@ -304,6 +419,8 @@ impl pallet_circuit::Config for Runtime {
 
 parameter_types! {
     pub const HeadersToStore: u32 = 100;
+    pub const HeaderSyncSessionDeposit: Balance = 10 * (TRN as Balance);
+    pub const HeaderSyncChunkDeposit: Balance = TRN as Balance;
     pub const RococoVendor: GatewayVendor = GatewayVendor::Rococo;
     pub const KusamaVendor: GatewayVendor = GatewayVendor::Kusama;
     pub const PolkadotVendor: GatewayVendor = GatewayVendor::Polkadot;
@@ -320,12 +437,19 @@ impl bp_runtime::Chain for Blake2ValU32Chain {
 
 impl pallet_grandpa_finality_verifier::Config<RococoInstance> for Runtime {
     type BridgedChain = Blake2ValU32Chain;
+    type Currency = Balances;
     type EpochOffset = ConstU32<2_400u32>;
     type FastConfirmationOffset = ConstU32<0u32>;
     type FinalizedConfirmationOffset = ConstU32<0u32>;
+    type HeaderSubmissionHooks = Portal;
+    type HeaderSyncChunkDeposit = HeaderSyncChunkDeposit;
+    type HeaderSyncSessionDeposit = HeaderSyncSessionDeposit;
+    type HeaderSyncSessionTtl = ConstU32<14_400u32>;
     type HeadersToStore = HeadersToStore;
     type LightClientAsyncAPI = XDNS;
+    type MaxHeaderSyncSessionsPerRelayer = ConstU32<4u32>;
     type MyVendor = RococoVendor;
+    type OnHeadersRolledBack = Portal;
     type RationalConfirmationOffset = ConstU32<0u32>;
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = ();
@@ -333,12 +457,19 @@ impl pallet_grandpa_finality_verifier::Config<RococoInstance> for Runtime {
 
 impl pallet_grandpa_finality_verifier::Config<PolkadotInstance> for Runtime {
     type BridgedChain = Blake2ValU32Chain;
+    type Currency = Balances;
     type EpochOffset = ConstU32<2_400u32>;
     type FastConfirmationOffset = ConstU32<0u32>;
     type FinalizedConfirmationOffset = ConstU32<0u32>;
+    type HeaderSubmissionHooks = Portal;
+    type HeaderSyncChunkDeposit = HeaderSyncChunkDeposit;
+    type HeaderSyncSessionDeposit = HeaderSyncSessionDeposit;
+    type HeaderSyncSessionTtl = ConstU32<14_400u32>;
     type HeadersToStore = HeadersToStore;
     type LightClientAsyncAPI = XDNS;
+    type MaxHeaderSyncSessionsPerRelayer = ConstU32<4u32>;
     type MyVendor = PolkadotVendor;
+    type OnHeadersRolledBack = Portal;
     type RationalConfirmationOffset = ConstU32<0u32>;
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = ();
@@ -346,12 +477,19 @@ impl pallet_grandpa_finality_verifier::Config<PolkadotInstance> for Runtime {
 
 impl pallet_grandpa_finality_verifier::Config<KusamaInstance> for Runtime {
     type BridgedChain = Blake2ValU32Chain;
+    type Currency = Balances;
     type EpochOffset = ConstU32<2_400u32>;
     type FastConfirmationOffset = ConstU32<0u32>;
     type FinalizedConfirmationOffset = ConstU32<0u32>;
+    type HeaderSubmissionHooks = Portal;
+    type HeaderSyncChunkDeposit = HeaderSyncChunkDeposit;
+    type HeaderSyncSessionDeposit = HeaderSyncSessionDeposit;
+    type HeaderSyncSessionTtl = ConstU32<14_400u32>;
     type HeadersToStore = HeadersToStore;
     type LightClientAsyncAPI = XDNS;
+    type MaxHeaderSyncSessionsPerRelayer = ConstU32<4u32>;
     type MyVendor = KusamaVendor;
+    type OnHeadersRolledBack = Portal;
     type RationalConfirmationOffset = ConstU32<0u32>;
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = ();
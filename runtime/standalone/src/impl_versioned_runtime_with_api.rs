@@ -256,7 +256,7 @@ impl_runtime_apis! {
         }
     }
 
-     impl pallet_xdns_rpc_runtime_api::XdnsRuntimeApi<Block, AccountId> for Runtime {
+     impl pallet_xdns_rpc_runtime_api::XdnsRuntimeApi<Block, AccountId, Balance> for Runtime {
         fn fetch_records() -> Vec<GatewayRecord<AccountId>> {
              <XDNS as t3rn_primitives::xdns::Xdns<Runtime, Balance>>::fetch_gateways()
         }
@@ -273,6 +273,66 @@ impl_runtime_apis! {
         fn retreive_treasury_address(treasury_account: t3rn_primitives::TreasuryAccount) -> AccountId {
             Runtime::get_treasury_account(treasury_account)
         }
+
+        fn best_gateway_for(sfx_4b_id: [u8; 4], asset_id: u32) -> Option<ChainId> {
+            <XDNS as t3rn_primitives::xdns::Xdns<Runtime, Balance>>::best_gateway_for(&sfx_4b_id, asset_id)
+        }
+
+        fn fetch_gateway_activity_stats(chain_id: ChainId) -> t3rn_primitives::xdns::GatewayActivityStats {
+            <XDNS as t3rn_primitives::xdns::Xdns<Runtime, Balance>>::get_gateway_activity_stats(&chain_id)
+        }
+
+        fn estimate_xtx_cost(side_effects: Vec<(ChainId, u32, Balance)>, quote_asset: u32) -> t3rn_primitives::xdns::XtxCostEstimate<Balance> {
+            <XDNS as t3rn_primitives::xdns::Xdns<Runtime, Balance>>::estimate_xtx_cost(&side_effects, quote_asset)
+        }
+
+        fn fetch_sfx_schemas() -> Vec<t3rn_primitives::xdns::SfxSchema> {
+            <XDNS as t3rn_primitives::xdns::Xdns<Runtime, Balance>>::fetch_sfx_schemas()
+        }
+
+        fn suggest_max_fee(gateway_id: ChainId, sfx_4b_id: [u8; 4]) -> Option<Balance> {
+            <XDNS as t3rn_primitives::xdns::Xdns<Runtime, Balance>>::suggest_max_fee(&gateway_id, &sfx_4b_id)
+        }
+    }
+
+    impl pallet_circuit_runtime_api::CircuitRuntimeApi<Block, AccountId, Balance, Hash, BlockNumber> for Runtime {
+        fn compute_xtx_id(requester: AccountId, requester_nonce: u32) -> Hash {
+            Circuit::compute_xtx_id(&requester, requester_nonce)
+        }
+
+        fn compute_sfx_id(side_effect: SideEffect<AccountId, Balance>, xtx_id: Hash, index: u32) -> Hash {
+            Circuit::compute_sfx_id(&side_effect, xtx_id, index)
+        }
+
+        fn order_book(gateway_id: [u8; 4]) -> Vec<pallet_circuit_runtime_api::OpenOrder<Hash, AccountId, Balance, BlockNumber>> {
+            Circuit::order_book(gateway_id)
+        }
+    }
+
+    impl pallet_executors_runtime_api::ExecutorsRuntimeApi<Block, AccountId, Balance> for Runtime {
+        fn active_set() -> Vec<AccountId> {
+            <Executors as t3rn_primitives::executors::Executors<Runtime, Balance>>::active_set()
+        }
+
+        fn executor_metrics(executor: AccountId) -> Vec<(t3rn_primitives::common::RoundIndex, t3rn_primitives::executors::ExecutorRoundMetrics<Balance>)> {
+            Executors::metrics_of(&executor)
+        }
+
+        fn corridor_liquidity(target: [u8; 4], sfx_4b_id: [u8; 4]) -> Balance {
+            Executors::corridor_liquidity(&target, &sfx_4b_id)
+        }
+
+        fn executor_standing(executor: AccountId) -> Option<t3rn_primitives::executors::ExecutorStanding<AccountId, Balance>> {
+            Executors::standing_of(&executor)
+        }
+
+        fn executor_standings() -> Vec<t3rn_primitives::executors::ExecutorStanding<AccountId, Balance>> {
+            Executors::standings()
+        }
+
+        fn staker_stakes(staker: AccountId) -> Vec<t3rn_primitives::executors::NominatedStake<AccountId, Balance>> {
+            <Executors as t3rn_primitives::executors::Executors<Runtime, Balance>>::stakes(&staker)
+        }
     }
 
      impl pallet_portal_rpc_runtime_api::PortalRuntimeApi<Block, AccountId, Balance, Hash> for Runtime {
@@ -292,6 +352,30 @@ impl_runtime_apis! {
         )> {
             Circuit::get_pending_xtx_for(for_executor)
         }
+
+        fn fetch_all_finalized_heights() -> Vec<(
+            ChainId, // gateway_id
+            u128,    // finalized height
+            u128,    // updated height
+            u128,    // epoch
+            bool,    // is operational
+        )> {
+            Portal::get_all_finalized_heights()
+                .into_iter()
+                .map(|(gateway_id, finalized_height, updated_height, epoch, is_active)| {
+                    (gateway_id, finalized_height.into(), updated_height.into(), epoch.into(), is_active)
+                })
+                .collect()
+        }
+
+        fn fetch_sfx_notifications() -> Vec<t3rn_primitives::circuit::SfxNotification<Hash, AccountId, Balance>> {
+            Circuit::get_sfx_notifications(System::read_events_no_consensus().filter_map(|record| {
+                match record.event {
+                    RuntimeEvent::Circuit(event) => Some(event),
+                    _ => None,
+                }
+            }))
+        }
     }
 
     #[cfg(feature = "try-runtime")]
@@ -125,6 +125,8 @@ construct_runtime!(
         ContractsRegistry: pallet_contracts_registry = 106,
         Circuit: pallet_circuit = 108,
         Vacuum: pallet_vacuum = 111,
+        Executors: pallet_executors = 112,
+        InsuranceFund: pallet_insurance_fund = 113,
 
         // 3VM
         ThreeVm: pallet_3vm = 119,
@@ -18,11 +18,15 @@ pub type UncheckedExtrinsic =
 /// Extrinsic type that has already been checked.
 pub type CheckedExtrinsic = generic::CheckedExtrinsic<AccountId, RuntimeCall, SignedExtra>;
 
-// impl<C> frame_system::offchain::SendTransactionTypes<C> for Runtime
-// where
-//     RuntimeCall: From<C>,
-// {
-//     // type Extrinsic = TestXt<Call, ()>;
-//     type Extrinsic = UncheckedExtrinsic;
-//     type OverarchingCall = RuntimeCall;
-// }
+impl<C> frame_system::offchain::SendTransactionTypes<C> for Runtime
+where
+    RuntimeCall: From<C>,
+{
+    type Extrinsic = UncheckedExtrinsic;
+    type OverarchingCall = RuntimeCall;
+}
+
+impl frame_system::offchain::SigningTypes for Runtime {
+    type Public = <Signature as sp_runtime::traits::Verify>::Signer;
+    type Signature = Signature;
+}
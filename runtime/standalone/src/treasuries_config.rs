@@ -1,6 +1,6 @@
 use super::{AccountId, Balance, Balances, Runtime, RuntimeEvent, SlashTreasury};
 use frame_support::{parameter_types, traits::NeverEnsureOrigin, PalletId};
-use frame_system::EnsureRoot;
+use frame_system::{EnsureRoot, EnsureRootWithSuccess};
 use sp_runtime::{traits::AccountIdConversion, Permill};
 
 use circuit_runtime_types::UNIT as TRN;
@@ -67,6 +67,8 @@ impl pallet_treasury::Config<EscrowTreasuryInstance> for Runtime {
 // Treasury#3 - FeeTreasury
 parameter_types! {
     pub const FeeTreasuryId: PalletId = PalletId(*b"feetrsry");
+    // Governance can spend protocol fees and slashes without going through proposals
+    pub const MaxProtocolTreasurySpend: Balance = 100_000 * (TRN as Balance);
 }
 
 impl pallet_treasury::Config<FeeTreasuryInstance> for Runtime {
@@ -83,7 +85,7 @@ impl pallet_treasury::Config<FeeTreasuryInstance> for Runtime {
     type RejectOrigin = EnsureRoot<AccountId>;
     type RuntimeEvent = RuntimeEvent;
     type SpendFunds = ();
-    type SpendOrigin = NeverEnsureOrigin<Balance>;
+    type SpendOrigin = EnsureRootWithSuccess<AccountId, MaxProtocolTreasurySpend>;
     type SpendPeriod = SpendPeriod;
     type WeightInfo = pallet_treasury::weights::SubstrateWeight<Runtime>;
 }
@@ -131,11 +133,24 @@ impl pallet_treasury::Config<SlashTreasuryInstance> for Runtime {
     type RejectOrigin = EnsureRoot<AccountId>;
     type RuntimeEvent = RuntimeEvent;
     type SpendFunds = ();
-    type SpendOrigin = NeverEnsureOrigin<Balance>;
+    type SpendOrigin = EnsureRootWithSuccess<AccountId, MaxProtocolTreasurySpend>;
     type SpendPeriod = SpendPeriod;
     type WeightInfo = pallet_treasury::weights::SubstrateWeight<Runtime>;
 }
 
+// Protocol insurance fund
+parameter_types! {
+    pub const InsuranceFundId: PalletId = PalletId(*b"insrfund");
+}
+
+impl pallet_insurance_fund::Config for Runtime {
+    type AdminOrigin = EnsureRoot<AccountId>;
+    type Currency = Balances;
+    type PalletId = InsuranceFundId;
+    type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = pallet_insurance_fund::weights::SubstrateWeight<Runtime>;
+}
+
 impl TreasuryAccountProvider<AccountId> for Runtime {
     fn get_treasury_account(treasury_account: TreasuryAccount) -> AccountId {
         match treasury_account {
@@ -1,21 +1,29 @@
 use crate::{
-    hooks::GlobalOnInitQueues, treasuries_config::EscrowTreasuryId, AssetId, Assets, Balance,
-    Balances, Clock, Imbalance, OnUnbalanced, Runtime, RuntimeCall, RuntimeEvent, ThreeVm,
-    Timestamp,
+    hooks::GlobalOnInitQueues, treasuries_config::EscrowTreasuryId, AccountManager, AssetId,
+    Assets, Balance, Balances, Clock, Executors, Imbalance, InsuranceFund, OnUnbalanced, Runtime,
+    RuntimeCall, RuntimeEvent, ThreeVm, Timestamp,
 };
-use frame_support::parameter_types;
+use frame_support::{parameter_types, weights::Weight};
 use sp_core::{crypto::AccountId32, ConstU32};
-use sp_runtime::traits::{AccountIdConversion, ConvertInto};
+use sp_runtime::{
+    traits::{AccountIdConversion, ConvertInto},
+    Percent,
+};
 
 parameter_types! {
     pub EscrowAccount: AccountId32 = EscrowTreasuryId::get().into_account_truncating();
+    pub const ProtocolBurn: Percent = Percent::from_percent(20);
+    pub const SettlementWeightBudget: Weight = Weight::from_parts(50_000_000_000, u64::MAX);
 }
 
 impl pallet_clock::Config for Runtime {
     type OnFinalizeQueues = t3rn_primitives::clock::EmptyOnHookQueues<Self>;
     type OnInitializeQueues = GlobalOnInitQueues;
+    type OnNewRound = (Executors, AccountManager, Attesters);
+    type OnRoundDurationChange = ();
     type RoundDuration = ConstU32<300u32>;
     type RuntimeEvent = RuntimeEvent;
+    type UpdateOrigin = frame_system::EnsureRoot<Self::AccountId>;
 }
 
 impl pallet_account_manager::Config for Runtime {
@@ -25,9 +33,13 @@ impl pallet_account_manager::Config for Runtime {
     type Clock = Clock;
     type Currency = Balances;
     type EscrowAccount = EscrowAccount;
-    type Executors = t3rn_primitives::executors::ExecutorsMock<Self>;
+    type Executors = Executors;
+    type InsuranceFund = InsuranceFund;
+    type ProtocolBurn = ProtocolBurn;
     type RuntimeEvent = RuntimeEvent;
+    type SettlementWeightBudget = SettlementWeightBudget;
     type Time = Timestamp;
+    type TreasuryAccounts = Runtime;
     type WeightInfo = ();
 }
 
@@ -40,6 +40,11 @@ const fn deposit(items: u32, bytes: u32) -> Balance {
 parameter_types! {
     pub const CreateSideEffectsPrecompileDest: AccountId = AccountId::new([51u8; 32]); // 0x333...3
     pub const CircuitTargetId: t3rn_primitives::ChainId = [3, 3, 3, 3];
+    pub CallbackGasLimit: Weight = Weight::from_parts(5_000_000_000, 256 * 1024);
+    pub const XtxGasLimit: u64 = 500_000;
+    pub XtxGasPrice: Balance = FixedGasPrice::min_gas_price().0.low_u128();
+    pub LocalTriggerWeight: Weight =
+        <pallet_circuit::weights::SubstrateWeight<Runtime> as pallet_circuit::weights::WeightInfo>::on_local_trigger();
 
     pub const MaxValueSize: u32 = 16_384;
     // The lazy deletion runs inside on_initialize.
@@ -56,15 +61,24 @@ impl pallet_3vm::Config for Runtime {
     type AccountManager = AccountManager;
     type AddressMapping = EvmAddressMapping<Runtime>;
     type AssetId = AssetId;
+    type CallbackGasLimit = CallbackGasLimit;
     type CircuitTargetId = CircuitTargetId;
     type ContractsRegistry = ContractsRegistry;
     type Currency = Balances;
     type EscrowAccount = EscrowAccount;
+    type GasPrice = XtxGasPrice;
+    type LocalTriggerWeight = LocalTriggerWeight;
     type OnLocalTrigger = Circuit;
     type Portal = Portal;
     type RuntimeEvent = RuntimeEvent;
     type SignalBounceThreshold = ConstU32<2>;
+    type UnifiedGas = pallet_3vm_evm::FixedGasWeightMapping<Runtime>;
     type VacuumEVMApi = Vacuum;
+    type XtxGasLimit = XtxGasLimit;
+    type XtxResultCallback = (
+        pallet_3vm_contracts::WasmXtxResultCallback<Runtime>,
+        pallet_3vm_evm::EvmXtxResultCallback<Runtime, EvmAddressMapping<Runtime>>,
+    );
 }
 
 impl pallet_3vm_contracts::Config for Runtime {
@@ -77,7 +91,7 @@ impl pallet_3vm_contracts::Config for Runtime {
     /// is not allowed to change the indices of existing pallets, too.
     type CallFilter = frame_support::traits::Nothing;
     type CallStack = [pallet_3vm_contracts::Frame<Self>; 5];
-    type ChainExtension = ();
+    type ChainExtension = pallet_3vm_contracts::ThreeVmExtension;
     type Currency = Balances;
     type DefaultDepositLimit = DefaultDepositLimit;
     type DepositPerByte = DepositPerByte;
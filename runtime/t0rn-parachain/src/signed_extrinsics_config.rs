@@ -27,3 +27,8 @@ where
     type Extrinsic = UncheckedExtrinsic;
     type OverarchingCall = RuntimeCall;
 }
+
+impl frame_system::offchain::SigningTypes for Runtime {
+    type Public = <Signature as sp_runtime::traits::Verify>::Signer;
+    type Signature = Signature;
+}
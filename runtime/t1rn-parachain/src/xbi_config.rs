@@ -9,7 +9,10 @@ use cumulus_primitives_core::ParaId;
 use cumulus_primitives_core::GetChannelInfo;
 use frame_support::{
     match_types, parameter_types,
-    traits::{ConstU32, Contains, ContainsPair, Currency, Everything, Get, Nothing, OnUnbalanced},
+    traits::{
+        ConstU32, Contains, ContainsPair, Currency, EnsureOrigin, Everything, Get, Nothing,
+        OnUnbalanced,
+    },
     weights::Weight,
 };
 use frame_system::EnsureRoot;
@@ -34,6 +37,13 @@ use xcm_executor::{traits::JustTry, XcmExecutor};
 
 use xcm_primitives::{AsAssetMultiLocation, ConvertedRegisteredAssetId};
 
+use codec::Encode;
+use sp_runtime::{traits::AccountIdConversion, DispatchError, DispatchResult};
+use t3rn_primitives::{
+    circuit::{CircuitStatus, XcmOrder},
+    ChainId,
+};
+
 parameter_types! {
     pub const SelfGatewayId: [u8; 4] = [3, 3, 3, 3];
     pub const XbiSovereign: AccountId = AccountId::new([68u8; 32]); // 0x444...4
@@ -42,6 +52,7 @@ parameter_types! {
 parameter_types! {
     pub ReserveBalanceCustodian: AccountId = PolkadotXcm::check_account();
     pub NotificationWeight: Weight = Weight::from_parts(1, 0u64);
+    pub SiblingNotificationWeight: Weight = Weight::from_parts(1_000_000_000, 64 * 1024);
 }
 
 // impl pallet_xbi_portal::Config for Runtime {
@@ -469,3 +480,86 @@ impl cumulus_pallet_xcmp_queue::Config for Runtime {
     type WeightInfo = cumulus_pallet_xcmp_queue::weights::SubstrateWeight<Runtime>;
     type XcmExecutor = XcmExecutor<XcmConfig>;
 }
+
+/// Resolves XCM `Transact` from sibling parachains into their para id.
+pub struct EnsureSiblingPara;
+impl EnsureOrigin<RuntimeOrigin> for EnsureSiblingPara {
+    type Success = u32;
+
+    fn try_origin(o: RuntimeOrigin) -> Result<Self::Success, RuntimeOrigin> {
+        cumulus_pallet_xcm::ensure_sibling_para(o.clone())
+            .map(Into::into)
+            .map_err(|_| o)
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn try_successful_origin() -> Result<RuntimeOrigin, ()> {
+        Ok(cumulus_pallet_xcm::Origin::SiblingParachain(ParaId::from(2000)).into())
+    }
+}
+
+/// Resolves XCM `Transact` from sibling parachains into their para id and sovereign account.
+pub struct EnsureSiblingParaSovereign;
+impl EnsureOrigin<RuntimeOrigin> for EnsureSiblingParaSovereign {
+    type Success = (u32, AccountId);
+
+    fn try_origin(o: RuntimeOrigin) -> Result<Self::Success, RuntimeOrigin> {
+        let para_id = EnsureSiblingPara::try_origin(o)?;
+        let sovereign: AccountId = Sibling(ParaId::from(para_id)).into_account_truncating();
+        Ok((para_id, sovereign))
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn try_successful_origin() -> Result<RuntimeOrigin, ()> {
+        EnsureSiblingPara::try_successful_origin()
+    }
+}
+
+/// Transacts `call` on a sibling as unpaid execution with the native origin of this parachain,
+///     so siblings subscribing to our services need to accept unpaid execution from us.
+fn transact_on_sibling(para_id: u32, call: Vec<u8>) -> DispatchResult {
+    let message = Xcm(sp_std::vec![
+        UnpaidExecution {
+            weight_limit: WeightLimit::Unlimited,
+            check_origin: None,
+        },
+        Transact {
+            origin_kind: OriginKind::Native,
+            require_weight_at_most: SiblingNotificationWeight::get(),
+            call: call.into(),
+        },
+    ]);
+
+    send_xcm::<XcmRouter>(MultiLocation::new(1, X1(Parachain(para_id))), message)
+        .map(|_| ())
+        .map_err(|_| DispatchError::Other("SiblingNotificationUnroutable"))
+}
+
+/// Transacts the notification call of an XCM order on its sibling, with its outcome appended.
+pub struct SiblingXcmOrderNotifier;
+impl<Hash: Encode> t3rn_primitives::circuit::XcmOrderNotifier<Hash> for SiblingXcmOrderNotifier {
+    fn notify(order: &XcmOrder, xtx_id: Hash, status: &CircuitStatus) -> DispatchResult {
+        let mut call = order.notify_call.clone();
+        (xtx_id, status).encode_to(&mut call);
+        transact_on_sibling(order.para_id, call)
+    }
+}
+
+/// Transacts the notification call of a header subscription on its sibling, with the latest
+///     finalized header of the gateway appended.
+pub struct SiblingHeaderExporter;
+impl<BlockNumber: Encode> t3rn_primitives::portal::HeaderExporter<BlockNumber>
+    for SiblingHeaderExporter
+{
+    fn export(
+        para_id: u32,
+        notify_call: &[u8],
+        gateway_id: ChainId,
+        height: BlockNumber,
+        header: Vec<u8>,
+    ) -> DispatchResult {
+        let mut call = notify_call.to_vec();
+        (gateway_id, height, header).encode_to(&mut call);
+        transact_on_sibling(para_id, call)
+    }
+}
@@ -187,6 +187,9 @@ impl Contains<RuntimeCall> for BaseCallFilter {
                 method,
                 pallet_contracts_registry::Call::add_new_contract { .. }
                     | pallet_contracts_registry::Call::purge { .. }
+                    | pallet_contracts_registry::Call::register_contract { .. }
+                    | pallet_contracts_registry::Call::set_author_fees { .. }
+                    | pallet_contracts_registry::Call::purge_own_contract { .. }
             ),
             RuntimeCall::Circuit(method) => matches!(
                 method,
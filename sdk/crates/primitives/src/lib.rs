@@ -16,6 +16,17 @@ pub const GET_STATE_FUNCTION_CODE: u32 = 2_8008_8008;
 pub const SUBMIT_FUNCTION_CODE: u32 = 3_8008_8008;
 /// A function pointer for posting execution signals
 pub const POST_SIGNAL_FUNCTION_CODE: u32 = 4_8008_8008;
+/// A function pointer for reading the status of an execution's side effects
+pub const GET_SFX_STATUS_FUNCTION_CODE: u32 = 5_8008_8008;
+/// A function pointer for reading the confirmed outputs of an execution's side effects
+pub const GET_CONFIRMED_OUTPUTS_FUNCTION_CODE: u32 = 6_8008_8008;
+/// A function pointer for declaring side effects, posted once the contract's execution succeeds
+pub const DECLARE_SIDE_EFFECTS_FUNCTION_CODE: u32 = 7_8008_8008;
+
+/// The selector contracts receive the results of the executions they originated at, once
+/// concluded, i.e. `#[ink(message, selector = 0x78747872)]`. EVM contracts implement
+/// `onXtxResult(bytes)` instead.
+pub const XTX_RESULT_CALLBACK_SELECTOR: [u8; 4] = [0x78, 0x74, 0x78, 0x72];
 
 /// The maximum amount of parameters we allow users to pass to a function
 pub const MAX_PARAMETERS_IN_FUNCTION: usize = 16;
@@ -1,6 +1,6 @@
 use crate::{storage::BoundedVec, xc::Chain, BTreeMap, Debug, Vec, MAX_PARAMETERS_IN_FUNCTION};
 use codec::{Decode, Encode, MaxEncodedLen};
-use t3rn_types::fsx::FullSideEffect;
+use t3rn_types::{fsx::FullSideEffect, sfx::ConfirmationOutcome};
 
 /// Some new side effects to submit
 #[derive(Encode, Decode, MaxEncodedLen)]
@@ -36,6 +36,59 @@ where
     pub xtx_id: Hash,
 }
 
+/// The progress of a single side effect within an execution
+#[derive(Encode, Decode, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SideEffectStatus {
+    /// Awaiting a confirmation from its executor
+    Pending,
+    /// Confirmed as executed on the target
+    Confirmed,
+    /// Confirmed with a misbehaviour or timed out
+    Failed,
+}
+
+impl From<&Option<ConfirmationOutcome>> for SideEffectStatus {
+    fn from(outcome: &Option<ConfirmationOutcome>) -> Self {
+        match outcome {
+            None => SideEffectStatus::Pending,
+            Some(ConfirmationOutcome::Success) => SideEffectStatus::Confirmed,
+            Some(_) => SideEffectStatus::Failed,
+        }
+    }
+}
+
+/// A side effect confirmed on its target, as seen by the circuit
+#[derive(Encode, Decode, Clone, Debug, PartialEq, Eq)]
+pub struct ConfirmedOutput<AccountId, BlockNumber, Balance> {
+    /// The step the side effect belongs to
+    pub step: u32,
+    /// The index of the side effect within the execution
+    pub index: u32,
+    /// The target the side effect was executed on
+    pub target: [u8; 4],
+    /// The outcome of the confirmation
+    pub outcome: ConfirmationOutcome,
+    /// The executor who confirmed the side effect
+    pub executioner: Option<AccountId>,
+    /// The block the confirmation was received at
+    pub received_at: Option<BlockNumber>,
+    /// The cost of executing the side effect, if reported
+    pub cost: Option<Balance>,
+    /// The value read from the target by a read side effect, as verified by the circuit
+    pub output: Option<Vec<u8>>,
+}
+
+/// The result of an execution originated by a contract, delivered back to it once concluded
+#[derive(Encode, Decode, Clone, Debug, PartialEq, Eq)]
+pub struct XtxResult<Hash, AccountId, BlockNumber, Balance> {
+    /// The id of the concluded execution
+    pub xtx_id: Hash,
+    /// Whether the execution committed, rather than being reverted or killed
+    pub succeeded: bool,
+    /// The outputs of the side effects confirmed throughout the execution
+    pub outputs: Vec<ConfirmedOutput<AccountId, BlockNumber, Balance>>,
+}
+
 /// A handler trait that allows generics to provide some execution_id
 pub trait GetExecutionId<Hash>
 where
@@ -103,3 +156,21 @@ where
     BalanceOf: Encode + Decode + Debug + Clone,
 {
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sfx_status_follows_confirmation_outcome() {
+        assert_eq!(SideEffectStatus::from(&None), SideEffectStatus::Pending);
+        assert_eq!(
+            SideEffectStatus::from(&Some(ConfirmationOutcome::Success)),
+            SideEffectStatus::Confirmed
+        );
+        assert_eq!(
+            SideEffectStatus::from(&Some(ConfirmationOutcome::TimedOut)),
+            SideEffectStatus::Failed
+        );
+    }
+}
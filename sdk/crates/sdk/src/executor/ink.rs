@@ -1,13 +1,15 @@
 use crate::{
-    executor::{Error as ExecutorError, StateHandler, Submitter},
+    executor::{Declarer, Error as ExecutorError, StateHandler, Submitter, Tracker},
     log_msg,
 };
 use codec::{Decode, Encode};
 use ink_env::{self, chain_extension::ChainExtensionMethod};
 use t3rn_sdk_primitives::{
     signal::{ExecutionSignal, Signaller},
-    state::{ExecutionState, SideEffects},
-    Debug, GET_STATE_FUNCTION_CODE, POST_SIGNAL_FUNCTION_CODE, SUBMIT_FUNCTION_CODE,
+    state::{ConfirmedOutput, ExecutionState, SideEffectStatus, SideEffects},
+    Debug, Vec, DECLARE_SIDE_EFFECTS_FUNCTION_CODE, GET_CONFIRMED_OUTPUTS_FUNCTION_CODE,
+    GET_SFX_STATUS_FUNCTION_CODE, GET_STATE_FUNCTION_CODE, POST_SIGNAL_FUNCTION_CODE,
+    SUBMIT_FUNCTION_CODE,
 };
 
 /// This provider utilizes chain extensions in the case of ink to call through to 3vm.
@@ -116,6 +118,23 @@ where
     }
 }
 
+impl<Thing> Declarer<Thing> for InkProvider
+where
+    Thing: Encode + Decode,
+{
+    fn declare(thing: Thing) -> Result<(), ExecutorError> {
+        log_msg!("[INKSDK] declaring side effects");
+
+        ChainExtensionMethod::build(DECLARE_SIDE_EFFECTS_FUNCTION_CODE)
+            .input::<Thing>()
+            .output_result::<(), StateHandlerError>()
+            .handle_error_code::<StateHandlerError>()
+            .call(&thing)
+            .map_err(Error::from)
+            .map_err(ExecutorError::from)
+    }
+}
+
 impl<Hash> Signaller<Hash> for InkProvider
 where
     Hash: Encode + Decode + Debug + Clone,
@@ -139,3 +158,44 @@ where
             .map_err(ExecutorError::from)
     }
 }
+
+impl<Hash, AccountId, BlockNumber, Balance> Tracker<Hash, AccountId, BlockNumber, Balance>
+    for InkProvider
+where
+    Hash: Encode + Decode + Debug + Clone,
+    AccountId: Encode + Decode + Debug + Clone,
+    BlockNumber: Encode + Decode + Debug + Clone,
+    Balance: Encode + Decode + Debug + Clone,
+{
+    fn sfx_status(execution_id: Hash) -> Result<Vec<Vec<SideEffectStatus>>, ExecutorError> {
+        log_msg!(
+            "[INKSDK] getting sfx status for execution: {:?}",
+            execution_id
+        );
+
+        ChainExtensionMethod::build(GET_SFX_STATUS_FUNCTION_CODE)
+            .input::<Hash>()
+            .output_result::<Vec<Vec<SideEffectStatus>>, StateHandlerError>()
+            .handle_error_code::<StateHandlerError>()
+            .call(&execution_id)
+            .map_err(Error::from)
+            .map_err(ExecutorError::from)
+    }
+
+    fn confirmed_outputs(
+        execution_id: Hash,
+    ) -> Result<Vec<ConfirmedOutput<AccountId, BlockNumber, Balance>>, ExecutorError> {
+        log_msg!(
+            "[INKSDK] getting confirmed outputs for execution: {:?}",
+            execution_id
+        );
+
+        ChainExtensionMethod::build(GET_CONFIRMED_OUTPUTS_FUNCTION_CODE)
+            .input::<Hash>()
+            .output_result::<Vec<ConfirmedOutput<AccountId, BlockNumber, Balance>>, StateHandlerError>()
+            .handle_error_code::<StateHandlerError>()
+            .call(&execution_id)
+            .map_err(Error::from)
+            .map_err(ExecutorError::from)
+    }
+}
@@ -1,8 +1,8 @@
 use codec::{Decode, Encode};
 use t3rn_sdk_primitives::{
     signal::{ExecutionSignal, Signaller},
-    state::{ExecutionState, SideEffects},
-    Debug,
+    state::{ConfirmedOutput, ExecutionState, SideEffectStatus, SideEffects},
+    Debug, Vec,
 };
 
 #[cfg(feature = "ink")]
@@ -55,6 +55,33 @@ where
     fn submit(state: Thing) -> Result<(), Error>;
 }
 
+/// This trait provides access for an executor to declare something to be submitted on-chain
+/// once its execution succeeds.
+///
+/// Usually Something is a set of side effects along with their speed mode, requested as a new
+/// execution paid for by the contract.
+pub trait Declarer<Thing>
+where
+    Thing: Encode + Decode,
+{
+    /// Declares something to be submitted after a successful execution
+    fn declare(thing: Thing) -> Result<(), Error>;
+}
+
+/// This trait provides access for an executor to track the side effects of an execution.
+pub trait Tracker<Hash, AccountId, BlockNumber, Balance>
+where
+    Hash: Encode + Decode,
+{
+    /// Gets the status of each side effect of an execution, indexed by `step`
+    fn sfx_status(execution_id: Hash) -> Result<Vec<Vec<SideEffectStatus>>, Error>;
+
+    /// Gets the side effects of an execution that were confirmed on their targets
+    fn confirmed_outputs(
+        execution_id: Hash,
+    ) -> Result<Vec<ConfirmedOutput<AccountId, BlockNumber, Balance>>, Error>;
+}
+
 /// Macro entrypoint for logging based on feature flags
 #[macro_export]
 macro_rules! log_msg {
@@ -104,6 +131,21 @@ where
     }
 }
 
+impl<Thing> Declarer<Thing> for Executor
+where
+    Thing: Encode + Decode,
+{
+    fn declare(thing: Thing) -> Result<(), Error> {
+        cfg_if::cfg_if! {
+            if #[cfg(feature = "ink")] {
+                <ink::InkProvider as Declarer<Thing>>::declare(thing)
+            } else {
+                Err(Error::NoExecutorSpecified)
+            }
+        }
+    }
+}
+
 impl<Hash> Signaller<Hash> for Executor
 where
     Hash: Encode + Decode + Debug + Clone,
@@ -120,3 +162,34 @@ where
         }
     }
 }
+
+impl<Hash, AccountId, BlockNumber, Balance> Tracker<Hash, AccountId, BlockNumber, Balance>
+    for Executor
+where
+    Hash: Encode + Decode + Debug + Clone,
+    AccountId: Encode + Decode + Debug + Clone,
+    BlockNumber: Encode + Decode + Debug + Clone,
+    Balance: Encode + Decode + Debug + Clone,
+{
+    fn sfx_status(execution_id: Hash) -> Result<Vec<Vec<SideEffectStatus>>, Error> {
+        cfg_if::cfg_if! {
+            if #[cfg(feature = "ink")] {
+                <ink::InkProvider as Tracker<Hash, AccountId, BlockNumber, Balance>>::sfx_status(execution_id)
+            } else {
+                Err(Error::NoExecutorSpecified)
+            }
+        }
+    }
+
+    fn confirmed_outputs(
+        execution_id: Hash,
+    ) -> Result<Vec<ConfirmedOutput<AccountId, BlockNumber, Balance>>, Error> {
+        cfg_if::cfg_if! {
+            if #[cfg(feature = "ink")] {
+                <ink::InkProvider as Tracker<Hash, AccountId, BlockNumber, Balance>>::confirmed_outputs(execution_id)
+            } else {
+                Err(Error::NoExecutorSpecified)
+            }
+        }
+    }
+}
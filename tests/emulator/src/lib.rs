@@ -0,0 +1,154 @@
+//! Emulated network of the t3rn mock runtime and a target chain, bridged by a simulated relayer.
+//!
+//! Each chain runs in its own externalities. The target produces blocks on demand and the relayer
+//! follows them, submitting their headers to the t3rn light client of the target gateway along
+//! with GRANDPA justifications of the test authorities. Executors confirm side effects with the
+//! same storage proofs of the target's events a live relayer would serve, so Xtx lifecycles are
+//! exercised end to end, inclusion verification included.
+
+pub mod relayer;
+pub mod target;
+
+use circuit_mock_runtime::{Clock, ExtBuilder, Portal, RuntimeOrigin, System, ALICE};
+use codec::Encode;
+use frame_support::traits::{GenesisBuild, OnInitialize};
+use pallet_grandpa_finality_verifier::{
+    bridges::test_utils::{authorities, TEST_GRANDPA_SET_ID},
+    types::RelaychainRegistrationData,
+};
+use relayer::Relayer;
+use sp_core::H256;
+use sp_runtime::{
+    traits::{Header as HeaderT, Zero},
+    BuildStorage, DispatchError,
+};
+use sp_state_machine::prove_read;
+use t3rn_primitives::{portal::Portal as PortalT, ChainId};
+
+/// Gateway of the target chain, registered in the default XDNS records as a Rococo relaychain
+pub const TARGET_GATEWAY_ID: ChainId = [0, 0, 0, 0];
+
+pub struct Network {
+    t3rn: sp_io::TestExternalities,
+    target: sp_io::TestExternalities,
+    relayer: Relayer,
+    target_best: target::Header,
+}
+
+impl Network {
+    /// Bootstrap both chains, endowing the target accounts, and register the target's genesis
+    /// header with the t3rn light client.
+    pub fn new(target_balances: Vec<(target::AccountId, target::Balance)>) -> Self {
+        let mut storage = frame_system::GenesisConfig::<target::TargetRuntime>::default()
+            .build_storage()
+            .expect("Frame system builds valid default genesis config");
+        pallet_balances::GenesisConfig::<target::TargetRuntime> {
+            balances: target_balances,
+        }
+        .assimilate_storage(&mut storage)
+        .expect("Pallet balances storage can be assimilated");
+        let target = sp_io::TestExternalities::new(storage);
+
+        let genesis = target::Header::new(
+            Zero::zero(),
+            Default::default(),
+            *target.as_backend().root(),
+            Default::default(),
+            Default::default(),
+        );
+
+        let mut t3rn = ExtBuilder::default()
+            .with_standard_sfx_abi()
+            .with_default_xdns_records()
+            .build();
+        t3rn.execute_with(|| {
+            Portal::initialize(
+                RuntimeOrigin::root(),
+                TARGET_GATEWAY_ID,
+                RelaychainRegistrationData::<circuit_mock_runtime::AccountId> {
+                    first_header: genesis.encode(),
+                    authorities: authorities(),
+                    authority_set_id: TEST_GRANDPA_SET_ID,
+                    owner: ALICE,
+                }
+                .encode(),
+            )
+            .expect("Target genesis header registers with the light client");
+        });
+
+        Network {
+            t3rn,
+            target,
+            relayer: Relayer::new(ALICE),
+            target_best: genesis,
+        }
+    }
+
+    pub fn t3rn<R>(&mut self, execute: impl FnOnce() -> R) -> R {
+        self.t3rn.execute_with(execute)
+    }
+
+    /// Read the target's state as of its best block. Use `produce_target_block` to change it.
+    pub fn target<R>(&mut self, execute: impl FnOnce() -> R) -> R {
+        self.target.execute_with(execute)
+    }
+
+    pub fn target_best(&self) -> &target::Header {
+        &self.target_best
+    }
+
+    pub fn relayer(&self) -> &Relayer {
+        &self.relayer
+    }
+
+    /// Execute on top of the target's best block as a new block, handing its header over to the
+    /// relayer along with the proof of the events emitted within it.
+    pub fn produce_target_block<R>(&mut self, execute: impl FnOnce() -> R) -> (R, H256) {
+        let number = *self.target_best.number() + 1;
+        let parent_hash = self.target_best.hash();
+        let (result, header) = self.target.execute_with(|| {
+            target::System::initialize(&number, &parent_hash, &Default::default());
+            let result = execute();
+            (result, target::System::finalize())
+        });
+
+        let backend = self.target.as_backend();
+        assert_eq!(
+            *header.state_root(),
+            *backend.root(),
+            "Target headers commit to the state the relayer proves events against"
+        );
+        let events_key = frame_support::storage::storage_prefix(b"System", b"Events");
+        let events_proof =
+            prove_read(backend, &[events_key]).expect("Events are provable at the best block");
+
+        let hash = header.hash();
+        self.relayer.observe(header.clone(), events_proof);
+        self.target_best = header;
+        (result, hash)
+    }
+
+    /// Relay the target's headers produced since the last relay to t3rn.
+    pub fn relay(&mut self) -> Result<Option<target::Header>, DispatchError> {
+        let relayer = &mut self.relayer;
+        self.t3rn.execute_with(|| relayer.relay())
+    }
+
+    /// Advance t3rn by the given number of blocks, running the Clock's queues at each of them.
+    pub fn advance_t3rn(&mut self, blocks: u32) {
+        self.t3rn.execute_with(|| {
+            for _ in 0..blocks {
+                let next = System::block_number() + 1;
+                System::set_block_number(next);
+                <Clock as OnInitialize<circuit_mock_runtime::BlockNumber>>::on_initialize(next);
+            }
+        });
+    }
+
+    /// Proof of the event's inclusion at the target block, provided the relayer observed it.
+    pub fn inclusion_proof(&self, block_hash: H256, event: &target::RuntimeEvent) -> Vec<u8> {
+        self.relayer
+            .inclusion_proof(block_hash, event)
+            .expect("Relayer observed every block produced on the target")
+    }
+}
@@ -0,0 +1,92 @@
+//! Relayer of the emulated network, following the target chain's blocks and submitting them to
+//! the t3rn light client finalized by the test GRANDPA authorities.
+
+use crate::target::{Header, RuntimeEvent};
+use circuit_mock_runtime::{AccountId, RococoBridge, Runtime, RuntimeOrigin};
+use codec::Encode;
+use pallet_grandpa_finality_verifier::{
+    bridges::test_utils::make_default_justification, types::RelaychainInclusionProof,
+};
+use sp_core::H256;
+use sp_runtime::{traits::Header as HeaderT, DispatchError};
+use sp_trie::StorageProof;
+
+/// Target block as seen by the relayer - its header and the proof of the events it emitted.
+#[derive(Clone, Debug)]
+pub struct RelayedBlock {
+    pub header: Header,
+    pub events_proof: StorageProof,
+}
+
+/// Relayer of the target gateway, registered as a Rococo relaychain and so bridged by the
+/// `RococoBridge` light client
+pub struct Relayer {
+    account: AccountId,
+    blocks: Vec<RelayedBlock>,
+    /// Index of the first block not submitted to t3rn yet
+    next_to_relay: usize,
+}
+
+impl Relayer {
+    pub fn new(account: AccountId) -> Self {
+        Relayer {
+            account,
+            blocks: vec![],
+            next_to_relay: 0,
+        }
+    }
+
+    pub fn observe(&mut self, header: Header, events_proof: StorageProof) {
+        self.blocks.push(RelayedBlock {
+            header,
+            events_proof,
+        });
+    }
+
+    pub fn block(&self, hash: H256) -> Option<&RelayedBlock> {
+        self.blocks.iter().find(|block| block.header.hash() == hash)
+    }
+
+    pub fn unrelayed(&self) -> &[RelayedBlock] {
+        &self.blocks[self.next_to_relay..]
+    }
+
+    /// Submit the blocks observed since the last relay as a single range, justified by the
+    /// authorities at its last header, through the bridge's `submit_headers` extrinsic as a live
+    /// relayer would. Must run in the t3rn externalities.
+    pub fn relay(&mut self) -> Result<Option<Header>, DispatchError> {
+        let Some((signed, range)) = self.unrelayed().split_last() else {
+            return Ok(None)
+        };
+        let signed_header = signed.header.clone();
+        let range = range
+            .iter()
+            .map(|block| block.header.clone())
+            .collect::<Vec<_>>()
+            .try_into()
+            .map_err(|_| pallet_grandpa_finality_verifier::Error::<Runtime>::RangeToLarge)?;
+        RococoBridge::submit_headers(
+            RuntimeOrigin::signed(self.account.clone()),
+            range,
+            signed_header.clone(),
+            make_default_justification(&signed_header),
+        )
+        .map_err(|e| e.error)?;
+        self.next_to_relay = self.blocks.len();
+        Ok(Some(signed_header))
+    }
+
+    /// Encoded proof of the event's inclusion at the block, as executors submit it confirming
+    /// their side effects.
+    pub fn inclusion_proof(&self, block_hash: H256, event: &RuntimeEvent) -> Option<Vec<u8>> {
+        let block = self.block(block_hash)?;
+        Some(
+            RelaychainInclusionProof::<Header> {
+                encoded_payload: event.encode(),
+                payload_proof: block.events_proof.clone(),
+                block_hash,
+            }
+            .encode(),
+        )
+    }
+}
@@ -0,0 +1,95 @@
+//! Target chain of the emulated network: a bare Substrate runtime finalized by GRANDPA, with
+//! Balances at the pallet index the `[0, 0, 0, 0]` gateway's transfers are registered under in XDNS.
+
+// From construct_runtime macro
+#![allow(clippy::from_over_into)]
+
+use frame_support::{
+    construct_runtime, parameter_types,
+    traits::{ConstU32, Everything},
+};
+use frame_system::mocking::MockUncheckedExtrinsic;
+use sp_core::H256;
+use sp_runtime::{
+    create_runtime_str, generic,
+    traits::{BlakeTwo256, IdentityLookup},
+    AccountId32,
+};
+use sp_version::RuntimeVersion;
+
+pub type AccountId = AccountId32;
+pub type Balance = u128;
+pub type BlockNumber = u32;
+pub type Header = generic::Header<BlockNumber, BlakeTwo256>;
+pub type Block = generic::Block<Header, MockUncheckedExtrinsic<TargetRuntime>>;
+type UncheckedExtrinsic = MockUncheckedExtrinsic<TargetRuntime>;
+
+construct_runtime! {
+    pub enum TargetRuntime where
+        Block = Block,
+        NodeBlock = Block,
+        UncheckedExtrinsic = UncheckedExtrinsic,
+    {
+        System: frame_system = 0,
+        Balances: pallet_balances = 2,
+    }
+}
+
+parameter_types! {
+    pub const BlockHashCount: u32 = 250;
+    // Blocks are finalized with the state version the test externalities are backed by, so that
+    // the state roots of their headers match the storage proofs relayed along with them
+    pub const Version: RuntimeVersion = RuntimeVersion {
+        spec_name: create_runtime_str!("target"),
+        impl_name: create_runtime_str!("target"),
+        authoring_version: 1,
+        spec_version: 1,
+        impl_version: 1,
+        apis: sp_version::create_apis_vec!([]),
+        transaction_version: 1,
+        state_version: 1,
+    };
+    pub const ExistentialDeposit: Balance = 1;
+}
+
+impl frame_system::Config for TargetRuntime {
+    type AccountData = pallet_balances::AccountData<Balance>;
+    type AccountId = AccountId;
+    type BaseCallFilter = Everything;
+    type Block = Block;
+    type BlockHashCount = BlockHashCount;
+    type BlockLength = ();
+    type BlockWeights = ();
+    type DbWeight = ();
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type MaxConsumers = ConstU32<16>;
+    type Nonce = u32;
+    type OnKilledAccount = ();
+    type OnNewAccount = ();
+    type OnSetCode = ();
+    type PalletInfo = PalletInfo;
+    type RuntimeCall = RuntimeCall;
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeOrigin = RuntimeOrigin;
+    type SS58Prefix = ();
+    type SystemWeightInfo = ();
+    type Version = Version;
+}
+
+impl pallet_balances::Config for TargetRuntime {
+    type AccountStore = System;
+    type Balance = Balance;
+    type DustRemoval = ();
+    type ExistentialDeposit = ExistentialDeposit;
+    type FreezeIdentifier = ();
+    type MaxFreezes = ConstU32<0>;
+    type MaxHolds = ConstU32<0>;
+    type MaxLocks = ConstU32<50>;
+    type MaxReserves = ConstU32<50>;
+    type ReserveIdentifier = [u8; 8];
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type WeightInfo = pallet_balances::weights::SubstrateWeight<TargetRuntime>;
+}
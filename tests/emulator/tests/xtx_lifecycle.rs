@@ -0,0 +1,197 @@
+use circuit_mock_runtime::{
+    pallet_circuit::{self, state::CircuitStatus},
+    test_utils::{produce_and_validate_side_effect, ArgVariant},
+    Balances, Circuit, RuntimeEvent, RuntimeOrigin, System, ALICE, BOB_RELAYER,
+};
+use frame_support::{
+    assert_ok,
+    traits::{Currency, ExistenceRequirement},
+};
+use sp_core::H256;
+use sp_runtime::{traits::Header as HeaderT, AccountId32};
+use t3rn_abi::Codec;
+use t3rn_emulator::{target, Network};
+use t3rn_primitives::SpeedMode;
+use t3rn_types::sfx::{ConfirmedSideEffect, SecurityLvl};
+
+// ArgVariant::A orders a transfer of 1 to 0x0909...09
+const BENEFICIARY: AccountId32 = AccountId32::new([9u8; 32]);
+const AMOUNT: u128 = 1;
+const INSURANCE: u128 = 1;
+const MAX_REWARD: u128 = 1;
+const BID_AMOUNT: u128 = 1;
+
+fn xtx_status(network: &mut Network, xtx_id: H256) -> CircuitStatus {
+    network.t3rn(|| Circuit::get_x_exec_signals(xtx_id).unwrap().status)
+}
+
+fn has_event(
+    network: &mut Network,
+    event: pallet_circuit::Event<circuit_mock_runtime::Runtime>,
+) -> bool {
+    network.t3rn(|| {
+        System::events()
+            .iter()
+            .any(|record| record.event == RuntimeEvent::Circuit(event.clone()))
+    })
+}
+
+// Order the transfer on the target and win the bidding as the executor, returning the Xtx ready
+// for execution along with its side effect id
+fn order_and_win_bid(network: &mut Network) -> (H256, H256) {
+    let sfx = produce_and_validate_side_effect(
+        *b"tran",
+        INSURANCE,
+        MAX_REWARD,
+        Codec::Scale,
+        ArgVariant::A,
+    );
+
+    let (xtx_id, sfx_id) = network.t3rn(|| {
+        let _ = Balances::deposit_creating(&ALICE, MAX_REWARD + 2);
+        let _ = Balances::deposit_creating(&BOB_RELAYER, INSURANCE + BID_AMOUNT);
+
+        assert_ok!(Circuit::on_extrinsic_trigger(
+            RuntimeOrigin::signed(ALICE),
+            vec![sfx.clone()],
+            SpeedMode::Finalized,
+            SecurityLvl::Optimistic,
+        ));
+        let (xtx_id, sfx_id) = System::events()
+            .into_iter()
+            .rev()
+            .find_map(|record| match record.event {
+                RuntimeEvent::Circuit(pallet_circuit::Event::NewSideEffectsAvailable(
+                    xtx_id,
+                    _,
+                    _,
+                    sfx_ids,
+                )) => Some((xtx_id, sfx_ids[0])),
+                _ => None,
+            })
+            .expect("Ordered side effects are announced to executors");
+
+        assert_ok!(Circuit::bid_sfx(
+            RuntimeOrigin::signed(BOB_RELAYER),
+            sfx_id,
+            BID_AMOUNT,
+        ));
+        (xtx_id, sfx_id)
+    });
+
+    // Bidding closes after SFXBiddingPeriod
+    network.advance_t3rn(3);
+    assert_eq!(xtx_status(network, xtx_id), CircuitStatus::Ready);
+
+    (xtx_id, sfx_id)
+}
+
+// Execute the ordered transfer on the target as the executor, returning the block it's included at
+fn execute_on_target(network: &mut Network) -> H256 {
+    let (result, block_hash) = network.produce_target_block(|| {
+        <target::Balances as Currency<_>>::transfer(
+            &BOB_RELAYER,
+            &BENEFICIARY,
+            AMOUNT,
+            ExistenceRequirement::AllowDeath,
+        )
+    });
+    assert_ok!(result);
+    block_hash
+}
+
+fn transfer_event() -> target::RuntimeEvent {
+    target::RuntimeEvent::Balances(pallet_balances::Event::Transfer {
+        from: BOB_RELAYER,
+        to: BENEFICIARY,
+        amount: AMOUNT,
+    })
+}
+
+fn confirm(network: &mut Network, sfx_id: H256, inclusion_data: Vec<u8>) -> bool {
+    network.t3rn(|| {
+        Circuit::confirm_side_effect(
+            RuntimeOrigin::signed(BOB_RELAYER),
+            sfx_id,
+            ConfirmedSideEffect {
+                err: None,
+                output: None,
+                inclusion_data,
+                executioner: BOB_RELAYER,
+                received_at: System::block_number(),
+                cost: None,
+            },
+        )
+        .is_ok()
+    })
+}
+
+#[test]
+fn xtx_settles_once_confirmed_with_the_relayed_proof_of_its_execution() {
+    let mut network = Network::new(vec![(BOB_RELAYER, 100)]);
+
+    let (xtx_id, sfx_id) = order_and_win_bid(&mut network);
+
+    let block_hash = execute_on_target(&mut network);
+    let relayed = network
+        .relay()
+        .unwrap()
+        .expect("Target block awaits relaying");
+    assert_eq!(relayed.hash(), block_hash);
+    assert!(network.relayer().unrelayed().is_empty());
+
+    let inclusion_data = network.inclusion_proof(block_hash, &transfer_event());
+    assert!(confirm(&mut network, sfx_id, inclusion_data));
+    assert!(has_event(
+        &mut network,
+        pallet_circuit::Event::SideEffectConfirmed(xtx_id, sfx_id, BOB_RELAYER)
+    ));
+    assert_eq!(
+        xtx_status(&mut network, xtx_id),
+        CircuitStatus::FinishedAllSteps
+    );
+
+    network.advance_t3rn(1);
+    assert!(has_event(
+        &mut network,
+        pallet_circuit::Event::XTransactionXtxCommitted(xtx_id)
+    ));
+
+    network.target(|| {
+        assert_eq!(target::Balances::free_balance(BENEFICIARY), AMOUNT);
+        assert_eq!(target::Balances::free_balance(BOB_RELAYER), 100 - AMOUNT);
+    });
+}
+
+#[test]
+fn confirmation_is_accepted_only_once_its_header_is_relayed() {
+    let mut network = Network::new(vec![(BOB_RELAYER, 100)]);
+
+    let (xtx_id, sfx_id) = order_and_win_bid(&mut network);
+    let block_hash = execute_on_target(&mut network);
+    let inclusion_data = network.inclusion_proof(block_hash, &transfer_event());
+
+    assert!(!confirm(&mut network, sfx_id, inclusion_data.clone()));
+    assert_eq!(xtx_status(&mut network, xtx_id), CircuitStatus::Ready);
+
+    network.relay().unwrap();
+    assert!(confirm(&mut network, sfx_id, inclusion_data));
+    assert_eq!(
+        xtx_status(&mut network, xtx_id),
+        CircuitStatus::FinishedAllSteps
+    );
+}
+
+#[test]
+fn confirmation_is_rejected_for_events_the_target_did_not_emit() {
+    let mut network = Network::new(vec![(BOB_RELAYER, 100)]);
+
+    let (xtx_id, sfx_id) = order_and_win_bid(&mut network);
+    // The executor skips the transfer, emitting nothing at the relayed block
+    let (_, block_hash) = network.produce_target_block(|| ());
+    network.relay().unwrap();
+
+    let inclusion_data = network.inclusion_proof(block_hash, &transfer_event());
+    assert!(!confirm(&mut network, sfx_id, inclusion_data));
+    assert_eq!(xtx_status(&mut network, xtx_id), CircuitStatus::Ready);
+}
@@ -0,0 +1,332 @@
+//! Recodes SCALE-encoded values to RLP and back as laid out by their ABI descriptor, so that types
+//! shared with EVM-side contracts come out byte-identical on both sides.
+//!
+//! Structs, tuples and logs are RLP lists of their fields, vectors lists of their items and options
+//! lists of none or one item. Enums are lists of the variant index followed by its payload. Bytes,
+//! accounts and hashes are RLP strings, while values are RLP integers.
+use crate::{to_abi::Abi, types::Data};
+use codec::{Compact, Decode, Encode};
+use rlp::{Rlp, RlpStream};
+use sp_runtime::DispatchError;
+use sp_std::prelude::*;
+
+/// Types encoded alike with SCALE and RLP, as laid out by their descriptor.
+pub trait DualCodec: Encode + Decode {
+    fn descriptor() -> Data;
+
+    fn to_rlp(&self) -> Result<Data, DispatchError> {
+        scale_to_rlp(Self::descriptor(), &self.encode())
+    }
+
+    fn from_rlp(rlp_encoded: &[u8]) -> Result<Self, DispatchError> {
+        let scale_encoded = rlp_to_scale(Self::descriptor(), rlp_encoded)?;
+        Self::decode(&mut &scale_encoded[..])
+            .map_err(|_| "DualCodec::from_rlp: failed to decode recoded SCALE".into())
+    }
+}
+
+/// Recode the SCALE-encoded value of the descriptor to RLP.
+pub fn scale_to_rlp(abi_descriptor: Data, scale_encoded: &[u8]) -> Result<Data, DispatchError> {
+    let abi: Abi = abi_descriptor.try_into()?;
+    let mut input = scale_encoded;
+    let mut stream = RlpStream::new();
+    append_as_rlp(&abi, &mut input, &mut stream)?;
+    if !input.is_empty() {
+        return Err("DualCodec::scale_to_rlp: trailing bytes".into())
+    }
+    Ok(stream.out().to_vec())
+}
+
+/// Recode the RLP-encoded value of the descriptor to SCALE.
+pub fn rlp_to_scale(abi_descriptor: Data, rlp_encoded: &[u8]) -> Result<Data, DispatchError> {
+    let abi: Abi = abi_descriptor.try_into()?;
+    let rlp = Rlp::new(rlp_encoded);
+    let payload = rlp
+        .payload_info()
+        .map_err(|_| "DualCodec::rlp_to_scale: invalid RLP")?;
+    if payload.header_len + payload.value_len != rlp_encoded.len() {
+        return Err("DualCodec::rlp_to_scale: trailing bytes".into())
+    }
+    let mut output = vec![];
+    append_as_scale(&abi, &rlp, &mut output)?;
+    Ok(output)
+}
+
+fn take<'a>(input: &mut &'a [u8], len: usize) -> Result<&'a [u8], DispatchError> {
+    if input.len() < len {
+        return Err("DualCodec::scale_to_rlp: not enough data".into())
+    }
+    let (taken, rest) = input.split_at(len);
+    *input = rest;
+    Ok(taken)
+}
+
+fn fields_of(abi: &Abi) -> Option<Vec<&Abi>> {
+    match abi {
+        Abi::Struct(_, fields) | Abi::Event(_, fields) | Abi::Log(_, fields) =>
+            Some(fields.iter().map(|field| field.as_ref()).collect()),
+        Abi::Uniple(_, field1) => Some(vec![&**field1]),
+        Abi::Tuple(_, (field1, field2)) => Some(vec![&**field1, &**field2]),
+        Abi::Triple(_, (field1, field2, field3)) => Some(vec![&**field1, &**field2, &**field3]),
+        Abi::Quadruple(_, (field1, field2, field3, field4)) =>
+            Some(vec![&**field1, &**field2, &**field3, &**field4]),
+        Abi::Quintuple(_, (field1, field2, field3, field4, field5)) =>
+            Some(vec![&**field1, &**field2, &**field3, &**field4, &**field5]),
+        Abi::Sextuple(_, (field1, field2, field3, field4, field5, field6)) => Some(vec![
+            &**field1, &**field2, &**field3, &**field4, &**field5, &**field6,
+        ]),
+        _ => None,
+    }
+}
+
+// Size of the fixed-size bytes and values, with values encoded little-endian by SCALE
+fn fixed_size_of(abi: &Abi) -> Option<(usize, bool)> {
+    match abi {
+        Abi::Bytes4(_) => Some((4, false)),
+        Abi::Account20(_) => Some((20, false)),
+        Abi::Account32(_) | Abi::H256(_) => Some((32, false)),
+        Abi::Value256(_) => Some((32, true)),
+        Abi::Value128(_) => Some((16, true)),
+        Abi::Value64(_) => Some((8, true)),
+        Abi::Value32(_) => Some((4, true)),
+        Abi::Byte(_) | Abi::Bool(_) | Abi::Codec(_) => Some((1, true)),
+        _ => None,
+    }
+}
+
+fn append_as_rlp(
+    abi: &Abi,
+    input: &mut &[u8],
+    stream: &mut RlpStream,
+) -> Result<(), DispatchError> {
+    if let Some(fields) = fields_of(abi) {
+        stream.begin_list(fields.len());
+        for field in fields {
+            append_as_rlp(field, input, stream)?;
+        }
+        return Ok(())
+    }
+    if let Some((size, is_value)) = fixed_size_of(abi) {
+        let bytes = take(input, size)?;
+        if is_value {
+            // RLP integers are big-endian, without leading zeroes
+            let big_endian: Vec<u8> = bytes
+                .iter()
+                .rev()
+                .skip_while(|byte| **byte == 0)
+                .copied()
+                .collect();
+            stream.append(&big_endian);
+        } else {
+            stream.append(&bytes.to_vec());
+        }
+        return Ok(())
+    }
+    match abi {
+        Abi::Bytes(_) => {
+            let bytes =
+                Vec::<u8>::decode(input).map_err(|_| "DualCodec::scale_to_rlp: invalid bytes")?;
+            stream.append(&bytes);
+        },
+        Abi::Option(_, field) => match take(input, 1)?[0] {
+            0 => {
+                stream.begin_list(0);
+            },
+            1 => {
+                stream.begin_list(1);
+                append_as_rlp(field, input, stream)?;
+            },
+            _ => return Err("DualCodec::scale_to_rlp: invalid option".into()),
+        },
+        Abi::Vec(_, field) => {
+            let len = <Compact<u32>>::decode(input)
+                .map_err(|_| "DualCodec::scale_to_rlp: invalid vec length")?
+                .0;
+            stream.begin_list(len as usize);
+            for _ in 0..len {
+                append_as_rlp(field, input, stream)?;
+            }
+        },
+        Abi::Enum(_, variants) => {
+            let index = take(input, 1)?[0];
+            let variant = variants
+                .get(index as usize)
+                .ok_or("DualCodec::scale_to_rlp: invalid enum variant")?;
+            stream.begin_list(2);
+            stream.append(&index);
+            append_as_rlp(variant, input, stream)?;
+        },
+        _ => return Err("DualCodec::scale_to_rlp: unsupported descriptor".into()),
+    }
+    Ok(())
+}
+
+fn append_as_scale(abi: &Abi, rlp: &Rlp, output: &mut Vec<u8>) -> Result<(), DispatchError> {
+    let item_at = |index: usize| {
+        rlp.at(index)
+            .map_err(|_| "DualCodec::rlp_to_scale: missing item")
+    };
+    let list_of = |expected: Option<usize>| -> Result<usize, DispatchError> {
+        if !rlp.is_list() {
+            return Err("DualCodec::rlp_to_scale: expected a list".into())
+        }
+        let count = rlp
+            .item_count()
+            .map_err(|_| "DualCodec::rlp_to_scale: expected a list")?;
+        match expected {
+            Some(expected) if expected != count =>
+                Err("DualCodec::rlp_to_scale: unexpected number of items".into()),
+            _ => Ok(count),
+        }
+    };
+    let data = || {
+        rlp.data()
+            .map_err(|_| "DualCodec::rlp_to_scale: expected a string")
+    };
+
+    if let Some(fields) = fields_of(abi) {
+        list_of(Some(fields.len()))?;
+        for (index, field) in fields.into_iter().enumerate() {
+            append_as_scale(field, &item_at(index)?, output)?;
+        }
+        return Ok(())
+    }
+    if let Some((size, is_value)) = fixed_size_of(abi) {
+        let bytes = data()?;
+        if is_value {
+            if bytes.len() > size || bytes.first() == Some(&0) {
+                return Err("DualCodec::rlp_to_scale: invalid integer".into())
+            }
+            output.extend(bytes.iter().rev());
+            output.extend(sp_std::iter::repeat(0u8).take(size - bytes.len()));
+        } else {
+            if bytes.len() != size {
+                return Err("DualCodec::rlp_to_scale: unexpected size".into())
+            }
+            output.extend_from_slice(bytes);
+        }
+        return Ok(())
+    }
+    match abi {
+        Abi::Bytes(_) => data()?.encode_to(output),
+        Abi::Option(_, field) => match list_of(None)? {
+            0 => output.push(0),
+            1 => {
+                output.push(1);
+                append_as_scale(field, &item_at(0)?, output)?;
+            },
+            _ => return Err("DualCodec::rlp_to_scale: invalid option".into()),
+        },
+        Abi::Vec(_, field) => {
+            let len = list_of(None)?;
+            Compact(len as u32).encode_to(output);
+            for index in 0..len {
+                append_as_scale(field, &item_at(index)?, output)?;
+            }
+        },
+        Abi::Enum(_, variants) => {
+            list_of(Some(2))?;
+            let index: u8 = item_at(0)?
+                .as_val()
+                .map_err(|_| "DualCodec::rlp_to_scale: invalid enum variant")?;
+            let variant = variants
+                .get(index as usize)
+                .ok_or("DualCodec::rlp_to_scale: invalid enum variant")?;
+            output.push(index);
+            append_as_scale(variant, &item_at(1)?, output)?;
+        },
+        _ => return Err("DualCodec::rlp_to_scale: unsupported descriptor".into()),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hex_literal::hex;
+
+    const DESCRIPTOR: &[u8] = b"Order:Struct(target:Bytes4,amount:Value128,args:Vec<Bytes>,\
+        beneficiary:Option<Account20>,outcome:Enum(Success:Struct(),Failed:Struct(reason:Bytes)),\
+        nonce:Value32)";
+
+    #[test]
+    fn recodes_scale_to_rlp_and_back() {
+        let scale_encoded = (
+            *b"eth2",
+            1_000u128,
+            vec![vec![1u8, 2], vec![]],
+            Some([9u8; 20]),
+            1u8,
+            b"oops".to_vec(),
+            0u32,
+        )
+            .encode();
+
+        let rlp_encoded = scale_to_rlp(DESCRIPTOR.to_vec(), &scale_encoded).unwrap();
+
+        let mut expected = RlpStream::new_list(6);
+        expected.append(&b"eth2".to_vec());
+        expected.append(&1_000u128);
+        expected
+            .begin_list(2)
+            .append(&vec![1u8, 2])
+            .append(&Vec::<u8>::new());
+        expected.begin_list(1).append(&[9u8; 20].to_vec());
+        expected
+            .begin_list(2)
+            .append(&1u8)
+            .begin_list(1)
+            .append(&b"oops".to_vec());
+        expected.append(&0u32);
+        assert_eq!(rlp_encoded, expected.out().to_vec());
+
+        assert_eq!(
+            rlp_to_scale(DESCRIPTOR.to_vec(), &rlp_encoded),
+            Ok(scale_encoded)
+        );
+    }
+
+    #[test]
+    fn recodes_none_and_unit_variants_as_empty_lists() {
+        let scale_encoded = (
+            *b"eth2",
+            0u128,
+            Vec::<Vec<u8>>::new(),
+            None::<[u8; 20]>,
+            0u8,
+            7u32,
+        )
+            .encode();
+
+        let rlp_encoded = scale_to_rlp(DESCRIPTOR.to_vec(), &scale_encoded).unwrap();
+
+        assert_eq!(rlp_encoded, hex!("cc846574683280c0c0c280c007").to_vec());
+        assert_eq!(
+            rlp_to_scale(DESCRIPTOR.to_vec(), &rlp_encoded),
+            Ok(scale_encoded)
+        );
+    }
+
+    #[test]
+    fn rejects_trailing_and_missing_data() {
+        let mut scale_encoded = (
+            *b"eth2",
+            0u128,
+            Vec::<Vec<u8>>::new(),
+            None::<[u8; 20]>,
+            0u8,
+            7u32,
+        )
+            .encode();
+        let rlp_encoded = scale_to_rlp(DESCRIPTOR.to_vec(), &scale_encoded).unwrap();
+
+        scale_encoded.push(0);
+        assert!(scale_to_rlp(DESCRIPTOR.to_vec(), &scale_encoded).is_err());
+        scale_encoded.truncate(scale_encoded.len() - 2);
+        assert!(scale_to_rlp(DESCRIPTOR.to_vec(), &scale_encoded).is_err());
+
+        let mut with_trailing = rlp_encoded;
+        with_trailing.push(0);
+        assert!(rlp_to_scale(DESCRIPTOR.to_vec(), &with_trailing).is_err());
+    }
+}
@@ -143,6 +143,40 @@ impl Decode for RemoteEVMOrderLog {
     }
 }
 
+// event BridgeLocked(bytes32 indexed id, address indexed asset, bytes32 indexed beneficiary, uint256 amount);
+pub fn get_remote_bridge_lock_abi_descriptor() -> Vec<u8> {
+    b"BridgeLocked:Log(lockId+:H256,asset+:Account20,beneficiary+:Account32,amount:Value256)"
+        .to_vec()
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Encode, TypeInfo)]
+pub struct RemoteEVMBridgeLockLog {
+    pub lock_id: H256,
+    pub asset: H160,
+    pub beneficiary: AccountId32,
+    pub amount: U256,
+}
+
+// Implement custom Scale decoding for RemoteEVMBridgeLockLog
+impl Decode for RemoteEVMBridgeLockLog {
+    fn decode<I: Input>(input: &mut I) -> Result<Self, codec::Error> {
+        // Skip first byte
+        input.read_byte()?;
+
+        let lock_id = H256::decode(input)?;
+        let asset = H160::decode(input)?;
+        let beneficiary = AccountId32::decode(input)?;
+        let amount = U256::decode(input)?;
+
+        Ok(RemoteEVMBridgeLockLog {
+            lock_id,
+            asset,
+            beneficiary,
+            amount,
+        })
+    }
+}
+
 pub fn get_instant_order_commit_abi_descriptor() -> Vec<u8> {
     b"OrderCommitted:Log(sfxId+:H256,to+:Account20,amount:Value256,empty32Bytes:H256,signatureLength:Value32,signaturePart1:H256,signaturePart2:H256,signaturePart3:H256)".to_vec()
 }
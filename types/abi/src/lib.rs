@@ -1,4 +1,5 @@
 #![cfg_attr(not(feature = "std"), no_std)]
+pub mod dual_codec;
 pub mod evm_ingress_logs;
 #[cfg(test)]
 pub mod mini_mock;
@@ -12,6 +13,7 @@ pub mod to_filled_abi;
 pub mod types;
 
 // Export the public API
+pub use dual_codec::DualCodec;
 pub use recode::{Codec, Recode};
 pub use recode_rlp::RecodeRlp;
 pub use recode_scale::RecodeScale;
@@ -94,6 +94,26 @@ impl SFXAbi {
         FilledAbi::try_fill_abi(abi, ordered_args_flatten, ordered_args_codec.clone())
     }
 
+    /// Read the field `name` off the received payload, recoded as the ordered arguments are.
+    pub fn extract_from_received(
+        &self,
+        name: &Name,
+        received_payload: Data,
+        ordered_args_codec: &Codec,
+        payload_codec: &Codec,
+    ) -> Result<Data, DispatchError> {
+        let abi: Abi = self
+            .get_expected_ingress_descriptor(payload_codec.clone())
+            .try_into()?;
+
+        FilledAbi::try_fill_abi(abi, received_payload, payload_codec.clone())?
+            .get_by_name(name)
+            .ok_or(DispatchError::Other(
+                "SFXAbi::Cannot find payload argument by name",
+            ))?
+            .recode_as(payload_codec, ordered_args_codec, true)
+    }
+
     pub fn validate_arguments_against_received(
         &self,
         ordered_args: &Vec<Data>,
@@ -5,18 +5,92 @@ use crate::{
 use sp_std::prelude::*;
 
 pub fn standard_sfx_abi() -> Vec<(Sfx4bId, SFXAbi)> {
-    vec![
-        (*b"data", get_data_abi()),
-        (*b"tddd", get_dynamic_destination_deal_abi()),
-        (*b"tran", get_sfx_transfer_abi()),
-        (*b"tass", get_sfx_transfer_asset_abi()),
-        (*b"swap", get_swap_abi()),
-        (*b"aliq", get_add_liquidity_abi()),
-        (*b"rliq", get_remove_liquidity_abi()),
-        (*b"cevm", get_call_evm_contract_abi()),
-        (*b"wasm", get_call_wasm_contract_abi()),
-        (*b"cgen", get_call_generic_abi()),
-    ]
+    StandardSfxAbis::all().build()
+}
+
+/// Declares which of the standard side effect ABIs to seed the on-chain registry with, at genesis
+/// or in a runtime upgrade, by the families of side effects a network supports.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct StandardSfxAbis {
+    abis: Vec<(Sfx4bId, SFXAbi)>,
+}
+
+impl StandardSfxAbis {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// All of the standard side effect ABIs
+    pub fn all() -> Self {
+        Self::new()
+            .with_data()
+            .with_transfers()
+            .with_swaps()
+            .with_liquidity()
+            .with_calls()
+            .with_messages()
+    }
+
+    /// Plain data, i.e. `data`
+    pub fn with_data(self) -> Self {
+        self.with(*b"data", get_data_abi())
+    }
+
+    /// Transfers of the native and foreign assets, along with their dynamic destination deals,
+    /// i.e. `tddd`, `tran` and `tass`
+    pub fn with_transfers(self) -> Self {
+        self.with(*b"tddd", get_dynamic_destination_deal_abi())
+            .with(*b"tran", get_sfx_transfer_abi())
+            .with(*b"tass", get_sfx_transfer_asset_abi())
+    }
+
+    /// Swaps, i.e. `swap`
+    pub fn with_swaps(self) -> Self {
+        self.with(*b"swap", get_swap_abi())
+    }
+
+    /// Adding and removing liquidity, i.e. `aliq` and `rliq`
+    pub fn with_liquidity(self) -> Self {
+        self.with(*b"aliq", get_add_liquidity_abi())
+            .with(*b"rliq", get_remove_liquidity_abi())
+    }
+
+    /// Contract calls and deployments, i.e. `cevm`, `wasm`, `cgen` and `depl`
+    pub fn with_calls(self) -> Self {
+        self.with(*b"cevm", get_call_evm_contract_abi())
+            .with(*b"wasm", get_call_wasm_contract_abi())
+            .with(*b"cgen", get_call_generic_abi())
+            .with(*b"depl", get_deploy_contract_abi())
+    }
+
+    /// Messages and reads of the target's state, i.e. `mesg` and `read`
+    pub fn with_messages(self) -> Self {
+        self.with(*b"mesg", get_message_abi())
+            .with(*b"read", get_read_abi())
+    }
+
+    /// Adds or replaces the ABI of a side effect
+    pub fn with(mut self, sfx_4b_id: Sfx4bId, sfx_abi: SFXAbi) -> Self {
+        match self
+            .abis
+            .iter_mut()
+            .find(|(known_sfx_4b_id, _)| known_sfx_4b_id == &sfx_4b_id)
+        {
+            Some((_, known_sfx_abi)) => *known_sfx_abi = sfx_abi,
+            None => self.abis.push((sfx_4b_id, sfx_abi)),
+        }
+        self
+    }
+
+    pub fn without(mut self, sfx_4b_id: Sfx4bId) -> Self {
+        self.abis
+            .retain(|(known_sfx_4b_id, _)| known_sfx_4b_id != &sfx_4b_id);
+        self
+    }
+
+    pub fn build(self) -> Vec<(Sfx4bId, SFXAbi)> {
+        self.abis
+    }
 }
 
 impl SFXAbi {
@@ -33,7 +107,7 @@ impl SFXAbi {
 pub fn standard_sfx_abi_ids() -> Vec<Sfx4bId> {
     vec![
         *b"data", *b"tran", *b"tass", *b"orml", *b"swap", *b"aliq", *b"cevm", *b"wasm", *b"comp",
-        *b"tddd",
+        *b"tddd", *b"depl", *b"mesg", *b"read",
     ]
 }
 
@@ -238,6 +312,62 @@ pub fn get_call_wasm_contract_abi() -> SFXAbi {
     }
 }
 
+pub fn get_deploy_contract_abi() -> SFXAbi {
+    SFXAbi {
+        args_names: vec![
+            (b"code_hash".to_vec(), true),
+            (b"code".to_vec(), false),
+            (b"input".to_vec(), false),
+        ],
+        ingress_abi_descriptors: PerCodecAbiDescriptors {
+            // code_hash is the keccak256 of the deployed artifact, as requested
+            for_rlp: b"Deployed:Log(code_hash+:H256,contract+:Account20,deployer+:Account20)"
+                .to_vec(),
+            for_scale: b"Contracts:Struct(Deployed:Event(code_hash:H256,contract:Account32,deployer:Account32))"
+                .to_vec(),
+        },
+        egress_abi_descriptors: PerCodecAbiDescriptors {
+            for_rlp: b"Deploy:Struct(code_hash:H256,code:Bytes,input:Bytes)".to_vec(),
+            for_scale: b"Deploy:Struct(code_hash:H256,code:Bytes,input:Bytes)".to_vec(),
+        },
+        maybe_prefix_memo: None,
+    }
+}
+
+pub fn get_message_abi() -> SFXAbi {
+    SFXAbi {
+        args_names: vec![(b"target".to_vec(), true), (b"payload".to_vec(), true)],
+        ingress_abi_descriptors: PerCodecAbiDescriptors {
+            // Receipt emitted by the target contract once it accepted the payload
+            for_rlp: b"MessageDelivered:Log(target+:Account20,payload-:Bytes)".to_vec(),
+            for_scale: b"Messages:Struct(MessageDelivered:Event(target:Account32,payload:Bytes))"
+                .to_vec(),
+        },
+        egress_abi_descriptors: PerCodecAbiDescriptors {
+            for_rlp: b"Message:Struct(target:Account20,payload:Bytes)".to_vec(),
+            for_scale: b"Message:Struct(target:Account32,payload:Bytes)".to_vec(),
+        },
+        maybe_prefix_memo: None,
+    }
+}
+
+pub fn get_read_abi() -> SFXAbi {
+    SFXAbi {
+        args_names: vec![(b"source".to_vec(), true), (b"key".to_vec(), true)],
+        ingress_abi_descriptors: PerCodecAbiDescriptors {
+            // Result emitted by the `source` contract or pallet reading off its storage slot or view call `key`
+            for_rlp: b"ReadResult:Log(source+:Account20,key+:H256,value-:Bytes)".to_vec(),
+            for_scale: b"Reads:Struct(ReadResult:Event(source:Account32,key:H256,value:Bytes))"
+                .to_vec(),
+        },
+        egress_abi_descriptors: PerCodecAbiDescriptors {
+            for_rlp: b"Read:Struct(source:Account20,key:H256)".to_vec(),
+            for_scale: b"Read:Struct(source:Account32,key:H256)".to_vec(),
+        },
+        maybe_prefix_memo: None,
+    }
+}
+
 pub fn get_call_generic_abi() -> SFXAbi {
     SFXAbi {
         args_names: vec![
@@ -277,6 +407,26 @@ mod test_abi_standards {
     use sp_core::H256;
     use sp_runtime::AccountId32;
 
+    #[test]
+    fn test_standard_sfx_abis_declare_each_side_effect_once() {
+        let all = StandardSfxAbis::all().build();
+        assert_eq!(all.len(), 13);
+        assert_eq!(
+            StandardSfxAbis::all().with_transfers().build(),
+            all,
+            "Redeclared side effects replace their ABI in place of adding another"
+        );
+
+        let transfers_only = StandardSfxAbis::new()
+            .with_transfers()
+            .without(*b"tddd")
+            .build()
+            .into_iter()
+            .map(|(sfx_4b_id, _)| sfx_4b_id)
+            .collect::<Vec<_>>();
+        assert_eq!(transfers_only, vec![*b"tran", *b"tass"]);
+    }
+
     #[test]
     fn test_transfer_validate_arguments_against_received_substrate_balances_event() {
         let transfer_interface = get_sfx_transfer_abi();
@@ -602,4 +752,104 @@ mod test_abi_standards {
 
         assert!(res.is_ok());
     }
+
+    #[test]
+    fn test_message_validate_arguments_against_received_evm_delivery_receipt() {
+        let message_interface = get_message_abi();
+        let payload = hex!("00000000000000000000000000000000000000000000000000000000deadbeef");
+
+        let ordered_args = vec![
+            // target
+            AccountId32::from(hex!(
+                "0000000000000000000000000000000000000000000000000000000000054321"
+            ))
+            .encode(),
+            // payload
+            payload.to_vec(),
+        ];
+
+        let delivery_receipt = |delivered: [u8; 32]| Eth2IngressEventLog {
+            address: H160::from_slice(&hex!("0909090909090909090909090909090909090909")),
+            topics: vec![
+                hex!("5a7e1f5ab5f0e2e8d4b5bd3a4e0b0c4b3f7b6f7c4b3e2d1c0b9a8f7e6d5c4b3a").into(),
+                // address of the smart contract -- target
+                hex!("0000000000000000000000000000000000000000000000000000000000054321").into(),
+            ],
+            data: delivered.to_vec(),
+        };
+
+        let res = message_interface.validate_arguments_against_received(
+            &ordered_args,
+            delivery_receipt(payload).encode(),
+            &Codec::Scale,
+            &Codec::Rlp,
+        );
+        assert!(res.is_ok());
+
+        // A receipt for any other payload doesn't confirm the message
+        let res = message_interface.validate_arguments_against_received(
+            &ordered_args,
+            delivery_receipt([7u8; 32]).encode(),
+            &Codec::Scale,
+            &Codec::Rlp,
+        );
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn test_read_extracts_value_off_evm_read_result_matching_requested_key() {
+        let read_interface = get_read_abi();
+        let key = hex!("0000000000000000000000000000000000000000000000000000000000000007");
+        let value = hex!("00000000000000000000000000000000000000000000000000000000deadbeef");
+
+        let ordered_args = vec![
+            // source
+            AccountId32::from(hex!(
+                "0000000000000000000000000000000000000000000000000000000000054321"
+            ))
+            .encode(),
+            // key
+            key.to_vec(),
+        ];
+
+        let read_result = |read_key: [u8; 32]| Eth2IngressEventLog {
+            // emitted by the source contract itself
+            address: H160::from_slice(&hex!("0000000000000000000000000000000000054321")),
+            topics: vec![
+                hex!("8b6e8d3b1d8a5f0e2c6d1f0a9b7c4e3d2a1f0e9d8c7b6a5f4e3d2c1b0a9f8e7d").into(),
+                // address of the smart contract -- source
+                hex!("0000000000000000000000000000000000000000000000000000000000054321").into(),
+                read_key.into(),
+            ],
+            data: value.to_vec(),
+        };
+
+        assert!(read_interface
+            .validate_arguments_against_received(
+                &ordered_args,
+                read_result(key).encode(),
+                &Codec::Scale,
+                &Codec::Rlp,
+            )
+            .is_ok());
+        assert_eq!(
+            read_interface.extract_from_received(
+                &b"value".to_vec(),
+                read_result(key).encode(),
+                &Codec::Scale,
+                &Codec::Rlp,
+            ),
+            Ok(value.to_vec())
+        );
+
+        // A result read off any other key doesn't confirm the read
+        assert!(read_interface
+            .validate_arguments_against_received(
+                &ordered_args,
+                read_result([9u8; 32]).encode(),
+                &Codec::Scale,
+                &Codec::Rlp,
+            )
+            .is_err());
+    }
 }
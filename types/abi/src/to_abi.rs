@@ -178,8 +178,9 @@ impl TryFrom<Data> for Abi {
                 },
                 "Struct" | "Enum" | "Event" | "Log" => {
                     let mut fields = Vec::new();
+                    // Fields are one level below, the levels above being the siblings that follow
                     while let Some((_next_field_str, _maybe_next_name, lvl)) = fields_iter.peek() {
-                        if lvl > &(current_depth + 1) {
+                        if lvl != &(current_depth + 1) {
                             break
                         }
                         fields.push(Box::new(from_parsed_descriptor_recursive(
@@ -1,6 +1,7 @@
 use codec::{Decode, Encode};
-use scale_info::TypeInfo;
+use scale_info::{prelude::vec::Vec, TypeInfo};
 use sp_runtime::RuntimeDebug;
+use t3rn_abi::DualCodec;
 
 /// All Executors from the active set can bid for SFX executions in order to claim the rewards (max_fee) set by users,
 ///     ultimately competing against one another on the open market rules.
@@ -65,3 +66,14 @@ impl<AccountId: Encode, BalanceOf, AssetId> SFXBid<AccountId, BalanceOf, AssetId
         Hasher::hash(sfx_id_and_index.as_ref())
     }
 }
+
+/// Laid out as encoded by the standard runtime types - 32b accounts, u128 balances and u32 assets
+impl<AccountId: Encode + Decode, BalanceOf: Encode + Decode, AssetId: Encode + Decode> DualCodec
+    for SFXBid<AccountId, BalanceOf, AssetId>
+{
+    fn descriptor() -> Vec<u8> {
+        b"SFXBid:Struct(amount:Value128,insurance:Value128,reserved_bond:Option<Value128>,\
+            reward_asset_id:Option<Value32>,executor:Account32,requester:Account32)"
+            .to_vec()
+    }
+}
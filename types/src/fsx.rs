@@ -3,7 +3,7 @@ use codec::{Decode, Encode};
 use num_traits::Zero;
 use scale_info::TypeInfo;
 use sp_runtime::RuntimeDebug;
-use sp_std::{convert::TryInto, vec};
+use sp_std::{convert::TryInto, vec, vec::Vec};
 
 pub use crate::{
     bid::SFXBid,
@@ -14,6 +14,7 @@ pub use crate::{
         DATA_SIDE_EFFECT_ID, EVM_CALL_SIDE_EFFECT_ID, ORML_TRANSFER_SIDE_EFFECT_ID,
         SWAP_SIDE_EFFECT_ID, TRANSFER_SIDE_EFFECT_ID, WASM_CALL_SIDE_EFFECT_ID,
     },
+    types::Bytes,
 };
 
 pub type SideEffectId<T> = <T as frame_system::Config>::Hash;
@@ -26,6 +27,12 @@ pub struct FullSideEffect<AccountId, BlockNumber, BalanceOf> {
     pub submission_target_height: BlockNumber,
     pub best_bid: Option<SFXBid<AccountId, BalanceOf, u32>>,
     pub index: u32,
+    /// Block by which the SFX must be confirmed, if sooner than the timeout of its Xtx
+    pub deadline: Option<BlockNumber>,
+    /// Indices of the SFX of the same Xtx this one waits on, besides those of preceding steps
+    pub dependencies: Vec<u32>,
+    /// Requester's metadata of the SFX, carried along but never interpreted on-chain
+    pub metadata: Bytes,
 }
 
 impl<AccountId, BlockNumber, BalanceOf> FullSideEffect<AccountId, BlockNumber, BalanceOf>
@@ -104,6 +111,7 @@ where
         let confirmed_executioner = self.clone().confirmed.map(|c| c.executioner);
         let confirmed_received_at = self.clone().confirmed.map(|c| c.received_at);
         let confirmed_cost = self.clone().confirmed.and_then(|c| c.cost);
+        let confirmed_output = self.clone().confirmed.and_then(|c| c.output);
         Ok(HardenedSideEffect::<AccountId, BlockNumber, BalanceOf> {
             target: self.input.target,
             prize: self.input.max_reward,
@@ -115,6 +123,7 @@ where
             confirmed_executioner,
             confirmed_received_at,
             confirmed_cost,
+            confirmed_output,
             index: self.index,
         })
     }
@@ -200,6 +209,9 @@ mod tests {
             }),
             best_bid: None,
             index: 0,
+            deadline: None,
+            dependencies: vec![],
+            metadata: vec![],
         };
 
         let hsfx: HardenedSideEffect<AccountId, BlockNumber, BalanceOf> = tfsfx.try_into().unwrap();
@@ -233,6 +245,7 @@ mod tests {
                 ))),
                 confirmed_received_at: Some(1),
                 confirmed_cost: Some(2),
+                confirmed_output: None,
                 index: 0,
             },
         );
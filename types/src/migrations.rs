@@ -76,6 +76,53 @@ pub mod v13 {
                 .unwrap_or_default(),
                 best_bid: old.best_bid,
                 index: old.index,
+                deadline: None,
+                dependencies: Vec::new(),
+                metadata: Vec::new(),
+            }
+        }
+    }
+}
+
+// Storage Migration: FSX gains the "deadline", "dependencies" and "metadata" fields
+// Storage Migration Details: 14-10-2026
+pub mod v14 {
+    use crate::fsx::FullSideEffect;
+    pub use crate::{
+        bid::SFXBid,
+        sfx::{ConfirmedSideEffect, SecurityLvl, SideEffect},
+    };
+    use codec::{Decode, Encode};
+    use scale_info::TypeInfo;
+    use sp_runtime::RuntimeDebug;
+    use sp_std::prelude::*;
+
+    #[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+    pub struct FullSideEffectV14<AccountId, BlockNumber, BalanceOf> {
+        pub input: SideEffect<AccountId, BalanceOf>,
+        pub confirmed: Option<ConfirmedSideEffect<AccountId, BlockNumber, BalanceOf>>,
+        pub security_lvl: SecurityLvl,
+        pub submission_target_height: BlockNumber,
+        pub best_bid: Option<SFXBid<AccountId, BalanceOf, u32>>,
+        pub index: u32,
+    }
+
+    impl<AccountId, BlockNumber, BalanceOf>
+        From<FullSideEffectV14<AccountId, BlockNumber, BalanceOf>>
+        for FullSideEffect<AccountId, BlockNumber, BalanceOf>
+    {
+        fn from(old: FullSideEffectV14<AccountId, BlockNumber, BalanceOf>) -> Self {
+            FullSideEffect {
+                input: old.input,
+                confirmed: old.confirmed,
+                security_lvl: old.security_lvl,
+                submission_target_height: old.submission_target_height,
+                best_bid: old.best_bid,
+                index: old.index,
+                // SFX requested before had neither deadlines nor dependencies other than their steps
+                deadline: None,
+                dependencies: Vec::new(),
+                metadata: Vec::new(),
             }
         }
     }
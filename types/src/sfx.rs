@@ -18,7 +18,7 @@ use serde::{Deserialize, Serialize};
 use sp_core::Hasher;
 use sp_runtime::DispatchError;
 
-use t3rn_abi::{Codec, SFXAbi};
+use t3rn_abi::{Codec, DualCodec, SFXAbi};
 
 pub type TargetId = [u8; 4];
 pub type TokenId4b = [u8; 4];
@@ -32,6 +32,9 @@ pub type SideEffectName = Bytes;
 pub const COMPOSABLE_CALL_SIDE_EFFECT_ID: &[u8; 4] = b"comp";
 pub const WASM_CALL_SIDE_EFFECT_ID: &[u8; 4] = b"wasm";
 pub const EVM_CALL_SIDE_EFFECT_ID: &[u8; 4] = b"cevm";
+pub const DEPLOY_SIDE_EFFECT_ID: &[u8; 4] = b"depl";
+pub const MESSAGE_SIDE_EFFECT_ID: &[u8; 4] = b"mesg";
+pub const READ_SIDE_EFFECT_ID: &[u8; 4] = b"read";
 pub const CALL_SIDE_EFFECT_ID: &[u8; 4] = b"call";
 pub const ORML_TRANSFER_SIDE_EFFECT_ID: &[u8; 4] = b"orml";
 pub const ASSETS_TRANSFER_SIDE_EFFECT_ID: &[u8; 4] = b"tass";
@@ -54,6 +57,18 @@ pub struct SideEffect<AccountId, BalanceOf> {
     pub reward_asset_id: Option<u32>,
 }
 
+/// Laid out as encoded by the standard runtime types - 32b accounts and u128 balances
+impl<AccountId: Encode + Decode, BalanceOf: Encode + Decode> DualCodec
+    for SideEffect<AccountId, BalanceOf>
+{
+    fn descriptor() -> Data {
+        b"SideEffect:Struct(target:Bytes4,max_reward:Value128,insurance:Value128,action:Bytes4,\
+            encoded_args:Vec<Bytes>,signature:Bytes,enforce_executor:Option<Account32>,\
+            reward_asset_id:Option<Value32>)"
+            .to_vec()
+    }
+}
+
 #[derive(Clone, Eq, PartialEq, Encode, Decode, Debug, TypeInfo)]
 pub struct HardenedSideEffect<AccountId, BlockNumber, BalanceOf> {
     pub target: TargetId,
@@ -66,6 +81,7 @@ pub struct HardenedSideEffect<AccountId, BlockNumber, BalanceOf> {
     pub confirmed_executioner: Option<AccountId>,
     pub confirmed_received_at: Option<BlockNumber>,
     pub confirmed_cost: Option<BalanceOf>,
+    pub confirmed_output: Option<Bytes>,
     pub index: u32,
 }
 
@@ -88,6 +104,7 @@ where
             confirmed_executioner: None,
             confirmed_received_at: None,
             confirmed_cost: None,
+            confirmed_output: None,
             index: 0,
         }
     }
@@ -405,6 +422,20 @@ pub struct ConfirmedSideEffect<AccountId, BlockNumber, BalanceOf> {
     pub cost: Option<BalanceOf>,
 }
 
+/// Laid out as encoded by the standard runtime types - 32b accounts, u32 block numbers and u128
+/// balances
+impl<AccountId: Encode + Decode, BlockNumber: Encode + Decode, BalanceOf: Encode + Decode> DualCodec
+    for ConfirmedSideEffect<AccountId, BlockNumber, BalanceOf>
+{
+    fn descriptor() -> Data {
+        b"ConfirmedSideEffect:Struct(err:Option<ConfirmationOutcome:Enum(Success:Struct(),\
+            MisbehaviourMalformedValues:Struct(key:Bytes,expected:Bytes,received:Bytes),\
+            TimedOut:Struct())>,output:Option<Bytes>,inclusion_data:Bytes,executioner:Account32,\
+            received_at:Value32,cost:Option<Value128>)"
+            .to_vec()
+    }
+}
+
 #[derive(Clone, Eq, PartialEq, PartialOrd, Ord, Encode, Decode, Debug, TypeInfo, Default)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum SecurityLvl {
@@ -510,6 +541,51 @@ mod tests {
         );
     }
 
+    #[test]
+    fn side_effect_bid_and_confirmation_roundtrip_through_rlp() {
+        let side_effect = SideEffect::<AccountId, BalanceOf> {
+            target: *b"eth2",
+            max_reward: 3,
+            insurance: 2,
+            action: *b"tran",
+            encoded_args: vec![AccountId32::new([2u8; 32]).encode(), 1u128.encode()],
+            signature: vec![],
+            enforce_executor: Some(AccountId32::new([1u8; 32])),
+            reward_asset_id: Some(1u32),
+        };
+        let rlp_encoded = side_effect.to_rlp().unwrap();
+        assert_eq!(SideEffect::from_rlp(&rlp_encoded), Ok(side_effect));
+
+        let bid = SFXBid::<AccountId, BalanceOf, u32> {
+            amount: 1,
+            insurance: 2,
+            reserved_bond: None,
+            reward_asset_id: Some(1u32),
+            executor: AccountId32::new([1u8; 32]),
+            requester: AccountId32::new([2u8; 32]),
+        };
+        let rlp_encoded = bid.to_rlp().unwrap();
+        assert_eq!(SFXBid::from_rlp(&rlp_encoded), Ok(bid));
+
+        let confirmation = ConfirmedSideEffect::<AccountId, u32, BalanceOf> {
+            err: Some(ConfirmationOutcome::MisbehaviourMalformedValues {
+                key: b"amount".to_vec(),
+                expected: 1u128.encode(),
+                received: 2u128.encode(),
+            }),
+            output: None,
+            inclusion_data: vec![1, 2, 3],
+            executioner: AccountId32::new([1u8; 32]),
+            received_at: 10,
+            cost: Some(1),
+        };
+        let rlp_encoded = confirmation.to_rlp().unwrap();
+        assert_eq!(
+            ConfirmedSideEffect::from_rlp(&rlp_encoded),
+            Ok(confirmation)
+        );
+    }
+
     // fixme: Revisit t3rn_sdk_primitives and update TryFrom SideEffect new interface changed
     //  after Executors Bidding (t3rn/t3rn#477)
     // use t3rn_sdk_primitives::{